@@ -48,7 +48,7 @@ use finance_query::indicators::{
 use finance_query::risk::{
     beta, historical_var, max_drawdown, parametric_var, sharpe_ratio, sortino_ratio,
 };
-use finance_query::streaming::{MarketHoursType, OptionType, PriceUpdate, QuoteType};
+use finance_query::streaming::{AssetClass, MarketHoursType, OptionType, PriceUpdate, QuoteType};
 use finance_query::translation::{Lang, translate_texts};
 use finance_query::{
     Candle, Chart, CompanyFacts, Currency, EdgarSubmissions, FinancialStatement, News, Options,
@@ -439,6 +439,7 @@ fn make_price_update() -> PriceUpdate {
         last_market: String::new(),
         circulating_supply: 0.0,
         market_cap: 2_700_000_000_000.0,
+        asset_class: AssetClass::Equity,
     }
 }
 
@@ -1,5 +1,5 @@
 use criterion::{Criterion, black_box, criterion_group, criterion_main};
-use finance_query::streaming::{MarketHoursType, OptionType, PriceUpdate, QuoteType};
+use finance_query::streaming::{AssetClass, MarketHoursType, OptionType, PriceUpdate, QuoteType};
 
 fn make_price_update() -> PriceUpdate {
     PriceUpdate {
@@ -36,6 +36,7 @@ fn make_price_update() -> PriceUpdate {
         last_market: String::new(),
         circulating_supply: 0.0,
         market_cap: 2_700_000_000_000.0,
+        asset_class: AssetClass::Equity,
     }
 }
 
@@ -117,6 +117,7 @@ pub struct BacktestOptions {
     pub json: bool,
     pub no_tui: bool,
     pub preset: Option<String>,
+    pub strategy_file: Option<std::path::PathBuf>,
 }
 
 // ============================================================================
@@ -190,6 +191,17 @@ fn run_config_tui_with_config(
 // ============================================================================
 
 pub async fn execute(args: BacktestOptions) -> Result<()> {
+    // Handle strategy definition files
+    if let Some(ref path) = args.strategy_file {
+        let symbol = args.symbol.as_deref().ok_or_else(|| {
+            crate::error::CliError::InvalidArgument(
+                "Symbol required. Use: fq backtest <SYMBOL> --strategy-file <PATH>".into(),
+            )
+        })?;
+        run_strategy_file(&symbol.to_uppercase(), path, args.json).await?;
+        return Ok(());
+    }
+
     // Handle preset mode
     if let Some(ref preset_name) = args.preset {
         let presets = StrategyPreset::all();
@@ -271,6 +283,37 @@ pub async fn execute(args: BacktestOptions) -> Result<()> {
     Ok(())
 }
 
+/// Run a serialized [`StrategyDefinition`](finance_query::backtesting::StrategyDefinition)
+/// with the default interval, range, and capital settings.
+async fn run_strategy_file(
+    symbol: &str,
+    path: &std::path::Path,
+    json_output: bool,
+) -> Result<ResultsAction> {
+    use finance_query::backtesting::StrategyDefinition;
+
+    let definition = StrategyDefinition::from_file(path)?;
+    let strategy = definition.build()?;
+    let config = BacktestConfiguration::default();
+
+    if !json_output {
+        eprintln!("Running backtest for {} — {}", symbol, definition.name);
+        eprintln!();
+    }
+
+    let ticker = crate::lang::ticker(symbol).await?;
+    let result = ticker
+        .backtest(strategy, config.interval, config.range, None)
+        .await?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+        Ok(ResultsAction::Quit)
+    } else {
+        run_results_tui(RunResult::simple(result))
+    }
+}
+
 async fn run_backtest_with_config(
     config: BacktestConfiguration,
     json_output: bool,
@@ -330,6 +373,9 @@ async fn run_backtest_with_config(
     if let Some(ts) = config.trailing_stop {
         builder = builder.trailing_stop_pct(ts);
     }
+    if let Some(ref bench) = config.benchmark {
+        builder = builder.benchmark(bench.clone());
+    }
 
     let backtest_config = builder
         .build()
@@ -501,7 +547,6 @@ async fn run_backtest_with_config(
                 optimization: None,
                 walk_forward: Some(wf_report),
                 opt_metric: Some(opt_config.metric),
-                bench_candles: None,
                 portfolio: None,
             }
         } else {
@@ -589,7 +634,6 @@ async fn run_backtest_with_config(
                 optimization: Some(opt_report),
                 walk_forward: None,
                 opt_metric: Some(opt_config.metric),
-                bench_candles: None,
                 portfolio: None,
             }
         };
@@ -632,7 +676,6 @@ async fn run_backtest_with_config(
                         ) {
                             run_result.backtest.benchmark = bench_result.benchmark;
                         }
-                        run_result.bench_candles = Some(bench_chart.candles);
                     }
                 }
                 Err(e) => {
@@ -654,33 +697,19 @@ async fn run_backtest_with_config(
 
     // ── Normal backtest path ──────────────────────────────────────────────────
 
-    // Dispatch a concrete Strategy to either backtest() or backtest_with_benchmark().
     // backtest_config is cloned per call because the library takes ownership.
+    // It carries the benchmark symbol, if any, so the result includes the
+    // benchmark comparison.
     macro_rules! run {
         ($strategy:expr) => {
-            match &config.benchmark {
-                Some(bench) => {
-                    ticker
-                        .backtest_with_benchmark(
-                            $strategy,
-                            config.interval,
-                            config.range,
-                            Some(backtest_config.clone()),
-                            bench,
-                        )
-                        .await?
-                }
-                None => {
-                    ticker
-                        .backtest(
-                            $strategy,
-                            config.interval,
-                            config.range,
-                            Some(backtest_config.clone()),
-                        )
-                        .await?
-                }
-            }
+            ticker
+                .backtest(
+                    $strategy,
+                    config.interval,
+                    config.range,
+                    Some(backtest_config.clone()),
+                )
+                .await?
         };
     }
 
@@ -731,17 +760,7 @@ async fn run_backtest_with_config(
         run!(SmaCrossover::new(20, 50))
     };
 
-    let mut run_result = RunResult::simple(result);
-
-    // Fetch benchmark candles for the actual buy-and-hold equity curve on the
-    // Charts tab. The chart data will be cached inside the bench Ticker, so this
-    // adds at most one network round-trip and is a no-op when the cache is warm.
-    if let Some(ref bench_sym) = config.benchmark
-        && let Ok(bench_ticker) = crate::lang::ticker(bench_sym).await
-        && let Ok(bench_chart) = bench_ticker.chart(config.interval, config.range).await
-    {
-        run_result.bench_candles = Some(bench_chart.candles);
-    }
+    let run_result = RunResult::simple(result);
 
     if json_output {
         let json = serde_json::to_string_pretty(&run_result.backtest)?;
@@ -23,10 +23,6 @@ pub struct RunResult {
     pub optimization: Option<OptimizationReport>,
     pub walk_forward: Option<WalkForwardReport>,
     pub opt_metric: Option<OptimizeMetric>,
-    /// Benchmark candles for plotting the actual buy-and-hold equity curve.
-    /// When present, the Charts tab uses the real curve instead of a linear
-    /// interpolation of the benchmark's total return.
-    pub bench_candles: Option<Vec<finance_query::Candle>>,
     /// Portfolio result (set when portfolio mode was used). The `backtest`
     /// field holds the primary symbol's result for single-symbol tabs.
     pub portfolio: Option<PortfolioResult>,
@@ -39,7 +35,6 @@ impl RunResult {
             optimization: None,
             walk_forward: None,
             opt_metric: None,
-            bench_candles: None,
             portfolio: None,
         }
     }
@@ -551,6 +546,8 @@ fn render_results_overview(f: &mut Frame, app: &ResultsApp, area: ratatui::layou
         metric_line("Avg Trade Ret", &format!("{:.2}%", m.avg_trade_return_pct)),
         metric_line("Avg Win Dur", &format_duration_secs(m.avg_win_duration)),
         metric_line("Avg Loss Dur", &format_duration_secs(m.avg_loss_duration)),
+        metric_line("Avg MAE", &format!("{:.2}%", m.avg_mae_pct)),
+        metric_line("Avg MFE", &format!("{:.2}%", m.avg_mfe_pct)),
     ];
 
     let risk = Paragraph::new(risk_lines)
@@ -638,6 +635,10 @@ fn render_results_overview(f: &mut Frame, app: &ResultsApp, area: ratatui::layou
                 "Buy & Hold Ret",
                 &format!("{:.2}%", bench.buy_and_hold_return_pct),
             ),
+            metric_line(
+                "Bench Max DD",
+                &format!("{:.2}%", bench.max_drawdown_pct * 100.0),
+            ),
         ];
 
         let right_lines = vec![
@@ -645,6 +646,10 @@ fn render_results_overview(f: &mut Frame, app: &ResultsApp, area: ratatui::layou
             metric_line("Alpha", &format!("{:.2}%", bench.alpha)),
             metric_line("Beta", &format!("{:.3}", bench.beta)),
             metric_line("Info Ratio", &format_ratio(bench.information_ratio)),
+            metric_line(
+                "Relative DD",
+                &format!("{:.2}%", bench.relative_drawdown_pct * 100.0),
+            ),
         ];
 
         let bench_left = Paragraph::new(left_lines)
@@ -1204,6 +1209,10 @@ fn render_monte_carlo(f: &mut Frame, app: &ResultsApp, area: ratatui::layout::Re
                 .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
         ),
+        Span::styled(
+            format!("seed {} ", mc.seed),
+            Style::default().fg(Color::Cyan),
+        ),
         Span::styled(
             " (press v to cycle method)",
             Style::default().fg(Color::DarkGray),
@@ -1357,16 +1366,17 @@ fn render_equity_chart(f: &mut Frame, app: &ResultsApp, area: ratatui::layout::R
             .data(&strategy_data),
     ];
 
-    // Benchmark overlay: actual buy-and-hold equity curve from candle closes,
-    // falling back to a linear approximation when candles aren't available.
+    // Benchmark overlay: buy-and-hold equity curve aligned with the strategy's,
+    // falling back to a linear approximation when the curve isn't available
+    // (e.g. results saved by older versions).
     let bench_data: Vec<(f64, f64)>;
     if let Some(ref bench) = r.benchmark {
-        bench_data = if let Some(ref candles) = app.result.bench_candles {
-            let first_close = candles.first().map(|c| c.close).unwrap_or(1.0);
-            candles
+        bench_data = if !bench.equity_curve.is_empty() {
+            bench
+                .equity_curve
                 .iter()
                 .enumerate()
-                .map(|(i, c)| (i as f64, r.initial_capital * (c.close / first_close)))
+                .map(|(i, p)| (i as f64, p.equity))
                 .collect()
         } else {
             // Fallback: linear interpolation of total return (no intra-period detail)
@@ -1381,7 +1391,7 @@ fn render_equity_chart(f: &mut Frame, app: &ResultsApp, area: ratatui::layout::R
                 })
                 .collect()
         };
-        let label = if app.result.bench_candles.is_some() {
+        let label = if !bench.equity_curve.is_empty() {
             format!("{} B&H", bench.symbol)
         } else {
             format!("{} B&H (approx)", bench.symbol)
@@ -222,7 +222,7 @@ impl ConfigField {
     pub fn help(&self) -> &'static str {
         match self {
             Self::Symbol => "Stock ticker symbol (e.g., AAPL, TSLA, MSFT)",
-            Self::Interval => "Candle interval: 1m, 5m, 15m, 1h, 1d, 1wk, 1mo",
+            Self::Interval => "Candle interval: 1m, 5m, 15m, 1h, 4h, 1d, 1wk, 1mo",
             Self::Range => "Historical range: 1d, 5d, 1mo, 3mo, 6mo, 1y, 2y, 5y, max",
             Self::Capital => "Starting capital in dollars",
             Self::CostProfile => {
@@ -1569,36 +1569,18 @@ pub fn parse_bool(s: &str) -> Result<bool> {
 }
 
 fn interval_rank(interval: Interval) -> usize {
-    match interval {
-        Interval::OneMinute => 0,
-        Interval::FiveMinutes => 1,
-        Interval::FifteenMinutes => 2,
-        Interval::ThirtyMinutes => 3,
-        Interval::OneHour => 4,
-        Interval::OneDay => 5,
-        Interval::OneWeek => 6,
-        Interval::OneMonth => 7,
-        Interval::ThreeMonths => 8,
-    }
+    Interval::ALL
+        .iter()
+        .position(|i| *i == interval)
+        .unwrap_or_default()
 }
 
 fn available_htf_intervals_for_base(base_interval: Interval) -> Vec<Option<Interval>> {
-    let all = [
-        Interval::OneMinute,
-        Interval::FiveMinutes,
-        Interval::FifteenMinutes,
-        Interval::ThirtyMinutes,
-        Interval::OneHour,
-        Interval::OneDay,
-        Interval::OneWeek,
-        Interval::OneMonth,
-        Interval::ThreeMonths,
-    ];
-
     let base_rank = interval_rank(base_interval);
     let mut options = vec![None];
     options.extend(
-        all.into_iter()
+        Interval::ALL
+            .into_iter()
             .filter(|interval| interval_rank(*interval) > base_rank)
             .map(Some),
     );
@@ -1745,6 +1727,8 @@ mod tests {
             options,
             vec![
                 None,
+                Some(Interval::NinetyMinutes),
+                Some(Interval::FourHours),
                 Some(Interval::OneDay),
                 Some(Interval::OneWeek),
                 Some(Interval::OneMonth),
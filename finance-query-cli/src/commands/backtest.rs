@@ -18,6 +18,10 @@ pub struct BacktestArgs {
     /// Use a preset strategy: swing, day, trend, mean-reversion, conservative, aggressive
     #[arg(short, long)]
     pub preset: Option<String>,
+
+    /// Run a strategy definition from a JSON file (skips the TUI setup)
+    #[arg(long, value_name = "PATH", conflicts_with = "preset")]
+    pub strategy_file: Option<std::path::PathBuf>,
 }
 
 pub async fn execute(args: BacktestArgs) -> Result<()> {
@@ -26,6 +30,7 @@ pub async fn execute(args: BacktestArgs) -> Result<()> {
         json: args.json,
         no_tui: args.no_tui,
         preset: args.preset,
+        strategy_file: args.strategy_file,
     };
 
     crate::backtest::execute(opts).await
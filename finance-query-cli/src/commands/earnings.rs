@@ -3,8 +3,12 @@ use crate::output::{self, OutputFormat};
 use chrono::{DateTime, Utc};
 use clap::Parser;
 use colored::Colorize;
+use finance_query::{EarningsChartData, EpsPoint};
 use serde::Serialize;
 
+/// Width of the beat/miss bar at the largest surprise shown
+const BAR_WIDTH: usize = 20;
+
 #[derive(Parser)]
 pub struct EarningsArgs {
     /// Stock symbol to get earnings for
@@ -20,11 +24,13 @@ pub struct EarningsArgs {
 struct EarningsJson {
     symbol: String,
     next_earnings_date: Option<String>,
+    earnings_date_is_estimate: Option<bool>,
     earnings_estimate: Option<f64>,
     revenue_estimate: Option<i64>,
     ex_dividend_date: Option<String>,
     dividend_date: Option<String>,
     quarterly_history: Vec<QuarterJson>,
+    earnings_chart: Option<EarningsChartData>,
 }
 
 #[derive(Debug, Serialize)]
@@ -42,7 +48,7 @@ pub async fn execute(args: EarningsArgs) -> Result<()> {
     // Fetch earnings data
     let (calendar, earnings, history) = tokio::join!(
         ticker.calendar_events(),
-        ticker.earnings(),
+        ticker.earnings_chart(),
         ticker.earnings_history()
     );
 
@@ -58,6 +64,9 @@ pub async fn execute(args: EarningsArgs) -> Result<()> {
                 .as_ref()
                 .and_then(|c| c.next_earnings_date())
                 .and_then(format_date),
+            earnings_date_is_estimate: cal
+                .as_ref()
+                .and_then(|c| c.earnings.as_ref()?.is_earnings_date_estimate),
             earnings_estimate: cal.as_ref().and_then(|c| c.earnings_estimate()),
             revenue_estimate: cal
                 .as_ref()
@@ -84,6 +93,7 @@ pub async fn execute(args: EarningsArgs) -> Result<()> {
                         .collect()
                 })
                 .unwrap_or_default(),
+            earnings_chart: earn.clone(),
         };
 
         match format {
@@ -121,50 +131,43 @@ pub async fn execute(args: EarningsArgs) -> Result<()> {
     println!("{}", "Upcoming Events".blue().bold());
     println!("{}", "─".repeat(50));
 
-    if let Some(c) = &cal {
-        // Next earnings date
-        if let Some(ts) = c.next_earnings_date() {
-            let date_str = format_date(ts).unwrap_or_else(|| "N/A".to_string());
-            let is_estimate = c
-                .earnings
-                .as_ref()
-                .and_then(|e| {
-                    e.earnings_date
-                        .as_ref()?
-                        .first()?
-                        .fmt
-                        .as_ref()
-                        .map(|_| true)
-                })
-                .unwrap_or(false);
-            let label = if is_estimate {
+    if let Some(dates) = cal.as_ref().map(|c| c.key_dates()) {
+        // Next earnings date, or window when Yahoo gives a range
+        if !dates.earnings_dates.is_empty() {
+            let window = dates
+                .earnings_dates
+                .iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<_>>()
+                .join(" – ");
+            let label = if dates.earnings_date_is_estimate == Some(true) {
                 "Next Earnings (Est)"
             } else {
                 "Next Earnings"
             };
-            print_row(label, Some(date_str));
+            print_row(label, Some(window));
         }
 
-        // Earnings estimate
-        if let Some(est) = c.earnings_estimate() {
-            print_row("EPS Estimate", Some(format!("${:.2}", est)));
+        // Earnings estimate, with the analyst range when known
+        if let Some(avg) = dates.eps_estimate.average {
+            let range = match (dates.eps_estimate.low, dates.eps_estimate.high) {
+                (Some(low), Some(high)) => format!(" (${:.2} – ${:.2})", low, high),
+                _ => String::new(),
+            };
+            print_row("EPS Estimate", Some(format!("${:.2}{}", avg, range)));
         }
 
         // Revenue estimate
-        if let Some(rev) = c
-            .earnings
-            .as_ref()
-            .and_then(|e| e.revenue_average.as_ref()?.raw)
-        {
+        if let Some(rev) = dates.revenue_estimate.average {
             print_row("Revenue Estimate", Some(format_currency(rev)));
         }
 
         // Dividend dates
-        if let Some(ts) = c.ex_dividend_timestamp() {
-            print_row("Ex-Dividend Date", format_date(ts));
+        if let Some(d) = dates.ex_dividend_date {
+            print_row("Ex-Dividend Date", Some(d.to_string()));
         }
-        if let Some(ts) = c.dividend_timestamp() {
-            print_row("Dividend Date", format_date(ts));
+        if let Some(d) = dates.dividend_date {
+            print_row("Dividend Date", Some(d.to_string()));
         }
     } else {
         println!("  {}", "No calendar events available".dimmed());
@@ -234,37 +237,97 @@ pub async fn execute(args: EarningsArgs) -> Result<()> {
         println!("  {}", "No earnings history available".dimmed());
     }
 
-    // Quarterly Earnings Chart (from earnings module)
-    if let Some(e) = &earn
-        && let Some(chart) = &e.earnings_chart
-        && !chart.quarterly.is_empty()
-    {
-        println!();
-        println!("{}", "Recent Quarterly Results".blue().bold());
-        println!("{}", "─".repeat(50));
-
-        for q in chart.quarterly.iter().take(4) {
-            let date = q.date.clone().unwrap_or_else(|| "N/A".to_string());
-            let actual = q
-                .actual
-                .as_ref()
-                .and_then(|v| v.raw)
-                .map(|v| format!("${:.2}", v))
-                .unwrap_or_else(|| "N/A".to_string());
-            let estimate = q
-                .estimate
-                .as_ref()
-                .and_then(|v| v.raw)
-                .map(|v| format!("${:.2}", v))
-                .unwrap_or_else(|| "N/A".to_string());
+    // Beat/miss chart (from earnings module)
+    if let Some(chart) = &earn {
+        if !chart.quarterly_eps.is_empty() {
+            println!();
+            println!("{}", "Beat / Miss".blue().bold());
+            println!("{}", "─".repeat(70));
+            print_beat_miss(&chart.quarterly_eps);
+            if let Some(est) = chart.current_quarter_estimate {
+                let label = chart.current_quarter.as_deref().unwrap_or("Next");
+                println!(
+                    "{:<8} {:>8} {:>8}  {}",
+                    label,
+                    "—",
+                    format!("${:.2}", est),
+                    "pending".dimmed()
+                );
+            }
+        }
 
-            println!("  {} - Actual: {}, Est: {}", date, actual, estimate);
+        if !chart.yearly.is_empty() {
+            println!();
+            println!("{}", "Annual Revenue / Earnings".blue().bold());
+            println!("{}", "─".repeat(50));
+            for y in &chart.yearly {
+                println!(
+                    "  {:<8} {:>12} {:>12}",
+                    y.period,
+                    y.revenue
+                        .map(format_currency)
+                        .unwrap_or_else(|| "N/A".to_string()),
+                    y.earnings
+                        .map(format_currency)
+                        .unwrap_or_else(|| "N/A".to_string()),
+                );
+            }
         }
     }
 
     Ok(())
 }
 
+/// One row per quarter: EPS actual vs. estimate with a bar sized by the
+/// surprise, green to the right for beats and red to the left for misses.
+fn print_beat_miss(points: &[EpsPoint]) {
+    let max = points
+        .iter()
+        .filter_map(|p| p.surprise_percent)
+        .map(f64::abs)
+        .fold(0.0, f64::max);
+
+    for p in points {
+        let actual = p
+            .actual
+            .map(|v| format!("${:.2}", v))
+            .unwrap_or_else(|| "N/A".to_string());
+        let estimate = p
+            .estimate
+            .map(|v| format!("${:.2}", v))
+            .unwrap_or_else(|| "N/A".to_string());
+
+        let len = match p.surprise_percent {
+            Some(s) if max > 0.0 => ((s.abs() / max) * BAR_WIDTH as f64).round().max(1.0) as usize,
+            _ => 0,
+        };
+        let (left, right, marker) = match p.beat() {
+            Some(true) => (
+                " ".repeat(BAR_WIDTH),
+                "█".repeat(len).green().to_string(),
+                "✓".green().to_string(),
+            ),
+            Some(false) => (
+                format!("{:>width$}", "█".repeat(len), width = BAR_WIDTH)
+                    .red()
+                    .to_string(),
+                String::new(),
+                "✗".red().to_string(),
+            ),
+            None => (" ".repeat(BAR_WIDTH), String::new(), " ".to_string()),
+        };
+        let surprise = p
+            .surprise_percent
+            .map(|v| format!("{:+.1}%", v))
+            .unwrap_or_default();
+
+        println!(
+            "{:<8} {:>8} {:>8}  {} {}│{} {}",
+            p.period, actual, estimate, marker, left, right, surprise
+        );
+    }
+}
+
 fn print_row(label: &str, value: Option<String>) {
     let val = value.unwrap_or_else(|| "N/A".to_string());
     println!("  {:<25} {}", label, val);
@@ -0,0 +1,52 @@
+use crate::error::{CliError, Result};
+use crate::output;
+use clap::Parser;
+use colored::Colorize;
+use finance_query::finance;
+use std::path::PathBuf;
+
+#[derive(Parser)]
+pub struct EodArgs {
+    /// Symbols to collect (e.g., AAPL MSFT SPY)
+    #[arg(required = true)]
+    symbols: Vec<String>,
+
+    /// Directory to write the dated bundle into
+    #[arg(short, long, default_value = ".")]
+    dir: PathBuf,
+
+    /// Collect even while U.S. markets are open (daily bars won't be final)
+    #[arg(short, long)]
+    force: bool,
+}
+
+pub async fn execute(args: EodArgs) -> Result<()> {
+    if !args.force {
+        let hours = finance::hours(None).await?;
+        if hours.markets.first().is_some_and(|m| m.is_open()) {
+            return Err(CliError::InvalidArgument(
+                "U.S. markets are still open; run after the close or pass --force".to_string(),
+            ));
+        }
+    }
+
+    let snapshot = finance::eod_snapshot(args.symbols, &args.dir).await?;
+    let path = args.dir.join(snapshot.file_name());
+
+    output::print_success(&format!(
+        "Saved {} symbols for {} to {}",
+        snapshot.symbols.len(),
+        snapshot.session_date,
+        path.display()
+    ));
+    if !snapshot.is_final() {
+        output::print_info("Session was not closed at collection time; daily bars may change");
+    }
+
+    let mut errors: Vec<_> = snapshot.errors.iter().collect();
+    errors.sort();
+    for (symbol, reason) in errors {
+        println!("  {} {}: {}", "✗".red(), symbol, reason);
+    }
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use crate::error::Result;
+use crate::output::{self, OutputFormat};
+use clap::Parser;
+use finance_query::finance;
+use serde::Serialize;
+use tabled::Tabled;
+
+#[derive(Parser)]
+pub struct GapsArgs {
+    /// Symbols to scan (e.g., AAPL NVDA TSLA)
+    #[arg(required = true)]
+    symbols: Vec<String>,
+
+    /// Minimum absolute gap from the prior close, in percent
+    #[arg(short, long, default_value = "2.0")]
+    min_gap: f64,
+
+    /// Only show gappers with recent news
+    #[arg(long)]
+    news_only: bool,
+
+    /// Output format (table, json, csv)
+    #[arg(short, long, default_value = "table")]
+    output: String,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct GapDisplay {
+    #[tabled(rename = "Symbol")]
+    symbol: String,
+
+    #[tabled(rename = "Name")]
+    name: String,
+
+    #[tabled(rename = "Prev Close")]
+    previous_close: String,
+
+    #[tabled(rename = "Price")]
+    price: String,
+
+    #[tabled(rename = "Gap %")]
+    gap_percent: String,
+
+    #[tabled(rename = "Pre-Mkt Vol")]
+    premarket_volume: String,
+
+    #[tabled(rename = "RVOL")]
+    rvol: String,
+
+    #[tabled(rename = "News")]
+    news: String,
+}
+
+pub async fn execute(args: GapsArgs) -> Result<()> {
+    let format = OutputFormat::from_str(&args.output)?;
+
+    let gappers = finance::gap_scan(args.symbols, args.min_gap).await?;
+
+    let rows: Vec<GapDisplay> = gappers
+        .iter()
+        .filter(|g| !args.news_only || g.has_news())
+        .map(|g| GapDisplay {
+            symbol: g.symbol.clone(),
+            name: g.name.clone().unwrap_or_default(),
+            previous_close: format!("{:.2}", g.previous_close),
+            price: format!("{:.2}", g.price),
+            gap_percent: format!("{:+.2}%", g.gap_percent),
+            premarket_volume: g
+                .premarket_volume
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            rvol: g
+                .rvol
+                .map(|r| format!("{r:.1}x"))
+                .unwrap_or_else(|| "-".to_string()),
+            news: if g.has_news() {
+                g.recent_news.to_string()
+            } else {
+                "-".to_string()
+            },
+        })
+        .collect();
+
+    if rows.is_empty() {
+        output::print_info(&format!("No symbols gapping at least {}%", args.min_gap));
+        return Ok(());
+    }
+
+    output::print_many(&rows, format)?;
+
+    Ok(())
+}
@@ -1,18 +1,47 @@
 use crate::error::Result;
 use crate::output;
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use finance_query::finance;
+use finance_query::streaming::AssetClass;
+
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum MarketKind {
+    /// Stock exchanges, as reported by Yahoo Finance
+    #[default]
+    Equity,
+    /// Crypto markets (always open)
+    Crypto,
+    /// Spot FX (closed weekends)
+    Fx,
+    /// CME futures (overnight sessions, daily break)
+    Futures,
+}
+
+impl MarketKind {
+    fn asset_class(self) -> AssetClass {
+        match self {
+            MarketKind::Equity => AssetClass::Equity,
+            MarketKind::Crypto => AssetClass::Crypto,
+            MarketKind::Fx => AssetClass::Fx,
+            MarketKind::Futures => AssetClass::Future,
+        }
+    }
+}
 
 #[derive(Parser)]
 pub struct HoursArgs {
     /// Region code (e.g., US, JP, GB). Defaults to US.
     #[arg(short, long)]
     region: Option<String>,
+
+    /// Market to check. Crypto, FX, and futures use their fixed trading schedules.
+    #[arg(short, long, value_enum, default_value_t = MarketKind::Equity)]
+    market: MarketKind,
 }
 
 pub async fn execute(args: HoursArgs) -> Result<()> {
     let region = args.region.as_deref().and_then(|s| s.parse().ok());
-    let hours = finance::hours(region).await?;
+    let hours = finance::hours_for(args.market.asset_class(), region).await?;
 
     if hours.markets.is_empty() {
         output::print_info("No market hours data available");
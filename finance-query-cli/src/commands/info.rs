@@ -1,7 +1,9 @@
 use crate::error::Result;
+use crate::numfmt;
 use crate::output::OutputFormat;
 use clap::Parser;
 use colored::Colorize;
+use finance_query::{PerformancePeriod, PerformanceSummary};
 use serde::Serialize;
 
 #[derive(Parser)]
@@ -56,14 +58,23 @@ struct InfoJson {
     employees: Option<i64>,
     website: Option<String>,
     description: Option<String>,
+    /// JSON only; CSV rows can't hold the nested period list
+    #[serde(skip_serializing_if = "Option::is_none")]
+    performance: Option<PerformanceSummary>,
 }
 
 pub async fn execute(args: InfoArgs) -> Result<()> {
     let format = OutputFormat::from_str(&args.output)?;
 
-    // Fetch full quote data
+    // Fetch the full quote and the performance overview concurrently
     let ticker = crate::lang::ticker(&args.symbol).await?;
-    let quote = ticker.quote::<finance_query::format::Both>().await?;
+    let (quote, performance) = tokio::join!(
+        ticker.quote::<finance_query::format::Both>(),
+        ticker.performance_overview()
+    );
+    let quote = quote?;
+    let performance = performance.ok().flatten();
+    crate::startup::mark("fetch quote");
 
     // For JSON/CSV output, return structured data
     if format != OutputFormat::Table {
@@ -112,6 +123,7 @@ pub async fn execute(args: InfoArgs) -> Result<()> {
             employees: quote.full_time_employees,
             website: quote.website.clone(),
             description: quote.long_business_summary.clone(),
+            performance: performance.clone().filter(|_| format == OutputFormat::Json),
         };
 
         match format {
@@ -348,6 +360,51 @@ pub async fn execute(args: InfoArgs) -> Result<()> {
             }
         }
 
+        // Performance vs benchmark
+        if let Some(perf) = &performance
+            && !perf.periods.is_empty()
+        {
+            print_section_header("Performance", term_width);
+            let benchmark = perf
+                .benchmark_name
+                .as_deref()
+                .or(perf.benchmark_symbol.as_deref())
+                .unwrap_or("Benchmark");
+            println!(
+                "  {:<20} {:>10}  {:>10}  {:>10}",
+                "",
+                quote.symbol.dimmed(),
+                benchmark.dimmed(),
+                "Delta".dimmed()
+            );
+            for period in [
+                PerformancePeriod::OneWeek,
+                PerformancePeriod::OneMonth,
+                PerformancePeriod::ThreeMonths,
+                PerformancePeriod::Ytd,
+                PerformancePeriod::OneYear,
+                PerformancePeriod::ThreeYears,
+                PerformancePeriod::FiveYears,
+            ] {
+                let Some(row) = perf.get(period) else {
+                    continue;
+                };
+                let cell = |v: Option<f64>| v.map(format_growth).unwrap_or_else(|| "—".to_string());
+                let delta = match row.delta {
+                    Some(d) if d >= 0.0 => format_growth(d).green().to_string(),
+                    Some(d) => format_growth(d).red().to_string(),
+                    None => "—".to_string(),
+                };
+                println!(
+                    "  {:<20} {:>10}  {:>10}  {:>10}",
+                    period.as_str().to_uppercase().dimmed(),
+                    cell(row.stock),
+                    cell(row.benchmark),
+                    delta
+                );
+            }
+        }
+
         // Analyst ratings
         let has_analyst = quote.recommendation_key.is_some() || quote.target_mean_price.is_some();
         if has_analyst {
@@ -588,48 +645,25 @@ fn print_wrapped_text(text: &str, width: usize) {
 
 /// Format volume with abbreviations (K, M, B)
 fn format_volume(volume: i64) -> String {
-    match volume {
-        v if v >= 1_000_000_000 => format!("{:.2}B", v as f64 / 1_000_000_000.0),
-        v if v >= 1_000_000 => format!("{:.2}M", v as f64 / 1_000_000.0),
-        v if v >= 1_000 => format!("{:.2}K", v as f64 / 1_000.0),
-        _ => volume.to_string(),
-    }
+    numfmt::large(volume as f64)
 }
 
 /// Format market cap with abbreviations (M, B, T)
 fn format_market_cap(market_cap: i64) -> String {
-    match market_cap {
-        v if v >= 1_000_000_000_000 => format!("{:.2}T", v as f64 / 1_000_000_000_000.0),
-        v if v >= 1_000_000_000 => format!("{:.2}B", v as f64 / 1_000_000_000.0),
-        v if v >= 1_000_000 => format!("{:.2}M", v as f64 / 1_000_000.0),
-        _ => market_cap.to_string(),
-    }
+    numfmt::large(market_cap as f64)
 }
 
 /// Format percentage (input is decimal, e.g., 0.25 -> 25.00%)
 fn format_percent(value: f64) -> String {
-    format!("{:.2}%", value * 100.0)
+    numfmt::percent(value)
 }
 
 /// Format growth with sign and color indicator
 fn format_growth(value: f64) -> String {
-    let pct = value * 100.0;
-    if pct >= 0.0 {
-        format!("+{:.2}%", pct)
-    } else {
-        format!("{:.2}%", pct)
-    }
+    numfmt::signed_percent(value)
 }
 
-/// Format large numbers with commas
+/// Format large numbers with group separators
 fn format_number(n: i64) -> String {
-    let s = n.to_string();
-    let mut result = String::new();
-    for (i, c) in s.chars().rev().enumerate() {
-        if i > 0 && i % 3 == 0 {
-            result.insert(0, ',');
-        }
-        result.insert(0, c);
-    }
-    result
+    numfmt::options().decimals(0).format_number(n as f64)
 }
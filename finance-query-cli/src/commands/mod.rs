@@ -7,9 +7,11 @@ pub mod dashboard;
 pub mod dividends;
 pub mod earnings;
 pub mod edgar;
+pub mod eod;
 pub mod exchanges;
 pub mod facts;
 pub mod financials;
+pub mod gaps;
 pub mod grades;
 pub mod holders;
 pub mod hours;
@@ -25,6 +27,7 @@ pub mod quote;
 pub mod recommendations;
 pub mod screener;
 pub mod sector;
+pub mod signals;
 pub mod splits;
 pub mod stream;
 pub mod transcript;
@@ -32,6 +32,10 @@ pub struct OptionsArgs {
     /// List available expirations only (non-interactive)
     #[arg(long)]
     list_expirations: bool,
+
+    /// Risk-free rate for Greeks (non-interactive mode, 0.045 = 4.5%)
+    #[arg(long, default_value_t = crate::options::RISK_FREE_RATE)]
+    rate: f64,
 }
 
 pub async fn execute(args: OptionsArgs) -> Result<()> {
@@ -89,7 +93,7 @@ pub async fn execute(args: OptionsArgs) -> Result<()> {
         if !calls.is_empty() {
             output::print_success(&format!("Call options ({} total)", calls.len()));
             println!();
-            print_contracts(&calls, args.limit);
+            print_contracts(&calls, options.underlying_price(), args.rate, args.limit);
         }
     }
 
@@ -102,31 +106,53 @@ pub async fn execute(args: OptionsArgs) -> Result<()> {
         if !puts.is_empty() {
             output::print_success(&format!("Put options ({} total)", puts.len()));
             println!();
-            print_contracts(&puts, args.limit);
+            print_contracts(&puts, options.underlying_price(), args.rate, args.limit);
         }
     }
 
     Ok(())
 }
 
-fn print_contracts(contracts: &finance_query::Contracts, limit: usize) {
+fn print_contracts(
+    contracts: &finance_query::Contracts,
+    spot: Option<f64>,
+    rate: f64,
+    limit: usize,
+) {
     // Header
     println!(
-        "{:<25} {:<10} {:<12} {:<10} {:<10} {:<10}",
-        "Contract", "Strike", "Last Price", "Bid", "Ask", "Volume"
+        "{:<25} {:<10} {:<12} {:<10} {:<10} {:<10} {:>7} {:>7} {:>7} {:>7}",
+        "Contract",
+        "Strike",
+        "Last Price",
+        "Bid",
+        "Ask",
+        "Volume",
+        "Delta",
+        "Gamma",
+        "Theta",
+        "Vega"
     );
-    println!("{}", "-".repeat(85));
+    println!("{}", "-".repeat(117));
 
     // Data rows
     for contract in contracts.iter().take(limit) {
+        let greeks = match spot.and_then(|s| contract.greeks(s, rate)) {
+            Some(g) => format!(
+                "{:>+7.3} {:>7.4} {:>7.3} {:>7.3}",
+                g.delta, g.gamma, g.theta, g.vega
+            ),
+            None => format!("{:>7} {:>7} {:>7} {:>7}", "-", "-", "-", "-"),
+        };
         println!(
-            "{:<25} ${:<9.2} ${:<11.2} ${:<9.2} ${:<9.2} {:>10}",
+            "{:<25} ${:<9.2} ${:<11.2} ${:<9.2} ${:<9.2} {:>10} {}",
             contract.contract_symbol,
             contract.strike,
             contract.last_price.unwrap_or(0.0),
             contract.bid.unwrap_or(0.0),
             contract.ask.unwrap_or(0.0),
-            contract.volume.unwrap_or(0)
+            contract.volume.unwrap_or(0),
+            greeks
         );
     }
 
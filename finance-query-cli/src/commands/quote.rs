@@ -1,4 +1,5 @@
 use crate::error::Result;
+use crate::numfmt;
 use crate::output::{self, OutputFormat};
 use clap::Parser;
 use colored::Colorize;
@@ -52,14 +53,12 @@ pub async fn execute(args: QuoteArgs) -> Result<()> {
     let format = OutputFormat::from_str(&args.output)?;
 
     // Use Tickers for efficient batch fetching
-    let builder = Tickers::builder(args.symbols.clone());
+    let client = crate::session::client().await?;
+    let builder = Tickers::with_client(client, args.symbols.clone());
     let builder = if args.logo { builder.logo() } else { builder };
-    let builder = match crate::lang::target() {
-        Some(lang) => builder.lang(lang),
-        None => builder,
-    };
     let tickers = builder.build().await?;
     let response = tickers.quotes().await?;
+    crate::startup::mark("fetch quotes");
 
     // Convert successful quotes to display format
     let mut quotes = Vec::new();
@@ -74,43 +73,28 @@ pub async fn execute(args: QuoteArgs) -> Result<()> {
             price: quote
                 .regular_market_price
                 .as_ref()
-                .and_then(|v| v.raw.map(|r| format!("{:.2}", r)))
+                .and_then(|v| v.raw.map(numfmt::number))
                 .unwrap_or_else(|| "N/A".to_string()),
             change: quote
                 .regular_market_change
                 .as_ref()
-                .and_then(|v| {
-                    v.raw.map(|r| {
-                        if r >= 0.0 {
-                            format!("+{:.2}", r)
-                        } else {
-                            format!("{:.2}", r)
-                        }
-                    })
-                })
+                .and_then(|v| v.raw.map(numfmt::signed))
                 .unwrap_or_else(|| "N/A".to_string()),
             change_percent: quote
                 .regular_market_change_percent
                 .as_ref()
-                .and_then(|v| {
-                    v.raw.map(|r| {
-                        if r >= 0.0 {
-                            format!("+{:.2}%", r)
-                        } else {
-                            format!("{:.2}%", r)
-                        }
-                    })
-                })
+                // Yahoo reports this one in percent, not as a fraction
+                .and_then(|v| v.raw.map(|r| numfmt::signed_percent(r / 100.0)))
                 .unwrap_or_else(|| "N/A".to_string()),
             volume: quote
                 .regular_market_volume
                 .as_ref()
-                .and_then(|v| v.raw.map(format_volume))
+                .and_then(|v| v.raw.map(|r| numfmt::large(r as f64)))
                 .unwrap_or_else(|| "N/A".to_string()),
             market_cap: quote
                 .market_cap
                 .as_ref()
-                .and_then(|v| v.raw.map(format_market_cap))
+                .and_then(|v| v.raw.map(|r| numfmt::large(r as f64)))
                 .unwrap_or_else(|| "N/A".to_string()),
             exchange: quote.exchange.clone().unwrap_or_else(|| "N/A".to_string()),
         };
@@ -145,23 +129,3 @@ pub async fn execute(args: QuoteArgs) -> Result<()> {
 
     Ok(())
 }
-
-/// Format volume with abbreviations (K, M, B)
-fn format_volume(volume: i64) -> String {
-    match volume {
-        v if v >= 1_000_000_000 => format!("{:.2}B", v as f64 / 1_000_000_000.0),
-        v if v >= 1_000_000 => format!("{:.2}M", v as f64 / 1_000_000.0),
-        v if v >= 1_000 => format!("{:.2}K", v as f64 / 1_000.0),
-        _ => volume.to_string(),
-    }
-}
-
-/// Format market cap with abbreviations (M, B, T)
-fn format_market_cap(market_cap: i64) -> String {
-    match market_cap {
-        v if v >= 1_000_000_000_000 => format!("{:.2}T", v as f64 / 1_000_000_000_000.0),
-        v if v >= 1_000_000_000 => format!("{:.2}B", v as f64 / 1_000_000_000.0),
-        v if v >= 1_000_000 => format!("{:.2}M", v as f64 / 1_000_000.0),
-        _ => market_cap.to_string(),
-    }
-}
@@ -0,0 +1,120 @@
+use crate::error::Result;
+use crate::parse::{parse_interval, parse_range};
+use clap::{Parser, Subcommand};
+use colored::Colorize;
+use finance_query::backtesting::StrategyDefinition;
+use finance_query::signals::{SignalEvent, SignalRunner, SignalWebhook};
+use std::path::PathBuf;
+use tokio::time::{Duration, interval};
+
+#[derive(Parser)]
+pub struct SignalsArgs {
+    #[command(subcommand)]
+    command: SignalsCommand,
+}
+
+#[derive(Subcommand)]
+enum SignalsCommand {
+    /// Evaluate a saved strategy on a schedule and POST new signals to a webhook
+    Run {
+        /// Strategy definition file (JSON)
+        strategy: PathBuf,
+
+        /// Symbols to evaluate (e.g., AAPL MSFT NVDA)
+        #[arg(short, long, required = true, num_args = 1..)]
+        symbols: Vec<String>,
+
+        /// Webhook URL to POST signals to (prints only when omitted)
+        #[arg(short, long)]
+        webhook: Option<String>,
+
+        /// Secret used to sign webhook payloads (HMAC-SHA256)
+        #[arg(long, env = "FQ_SIGNALS_SECRET", hide_env_values = true)]
+        secret: Option<String>,
+
+        /// Bar interval (1m, 5m, 15m, 1h, 4h, 1d, 1wk, 1mo)
+        #[arg(short, long, default_value = "1d")]
+        interval: String,
+
+        /// History to evaluate over (1mo, 3mo, 6mo, 1y, 2y, 5y, max)
+        #[arg(short, long, default_value = "1y")]
+        range: String,
+
+        /// Seconds between evaluations
+        #[arg(short, long, default_value = "300")]
+        every: u64,
+
+        /// Evaluate once and exit (for cron)
+        #[arg(long)]
+        once: bool,
+    },
+}
+
+pub async fn execute(args: SignalsArgs) -> Result<()> {
+    match args.command {
+        SignalsCommand::Run {
+            strategy,
+            symbols,
+            webhook,
+            secret,
+            interval,
+            range,
+            every,
+            once,
+        } => {
+            let definition = StrategyDefinition::from_file(&strategy)?;
+            // Fail on invalid definitions up front rather than on every poll
+            definition.build()?;
+
+            let mut runner = SignalRunner::new(definition, symbols)
+                .interval(parse_interval(&interval)?)
+                .range(parse_range(&range)?);
+            if let Some(url) = webhook {
+                let mut hook = SignalWebhook::new(url);
+                if let Some(secret) = secret {
+                    hook = hook.with_secret(secret);
+                }
+                runner = runner.webhook(hook);
+            }
+
+            run(runner, every, once).await
+        }
+    }
+}
+
+async fn run(mut runner: SignalRunner, every: u64, once: bool) -> Result<()> {
+    if !once {
+        println!(
+            "{} Evaluating {} every {}s (Ctrl+C to stop)",
+            "[signals]".blue(),
+            runner.symbols().join(", "),
+            every
+        );
+    }
+
+    let mut poll_interval = interval(Duration::from_secs(every.max(1)));
+    loop {
+        poll_interval.tick().await;
+        for event in runner.poll().await? {
+            print_event(&event);
+        }
+        if once {
+            return Ok(());
+        }
+    }
+}
+
+fn print_event(event: &SignalEvent) {
+    let time = chrono::DateTime::from_timestamp(event.timestamp, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default();
+    let direction = format!("{:?}", event.direction).to_uppercase();
+    println!(
+        "{} {} {} @ {:.2}  {}",
+        time.dimmed(),
+        event.symbol.bold(),
+        direction.yellow().bold(),
+        event.price,
+        event.reason.as_deref().unwrap_or("")
+    );
+}
@@ -108,7 +108,8 @@ impl App {
 
         let options = finance_query::SearchOptions::new()
             .quotes_count(20)
-            .enable_logo_url(true);
+            .enable_logo_url(true)
+            .trading_status_count(10);
 
         match finance_query::finance::search(&self.search_query, &options).await {
             Ok(results) => {
@@ -134,6 +135,10 @@ impl App {
 
         let result = &self.search_results[self.selected_search_idx];
         let symbol = result.symbol.clone();
+        if result.trading_status == Some(finance_query::TradingStatus::Delisted) {
+            self.status_message = format!("{} is delisted", symbol);
+            return Ok(());
+        }
 
         self.storage
             .add_symbol_to_watchlist(self.current_watchlist.id, &symbol)?;
@@ -2090,20 +2090,27 @@ fn render_lookup(f: &mut Frame, app: &App, area: Rect) {
             .enumerate()
             .map(|(idx, result)| {
                 let is_selected = is_focused && idx == app.selected_search_idx;
+                let delisted =
+                    result.trading_status == Some(finance_query::TradingStatus::Delisted);
+                let (symbol_color, name_color) = if delisted {
+                    (Color::DarkGray, Color::DarkGray)
+                } else {
+                    (Color::Cyan, Color::White)
+                };
 
                 let symbol_style = if is_selected {
                     Style::default()
-                        .fg(Color::Cyan)
+                        .fg(symbol_color)
                         .add_modifier(Modifier::BOLD)
                         .bg(Color::DarkGray)
                 } else {
-                    Style::default().fg(Color::Cyan)
+                    Style::default().fg(symbol_color)
                 };
 
                 let name_style = if is_selected {
-                    Style::default().fg(Color::White).bg(Color::DarkGray)
+                    Style::default().fg(name_color).bg(Color::DarkGray)
                 } else {
-                    Style::default().fg(Color::White)
+                    Style::default().fg(name_color)
                 };
 
                 let meta_style = if is_selected {
@@ -2118,11 +2125,14 @@ fn render_lookup(f: &mut Frame, app: &App, area: Rect) {
                     .or_else(|| result.long_name.clone())
                     .unwrap_or_else(|| "Unknown".to_string());
 
-                let type_exch = format!(
+                let mut type_exch = format!(
                     "{} | {}",
                     result.quote_type.as_deref().unwrap_or(""),
                     result.exch_disp.as_deref().unwrap_or("")
                 );
+                if delisted {
+                    type_exch.push_str(" | DELISTED");
+                }
 
                 let content = vec![
                     Line::from(vec![
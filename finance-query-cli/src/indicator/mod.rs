@@ -351,6 +351,30 @@ fn display_result(
             }
             output::print_many(&rows, format)?;
         }
+        IndicatorResult::Patterns(signals) => {
+            let mut rows = Vec::new();
+            for (idx, candle) in candles.iter().enumerate() {
+                if latest && idx != candles.len() - 1 {
+                    continue;
+                }
+
+                let date = chrono::DateTime::from_timestamp(candle.timestamp, 0)
+                    .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+                    .unwrap_or_else(|| "N/A".to_string());
+
+                rows.push(IndicatorRow {
+                    date,
+                    close: format!("{:.2}", candle.close),
+                    value: signals
+                        .get(idx)
+                        .copied()
+                        .flatten()
+                        .map(|p| format!("{:?} ({:?})", p, p.sentiment()))
+                        .unwrap_or_else(|| "-".to_string()),
+                });
+            }
+            output::print_many(&rows, format)?;
+        }
         _ => {
             return Err(crate::error::CliError::InvalidArgument(
                 "This indicator type is not yet fully supported in CLI. Use the library API or JSON output.".to_string()
@@ -635,6 +659,29 @@ fn parse_indicator(s: &str) -> Result<Indicator> {
             Ok(Indicator::BalanceOfPower(period))
         }
 
+        // Candlestick Patterns
+        "patterns" | "candlestick_patterns" => Ok(Indicator::Patterns),
+
+        // Indicators registered through finance_query::indicators::register_indicator
+        custom if finance_query::indicators::custom_indicator(custom).is_some() => {
+            let params = params
+                .map(|p| {
+                    p.split(',')
+                        .map(|s| s.trim().parse::<f64>())
+                        .collect::<std::result::Result<Vec<_>, _>>()
+                })
+                .transpose()
+                .map_err(|e| {
+                    crate::error::CliError::InvalidArgument(format!(
+                        "Invalid parameters for '{}': {}",
+                        name, e
+                    ))
+                })?
+                .unwrap_or_default();
+            Indicator::custom(custom, &params)
+                .map_err(|e| crate::error::CliError::InvalidArgument(e.to_string()))
+        }
+
         _ => Err(crate::error::CliError::InvalidArgument(format!(
             "Unknown indicator '{}'. Use the interactive TUI to browse available indicators.",
             name
@@ -13,6 +13,7 @@ pub enum IndicatorCategory {
     Trend,
     Volatility,
     Volume,
+    Patterns,
 }
 
 impl IndicatorCategory {
@@ -23,6 +24,7 @@ impl IndicatorCategory {
             Self::Trend,
             Self::Volatility,
             Self::Volume,
+            Self::Patterns,
         ]
     }
 
@@ -33,6 +35,7 @@ impl IndicatorCategory {
             Self::Trend => "Trend",
             Self::Volatility => "Volatility",
             Self::Volume => "Volume",
+            Self::Patterns => "Patterns",
         }
     }
 
@@ -43,6 +46,7 @@ impl IndicatorCategory {
             Self::Trend => Color::Yellow,
             Self::Volatility => Color::Red,
             Self::Volume => Color::Green,
+            Self::Patterns => Color::Blue,
         }
     }
 }
@@ -755,6 +759,14 @@ impl IndicatorDef {
                     step: 1.0,
                 }],
             },
+            // Patterns
+            Self {
+                name: "Candlestick Patterns",
+                code: "patterns",
+                description: "Engulfing, doji, hammer and 17 other candle patterns",
+                category: IndicatorCategory::Patterns,
+                params: vec![],
+            },
         ]
     }
 }
@@ -1156,6 +1168,9 @@ fn build_indicator(def: &IndicatorDef, params: &[f64]) -> Result<Indicator> {
             }))
         }
 
+        // Patterns
+        "patterns" => Ok(Indicator::Patterns),
+
         _ => Err(CliError::InvalidArgument(format!(
             "Unknown indicator code: {}",
             def.code
@@ -3,7 +3,8 @@
 //! Commands construct tickers through [`ticker`]/[`tickers`] so the target
 //! language flows into the library, which translates human-readable fields
 //! (names, sectors, summaries, news titles) while leaving symbols, codes,
-//! and numbers untouched.
+//! and numbers untouched. Both share the process-wide session from
+//! [`crate::session`].
 
 use std::sync::OnceLock;
 
@@ -35,10 +36,8 @@ pub fn target() -> Option<&'static str> {
 
 /// Build a [`Ticker`] honoring the global target language.
 pub async fn ticker(symbol: impl Into<String>) -> Result<Ticker> {
-    match target() {
-        Some(lang) => Ticker::builder(symbol).lang(lang).build().await,
-        None => Ticker::new(symbol).await,
-    }
+    let client = crate::session::client().await?;
+    Ticker::with_client(client, symbol).build().await
 }
 
 /// Build a [`Tickers`] batch honoring the global target language.
@@ -47,10 +46,8 @@ where
     S: Into<String>,
     I: IntoIterator<Item = S>,
 {
-    match target() {
-        Some(lang) => Tickers::builder(symbols).lang(lang).build().await,
-        None => Tickers::new(symbols).await,
-    }
+    let client = crate::session::client().await?;
+    Tickers::with_client(client, symbols).build().await
 }
 
 /// Translate a typed library response in place when a target language is set.
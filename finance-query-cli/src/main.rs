@@ -151,6 +151,7 @@
 //! ### Utilities
 //! - `lookup` - Search for symbols
 //! - `hours` - Check market hours
+//! - `eod` - Save a daily end-of-day data bundle
 //! - `currencies` - Currency list and rates
 //! - `exchanges` - Exchange information
 //! - `alerts` - Manage price alerts
@@ -220,7 +221,7 @@
 #![warn(missing_docs)]
 #![warn(rustdoc::missing_crate_level_docs)]
 
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
 use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
 
 pub(crate) mod alerts;
@@ -232,10 +233,13 @@ mod edgar;
 mod error;
 mod indicator;
 pub(crate) mod lang;
+pub(crate) mod numfmt;
 pub(crate) mod options;
 mod output;
 mod parse;
 pub(crate) mod portfolio;
+mod session;
+mod startup;
 
 use error::Result;
 
@@ -261,6 +265,18 @@ struct Cli {
     #[arg(long, global = true, env = "FQ_LANG")]
     lang: Option<String>,
 
+    /// Number display options, e.g. "decimals=1,locale=de-DE,percent=bp,units=full"
+    #[arg(long, global = true, env = "FQ_NUMBER_FORMAT")]
+    number_format: Option<String>,
+
+    /// Authenticate from scratch instead of reusing the session saved by a previous run
+    #[arg(long, global = true, env = "FQ_NO_SESSION_CACHE")]
+    no_session_cache: bool,
+
+    /// Print a breakdown of where the command spent its time to stderr
+    #[arg(long, global = true)]
+    profile_startup: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -354,16 +370,30 @@ enum Commands {
     /// Get trending symbols by region
     Trending(commands::trending::TrendingArgs),
 
+    /// Scan symbols for pre-market/opening gaps vs. the prior close
+    Gaps(commands::gaps::GapsArgs),
+
+    /// Save an end-of-day bundle of quotes, key stats, and daily bars
+    Eod(commands::eod::EodArgs),
+
     /// Manage price alerts for stocks (add, list, check, remove)
     Alerts(commands::alerts::AlertsArgs),
 
+    /// Evaluate saved strategies live and POST signals to a webhook
+    Signals(commands::signals::SignalsArgs),
+
     /// Live market dashboard with real-time streaming (TUI)
     Dashboard(commands::dashboard::DashboardArgs),
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let cli = Cli::parse();
+    let started = std::time::Instant::now();
+    let matches = Cli::command().get_matches();
+    let command_name = matches.subcommand_name().unwrap_or_default().to_string();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    startup::init(cli.profile_startup, started);
+    startup::mark("parse args");
 
     // Initialize tracing/logging
     let filter = if cli.verbose {
@@ -385,8 +415,15 @@ async fn main() -> Result<()> {
     // Resolve the target translation language (--lang / FQ_LANG)
     lang::init(cli.lang.as_deref());
 
+    // Resolve number display options (--number-format / FQ_NUMBER_FORMAT)
+    numfmt::init(cli.number_format.as_deref());
+
+    // The Yahoo session itself is built lazily by the first command that needs it
+    session::init(!cli.no_session_cache);
+    startup::mark("init");
+
     // Execute the appropriate command
-    match cli.command {
+    let result = match cli.command {
         Commands::Quote(args) => commands::quote::execute(args).await,
         Commands::Backtest(args) => commands::backtest::execute(args).await,
         Commands::Stream(args) => commands::stream::execute(args).await,
@@ -416,7 +453,19 @@ async fn main() -> Result<()> {
         Commands::Exchanges(args) => commands::exchanges::execute(args).await,
         Commands::Market(args) => commands::market::execute(args).await,
         Commands::Trending(args) => commands::trending::execute(args).await,
+        Commands::Gaps(args) => commands::gaps::execute(args).await,
+        Commands::Eod(args) => commands::eod::execute(args).await,
         Commands::Alerts(args) => commands::alerts::execute(args).await,
+        Commands::Signals(args) => commands::signals::execute(args).await,
         Commands::Dashboard(args) => commands::dashboard::execute(args).await,
+    };
+    startup::mark("command");
+    startup::report(&command_name);
+
+    if let Err(error::CliError::FinanceQuery(e)) = &result
+        && e.is_auth_error()
+    {
+        session::forget();
     }
+    result
 }
@@ -0,0 +1,59 @@
+//! Global number display options (`--number-format` / `FQ_NUMBER_FORMAT`).
+//!
+//! Table output renders prices, percentages and large numbers through the
+//! library's [`FormatOptions`], so one spec such as
+//! `decimals=1,locale=de-DE,units=full` applies across commands. JSON and
+//! CSV output keep raw numbers.
+
+use std::sync::OnceLock;
+
+use finance_query::FormatOptions;
+
+static OPTIONS: OnceLock<FormatOptions> = OnceLock::new();
+
+/// Parse and store the display options once at startup.
+///
+/// Invalid specs print a warning to stderr and fall back to the defaults.
+pub fn init(spec: Option<&str>) {
+    let options = spec
+        .map(|spec| {
+            spec.parse().unwrap_or_else(|_| {
+                eprintln!("warning: ignoring invalid number format '{spec}'");
+                FormatOptions::new()
+            })
+        })
+        .unwrap_or_default();
+    let _ = OPTIONS.set(options);
+}
+
+/// The active display options.
+pub fn options() -> FormatOptions {
+    OPTIONS.get().copied().unwrap_or_default()
+}
+
+/// Format a price or other plain number.
+pub fn number(value: f64) -> String {
+    options().format_number(value)
+}
+
+/// Format a signed change, with a leading `+` for gains.
+pub fn signed(value: f64) -> String {
+    let s = number(value);
+    if value >= 0.0 { format!("+{s}") } else { s }
+}
+
+/// Format a fraction (`0.25`) as a percentage.
+pub fn percent(fraction: f64) -> String {
+    options().format_percent(fraction)
+}
+
+/// Format a signed fraction, with a leading `+` for gains.
+pub fn signed_percent(fraction: f64) -> String {
+    let s = percent(fraction);
+    if fraction >= 0.0 { format!("+{s}") } else { s }
+}
+
+/// Format a volume, market cap or other large number.
+pub fn large(value: f64) -> String {
+    options().format_large(value)
+}
@@ -3,3 +3,6 @@ mod render;
 mod state;
 
 pub use state::run_options_tui;
+
+/// Risk-free rate used for Greeks (annualized, continuously compounded)
+pub const RISK_FREE_RATE: f64 = 0.045;
@@ -234,10 +234,13 @@ fn create_options_table<'a>(
         ("Vol", SortField::Volume),
         ("OI", SortField::OpenInterest),
         ("IV", SortField::ImpliedVolatility),
+        ("Delta", SortField::Strike), // No sort for Greeks
+        ("Theta", SortField::Strike),
     ]
     .iter()
     .map(|(name, field)| {
-        let indicator = if *field == sort_field && *name != "Bid" && *name != "Ask" {
+        let sortable = !matches!(*name, "Bid" | "Ask" | "Delta" | "Theta");
+        let indicator = if *field == sort_field && sortable {
             " ▼"
         } else {
             ""
@@ -295,6 +298,12 @@ fn create_options_table<'a>(
                 Style::default().fg(Color::Gray)
             };
 
+            let greeks = underlying.and_then(|spot| c.greeks(spot, super::RISK_FREE_RATE));
+            let (delta, theta) = match greeks {
+                Some(g) => (format!("{:+.2}", g.delta), format!("{:.3}", g.theta)),
+                None => ("-".to_string(), "-".to_string()),
+            };
+
             Row::new(vec![
                 Cell::from(format!("{:.2}", c.strike)).style(strike_style),
                 Cell::from(format_price(c.last_price)),
@@ -304,6 +313,8 @@ fn create_options_table<'a>(
                 Cell::from(format_volume(c.volume)),
                 Cell::from(format_volume(c.open_interest)),
                 Cell::from(format!("{:.1}%", iv * 100.0)).style(iv_style),
+                Cell::from(delta),
+                Cell::from(theta),
             ])
             .style(row_style)
         })
@@ -320,6 +331,8 @@ fn create_options_table<'a>(
             Constraint::Length(8), // Vol
             Constraint::Length(8), // OI
             Constraint::Length(7), // IV
+            Constraint::Length(6), // Delta
+            Constraint::Length(7), // Theta
         ],
     )
     .header(header)
@@ -8,7 +8,7 @@ use finance_query::{Interval, TimeRange};
 pub fn parse_interval(s: &str) -> Result<Interval> {
     s.parse().map_err(|_| {
         CliError::InvalidArgument(format!(
-            "Invalid interval '{s}'. Valid: 1m, 5m, 15m, 30m, 1h, 1d, 1wk, 1mo, 3mo"
+            "Invalid interval '{s}'. Valid: 1m, 2m, 5m, 15m, 30m, 1h, 90m, 4h, 1d, 1wk, 1mo, 3mo"
         ))
     })
 }
@@ -32,6 +32,8 @@ mod tests {
         assert_eq!(parse_interval("30m").unwrap(), Interval::ThirtyMinutes);
         assert_eq!(parse_interval("3mo").unwrap(), Interval::ThreeMonths);
         assert_eq!(parse_interval("1D").unwrap(), Interval::OneDay);
+        assert_eq!(parse_interval("4h").unwrap(), Interval::FourHours);
+        assert_eq!(parse_interval("90m").unwrap(), Interval::NinetyMinutes);
     }
 
     #[test]
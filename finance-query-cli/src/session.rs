@@ -0,0 +1,62 @@
+//! Process-wide Yahoo Finance session shared by every command.
+//!
+//! The first ticker a command builds authenticates one [`FinanceClient`];
+//! later tickers in the same run reuse it. Unless `--no-session-cache` (or
+//! `FQ_NO_SESSION_CACHE`) is set, the session is also saved under the user
+//! cache directory so the next invocation within the hour skips the
+//! handshake entirely.
+
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use finance_query::{FinanceClient, Result};
+use tokio::sync::OnceCell;
+
+static PERSIST: OnceLock<bool> = OnceLock::new();
+static CLIENT: OnceCell<FinanceClient> = OnceCell::const_new();
+
+/// Record whether the session may be read from and saved to disk.
+pub fn init(persist: bool) {
+    let _ = PERSIST.set(persist);
+}
+
+/// Location of the persisted session, when persistence is enabled.
+fn path() -> Option<PathBuf> {
+    if !PERSIST.get().copied().unwrap_or(false) {
+        return None;
+    }
+    dirs::cache_dir().map(|dir| dir.join("fq").join("session.json"))
+}
+
+/// The shared client, authenticating (or restoring a saved session) on first use.
+pub async fn client() -> Result<&'static FinanceClient> {
+    CLIENT
+        .get_or_try_init(|| async {
+            let path = path();
+            let phase = match &path {
+                Some(p) if p.exists() => "session (saved)",
+                _ => "session (handshake)",
+            };
+            let mut builder = FinanceClient::builder();
+            if let Some(lang) = crate::lang::target() {
+                builder = builder.lang(lang);
+            }
+            if let Some(path) = path {
+                builder = builder.session_file(path);
+            }
+            let client = builder.build().await;
+            crate::startup::mark(phase);
+            client
+        })
+        .await
+}
+
+/// Delete the saved session so the next run authenticates from scratch.
+///
+/// Called when Yahoo rejects a request as unauthenticated, which is how a
+/// saved crumb that expired early shows up.
+pub fn forget() {
+    if let Some(path) = path() {
+        let _ = std::fs::remove_file(path);
+    }
+}
@@ -0,0 +1,66 @@
+//! `--profile-startup`: wall-clock breakdown of where a command spends its time.
+//!
+//! Phases are recorded with [`mark`] as the run progresses; each covers the
+//! time since the previous mark. Nothing is recorded unless profiling was
+//! enabled in [`init`], so marks are free in normal runs.
+
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use colored::Colorize;
+
+struct Profile {
+    start: Instant,
+    last: Instant,
+    phases: Vec<(&'static str, Duration)>,
+}
+
+static PROFILE: OnceLock<Mutex<Profile>> = OnceLock::new();
+
+/// Start profiling from `start` (captured as early in `main` as possible).
+pub fn init(enabled: bool, start: Instant) {
+    if enabled {
+        let _ = PROFILE.set(Mutex::new(Profile {
+            start,
+            last: start,
+            phases: Vec::new(),
+        }));
+    }
+}
+
+/// Close the current phase under `phase`.
+pub fn mark(phase: &'static str) {
+    if let Some(profile) = PROFILE.get() {
+        let mut profile = profile.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        let elapsed = now - profile.last;
+        profile.last = now;
+        profile.phases.push((phase, elapsed));
+    }
+}
+
+/// Print the recorded phases and the total to stderr.
+pub fn report(command: &str) {
+    let Some(profile) = PROFILE.get() else {
+        return;
+    };
+    let profile = profile.lock().unwrap_or_else(|e| e.into_inner());
+    let total = profile.last - profile.start;
+    let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+
+    eprintln!();
+    eprintln!("{} fq {}", "Startup profile:".blue().bold(), command);
+    for (phase, elapsed) in &profile.phases {
+        let share = if total.is_zero() {
+            0.0
+        } else {
+            elapsed.as_secs_f64() / total.as_secs_f64() * 100.0
+        };
+        eprintln!("  {:<24} {:>9.1} ms {:>5.1}%", phase, ms(*elapsed), share);
+    }
+    eprintln!(
+        "  {} {:>9.1} ms",
+        format!("{:<24}", "total").bold(),
+        ms(total)
+    );
+}
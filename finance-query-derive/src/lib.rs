@@ -34,6 +34,7 @@
 //! // Automatically generates:
 //! // - to_dataframe(&self) -> PolarsResult<DataFrame>
 //! // - vec_to_dataframe(&[Self]) -> PolarsResult<DataFrame>
+//! // - dataframe_schema() -> Schema
 //!
 //! let quote = Quote {
 //!     symbol: "AAPL".to_string(),
@@ -53,13 +54,35 @@
 //! - **Optional primitives**: `Option<i32>`, `Option<f64>`, etc.
 //! - **FormattedValue**: `Option<FormattedValue<f64>>`, `Option<FormattedValue<i64>>`
 //!   (automatically extracts the `.raw` field)
+//! - **String lists**: `Vec<String>`, `Option<Vec<String>>` (as `List(String)` columns)
+//! - **Dates**: `chrono::NaiveDate` (`Date`), `chrono::DateTime<Utc>` (`Datetime[ms]`,
+//!   holding the UTC instant), and their `Option<>` wrappers
 //!
-//! Complex types like nested structs and vectors are automatically skipped and won't
-//! appear in the generated DataFrame.
+//! Complex types like nested structs and other vectors are automatically skipped and
+//! won't appear in the generated DataFrame, unless marked `#[dataframe(flatten)]`.
+//!
+//! ## Field Attributes
+//!
+//! - `#[dataframe(rename = "name")]`: use `name` as the column name
+//! - `#[dataframe(skip)]`: leave the field out
+//! - `#[dataframe(flatten)]`: inline the columns of a nested `ToDataFrame` struct
+//!   (or `Option` of one) as `<field>_<column>`; `rename` changes the prefix
+//!
+//! ```ignore
+//! #[derive(ToDataFrame)]
+//! struct Holding {
+//!     #[dataframe(rename = "ticker")]
+//!     symbol: String,
+//!     #[dataframe(skip)]
+//!     internal_id: i64,
+//!     #[dataframe(flatten)]
+//!     quote: Option<Quote>,  // columns quote_symbol, quote_price, quote_volume
+//! }
+//! ```
 //!
 //! ## Generated Methods
 //!
-//! For each struct with `#[derive(ToDataFrame)]`, two methods are generated:
+//! For each struct with `#[derive(ToDataFrame)]`, three methods are generated:
 //!
 //! ### `to_dataframe(&self)`
 //!
@@ -78,6 +101,18 @@
 //! let quotes = vec![quote1, quote2, quote3];
 //! let df: DataFrame = Quote::vec_to_dataframe(&quotes)?;
 //! ```
+//!
+//! ### `dataframe_schema()`
+//!
+//! Returns the column names and dtypes of the DataFrames above without
+//! building any data, e.g. to create a database table before fetching:
+//!
+//! ```ignore
+//! let schema: Schema = Quote::dataframe_schema();
+//! for (name, dtype) in schema.iter() {
+//!     println!("{name}: {dtype}");
+//! }
+//! ```
 
 #![warn(missing_docs)]
 #![warn(rustdoc::missing_crate_level_docs)]
@@ -93,7 +128,9 @@ use syn::{
 /// Derive macro for automatic DataFrame conversion.
 ///
 /// Generates a `to_dataframe(&self) -> PolarsResult<DataFrame>` method
-/// that converts all struct fields to DataFrame columns.
+/// that converts all struct fields to DataFrame columns, a matching
+/// `vec_to_dataframe(&[Self])`, and a `dataframe_schema() -> Schema`
+/// describing those columns without constructing data.
 ///
 /// # Supported Types
 ///
@@ -103,7 +140,18 @@ use syn::{
 /// - `Option<FormattedValue<i64>>` → extracts `.raw` as `Option<i64>`
 /// - `i32`, `i64`, `f64`, `bool` → direct columns
 /// - `Option<T>` for primitives → nullable columns
-/// - Nested structs/Vec → skipped (complex types not suitable for flat DataFrame)
+/// - `Vec<String>`, `Option<Vec<String>>` → `List(String)` column
+/// - `NaiveDate` → `Date`, `DateTime<Utc>` → `Datetime[ms]` (and `Option<>` of each)
+/// - Nested structs/other Vecs → skipped (complex types not suitable for flat DataFrame)
+///
+/// # Field Attributes
+///
+/// - `#[dataframe(rename = "name")]` → column named `name` instead of the field name
+/// - `#[dataframe(skip)]` → no column for the field
+/// - `#[dataframe(flatten)]` → for a nested struct `T` or `Option<T>` where `T`
+///   also derives `ToDataFrame`: one column per column of `T`, named
+///   `<field>_<column>` (nulls when the `Option` is `None`). Combine with
+///   `rename` to change the prefix.
 ///
 /// # Example
 ///
@@ -124,7 +172,7 @@ use syn::{
 ///     }
 /// }
 /// ```
-#[proc_macro_derive(ToDataFrame)]
+#[proc_macro_derive(ToDataFrame, attributes(dataframe))]
 pub fn derive_to_dataframe(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
@@ -193,33 +241,175 @@ pub fn derive_to_dataframe(input: TokenStream) -> TokenStream {
         }
     });
 
-    let mut column_names: Vec<String> = Vec::new();
+    // `fields.push(..)` statements building the schema, in column order
+    let mut schema_fields: Vec<TokenStream2> = Vec::new();
+    // Columns before flattened ones: `df!` columns plus list columns
+    let mut static_count = 0usize;
+
+    // Columns passed to `df!`, in order
+    let mut direct_names: Vec<String> = Vec::new();
     let mut column_values: Vec<TokenStream2> = Vec::new();
+    let mut vec_column_values: Vec<TokenStream2> = Vec::new();
+    // `df!` columns cast to their final dtype afterwards (datetimes)
+    let mut cast_names: Vec<String> = Vec::new();
+    let mut cast_dtypes: Vec<TokenStream2> = Vec::new();
+    // List columns inserted at their position after `df!`
+    let mut list_indices: Vec<usize> = Vec::new();
+    let mut list_values: Vec<TokenStream2> = Vec::new();
+    let mut vec_list_values: Vec<TokenStream2> = Vec::new();
+    // Flattened nested structs inserted after list columns
+    let mut flatten_indices: Vec<usize> = Vec::new();
+    let mut flatten_prefixes: Vec<String> = Vec::new();
+    let mut flatten_values: Vec<TokenStream2> = Vec::new();
+    let mut vec_flatten_values: Vec<TokenStream2> = Vec::new();
 
     for field in fields.iter() {
+        let attrs = match FieldAttrs::parse(field) {
+            Ok(attrs) => attrs,
+            Err(e) => return e.to_compile_error().into(),
+        };
+        if attrs.skip {
+            continue;
+        }
         let field_name = field.ident.as_ref().unwrap();
-        let field_name_str = to_snake_case(&field_name.to_string());
+        let field_name_str = attrs
+            .rename
+            .unwrap_or_else(|| to_snake_case(&field_name.to_string()));
         let field_type = &field.ty;
 
-        if let Some(value_expr) = generate_column_value(field_name, field_type, format_param_ident)
-        {
-            column_names.push(field_name_str);
-            column_values.push(value_expr);
+        if attrs.flatten {
+            let (nested_ty, optional) = match get_option_inner(field_type) {
+                Some(inner) => (inner, true),
+                None => (field_type, false),
+            };
+            let nested_ty = with_both_format(nested_ty, format_param_ident);
+            let (row, vec_row) = if optional {
+                let null_row = quote! {
+                    DataFrame::full_null(&::std::sync::Arc::new(<#nested_ty>::dataframe_schema()), 1)
+                };
+                (
+                    quote! {
+                        match &self.#field_name {
+                            Some(v) => v.to_dataframe()?,
+                            None => #null_row,
+                        }
+                    },
+                    quote! {
+                        match &item.#field_name {
+                            Some(v) => v.to_dataframe()?,
+                            None => #null_row,
+                        }
+                    },
+                )
+            } else {
+                (
+                    quote! { self.#field_name.to_dataframe()? },
+                    quote! { item.#field_name.to_dataframe()? },
+                )
+            };
+            flatten_values.push(row);
+            vec_flatten_values.push(quote! {{
+                let schema = ::std::sync::Arc::new(<#nested_ty>::dataframe_schema());
+                let mut nested = DataFrame::full_null(&schema, 0);
+                for item in items {
+                    nested.vstack_mut(&#vec_row)?;
+                }
+                nested
+            }});
+            schema_fields.push(quote! {
+                for (name, dtype) in <#nested_ty>::dataframe_schema().iter() {
+                    fields.push(Field::new(format!("{}_{}", #field_name_str, name).into(), dtype.clone()));
+                }
+            });
+            flatten_indices.push(static_count);
+            flatten_prefixes.push(field_name_str);
+            continue;
         }
-    }
 
-    let mut vec_column_values: Vec<TokenStream2> = Vec::new();
-    for field in fields.iter() {
-        let field_name = field.ident.as_ref().unwrap();
-        let field_type = &field.ty;
+        if let Some(optional) = string_list_field(field_type) {
+            let dtype = quote! { DataType::List(Box::new(DataType::String)) };
+            list_indices.push(static_count);
+            list_values.push(generate_list_column(
+                &field_name_str,
+                quote! { ::std::iter::once(&self.#field_name) },
+                optional,
+                &dtype,
+            ));
+            vec_list_values.push(generate_list_column(
+                &field_name_str,
+                quote! { items.iter().map(|item| &item.#field_name) },
+                optional,
+                &dtype,
+            ));
+            schema_fields.push(quote! { fields.push(Field::new(#field_name_str.into(), #dtype)); });
+            static_count += 1;
+            continue;
+        }
 
-        if let Some(value_expr) =
-            generate_vec_column_value(field_name, field_type, format_param_ident)
-        {
-            vec_column_values.push(value_expr);
+        let (Some(value_expr), Some(vec_value_expr)) = (
+            generate_column_value(field_name, field_type, format_param_ident),
+            generate_vec_column_value(field_name, field_type, format_param_ident),
+        ) else {
+            continue;
+        };
+        let Some(dtype) = generate_column_dtype(field_type, format_param_ident) else {
+            return syn::Error::new_spanned(
+                field_type,
+                "ToDataFrame cannot determine the column dtype of this field",
+            )
+            .to_compile_error()
+            .into();
+        };
+        if is_datetime_field(field_type) {
+            cast_names.push(field_name_str.clone());
+            cast_dtypes.push(dtype.clone());
         }
+        direct_names.push(field_name_str.clone());
+        column_values.push(value_expr);
+        vec_column_values.push(vec_value_expr);
+        schema_fields.push(quote! { fields.push(Field::new(#field_name_str.into(), #dtype)); });
+        static_count += 1;
     }
 
+    let finish = |values: &[TokenStream2], lists: &[TokenStream2], nested: &[TokenStream2]| {
+        if lists.is_empty() && cast_names.is_empty() && nested.is_empty() {
+            return quote! {
+                df![
+                    #( #direct_names => #values ),*
+                ]
+            };
+        }
+        let flatten = if nested.is_empty() {
+            quote! {}
+        } else {
+            quote! {
+                let mut flattened = 0usize;
+                #(
+                    let nested: DataFrame = #nested;
+                    for column in nested.columns() {
+                        let name = format!("{}_{}", #flatten_prefixes, column.name());
+                        df.insert_column(#flatten_indices + flattened, column.clone().with_name(name.into()))?;
+                        flattened += 1;
+                    }
+                )*
+            }
+        };
+        quote! {
+            let mut df = df![
+                #( #direct_names => #values ),*
+            ]?;
+            #( df.insert_column(#list_indices, #lists)?; )*
+            #(
+                let column = df.column(#cast_names)?.cast(&#cast_dtypes)?;
+                df.with_column(column)?;
+            )*
+            #flatten
+            Ok(df)
+        }
+    };
+    let to_dataframe_body = finish(&column_values, &list_values, &flatten_values);
+    let vec_to_dataframe_body = finish(&vec_column_values, &vec_list_values, &vec_flatten_values);
+
     let expanded = quote! {
         #[cfg(feature = "dataframe")]
         impl #impl_ty {
@@ -231,9 +421,7 @@ pub fn derive_to_dataframe(input: TokenStream) -> TokenStream {
             /// This method is auto-generated by the `ToDataFrame` derive macro.
             pub fn to_dataframe(&self) -> ::polars::prelude::PolarsResult<::polars::prelude::DataFrame> {
                 use ::polars::prelude::*;
-                df![
-                    #( #column_names => #column_values ),*
-                ]
+                #to_dataframe_body
             }
 
             /// Converts a slice of structs to a multi-row polars DataFrame.
@@ -244,9 +432,21 @@ pub fn derive_to_dataframe(input: TokenStream) -> TokenStream {
             /// This method is auto-generated by the `ToDataFrame` derive macro.
             pub fn vec_to_dataframe(items: &[Self]) -> ::polars::prelude::PolarsResult<::polars::prelude::DataFrame> {
                 use ::polars::prelude::*;
-                df![
-                    #( #column_names => #vec_column_values ),*
-                ]
+                #vec_to_dataframe_body
+            }
+
+            /// Column names and dtypes of the DataFrames produced by
+            /// `to_dataframe` and `vec_to_dataframe`, in column order.
+            ///
+            /// Builds no data, so it can be used to create or validate a
+            /// destination table before anything is fetched.
+            ///
+            /// This method is auto-generated by the `ToDataFrame` derive macro.
+            pub fn dataframe_schema() -> ::polars::prelude::Schema {
+                use ::polars::prelude::*;
+                let mut fields: Vec<Field> = Vec::new();
+                #( #schema_fields )*
+                Schema::from_iter(fields)
             }
         }
     };
@@ -254,6 +454,72 @@ pub fn derive_to_dataframe(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// Options from `#[dataframe(...)]` on a `ToDataFrame` field.
+#[derive(Default)]
+struct FieldAttrs {
+    rename: Option<String>,
+    skip: bool,
+    flatten: bool,
+}
+
+impl FieldAttrs {
+    fn parse(field: &syn::Field) -> syn::Result<Self> {
+        let mut attrs = Self::default();
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("dataframe"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    let name: syn::LitStr = meta.value()?.parse()?;
+                    attrs.rename = Some(name.value());
+                } else if meta.path.is_ident("skip") {
+                    attrs.skip = true;
+                } else if meta.path.is_ident("flatten") {
+                    attrs.flatten = true;
+                } else {
+                    return Err(meta.error(
+                        "unsupported dataframe attribute; expected `rename = \"...\"`, `skip`, or `flatten`",
+                    ));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(attrs)
+    }
+}
+
+/// Replaces the format type parameter (e.g. `F` in `SummaryDetail<F>`) with
+/// `crate::format::Both`, so flattened types can be named inside the
+/// generated `impl Struct<Both>` block.
+fn with_both_format(ty: &Type, fmt_param: Option<&Ident>) -> Type {
+    let mut ty = ty.clone();
+    if let Some(param) = fmt_param {
+        replace_format_param(&mut ty, param);
+    }
+    ty
+}
+
+fn replace_format_param(ty: &mut Type, param: &Ident) {
+    let Type::Path(type_path) = ty else {
+        return;
+    };
+    if type_path.qself.is_none() && type_path.path.is_ident(param) {
+        *ty = syn::parse_quote! { crate::format::Both };
+        return;
+    }
+    for seg in type_path.path.segments.iter_mut() {
+        if let PathArguments::AngleBracketed(args) = &mut seg.arguments {
+            for arg in args.args.iter_mut() {
+                if let GenericArgument::Type(inner) = arg {
+                    replace_format_param(inner, param);
+                }
+            }
+        }
+    }
+}
+
 /// Converts a field name to snake_case for DataFrame column names.
 fn to_snake_case(s: &str) -> String {
     s.to_string()
@@ -278,7 +544,12 @@ fn generate_column_value(
             let inner_type = get_option_inner_type(type_path)?;
             generate_option_value(field_name, inner_type, fmt_param)
         }
-        Type::Path(type_path) if is_primitive(type_path) => Some(quote! { [self.#field_name] }),
+        Type::Path(type_path) if is_primitive(type_path) || is_naive_date(type_path) => {
+            Some(quote! { [self.#field_name] })
+        }
+        Type::Path(type_path) if is_datetime(type_path) => {
+            Some(quote! { [self.#field_name.timestamp_millis()] })
+        }
         _ => None,
     }
 }
@@ -302,13 +573,70 @@ fn generate_vec_column_value(
             let inner_type = get_option_inner_type(type_path)?;
             generate_vec_option_value(field_name, inner_type, fmt_param)
         }
-        Type::Path(type_path) if is_primitive(type_path) => {
+        Type::Path(type_path) if is_primitive(type_path) || is_naive_date(type_path) => {
             Some(quote! { items.iter().map(|item| item.#field_name).collect::<Vec<_>>() })
         }
+        Type::Path(type_path) if is_datetime(type_path) => Some(
+            quote! { items.iter().map(|item| item.#field_name.timestamp_millis()).collect::<Vec<_>>() },
+        ),
         _ => None,
     }
 }
 
+/// Generates the polars `DataType` of a DataFrame column based on field type.
+///
+/// Covers the same types as [`generate_column_value`]; `FormattedValue<T>`
+/// and `F::Value<T>` columns hold the raw `T`.
+fn generate_column_dtype(field_type: &Type, fmt_param: Option<&Ident>) -> Option<TokenStream2> {
+    let Type::Path(type_path) = field_type else {
+        return None;
+    };
+    if is_formatted_value(type_path) {
+        return value_dtype(type_path);
+    }
+    if is_option(type_path) {
+        let Type::Path(inner) = get_option_inner_type(type_path)? else {
+            return None;
+        };
+        if is_formatted_value(inner) || is_format_assoc_value(inner, fmt_param) {
+            return value_dtype(inner);
+        }
+        return scalar_dtype(inner);
+    }
+    scalar_dtype(type_path)
+}
+
+/// Dtype of the raw `T` inside `FormattedValue<T>` or `F::Value<T>`.
+fn value_dtype(type_path: &TypePath) -> Option<TokenStream2> {
+    let PathArguments::AngleBracketed(args) = &type_path.path.segments.last()?.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(Type::Path(inner)) => scalar_dtype(inner),
+        _ => None,
+    }
+}
+
+/// Dtype of a `String`, primitive, or chrono date column.
+///
+/// `DateTime<Tz>` becomes a naive `Datetime` holding the UTC instant.
+fn scalar_dtype(type_path: &TypePath) -> Option<TokenStream2> {
+    let name = type_path.path.segments.last()?.ident.to_string();
+    let dtype = match name.as_str() {
+        "NaiveDate" => quote! { DataType::Date },
+        "DateTime" => quote! { DataType::Datetime(TimeUnit::Milliseconds, None) },
+        "String" => quote! { DataType::String },
+        "i32" => quote! { DataType::Int32 },
+        "i64" => quote! { DataType::Int64 },
+        "u32" => quote! { DataType::UInt32 },
+        "u64" => quote! { DataType::UInt64 },
+        "f64" => quote! { DataType::Float64 },
+        "bool" => quote! { DataType::Boolean },
+        _ => return None,
+    };
+    Some(dtype)
+}
+
 /// Generates value expression for Option<T> fields when iterating over a Vec.
 fn generate_vec_option_value(
     field_name: &syn::Ident,
@@ -327,9 +655,12 @@ fn generate_vec_option_value(
                 quote! { items.iter().map(|item| item.#field_name.as_ref().and_then(|v| v.raw)).collect::<Vec<_>>() },
             )
         }
-        Type::Path(type_path) if is_primitive(type_path) => {
+        Type::Path(type_path) if is_primitive(type_path) || is_naive_date(type_path) => {
             Some(quote! { items.iter().map(|item| item.#field_name).collect::<Vec<_>>() })
         }
+        Type::Path(type_path) if is_datetime(type_path) => Some(
+            quote! { items.iter().map(|item| item.#field_name.as_ref().map(|d| d.timestamp_millis())).collect::<Vec<_>>() },
+        ),
         _ => None,
     }
 }
@@ -350,11 +681,101 @@ fn generate_option_value(
         {
             Some(quote! { [self.#field_name.as_ref().and_then(|v| v.raw)] })
         }
-        Type::Path(type_path) if is_primitive(type_path) => Some(quote! { [self.#field_name] }),
+        Type::Path(type_path) if is_primitive(type_path) || is_naive_date(type_path) => {
+            Some(quote! { [self.#field_name] })
+        }
+        Type::Path(type_path) if is_datetime(type_path) => {
+            Some(quote! { [self.#field_name.as_ref().map(|d| d.timestamp_millis())] })
+        }
+        _ => None,
+    }
+}
+
+/// Generates a `Vec<String>` / `Option<Vec<String>>` list column from an
+/// iterator of field references.
+///
+/// `df!` cannot build list columns without inferring the inner dtype from
+/// the data, so these are collected into a `ListChunked` and cast to
+/// `List(String)`, which also types empty and all-null columns.
+fn generate_list_column(
+    column_name: &str,
+    values: TokenStream2,
+    optional: bool,
+    dtype: &TokenStream2,
+) -> TokenStream2 {
+    let to_series = if optional {
+        quote! { |v| v.as_ref().map(|v| Series::new(PlSmallStr::EMPTY, v.as_slice())) }
+    } else {
+        quote! { |v| Some(Series::new(PlSmallStr::EMPTY, v.as_slice())) }
+    };
+    quote! {
+        #values
+            .map(#to_series)
+            .collect::<ListChunked>()
+            .with_name(#column_name.into())
+            .into_series()
+            .cast(&#dtype)?
+    }
+}
+
+/// Detects `Vec<String>` (`Some(false)`) and `Option<Vec<String>>` (`Some(true)`).
+fn string_list_field(ty: &Type) -> Option<bool> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    if is_string_vec(type_path) {
+        return Some(false);
+    }
+    match get_option_inner(ty)? {
+        Type::Path(inner) if is_string_vec(inner) => Some(true),
         _ => None,
     }
 }
 
+/// Checks if a type is `DateTime<Tz>` or `Option<DateTime<Tz>>`.
+///
+/// These columns are built from epoch milliseconds and cast to `Datetime`.
+fn is_datetime_field(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) if is_datetime(type_path) => true,
+        _ => matches!(get_option_inner(ty), Some(Type::Path(inner)) if is_datetime(inner)),
+    }
+}
+
+/// Checks if a type path is `Vec<String>`.
+fn is_string_vec(type_path: &TypePath) -> bool {
+    let Some(seg) = type_path.path.segments.last() else {
+        return false;
+    };
+    if seg.ident != "Vec" {
+        return false;
+    }
+    let PathArguments::AngleBracketed(args) = &seg.arguments else {
+        return false;
+    };
+    matches!(args.args.first(), Some(GenericArgument::Type(Type::Path(inner))) if is_string(inner))
+}
+
+/// Checks if a type path is `chrono::NaiveDate`.
+fn is_naive_date(type_path: &TypePath) -> bool {
+    type_path
+        .path
+        .segments
+        .last()
+        .map(|seg| seg.ident == "NaiveDate")
+        .unwrap_or(false)
+}
+
+/// Checks if a type path is `chrono::DateTime<Tz>`.
+fn is_datetime(type_path: &TypePath) -> bool {
+    type_path
+        .path
+        .segments
+        .last()
+        .map(|seg| seg.ident == "DateTime")
+        .unwrap_or(false)
+}
+
 /// Checks if a type path is `String`.
 fn is_string(type_path: &TypePath) -> bool {
     type_path
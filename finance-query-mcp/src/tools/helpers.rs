@@ -37,10 +37,13 @@ pub fn frequency_to_gql(frequency: Frequency) -> &'static str {
 pub fn interval_to_gql(s: &str) -> &'static str {
     match parse_interval(s) {
         Interval::OneMinute => "ONE_MINUTE",
+        Interval::TwoMinutes => "TWO_MINUTES",
         Interval::FiveMinutes => "FIVE_MINUTES",
         Interval::FifteenMinutes => "FIFTEEN_MINUTES",
         Interval::ThirtyMinutes => "THIRTY_MINUTES",
         Interval::OneHour => "ONE_HOUR",
+        Interval::NinetyMinutes => "NINETY_MINUTES",
+        Interval::FourHours => "FOUR_HOURS",
         Interval::OneDay => "ONE_DAY",
         Interval::OneWeek => "ONE_WEEK",
         Interval::OneMonth => "ONE_MONTH",
@@ -92,7 +92,7 @@ pub struct CalendarParams {
 pub struct ChartParams {
     /// One or more comma-separated ticker symbols (e.g., "AAPL" or "AAPL,MSFT,GOOG")
     pub symbols: String,
-    /// Candle interval: 1m|5m|15m|30m|1h|1d|1wk|1mo|3mo (default: 1d)
+    /// Candle interval: 1m|2m|5m|15m|30m|1h|90m|4h|1d|1wk|1mo|3mo (default: 1d)
     pub interval: Option<String>,
     /// Time range: 1d|5d|1mo|3mo|6mo|1y|2y|5y|10y|ytd|max (default: 1mo). Ignored when `start` is set.
     pub range: Option<String>,
@@ -418,7 +418,7 @@ pub struct LookupParams {
 pub struct BatchSymbolsParams {
     /// Comma-separated list of ticker symbols (e.g., "AAPL,MSFT,GOOG")
     pub symbols: String,
-    /// Candle interval: 1m|5m|15m|30m|1h|1d|1wk|1mo|3mo (default: 1d)
+    /// Candle interval: 1m|2m|5m|15m|30m|1h|90m|1d|1wk|1mo|3mo (default: 1d)
     pub interval: Option<String>,
     /// Time range: 1d|5d|1mo|3mo|6mo|1y|2y|5y|10y|ytd|max (default: 1mo)
     pub range: Option<String>,
@@ -1,11 +1,13 @@
-//! Redis caching module for finance-query-server.
+//! Response caching module for finance-query-server.
 //!
-//! Provides time-based caching with market-hours awareness.
+//! Provides time-based caching with market-hours awareness, backed by Redis
+//! when configured and by an in-process store otherwise.
 //! Cache keys are prefixed with "v2:" to avoid conflicts with v1 Python server.
 
+use moka::Expiry;
 use serde::{Serialize, de::DeserializeOwned};
-#[cfg(feature = "redis-cache")]
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 #[cfg(feature = "redis-cache")]
 use redis::aio::ConnectionManager;
@@ -101,11 +103,82 @@ pub mod ttl {
     pub const METADATA: CacheTtl = CacheTtl::new(86400, 86400);
 }
 
+/// Default number of entries kept by the in-process cache
+const DEFAULT_MEMORY_CAPACITY: usize = 10_000;
+
+/// A cached JSON value and how long it lives
+#[derive(Clone)]
+struct MemoryEntry {
+    ttl: Duration,
+    data: Arc<str>,
+}
+
+/// Expires each entry after its own TTL, restarting it when the key is overwritten
+struct EntryTtl;
+
+impl Expiry<String, MemoryEntry> for EntryTtl {
+    fn expire_after_create(
+        &self,
+        _key: &String,
+        entry: &MemoryEntry,
+        _created_at: Instant,
+    ) -> Option<Duration> {
+        Some(entry.ttl)
+    }
+
+    fn expire_after_update(
+        &self,
+        _key: &String,
+        entry: &MemoryEntry,
+        _updated_at: Instant,
+        _remaining: Option<Duration>,
+    ) -> Option<Duration> {
+        Some(entry.ttl)
+    }
+}
+
+/// In-process cache used when Redis isn't available.
+///
+/// Values are kept as serialized JSON, like in Redis, so both backends accept
+/// the same types. Expired entries are never returned and are evicted in the
+/// background; when full, moka's TinyLFU policy picks what to drop.
+struct MemoryStore {
+    entries: moka::future::Cache<String, MemoryEntry>,
+}
+
+impl MemoryStore {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: moka::future::Cache::builder()
+                .max_capacity(capacity as u64)
+                .expire_after(EntryTtl)
+                .build(),
+        }
+    }
+
+    async fn get(&self, key: &str) -> Option<Arc<str>> {
+        self.entries.get(key).await.map(|entry| entry.data)
+    }
+
+    async fn set(&self, key: &str, data: String, ttl: Duration) {
+        let entry = MemoryEntry {
+            ttl,
+            data: data.into(),
+        };
+        self.entries.insert(key.to_string(), entry).await;
+    }
+}
+
 /// Cache client wrapper
+///
+/// Uses Redis when connected, otherwise an in-process memory cache (sized by
+/// `CACHE_MEMORY_CAPACITY`, `0` disables it) so a single instance still
+/// serves repeated requests without refetching upstream.
 #[derive(Clone)]
 pub struct Cache {
     #[cfg(feature = "redis-cache")]
     conn: Option<Arc<ConnectionManager>>,
+    memory: Option<Arc<MemoryStore>>,
 }
 
 impl Cache {
@@ -120,45 +193,76 @@ impl Cache {
                         Some(Arc::new(manager))
                     }
                     Err(e) => {
-                        tracing::warn!("Failed to connect to Redis: {}. Caching disabled.", e);
+                        tracing::warn!(
+                            "Failed to connect to Redis: {}. Falling back to in-memory cache.",
+                            e
+                        );
                         None
                     }
                 },
                 Err(e) => {
-                    tracing::warn!("Invalid Redis URL: {}. Caching disabled.", e);
+                    tracing::warn!("Invalid Redis URL: {}. Falling back to in-memory cache.", e);
                     None
                 }
             }
         } else {
-            tracing::info!("No REDIS_URL configured. Caching disabled.");
+            tracing::info!("No REDIS_URL configured. Using in-memory cache.");
             None
         };
 
-        // The uncached fallback is otherwise invisible in production; expose
+        // The in-memory fallback is otherwise invisible in production; expose
         // it as a gauge so monitoring can alert on it.
         crate::metrics::CACHE_BACKEND_CONNECTED.set(if conn.is_some() { 1.0 } else { 0.0 });
 
-        Self { conn }
+        let memory = match conn {
+            Some(_) => None,
+            None => Self::memory_from_env(),
+        };
+        Self { conn, memory }
     }
 
     #[cfg(not(feature = "redis-cache"))]
     pub async fn new(_redis_url: Option<&str>) -> Self {
-        tracing::info!("Redis cache feature not enabled. Caching disabled.");
+        tracing::info!("Redis cache feature not enabled. Using in-memory cache.");
         crate::metrics::CACHE_BACKEND_CONNECTED.set(0.0);
-        Self {}
+        Self {
+            memory: Self::memory_from_env(),
+        }
+    }
+
+    /// Create a cache backed only by an in-process store of `capacity` entries
+    pub fn in_memory(capacity: usize) -> Self {
+        Self {
+            #[cfg(feature = "redis-cache")]
+            conn: None,
+            memory: (capacity > 0).then(|| Arc::new(MemoryStore::new(capacity))),
+        }
+    }
+
+    fn memory_from_env() -> Option<Arc<MemoryStore>> {
+        let capacity = std::env::var("CACHE_MEMORY_CAPACITY")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_MEMORY_CAPACITY);
+        if capacity == 0 {
+            tracing::info!("In-memory cache disabled (CACHE_MEMORY_CAPACITY=0)");
+            return None;
+        }
+        tracing::info!("In-memory cache enabled: {} entries", capacity);
+        Some(Arc::new(MemoryStore::new(capacity)))
     }
 
     /// Check if caching is enabled
     #[allow(dead_code)]
     #[cfg(feature = "redis-cache")]
     pub fn is_enabled(&self) -> bool {
-        self.conn.is_some()
+        self.conn.is_some() || self.memory.is_some()
     }
 
     #[allow(dead_code)]
     #[cfg(not(feature = "redis-cache"))]
     pub fn is_enabled(&self) -> bool {
-        false
+        self.memory.is_some()
     }
 
     /// Build a cache key with the v2 prefix
@@ -171,10 +275,36 @@ impl Cache {
     }
 
     /// Get a value from cache
-    #[cfg(feature = "redis-cache")]
     pub async fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
-        let conn = self.conn.as_ref()?;
-        let mut conn = conn.as_ref().clone();
+        #[cfg(feature = "redis-cache")]
+        if let Some(conn) = self.conn.as_ref() {
+            return Self::redis_get(conn, key).await;
+        }
+
+        let memory = self.memory.as_ref()?;
+        match memory.get(key).await {
+            Some(data) => match serde_json::from_str(&data) {
+                Ok(value) => {
+                    tracing::debug!(key = %key, "Memory cache HIT");
+                    crate::metrics::CACHE_HITS.inc();
+                    Some(value)
+                }
+                Err(e) => {
+                    tracing::warn!(key = %key, error = %e, "Cache deserialize error");
+                    None
+                }
+            },
+            None => {
+                tracing::debug!(key = %key, "Memory cache MISS");
+                crate::metrics::CACHE_MISSES.inc();
+                None
+            }
+        }
+    }
+
+    #[cfg(feature = "redis-cache")]
+    async fn redis_get<T: DeserializeOwned>(conn: &ConnectionManager, key: &str) -> Option<T> {
+        let mut conn = conn.clone();
 
         let timer = crate::metrics::CacheTimer::new("get");
 
@@ -213,19 +343,35 @@ impl Cache {
         }
     }
 
-    #[cfg(not(feature = "redis-cache"))]
-    pub async fn get<T: DeserializeOwned>(&self, _key: &str) -> Option<T> {
-        None
-    }
-
     /// Set a value in cache with TTL
-    #[cfg(feature = "redis-cache")]
     pub async fn set<T: Serialize>(&self, key: &str, value: &T, ttl_seconds: u64) {
-        let Some(conn) = self.conn.as_ref() else {
+        #[cfg(feature = "redis-cache")]
+        if let Some(conn) = self.conn.as_ref() {
+            return Self::redis_set(conn, key, value, ttl_seconds).await;
+        }
+
+        let Some(memory) = self.memory.as_ref() else {
             return;
         };
+        match serde_json::to_string(value) {
+            Ok(data) => {
+                memory
+                    .set(key, data, Duration::from_secs(ttl_seconds))
+                    .await;
+                tracing::debug!(key = %key, ttl = ttl_seconds, "Memory cache SET");
+            }
+            Err(e) => tracing::warn!(key = %key, error = %e, "Cache serialize error"),
+        }
+    }
 
-        let mut conn = conn.as_ref().clone();
+    #[cfg(feature = "redis-cache")]
+    async fn redis_set<T: Serialize>(
+        conn: &ConnectionManager,
+        key: &str,
+        value: &T,
+        ttl_seconds: u64,
+    ) {
+        let mut conn = conn.clone();
         let timer = crate::metrics::CacheTimer::new("set");
 
         let data = match serde_json::to_string(value) {
@@ -255,9 +401,6 @@ impl Cache {
         timer.observe();
     }
 
-    #[cfg(not(feature = "redis-cache"))]
-    pub async fn set<T: Serialize>(&self, _key: &str, _value: &T, _ttl_seconds: u64) {}
-
     /// Get or fetch: returns cached value or fetches and caches it
     pub async fn get_or_fetch<T, F, Fut>(
         &self,
@@ -329,6 +472,42 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_memory_cache_roundtrip_and_expiry() {
+        let cache = Cache::in_memory(10);
+        assert!(cache.is_enabled());
+        assert_eq!(cache.get::<Vec<u32>>("v2:a").await, None);
+
+        cache.set("v2:a", &vec![1u32, 2, 3], 60).await;
+        assert_eq!(cache.get::<Vec<u32>>("v2:a").await, Some(vec![1, 2, 3]));
+
+        cache.set("v2:b", &"gone", 0).await;
+        assert_eq!(cache.get::<String>("v2:b").await, None);
+
+        assert!(!Cache::in_memory(0).is_enabled());
+    }
+
+    #[tokio::test]
+    async fn test_memory_store_per_entry_ttl_and_capacity() {
+        let store = MemoryStore::new(2);
+        store.set("a", "1".into(), Duration::from_secs(60)).await;
+        store.set("b", "2".into(), Duration::ZERO).await;
+        assert_eq!(store.get("a").await.as_deref(), Some("1"));
+        assert_eq!(store.get("b").await, None);
+
+        // Overwriting a key restarts its TTL
+        store.set("b", "3".into(), Duration::from_secs(60)).await;
+        assert_eq!(store.get("b").await.as_deref(), Some("3"));
+
+        for i in 0..10 {
+            store
+                .set(&i.to_string(), "x".into(), Duration::from_secs(60))
+                .await;
+        }
+        store.entries.run_pending_tasks().await;
+        assert!(store.entries.entry_count() <= 2);
+    }
+
     #[test]
     fn test_ttl_market_hours() {
         let ttl = CacheTtl::new(10, 60);
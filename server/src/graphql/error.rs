@@ -46,6 +46,7 @@ fn finance_error_to_gql(err: &FinanceError) -> Error {
         FinanceError::Timeout { .. } => ("TIMEOUT", 408),
         FinanceError::AuthenticationFailed { .. } => ("UNAUTHORIZED", 401),
         FinanceError::ServerError { status, .. } => ("SERVER_ERROR", *status),
+        FinanceError::ResponseTooLarge { .. } => ("UPSTREAM_TOO_LARGE", 502),
         _ => ("INTERNAL_ERROR", 500),
     };
 
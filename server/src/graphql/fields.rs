@@ -201,6 +201,10 @@ pub const GQL_QUOTE_VALID_FIELDS: &[&str] = &[
     "navPrice",
     "totalAssets",
     "yieldValue",
+    // Bond-specific
+    "couponRate",
+    "maturityDate",
+    "yieldToMaturity",
     // Governance
     "auditRisk",
     "boardRisk",
@@ -255,6 +259,17 @@ pub const GQL_QUOTE_VALID_FIELDS: &[&str] = &[
 /// Valid GraphQL field names for `GqlChart` (top-level only).
 pub const GQL_CHART_VALID_FIELDS: &[&str] = &["symbol", "meta", "candles", "interval", "range"];
 
+/// Valid GraphQL field names for `GqlChartEvent` (sub-fields of `events`).
+pub const GQL_CHART_EVENT_VALID_FIELDS: &[&str] = &[
+    "timestamp",
+    "candleIndex",
+    "type",
+    "amount",
+    "numerator",
+    "denominator",
+    "ratio",
+];
+
 /// Valid GraphQL field names for `GqlChartMeta` (sub-fields of `meta`).
 pub const GQL_CHART_META_VALID_FIELDS: &[&str] = &[
     "symbol",
@@ -374,6 +389,78 @@ pub const CALENDAR_EVENT_UNION_SELECTION: &str = "{ \
     ... on GqlUnknownEvent { raw } \
 }";
 
+/// Valid fields for `GqlEarningsCalendarEntry`.
+pub const GQL_EARNINGS_CALENDAR_VALID_FIELDS: &[&str] = &[
+    "symbol",
+    "name",
+    "eventName",
+    "reportDate",
+    "timestamp",
+    "callTime",
+    "fiscalDateEnding",
+    "estimate",
+    "epsActual",
+    "surprisePercent",
+    "marketCap",
+    "currency",
+];
+
+/// Valid fields for `GqlIpoCalendarEntry`.
+pub const GQL_IPO_CALENDAR_VALID_FIELDS: &[&str] = &[
+    "symbol",
+    "name",
+    "exchange",
+    "filingDate",
+    "ipoDate",
+    "amendedDate",
+    "priceFrom",
+    "priceTo",
+    "offerPrice",
+    "currency",
+    "shares",
+    "dealType",
+];
+
+/// Valid fields for `GqlSplitCalendarEntry`.
+pub const GQL_SPLITS_CALENDAR_VALID_FIELDS: &[&str] = &[
+    "symbol",
+    "name",
+    "splitDate",
+    "timestamp",
+    "optionable",
+    "oldShares",
+    "newShares",
+];
+
+/// `keyDates` (`GqlKeyDates`) — `epsEstimate`/`revenueEstimate` are estimate
+/// ranges (composite).
+pub const GQL_KEY_DATES_VALID_FIELDS: &[&str] = &[
+    "earningsDates",
+    "earningsDateIsEstimate",
+    "epsEstimate",
+    "revenueEstimate",
+    "exDividendDate",
+    "dividendDate",
+];
+pub const KEY_DATES_COMPOSITE_FIELDS: &[(&str, &str)] = &[
+    ("epsEstimate", "{ low average high }"),
+    ("revenueEstimate", "{ low average high }"),
+];
+
+/// Valid fields for `GqlEconomicEvent`.
+pub const GQL_ECONOMIC_CALENDAR_VALID_FIELDS: &[&str] = &[
+    "event",
+    "category",
+    "country",
+    "releaseDate",
+    "timestamp",
+    "period",
+    "actual",
+    "consensus",
+    "prior",
+    "originallyReported",
+];
+
 // ── Market-wide (indices reuse GQL_QUOTE_VALID_FIELDS) ──────────────────────
 
 /// Valid fields for `GqlMarketSummaryQuote`.
@@ -602,6 +689,40 @@ pub const GQL_EARNINGS_HISTORY_VALID_FIELDS: &[&str] = &["defaultMethodology", "
 pub const GQL_EARNINGS_HISTORY_COMPOSITE: &str =
     "{ maxAge quarter period currency epsActual epsEstimate epsDifference surprisePercent }";
 
+/// `esgScores` (`GqlEsgScores`) — the `peer*Performance` fields are
+/// `GqlPeerPerformance` (composite).
+pub const GQL_ESG_SCORES_VALID_FIELDS: &[&str] = &[
+    "maxAge",
+    "totalEsg",
+    "environmentScore",
+    "socialScore",
+    "governanceScore",
+    "percentile",
+    "ratingYear",
+    "ratingMonth",
+    "highestControversy",
+    "relatedControversy",
+    "esgPerformance",
+    "peerGroup",
+    "peerCount",
+    "peerEsgScorePerformance",
+    "peerEnvironmentPerformance",
+    "peerSocialPerformance",
+    "peerGovernancePerformance",
+    "peerHighestControversyPerformance",
+];
+pub const GQL_PEER_PERFORMANCE_COMPOSITE: &str = "{ min avg max }";
+pub const ESG_SCORES_COMPOSITE_FIELDS: &[(&str, &str)] = &[
+    ("peerEsgScorePerformance", GQL_PEER_PERFORMANCE_COMPOSITE),
+    ("peerEnvironmentPerformance", GQL_PEER_PERFORMANCE_COMPOSITE),
+    ("peerSocialPerformance", GQL_PEER_PERFORMANCE_COMPOSITE),
+    ("peerGovernancePerformance", GQL_PEER_PERFORMANCE_COMPOSITE),
+    (
+        "peerHighestControversyPerformance",
+        GQL_PEER_PERFORMANCE_COMPOSITE,
+    ),
+];
+
 // ── Technical indicators ─────────────────────────────────────────────────────
 
 /// Valid fields for `GqlIndicatorsSummary` — camelCase GraphQL field names
@@ -662,6 +783,7 @@ pub const GQL_INDICATORS_VALID_FIELDS: &[&str] = &[
     "accumulationDistribution",
     "vwap",
     "balanceOfPower",
+    "custom",
 ];
 
 /// Indicator fields whose GraphQL type is a composite object (not a scalar),
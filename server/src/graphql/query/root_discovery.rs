@@ -95,14 +95,34 @@ impl RootDiscoveryQuery {
         #[graphql(name = "type")] r#type: GqlScreener,
         #[graphql(default = 25)] count: u32,
         #[graphql(default)] format: GqlValueFormat,
+        #[graphql(
+            desc = "Country code (e.g. \"JP\", \"GB\") to screen non-US listings; only DAY_GAINERS, DAY_LOSERS, and MOST_ACTIVES"
+        )]
+        country: Option<String>,
     ) -> Result<GqlScreenerResults> {
         let state = ctx.data::<AppState>()?;
         let lib_screener: finance_query::Screener = r#type.into();
+        let region = match country.as_deref() {
+            Some(c) => {
+                let region: finance_query::Region = c.parse().map_err(|_| {
+                    async_graphql::Error::new(format!("Invalid country code: '{c}'"))
+                })?;
+                if lib_screener.regional(region).is_none() {
+                    return Err(async_graphql::Error::new(format!(
+                        "Screener {} has no regional version; use DAY_GAINERS, DAY_LOSERS, or MOST_ACTIVES",
+                        r#type.as_scr_id().to_uppercase()
+                    )));
+                }
+                Some(region)
+            }
+            None => None,
+        };
         let json = crate::services::market::get_screener(
             &state.cache,
             lib_screener,
             r#type.as_scr_id(),
             count,
+            region,
         )
         .await
         .map_err(to_gql_error)?;
@@ -7,7 +7,10 @@ use crate::AppState;
 use crate::graphql::error::{exec_gql, from_gql_json, to_gql_error};
 use crate::graphql::pagination::{self, Page};
 use crate::graphql::types::{
-    calendar::GqlCalendarEvent,
+    calendar::{
+        GqlCalendarEvent, GqlEarningsCalendarEntry, GqlEconomicEvent, GqlIpoCalendarEntry,
+        GqlSplitCalendarEntry,
+    },
     crypto::GqlCoinQuote,
     edgar::{GqlEdgarCik, GqlEdgarSearchHit, GqlEdgarSearchResults},
     enums::GqlTimeRange,
@@ -125,6 +128,90 @@ impl RootMetadataQuery {
         Ok(events.into_iter().map(GqlCalendarEvent::from).collect())
     }
 
+    /// US companies reporting earnings between two dates (inclusive,
+    /// `YYYY-MM-DD`), with consensus EPS estimates.
+    async fn earnings_calendar(
+        &self,
+        ctx: &Context<'_>,
+        start_date: String,
+        end_date: String,
+    ) -> Result<Vec<GqlEarningsCalendarEntry>> {
+        let state = ctx.data::<AppState>()?;
+        let (start, end) = parse_date_window(&start_date, &end_date)?;
+        exec_gql(crate::services::calendar::get_earnings_calendar(
+            &state.cache,
+            start,
+            end,
+        ))
+        .await
+    }
+
+    /// IPOs scheduled between two dates (inclusive, `YYYY-MM-DD`), with
+    /// price ranges and deal status.
+    async fn ipo_calendar(
+        &self,
+        ctx: &Context<'_>,
+        start_date: String,
+        end_date: String,
+    ) -> Result<Vec<GqlIpoCalendarEntry>> {
+        let state = ctx.data::<AppState>()?;
+        let (start, end) = parse_date_window(&start_date, &end_date)?;
+        exec_gql(crate::services::calendar::get_ipo_calendar(
+            &state.cache,
+            start,
+            end,
+        ))
+        .await
+    }
+
+    /// Stock splits taking effect between two dates (inclusive, `YYYY-MM-DD`).
+    async fn splits_calendar(
+        &self,
+        ctx: &Context<'_>,
+        start_date: String,
+        end_date: String,
+    ) -> Result<Vec<GqlSplitCalendarEntry>> {
+        let state = ctx.data::<AppState>()?;
+        let (start, end) = parse_date_window(&start_date, &end_date)?;
+        exec_gql(crate::services::calendar::get_splits_calendar(
+            &state.cache,
+            start,
+            end,
+        ))
+        .await
+    }
+
+    /// Macroeconomic releases (CPI, central-bank decisions, payrolls, GDP, ...)
+    /// between two dates (inclusive, `YYYY-MM-DD`), with consensus and actual
+    /// figures.
+    async fn economic_calendar(
+        &self,
+        ctx: &Context<'_>,
+        start_date: String,
+        end_date: String,
+        #[graphql(desc = "Country code to keep (e.g. \"US\", \"DE\"); omitted = every country")]
+        region: Option<String>,
+    ) -> Result<Vec<GqlEconomicEvent>> {
+        let state = ctx.data::<AppState>()?;
+        let (start, end) = parse_date_window(&start_date, &end_date)?;
+        let region = match region.as_deref() {
+            Some(code) => Some(code.parse::<finance_query::Region>().map_err(|_| {
+                async_graphql::Error::new(format!("Unknown region '{code}'")).extend_with(|_, e| {
+                    e.set("code", "BAD_REQUEST");
+                    e.set("status", 400);
+                })
+            })?),
+            None => None,
+        };
+        exec_gql(crate::services::calendar::get_economic_calendar(
+            &state.cache,
+            region,
+            start,
+            end,
+        ))
+        .await
+    }
+
     /// A FRED economic data series (e.g. "FEDFUNDS", "CPIAUCSL"). Requires `FRED_API_KEY`.
     async fn fred_series(&self, ctx: &Context<'_>, id: String) -> Result<GqlMacroSeries> {
         let state = ctx.data::<AppState>()?;
@@ -218,3 +305,29 @@ impl RootMetadataQuery {
         })
     }
 }
+
+/// Parse an inclusive `YYYY-MM-DD` window for the market-wide calendars,
+/// rejecting malformed dates and reversed windows as BAD_REQUEST.
+fn parse_date_window(
+    start_date: &str,
+    end_date: &str,
+) -> Result<(chrono::NaiveDate, chrono::NaiveDate)> {
+    let bad_request = |msg: String| {
+        async_graphql::Error::new(msg).extend_with(|_, e| {
+            e.set("code", "BAD_REQUEST");
+            e.set("status", 400);
+        })
+    };
+    let parse = |name: &str, value: &str| {
+        chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map_err(|_| bad_request(format!("Invalid {name} '{value}': expected YYYY-MM-DD")))
+    };
+    let start = parse("startDate", start_date)?;
+    let end = parse("endDate", end_date)?;
+    if end < start {
+        return Err(bad_request(format!(
+            "endDate {end} is before startDate {start}"
+        )));
+    }
+    Ok((start, end))
+}
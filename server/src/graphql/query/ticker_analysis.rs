@@ -1,6 +1,6 @@
 //! Per-symbol analyst and disclosure fields: recommendation trend, grading
-//! history, earnings estimates/history, SEC EDGAR facts/submissions, and
-//! earnings call transcripts.
+//! history, earnings estimates/history, ESG scores, SEC EDGAR
+//! facts/submissions, and earnings call transcripts.
 
 use async_graphql::{Context, Object, Result};
 
@@ -9,7 +9,8 @@ use crate::AppState;
 use crate::graphql::error::{exec_gql, from_gql_json, to_gql_error};
 use crate::graphql::types::{
     analysis::{
-        GqlEarningsHistory, GqlEarningsTrend, GqlRecommendationTrend, GqlUpgradeDowngradeHistory,
+        GqlEarningsHistory, GqlEarningsTrend, GqlEsgScores, GqlRecommendationTrend,
+        GqlUpgradeDowngradeHistory,
     },
     edgar::{GqlEdgarFiling, GqlEdgarSubmissions, GqlFactConcept, GqlFactDataPoint},
     transcript::GqlTranscriptWithMeta,
@@ -57,6 +58,17 @@ impl TickerAnalysisQuery {
         .await
     }
 
+    /// Sustainalytics ESG risk scores (lower is better); null when the symbol
+    /// has no ESG coverage.
+    async fn esg_scores(&self, ctx: &Context<'_>) -> Result<Option<GqlEsgScores>> {
+        let state = ctx.data::<AppState>()?;
+        exec_gql(crate::services::analysis::get_esg_scores(
+            &state.cache,
+            &self.symbol,
+        ))
+        .await
+    }
+
     /// SEC EDGAR company facts (XBRL) for this symbol.
     async fn edgar_facts(
         &self,
@@ -1,11 +1,12 @@
 //! Per-symbol corporate-events and quant fields: dividends, splits, capital
-//! gains, technical indicators, and risk analytics.
+//! gains, upcoming key dates, technical indicators, and risk analytics.
 
 use async_graphql::{Context, Object, Result};
 
 use crate::AppState;
 use crate::graphql::error::exec_gql;
 use crate::graphql::types::{
+    calendar::GqlKeyDates,
     enums::{GqlInterval, GqlTimeRange},
     events::{GqlCapitalGain, GqlDividends, GqlSplit},
     indicators::GqlIndicatorsSummary,
@@ -63,13 +64,43 @@ impl TickerEventsQuery {
         .await
     }
 
+    /// Next earnings date(s), analyst estimate ranges, and dividend dates;
+    /// null when Yahoo has no calendar data for the symbol.
+    async fn key_dates(&self, ctx: &Context<'_>) -> Result<Option<GqlKeyDates>> {
+        let state = ctx.data::<AppState>()?;
+        exec_gql(crate::services::calendar::get_key_dates(
+            &state.cache,
+            &self.symbol,
+        ))
+        .await
+    }
+
     async fn indicators(
         &self,
         ctx: &Context<'_>,
         interval: GqlInterval,
         range: GqlTimeRange,
+        #[graphql(
+            desc = "Comma-separated indicator subset with optional periods, e.g. \"rsi:21,macd,sma\"; omitted = all"
+        )]
+        indicators: Option<String>,
+        #[graphql(
+            default,
+            desc = "Also return the candlestick patterns detected across the range"
+        )]
+        patterns: bool,
     ) -> Result<GqlIndicatorsSummary> {
         let state = ctx.data::<AppState>()?;
+        let selection = indicators
+            .as_deref()
+            .map(str::parse::<finance_query::IndicatorSelection>)
+            .transpose()
+            .map_err(|e| {
+                async_graphql::Error::new(e.to_string()).extend_with(|_, ext| {
+                    ext.set("code", "BAD_REQUEST");
+                    ext.set("status", 400);
+                })
+            })?;
         exec_gql(crate::services::indicators::get_indicators(
             &state.cache,
             &self.symbol,
@@ -77,6 +108,8 @@ impl TickerEventsQuery {
             interval.as_str(),
             range.into(),
             range.as_str(),
+            selection,
+            patterns,
         ))
         .await
     }
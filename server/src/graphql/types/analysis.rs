@@ -147,3 +147,38 @@ pub struct GqlEarningsHistoryEntry {
     pub eps_difference: Option<Json<serde_json::Value>>,
     pub surprise_percent: Option<Json<serde_json::Value>>,
 }
+
+// ── ESG Scores ──────────────────────────────────────────────────────────────
+
+#[derive(SimpleObject, Deserialize, Debug, Clone, Default)]
+#[graphql(rename_fields = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
+pub struct GqlEsgScores {
+    pub max_age: Option<i64>,
+    pub total_esg: Option<Json<serde_json::Value>>,
+    pub environment_score: Option<Json<serde_json::Value>>,
+    pub social_score: Option<Json<serde_json::Value>>,
+    pub governance_score: Option<Json<serde_json::Value>>,
+    pub percentile: Option<Json<serde_json::Value>>,
+    pub rating_year: Option<i32>,
+    pub rating_month: Option<u32>,
+    pub highest_controversy: Option<f64>,
+    pub related_controversy: Vec<String>,
+    pub esg_performance: Option<String>,
+    pub peer_group: Option<String>,
+    pub peer_count: Option<i64>,
+    pub peer_esg_score_performance: Option<GqlPeerPerformance>,
+    pub peer_environment_performance: Option<GqlPeerPerformance>,
+    pub peer_social_performance: Option<GqlPeerPerformance>,
+    pub peer_governance_performance: Option<GqlPeerPerformance>,
+    pub peer_highest_controversy_performance: Option<GqlPeerPerformance>,
+}
+
+#[derive(SimpleObject, Deserialize, Debug, Clone, Default)]
+#[graphql(rename_fields = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
+pub struct GqlPeerPerformance {
+    pub min: Option<f64>,
+    pub avg: Option<f64>,
+    pub max: Option<f64>,
+}
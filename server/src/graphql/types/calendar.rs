@@ -6,6 +6,7 @@
 //! rather than a flat object, since the variants don't share a field set.
 
 use async_graphql::{Json, SimpleObject, Union};
+use serde::Deserialize;
 
 /// Upcoming earnings report with analyst estimate data.
 #[derive(SimpleObject, Debug, Clone)]
@@ -67,6 +68,114 @@ pub enum GqlEventKind {
     Unknown(GqlUnknownEvent),
 }
 
+/// Upcoming earnings and dividend dates for one symbol, mirroring
+/// `finance_query::KeyDates`. Dates are `YYYY-MM-DD`.
+#[derive(SimpleObject, Deserialize, Debug, Clone, Default)]
+#[graphql(rename_fields = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
+pub struct GqlKeyDates {
+    /// Next earnings date(s); two dates mean a reporting window
+    pub earnings_dates: Vec<String>,
+    pub earnings_date_is_estimate: Option<bool>,
+    pub eps_estimate: GqlEpsEstimateRange,
+    pub revenue_estimate: GqlRevenueEstimateRange,
+    pub ex_dividend_date: Option<String>,
+    pub dividend_date: Option<String>,
+}
+
+/// Low / average / high analyst EPS estimate.
+#[derive(SimpleObject, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct GqlEpsEstimateRange {
+    pub low: Option<f64>,
+    pub average: Option<f64>,
+    pub high: Option<f64>,
+}
+
+/// Low / average / high analyst revenue estimate.
+#[derive(SimpleObject, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct GqlRevenueEstimateRange {
+    pub low: Option<i64>,
+    pub average: Option<i64>,
+    pub high: Option<i64>,
+}
+
+/// A company scheduled to report earnings, mirroring
+/// `finance_query::EarningsCalendarEntry`.
+#[derive(SimpleObject, Deserialize, Debug, Clone, Default)]
+#[graphql(rename_fields = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
+pub struct GqlEarningsCalendarEntry {
+    pub symbol: String,
+    pub name: Option<String>,
+    pub event_name: Option<String>,
+    pub report_date: Option<String>,
+    pub timestamp: Option<i64>,
+    pub call_time: Option<String>,
+    pub fiscal_date_ending: Option<String>,
+    pub estimate: Option<f64>,
+    pub eps_actual: Option<f64>,
+    pub surprise_percent: Option<f64>,
+    pub market_cap: Option<f64>,
+    pub currency: Option<String>,
+}
+
+/// An IPO scheduled in the requested window.
+#[derive(SimpleObject, Deserialize, Debug, Clone, Default)]
+#[graphql(rename_fields = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
+pub struct GqlIpoCalendarEntry {
+    pub symbol: String,
+    pub name: Option<String>,
+    pub exchange: Option<String>,
+    pub filing_date: Option<String>,
+    pub ipo_date: Option<String>,
+    pub amended_date: Option<String>,
+    pub price_from: Option<f64>,
+    pub price_to: Option<f64>,
+    pub offer_price: Option<f64>,
+    pub currency: Option<String>,
+    pub shares: Option<i64>,
+    pub deal_type: Option<String>,
+}
+
+/// A stock split taking effect in the requested window.
+#[derive(SimpleObject, Deserialize, Debug, Clone, Default)]
+#[graphql(rename_fields = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
+pub struct GqlSplitCalendarEntry {
+    pub symbol: String,
+    pub name: Option<String>,
+    pub split_date: Option<String>,
+    pub timestamp: Option<i64>,
+    pub optionable: Option<bool>,
+    /// Shares held before the split (the 1 in a 4-for-1 split)
+    pub old_shares: Option<f64>,
+    /// Shares held after the split (the 4 in a 4-for-1 split)
+    pub new_shares: Option<f64>,
+}
+
+/// A scheduled macroeconomic release, mirroring `finance_query::EconomicEvent`.
+#[derive(SimpleObject, Deserialize, Debug, Clone, Default)]
+#[graphql(rename_fields = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
+pub struct GqlEconomicEvent {
+    pub event: String,
+    /// inflation, central_bank, employment, growth, manufacturing, consumer,
+    /// housing, trade, or other
+    pub category: String,
+    pub country: Option<String>,
+    pub release_date: Option<String>,
+    pub timestamp: Option<i64>,
+    pub period: Option<String>,
+    pub actual: Option<f64>,
+    pub consensus: Option<f64>,
+    pub prior: Option<f64>,
+    /// Prior-period value as first reported, before any revision
+    pub originally_reported: Option<f64>,
+}
+
 /// A single upcoming financial event.
 #[derive(SimpleObject, Debug, Clone)]
 #[graphql(rename_fields = "camelCase")]
@@ -61,6 +61,32 @@ pub struct GqlChart {
     pub interval: Option<String>,
     /// Time range used for this chart (e.g. "1mo").
     pub range: Option<String>,
+    /// Dividends, splits, and capital gains aligned to their candles.
+    /// Populated only when the chart is requested with `events: true`.
+    #[serde(default)]
+    pub events: Vec<GqlChartEvent>,
+}
+
+/// A corporate event aligned to the candle it falls on.
+#[derive(SimpleObject, Deserialize, Debug, Clone)]
+#[graphql(rename_fields = "camelCase")]
+#[serde(rename_all = "camelCase")]
+pub struct GqlChartEvent {
+    pub timestamp: i64,
+    /// Index into the chart's candles of the bar containing this event.
+    pub candle_index: i32,
+    /// Event type: "dividend", "split", or "capitalGain".
+    #[serde(rename = "type")]
+    #[graphql(name = "type")]
+    pub kind: String,
+    /// Per-share amount (dividends and capital gains).
+    pub amount: Option<f64>,
+    /// Split numerator.
+    pub numerator: Option<f64>,
+    /// Split denominator.
+    pub denominator: Option<f64>,
+    /// Split ratio (e.g. "4:1").
+    pub ratio: Option<String>,
 }
 
 #[ComplexObject(rename_fields = "camelCase")]
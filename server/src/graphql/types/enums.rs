@@ -13,10 +13,13 @@ use finance_query::{
 #[derive(Enum, Copy, Clone, Eq, PartialEq, Debug)]
 pub enum GqlInterval {
     OneMinute,
+    TwoMinutes,
     FiveMinutes,
     FifteenMinutes,
     ThirtyMinutes,
     OneHour,
+    NinetyMinutes,
+    FourHours,
     OneDay,
     OneWeek,
     OneMonth,
@@ -27,10 +30,13 @@ impl From<GqlInterval> for Interval {
     fn from(v: GqlInterval) -> Self {
         match v {
             GqlInterval::OneMinute => Interval::OneMinute,
+            GqlInterval::TwoMinutes => Interval::TwoMinutes,
             GqlInterval::FiveMinutes => Interval::FiveMinutes,
             GqlInterval::FifteenMinutes => Interval::FifteenMinutes,
             GqlInterval::ThirtyMinutes => Interval::ThirtyMinutes,
             GqlInterval::OneHour => Interval::OneHour,
+            GqlInterval::NinetyMinutes => Interval::NinetyMinutes,
+            GqlInterval::FourHours => Interval::FourHours,
             GqlInterval::OneDay => Interval::OneDay,
             GqlInterval::OneWeek => Interval::OneWeek,
             GqlInterval::OneMonth => Interval::OneMonth,
@@ -44,10 +50,13 @@ impl GqlInterval {
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::OneMinute => "1m",
+            Self::TwoMinutes => "2m",
             Self::FiveMinutes => "5m",
             Self::FifteenMinutes => "15m",
             Self::ThirtyMinutes => "30m",
             Self::OneHour => "1h",
+            Self::NinetyMinutes => "90m",
+            Self::FourHours => "4h",
             Self::OneDay => "1d",
             Self::OneWeek => "1wk",
             Self::OneMonth => "1mo",
@@ -5,7 +5,9 @@
 
 use super::batch::GqlBatchError;
 use crate::graphql::pagination::{self, Page};
-use async_graphql::{ComplexObject, Result, SimpleObject};
+use std::collections::BTreeMap;
+
+use async_graphql::{ComplexObject, Json, Result, SimpleObject};
 use serde::Deserialize;
 
 // ── Nested sub-types ───────────────────────────────────────────────────────
@@ -102,6 +104,21 @@ pub struct GqlElderRayData {
     pub bear_power: Option<f64>,
 }
 
+/// A candlestick pattern detected on one bar of the indicator window.
+#[derive(SimpleObject, Deserialize, Debug, Clone, Default)]
+#[graphql(rename_fields = "camelCase")]
+#[serde(rename_all = "camelCase", default)]
+pub struct GqlPatternMatch {
+    /// Index of the bar within the requested range
+    pub index: i64,
+    /// Bar timestamp (Unix seconds)
+    pub timestamp: i64,
+    /// Pattern name (e.g. "BullishEngulfing")
+    pub pattern: String,
+    /// "Bullish", "Bearish", or "Neutral"
+    pub sentiment: String,
+}
+
 // ── Main indicators summary ────────────────────────────────────────────────
 
 /// All technical indicators for a symbol (latest values).
@@ -174,6 +191,10 @@ pub struct GqlIndicatorsSummary {
     pub accumulation_distribution: Option<f64>,
     pub vwap: Option<f64>,
     pub balance_of_power: Option<f64>,
+    // Custom indicators and non-default periods (e.g. "rsi_21")
+    pub custom: Option<Json<BTreeMap<String, f64>>>,
+    /// Candlestick patterns across the range; only filled when requested
+    pub patterns: Vec<GqlPatternMatch>,
 }
 
 /// Wraps a symbol name with its indicators, used by the batch root field.
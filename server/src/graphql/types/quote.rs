@@ -171,6 +171,11 @@ pub struct GqlQuote {
     pub total_assets: Option<Json<serde_json::Value>>,
     pub yield_value: Option<Json<serde_json::Value>>,
 
+    // ── Bond-specific ───────────────────────────────────────────────────────
+    pub coupon_rate: Option<Json<serde_json::Value>>,
+    pub maturity_date: Option<Json<serde_json::Value>>,
+    pub yield_to_maturity: Option<Json<serde_json::Value>>,
+
     // ── Governance ──────────────────────────────────────────────────────────
     pub audit_risk: Option<i32>,
     pub board_risk: Option<i32>,
@@ -42,6 +42,7 @@ pub struct GqlPriceUpdate {
     pub last_market: String,
     pub circulating_supply: f64,
     pub market_cap: f64,
+    pub asset_class: String,
 }
 
 impl From<PriceUpdate> for GqlPriceUpdate {
@@ -80,6 +81,7 @@ impl From<PriceUpdate> for GqlPriceUpdate {
             last_market: u.last_market,
             circulating_supply: u.circulating_supply,
             market_cap: u.market_cap,
+            asset_class: format!("{:?}", u.asset_class),
         }
     }
 }
@@ -7,8 +7,9 @@ use axum::{
 use finance_query_server::graphql::{
     self,
     fields::{
-        GQL_EARNINGS_ESTIMATE_COMPOSITE, GQL_EARNINGS_ESTIMATE_VALID_FIELDS,
-        GQL_EARNINGS_HISTORY_COMPOSITE, GQL_EARNINGS_HISTORY_VALID_FIELDS,
+        ESG_SCORES_COMPOSITE_FIELDS, GQL_EARNINGS_ESTIMATE_COMPOSITE,
+        GQL_EARNINGS_ESTIMATE_VALID_FIELDS, GQL_EARNINGS_HISTORY_COMPOSITE,
+        GQL_EARNINGS_HISTORY_VALID_FIELDS, GQL_ESG_SCORES_VALID_FIELDS,
         GQL_GRADING_HISTORY_COMPOSITE, GQL_GRADING_HISTORY_VALID_FIELDS,
         GQL_RECOMMENDATION_TREND_COMPOSITE, GQL_RECOMMENDATION_TREND_VALID_FIELDS,
         GQL_RECOMMENDATION_VALID_FIELDS, RECOMMENDATION_COMPOSITE_FIELDS, gql_string_list_literal,
@@ -185,6 +186,35 @@ pub(crate) async fn get_batch_recommendations(
     (StatusCode::OK, Json(result)).into_response()
 }
 
+/// GET /v2/esg/{symbol}
+///
+/// ESG risk scores for a symbol; `null` when it has no ESG coverage.
+pub(crate) async fn get_esg(
+    Extension(schema): Extension<graphql::FinanceSchema>,
+    Path(symbol): Path<String>,
+    Query(params): Query<AnalysisQuery>,
+) -> impl IntoResponse {
+    let selection = build_rest_composite_selection(
+        params.fields.as_deref(),
+        GQL_ESG_SCORES_VALID_FIELDS,
+        ESG_SCORES_COMPOSITE_FIELDS,
+    );
+    let query = format!(
+        "query GetEsg($symbol: String!) {{ ticker(symbol: $symbol) {{ esgScores {selection} }} }}"
+    );
+    info!(
+        "Fetching ESG scores for {} (fields={:?})",
+        symbol, params.fields
+    );
+    let mut vars = Variables::default();
+    vars.insert(Name::new("symbol"), symbol.clone().into());
+    let data = match execute_gql_rest(&schema, &query, vars).await {
+        Ok(d) => d,
+        Err(resp) => return resp,
+    };
+    (StatusCode::OK, Json(unwrap_ticker_field(data, "esgScores"))).into_response()
+}
+
 /// GET /v2/analysis/{symbol}/{analysis_type}
 pub(crate) async fn get_analysis(
     Extension(schema): Extension<graphql::FinanceSchema>,
@@ -1,20 +1,25 @@
-use async_graphql::Variables;
+use async_graphql::{Name, Variables};
 use axum::{
-    extract::{Extension, Query},
+    extract::{Extension, Path, Query},
     http::StatusCode,
     response::{IntoResponse, Json},
 };
 use finance_query_server::graphql::{
     self,
     fields::{
-        CALENDAR_EVENT_UNION_SELECTION, GQL_CALENDAR_VALID_FIELDS, gql_string_list_literal,
-        unwrap_field,
+        CALENDAR_EVENT_UNION_SELECTION, GQL_CALENDAR_VALID_FIELDS,
+        GQL_EARNINGS_CALENDAR_VALID_FIELDS, GQL_ECONOMIC_CALENDAR_VALID_FIELDS,
+        GQL_IPO_CALENDAR_VALID_FIELDS, GQL_KEY_DATES_VALID_FIELDS,
+        GQL_SPLITS_CALENDAR_VALID_FIELDS, KEY_DATES_COMPOSITE_FIELDS, escape_gql_string,
+        gql_string_list_literal, unwrap_field, unwrap_ticker_field,
     },
 };
 use serde::Deserialize;
 use tracing::info;
 
-use super::gql_bridge::{build_rest_selection, execute_gql_rest, range_to_gql};
+use super::gql_bridge::{
+    build_rest_composite_selection, build_rest_selection, execute_gql_rest, range_to_gql,
+};
 
 fn default_calendar_range() -> String {
     "1mo".to_string()
@@ -32,6 +37,28 @@ pub(crate) struct CalendarQuery {
     fields: Option<String>,
 }
 
+/// Query parameters for the market-wide /v2/calendar/{earnings,economic,ipos,splits}
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CalendarWindowQuery {
+    /// First date, YYYY-MM-DD (default: today, UTC)
+    start_date: Option<String>,
+    /// Last date, YYYY-MM-DD (default: six days after `startDate`)
+    end_date: Option<String>,
+    /// Comma-separated list of fields to include in response
+    fields: Option<String>,
+}
+
+/// Query parameters for /v2/calendar/economic
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EconomicCalendarQuery {
+    /// Country code to keep, e.g. US or DE (default: every country)
+    region: Option<String>,
+    #[serde(flatten)]
+    window: CalendarWindowQuery,
+}
+
 /// Build the `calendar { ... }` selection set, expanding `event` with its
 /// full union inline-fragment selection (see `CALENDAR_EVENT_UNION_SELECTION`).
 fn build_rest_calendar_selection(fields: Option<&str>) -> String {
@@ -79,3 +106,155 @@ pub(crate) async fn get_calendar(
     };
     (StatusCode::OK, Json(unwrap_field(data, "calendar"))).into_response()
 }
+
+/// Query parameters for /v2/calendar/{symbol}
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct KeyDatesQuery {
+    /// Comma-separated list of fields to include in response
+    fields: Option<String>,
+}
+
+/// GET /v2/calendar/{symbol}
+///
+/// Next earnings date(s), analyst estimate ranges, and dividend dates for one
+/// symbol; `null` when Yahoo has no calendar data for it.
+pub(crate) async fn get_key_dates(
+    Extension(schema): Extension<graphql::FinanceSchema>,
+    Path(symbol): Path<String>,
+    Query(params): Query<KeyDatesQuery>,
+) -> impl IntoResponse {
+    let selection = build_rest_composite_selection(
+        params.fields.as_deref(),
+        GQL_KEY_DATES_VALID_FIELDS,
+        KEY_DATES_COMPOSITE_FIELDS,
+    );
+    let query = format!(
+        "query GetKeyDates($symbol: String!) {{ ticker(symbol: $symbol) {{ keyDates {selection} }} }}"
+    );
+    let mut vars = Variables::default();
+    vars.insert(Name::new("symbol"), symbol.clone().into());
+
+    info!("Fetching key dates for {}", symbol);
+
+    let data = match execute_gql_rest(&schema, &query, vars).await {
+        Ok(d) => d,
+        Err(resp) => return resp,
+    };
+    (StatusCode::OK, Json(unwrap_ticker_field(data, "keyDates"))).into_response()
+}
+
+/// GET /v2/calendar/earnings?startDate=<date>&endDate=<date>
+///
+/// Companies reporting earnings in the window, market-wide. Defaults to the
+/// coming week.
+pub(crate) async fn get_earnings_calendar(
+    Extension(schema): Extension<graphql::FinanceSchema>,
+    Query(params): Query<CalendarWindowQuery>,
+) -> impl IntoResponse {
+    window_calendar(
+        &schema,
+        "earningsCalendar",
+        GQL_EARNINGS_CALENDAR_VALID_FIELDS,
+        params,
+        "",
+    )
+    .await
+}
+
+/// GET /v2/calendar/ipos?startDate=<date>&endDate=<date>
+///
+/// IPOs scheduled in the window. Defaults to the coming week.
+pub(crate) async fn get_ipo_calendar(
+    Extension(schema): Extension<graphql::FinanceSchema>,
+    Query(params): Query<CalendarWindowQuery>,
+) -> impl IntoResponse {
+    window_calendar(
+        &schema,
+        "ipoCalendar",
+        GQL_IPO_CALENDAR_VALID_FIELDS,
+        params,
+        "",
+    )
+    .await
+}
+
+/// GET /v2/calendar/splits?startDate=<date>&endDate=<date>
+///
+/// Stock splits taking effect in the window. Defaults to the coming week.
+pub(crate) async fn get_splits_calendar(
+    Extension(schema): Extension<graphql::FinanceSchema>,
+    Query(params): Query<CalendarWindowQuery>,
+) -> impl IntoResponse {
+    window_calendar(
+        &schema,
+        "splitsCalendar",
+        GQL_SPLITS_CALENDAR_VALID_FIELDS,
+        params,
+        "",
+    )
+    .await
+}
+
+/// GET /v2/calendar/economic?startDate=<date>&endDate=<date>&region=<code>
+///
+/// Macroeconomic releases in the window, optionally for one country.
+/// Defaults to the coming week.
+pub(crate) async fn get_economic_calendar(
+    Extension(schema): Extension<graphql::FinanceSchema>,
+    Query(params): Query<EconomicCalendarQuery>,
+) -> impl IntoResponse {
+    let region_arg = params
+        .region
+        .as_deref()
+        .map(|r| format!(", region: \"{}\"", escape_gql_string(r)))
+        .unwrap_or_default();
+    window_calendar(
+        &schema,
+        "economicCalendar",
+        GQL_ECONOMIC_CALENDAR_VALID_FIELDS,
+        params.window,
+        &region_arg,
+    )
+    .await
+}
+
+/// Run one of the date-window calendar root fields, filling in the default
+/// window (today through six days later). `extra_args` is appended to the
+/// field's argument list as-is.
+async fn window_calendar(
+    schema: &graphql::FinanceSchema,
+    field: &str,
+    valid_fields: &[&str],
+    params: CalendarWindowQuery,
+    extra_args: &str,
+) -> axum::response::Response {
+    let start = params
+        .start_date
+        .unwrap_or_else(|| chrono::Utc::now().date_naive().to_string());
+    let end = match params.end_date {
+        Some(end) => end,
+        // An unparseable start falls through unchanged for GraphQL to reject
+        None => chrono::NaiveDate::parse_from_str(&start, "%Y-%m-%d")
+            .map(|d| (d + chrono::Days::new(6)).to_string())
+            .unwrap_or_else(|_| start.clone()),
+    };
+    let selection = build_rest_selection(params.fields.as_deref(), valid_fields);
+
+    let query = format!(
+        "query {{ {}(startDate: \"{}\", endDate: \"{}\"{}) {} }}",
+        field,
+        escape_gql_string(&start),
+        escape_gql_string(&end),
+        extra_args,
+        selection
+    );
+
+    info!("Fetching {} ({} to {})", field, start, end);
+
+    let data = match execute_gql_rest(schema, &query, Variables::default()).await {
+        Ok(d) => d,
+        Err(resp) => return resp,
+    };
+    (StatusCode::OK, Json(unwrap_field(data, field))).into_response()
+}
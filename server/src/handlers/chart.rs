@@ -7,9 +7,9 @@ use axum::{
 use finance_query_server::graphql::{
     self,
     fields::{
-        GQL_CANDLE_VALID_FIELDS, GQL_CHART_META_VALID_FIELDS, GQL_CHART_VALID_FIELDS,
-        GQL_SPARK_VALID_FIELDS, escape_gql_string, gql_string_list_literal, unwrap_field,
-        unwrap_ticker_field,
+        GQL_CANDLE_VALID_FIELDS, GQL_CHART_EVENT_VALID_FIELDS, GQL_CHART_META_VALID_FIELDS,
+        GQL_CHART_VALID_FIELDS, GQL_SPARK_VALID_FIELDS, escape_gql_string, gql_string_list_literal,
+        unwrap_field, unwrap_ticker_field,
     },
     pagination::{
         build_connection_selection, connection_nodes, connection_page_info,
@@ -99,6 +99,7 @@ pub(crate) async fn get_chart(
         params.fields.as_deref(),
         params.limit,
         params.cursor.as_deref(),
+        params.events,
     );
 
     if params.start.is_none() && params.end.is_some() {
@@ -180,7 +181,7 @@ pub(crate) async fn get_batch_charts(
     let item_selection = if want_chart {
         format!(
             "{{ symbol chart {} }}",
-            build_rest_chart_selection(params.fields.as_deref(), None, None)
+            build_rest_chart_selection(params.fields.as_deref(), None, None, false)
         )
     } else {
         "{ symbol }".to_string()
@@ -301,15 +302,18 @@ fn build_rest_spark_selection(fields: Option<&str>) -> String {
 /// — mirrors `build_chart_selection` in finance-query-mcp/src/tools/chart.rs.
 /// `candles` is a paginated Connection field; `limit`/`cursor` are `None` for
 /// batch callers (which don't expose pagination params for the nested list).
+/// `events` is only populated on request, so it is added only when `events`
+/// is set and never comes from `fields`.
 pub(crate) fn build_rest_chart_selection(
     fields: Option<&str>,
     limit: Option<u32>,
     cursor: Option<&str>,
+    events: bool,
 ) -> String {
     let top_selection = build_rest_selection(fields, GQL_CHART_VALID_FIELDS);
     let want_meta = top_selection.contains("meta");
     let want_candles = top_selection.contains("candles");
-    if !want_meta && !want_candles {
+    if !want_meta && !want_candles && !events {
         return top_selection;
     }
     let mut sel = String::from("{ ");
@@ -345,6 +349,31 @@ pub(crate) fn build_rest_chart_selection(
         )));
         sel.push(' ');
     }
+    if events {
+        sel.push_str("events ");
+        sel.push_str(&build_rest_selection(None, GQL_CHART_EVENT_VALID_FIELDS));
+        sel.push(' ');
+    }
     sel.push('}');
     sel
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chart_selection_adds_events_only_on_request() {
+        let plain = build_rest_chart_selection(Some("symbol,meta"), None, None, false);
+        assert!(!plain.contains("events"));
+
+        let with_events = build_rest_chart_selection(Some("symbol,meta"), None, None, true);
+        assert!(with_events.starts_with("{ symbol meta {"));
+        assert!(with_events.contains("events {"));
+        assert!(with_events.ends_with(" }"));
+
+        // Events still come through when neither meta nor candles is selected
+        let events_only = build_rest_chart_selection(Some("symbol"), None, None, true);
+        assert!(events_only.starts_with("{ symbol events {"));
+    }
+}
@@ -146,10 +146,13 @@ pub(crate) fn interval_to_gql(s: &str) -> &'static str {
     use finance_query::Interval;
     match parse_interval(s) {
         Interval::OneMinute => "ONE_MINUTE",
+        Interval::TwoMinutes => "TWO_MINUTES",
         Interval::FiveMinutes => "FIVE_MINUTES",
         Interval::FifteenMinutes => "FIFTEEN_MINUTES",
         Interval::ThirtyMinutes => "THIRTY_MINUTES",
         Interval::OneHour => "ONE_HOUR",
+        Interval::NinetyMinutes => "NINETY_MINUTES",
+        Interval::FourHours => "FOUR_HOURS",
         Interval::OneDay => "ONE_DAY",
         Interval::OneWeek => "ONE_WEEK",
         Interval::OneMonth => "ONE_MONTH",
@@ -4,6 +4,7 @@ use axum::{
     http::StatusCode,
     response::{IntoResponse, Json},
 };
+use finance_query::IndicatorSelection;
 use finance_query_server::graphql::{
     self,
     fields::{
@@ -48,6 +49,12 @@ pub(crate) struct IndicatorsQuery {
     range: String,
     /// Comma-separated list of fields to include in response
     fields: Option<String>,
+    /// Comma-separated indicator subset with optional periods (e.g.
+    /// `rsi:21,macd`); only these are computed. Omitted = all indicators
+    indicators: Option<String>,
+    /// Also return the candlestick patterns detected across the range
+    #[serde(default)]
+    patterns: bool,
 }
 
 /// GET /v2/indicators/{symbol}
@@ -58,16 +65,37 @@ pub(crate) async fn get_indicators(
 ) -> impl IntoResponse {
     let gql_interval = interval_to_gql(&params.interval);
     let gql_range = range_to_gql(&params.range);
-    let selection = build_rest_indicators_selection(params.fields.as_deref());
+    // Without an explicit `fields`, return only the fields the indicator
+    // subset populates so unselected indicators don't come back as nulls.
+    let derived_fields = match (&params.fields, &params.indicators) {
+        (None, Some(ind)) => ind
+            .parse::<IndicatorSelection>()
+            .ok()
+            .map(|sel| sel.summary_fields().join(",")),
+        _ => None,
+    };
+    let mut selection =
+        build_rest_indicators_selection(params.fields.as_deref().or(derived_fields.as_deref()));
+    if params.patterns {
+        selection.pop();
+        selection.push_str("patterns { index timestamp pattern sentiment } }");
+    }
+    let patterns = params.patterns;
     let query = format!(
-        "query GetIndicators($symbol: String!) {{ ticker(symbol: $symbol) {{ indicators(interval: {gql_interval}, range: {gql_range}) {selection} }} }}"
+        "query GetIndicators($symbol: String!, $indicators: String) {{ ticker(symbol: $symbol) {{ indicators(interval: {gql_interval}, range: {gql_range}, indicators: $indicators, patterns: {patterns}) {selection} }} }}"
     );
     info!(
-        "Calculating indicators for {} with interval={}, range={}",
-        symbol, params.interval, params.range
+        "Calculating indicators for {} with interval={}, range={}, indicators={}",
+        symbol,
+        params.interval,
+        params.range,
+        params.indicators.as_deref().unwrap_or("all")
     );
     let mut vars = Variables::default();
     vars.insert(Name::new("symbol"), symbol.clone().into());
+    if let Some(ind) = params.indicators {
+        vars.insert(Name::new("indicators"), ind.into());
+    }
     let data = match execute_gql_rest(&schema, &query, vars).await {
         Ok(d) => d,
         Err(resp) => return resp,
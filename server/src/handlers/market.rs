@@ -4,7 +4,7 @@ use axum::{
     http::{HeaderMap, StatusCode},
     response::{IntoResponse, Json},
 };
-use finance_query::{IndicesRegion, ValueFormat};
+use finance_query::IndicesRegion;
 use finance_query_server::graphql::{
     self,
     fields::{
@@ -17,7 +17,7 @@ use serde::Deserialize;
 use tracing::info;
 
 use super::gql_bridge::{build_rest_selection, execute_gql_rest};
-use super::support::parse_format;
+use super::support::{apply_format_options, gql_format, parse_format, parse_format_options};
 
 /// Map a REST `region` string (world-indices region, e.g. "americas",
 /// "asia-pacific") to a `GqlIndicesRegion` enum literal. Returns `None` for
@@ -33,15 +33,6 @@ fn indices_region_to_gql(s: &str) -> Option<&'static str> {
     })
 }
 
-/// Map a REST `format` string to the `GqlValueFormat` enum literal.
-fn format_to_gql(format: ValueFormat) -> &'static str {
-    match format {
-        ValueFormat::Raw => "RAW",
-        ValueFormat::Pretty => "PRETTY",
-        ValueFormat::Both => "BOTH",
-    }
-}
-
 /// Query parameters for /v2/indices
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -87,6 +78,7 @@ pub(crate) async fn get_indices(
     Query(params): Query<IndicesQuery>,
 ) -> impl IntoResponse {
     let format = parse_format(params.format.as_deref());
+    let format_options = parse_format_options(params.format.as_deref());
     let selection = build_rest_selection(params.fields.as_deref(), GQL_QUOTE_VALID_FIELDS);
     let region_arg = params
         .region
@@ -97,7 +89,7 @@ pub(crate) async fn get_indices(
     let query = format!(
         "query {{ indices({}format: {}) {} }}",
         args,
-        format_to_gql(format),
+        gql_format(format, &format_options),
         selection
     );
 
@@ -112,7 +104,12 @@ pub(crate) async fn get_indices(
         Ok(d) => d,
         Err(resp) => return resp,
     };
-    (StatusCode::OK, Json(unwrap_field(data, "indices"))).into_response()
+    let indices = unwrap_field(data, "indices");
+    (
+        StatusCode::OK,
+        Json(apply_format_options(indices, format, &format_options)),
+    )
+        .into_response()
 }
 
 /// GET /v2/market-summary
@@ -124,6 +121,7 @@ pub(crate) async fn get_market_summary(
     headers: HeaderMap,
 ) -> impl IntoResponse {
     let format = parse_format(params.format.as_deref());
+    let format_options = parse_format_options(params.format.as_deref());
     let lang = lang::resolve_lang(params.lang.as_deref(), &headers);
     let selection = build_rest_selection(params.fields.as_deref(), GQL_MARKET_SUMMARY_VALID_FIELDS);
     let region_arg = params
@@ -139,7 +137,7 @@ pub(crate) async fn get_market_summary(
         "query {{ marketSummary({}{}format: {}) {} }}",
         region_arg.unwrap_or_default(),
         lang_arg,
-        format_to_gql(format),
+        gql_format(format, &format_options),
         selection
     );
 
@@ -154,7 +152,12 @@ pub(crate) async fn get_market_summary(
         Ok(d) => d,
         Err(resp) => return resp,
     };
-    (StatusCode::OK, Json(unwrap_field(data, "marketSummary"))).into_response()
+    let summary = unwrap_field(data, "marketSummary");
+    (
+        StatusCode::OK,
+        Json(apply_format_options(summary, format, &format_options)),
+    )
+        .into_response()
 }
 
 /// GET /v2/trending
@@ -21,6 +21,7 @@ mod market;
 mod metadata;
 mod news;
 mod options;
+mod overview;
 mod quote;
 mod risk;
 mod screener;
@@ -30,8 +31,10 @@ mod stream;
 mod support;
 mod system;
 mod transcripts;
+mod v1_compat;
 
 pub(crate) use system::metrics_middleware;
+pub(crate) use v1_compat::v1_routes;
 
 use axum::{
     Router,
@@ -51,7 +54,18 @@ pub(crate) fn api_routes() -> Router {
         .route("/capital-gains/{symbol}", get(events::get_capital_gains))
         // GET /v2/capital-gains?symbols=<csv>&range=<str>
         .route("/capital-gains", get(events::get_batch_capital_gains))
+        // GET /v2/calendar?symbols=<csv>&range=<str>
         .route("/calendar", get(calendar::get_calendar))
+        // GET /v2/calendar/earnings?startDate=<date>&endDate=<date>
+        .route("/calendar/earnings", get(calendar::get_earnings_calendar))
+        // GET /v2/calendar/economic?startDate=<date>&endDate=<date>&region=<code>
+        .route("/calendar/economic", get(calendar::get_economic_calendar))
+        // GET /v2/calendar/ipos?startDate=<date>&endDate=<date>
+        .route("/calendar/ipos", get(calendar::get_ipo_calendar))
+        // GET /v2/calendar/splits?startDate=<date>&endDate=<date>
+        .route("/calendar/splits", get(calendar::get_splits_calendar))
+        // GET /v2/calendar/{symbol} - fixed paths above take precedence
+        .route("/calendar/{symbol}", get(calendar::get_key_dates))
         // GET /v2/chart/{symbol}?interval=<str>&range=<str>&events=<bool>&patterns=<bool>
         .route("/chart/{symbol}", get(chart::get_chart))
         // GET /v2/charts?symbols=<csv>&interval=<str>&range=<str>&patterns=<bool>
@@ -77,6 +91,8 @@ pub(crate) fn api_routes() -> Router {
             "/edgar/submissions/{symbol}",
             get(edgar::get_edgar_submissions),
         )
+        // GET /v2/esg/{symbol}
+        .route("/esg/{symbol}", get(analysis::get_esg))
         // GET /v2/exchanges
         .route("/exchanges", get(metadata::get_exchanges))
         // GET /v2/fear-and-greed
@@ -122,6 +138,8 @@ pub(crate) fn api_routes() -> Router {
         .route("/options/{symbol}", get(options::get_options))
         // GET /v2/options?symbols=<csv>&date=<i64>
         .route("/options", get(options::get_batch_options))
+        // GET /v2/overview/{symbol}?include=<csv>&interval=<str>&range=<str>&newsCount=<u32>
+        .route("/overview/{symbol}", get(overview::get_overview))
         // GET /v2/ping - version-prefixed ping
         .route("/ping", get(system::ping))
         // GET /v2/quote/{symbol}?logo=<bool>
@@ -139,7 +157,7 @@ pub(crate) fn api_routes() -> Router {
         .route("/recommendations", get(analysis::get_batch_recommendations))
         // GET /v2/risk/{symbol}?interval=<str>&range=<str>&benchmark=<str>
         .route("/risk/{symbol}", get(risk::get_risk))
-        // GET /v2/screeners/{screener}?count=<u32>
+        // GET /v2/screeners/{screener}?count=<u32>&country=<code>
         .route("/screeners/{screener}", get(screener::get_screeners))
         // POST /v2/screeners/custom
         .route("/screeners/custom", post(screener::post_custom_screener))
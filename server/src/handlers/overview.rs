@@ -0,0 +1,116 @@
+//! GET /v2/overview/{symbol} — symbol-scoped composite page load.
+//!
+//! Unlike the other per-symbol handlers this one calls the service layer
+//! directly instead of going through the GraphQL bridge: the composite
+//! reports each section's error alongside the sections that succeeded,
+//! whereas `execute_gql_rest` fails the whole response on any field error.
+
+use axum::{
+    extract::{Extension, Path, Query},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Json},
+};
+use finance_query_server::services::overview::{self, OverviewParams, OverviewSection};
+use finance_query_server::services::{parse_interval, parse_range};
+use finance_query_server::{AppState, lang};
+use serde::Deserialize;
+use tracing::info;
+
+use super::support::{default_interval, default_range, parse_format, parse_format_options};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct OverviewQuery {
+    /// Comma-separated sections: quote, chart, news, analysis (default: all)
+    include: Option<String>,
+    /// Chart interval (default: `DEFAULT_INTERVAL` or 1d)
+    #[serde(default = "default_interval")]
+    interval: String,
+    /// Chart range (default: `DEFAULT_RANGE` or 1mo)
+    #[serde(default = "default_range")]
+    range: String,
+    /// Maximum news articles (default: 10)
+    #[serde(default = "default_news_count")]
+    news_count: u32,
+    /// Quote value format: raw, pretty, or both (default: raw)
+    format: Option<String>,
+    /// Target language for translated text fields (BCP 47, e.g. "ja", "zh-Hant");
+    /// falls back to the Accept-Language header
+    lang: Option<String>,
+}
+
+fn default_news_count() -> u32 {
+    10
+}
+
+/// GET /v2/overview/{symbol}
+///
+/// Query: `include` (comma-separated: quote,chart,news,analysis; default all),
+/// `interval`/`range` (chart), `newsCount` (default 10), `format` (quote),
+/// `lang`. Sections are fetched concurrently; a failed section is `null`
+/// with its message under `errors`, and the response is still 200 unless
+/// every requested section failed (502).
+pub(crate) async fn get_overview(
+    Extension(state): Extension<AppState>,
+    Path(symbol): Path<String>,
+    Query(params): Query<OverviewQuery>,
+    headers: HeaderMap,
+) -> impl IntoResponse {
+    let sections = match OverviewSection::parse_list(params.include.as_deref()) {
+        Ok(sections) => sections,
+        Err(unknown) => {
+            let body = serde_json::json!({
+                "error": format!(
+                    "Unknown overview section '{unknown}'. Valid: quote, chart, news, analysis"
+                ),
+                "status": 400,
+            });
+            return (StatusCode::BAD_REQUEST, Json(body)).into_response();
+        }
+    };
+    let format = parse_format(params.format.as_deref());
+    let format_options = parse_format_options(params.format.as_deref());
+    let lang = lang::resolve_lang(params.lang.as_deref(), &headers);
+    let overview_params = OverviewParams {
+        interval: parse_interval(&params.interval),
+        range: parse_range(&params.range),
+        news_count: params.news_count as usize,
+    };
+
+    info!(
+        "Received overview request for symbol: {} (include={:?})",
+        symbol, sections
+    );
+
+    let mut doc = match overview::get_overview(
+        &state.cache,
+        &symbol,
+        &sections,
+        overview_params,
+        lang.as_deref(),
+    )
+    .await
+    {
+        Ok(doc) => doc,
+        Err(e) => {
+            let body = serde_json::json!({ "error": e.to_string(), "status": 500 });
+            return (StatusCode::INTERNAL_SERVER_ERROR, Json(body)).into_response();
+        }
+    };
+
+    // The quote section is cached with both raw and formatted values
+    if let Some(quote) = doc.get_mut("quote").filter(|q| !q.is_null()) {
+        *quote = format.transform_with(quote.take(), &format_options);
+    }
+
+    let failed = doc
+        .get("errors")
+        .and_then(|e| e.as_object())
+        .map_or(0, |e| e.len());
+    let status = if failed > 0 && failed == sections.len() {
+        StatusCode::BAD_GATEWAY
+    } else {
+        StatusCode::OK
+    };
+    (status, Json(doc)).into_response()
+}
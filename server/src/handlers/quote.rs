@@ -4,7 +4,6 @@ use axum::{
     http::HeaderMap,
     response::{IntoResponse, Json},
 };
-use finance_query::ValueFormat;
 use serde::Deserialize;
 use tracing::info;
 
@@ -17,7 +16,7 @@ use finance_query_server::graphql::{
 use finance_query_server::lang;
 
 use super::gql_bridge::{build_rest_selection, execute_gql_rest};
-use super::support::parse_format;
+use super::support::{apply_format_options, gql_format, parse_format, parse_format_options};
 
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -57,7 +56,8 @@ pub(crate) struct QuotesQuery {
 
 /// GET /v2/quote/{symbol}
 ///
-/// Query: `logo` (bool, default: false), `format` (raw|pretty|both), `fields` (comma-separated)
+/// Query: `logo` (bool, default: false), `format` (raw|pretty|both, with optional
+/// `:decimals=..,locale=..,percent=pct|bp,units=short|full`), `fields` (comma-separated)
 pub(crate) async fn get_quote(
     Extension(schema): Extension<graphql::FinanceSchema>,
     Path(symbol): Path<String>,
@@ -65,14 +65,11 @@ pub(crate) async fn get_quote(
     headers: HeaderMap,
 ) -> impl IntoResponse {
     let format = parse_format(params.format.as_deref());
+    let format_options = parse_format_options(params.format.as_deref());
     let lang = lang::resolve_lang(params.lang.as_deref(), &headers);
 
     // Map REST format to GraphQL enum value string.
-    let gql_format = match format {
-        ValueFormat::Raw => "RAW",
-        ValueFormat::Pretty => "PRETTY",
-        ValueFormat::Both => "BOTH",
-    };
+    let gql_format = gql_format(format, &format_options);
 
     let selection = build_rest_selection(params.fields.as_deref(), GQL_QUOTE_VALID_FIELDS);
 
@@ -103,7 +100,12 @@ pub(crate) async fn get_quote(
         Err(resp) => return resp,
     };
 
-    (StatusCode::OK, Json(unwrap_ticker_field(data, "quote"))).into_response()
+    let quote = unwrap_ticker_field(data, "quote");
+    (
+        StatusCode::OK,
+        Json(apply_format_options(quote, format, &format_options)),
+    )
+        .into_response()
 }
 
 /// GET /v2/quotes
@@ -118,13 +120,10 @@ pub(crate) async fn get_quotes(
     headers: HeaderMap,
 ) -> impl IntoResponse {
     let format = parse_format(params.format.as_deref());
+    let format_options = parse_format_options(params.format.as_deref());
     let lang = lang::resolve_lang(params.lang.as_deref(), &headers);
 
-    let gql_format = match format {
-        ValueFormat::Raw => "RAW",
-        ValueFormat::Pretty => "PRETTY",
-        ValueFormat::Both => "BOTH",
-    };
+    let gql_format = gql_format(format, &format_options);
     let lang_arg = match &lang {
         Some(l) => format!(", lang: \"{}\"", l),
         None => String::new(),
@@ -176,5 +175,9 @@ pub(crate) async fn get_quotes(
 
     let paginated = params.limit.is_some() || params.cursor.is_some();
     let result = unwrap_nested_connection(unwrap_field(data, "quotes"), "quotes", paginated);
-    (StatusCode::OK, Json(result)).into_response()
+    (
+        StatusCode::OK,
+        Json(apply_format_options(result, format, &format_options)),
+    )
+        .into_response()
 }
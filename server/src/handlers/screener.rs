@@ -4,7 +4,7 @@ use axum::{
     http::StatusCode,
     response::{IntoResponse, Json},
 };
-use finance_query::{Screener, ValueFormat};
+use finance_query::{Region, Screener};
 use finance_query_server::graphql::{
     self,
     fields::{GQL_SCREENER_RESULTS_VALID_FIELDS, SCREENER_RESULTS_COMPOSITE_FIELDS, unwrap_field},
@@ -13,7 +13,7 @@ use serde::Deserialize;
 use tracing::info;
 
 use super::gql_bridge::{build_rest_composite_selection, execute_gql_rest};
-use super::support::parse_format;
+use super::support::{apply_format_options, gql_format, parse_format, parse_format_options};
 
 fn default_screeners_count() -> u32 {
     std::env::var("SCREENERS_COUNT")
@@ -22,21 +22,13 @@ fn default_screeners_count() -> u32 {
         .unwrap_or(25)
 }
 
-// Delegates to the shared `parse_format` (same `fmt`/`full` aliases every other
-// endpoint accepts) instead of re-matching the raw string.
-fn format_to_gql(format: Option<&str>) -> &'static str {
-    match parse_format(format) {
-        ValueFormat::Raw => "RAW",
-        ValueFormat::Pretty => "PRETTY",
-        ValueFormat::Both => "BOTH",
-    }
-}
-
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct ScreenersQuery {
     #[serde(default = "default_screeners_count")]
     count: u32,
+    /// Country code (e.g. "JP", "GB") to screen non-US listings
+    country: Option<String>,
     /// Value format: raw, pretty, or both (default: raw)
     format: Option<String>,
     /// Comma-separated list of fields to include in response
@@ -91,7 +83,8 @@ pub(crate) struct FilterCondition {
 ///   - Fund: conservative-foreign-funds, high-yield-bond, portfolio-anchors,
 ///     solid-large-growth-funds, solid-midcap-growth-funds, top-mutual-funds
 ///
-/// Query: `count` (u32, default 25, max 250), `format` (raw|pretty|both), `fields` (comma-separated)
+/// Query: `count` (u32, default 25, max 250), `country` (e.g. JP, GB, DE, IN; day-gainers,
+/// day-losers, and most-actives only), `format` (raw|pretty|both), `fields` (comma-separated)
 pub(crate) async fn get_screeners(
     Extension(schema): Extension<graphql::FinanceSchema>,
     Path(screener): Path<String>,
@@ -107,8 +100,33 @@ pub(crate) async fn get_screeners(
             return (StatusCode::BAD_REQUEST, Json(error)).into_response();
         }
     };
+    let country_arg = match params.country.as_deref() {
+        Some(country) => {
+            let region = match country.parse::<Region>() {
+                Ok(r) => r,
+                Err(_) => {
+                    let error = serde_json::json!({
+                        "error": format!("Invalid country: '{}'. Use a two-letter code such as JP, GB, DE, or IN", country),
+                        "status": 400
+                    });
+                    return (StatusCode::BAD_REQUEST, Json(error)).into_response();
+                }
+            };
+            if st.regional(region).is_none() {
+                let error = serde_json::json!({
+                    "error": format!("Screener '{}' has no regional version. Valid types with country: day-gainers, day-losers, most-actives", screener),
+                    "status": 400
+                });
+                return (StatusCode::BAD_REQUEST, Json(error)).into_response();
+            }
+            format!(", country: \"{}\"", region.region())
+        }
+        None => String::new(),
+    };
     let gql_type = st.as_scr_id().to_uppercase();
-    let gql_format = format_to_gql(params.format.as_deref());
+    let format = parse_format(params.format.as_deref());
+    let format_options = parse_format_options(params.format.as_deref());
+    let gql_format = gql_format(format, &format_options);
     let selection = build_rest_composite_selection(
         params.fields.as_deref(),
         GQL_SCREENER_RESULTS_VALID_FIELDS,
@@ -116,20 +134,25 @@ pub(crate) async fn get_screeners(
     );
 
     let query = format!(
-        "query {{ screener(type: {}, count: {}, format: {}) {} }}",
-        gql_type, params.count, gql_format, selection
+        "query {{ screener(type: {}, count: {}, format: {}{}) {} }}",
+        gql_type, params.count, gql_format, country_arg, selection
     );
 
     info!(
-        "Fetching {} screener (count={}, format={:?}, fields={:?})",
-        screener, params.count, params.format, params.fields
+        "Fetching {} screener (count={}, country={:?}, format={:?}, fields={:?})",
+        screener, params.count, params.country, params.format, params.fields
     );
 
     let data = match execute_gql_rest(&schema, &query, Variables::default()).await {
         Ok(d) => d,
         Err(resp) => return resp,
     };
-    (StatusCode::OK, Json(unwrap_field(data, "screener"))).into_response()
+    let results = unwrap_field(data, "screener");
+    (
+        StatusCode::OK,
+        Json(apply_format_options(results, format, &format_options)),
+    )
+        .into_response()
 }
 
 /// POST /v2/screeners/custom
@@ -161,7 +184,9 @@ pub(crate) async fn post_custom_screener(
         .as_deref()
         .map(|s| s.to_lowercase() == "asc")
         .unwrap_or(false);
-    let gql_format = format_to_gql(body.format.as_deref());
+    let format = parse_format(body.format.as_deref());
+    let format_options = parse_format_options(body.format.as_deref());
+    let gql_format = gql_format(format, &format_options);
     let selection = build_rest_composite_selection(
         body.fields.as_deref(),
         GQL_SCREENER_RESULTS_VALID_FIELDS,
@@ -207,5 +232,10 @@ pub(crate) async fn post_custom_screener(
         Ok(d) => d,
         Err(resp) => return resp,
     };
-    (StatusCode::OK, Json(unwrap_field(data, "customScreener"))).into_response()
+    let results = unwrap_field(data, "customScreener");
+    (
+        StatusCode::OK,
+        Json(apply_format_options(results, format, &format_options)),
+    )
+        .into_response()
 }
@@ -16,7 +16,7 @@ use serde::Deserialize;
 use tracing::info;
 
 use super::gql_bridge::{build_rest_composite_selection, execute_gql_rest};
-use super::support::parse_format;
+use super::support::{apply_format_options, gql_format, parse_format, parse_format_options};
 
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -40,12 +40,9 @@ pub(crate) async fn get_sector(
     headers: HeaderMap,
 ) -> impl IntoResponse {
     let format = parse_format(params.format.as_deref());
+    let format_options = parse_format_options(params.format.as_deref());
     let lang = lang::resolve_lang(params.lang.as_deref(), &headers);
-    let gql_format = match format {
-        finance_query::ValueFormat::Raw => "RAW",
-        finance_query::ValueFormat::Pretty => "PRETTY",
-        finance_query::ValueFormat::Both => "BOTH",
-    };
+    let gql_format = gql_format(format, &format_options);
     let selection = build_rest_composite_selection(
         params.fields.as_deref(),
         GQL_SECTOR_VALID_FIELDS,
@@ -69,7 +66,12 @@ pub(crate) async fn get_sector(
         Ok(d) => d,
         Err(resp) => return resp,
     };
-    (StatusCode::OK, Json(unwrap_field(data, "sector"))).into_response()
+    let sector = unwrap_field(data, "sector");
+    (
+        StatusCode::OK,
+        Json(apply_format_options(sector, format, &format_options)),
+    )
+        .into_response()
 }
 
 /// GET /v2/industries/{industry}
@@ -80,12 +82,9 @@ pub(crate) async fn get_industry(
     headers: HeaderMap,
 ) -> impl IntoResponse {
     let format = parse_format(params.format.as_deref());
+    let format_options = parse_format_options(params.format.as_deref());
     let lang = lang::resolve_lang(params.lang.as_deref(), &headers);
-    let gql_format = match format {
-        finance_query::ValueFormat::Raw => "RAW",
-        finance_query::ValueFormat::Pretty => "PRETTY",
-        finance_query::ValueFormat::Both => "BOTH",
-    };
+    let gql_format = gql_format(format, &format_options);
     let selection = build_rest_composite_selection(
         params.fields.as_deref(),
         GQL_INDUSTRY_VALID_FIELDS,
@@ -107,5 +106,10 @@ pub(crate) async fn get_industry(
         Ok(d) => d,
         Err(resp) => return resp,
     };
-    (StatusCode::OK, Json(unwrap_field(data, "industry"))).into_response()
+    let industry = unwrap_field(data, "industry");
+    (
+        StatusCode::OK,
+        Json(apply_format_options(industry, format, &format_options)),
+    )
+        .into_response()
 }
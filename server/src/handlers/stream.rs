@@ -12,6 +12,10 @@
 //! {"unsubscribe": ["AAPL"]}
 //! ```
 //!
+//! Both commands can be sent at any time after the initial subscription to
+//! change the subscription without reconnecting. Symbols are trimmed and
+//! uppercased to match the `id` of price updates.
+//!
 //! **Receive price updates:**
 //! ```json
 //! {
@@ -176,7 +180,7 @@ async fn handle_stream_socket(state: AppState, mut socket: WebSocket) {
                         metrics::WEBSOCKET_MESSAGES_RECEIVED.inc();
                         info!("Received stream command: {:?}", cmd);
 
-                        if let Some(symbols) = cmd.subscribe {
+                        if let Some(symbols) = cmd.subscribe.map(normalize_symbols) {
                             let mut newly_added: Vec<String> = Vec::new();
                             {
                                 let mut subs = subscriptions_for_recv.write().await;
@@ -214,7 +218,7 @@ async fn handle_stream_socket(state: AppState, mut socket: WebSocket) {
                             }
                         }
 
-                        if let Some(symbols) = cmd.unsubscribe {
+                        if let Some(symbols) = cmd.unsubscribe.map(normalize_symbols) {
                             let mut removed: Vec<String> = Vec::new();
                             {
                                 let mut subs = subscriptions_for_recv.write().await;
@@ -276,8 +280,33 @@ async fn wait_for_subscription(socket: &mut WebSocket) -> Option<Vec<String>> {
             && let Ok(cmd) = serde_json::from_str::<StreamCommand>(&text)
             && let Some(symbols) = cmd.subscribe
         {
-            return Some(symbols);
+            return Some(normalize_symbols(symbols));
         }
     }
     None
 }
+
+/// Trim and uppercase symbols so they match price update ids, dropping blanks.
+fn normalize_symbols(symbols: Vec<String>) -> Vec<String> {
+    symbols
+        .into_iter()
+        .map(|s| s.trim().to_uppercase())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_symbols() {
+        let cmd: StreamCommand =
+            serde_json::from_str(r#"{"subscribe": [" aapl", "NVDA", "", "brk-b"]}"#).unwrap();
+        assert_eq!(
+            normalize_symbols(cmd.subscribe.unwrap()),
+            vec!["AAPL", "NVDA", "BRK-B"]
+        );
+        assert!(cmd.unsubscribe.is_none());
+    }
+}
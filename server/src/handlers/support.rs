@@ -1,11 +1,53 @@
 //! Shared REST helpers used across multiple `handlers/*` domain modules:
 //! query-param parsing.
 
-use finance_query::ValueFormat;
+use finance_query::{FormatOptions, ValueFormat};
 
 /// Parse format query parameter into ValueFormat
+///
+/// Accepts `raw|pretty|both`, optionally followed by display options after a
+/// colon (see [`parse_format_options`]).
 pub(crate) fn parse_format(s: Option<&str>) -> ValueFormat {
-    s.and_then(ValueFormat::parse).unwrap_or_default()
+    s.map(|s| s.split_once(':').map_or(s, |(f, _)| f))
+        .and_then(ValueFormat::parse)
+        .unwrap_or_default()
+}
+
+/// Parse the display options of a format parameter such as
+/// `pretty:decimals=1,locale=de-DE,percent=bp,units=full`.
+///
+/// Missing or invalid options fall back to Yahoo's own formatting.
+pub(crate) fn parse_format_options(s: Option<&str>) -> FormatOptions {
+    s.and_then(|s| s.split_once(':'))
+        .and_then(|(_, opts)| opts.parse().ok())
+        .unwrap_or_default()
+}
+
+/// GraphQL format enum to request for a REST format.
+///
+/// Asks for `BOTH` when display options are set, since re-rendering needs
+/// the raw values; [`apply_format_options`] narrows the result afterwards.
+pub(crate) fn gql_format(format: ValueFormat, options: &FormatOptions) -> &'static str {
+    match format {
+        ValueFormat::Raw => "RAW",
+        _ if !options.is_default() => "BOTH",
+        ValueFormat::Pretty => "PRETTY",
+        ValueFormat::Both => "BOTH",
+    }
+}
+
+/// Re-render formatted strings with `options` and narrow to `format`.
+///
+/// No-op unless [`gql_format`] upgraded the request to `BOTH`.
+pub(crate) fn apply_format_options(
+    value: serde_json::Value,
+    format: ValueFormat,
+    options: &FormatOptions,
+) -> serde_json::Value {
+    if options.is_default() || format == ValueFormat::Raw {
+        return value;
+    }
+    format.transform_with(value, options)
 }
 
 /// Default chart interval, overridable via `DEFAULT_INTERVAL` env var.
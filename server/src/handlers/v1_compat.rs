@@ -0,0 +1,313 @@
+//! `/v1` compatibility shim for clients of the Python finance-query server.
+//!
+//! Each legacy route is rewritten to its `/v2` equivalent (path and query
+//! params) and dispatched through the v2 router, so responses are v2-shaped.
+//! Legacy params with a v2 counterpart are renamed; any other params pass
+//! through untouched, which lets clients adopt v2 options before switching
+//! paths. Every response carries `Deprecation: true`, and forwarded responses
+//! also carry a `Link` to the successor route. Routes with no v2 equivalent
+//! answer 410 Gone with a pointer to the replacement.
+
+use axum::{
+    Json, Router,
+    extract::Request,
+    http::{HeaderValue, StatusCode, Uri},
+    response::{IntoResponse, Response},
+};
+use tower::ServiceExt;
+use tracing::info;
+use url::form_urlencoded;
+
+use super::api_routes;
+
+/// Where a legacy request goes.
+#[derive(Debug, PartialEq, Eq)]
+enum Rewrite {
+    /// v2 path and query, relative to `/v2`
+    To(String),
+    /// No v2 equivalent; the message names the replacement
+    Gone(&'static str),
+}
+
+/// Router for `/v1`: every path goes through the rewrite table.
+pub(crate) fn v1_routes() -> Router {
+    let v2 = api_routes();
+    Router::new().fallback(move |req: Request| forward(v2.clone(), req))
+}
+
+async fn forward(v2: Router, req: Request) -> Response {
+    let params: Vec<(String, String)> =
+        form_urlencoded::parse(req.uri().query().unwrap_or_default().as_bytes())
+            .into_owned()
+            .collect();
+    let path = req.uri().path().to_string();
+
+    let mut response = match rewrite(&path, params) {
+        Some(Rewrite::To(target)) => {
+            info!("Forwarding legacy /v1{} to /v2{}", path, target);
+            let Ok(uri) = target.parse::<Uri>() else {
+                return error(StatusCode::BAD_REQUEST, "Invalid legacy request".into());
+            };
+            let (mut parts, body) = req.into_parts();
+            parts.uri = uri;
+            let mut response = match v2.oneshot(Request::from_parts(parts, body)).await {
+                Ok(response) => response,
+                Err(never) => match never {},
+            };
+            if let Ok(link) =
+                HeaderValue::from_str(&format!("</v2{target}>; rel=\"successor-version\""))
+            {
+                response.headers_mut().insert("link", link);
+            }
+            response
+        }
+        Some(Rewrite::Gone(replacement)) => error(
+            StatusCode::GONE,
+            format!("/v1{path} has no v2 equivalent. {replacement}"),
+        ),
+        None => error(
+            StatusCode::NOT_FOUND,
+            format!("Unknown v1 route: /v1{path}"),
+        ),
+    };
+    response
+        .headers_mut()
+        .insert("deprecation", HeaderValue::from_static("true"));
+    response
+}
+
+fn error(status: StatusCode, message: String) -> Response {
+    let body = serde_json::json!({ "error": message, "status": status.as_u16() });
+    (status, Json(body)).into_response()
+}
+
+/// Map a legacy path (relative to `/v1`) and its query params to a v2 route.
+///
+/// Returns `None` for paths the Python server never exposed.
+fn rewrite(path: &str, mut params: Vec<(String, String)>) -> Option<Rewrite> {
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+    let target = match segments.as_slice() {
+        ["quotes"] => "/quotes".to_string(),
+        ["simple-quotes"] => {
+            params.push((
+                "fields".into(),
+                "symbol,shortName,regularMarketPrice,regularMarketChange,regularMarketChangePercent"
+                    .into(),
+            ));
+            "/quotes".to_string()
+        }
+        ["historical"] => {
+            let symbol = take(&mut params, "symbol")?;
+            take(&mut params, "epoch");
+            format!("/chart/{}", encode(&symbol))
+        }
+        ["actives"] => "/screeners/most-actives".to_string(),
+        ["gainers"] => "/screeners/day-gainers".to_string(),
+        ["losers"] => "/screeners/day-losers".to_string(),
+        ["similar"] => {
+            let symbol = take(&mut params, "symbol")?;
+            format!("/recommendations/{}", encode(&symbol))
+        }
+        ["news"] => match take(&mut params, "symbol") {
+            Some(symbol) => format!("/news/{}", encode(&symbol)),
+            None => "/news".to_string(),
+        },
+        ["indices"] => {
+            take(&mut params, "index");
+            take(&mut params, "region");
+            "/indices".to_string()
+        }
+        ["search"] => {
+            take(&mut params, "type");
+            take(&mut params, "yahoo");
+            rename(&mut params, "query", "q");
+            rename(&mut params, "hits", "quotes");
+            "/search".to_string()
+        }
+        ["indicators"] => {
+            let symbol = take(&mut params, "symbol")?;
+            take(&mut params, "epoch");
+            format!("/indicators/{}", encode(&symbol))
+        }
+        ["indicator"] => {
+            let symbol = take(&mut params, "symbol")?;
+            let function = take(&mut params, "function")?;
+            let name = indicator_name(&function)?;
+            let indicator = match take(&mut params, "lookBackPeriod") {
+                Some(period) => format!("{name}:{period}"),
+                None => name.to_string(),
+            };
+            take(&mut params, "epoch");
+            params.push(("indicators".into(), indicator));
+            format!("/indicators/{}", encode(&symbol))
+        }
+        ["financials", symbol] => {
+            let statement = take(&mut params, "statement")?;
+            format!("/financials/{symbol}/{}", encode(&statement))
+        }
+        ["holders", symbol, holder_type] => format!("/holders/{symbol}/{holder_type}"),
+        ["analysis", _, "price-targets"] => {
+            return Some(Rewrite::Gone(
+                "Price targets are quote fields: use /v2/quote/{symbol}.",
+            ));
+        }
+        ["analysis", _, "revenue-estimate"] => {
+            return Some(Rewrite::Gone(
+                "Use /v2/analysis/{symbol}/earnings-estimate.",
+            ));
+        }
+        ["analysis", symbol, analysis_type] => format!("/analysis/{symbol}/{analysis_type}"),
+        ["earnings-transcript", symbol] => format!("/transcripts/{symbol}/all"),
+        ["earnings-transcript", symbol, "latest"] => format!("/transcripts/{symbol}"),
+        ["earnings-transcript", symbol, quarter, year] => {
+            params.push(("quarter".into(), quarter.to_string()));
+            params.push(("year".into(), year.to_string()));
+            format!("/transcripts/{symbol}")
+        }
+        ["sectors", "details", sector] => {
+            let decoded = form_urlencoded::parse(format!("s={sector}").as_bytes())
+                .next()
+                .map(|(_, v)| v.into_owned())
+                .unwrap_or_default();
+            let slug = decoded.trim().to_lowercase().replace(' ', "-");
+            format!("/sectors/{}", encode(&slug))
+        }
+        ["sectors"] | ["sectors", "symbol", _] => {
+            return Some(Rewrite::Gone("Use /v2/sectors/{sector}."));
+        }
+        ["stream", "quotes"] => {
+            return Some(Rewrite::Gone(
+                "Use the /v2/stream WebSocket for real-time quotes.",
+            ));
+        }
+        _ => return None,
+    };
+
+    if params.is_empty() {
+        return Some(Rewrite::To(target));
+    }
+    let query = form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(&params)
+        .finish();
+    Some(Rewrite::To(format!("{target}?{query}")))
+}
+
+/// Remove and return the first value of `key`.
+fn take(params: &mut Vec<(String, String)>, key: &str) -> Option<String> {
+    let index = params.iter().position(|(k, _)| k == key)?;
+    Some(params.remove(index).1)
+}
+
+/// Rename every `from` param to `to`.
+fn rename(params: &mut [(String, String)], from: &str, to: &str) {
+    for (key, _) in params.iter_mut().filter(|(k, _)| k == from) {
+        *key = to.to_string();
+    }
+}
+
+/// Percent-encode a query value moved into a path segment.
+fn encode(segment: &str) -> String {
+    form_urlencoded::byte_serialize(segment.as_bytes()).collect()
+}
+
+/// v2 indicator name for a v1 `function` value.
+fn indicator_name(function: &str) -> Option<&'static str> {
+    Some(match function.to_uppercase().as_str() {
+        "SMA" => "sma",
+        "EMA" => "ema",
+        "WMA" => "wma",
+        "VWMA" => "vwma",
+        "RSI" => "rsi",
+        "SRSI" => "stochastic_rsi",
+        "STOCH" => "stochastic",
+        "CCI" => "cci",
+        "OBV" => "obv",
+        "BBANDS" => "bollinger_bands",
+        "AROON" => "aroon",
+        "ADX" => "adx",
+        "MACD" => "macd",
+        "SUPERTREND" => "supertrend",
+        "ICHIMOKU" => "ichimoku",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rewrite_uri(uri: &str) -> Option<Rewrite> {
+        let (path, query) = uri.split_once('?').unwrap_or((uri, ""));
+        let params = form_urlencoded::parse(query.as_bytes())
+            .into_owned()
+            .collect();
+        rewrite(path, params)
+    }
+
+    fn to(target: &str) -> Option<Rewrite> {
+        Some(Rewrite::To(target.to_string()))
+    }
+
+    #[test]
+    fn test_rewrite_renames_paths_and_params() {
+        assert_eq!(
+            rewrite_uri("/historical?symbol=AAPL&range=1y&interval=1d&epoch=true"),
+            to("/chart/AAPL?range=1y&interval=1d")
+        );
+        assert_eq!(
+            rewrite_uri("/historical?symbol=%5EGSPC&range=5d&interval=1h"),
+            to("/chart/%5EGSPC?range=5d&interval=1h")
+        );
+        assert_eq!(
+            rewrite_uri("/search?query=apple&hits=10"),
+            to("/search?q=apple&quotes=10")
+        );
+        assert_eq!(
+            rewrite_uri("/financials/MSFT?statement=income&frequency=quarterly"),
+            to("/financials/MSFT/income?frequency=quarterly")
+        );
+        assert_eq!(
+            rewrite_uri("/indicator?function=BBANDS&symbol=NVDA&lookBackPeriod=20"),
+            to("/indicators/NVDA?indicators=bollinger_bands%3A20")
+        );
+        assert_eq!(
+            rewrite_uri("/earnings-transcript/AAPL/Q1/2024"),
+            to("/transcripts/AAPL?quarter=Q1&year=2024")
+        );
+        assert_eq!(
+            rewrite_uri("/earnings-transcript/AAPL"),
+            to("/transcripts/AAPL/all")
+        );
+        assert_eq!(rewrite_uri("/news"), to("/news"));
+        assert_eq!(rewrite_uri("/news?symbol=TSLA"), to("/news/TSLA"));
+        assert_eq!(
+            rewrite_uri("/gainers?count=25"),
+            to("/screeners/day-gainers?count=25")
+        );
+        assert_eq!(
+            rewrite_uri("/sectors/details/Basic%20Materials"),
+            to("/sectors/basic-materials")
+        );
+        // Unmapped params pass through for v2 options
+        assert_eq!(
+            rewrite_uri("/quotes?symbols=AAPL,MSFT&format=pretty"),
+            to("/quotes?symbols=AAPL%2CMSFT&format=pretty")
+        );
+    }
+
+    #[test]
+    fn test_rewrite_gone_and_unknown() {
+        assert!(matches!(
+            rewrite_uri("/analysis/AAPL/price-targets"),
+            Some(Rewrite::Gone(_))
+        ));
+        assert!(matches!(
+            rewrite_uri("/stream/quotes?symbols=AAPL"),
+            Some(Rewrite::Gone(_))
+        ));
+        assert_eq!(rewrite_uri("/historical?range=1y"), None);
+        assert_eq!(rewrite_uri("/indicator?function=NOPE&symbol=AAPL"), None);
+        assert_eq!(rewrite_uri("/nonexistent"), None);
+    }
+}
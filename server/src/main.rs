@@ -21,6 +21,12 @@ mod handlers;
 /// Default server port, overridable via the `PORT` env var.
 const DEFAULT_SERVER_PORT: u16 = 8000;
 
+/// Default cap on a single upstream response body (64 MiB).
+const DEFAULT_MAX_RESPONSE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Default cap on decompressed/compressed size of an upstream body.
+const DEFAULT_MAX_DECOMPRESSION_RATIO: f64 = 100.0;
+
 #[tokio::main]
 async fn main() {
     // Load environment variables from .env file
@@ -69,6 +75,15 @@ async fn main() {
 }
 
 async fn create_app() -> Router {
+    // Bound upstream response bodies before any client is created
+    let response_limits = response_limits_from_env();
+    finance_query::ResponseLimits::set_default(response_limits);
+    info!(
+        "Upstream response limits: max_bytes={:?}, max_decompression_ratio={:?}",
+        response_limits.bytes_limit(),
+        response_limits.ratio_limit()
+    );
+
     // Initialize Redis cache (optional - falls back gracefully if not configured)
     let redis_url = std::env::var("REDIS_URL").ok();
     let cache = Cache::new(redis_url.as_deref()).await;
@@ -124,6 +139,8 @@ async fn create_app() -> Router {
     Router::new()
         // Nest all API routes under /v2
         .nest("/v2", handlers::api_routes())
+        // Legacy /v1 paths are rewritten onto the v2 handlers
+        .nest("/v1", handlers::v1_routes())
         // GraphQL endpoints at root (not under /v2 — different versioning story)
         .merge(graphql::graphql_routes(schema.clone()))
         .layer(Extension(schema))
@@ -199,3 +216,24 @@ async fn shutdown_signal() {
 
     info!("Shutting down gracefully...");
 }
+
+/// Read `MAX_RESPONSE_BYTES` and `MAX_DECOMPRESSION_RATIO`; `0` disables a limit.
+fn response_limits_from_env() -> finance_query::ResponseLimits {
+    let max_bytes = std::env::var("MAX_RESPONSE_BYTES")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_MAX_RESPONSE_BYTES);
+    let max_ratio = std::env::var("MAX_DECOMPRESSION_RATIO")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_MAX_DECOMPRESSION_RATIO);
+
+    let mut limits = finance_query::ResponseLimits::new();
+    if max_bytes > 0 {
+        limits = limits.max_bytes(max_bytes);
+    }
+    if max_ratio > 0.0 {
+        limits = limits.max_decompression_ratio(max_ratio);
+    }
+    limits
+}
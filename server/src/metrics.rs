@@ -143,11 +143,11 @@ lazy_static! {
     // === Cache Backend Metrics ===
 
     /// Whether the Redis cache backend is connected (1) or the server is
-    /// running uncached (0); the fallback is otherwise silent without this.
+    /// running on the in-process fallback (0); otherwise silent without this.
     pub static ref CACHE_BACKEND_CONNECTED: Gauge = Gauge::with_opts(
         Opts::new(
             "cache_backend_connected",
-            "1 when the Redis cache backend is connected, 0 when caching is disabled"
+            "1 when the Redis cache backend is connected, 0 when using the in-process cache"
         )
         .namespace("finance_query")
     )
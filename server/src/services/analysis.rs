@@ -78,6 +78,24 @@ pub async fn get_earnings_history(cache: &Cache, symbol: &str) -> ServiceResult
         .await
 }
 
+/// ESG risk scores; `null` when the symbol has no ESG coverage.
+pub async fn get_esg_scores(cache: &Cache, symbol: &str) -> ServiceResult {
+    let cache_key = Cache::key("esg", &[&symbol.to_uppercase()]);
+    let symbol = symbol.to_string();
+    cache
+        .get_or_fetch(
+            &cache_key,
+            cache::ttl::ANALYSIS,
+            cache::is_market_open(),
+            || async move {
+                let ticker = Ticker::new(&symbol).await?;
+                let data = ticker.esg_scores().await?;
+                serde_json::to_value(data).map_err(|e| Box::new(e) as ServiceError)
+            },
+        )
+        .await
+}
+
 pub async fn get_recommendations(cache: &Cache, symbol: &str, limit: u32) -> ServiceResult {
     let cache_key = Cache::key(
         "recommendations",
@@ -1,5 +1,6 @@
 use crate::cache::{self, Cache};
-use finance_query::{Tickers, TimeRange};
+use chrono::NaiveDate;
+use finance_query::{Region, Ticker, Tickers, TimeRange, finance};
 use tracing::info;
 
 use super::{ServiceError, ServiceResult};
@@ -32,3 +33,114 @@ pub async fn get_calendar(
         )
         .await
 }
+
+/// Next earnings date(s), analyst estimate ranges, and dividend dates for one
+/// symbol; `null` when Yahoo has no calendar data for it.
+pub async fn get_key_dates(cache: &Cache, symbol: &str) -> ServiceResult {
+    let cache_key = Cache::key("key_dates", &[&symbol.to_uppercase()]);
+    let symbol = symbol.to_string();
+
+    cache
+        .get_or_fetch(
+            &cache_key,
+            cache::ttl::ANALYSIS,
+            cache::is_market_open(),
+            || async move {
+                let ticker = Ticker::new(&symbol).await?;
+                let dates = ticker.key_dates().await?;
+                serde_json::to_value(&dates).map_err(|e| Box::new(e) as ServiceError)
+            },
+        )
+        .await
+}
+
+/// US companies reporting earnings between `start` and `end` (inclusive),
+/// with consensus EPS estimates.
+pub async fn get_earnings_calendar(
+    cache: &Cache,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> ServiceResult {
+    let cache_key = Cache::key("earnings_calendar", &[&start.to_string(), &end.to_string()]);
+
+    cache
+        .get_or_fetch(
+            &cache_key,
+            cache::ttl::ANALYSIS,
+            cache::is_market_open(),
+            || async move {
+                let entries = finance::earnings_calendar(start, end).await?;
+                info!(
+                    "Earnings calendar fetch complete: {} entries",
+                    entries.len()
+                );
+                serde_json::to_value(&entries).map_err(|e| Box::new(e) as ServiceError)
+            },
+        )
+        .await
+}
+
+/// IPOs scheduled between `start` and `end` (inclusive), with price ranges
+/// and deal status.
+pub async fn get_ipo_calendar(cache: &Cache, start: NaiveDate, end: NaiveDate) -> ServiceResult {
+    let cache_key = Cache::key("ipo_calendar", &[&start.to_string(), &end.to_string()]);
+
+    cache
+        .get_or_fetch(
+            &cache_key,
+            cache::ttl::ANALYSIS,
+            cache::is_market_open(),
+            || async move {
+                let entries = finance::ipo_calendar(start, end).await?;
+                info!("IPO calendar fetch complete: {} entries", entries.len());
+                serde_json::to_value(&entries).map_err(|e| Box::new(e) as ServiceError)
+            },
+        )
+        .await
+}
+
+/// Stock splits taking effect between `start` and `end` (inclusive).
+pub async fn get_splits_calendar(cache: &Cache, start: NaiveDate, end: NaiveDate) -> ServiceResult {
+    let cache_key = Cache::key("splits_calendar", &[&start.to_string(), &end.to_string()]);
+
+    cache
+        .get_or_fetch(
+            &cache_key,
+            cache::ttl::ANALYSIS,
+            cache::is_market_open(),
+            || async move {
+                let entries = finance::splits_calendar(start, end).await?;
+                info!("Splits calendar fetch complete: {} entries", entries.len());
+                serde_json::to_value(&entries).map_err(|e| Box::new(e) as ServiceError)
+            },
+        )
+        .await
+}
+
+/// Macroeconomic releases between `start` and `end` (inclusive), optionally
+/// limited to one country.
+pub async fn get_economic_calendar(
+    cache: &Cache,
+    region: Option<Region>,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> ServiceResult {
+    let country = region.map_or("all", |r| r.region());
+    let cache_key = Cache::key(
+        "economic_calendar",
+        &[country, &start.to_string(), &end.to_string()],
+    );
+
+    cache
+        .get_or_fetch(
+            &cache_key,
+            cache::ttl::ANALYSIS,
+            cache::is_market_open(),
+            || async move {
+                let events = finance::economic_calendar(region, start, end).await?;
+                info!("Economic calendar fetch complete: {} events", events.len());
+                serde_json::to_value(&events).map_err(|e| Box::new(e) as ServiceError)
+            },
+        )
+        .await
+}
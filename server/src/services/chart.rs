@@ -1,5 +1,5 @@
 use crate::cache::{self, Cache};
-use finance_query::{Interval, Ticker, Tickers, TimeRange};
+use finance_query::{CapitalGain, Chart, Dividend, Interval, Split, Ticker, Tickers, TimeRange};
 use tracing::info;
 
 use super::{ServiceError, ServiceResult};
@@ -11,6 +11,44 @@ fn now_unix_secs() -> i64 {
         .as_secs() as i64
 }
 
+/// Insert the raw `dividends`/`splits`/`capitalGains` lists plus the
+/// candle-aligned `events` list into a serialized chart.
+fn insert_events(
+    map: &mut serde_json::Map<String, serde_json::Value>,
+    chart: Chart,
+    dividends: Option<Vec<Dividend>>,
+    splits: Option<Vec<Split>>,
+    capital_gains: Option<Vec<CapitalGain>>,
+) {
+    let annotated = chart.annotate(
+        dividends.as_deref().unwrap_or_default(),
+        splits.as_deref().unwrap_or_default(),
+        capital_gains.as_deref().unwrap_or_default(),
+    );
+    map.insert(
+        "events".to_string(),
+        serde_json::to_value(annotated.events).unwrap_or_default(),
+    );
+    if let Some(dividends) = dividends {
+        map.insert(
+            "dividends".to_string(),
+            serde_json::to_value(dividends).unwrap_or_default(),
+        );
+    }
+    if let Some(splits) = splits {
+        map.insert(
+            "splits".to_string(),
+            serde_json::to_value(splits).unwrap_or_default(),
+        );
+    }
+    if let Some(capital_gains) = capital_gains {
+        map.insert(
+            "capitalGains".to_string(),
+            serde_json::to_value(capital_gains).unwrap_or_default(),
+        );
+    }
+}
+
 /// Fetch chart data, optionally with absolute date boundaries.
 ///
 /// When `start` is `Some`, uses `chart_range` with absolute timestamps (not cached).
@@ -49,24 +87,7 @@ pub async fn get_chart(
                 ticker.splits(TimeRange::Max),
                 ticker.capital_gains(TimeRange::Max),
             );
-            if let Ok(dividends) = div_res {
-                map.insert(
-                    "dividends".to_string(),
-                    serde_json::to_value(dividends).unwrap_or_default(),
-                );
-            }
-            if let Ok(splits) = splits_res {
-                map.insert(
-                    "splits".to_string(),
-                    serde_json::to_value(splits).unwrap_or_default(),
-                );
-            }
-            if let Ok(capital_gains) = cg_res {
-                map.insert(
-                    "capitalGains".to_string(),
-                    serde_json::to_value(capital_gains).unwrap_or_default(),
-                );
-            }
+            insert_events(map, chart, div_res.ok(), splits_res.ok(), cg_res.ok());
         }
 
         return Ok(json);
@@ -107,24 +128,10 @@ pub async fn get_chart(
                 }
 
                 if events && let serde_json::Value::Object(ref mut map) = json {
-                    if let Ok(dividends) = ticker.dividends(range).await {
-                        map.insert(
-                            "dividends".to_string(),
-                            serde_json::to_value(dividends).unwrap_or_default(),
-                        );
-                    }
-                    if let Ok(splits) = ticker.splits(range).await {
-                        map.insert(
-                            "splits".to_string(),
-                            serde_json::to_value(splits).unwrap_or_default(),
-                        );
-                    }
-                    if let Ok(capital_gains) = ticker.capital_gains(range).await {
-                        map.insert(
-                            "capitalGains".to_string(),
-                            serde_json::to_value(capital_gains).unwrap_or_default(),
-                        );
-                    }
+                    let dividends = ticker.dividends(range).await.ok();
+                    let splits = ticker.splits(range).await.ok();
+                    let capital_gains = ticker.capital_gains(range).await.ok();
+                    insert_events(map, chart, dividends, splits, capital_gains);
                 }
                 Ok(json)
             },
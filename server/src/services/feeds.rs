@@ -24,6 +24,7 @@ const FEED_SOURCE_SLUGS: &[&str] = &[
     "wsj",
     "fortune",
     "businesswire",
+    "prnewswire",
     "coindesk",
     "cointelegraph",
     "techcrunch",
@@ -63,6 +64,7 @@ fn parse_source_slug(slug: &str, form_type: Option<&str>) -> Option<FeedSource>
         "wsj" | "wsjmarkets" => Some(FeedSource::WsjMarkets),
         "fortune" => Some(FeedSource::Fortune),
         "businesswire" => Some(FeedSource::BusinessWire),
+        "prnewswire" => Some(FeedSource::PrNewswire),
         "coindesk" => Some(FeedSource::CoinDesk),
         "cointelegraph" => Some(FeedSource::CoinTelegraph),
         "techcrunch" => Some(FeedSource::TechCrunch),
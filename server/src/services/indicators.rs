@@ -1,5 +1,5 @@
 use crate::cache::{self, Cache};
-use finance_query::{Interval, Ticker, Tickers, TimeRange};
+use finance_query::{IndicatorSelection, Interval, Ticker, Tickers, TimeRange};
 use tracing::info;
 
 use super::{ServiceError, ServiceResult};
@@ -11,11 +11,17 @@ pub async fn get_indicators(
     interval_str: &str,
     range: TimeRange,
     range_str: &str,
+    selection: Option<IndicatorSelection>,
+    patterns: bool,
 ) -> ServiceResult {
-    let cache_key = Cache::key(
-        "indicators",
-        &[&symbol.to_uppercase(), interval_str, range_str],
-    );
+    let symbol_key = symbol.to_uppercase();
+    let selection_key = selection.as_ref().map(|s| s.to_string());
+    let mut key_parts = vec![symbol_key.as_str(), interval_str, range_str];
+    key_parts.extend(selection_key.as_deref());
+    if patterns {
+        key_parts.push("patterns");
+    }
+    let cache_key = Cache::key("indicators", &key_parts);
     let symbol = symbol.to_string();
 
     cache
@@ -25,9 +31,26 @@ pub async fn get_indicators(
             cache::is_market_open(),
             || async move {
                 let ticker = Ticker::new(&symbol).await?;
-                let indicators = ticker.indicators(interval, range).await?;
+                let indicators = match selection {
+                    Some(selection) => {
+                        ticker
+                            .indicators_selected(selection, interval, range)
+                            .await?
+                    }
+                    None => ticker.indicators(interval, range).await?,
+                };
                 info!("Successfully calculated indicators for {}", symbol);
-                serde_json::to_value(&indicators).map_err(|e| Box::new(e) as ServiceError)
+                let mut json =
+                    serde_json::to_value(&indicators).map_err(|e| Box::new(e) as ServiceError)?;
+                if patterns && let serde_json::Value::Object(ref mut map) = json {
+                    // Served from the ticker's chart cache populated above
+                    let chart = ticker.chart(interval, range).await?;
+                    map.insert(
+                        "patterns".to_string(),
+                        serde_json::to_value(chart.pattern_matches()).unwrap_or_default(),
+                    );
+                }
+                Ok(json)
             },
         )
         .await
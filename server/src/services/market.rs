@@ -113,8 +113,13 @@ pub async fn get_screener(
     screener: Screener,
     screener_str: &str,
     count: u32,
+    region: Option<Region>,
 ) -> ServiceResult {
-    let cache_key = Cache::key("screener", &[screener_str, &count.to_string()]);
+    let count_str = count.to_string();
+    let cache_key = match region {
+        Some(r) => Cache::key("screener", &[screener_str, &count_str, r.region()]),
+        None => Cache::key("screener", &[screener_str, &count_str]),
+    };
 
     cache
         .get_or_fetch(
@@ -122,7 +127,10 @@ pub async fn get_screener(
             cache::ttl::MOVERS,
             cache::is_market_open(),
             || async move {
-                let data = finance::screener(screener, count).await?;
+                let data = match region {
+                    Some(region) => finance::regional_screener(screener, region, count).await?,
+                    None => finance::screener(screener, count).await?,
+                };
                 serde_json::to_value(data).map_err(|e| Box::new(e) as ServiceError)
             },
         )
@@ -18,6 +18,7 @@ pub mod market;
 pub mod metadata;
 pub mod news;
 pub mod options;
+pub mod overview;
 pub mod quote;
 pub mod risk;
 pub mod screener;
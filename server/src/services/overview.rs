@@ -0,0 +1,260 @@
+//! Symbol-scoped composite document: several per-symbol sections fetched
+//! concurrently in one request.
+//!
+//! Sections reuse the cache keys of their standalone services (`quote`,
+//! `chart`, `news`, `analysis`), so an overview warms — and is served from —
+//! the same entries as the individual endpoints. Cache misses share one lazily
+//! built `Ticker`, so the quote and analysis sections resolve from a single
+//! quote-summary fetch and only one auth handshake is made. A failing section
+//! is reported under `errors` instead of failing the whole document.
+
+use std::str::FromStr;
+
+use crate::cache::{self, Cache};
+use finance_query::{Interval, Ticker, TimeRange};
+use serde_json::{Map, Value};
+use tokio::sync::OnceCell;
+
+use super::{ServiceError, ServiceResult, lang_key};
+
+/// One section of the overview document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverviewSection {
+    /// Aggregated quote (both raw and formatted values)
+    Quote,
+    /// OHLCV chart for the requested interval/range
+    Chart,
+    /// Recent news articles
+    News,
+    /// Analyst recommendation trend and upgrade/downgrade history
+    Analysis,
+}
+
+impl OverviewSection {
+    /// Every section, in document order.
+    pub const ALL: [OverviewSection; 4] = [
+        OverviewSection::Quote,
+        OverviewSection::Chart,
+        OverviewSection::News,
+        OverviewSection::Analysis,
+    ];
+
+    /// Key of the section in the composite document.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OverviewSection::Quote => "quote",
+            OverviewSection::Chart => "chart",
+            OverviewSection::News => "news",
+            OverviewSection::Analysis => "analysis",
+        }
+    }
+
+    /// Parse a comma-separated `include` list; `None` or empty selects every
+    /// section. Returns the first unknown name as the error.
+    pub fn parse_list(include: Option<&str>) -> Result<Vec<Self>, String> {
+        let Some(raw) = include.filter(|s| !s.trim().is_empty()) else {
+            return Ok(Self::ALL.to_vec());
+        };
+        let mut sections = Vec::new();
+        for name in raw.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let section: Self = name.parse().map_err(|_| name.to_string())?;
+            if !sections.contains(&section) {
+                sections.push(section);
+            }
+        }
+        Ok(sections)
+    }
+}
+
+impl FromStr for OverviewSection {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "quote" => Ok(OverviewSection::Quote),
+            "chart" => Ok(OverviewSection::Chart),
+            "news" => Ok(OverviewSection::News),
+            "analysis" => Ok(OverviewSection::Analysis),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Per-section parameters for [`get_overview`].
+#[derive(Debug, Clone, Copy)]
+pub struct OverviewParams {
+    /// Chart interval
+    pub interval: Interval,
+    /// Chart range
+    pub range: TimeRange,
+    /// Maximum news articles
+    pub news_count: usize,
+}
+
+/// Fetch the requested sections concurrently and assemble
+/// `{symbol, <section>: value | null, errors: {<section>: message}}`.
+///
+/// Only requested sections appear; a failed section is `null` with its
+/// message under `errors`. Never fails as a whole.
+pub async fn get_overview(
+    cache: &Cache,
+    symbol: &str,
+    sections: &[OverviewSection],
+    params: OverviewParams,
+    lang: Option<&str>,
+) -> ServiceResult {
+    let upper = symbol.to_uppercase();
+    let market_open = cache::is_market_open();
+    let ticker = OnceCell::new();
+    let wants = |section| sections.contains(&section);
+
+    let quote = async {
+        if !wants(OverviewSection::Quote) {
+            return None;
+        }
+        let key = Cache::key("quote", &[&upper, "0", lang_key(lang)]);
+        Some(
+            cache
+                .get_or_fetch(&key, cache::ttl::QUOTES, market_open, || async {
+                    let ticker = shared_ticker(&ticker, symbol, lang).await?;
+                    let quote = ticker.quote::<finance_query::format::Both>().await?;
+                    serde_json::to_value(&quote).map_err(|e| Box::new(e) as ServiceError)
+                })
+                .await,
+        )
+    };
+
+    let chart = async {
+        if !wants(OverviewSection::Chart) {
+            return None;
+        }
+        let key = Cache::key(
+            "chart",
+            &[
+                &upper,
+                params.interval.as_str(),
+                params.range.as_str(),
+                "0",
+                "0",
+            ],
+        );
+        Some(
+            cache
+                .get_or_fetch(&key, cache::ttl::CHART, market_open, || async {
+                    let ticker = shared_ticker(&ticker, symbol, lang).await?;
+                    let chart = ticker.chart(params.interval, params.range).await?;
+                    serde_json::to_value(&chart).map_err(|e| Box::new(e) as ServiceError)
+                })
+                .await,
+        )
+    };
+
+    let news = async {
+        if !wants(OverviewSection::News) {
+            return None;
+        }
+        let key = Cache::key("news", &[&upper, lang_key(lang)]);
+        let result = cache
+            .get_or_fetch(&key, cache::ttl::NEWS, market_open, || async {
+                let ticker = shared_ticker(&ticker, symbol, lang).await?;
+                let news = ticker.news().await?;
+                serde_json::to_value(&news).map_err(|e| Box::new(e) as ServiceError)
+            })
+            .await;
+        Some(result.map(|mut json| {
+            if let Value::Array(ref mut articles) = json {
+                articles.truncate(params.news_count);
+            }
+            json
+        }))
+    };
+
+    let analysis = async {
+        if !wants(OverviewSection::Analysis) {
+            return None;
+        }
+        let trend_key = Cache::key("analysis", &[&upper, "recommendations"]);
+        let grading_key = Cache::key("analysis", &[&upper, "upgrades-downgrades"]);
+        let (trend, grading) = tokio::join!(
+            cache.get_or_fetch(&trend_key, cache::ttl::ANALYSIS, market_open, || async {
+                let ticker = shared_ticker(&ticker, symbol, lang).await?;
+                let data = ticker.recommendation_trend().await?;
+                serde_json::to_value(data).map_err(|e| Box::new(e) as ServiceError)
+            }),
+            cache.get_or_fetch(&grading_key, cache::ttl::ANALYSIS, market_open, || async {
+                let ticker = shared_ticker(&ticker, symbol, lang).await?;
+                let data = ticker.grading_history().await?;
+                serde_json::to_value(data).map_err(|e| Box::new(e) as ServiceError)
+            }),
+        );
+        Some(match (trend, grading) {
+            (Ok(trend), Ok(grading)) => Ok(serde_json::json!({
+                "recommendationTrend": trend,
+                "upgradeDowngradeHistory": grading,
+            })),
+            (Err(e), _) | (_, Err(e)) => Err(e),
+        })
+    };
+
+    let (quote, chart, news, analysis) = tokio::join!(quote, chart, news, analysis);
+
+    let mut doc = Map::new();
+    doc.insert("symbol".to_string(), Value::String(upper));
+    let mut errors = Map::new();
+    for (section, result) in OverviewSection::ALL
+        .into_iter()
+        .zip([quote, chart, news, analysis])
+    {
+        match result {
+            None => {}
+            Some(Ok(value)) => {
+                doc.insert(section.as_str().to_string(), value);
+            }
+            Some(Err(e)) => {
+                doc.insert(section.as_str().to_string(), Value::Null);
+                errors.insert(section.as_str().to_string(), Value::String(e.to_string()));
+            }
+        }
+    }
+    doc.insert("errors".to_string(), Value::Object(errors));
+    Ok(Value::Object(doc))
+}
+
+/// Build the request's `Ticker` on first use; later sections reuse it.
+async fn shared_ticker<'a>(
+    cell: &'a OnceCell<Ticker>,
+    symbol: &str,
+    lang: Option<&str>,
+) -> Result<&'a Ticker, ServiceError> {
+    cell.get_or_try_init(|| async {
+        let builder = Ticker::builder(symbol);
+        let builder = match lang {
+            Some(lang) => builder.lang(lang),
+            None => builder,
+        };
+        builder.build().await
+    })
+    .await
+    .map_err(|e| Box::new(e) as ServiceError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_include_list() {
+        assert_eq!(
+            OverviewSection::parse_list(None).unwrap(),
+            OverviewSection::ALL
+        );
+        assert_eq!(
+            OverviewSection::parse_list(Some("news, Quote,news")).unwrap(),
+            [OverviewSection::News, OverviewSection::Quote]
+        );
+        assert_eq!(
+            OverviewSection::parse_list(Some("quote,bogus")),
+            Err("bogus".to_string())
+        );
+    }
+}
@@ -7,6 +7,7 @@ use reqwest::{Client, StatusCode};
 use serde_json::Value;
 use tracing::debug;
 
+use crate::adapters::pool::ConnectionPool;
 use crate::error::{FinanceError, Result};
 use crate::rate_limiter::RateLimiter;
 
@@ -42,7 +43,8 @@ impl AlphaVantageClientBuilder {
         self,
         limiter: Arc<RateLimiter>,
     ) -> Result<AlphaVantageClient> {
-        let http = Client::builder()
+        let http = ConnectionPool::default()
+            .apply(Client::builder())
             .timeout(self.timeout)
             .user_agent(format!(
                 "finance-query/{} (https://github.com/Verdenroz/finance-query)",
@@ -79,7 +81,10 @@ impl AlphaVantageClient {
         query.extend_from_slice(params);
 
         debug!("AlphaVantage request: function={function}");
-        let resp = self.http.get(&self.base_url).query(&query).send().await?;
+        let resp = crate::telemetry::track(
+            &self.base_url,
+            self.http.get(&self.base_url).query(&query).send().await,
+        )?;
 
         match resp.status() {
             StatusCode::OK => {}
@@ -138,7 +143,10 @@ impl AlphaVantageClient {
         query.extend_from_slice(params);
 
         debug!("AlphaVantage CSV request: function={function}");
-        let resp = self.http.get(&self.base_url).query(&query).send().await?;
+        let resp = crate::telemetry::track(
+            &self.base_url,
+            self.http.get(&self.base_url).query(&query).send().await,
+        )?;
 
         match resp.status() {
             StatusCode::OK => {}
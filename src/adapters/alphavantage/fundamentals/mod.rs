@@ -338,6 +338,8 @@ pub async fn earnings_calendar() -> Result<Vec<EarningsCalendarEntryDTO>> {
 }
 
 /// Fetch upcoming IPO calendar.
+///
+/// CSV columns: `symbol,name,ipoDate,priceRangeLow,priceRangeHigh,currency,exchange`.
 pub async fn ipo_calendar() -> Result<Vec<IpoCalendarEntryDTO>> {
     let client = build_client()?;
     let csv = client.get_csv("IPO_CALENDAR", &[]).await?;
@@ -353,8 +355,10 @@ pub async fn ipo_calendar() -> Result<Vec<IpoCalendarEntryDTO>> {
                 symbol: cols.first().map(|s| s.to_string()),
                 name: cols.get(1).map(|s| s.to_string()),
                 ipo_date: cols.get(2).map(|s| s.to_string()),
-                price_range: cols.get(3).map(|s| s.to_string()),
-                exchange: cols.get(4).map(|s| s.to_string()),
+                price_range_low: cols.get(3).and_then(|s| s.parse().ok()),
+                price_range_high: cols.get(4).and_then(|s| s.parse().ok()),
+                currency: cols.get(5).map(|s| s.to_string()),
+                exchange: cols.get(6).map(|s| s.to_string()),
             });
         }
     }
@@ -669,8 +669,12 @@ pub struct IpoCalendarEntryDTO {
     pub name: Option<String>,
     /// IPO date
     pub ipo_date: Option<String>,
-    /// Price range (e.g., `"$15-$17"`)
-    pub price_range: Option<String>,
+    /// Low end of the price range
+    pub price_range_low: Option<f64>,
+    /// High end of the price range
+    pub price_range_high: Option<f64>,
+    /// Currency
+    pub currency: Option<String>,
     /// Exchange
     pub exchange: Option<String>,
 }
@@ -526,6 +526,7 @@ pub async fn fetch_chart_response(
         interval: None,
         range: None,
         provider_id: Some(crate::Provider::AlphaVantage),
+        adjustment: None,
     })
 }
 
@@ -571,6 +572,7 @@ pub async fn fetch_chart_range_response(
         interval: None,
         range: None,
         provider_id: Some(crate::Provider::AlphaVantage),
+        adjustment: None,
     })
 }
 
@@ -9,6 +9,7 @@ use reqwest::{Client, StatusCode};
 use tracing::debug;
 
 use super::models::CoinQuote;
+use crate::adapters::pool::ConnectionPool;
 use crate::error::{FinanceError, Result};
 use crate::rate_limiter::RateLimiter;
 
@@ -23,7 +24,8 @@ pub(crate) struct CoinGeckoClient {
 
 impl CoinGeckoClient {
     pub fn new() -> Result<Self> {
-        let http = Client::builder()
+        let http = ConnectionPool::default()
+            .apply(Client::builder())
             .timeout(Duration::from_secs(30))
             .user_agent(format!(
                 "finance-query/{} (https://github.com/Verdenroz/finance-query)",
@@ -52,7 +54,7 @@ impl CoinGeckoClient {
         );
 
         debug!("CoinGecko request: coins(vs_currency={vs_currency}, count={count})");
-        let resp = self.http.get(&url).send().await?;
+        let resp = crate::telemetry::track(&url, self.http.get(&url).send().await)?;
         CoinGeckoClient::check_status(&resp)?;
         Ok(resp.json().await?)
     }
@@ -66,7 +68,7 @@ impl CoinGeckoClient {
         );
 
         debug!("CoinGecko request: coin(id={id})");
-        let resp = self.http.get(&url).send().await?;
+        let resp = crate::telemetry::track(&url, self.http.get(&url).send().await)?;
         CoinGeckoClient::check_status(&resp)?;
         let mut list: Vec<CoinQuote> = resp.json().await?;
 
@@ -4,6 +4,7 @@
 //! Handles the SEC-required User-Agent header and 10 req/sec rate limit internally.
 
 use super::endpoints as urls;
+use crate::adapters::pool::ConnectionPool;
 use crate::error::{FinanceError, Result};
 use crate::models::filings::{
     CompanyFacts, EdgarFilingIndex, EdgarSearchResults, EdgarSubmissions,
@@ -74,7 +75,8 @@ impl EdgarClientBuilder {
         let version = env!("CARGO_PKG_VERSION");
         let user_agent = format!("{}/{} ({})", self.app_name, version, self.email);
 
-        let http = reqwest::Client::builder()
+        let http = ConnectionPool::default()
+            .apply(reqwest::Client::builder())
             .user_agent(&user_agent)
             .timeout(self.timeout)
             .build()?;
@@ -103,7 +105,7 @@ impl EdgarClient {
     async fn get(&self, url: &str) -> Result<reqwest::Response> {
         self.rate_limiter.acquire().await;
         debug!("EDGAR GET {}", url);
-        let response = self.http.get(url).send().await?;
+        let response = crate::telemetry::track(url, self.http.get(url).send().await)?;
 
         let status = response.status();
         if !status.is_success() {
@@ -120,7 +122,7 @@ impl EdgarClient {
     ) -> Result<reqwest::Response> {
         self.rate_limiter.acquire().await;
         debug!("EDGAR GET {} (with params)", url);
-        let response = self.http.get(url).query(params).send().await?;
+        let response = crate::telemetry::track(url, self.http.get(url).query(params).send().await)?;
 
         let status = response.status();
         if !status.is_success() {
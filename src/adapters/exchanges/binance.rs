@@ -0,0 +1,211 @@
+//! Binance spot public REST API (`api.binance.com/api/v3`).
+
+use serde_json::Value;
+
+use super::{
+    MAX_TRADES, Pair, candle, get_json, number, structure_error, unsupported_interval, windows,
+};
+use crate::constants::Interval;
+use crate::error::Result;
+use crate::models::chart::Candle;
+use crate::models::crypto::{CryptoExchange, CryptoTrade, TradeSide};
+
+const BASE: &str = "https://api.binance.com/api/v3";
+/// Most klines Binance returns per request.
+const PER_PAGE: usize = 1000;
+
+/// Binance's symbol for a pair: base and quote concatenated (`BTCUSDT`).
+pub(super) fn symbol(pair: &Pair) -> String {
+    format!("{}{}", pair.base, pair.quote)
+}
+
+fn interval_code(interval: Interval) -> Option<&'static str> {
+    Some(match interval {
+        Interval::OneMinute => "1m",
+        Interval::FiveMinutes => "5m",
+        Interval::FifteenMinutes => "15m",
+        Interval::ThirtyMinutes => "30m",
+        Interval::OneHour => "1h",
+        Interval::FourHours => "4h",
+        Interval::OneDay => "1d",
+        Interval::OneWeek => "1w",
+        Interval::OneMonth => "1M",
+        _ => return None,
+    })
+}
+
+pub(super) async fn candles(
+    pair: &Pair,
+    interval: Interval,
+    start: i64,
+    end: i64,
+) -> Result<Vec<Candle>> {
+    let code = interval_code(interval).ok_or_else(|| {
+        unsupported_interval(
+            CryptoExchange::Binance,
+            interval,
+            "1m, 5m, 15m, 30m, 1h, 4h, 1d, 1wk, 1mo",
+        )
+    })?;
+    let symbol = symbol(pair);
+
+    let mut candles = Vec::new();
+    for (from, to) in windows(start, end, interval.approx_duration_secs(), PER_PAGE) {
+        let url = format!(
+            "{BASE}/klines?symbol={symbol}&interval={code}&startTime={}&endTime={}&limit={PER_PAGE}",
+            from * 1000,
+            to * 1000 + 999
+        );
+        let json = get_json(CryptoExchange::Binance, &url, pair).await?;
+        let page = parse_klines(&json).map_err(|e| structure_error(CryptoExchange::Binance, e))?;
+        if page.is_empty() {
+            // Walking backwards past the pair's listing date.
+            break;
+        }
+        candles.extend(page);
+    }
+    Ok(candles)
+}
+
+pub(super) async fn trades(pair: &Pair, limit: usize) -> Result<Vec<CryptoTrade>> {
+    let url = format!(
+        "{BASE}/trades?symbol={}&limit={}",
+        symbol(pair),
+        limit.min(MAX_TRADES)
+    );
+    let json = get_json(CryptoExchange::Binance, &url, pair).await?;
+    parse_trades(&json, pair).map_err(|e| structure_error(CryptoExchange::Binance, e))
+}
+
+/// Parse `/klines`: `[[openTime, open, high, low, close, volume, closeTime, ...], ...]`.
+fn parse_klines(json: &Value) -> std::result::Result<Vec<Candle>, String> {
+    let rows = json.as_array().ok_or("klines response is not an array")?;
+    rows.iter()
+        .map(|row| {
+            let field = |i: usize| row.get(i).and_then(number);
+            let open_ms = row.get(0).and_then(Value::as_i64);
+            match (open_ms, field(1), field(2), field(3), field(4), field(5)) {
+                (Some(t), Some(o), Some(h), Some(l), Some(c), Some(v)) => {
+                    Ok(candle(t / 1000, o, h, l, c, v))
+                }
+                _ => Err(format!("malformed kline: {row}")),
+            }
+        })
+        .collect()
+}
+
+/// Parse `/trades`: `[{id, price, qty, time, isBuyerMaker}, ...]`.
+fn parse_trades(json: &Value, pair: &Pair) -> std::result::Result<Vec<CryptoTrade>, String> {
+    let rows = json.as_array().ok_or("trades response is not an array")?;
+    rows.iter()
+        .map(|row| {
+            trade(
+                pair,
+                row.get("id").and_then(Value::as_u64),
+                row.get("price").and_then(number),
+                row.get("qty").and_then(number),
+                row.get("time").and_then(Value::as_i64),
+                row.get("isBuyerMaker").and_then(Value::as_bool),
+            )
+            .ok_or_else(|| format!("malformed trade: {row}"))
+        })
+        .collect()
+}
+
+/// Parse one combined-stream message (`{"stream": ..., "data": {"e": "trade", ...}}`),
+/// mapping Binance's symbol back to the subscribed pair.
+#[cfg(feature = "streaming")]
+pub(super) fn parse_stream_trade(text: &str, pairs: &[Pair]) -> Option<CryptoTrade> {
+    let msg: Value = serde_json::from_str(text).ok()?;
+    let data = msg.get("data").unwrap_or(&msg);
+    if data.get("e")?.as_str()? != "trade" {
+        return None;
+    }
+    let symbol = data.get("s")?.as_str()?;
+    let pair = pairs.iter().find(|p| self::symbol(p) == symbol)?;
+    trade(
+        pair,
+        data.get("t").and_then(Value::as_u64),
+        data.get("p").and_then(number),
+        data.get("q").and_then(number),
+        data.get("T").and_then(Value::as_i64),
+        data.get("m").and_then(Value::as_bool),
+    )
+}
+
+/// `buyer_is_maker` means the seller crossed the spread.
+fn trade(
+    pair: &Pair,
+    id: Option<u64>,
+    price: Option<f64>,
+    size: Option<f64>,
+    time_ms: Option<i64>,
+    buyer_is_maker: Option<bool>,
+) -> Option<CryptoTrade> {
+    Some(CryptoTrade {
+        exchange: CryptoExchange::Binance,
+        pair: pair.to_string(),
+        trade_id: id?,
+        price: price?,
+        size: size?,
+        side: buyer_is_maker.map(|m| if m { TradeSide::Sell } else { TradeSide::Buy }),
+        timestamp_ms: time_ms?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pair() -> Pair {
+        Pair::parse("BTC-USDT").unwrap()
+    }
+
+    #[test]
+    fn test_parse_klines() {
+        let json = serde_json::json!([[
+            1_700_000_000_000_i64,
+            "36500.00",
+            "36600.50",
+            "36450.10",
+            "36590.00",
+            "152.6",
+            1_700_003_599_999_i64,
+            "5571234.1",
+            4210,
+            "80.1",
+            "2925000.0",
+            "0"
+        ]]);
+        let candles = parse_klines(&json).unwrap();
+        assert_eq!(candles.len(), 1);
+        assert_eq!(candles[0].timestamp, 1_700_000_000);
+        assert_eq!(candles[0].high, 36600.5);
+        assert_eq!(candles[0].volume, 153);
+        assert!(parse_klines(&serde_json::json!([[1, "x"]])).is_err());
+    }
+
+    #[test]
+    fn test_parse_trades_taker_side() {
+        let json = serde_json::json!([
+            {"id": 28457, "price": "36500.01", "qty": "0.015", "quoteQty": "547.5", "time": 1_700_000_000_123_i64, "isBuyerMaker": true, "isBestMatch": true},
+            {"id": 28458, "price": "36500.02", "qty": "0.2", "quoteQty": "7300.0", "time": 1_700_000_000_456_i64, "isBuyerMaker": false, "isBestMatch": true}
+        ]);
+        let trades = parse_trades(&json, &pair()).unwrap();
+        assert_eq!(trades[0].side, Some(TradeSide::Sell));
+        assert_eq!(trades[1].side, Some(TradeSide::Buy));
+        assert_eq!(trades[1].pair, "BTC-USDT");
+        assert!((trades[1].notional() - 7300.004).abs() < 1e-9);
+    }
+
+    #[cfg(feature = "streaming")]
+    #[test]
+    fn test_parse_stream_trade() {
+        let text = r#"{"stream":"btcusdt@trade","data":{"e":"trade","E":1700000000200,"s":"BTCUSDT","t":12345,"p":"36500.10","q":"0.002","T":1700000000199,"m":false,"M":true}}"#;
+        let trade = parse_stream_trade(text, &[pair()]).unwrap();
+        assert_eq!(trade.trade_id, 12345);
+        assert_eq!(trade.side, Some(TradeSide::Buy));
+        assert_eq!(trade.timestamp_ms, 1_700_000_000_199);
+        assert!(parse_stream_trade(r#"{"result":null,"id":1}"#, &[pair()]).is_none());
+    }
+}
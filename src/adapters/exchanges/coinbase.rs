@@ -0,0 +1,169 @@
+//! Coinbase Exchange public REST API (`api.exchange.coinbase.com`).
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde_json::Value;
+
+use super::{
+    MAX_TRADES, Pair, candle, get_json, number, structure_error, unsupported_interval, windows,
+};
+use crate::constants::Interval;
+use crate::error::Result;
+use crate::models::chart::Candle;
+use crate::models::crypto::{CryptoExchange, CryptoTrade, TradeSide};
+
+const BASE: &str = "https://api.exchange.coinbase.com";
+/// Most candles Coinbase returns per request.
+const PER_PAGE: usize = 300;
+
+fn granularity(interval: Interval) -> Option<i64> {
+    Some(match interval {
+        Interval::OneMinute => 60,
+        Interval::FiveMinutes => 300,
+        Interval::FifteenMinutes => 900,
+        Interval::OneHour => 3600,
+        Interval::OneDay => 86_400,
+        _ => return None,
+    })
+}
+
+fn iso(ts: i64) -> String {
+    DateTime::<Utc>::from_timestamp(ts, 0)
+        .unwrap_or_default()
+        .to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+pub(super) async fn candles(
+    pair: &Pair,
+    interval: Interval,
+    start: i64,
+    end: i64,
+) -> Result<Vec<Candle>> {
+    let granularity = granularity(interval).ok_or_else(|| {
+        unsupported_interval(CryptoExchange::Coinbase, interval, "1m, 5m, 15m, 1h, 1d")
+    })?;
+
+    let mut candles = Vec::new();
+    for (from, to) in windows(start, end, granularity, PER_PAGE) {
+        let url = format!(
+            "{BASE}/products/{pair}/candles?granularity={granularity}&start={}&end={}",
+            iso(from),
+            iso(to)
+        );
+        let json = get_json(CryptoExchange::Coinbase, &url, pair).await?;
+        let page =
+            parse_candles(&json).map_err(|e| structure_error(CryptoExchange::Coinbase, e))?;
+        if page.is_empty() {
+            // Walking backwards past the product's listing date.
+            break;
+        }
+        candles.extend(page);
+    }
+    Ok(candles)
+}
+
+pub(super) async fn trades(pair: &Pair, limit: usize) -> Result<Vec<CryptoTrade>> {
+    let url = format!(
+        "{BASE}/products/{pair}/trades?limit={}",
+        limit.min(MAX_TRADES)
+    );
+    let json = get_json(CryptoExchange::Coinbase, &url, pair).await?;
+    parse_trades(&json, pair).map_err(|e| structure_error(CryptoExchange::Coinbase, e))
+}
+
+/// Parse `/candles`: `[[time, low, high, open, close, volume], ...]`, newest first.
+fn parse_candles(json: &Value) -> std::result::Result<Vec<Candle>, String> {
+    let rows = json.as_array().ok_or("candles response is not an array")?;
+    rows.iter()
+        .map(|row| {
+            let field = |i: usize| row.get(i).and_then(number);
+            let time = row.get(0).and_then(Value::as_i64);
+            match (time, field(1), field(2), field(3), field(4), field(5)) {
+                (Some(t), Some(l), Some(h), Some(o), Some(c), Some(v)) => {
+                    Ok(candle(t, o, h, l, c, v))
+                }
+                _ => Err(format!("malformed candle: {row}")),
+            }
+        })
+        .collect()
+}
+
+/// Parse `/trades`: `[{trade_id, price, size, side, time}, ...]`.
+fn parse_trades(json: &Value, pair: &Pair) -> std::result::Result<Vec<CryptoTrade>, String> {
+    let rows = json.as_array().ok_or("trades response is not an array")?;
+    rows.iter()
+        .map(|row| trade(pair, row).ok_or_else(|| format!("malformed trade: {row}")))
+        .collect()
+}
+
+/// Parse one `matches` channel message from the WebSocket feed.
+#[cfg(feature = "streaming")]
+pub(super) fn parse_stream_trade(text: &str) -> Option<CryptoTrade> {
+    let msg: Value = serde_json::from_str(text).ok()?;
+    if !matches!(msg.get("type")?.as_str()?, "match" | "last_match") {
+        return None;
+    }
+    let pair = Pair::parse(msg.get("product_id")?.as_str()?).ok()?;
+    trade(&pair, &msg)
+}
+
+/// Coinbase reports the *maker* order's side, so the taker is the opposite.
+fn trade(pair: &Pair, row: &Value) -> Option<CryptoTrade> {
+    let time = row.get("time")?.as_str()?;
+    Some(CryptoTrade {
+        exchange: CryptoExchange::Coinbase,
+        pair: pair.to_string(),
+        trade_id: row.get("trade_id")?.as_u64()?,
+        price: row.get("price").and_then(number)?,
+        size: row.get("size").and_then(number)?,
+        side: match row.get("side").and_then(Value::as_str) {
+            Some("buy") => Some(TradeSide::Sell),
+            Some("sell") => Some(TradeSide::Buy),
+            _ => None,
+        },
+        timestamp_ms: DateTime::parse_from_rfc3339(time).ok()?.timestamp_millis(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_candles_column_order() {
+        let json =
+            serde_json::json!([[1_700_000_000_i64, 36450.1, 36600.5, 36500.0, 36590.0, 12.4]]);
+        let candles = parse_candles(&json).unwrap();
+        let c = &candles[0];
+        assert_eq!(
+            (c.open, c.high, c.low, c.close),
+            (36500.0, 36600.5, 36450.1, 36590.0)
+        );
+        assert_eq!(c.volume, 12);
+    }
+
+    #[test]
+    fn test_parse_trades_taker_side() {
+        let json = serde_json::json!([
+            {"time": "2023-11-14T22:13:20.123456Z", "trade_id": 74, "price": "36500.01", "size": "0.01", "side": "buy"}
+        ]);
+        let pair = Pair::parse("BTC-USD").unwrap();
+        let trades = parse_trades(&json, &pair).unwrap();
+        assert_eq!(trades[0].side, Some(TradeSide::Sell));
+        assert_eq!(trades[0].timestamp_ms, 1_700_000_000_123);
+    }
+
+    #[test]
+    fn test_iso() {
+        assert_eq!(iso(1_700_000_000), "2023-11-14T22:13:20Z");
+    }
+
+    #[cfg(feature = "streaming")]
+    #[test]
+    fn test_parse_stream_trade() {
+        let text = r#"{"type":"match","trade_id":10,"maker_order_id":"a","taker_order_id":"b","side":"sell","size":"0.5","price":"2000.10","product_id":"ETH-USD","sequence":50,"time":"2023-11-14T22:13:20.000000Z"}"#;
+        let trade = parse_stream_trade(text).unwrap();
+        assert_eq!(trade.pair, "ETH-USD");
+        assert_eq!(trade.side, Some(TradeSide::Buy));
+        assert!(parse_stream_trade(r#"{"type":"subscriptions","channels":[]}"#).is_none());
+    }
+}
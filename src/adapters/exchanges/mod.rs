@@ -0,0 +1,350 @@
+//! Crypto exchange market data from the Binance and Coinbase public APIs.
+//!
+//! Requires the **`crypto`** feature flag. Both APIs are keyless.
+//!
+//! Candles come back as a regular [`Chart`], so indicators, resampling, risk
+//! analytics, and backtesting work on them exactly as on Yahoo charts.
+//! Exchange bars are exact trade aggregates at the requested granularity,
+//! including minute bars years back, where Yahoo's crypto history is limited
+//! to coarse intervals.
+//!
+//! # Quick Start
+//!
+//! ```no_run
+//! use finance_query::crypto::{self, CryptoExchange};
+//! use finance_query::{Interval, TimeRange};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let chart = crypto::candles(CryptoExchange::Binance, "BTC-USDT", Interval::OneHour, TimeRange::OneMonth).await?;
+//! println!("{} hourly bars, last close {:?}", chart.candles.len(), chart.candles.last().map(|c| c.close));
+//!
+//! let trades = crypto::trades(CryptoExchange::Coinbase, "ETH-USD", 50).await?;
+//! println!("{} recent ETH trades", trades.len());
+//! # Ok(())
+//! # }
+//! ```
+
+mod binance;
+mod coinbase;
+#[cfg(feature = "streaming")]
+mod stream;
+
+#[cfg(feature = "streaming")]
+pub use stream::TradeStream;
+
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use reqwest::{Client, StatusCode};
+use tracing::debug;
+
+use crate::adapters::pool::ConnectionPool;
+use crate::constants::{Interval, TimeRange};
+use crate::error::{FinanceError, Result};
+use crate::models::chart::{Candle, Chart, ChartMeta};
+use crate::models::crypto::{CryptoExchange, CryptoTrade};
+use crate::rate_limiter::RateLimiter;
+
+/// Most requests a single candle fetch makes. Long intraday ranges keep the
+/// most recent bars (50 pages is ~34 days of Binance minute bars).
+const MAX_PAGES: usize = 50;
+
+/// Most trades either exchange returns per request.
+const MAX_TRADES: usize = 1000;
+
+/// Fetch OHLCV candles for a pair over a preset time range.
+///
+/// `pair` is `BASE-QUOTE` (e.g. `"BTC-USDT"` on Binance, `"BTC-USD"` on
+/// Coinbase). Volume is in the base currency, rounded to whole units.
+///
+/// # Errors
+///
+/// - [`FinanceError::InvalidParameter`] if the pair is malformed or the
+///   exchange has no candles at `interval`.
+/// - [`FinanceError::SymbolNotFound`] if the exchange doesn't list the pair.
+pub async fn candles(
+    exchange: CryptoExchange,
+    pair: &str,
+    interval: Interval,
+    range: TimeRange,
+) -> Result<Chart> {
+    let end = chrono::Utc::now().timestamp();
+    let mut chart = fetch_chart(exchange, pair, interval, range_start(range, end), end).await?;
+    chart.range = Some(range);
+    Ok(chart)
+}
+
+/// Fetch OHLCV candles for a pair between two Unix timestamps (seconds).
+///
+/// Same pair format and errors as [`candles`]; `end` must be after `start`.
+pub async fn candles_between(
+    exchange: CryptoExchange,
+    pair: &str,
+    interval: Interval,
+    start: i64,
+    end: i64,
+) -> Result<Chart> {
+    if start >= end {
+        return Err(FinanceError::InvalidParameter {
+            param: "end".into(),
+            reason: format!("end ({end}) must be > start ({start})"),
+        });
+    }
+    fetch_chart(exchange, pair, interval, start, end).await
+}
+
+/// Fetch the most recent trades for a pair, newest first.
+///
+/// `limit` is capped at 1000.
+pub async fn trades(
+    exchange: CryptoExchange,
+    pair: &str,
+    limit: usize,
+) -> Result<Vec<CryptoTrade>> {
+    let pair = Pair::parse(pair)?;
+    let limit = limit.clamp(1, MAX_TRADES);
+    let mut trades = match exchange {
+        CryptoExchange::Binance => binance::trades(&pair, limit).await?,
+        CryptoExchange::Coinbase => coinbase::trades(&pair, limit).await?,
+    };
+    trades.sort_by_key(|t| std::cmp::Reverse((t.timestamp_ms, t.trade_id)));
+    Ok(trades)
+}
+
+async fn fetch_chart(
+    exchange: CryptoExchange,
+    pair: &str,
+    interval: Interval,
+    start: i64,
+    end: i64,
+) -> Result<Chart> {
+    let pair = Pair::parse(pair)?;
+    let mut candles = match exchange {
+        CryptoExchange::Binance => binance::candles(&pair, interval, start, end).await?,
+        CryptoExchange::Coinbase => coinbase::candles(&pair, interval, start, end).await?,
+    };
+    candles.sort_by_key(|c| c.timestamp);
+    candles.dedup_by_key(|c| c.timestamp);
+
+    Ok(Chart {
+        symbol: pair.to_string(),
+        meta: ChartMeta {
+            symbol: pair.to_string(),
+            currency: Some(pair.quote.clone()),
+            exchange_name: Some(exchange.name().to_string()),
+            full_exchange_name: Some(exchange.name().to_string()),
+            instrument_type: Some("CRYPTOCURRENCY".to_string()),
+            timezone: Some("UTC".to_string()),
+            exchange_timezone_name: Some("UTC".to_string()),
+            gmt_offset: Some(0),
+            regular_market_price: candles.last().map(|c| c.close),
+            regular_market_time: candles.last().map(|c| c.timestamp),
+            ..Default::default()
+        },
+        candles,
+        interval: Some(interval),
+        range: None,
+        provider_id: None,
+        adjustment: None,
+    })
+}
+
+/// A `BASE-QUOTE` pair, upper-cased.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Pair {
+    base: String,
+    quote: String,
+}
+
+impl Pair {
+    /// Accepts `BTC-USD` or `BTC/USD`, in any case.
+    fn parse(pair: &str) -> Result<Self> {
+        let invalid = || FinanceError::InvalidParameter {
+            param: "pair".into(),
+            reason: format!("expected BASE-QUOTE (e.g. \"BTC-USDT\"), got \"{pair}\""),
+        };
+        let (base, quote) = pair.trim().split_once(['-', '/']).ok_or_else(invalid)?;
+        let valid = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric());
+        if !valid(base) || !valid(quote) {
+            return Err(invalid());
+        }
+        Ok(Self {
+            base: base.to_ascii_uppercase(),
+            quote: quote.to_ascii_uppercase(),
+        })
+    }
+}
+
+impl std::fmt::Display for Pair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.base, self.quote)
+    }
+}
+
+/// Start of a preset range ending at `end` (Unix seconds). `Max` starts at the
+/// epoch; [`MAX_PAGES`] bounds how far back that actually reaches.
+fn range_start(range: TimeRange, end: i64) -> i64 {
+    use chrono::{Datelike, TimeZone, Utc};
+    let days = match range {
+        TimeRange::OneDay => 1,
+        TimeRange::FiveDays => 5,
+        TimeRange::OneMonth => 30,
+        TimeRange::ThreeMonths => 90,
+        TimeRange::SixMonths => 180,
+        TimeRange::OneYear => 365,
+        TimeRange::TwoYears => 730,
+        TimeRange::FiveYears => 1825,
+        TimeRange::TenYears => 3650,
+        TimeRange::Max => return 0,
+        TimeRange::YearToDate => {
+            let year = Utc
+                .timestamp_opt(end, 0)
+                .single()
+                .map_or(1970, |dt| dt.year());
+            return Utc
+                .with_ymd_and_hms(year, 1, 1, 0, 0, 0)
+                .single()
+                .map_or(0, |dt| dt.timestamp());
+        }
+    };
+    end - days * 86_400
+}
+
+/// Request windows `(from, to)` (inclusive, Unix seconds) covering
+/// `start..=end` newest first, each holding at most `per_page` bars of
+/// `step` seconds. Stops after [`MAX_PAGES`] windows.
+fn windows(start: i64, end: i64, step: i64, per_page: usize) -> Vec<(i64, i64)> {
+    let span = step * per_page as i64;
+    let mut out = Vec::new();
+    let mut to = end;
+    while to >= start && out.len() < MAX_PAGES {
+        let from = (to - span + 1).max(start);
+        out.push((from, to));
+        to = from - 1;
+    }
+    out
+}
+
+fn unsupported_interval(
+    exchange: CryptoExchange,
+    interval: Interval,
+    supported: &str,
+) -> FinanceError {
+    FinanceError::InvalidParameter {
+        param: "interval".into(),
+        reason: format!("{exchange} has no {interval} candles (supported: {supported})"),
+    }
+}
+
+/// Shared per-exchange limiter; the HTTP client itself is built per call
+/// because `reqwest::Client` is bound to the runtime that first uses it.
+fn limiter(exchange: CryptoExchange) -> Arc<RateLimiter> {
+    static BINANCE: OnceLock<Arc<RateLimiter>> = OnceLock::new();
+    static COINBASE: OnceLock<Arc<RateLimiter>> = OnceLock::new();
+    let (cell, rate) = match exchange {
+        // Binance allows 6000 request weight per minute; klines cost 2.
+        CryptoExchange::Binance => (&BINANCE, 20.0),
+        // Coinbase public endpoints allow 10 requests per second.
+        CryptoExchange::Coinbase => (&COINBASE, 8.0),
+    };
+    Arc::clone(cell.get_or_init(|| Arc::new(RateLimiter::new(rate))))
+}
+
+/// GET a JSON document from `exchange`, mapping its error statuses.
+async fn get_json(exchange: CryptoExchange, url: &str, pair: &Pair) -> Result<serde_json::Value> {
+    limiter(exchange).acquire().await;
+    let http = ConnectionPool::default()
+        .apply(Client::builder())
+        .timeout(Duration::from_secs(30))
+        .user_agent(format!(
+            "finance-query/{} (https://github.com/Verdenroz/finance-query)",
+            env!("CARGO_PKG_VERSION")
+        ))
+        .build()?;
+
+    debug!("{exchange} request: {url}");
+    let resp = crate::telemetry::track(url, http.get(url).send().await)?;
+    match resp.status() {
+        StatusCode::OK => Ok(resp.json().await?),
+        // Binance answers an unknown symbol with 400, Coinbase with 404.
+        StatusCode::BAD_REQUEST | StatusCode::NOT_FOUND => Err(FinanceError::SymbolNotFound {
+            symbol: Some(pair.to_string()),
+            context: format!("{exchange} does not list {pair}"),
+        }),
+        // 418 is Binance's "IP banned after ignoring 429s".
+        StatusCode::TOO_MANY_REQUESTS | StatusCode::IM_A_TEAPOT => Err(FinanceError::RateLimited {
+            retry_after: Some(60),
+        }),
+        s => Err(FinanceError::ExternalApiError {
+            api: exchange.name().to_string(),
+            status: s.as_u16(),
+        }),
+    }
+}
+
+/// Number sent either as a JSON number or a decimal string (both exchanges
+/// quote prices as strings to keep precision).
+fn number(value: &serde_json::Value) -> Option<f64> {
+    value
+        .as_f64()
+        .or_else(|| value.as_str().and_then(|s| s.parse().ok()))
+}
+
+fn structure_error(exchange: CryptoExchange, reason: String) -> FinanceError {
+    FinanceError::ResponseStructureError {
+        field: exchange.name().to_ascii_lowercase(),
+        context: reason,
+    }
+}
+
+/// Build a candle from exchange values, rounding base-currency volume.
+fn candle(timestamp: i64, open: f64, high: f64, low: f64, close: f64, volume: f64) -> Candle {
+    Candle::new(timestamp, open, high, low, close, volume.round() as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pair_parse() {
+        let pair = Pair::parse("btc/usdt").unwrap();
+        assert_eq!(pair.base, "BTC");
+        assert_eq!(pair.quote, "USDT");
+        assert_eq!(pair.to_string(), "BTC-USDT");
+        for bad in ["BTCUSDT", "BTC-", "-USD", "BTC-US D"] {
+            assert!(Pair::parse(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_windows_cover_range_newest_first() {
+        // 10 bars of 60s, 4 per page: [end-239, end], [end-479, end-240], [start, end-480]
+        let w = windows(0, 599, 60, 4);
+        assert_eq!(w, vec![(360, 599), (120, 359), (0, 119)]);
+        assert_eq!(windows(0, 10_000_000, 60, 1000).len(), MAX_PAGES);
+    }
+
+    #[test]
+    fn test_range_start() {
+        let now = 1_700_000_000;
+        assert_eq!(range_start(TimeRange::FiveDays, now), now - 5 * 86_400);
+        assert_eq!(range_start(TimeRange::Max, now), 0);
+        // 2023-11-14 → 2023-01-01
+        assert_eq!(range_start(TimeRange::YearToDate, now), 1_672_531_200);
+    }
+
+    #[tokio::test]
+    async fn test_candles_between_rejects_reversed_window() {
+        let err = candles_between(
+            CryptoExchange::Binance,
+            "BTC-USDT",
+            Interval::OneHour,
+            10,
+            5,
+        )
+        .await
+        .unwrap_err();
+        assert!(matches!(err, FinanceError::InvalidParameter { .. }));
+    }
+}
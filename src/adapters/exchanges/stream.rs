@@ -0,0 +1,139 @@
+//! Live trade prints from the Binance and Coinbase WebSocket feeds.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::{SinkExt, Stream, StreamExt};
+use tokio_tungstenite::tungstenite::Message;
+
+use super::{Pair, binance, coinbase};
+use crate::error::{FinanceError, Result};
+use crate::models::crypto::{CryptoExchange, CryptoTrade};
+
+const BINANCE_WS: &str = "wss://stream.binance.com:9443/stream";
+const COINBASE_WS: &str = "wss://ws-feed.exchange.coinbase.com";
+
+type WsStream =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+/// A live stream of trades for one or more pairs on a single exchange.
+///
+/// Implements `futures::Stream<Item = CryptoTrade>`. Subscription
+/// acknowledgements and heartbeats are skipped; the stream ends when the
+/// exchange closes the connection (Binance drops connections after 24 hours).
+///
+/// # Example
+///
+/// ```no_run
+/// use finance_query::crypto::{CryptoExchange, TradeStream};
+/// use futures::StreamExt;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut stream = TradeStream::subscribe(CryptoExchange::Binance, &["BTC-USDT", "ETH-USDT"]).await?;
+/// while let Some(trade) = stream.next().await {
+///     println!("{} {} @ {}", trade.pair, trade.size, trade.price);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct TradeStream {
+    exchange: CryptoExchange,
+    pairs: Vec<Pair>,
+    read: futures::stream::SplitStream<WsStream>,
+    // Held so the connection isn't half-closed.
+    _write: futures::stream::SplitSink<WsStream, Message>,
+}
+
+impl TradeStream {
+    /// Connect to `exchange` and subscribe to trades for `pairs`
+    /// (`BASE-QUOTE`, as for [`candles`](super::candles)).
+    ///
+    /// # Errors
+    ///
+    /// [`FinanceError::InvalidParameter`] if `pairs` is empty or malformed;
+    /// [`FinanceError::ApiError`] if the connection or subscription fails.
+    pub async fn subscribe(exchange: CryptoExchange, pairs: &[&str]) -> Result<Self> {
+        if pairs.is_empty() {
+            return Err(FinanceError::InvalidParameter {
+                param: "pairs".into(),
+                reason: "at least one pair is required".into(),
+            });
+        }
+        let pairs = pairs
+            .iter()
+            .map(|p| Pair::parse(p))
+            .collect::<Result<Vec<_>>>()?;
+
+        let url = match exchange {
+            CryptoExchange::Binance => {
+                let streams: Vec<String> = pairs
+                    .iter()
+                    .map(|p| format!("{}@trade", binance::symbol(p).to_ascii_lowercase()))
+                    .collect();
+                format!("{BINANCE_WS}?streams={}", streams.join("/"))
+            }
+            CryptoExchange::Coinbase => COINBASE_WS.to_string(),
+        };
+
+        let (ws, _) = tokio_tungstenite::connect_async(&url).await.map_err(|e| {
+            FinanceError::ApiError(format!("{exchange} WebSocket connect error: {e}"))
+        })?;
+        let (mut write, read) = ws.split();
+
+        // Binance subscribes through the URL; Coinbase needs a message.
+        if exchange == CryptoExchange::Coinbase {
+            let sub = serde_json::json!({
+                "type": "subscribe",
+                "product_ids": pairs.iter().map(Pair::to_string).collect::<Vec<_>>(),
+                "channels": ["matches"],
+            });
+            write
+                .send(Message::Text(sub.to_string().into()))
+                .await
+                .map_err(|e| {
+                    FinanceError::ApiError(format!("{exchange} WebSocket subscribe error: {e}"))
+                })?;
+        }
+
+        Ok(Self {
+            exchange,
+            pairs,
+            read,
+            _write: write,
+        })
+    }
+
+    /// The exchange this stream is connected to.
+    pub fn exchange(&self) -> CryptoExchange {
+        self.exchange
+    }
+
+    fn parse(&self, text: &str) -> Option<CryptoTrade> {
+        match self.exchange {
+            CryptoExchange::Binance => binance::parse_stream_trade(text, &self.pairs),
+            CryptoExchange::Coinbase => coinbase::parse_stream_trade(text),
+        }
+    }
+}
+
+impl Stream for TradeStream {
+    type Item = CryptoTrade;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match self.read.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(Message::Text(text)))) => {
+                    if let Some(trade) = self.parse(&text) {
+                        return Poll::Ready(Some(trade));
+                    }
+                }
+                Poll::Ready(Some(Ok(Message::Close(_)))) | Poll::Ready(None) => {
+                    return Poll::Ready(None);
+                }
+                Poll::Ready(Some(Ok(_))) => continue, // skip ping/pong/binary
+                Poll::Ready(Some(Err(_))) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -8,6 +8,7 @@ use serde::de::DeserializeOwned;
 use serde_json::Value;
 use tracing::debug;
 
+use crate::adapters::pool::ConnectionPool;
 use crate::error::{FinanceError, Result};
 use crate::rate_limiter::RateLimiter;
 
@@ -41,7 +42,8 @@ impl FmpClientBuilder {
     }
 
     pub(super) fn build_with_limiter(self, limiter: Arc<RateLimiter>) -> Result<FmpClient> {
-        let http = Client::builder()
+        let http = ConnectionPool::default()
+            .apply(Client::builder())
             .timeout(self.timeout)
             .user_agent(format!(
                 "finance-query/{} (https://github.com/Verdenroz/finance-query)",
@@ -114,7 +116,7 @@ impl FmpClient {
         query.extend_from_slice(params);
 
         debug!("FMP request: {path}");
-        let resp = self.http.get(&url).query(&query).send().await?;
+        let resp = crate::telemetry::track(&url, self.http.get(&url).query(&query).send().await)?;
 
         Self::check_status(resp.status())?;
 
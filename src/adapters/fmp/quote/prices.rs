@@ -196,6 +196,7 @@ pub async fn fetch_daily_chart_canonical(
         interval: None,
         range: None,
         provider_id: Some(crate::providers::Provider::Fmp),
+        adjustment: None,
     })
 }
 
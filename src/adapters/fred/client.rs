@@ -3,10 +3,12 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use chrono::NaiveDate;
 use reqwest::{Client, StatusCode};
 use tracing::debug;
 
 use super::models::{MacroObservation, MacroSeries, ReleaseDate};
+use crate::adapters::pool::ConnectionPool;
 use crate::error::{FinanceError, Result};
 use crate::rate_limiter::RateLimiter;
 
@@ -47,7 +49,8 @@ impl FredClientBuilder {
     /// per request, but the `RateLimiter` state must persist across calls so the
     /// 2 req/sec FRED limit is respected.
     pub(super) fn build_with_limiter(self, limiter: Arc<RateLimiter>) -> Result<FredClient> {
-        let http = Client::builder()
+        let http = ConnectionPool::default()
+            .apply(Client::builder())
             .timeout(self.timeout)
             .user_agent(format!(
                 "finance-query/{} (https://github.com/Verdenroz/finance-query)",
@@ -73,17 +76,25 @@ pub(crate) struct FredClient {
 }
 
 impl FredClient {
-    /// Fetch all observations for a FRED series by ID (e.g., `"FEDFUNDS"`, `"CPIAUCSL"`).
-    pub async fn series(&self, series_id: &str) -> Result<MacroSeries> {
+    /// Fetch observations for a FRED series by ID (e.g., `"FEDFUNDS"`, `"CPIAUCSL"`),
+    /// optionally limited to an inclusive `(start, end)` observation window.
+    pub async fn series(
+        &self,
+        series_id: &str,
+        window: Option<(NaiveDate, NaiveDate)>,
+    ) -> Result<MacroSeries> {
         self.limiter.acquire().await;
 
-        let url = format!(
+        let mut url = format!(
             "{}/series/observations?series_id={series_id}&api_key={}&file_type=json",
             self.base_url, self.api_key
         );
+        if let Some((start, end)) = window {
+            url.push_str(&format!("&observation_start={start}&observation_end={end}"));
+        }
 
         debug!("FRED request: series_id={series_id}");
-        let resp = self.http.get(&url).send().await?;
+        let resp = crate::telemetry::track(&url, self.http.get(&url).send().await)?;
 
         match resp.status() {
             StatusCode::OK => {}
@@ -157,7 +168,7 @@ impl FredClient {
         );
 
         debug!("FRED request: releases/dates from {today}");
-        let resp = self.http.get(&url).send().await?;
+        let resp = crate::telemetry::track(&url, self.http.get(&url).send().await)?;
 
         match resp.status() {
             StatusCode::OK => {}
@@ -27,12 +27,10 @@ pub(crate) async fn fetch_yields(year: u32) -> Result<Vec<TreasuryYield>> {
     // A static OnceLock<reqwest::Client> binds the pool to the initialising tokio
     // runtime; if that runtime drops the pool tasks die, causing DispatchGone on the
     // next call from a different runtime.
-    let resp = reqwest::Client::builder()
+    let client = reqwest::Client::builder()
         .timeout(Duration::from_secs(30))
-        .build()?
-        .get(&url)
-        .send()
-        .await?;
+        .build()?;
+    let resp = crate::telemetry::track(&url, client.get(&url).send().await)?;
 
     let status = resp.status();
     if !status.is_success() {
@@ -7,7 +7,7 @@
 //! Access 800k+ macro time series (CPI, Fed Funds Rate, M2, GDP, etc.).
 //! Requires a free API key from <https://fred.stlouisfed.org/docs/api/api_key.html>.
 //!
-//! Call [`init`] once at startup before using [`series`].
+//! Call [`init`] once at startup before using [`series`] or [`series_range`].
 //!
 //! # US Treasury Yields
 //!
@@ -25,6 +25,12 @@
 //! let cpi = fred::series("CPIAUCSL").await?;
 //! println!("CPI observations: {}", cpi.observations.len());
 //!
+//! // Or just a date window
+//! let start = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+//! let end = chrono::NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+//! let dgs10 = fred::series_range("DGS10", start, end).await?;
+//! println!("10Y observations in 2024: {}", dgs10.observations.len());
+//!
 //! // Treasury: no key required
 //! let yields = fred::treasury_yields(2025).await?;
 //! println!("Latest 10Y yield: {:?}", yields.last().and_then(|y| y.y10));
@@ -68,7 +74,7 @@ provider_singleton_state!(
 
 /// Initialize the global FRED client with an API key.
 ///
-/// Must be called once before [`series`]. Subsequent calls return an error.
+/// Must be called once before [`series`] or [`series_range`]. Subsequent calls return an error.
 ///
 /// # Arguments
 ///
@@ -100,16 +106,31 @@ pub fn init_with_timeout(api_key: impl Into<String>, timeout: Duration) -> Resul
 ///
 /// Returns [`FinanceError::InvalidParameter`] if FRED has not been initialized.
 pub async fn series(series_id: &str) -> Result<MacroSeries> {
-    let s = FRED_SINGLETON
-        .get()
-        .ok_or_else(|| FinanceError::InvalidParameter {
-            param: "fred".to_string(),
-            reason: "FRED not initialized. Call fred::init(api_key) first.".to_string(),
-        })?;
-    let c = FredClientBuilder::new(&s.api_key)
-        .timeout(s.timeout)
-        .build_with_limiter(Arc::clone(&s.limiter))?;
-    c.series(series_id).await
+    client()?.series(series_id, None).await
+}
+
+/// Fetch observations for a FRED data series between two dates (inclusive).
+///
+/// Same series IDs as [`series`]; FRED filters server-side, so narrow windows
+/// on long daily series (e.g. `"DGS10"`) are much cheaper than the full history.
+/// Use [`MacroSeries::align_to`] to line the result up with price candles.
+///
+/// # Errors
+///
+/// Returns [`FinanceError::InvalidParameter`] if FRED has not been initialized
+/// or `end` is before `start`.
+pub async fn series_range(
+    series_id: &str,
+    start: chrono::NaiveDate,
+    end: chrono::NaiveDate,
+) -> Result<MacroSeries> {
+    if end < start {
+        return Err(FinanceError::InvalidParameter {
+            param: "end".to_string(),
+            reason: format!("{end} is before start {start}"),
+        });
+    }
+    client()?.series(series_id, Some((start, end))).await
 }
 
 /// Fetch upcoming scheduled economic-data release dates (CPI, NFP, GDP, FOMC, …).
@@ -120,17 +141,21 @@ pub async fn series(series_id: &str) -> Result<MacroSeries> {
 ///
 /// Returns [`FinanceError::InvalidParameter`] if FRED has not been initialized.
 pub async fn release_dates() -> Result<Vec<ReleaseDate>> {
+    let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
+    client()?.release_dates(&today).await
+}
+
+/// Build a client from the initialized singleton.
+fn client() -> Result<client::FredClient> {
     let s = FRED_SINGLETON
         .get()
         .ok_or_else(|| FinanceError::InvalidParameter {
             param: "fred".to_string(),
             reason: "FRED not initialized. Call fred::init(api_key) first.".to_string(),
         })?;
-    let today = chrono::Utc::now().format("%Y-%m-%d").to_string();
-    let c = FredClientBuilder::new(&s.api_key)
+    FredClientBuilder::new(&s.api_key)
         .timeout(s.timeout)
-        .build_with_limiter(Arc::clone(&s.limiter))?;
-    c.release_dates(&today).await
+        .build_with_limiter(Arc::clone(&s.limiter))
 }
 
 /// Fetch US Treasury yield curve data for the given year.
@@ -222,7 +247,10 @@ mod tests {
             .create_async()
             .await;
 
-        let series = test_client(&server.url()).series("GDP").await.unwrap();
+        let series = test_client(&server.url())
+            .series("GDP", None)
+            .await
+            .unwrap();
         assert_eq!(series.id, "GDP");
         assert_eq!(series.observations.len(), 2);
         assert_eq!(series.observations[0].date, "2023-01-01");
@@ -236,6 +264,45 @@ mod tests {
         assert_eq!(canonical.observations[1].value, None);
     }
 
+    #[tokio::test]
+    async fn test_series_window_is_sent_as_observation_bounds() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/series/observations")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("series_id".into(), "DGS10".into()),
+                mockito::Matcher::UrlEncoded("observation_start".into(), "2024-01-01".into()),
+                mockito::Matcher::UrlEncoded("observation_end".into(), "2024-01-31".into()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "observations": [{ "date": "2024-01-02", "value": "3.95" }]
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let start = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let end = chrono::NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        let series = test_client(&server.url())
+            .series("DGS10", Some((start, end)))
+            .await
+            .unwrap();
+        assert_eq!(series.observations.len(), 1);
+        assert_eq!(series.observations[0].value, Some(3.95));
+    }
+
+    #[tokio::test]
+    async fn test_series_range_rejects_reversed_window() {
+        let start = chrono::NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+        let end = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let err = series_range("DGS10", start, end).await.unwrap_err();
+        assert!(matches!(err, FinanceError::InvalidParameter { .. }));
+    }
+
     #[tokio::test]
     async fn test_series_unknown_id_maps_400_to_invalid_parameter() {
         let mut server = mockito::Server::new_async().await;
@@ -247,7 +314,7 @@ mod tests {
             .await;
 
         let err = test_client(&server.url())
-            .series("NOT_A_SERIES")
+            .series("NOT_A_SERIES", None)
             .await
             .unwrap_err();
         assert!(matches!(err, FinanceError::InvalidParameter { .. }));
@@ -265,7 +332,10 @@ mod tests {
             .create_async()
             .await;
 
-        let err = test_client(&server.url()).series("GDP").await.unwrap_err();
+        let err = test_client(&server.url())
+            .series("GDP", None)
+            .await
+            .unwrap_err();
         assert!(matches!(err, FinanceError::ResponseStructureError { .. }));
     }
 
@@ -0,0 +1,334 @@
+//! Response size and decompression limits.
+//!
+//! The HTTP client is built with reqwest's transparent decompression turned
+//! off so the compressed and decompressed sizes of each body can both be
+//! observed. [`read_limited`] buffers the body, decodes it, and aborts with
+//! [`FinanceError::ResponseTooLarge`] as soon as either limit is crossed.
+
+use std::io::Read;
+use std::sync::{LazyLock, RwLock};
+
+use reqwest::header::{CONTENT_ENCODING, CONTENT_LENGTH};
+
+use crate::error::{FinanceError, Result};
+
+/// `Accept-Encoding` advertised by clients that decode through [`read_limited`].
+pub(crate) const ACCEPT_ENCODING: &str = "gzip, br";
+
+/// Decompressed bytes allowed before the ratio limit is enforced.
+///
+/// Small, highly repetitive payloads (e.g. padded timeseries) legitimately
+/// compress far better than typical JSON; the ratio only matters once the
+/// output is large enough to hurt.
+const RATIO_GRACE_BYTES: u64 = 1024 * 1024;
+
+const DECODE_CHUNK: usize = 64 * 1024;
+
+static DEFAULT_LIMITS: LazyLock<RwLock<ResponseLimits>> =
+    LazyLock::new(|| RwLock::new(ResponseLimits::unlimited()));
+
+/// Upper bounds on HTTP response bodies.
+///
+/// Both limits are off by default. Set them per ticker with
+/// [`TickerBuilder::response_limits`](crate::TickerBuilder::response_limits),
+/// or process-wide with [`ResponseLimits::set_default`], which applies to
+/// every client created afterwards.
+///
+/// # Example
+///
+/// ```no_run
+/// use finance_query::{ResponseLimits, Ticker};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let limits = ResponseLimits::new()
+///     .max_bytes(16 * 1024 * 1024)
+///     .max_decompression_ratio(50.0);
+/// let ticker = Ticker::builder("AAPL").response_limits(limits).build().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResponseLimits {
+    max_bytes: Option<u64>,
+    max_decompression_ratio: Option<f64>,
+}
+
+impl Default for ResponseLimits {
+    /// The process-wide default (see [`ResponseLimits::set_default`]).
+    fn default() -> Self {
+        *DEFAULT_LIMITS.read().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl ResponseLimits {
+    /// Limits with nothing enforced.
+    pub const fn unlimited() -> Self {
+        Self {
+            max_bytes: None,
+            max_decompression_ratio: None,
+        }
+    }
+
+    /// Start from no limits; alias of [`ResponseLimits::unlimited`].
+    pub const fn new() -> Self {
+        Self::unlimited()
+    }
+
+    /// Maximum body size in bytes, checked against both the bytes received
+    /// and the decompressed output.
+    pub const fn max_bytes(mut self, bytes: u64) -> Self {
+        self.max_bytes = Some(bytes);
+        self
+    }
+
+    /// Maximum ratio of decompressed to compressed size.
+    ///
+    /// Only checked once the decompressed output exceeds 1 MiB.
+    pub const fn max_decompression_ratio(mut self, ratio: f64) -> Self {
+        self.max_decompression_ratio = Some(ratio);
+        self
+    }
+
+    /// Configured byte limit, if any.
+    pub fn bytes_limit(&self) -> Option<u64> {
+        self.max_bytes
+    }
+
+    /// Configured decompression ratio limit, if any.
+    pub fn ratio_limit(&self) -> Option<f64> {
+        self.max_decompression_ratio
+    }
+
+    /// Replace the process-wide default used by clients created afterwards.
+    pub fn set_default(limits: ResponseLimits) {
+        *DEFAULT_LIMITS.write().unwrap_or_else(|e| e.into_inner()) = limits;
+    }
+
+    fn check_received(&self, received: u64) -> Result<()> {
+        match self.max_bytes {
+            Some(limit) if received > limit => Err(FinanceError::ResponseTooLarge {
+                limit,
+                context: format!("received more than {limit} bytes"),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_decoded(&self, decoded: u64, compressed: u64) -> Result<()> {
+        if let Some(limit) = self.max_bytes
+            && decoded > limit
+        {
+            return Err(FinanceError::ResponseTooLarge {
+                limit,
+                context: format!("decompressed body exceeds {limit} bytes"),
+            });
+        }
+        if let Some(ratio) = self.max_decompression_ratio
+            && decoded > RATIO_GRACE_BYTES
+            && decoded as f64 > compressed.max(1) as f64 * ratio
+        {
+            return Err(FinanceError::ResponseTooLarge {
+                limit: (compressed as f64 * ratio) as u64,
+                context: format!(
+                    "decompression ratio exceeds {ratio}x ({compressed} compressed bytes)"
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Buffer, decode, and size-check a response body, returning an equivalent
+/// response whose body is the decoded bytes.
+///
+/// Status, version, URL, and headers are preserved; `Content-Encoding` and
+/// `Content-Length` are dropped since they described the wire body.
+/// Decompression runs on the blocking thread pool so large bodies don't stall
+/// the async executor.
+pub(crate) async fn read_limited(
+    mut response: reqwest::Response,
+    limits: &ResponseLimits,
+) -> Result<reqwest::Response> {
+    use reqwest::ResponseBuilderExt;
+
+    if let Some(len) = response.content_length() {
+        limits.check_received(len)?;
+    }
+
+    let mut raw = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        raw.extend_from_slice(&chunk);
+        limits.check_received(raw.len() as u64)?;
+    }
+
+    let encoding = response
+        .headers()
+        .get(CONTENT_ENCODING)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim().to_ascii_lowercase());
+    let body = match encoding.as_deref() {
+        None | Some("") | Some("identity") => raw,
+        _ => {
+            let limits = *limits;
+            tokio::task::spawn_blocking(move || decode(encoding.as_deref(), raw, &limits))
+                .await
+                .map_err(|e| {
+                    FinanceError::InternalError(format!("Response decoding task failed: {e}"))
+                })??
+        }
+    };
+
+    let mut rebuilt = http::Response::builder()
+        .status(response.status())
+        .version(response.version())
+        .url(response.url().clone());
+    if let Some(headers) = rebuilt.headers_mut() {
+        *headers = response.headers().clone();
+        headers.remove(CONTENT_ENCODING);
+        headers.remove(CONTENT_LENGTH);
+    }
+    let rebuilt = rebuilt
+        .body(body)
+        .map_err(|e| FinanceError::InternalError(format!("Failed to rebuild response: {e}")))?;
+    Ok(reqwest::Response::from(rebuilt))
+}
+
+fn decode(encoding: Option<&str>, raw: Vec<u8>, limits: &ResponseLimits) -> Result<Vec<u8>> {
+    let compressed = raw.len() as u64;
+    match encoding {
+        None | Some("") | Some("identity") => Ok(raw),
+        Some("gzip") | Some("x-gzip") => decode_with(
+            flate2::read::MultiGzDecoder::new(&raw[..]),
+            compressed,
+            limits,
+        ),
+        Some("br") => decode_with(
+            brotli_decompressor::Decompressor::new(&raw[..], DECODE_CHUNK),
+            compressed,
+            limits,
+        ),
+        Some(other) => Err(FinanceError::UnexpectedResponse(format!(
+            "Unsupported Content-Encoding '{other}'"
+        ))),
+    }
+}
+
+fn decode_with(mut reader: impl Read, compressed: u64, limits: &ResponseLimits) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    let mut buf = vec![0u8; DECODE_CHUNK];
+    loop {
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            return Ok(out);
+        }
+        out.extend_from_slice(&buf[..n]);
+        limits.check_decoded(out.len() as u64, compressed)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::best());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_read_limited_decodes_and_keeps_url() {
+        use reqwest::ResponseBuilderExt;
+
+        let url =
+            reqwest::Url::parse("https://query1.finance.yahoo.com/v8/finance/chart/AAPL").unwrap();
+        let data = br#"{"chart":{"result":[]}}"#;
+        let response = http::Response::builder()
+            .status(200)
+            .url(url.clone())
+            .header(CONTENT_ENCODING, "gzip")
+            .body(gzip(data))
+            .unwrap();
+
+        let decoded = read_limited(reqwest::Response::from(response), &ResponseLimits::new())
+            .await
+            .unwrap();
+        assert_eq!(decoded.url(), &url);
+        assert!(decoded.headers().get(CONTENT_ENCODING).is_none());
+        assert_eq!(decoded.bytes().await.unwrap().as_ref(), data);
+    }
+
+    #[test]
+    fn test_identity_passthrough() {
+        let body = decode(None, b"{}".to_vec(), &ResponseLimits::unlimited()).unwrap();
+        assert_eq!(body, b"{}");
+    }
+
+    #[test]
+    fn test_gzip_roundtrip() {
+        let data = br#"{"chart":{"result":[]}}"#;
+        let body = decode(Some("gzip"), gzip(data), &ResponseLimits::new()).unwrap();
+        assert_eq!(body, data);
+    }
+
+    #[test]
+    fn test_max_bytes_on_decoded_output() {
+        let data = vec![b'a'; 10_000];
+        let limits = ResponseLimits::new().max_bytes(5_000);
+        let err = decode(Some("gzip"), gzip(&data), &limits).unwrap_err();
+        assert!(matches!(
+            err,
+            FinanceError::ResponseTooLarge { limit: 5_000, .. }
+        ));
+    }
+
+    #[test]
+    fn test_decompression_ratio() {
+        // 4 MiB of zeros compresses to a few KiB
+        let data = vec![0u8; 4 * 1024 * 1024];
+        let compressed = gzip(&data);
+
+        let strict = ResponseLimits::new().max_decompression_ratio(100.0);
+        assert!(matches!(
+            decode(Some("gzip"), compressed.clone(), &strict),
+            Err(FinanceError::ResponseTooLarge { .. })
+        ));
+
+        let lenient = ResponseLimits::new().max_decompression_ratio(1e6);
+        assert_eq!(
+            decode(Some("gzip"), compressed, &lenient).unwrap().len(),
+            data.len()
+        );
+    }
+
+    #[test]
+    fn test_ratio_grace_for_small_bodies() {
+        let data = vec![0u8; 64 * 1024];
+        let limits = ResponseLimits::new().max_decompression_ratio(2.0);
+        assert!(decode(Some("gzip"), gzip(&data), &limits).is_ok());
+    }
+
+    #[test]
+    fn test_unknown_encoding_rejected() {
+        assert!(decode(Some("zstd"), vec![1, 2, 3], &ResponseLimits::new()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_read_limited_rebuilds_response() {
+        let data = br#"{"ok":true}"#;
+        let response = http::Response::builder()
+            .status(200)
+            .header(CONTENT_ENCODING, "gzip")
+            .header("x-test", "1")
+            .body(gzip(data))
+            .unwrap();
+        let response = read_limited(reqwest::Response::from(response), &ResponseLimits::new())
+            .await
+            .unwrap();
+        assert!(response.headers().get(CONTENT_ENCODING).is_none());
+        assert_eq!(response.headers()["x-test"], "1");
+        let json: serde_json::Value = response.json().await.unwrap();
+        assert_eq!(json["ok"], true);
+    }
+}
@@ -17,7 +17,7 @@
 //! | `fmp` | [Financial Modeling Prep](https://financialmodelingprep.com/) | 250 req/day | ~100 | Fundamentals, DCF/ratings, insider trading, institutional holdings, screener, 60+ exchanges |
 //! | `crypto` | [CoinGecko](https://www.coingecko.com/) | 30 req/min | 2 | Top coins by market cap, single coin quotes (keyless) |
 //! | `fred` | [FRED](https://fred.stlouisfed.org/) | 120 req/min | 2+ | 800k+ macro time series, US Treasury yield curve |
-//! | *(always)* | [SEC EDGAR](https://www.sec.gov/edgar) | 10 req/sec | 5+ | Filing history, XBRL financials, full-text search (keyless, requires contact email) |
+//! | `edgar` | [SEC EDGAR](https://www.sec.gov/edgar) | 10 req/sec | 5+ | Filing history, XBRL financials, full-text search (keyless, requires contact email) |
 //!
 //! # Quick comparison
 //!
@@ -34,6 +34,8 @@
 //! - Full mockito test coverage (no API key needed to run tests)
 
 pub(crate) mod common;
+pub(crate) mod limits;
+pub(crate) mod pool;
 pub(crate) mod singleton;
 
 /// Alpha Vantage financial data API (requires `alphavantage` feature).
@@ -52,11 +54,16 @@ pub(crate) mod fmp;
 #[cfg(feature = "crypto")]
 pub(crate) mod coingecko;
 
+/// Binance and Coinbase public market data (keyless, requires `crypto` feature).
+#[cfg(feature = "crypto")]
+pub(crate) mod exchanges;
+
 /// FRED economic data API (requires `fred` feature).
 #[cfg(feature = "fred")]
 pub(crate) mod fred;
 
-/// SEC EDGAR API client (always available, requires init with contact email).
+/// SEC EDGAR API client (requires `edgar` feature and init with contact email).
+#[cfg(feature = "edgar")]
 pub(crate) mod edgar;
 
 /// Yahoo Finance API endpoints (always available — the canonical data source).
@@ -64,10 +64,13 @@ pub async fn stock_aggregates(
 fn interval_to_polygon(interval: Interval) -> (u32, Timespan) {
     match interval {
         Interval::OneMinute => (1, Timespan::Minute),
+        Interval::TwoMinutes => (2, Timespan::Minute),
         Interval::FiveMinutes => (5, Timespan::Minute),
         Interval::FifteenMinutes => (15, Timespan::Minute),
         Interval::ThirtyMinutes => (30, Timespan::Minute),
         Interval::OneHour => (1, Timespan::Hour),
+        Interval::NinetyMinutes => (90, Timespan::Minute),
+        Interval::FourHours => (4, Timespan::Hour),
         Interval::OneDay => (1, Timespan::Day),
         Interval::OneWeek => (1, Timespan::Week),
         Interval::OneMonth => (1, Timespan::Month),
@@ -140,6 +143,7 @@ pub async fn fetch_chart_response(
         interval: Some(interval),
         range: Some(range),
         provider_id: Some(Provider::Polygon),
+        adjustment: None,
     })
 }
 
@@ -162,6 +166,7 @@ pub async fn fetch_chart_range_response(
         interval: None,
         range: None,
         provider_id: Some(Provider::Polygon),
+        adjustment: None,
     })
 }
 
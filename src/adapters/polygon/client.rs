@@ -8,6 +8,7 @@ use serde::de::DeserializeOwned;
 use serde_json::Value;
 use tracing::debug;
 
+use crate::adapters::pool::ConnectionPool;
 use crate::error::{FinanceError, Result};
 use crate::rate_limiter::RateLimiter;
 
@@ -43,7 +44,8 @@ impl PolygonClientBuilder {
     }
 
     pub(super) fn build_with_limiter(self, limiter: Arc<RateLimiter>) -> Result<PolygonClient> {
-        let http = Client::builder()
+        let http = ConnectionPool::default()
+            .apply(Client::builder())
             .timeout(self.timeout)
             .user_agent(format!(
                 "finance-query/{} (https://github.com/Verdenroz/finance-query)",
@@ -128,7 +130,7 @@ impl PolygonClient {
         query.extend_from_slice(params);
 
         debug!("Polygon request: {path}");
-        let resp = self.http.get(&url).query(&query).send().await?;
+        let resp = crate::telemetry::track(&url, self.http.get(&url).query(&query).send().await)?;
 
         Self::check_status(resp.status())?;
 
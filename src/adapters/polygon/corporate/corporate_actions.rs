@@ -118,7 +118,6 @@ pub async fn stock_ipos(params: &[(&str, &str)]) -> Result<PaginatedResponseDTO<
 }
 
 /// Fetch ticker events (name changes, mergers, etc.).
-#[allow(dead_code)]
 pub async fn stock_ticker_events(ticker: &str) -> Result<TickerEventsResponseDTO> {
     let client = build_client()?;
     let path = format!("/vX/reference/tickers/{}/events", ticker);
@@ -136,6 +135,38 @@ fn parse_date(d: &Option<String>) -> i64 {
         .unwrap_or(0)
 }
 
+/// Fetch the symbol changes of a stock ticker as `(timestamp, new_symbol)`, oldest first.
+pub async fn fetch_symbol_changes(ticker: &str) -> Result<Vec<(i64, String)>> {
+    Ok(symbol_changes(stock_ticker_events(ticker).await?))
+}
+
+/// Extract symbol changes from ticker events.
+///
+/// The earliest `ticker_change` event is the original listing, not a change,
+/// so it is dropped.
+fn symbol_changes(response: TickerEventsResponseDTO) -> Vec<(i64, String)> {
+    let mut changes: Vec<(i64, String)> = response
+        .events
+        .into_iter()
+        .flatten()
+        .filter(|e| e.event_type.as_deref() == Some("ticker_change"))
+        .filter_map(|e| {
+            let symbol = e
+                .ticker_change
+                .as_ref()?
+                .get("ticker")?
+                .as_str()?
+                .to_string();
+            Some((parse_date(&e.date), symbol))
+        })
+        .collect();
+    changes.sort_by_key(|(timestamp, _)| *timestamp);
+    if !changes.is_empty() {
+        changes.remove(0);
+    }
+    changes
+}
+
 /// Fetch chart events (dividends + splits, canonical) for a stock ticker.
 pub async fn fetch_events_response(symbol: &str) -> Result<ChartEvents> {
     let dividends = stock_dividends(&[("ticker", symbol)]).await?;
@@ -178,3 +209,24 @@ pub async fn fetch_events_response(symbol: &str) -> Result<ChartEvents> {
         .collect();
     Ok(chart_events)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symbol_changes_drop_original_listing() {
+        let response: TickerEventsResponseDTO = serde_json::from_value(serde_json::json!({
+            "name": "Meta Platforms, Inc. Class A Common Stock",
+            "events": [
+                {"type": "ticker_change", "date": "2022-06-09", "ticker_change": {"ticker": "META"}},
+                {"type": "ticker_change", "date": "2012-05-18", "ticker_change": {"ticker": "FB"}}
+            ]
+        }))
+        .unwrap();
+        assert_eq!(
+            symbol_changes(response),
+            vec![(1_654_732_800, "META".to_string())]
+        );
+    }
+}
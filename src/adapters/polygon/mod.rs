@@ -47,10 +47,13 @@ mod futures; // FUTURES
 mod indices; // INDICES
 mod options; // OPTIONS
 
+#[cfg(feature = "streaming")]
 pub(crate) mod websocket;
 
 use crate::adapters::singleton::{provider_build_client, provider_singleton_state};
-use crate::error::{FinanceError, Result};
+#[cfg(any(test, feature = "streaming"))]
+use crate::error::FinanceError;
+use crate::error::Result;
 use std::time::Duration;
 
 // Capability modules
@@ -100,7 +103,7 @@ provider_build_client!(
 ///
 /// # Errors
 ///
-/// Returns [`FinanceError::InvalidParameter`] if already initialized.
+/// Returns [`FinanceError::InvalidParameter`](crate::FinanceError::InvalidParameter) if already initialized.
 #[allow(dead_code)]
 pub fn init(api_key: impl Into<String>) -> Result<()> {
     init_with_timeout(api_key, Duration::from_secs(30))
@@ -113,6 +116,7 @@ pub fn init_with_timeout(api_key: impl Into<String>, timeout: Duration) -> Resul
 }
 
 /// Internal: read the configured API key. Used by the websocket module.
+#[cfg(feature = "streaming")]
 pub(crate) fn api_key() -> Result<String> {
     PG_SINGLETON
         .get()
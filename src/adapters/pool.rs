@@ -0,0 +1,178 @@
+//! HTTP connection pool and keep-alive settings.
+//!
+//! Applied to every reqwest client the crate builds for an upstream API.
+//! The defaults favour batch throughput: plenty of idle connections kept per
+//! host, TCP and HTTP/2 keep-alive so pooled connections survive pauses
+//! between bursts, and an adaptive HTTP/2 flow-control window so large chart
+//! and timeseries bodies are not throttled by the initial 64 KiB window.
+
+use std::sync::{LazyLock, RwLock};
+use std::time::Duration;
+
+static DEFAULT_POOL: LazyLock<RwLock<ConnectionPool>> =
+    LazyLock::new(|| RwLock::new(ConnectionPool::batch()));
+
+/// Connection pool and keep-alive tuning for the HTTP client.
+///
+/// Set per ticker with
+/// [`TickerBuilder::connection_pool`](crate::TickerBuilder::connection_pool),
+/// or process-wide with [`ConnectionPool::set_default`], which applies to
+/// every client created afterwards.
+///
+/// # Example
+///
+/// ```no_run
+/// use finance_query::{ConnectionPool, Tickers};
+/// use std::time::Duration;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let pool = ConnectionPool::new()
+///     .max_idle_per_host(128)
+///     .keep_alive(Duration::from_secs(30));
+/// let tickers = Tickers::builder(["AAPL", "MSFT", "NVDA"])
+///     .connection_pool(pool)
+///     .build()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectionPool {
+    max_idle_per_host: usize,
+    idle_timeout: Option<Duration>,
+    keep_alive: Option<Duration>,
+    http2_adaptive_window: bool,
+}
+
+impl Default for ConnectionPool {
+    /// The process-wide default (see [`ConnectionPool::set_default`]).
+    fn default() -> Self {
+        *DEFAULT_POOL.read().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl ConnectionPool {
+    /// Settings tuned for bulk downloads: 64 idle connections per host,
+    /// 90s idle timeout, 30s keep-alive, adaptive HTTP/2 window.
+    pub const fn batch() -> Self {
+        Self {
+            max_idle_per_host: 64,
+            idle_timeout: Some(Duration::from_secs(90)),
+            keep_alive: Some(Duration::from_secs(30)),
+            http2_adaptive_window: true,
+        }
+    }
+
+    /// Start from the batch settings; alias of [`ConnectionPool::batch`].
+    pub const fn new() -> Self {
+        Self::batch()
+    }
+
+    /// Maximum idle connections kept open per host.
+    pub const fn max_idle_per_host(mut self, n: usize) -> Self {
+        self.max_idle_per_host = n;
+        self
+    }
+
+    /// How long an idle pooled connection is kept before being closed.
+    pub const fn idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Keep idle pooled connections open indefinitely.
+    pub const fn no_idle_timeout(mut self) -> Self {
+        self.idle_timeout = None;
+        self
+    }
+
+    /// Interval for TCP keep-alive probes and HTTP/2 PING frames.
+    pub const fn keep_alive(mut self, interval: Duration) -> Self {
+        self.keep_alive = Some(interval);
+        self
+    }
+
+    /// Disable TCP and HTTP/2 keep-alive.
+    pub const fn no_keep_alive(mut self) -> Self {
+        self.keep_alive = None;
+        self
+    }
+
+    /// Let HTTP/2 grow its flow-control window with measured bandwidth.
+    pub const fn http2_adaptive_window(mut self, enabled: bool) -> Self {
+        self.http2_adaptive_window = enabled;
+        self
+    }
+
+    /// Configured idle connections per host.
+    pub fn idle_per_host(&self) -> usize {
+        self.max_idle_per_host
+    }
+
+    /// Configured idle timeout, if any.
+    pub fn idle_timeout_duration(&self) -> Option<Duration> {
+        self.idle_timeout
+    }
+
+    /// Configured keep-alive interval, if any.
+    pub fn keep_alive_interval(&self) -> Option<Duration> {
+        self.keep_alive
+    }
+
+    /// Whether the adaptive HTTP/2 window is enabled.
+    pub fn is_http2_adaptive_window(&self) -> bool {
+        self.http2_adaptive_window
+    }
+
+    /// Replace the process-wide default used by clients created afterwards.
+    pub fn set_default(pool: ConnectionPool) {
+        *DEFAULT_POOL.write().unwrap_or_else(|e| e.into_inner()) = pool;
+    }
+
+    /// Apply these settings to a client builder.
+    pub(crate) fn apply(&self, builder: reqwest::ClientBuilder) -> reqwest::ClientBuilder {
+        let builder = builder
+            .pool_max_idle_per_host(self.max_idle_per_host)
+            .pool_idle_timeout(self.idle_timeout)
+            .tcp_keepalive(self.keep_alive)
+            .http2_adaptive_window(self.http2_adaptive_window);
+        match self.keep_alive {
+            Some(interval) => builder
+                .http2_keep_alive_interval(interval)
+                .http2_keep_alive_while_idle(true),
+            None => builder,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_overrides_batch_defaults() {
+        let pool = ConnectionPool::new()
+            .max_idle_per_host(8)
+            .no_idle_timeout()
+            .keep_alive(Duration::from_secs(5))
+            .http2_adaptive_window(false);
+        assert_eq!(pool.idle_per_host(), 8);
+        assert_eq!(pool.idle_timeout_duration(), None);
+        assert_eq!(pool.keep_alive_interval(), Some(Duration::from_secs(5)));
+        assert!(!pool.is_http2_adaptive_window());
+
+        let batch = ConnectionPool::batch();
+        assert_eq!(batch.idle_per_host(), 64);
+        assert!(batch.is_http2_adaptive_window());
+    }
+
+    #[test]
+    fn test_apply_builds_client() {
+        let builder = ConnectionPool::batch().apply(reqwest::Client::builder());
+        assert!(builder.build().is_ok());
+        let builder = ConnectionPool::new()
+            .no_keep_alive()
+            .apply(reqwest::Client::builder());
+        assert!(builder.build().is_ok());
+    }
+}
@@ -1,7 +1,13 @@
 use super::client::ClientConfig;
+use crate::adapters::limits::{self, ResponseLimits};
 use crate::adapters::yahoo::endpoints::{api, base};
 use crate::error::{FinanceError, Result};
 use reqwest::Proxy;
+use reqwest::cookie::{CookieStore, Jar};
+use reqwest::header::{ACCEPT_ENCODING, HeaderMap, HeaderValue};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tracing::{debug, info, warn};
 
@@ -20,7 +26,6 @@ const AUTH_TIMEOUT: Duration = Duration::from_secs(15);
 const MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
 
 /// Maximum age of auth before considering it stale
-#[cfg(test)]
 const AUTH_MAX_AGE: Duration = Duration::from_secs(3600); // 1 hour
 
 /// Yahoo Finance authentication data
@@ -51,11 +56,23 @@ impl YahooAuth {
         info!("Starting Yahoo Finance authentication");
 
         // Create HTTP client with configuration
-        let mut builder = reqwest::Client::builder()
-            .cookie_store(true)
+        // Bodies are decoded by `limits::read_limited` so their size can be bounded
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            ACCEPT_ENCODING,
+            HeaderValue::from_static(limits::ACCEPT_ENCODING),
+        );
+        let jar = Arc::new(Jar::default());
+        let mut builder = config
+            .pool
+            .apply(reqwest::Client::builder())
+            .cookie_provider(jar.clone())
             .timeout(config.timeout)
             .connect_timeout(AUTH_TIMEOUT)
-            .user_agent(USER_AGENT);
+            .user_agent(USER_AGENT)
+            .no_gzip()
+            .no_brotli()
+            .default_headers(headers);
 
         // Apply proxy if configured
         if let Some(proxy_url) = &config.proxy {
@@ -69,22 +86,34 @@ impl YahooAuth {
             FinanceError::InternalError(format!("Failed to create HTTP client: {}", e))
         })?;
 
+        if let Some(path) = &config.session_file
+            && let Some(session) = PersistedSession::load(path)
+        {
+            debug!("Reusing persisted session from {}", path.display());
+            return Ok(session.restore(jar, client));
+        }
+
         // Visit fc.yahoo.com to establish session
         debug!("Visiting {} to establish session", base::YAHOO_FC);
-        client.get(base::YAHOO_FC).send().await.map_err(|e| {
-            FinanceError::InternalError(format!("Failed to establish session: {}", e))
-        })?;
+        crate::telemetry::track(base::YAHOO_FC, client.get(base::YAHOO_FC).send().await).map_err(
+            |e| FinanceError::InternalError(format!("Failed to establish session: {}", e)),
+        )?;
 
         // Try to get crumb from query1
         debug!("Attempting to fetch crumb from query1");
-        let crumb = get_crumb(&client, api::CRUMB_QUERY1).await.map_err(|e| {
-            warn!("Failed to fetch crumb: {}", e);
-            FinanceError::AuthenticationFailed {
-                context: format!("Failed to fetch crumb: {}", e),
-            }
-        })?;
+        let crumb = get_crumb(&client, api::CRUMB_QUERY1, &config.limits)
+            .await
+            .map_err(|e| {
+                warn!("Failed to fetch crumb: {}", e);
+                FinanceError::AuthenticationFailed {
+                    context: format!("Failed to fetch crumb: {}", e),
+                }
+            })?;
 
         info!("Successfully authenticated with Yahoo Finance");
+        if let Some(path) = &config.session_file {
+            PersistedSession::capture(&crumb, &jar).save(path);
+        }
         Ok(Self {
             crumb,
             last_refresh: Instant::now(),
@@ -105,12 +134,88 @@ impl YahooAuth {
     }
 }
 
+/// Authenticated session saved between processes, so short-lived programs
+/// (CLI invocations, cron jobs) skip the two-request handshake.
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedSession {
+    crumb: String,
+    /// `name=value` pairs sent to Yahoo's API hosts
+    cookies: Vec<String>,
+    /// Unix seconds when the handshake ran
+    saved_at: i64,
+}
+
+impl PersistedSession {
+    /// URL whose cookies are captured and restored (Yahoo scopes them to `.yahoo.com`).
+    const COOKIE_URL: &'static str = "https://query1.finance.yahoo.com/";
+
+    fn capture(crumb: &str, jar: &Jar) -> Self {
+        let cookies = Self::COOKIE_URL
+            .parse()
+            .ok()
+            .and_then(|url| jar.cookies(&url))
+            .and_then(|header| header.to_str().ok().map(str::to_string))
+            .map(|header| header.split("; ").map(str::to_string).collect())
+            .unwrap_or_default();
+        Self {
+            crumb: crumb.to_string(),
+            cookies,
+            saved_at: chrono::Utc::now().timestamp(),
+        }
+    }
+
+    /// Read a session that is younger than [`AUTH_MAX_AGE`]. Missing,
+    /// unreadable, and stale files all mean "authenticate again".
+    fn load(path: &Path) -> Option<Self> {
+        let session: Self = serde_json::from_slice(&std::fs::read(path).ok()?).ok()?;
+        let age = chrono::Utc::now().timestamp() - session.saved_at;
+        (!session.crumb.is_empty() && (0..AUTH_MAX_AGE.as_secs() as i64).contains(&age))
+            .then_some(session)
+    }
+
+    /// Best-effort write, readable only by the owner on Unix since the
+    /// cookies authenticate requests.
+    fn save(&self, path: &Path) {
+        let write = || -> std::io::Result<()> {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            let mut options = std::fs::OpenOptions::new();
+            options.write(true).create(true).truncate(true);
+            #[cfg(unix)]
+            std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+            let mut file = options.open(path)?;
+            std::io::Write::write_all(&mut file, &serde_json::to_vec(self)?)
+        };
+        if let Err(e) = write() {
+            warn!("Failed to persist session to {}: {}", path.display(), e);
+        }
+    }
+
+    fn restore(self, jar: Arc<Jar>, http_client: reqwest::Client) -> YahooAuth {
+        if let Ok(url) = Self::COOKIE_URL.parse() {
+            for cookie in &self.cookies {
+                jar.add_cookie_str(&format!("{cookie}; Domain=.yahoo.com; Path=/"), &url);
+            }
+        }
+        let age = (chrono::Utc::now().timestamp() - self.saved_at).max(0) as u64;
+        YahooAuth {
+            crumb: self.crumb,
+            last_refresh: Instant::now()
+                .checked_sub(Duration::from_secs(age))
+                .unwrap_or_else(Instant::now),
+            http_client,
+        }
+    }
+}
+
 /// Fetch crumb token from Yahoo Finance
-async fn get_crumb(client: &reqwest::Client, crumb_url: &str) -> Result<String> {
-    let response = client
-        .get(crumb_url)
-        .send()
-        .await
+async fn get_crumb(
+    client: &reqwest::Client,
+    crumb_url: &str,
+    response_limits: &ResponseLimits,
+) -> Result<String> {
+    let response = crate::telemetry::track(crumb_url, client.get(crumb_url).send().await)
         .map_err(|e| FinanceError::InternalError(format!("Crumb request failed: {}", e)))?;
 
     if !response.status().is_success() {
@@ -120,6 +225,7 @@ async fn get_crumb(client: &reqwest::Client, crumb_url: &str) -> Result<String>
         )));
     }
 
+    let response = limits::read_limited(response, response_limits).await?;
     let crumb = response.text().await.map_err(|e| {
         FinanceError::InternalError(format!("Failed to read crumb response: {}", e))
     })?;
@@ -153,6 +259,33 @@ mod tests {
         assert!(!auth.crumb.contains("<html"));
     }
 
+    #[test]
+    fn test_persisted_session_round_trip() {
+        let path = std::env::temp_dir().join(format!("fq-session-{}.json", std::process::id()));
+        let jar = Jar::default();
+        let url = PersistedSession::COOKIE_URL.parse().unwrap();
+        jar.add_cookie_str("A3=abc; Domain=.yahoo.com; Path=/", &url);
+        PersistedSession::capture("crumb123", &jar).save(&path);
+
+        let session = PersistedSession::load(&path).unwrap();
+        assert_eq!(session.cookies, ["A3=abc"]);
+        let restored = Arc::new(Jar::default());
+        let auth = session.restore(restored.clone(), reqwest::Client::new());
+        assert_eq!(auth.crumb, "crumb123");
+        let chart_url = "https://query2.finance.yahoo.com/v8/finance/chart/AAPL"
+            .parse()
+            .unwrap();
+        assert_eq!(restored.cookies(&chart_url).unwrap(), "A3=abc");
+
+        let stale = PersistedSession {
+            saved_at: chrono::Utc::now().timestamp() - 7200,
+            ..PersistedSession::capture("crumb123", &jar)
+        };
+        stale.save(&path);
+        assert!(PersistedSession::load(&path).is_none());
+        let _ = std::fs::remove_file(&path);
+    }
+
     #[test]
     fn test_is_expired() {
         let client = reqwest::Client::new();
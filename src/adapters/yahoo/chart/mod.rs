@@ -21,19 +21,23 @@ const CHART_EVENTS: &str = "div|split|capitalGain";
 ///
 /// Empirically verified limits:
 /// - 1m:         max age 29d, max span/request 8d → chunked into 7d windows
-/// - 5m/15m/30m: max age 58d, max span/request 8d → chunked into 7d windows
-/// - 1h:         max age 728d, single request covers full window
+/// - 2m/5m/15m/30m/90m: max age 58d, max span/request 8d → chunked into 7d windows
+/// - 1h:         max age 728d, single request covers full window (4h resamples 1h)
 /// - 1d+:        no restriction
 // Test-only infrastructure — validates empirically determined Yahoo API limits.
 #[allow(dead_code)]
 pub(crate) fn intraday_limit(interval: Interval) -> Option<(i64, &'static [TimeRange])> {
     match interval {
         Interval::OneMinute => Some((29 * 24 * 3600, &[TimeRange::OneDay, TimeRange::FiveDays])),
-        Interval::FiveMinutes | Interval::FifteenMinutes | Interval::ThirtyMinutes => Some((
+        Interval::TwoMinutes
+        | Interval::FiveMinutes
+        | Interval::FifteenMinutes
+        | Interval::ThirtyMinutes
+        | Interval::NinetyMinutes => Some((
             58 * 24 * 3600,
             &[TimeRange::OneDay, TimeRange::FiveDays, TimeRange::OneMonth],
         )),
-        Interval::OneHour => Some((
+        Interval::OneHour | Interval::FourHours => Some((
             728 * 24 * 3600,
             &[
                 TimeRange::OneDay,
@@ -528,6 +532,7 @@ pub(crate) fn parse_chart_data(
         interval: None,
         range: None,
         provider_id: Some(Provider::Yahoo),
+        adjustment: None,
     })
 }
 
@@ -535,12 +540,28 @@ pub(crate) fn parse_chart_data(
 ///
 /// Delegates to [`YahooClient::get_chart`] for non-max ranges. For max-range
 /// daily/weekly requests, falls back to chunked fetching since Yahoo truncates
-/// those responses.
+/// those responses. Intervals Yahoo does not serve natively (e.g. 4h) are
+/// fetched at their [`Interval::resample_base`] and aggregated locally.
 pub async fn fetch_chart(
     client: &YahooClient,
     symbol: &str,
     interval: Interval,
     range: TimeRange,
+) -> Result<crate::models::chart::Chart> {
+    let Some(base) = interval.resample_base() else {
+        return fetch_native_chart(client, symbol, interval, range).await;
+    };
+    debug!("Resampling {} {} from {} bars", symbol, interval, base);
+    fetch_native_chart(client, symbol, base, range)
+        .await?
+        .resample(interval)
+}
+
+async fn fetch_native_chart(
+    client: &YahooClient,
+    symbol: &str,
+    interval: Interval,
+    range: TimeRange,
 ) -> Result<crate::models::chart::Chart> {
     // Max range with daily/weekly intervals requires chunking
     if matches!(range, TimeRange::Max) && matches!(interval, Interval::OneDay | Interval::OneWeek) {
@@ -551,6 +572,8 @@ pub async fn fetch_chart(
 }
 
 /// Fetch chart data with absolute date boundaries and return a canonical `Chart` model.
+///
+/// Non-native intervals are resampled as in [`fetch_chart`].
 pub async fn fetch_chart_with_dates(
     client: &YahooClient,
     symbol: &str,
@@ -558,7 +581,14 @@ pub async fn fetch_chart_with_dates(
     start: i64,
     end: i64,
 ) -> Result<crate::models::chart::Chart> {
-    client.get_chart_range(symbol, interval, start, end).await
+    let Some(base) = interval.resample_base() else {
+        return client.get_chart_range(symbol, interval, start, end).await;
+    };
+    debug!("Resampling {} {} from {} bars", symbol, interval, base);
+    client
+        .get_chart_range(symbol, base, start, end)
+        .await?
+        .resample(interval)
 }
 
 /// Fetch chart events (dividends, splits, capital gains) for a symbol.
@@ -581,11 +611,64 @@ pub async fn fetch_events(
     Ok(events)
 }
 
+/// Fetch today's pre-market volume for a symbol.
+///
+/// Requests 1-minute bars with extended hours (`includePrePost=true`) and sums
+/// the volume of bars inside the current pre-market trading period. Returns
+/// `None` when Yahoo reports no pre-market session for the symbol.
+pub(crate) async fn fetch_premarket_volume(
+    client: &YahooClient,
+    symbol: &str,
+) -> Result<Option<i64>> {
+    super::common::validate_symbol(symbol)?;
+    let url = api::chart(symbol);
+    let params = [
+        ("interval", Interval::OneMinute.as_str()),
+        ("range", TimeRange::OneDay.as_str()),
+        ("includePrePost", "true"),
+    ];
+    let response = client.request_with_params(&url, &params).await?;
+    let json: serde_json::Value = response.json().await?;
+    Ok(premarket_volume(&json))
+}
+
+/// Sum bar volumes inside `meta.currentTradingPeriod.pre` of a chart response.
+fn premarket_volume(json: &serde_json::Value) -> Option<i64> {
+    let result = json.pointer("/chart/result/0")?;
+    let pre = result.pointer("/meta/currentTradingPeriod/pre")?;
+    let start = pre.get("start")?.as_i64()?;
+    let end = pre.get("end")?.as_i64()?;
+    let timestamps = result.get("timestamp")?.as_array()?;
+    let volumes = result.pointer("/indicators/quote/0/volume")?.as_array()?;
+    Some(
+        timestamps
+            .iter()
+            .zip(volumes)
+            .filter_map(|(t, v)| Some((t.as_i64()?, v.as_i64()?)))
+            .filter(|(t, _)| (start..end).contains(t))
+            .map(|(_, v)| v)
+            .sum(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::adapters::yahoo::client::ClientConfig;
 
+    #[test]
+    fn test_premarket_volume_sums_pre_session() {
+        let json = serde_json::json!({"chart": {"result": [{
+            "meta": {"currentTradingPeriod": {"pre": {"start": 100, "end": 300}}},
+            "timestamp": [50, 100, 200, 300],
+            "indicators": {"quote": [{"volume": [7, 10, null, 99]}]}
+        }]}});
+        assert_eq!(premarket_volume(&json), Some(10));
+
+        let no_pre = serde_json::json!({"chart": {"result": [{"meta": {}}]}});
+        assert_eq!(premarket_volume(&no_pre), None);
+    }
+
     #[tokio::test]
     #[ignore] // Requires network access
     async fn test_fetch_chart() {
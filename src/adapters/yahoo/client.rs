@@ -1,4 +1,7 @@
 use super::auth::YahooAuth;
+use super::quote::batcher::QuoteBatcher;
+use crate::adapters::limits::{self, ResponseLimits};
+use crate::adapters::pool::ConnectionPool;
 use crate::constants::{Interval, Region, TimeRange};
 use crate::error::{FinanceError, Result};
 use std::time::Duration;
@@ -34,6 +37,15 @@ pub struct ClientConfig {
     pub lang: String,
     /// Region code for API requests (e.g., "US", "JP", "DE")
     pub region: String,
+    /// Maximum response size and decompression ratio
+    pub limits: ResponseLimits,
+    /// Connection pool and keep-alive settings
+    pub pool: ConnectionPool,
+    /// Window for coalescing concurrent batch quote requests (`None` disables)
+    pub quote_batch_window: Option<Duration>,
+    /// File to reuse the authenticated session (cookies + crumb) from, and
+    /// save it to after a fresh handshake (`None` always authenticates)
+    pub session_file: Option<std::path::PathBuf>,
 }
 
 impl Default for ClientConfig {
@@ -43,6 +55,10 @@ impl Default for ClientConfig {
             proxy: None,
             lang: DEFAULT_LANG.to_string(),
             region: DEFAULT_REGION.to_string(),
+            limits: ResponseLimits::default(),
+            pool: ConnectionPool::default(),
+            quote_batch_window: None,
+            session_file: None,
         }
     }
 }
@@ -56,16 +72,18 @@ pub struct YahooClient {
     auth: YahooAuth,
     /// Client configuration
     config: ClientConfig,
+    /// Coalescer for batch quote requests, when a window is configured
+    quote_batcher: Option<QuoteBatcher>,
 }
 
 impl YahooClient {
-    /// Check response status and return it if successful, or map the error code
-    fn check_response(response: reqwest::Response) -> Result<reqwest::Response> {
+    /// Check response status, then buffer the body within the configured limits
+    async fn check_response(&self, response: reqwest::Response) -> Result<reqwest::Response> {
         let status = response.status();
         if !status.is_success() {
             return Err(Self::map_http_status(status.as_u16()));
         }
-        Ok(response)
+        limits::read_limited(response, &self.config.limits).await
     }
 
     /// HTTP error mapping
@@ -118,7 +136,12 @@ impl YahooClient {
         // Authenticate with the provided configuration (timeout, proxy)
         let auth = YahooAuth::authenticate_with_config(&config).await?;
 
-        Ok(Self { auth, config })
+        let quote_batcher = config.quote_batch_window.map(QuoteBatcher::new);
+        Ok(Self {
+            auth,
+            config,
+            quote_batcher,
+        })
     }
 
     /// Make a GET request to Yahoo Finance with authentication
@@ -137,12 +160,10 @@ impl YahooClient {
         debug!("Making request to {}", url);
 
         // Send request
-        let response = request
-            .send()
-            .await
+        let response = crate::telemetry::track(url, request.send().await)
             .map_err(|e| self.map_request_error(e))?;
 
-        Self::check_response(response)
+        self.check_response(response).await
     }
 
     /// Get the client configuration
@@ -152,6 +173,11 @@ impl YahooClient {
         &self.config
     }
 
+    /// Batch quote coalescer, if a batching window is configured.
+    pub(crate) fn quote_batcher(&self) -> Option<&QuoteBatcher> {
+        self.quote_batcher.as_ref()
+    }
+
     /// Fetch logo URLs for a symbol
     ///
     /// Returns (logoUrl, companyLogoUrl) if available, None for each if not found or on error.
@@ -215,6 +241,44 @@ impl YahooClient {
         (logo_url, company_logo_url)
     }
 
+    /// Get the best bid and ask, with sizes, for a symbol
+    ///
+    /// Uses the /v7/finance/quote endpoint with only the top-of-book fields.
+    pub async fn get_top_of_book(&self, symbol: &str) -> Result<crate::models::quote::TopOfBook> {
+        info!("Fetching top of book for {}", symbol);
+        let json = crate::adapters::yahoo::quote::quotes::fetch_with_fields(
+            self,
+            &[symbol],
+            Some(&[
+                "bid",
+                "bidSize",
+                "ask",
+                "askSize",
+                "regularMarketPrice",
+                "regularMarketTime",
+                "marketState",
+                "currency",
+            ]),
+            false,
+            false,
+        )
+        .await?;
+        let item = json
+            .get("quoteResponse")
+            .and_then(|qr| qr.get("result"))
+            .and_then(|r| r.as_array())
+            .and_then(|arr| arr.first())
+            .ok_or_else(|| FinanceError::SymbolNotFound {
+                symbol: Some(symbol.to_string()),
+                context: "no quote result".into(),
+            })?;
+        crate::models::quote::TopOfBook::from_quote_result(item, chrono::Utc::now().timestamp())
+            .map_err(|e| FinanceError::ResponseStructureError {
+                field: "quoteResponse".to_string(),
+                context: e,
+            })
+    }
+
     /// Make a POST request with JSON body and crumb authentication
     ///
     /// Used for endpoints that require POST with JSON payload (e.g., custom screeners)
@@ -241,12 +305,10 @@ impl YahooClient {
 
         debug!("Making POST request to {}", url_with_crumb);
 
-        let response = request
-            .send()
-            .await
+        let response = crate::telemetry::track(url, request.send().await)
             .map_err(|e| self.map_request_error(e))?;
 
-        Self::check_response(response)
+        self.check_response(response).await
     }
 
     /// Make a GET request with query parameters and crumb authentication
@@ -267,12 +329,10 @@ impl YahooClient {
             url, self.config.lang, self.config.region
         );
 
-        let response = request
-            .send()
-            .await
+        let response = crate::telemetry::track(url, request.send().await)
             .map_err(|e| self.map_request_error(e))?;
 
-        Self::check_response(response)
+        self.check_response(response).await
     }
 
     /// Fetch batch quotes for multiple symbols
@@ -395,6 +455,7 @@ impl YahooClient {
             interval: None,
             range: None,
             provider_id: Some(crate::Provider::Yahoo),
+            adjustment: None,
         })
     }
 
@@ -494,6 +555,7 @@ impl YahooClient {
             interval: None,
             range: None,
             provider_id: Some(crate::Provider::Yahoo),
+            adjustment: None,
         })
     }
 
@@ -804,6 +866,7 @@ impl YahooClient {
     /// # Arguments
     ///
     /// * `screener_type` - The predefined screener type to use
+    /// * `start` - Zero-based index of the first result
     /// * `count` - Number of results to return (max 250)
     ///
     /// # Example
@@ -812,19 +875,21 @@ impl YahooClient {
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let client = finance_query::YahooClient::new(Default::default()).await?;
     /// use finance_query::Screener;
-    /// let gainers = client.get_screener(Screener::DayGainers, 25).await?;
-    /// let losers = client.get_screener(Screener::DayLosers, 25).await?;
-    /// let actives = client.get_screener(Screener::MostActives, 25).await?;
-    /// let shorted = client.get_screener(Screener::MostShortedStocks, 25).await?;
+    /// let gainers = client.get_screener(Screener::DayGainers, 0, 25).await?;
+    /// let losers = client.get_screener(Screener::DayLosers, 0, 25).await?;
+    /// let actives = client.get_screener(Screener::MostActives, 0, 25).await?;
+    /// let shorted = client.get_screener(Screener::MostShortedStocks, 0, 25).await?;
     /// # Ok(())
     /// # }
     /// ```
     pub async fn get_screener(
         &self,
         screener_type: crate::constants::screeners::Screener,
+        start: u32,
         count: u32,
     ) -> Result<crate::models::discovery::screeners::ScreenerResults> {
-        let url = crate::adapters::yahoo::endpoints::builders::screener(screener_type, count);
+        let url =
+            crate::adapters::yahoo::endpoints::builders::screener(screener_type, start, count);
         let response = self.request_with_crumb(&url).await?;
         let json: serde_json::Value = response.json().await?;
         crate::models::discovery::screeners::ScreenerResults::from_response(&json).map_err(|e| {
@@ -864,9 +929,18 @@ impl YahooClient {
     pub async fn custom_screener<F: crate::models::discovery::screeners::ScreenerField>(
         &self,
         query: crate::models::discovery::screeners::ScreenerQuery<F>,
+    ) -> Result<crate::models::discovery::screeners::ScreenerResults> {
+        self.custom_screener_body(&query).await
+    }
+
+    /// Execute a custom screener from any serializable request body, e.g. a
+    /// query saved as JSON.
+    pub(crate) async fn custom_screener_body<T: serde::Serialize + ?Sized>(
+        &self,
+        body: &T,
     ) -> Result<crate::models::discovery::screeners::ScreenerResults> {
         let url = crate::adapters::yahoo::endpoints::builders::custom_screener();
-        let response = self.request_post_with_crumb(&url, &query).await?;
+        let response = self.request_post_with_crumb(&url, body).await?;
         let json: serde_json::Value = response.json().await?;
         crate::models::discovery::screeners::ScreenerResults::from_custom_response(&json).map_err(
             |e| crate::error::FinanceError::ResponseStructureError {
@@ -1113,6 +1187,183 @@ impl YahooClient {
         let json: serde_json::Value = response.json().await?;
         Ok(crate::models::discovery::trending::TrendingQuote::from_response(json)?)
     }
+
+    /// Get US companies reporting earnings between two dates (inclusive)
+    ///
+    /// Pages through the visualization endpoint until every matching row is
+    /// fetched, sorted by report time.
+    pub async fn get_earnings_calendar(
+        &self,
+        start: chrono::NaiveDate,
+        end: chrono::NaiveDate,
+    ) -> Result<Vec<crate::models::calendar::EarningsCalendarEntry>> {
+        info!("Fetching earnings calendar ({} to {})", start, end);
+        self.get_visualization(
+            "earnings",
+            &[
+                "ticker",
+                "companyshortname",
+                "eventname",
+                "startdatetime",
+                "startdatetimetype",
+                "epsestimate",
+                "epsactual",
+                "epssurprisepct",
+                "intradaymarketcap",
+            ],
+            (start, end),
+            Some("us"),
+            "earnings_calendar",
+            crate::models::calendar::EarningsCalendarEntry::from_visualization,
+        )
+        .await
+    }
+
+    /// Get IPOs listing between two dates (inclusive), sorted by IPO date
+    pub async fn get_ipo_calendar(
+        &self,
+        start: chrono::NaiveDate,
+        end: chrono::NaiveDate,
+    ) -> Result<Vec<crate::models::calendar::IpoCalendarEntry>> {
+        info!("Fetching IPO calendar ({} to {})", start, end);
+        self.get_visualization(
+            "ipo_info",
+            &[
+                "ticker",
+                "companyshortname",
+                "exchange_short_name",
+                "filingdate",
+                "startdatetime",
+                "amendeddate",
+                "pricefrom",
+                "priceto",
+                "offerprice",
+                "currencyname",
+                "shares",
+                "dealtype",
+            ],
+            (start, end),
+            None,
+            "ipo_calendar",
+            crate::models::calendar::IpoCalendarEntry::from_visualization,
+        )
+        .await
+    }
+
+    /// Get stock splits taking effect between two dates (inclusive), sorted by date
+    pub async fn get_splits_calendar(
+        &self,
+        start: chrono::NaiveDate,
+        end: chrono::NaiveDate,
+    ) -> Result<Vec<crate::models::calendar::SplitCalendarEntry>> {
+        info!("Fetching splits calendar ({} to {})", start, end);
+        self.get_visualization(
+            "splits",
+            &[
+                "ticker",
+                "companyshortname",
+                "startdatetime",
+                "optionable",
+                "old_share_worth",
+                "share_worth",
+            ],
+            (start, end),
+            None,
+            "splits_calendar",
+            crate::models::calendar::SplitCalendarEntry::from_visualization,
+        )
+        .await
+    }
+
+    /// Get macroeconomic releases between two dates (inclusive), sorted by
+    /// release time
+    ///
+    /// With a region, only that country's releases are kept.
+    pub async fn get_economic_calendar(
+        &self,
+        region: Option<Region>,
+        start: chrono::NaiveDate,
+        end: chrono::NaiveDate,
+    ) -> Result<Vec<crate::models::calendar::EconomicEvent>> {
+        info!("Fetching economic calendar ({} to {})", start, end);
+        let mut events = self
+            .get_visualization(
+                "economic_event",
+                &[
+                    "econ_release",
+                    "country_code",
+                    "startdatetime",
+                    "period",
+                    "after_release_actual",
+                    "consensus_estimate",
+                    "prior_release_actual",
+                    "originally_reported_actual",
+                ],
+                (start, end),
+                None,
+                "economic_calendar",
+                crate::models::calendar::EconomicEvent::from_visualization,
+            )
+            .await?;
+        if let Some(region) = region {
+            events.retain(|e| e.country.as_deref() == Some(region.region()));
+        }
+        Ok(events)
+    }
+
+    /// Page through one visualization entity type over an inclusive date
+    /// window, sorted ascending by `startdatetime`
+    async fn get_visualization<T>(
+        &self,
+        entity: &str,
+        include_fields: &[&str],
+        (start, end): (chrono::NaiveDate, chrono::NaiveDate),
+        region: Option<&str>,
+        field: &str,
+        parse: crate::models::calendar::ParsePage<T>,
+    ) -> Result<Vec<T>> {
+        const PAGE_SIZE: usize = 100;
+        const MAX_PAGES: usize = 50;
+
+        let url = format!(
+            "{}?lang=en-US&region=US",
+            crate::adapters::yahoo::endpoints::api::VISUALIZATION
+        );
+        let start_str = start.format("%Y-%m-%d").to_string();
+        let end_str = (end + chrono::Days::new(1)).format("%Y-%m-%d").to_string();
+        let mut operands = vec![
+            serde_json::json!({"operator": "gte", "operands": ["startdatetime", start_str]}),
+            serde_json::json!({"operator": "lt", "operands": ["startdatetime", end_str]}),
+        ];
+        if let Some(region) = region {
+            operands.push(serde_json::json!({"operator": "eq", "operands": ["region", region]}));
+        }
+
+        let mut entries = Vec::new();
+        for page in 0..MAX_PAGES {
+            let body = serde_json::json!({
+                "sortType": "ASC",
+                "entityIdType": entity,
+                "sortField": "startdatetime",
+                "includeFields": include_fields,
+                "query": {"operator": "and", "operands": operands},
+                "offset": page * PAGE_SIZE,
+                "size": PAGE_SIZE
+            });
+            let response = self.request_post_with_crumb(&url, &body).await?;
+            let json: serde_json::Value = response.json().await?;
+            let (rows, total) = parse(&json).map_err(|e| FinanceError::ResponseStructureError {
+                field: field.to_string(),
+                context: e,
+            })?;
+            let fetched = rows.len();
+            entries.extend(rows);
+            if fetched < PAGE_SIZE || (page + 1) * PAGE_SIZE >= total {
+                break;
+            }
+        }
+        Ok(entries)
+    }
 }
 
 #[cfg(test)]
@@ -5,9 +5,10 @@
 
 use crate::adapters::yahoo::client::YahooClient;
 use crate::error::{FinanceError, Result};
-use crate::models::corporate::transcript::{Transcript, TranscriptWithMeta};
+use crate::models::corporate::transcript::{MatchedTranscript, Transcript, TranscriptWithMeta};
 use crate::models::quote::quote_type::QuoteTypeResponse;
 use crate::scrapers::yahoo_earnings::{EarningsCall, scrape_earnings_calls};
+use chrono::NaiveDate;
 
 /// Fetch earnings call transcript by event ID and company ID (low-level)
 ///
@@ -75,6 +76,76 @@ pub async fn fetch_for_symbol(
     fetch(client, &call.event_id, &quartr_id).await
 }
 
+/// Fetch the earnings call closest to a fiscal quarter and year
+///
+/// Returns the exact call when it exists; otherwise the call whose fiscal
+/// period is nearest, preferring the earlier one on a tie. The response
+/// records which period was matched.
+///
+/// # Arguments
+///
+/// * `client` - Yahoo Finance client
+/// * `symbol` - Stock symbol (e.g., "AAPL", "MSFT")
+/// * `quarter` - Fiscal quarter (Q1, Q2, Q3, Q4)
+/// * `year` - Fiscal year
+pub async fn fetch_nearest_for_symbol(
+    client: &YahooClient,
+    symbol: &str,
+    quarter: &str,
+    year: i32,
+) -> Result<MatchedTranscript> {
+    let target = period_index(quarter, year).ok_or_else(|| FinanceError::InvalidParameter {
+        param: "quarter".to_string(),
+        reason: format!("Expected Q1-Q4, got '{}'", quarter),
+    })?;
+    let quartr_id = get_quartr_id(client, symbol).await?;
+    let calls = scrape_earnings_calls(symbol).await?;
+    let (call, exact) = find_nearest_call(&calls, target)?;
+    let transcript = fetch(client, &call.event_id, &quartr_id).await?;
+    Ok(MatchedTranscript::new(transcript, exact))
+}
+
+/// Fetch the latest earnings call held on or before a calendar date
+///
+/// The scraped call list carries only fiscal labels, so call dates come from
+/// the transcripts themselves. The list is ordered newest first, which lets
+/// this binary-search it and fetch O(log n) transcripts.
+///
+/// # Arguments
+///
+/// * `client` - Yahoo Finance client
+/// * `symbol` - Stock symbol (e.g., "AAPL", "MSFT")
+/// * `date` - Latest acceptable call date
+pub async fn fetch_on_or_before(
+    client: &YahooClient,
+    symbol: &str,
+    date: NaiveDate,
+) -> Result<MatchedTranscript> {
+    let quartr_id = get_quartr_id(client, symbol).await?;
+    let calls = scrape_earnings_calls(symbol).await?;
+
+    // First index whose call date is on or before `date`
+    let (mut lo, mut hi) = (0, calls.len());
+    let mut found = None;
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let transcript = fetch(client, &calls[mid].event_id, &quartr_id).await?;
+        if transcript.call_date().is_none_or(|d| d <= date) {
+            hi = mid;
+            found = Some(transcript);
+        } else {
+            lo = mid + 1;
+        }
+    }
+
+    let transcript = found.ok_or_else(|| FinanceError::ResponseStructureError {
+        field: "earnings_call".to_string(),
+        context: format!("No earnings call found on or before {}", date),
+    })?;
+    let exact = transcript.call_date() == Some(date);
+    Ok(MatchedTranscript::new(transcript, exact))
+}
+
 /// Fetch multiple earnings transcripts for a symbol
 ///
 /// # Arguments
@@ -185,6 +256,35 @@ fn find_matching_call<'a>(
     }
 }
 
+/// Position of a fiscal period on a single quarterly axis
+fn period_index(quarter: &str, year: i32) -> Option<i32> {
+    let q = match quarter.to_ascii_uppercase().as_str() {
+        "Q1" => 0,
+        "Q2" => 1,
+        "Q3" => 2,
+        "Q4" => 3,
+        _ => return None,
+    };
+    Some(year * 4 + q)
+}
+
+/// Find the call nearest a fiscal period, and whether it is an exact match
+fn find_nearest_call(calls: &[EarningsCall], target: i32) -> Result<(&EarningsCall, bool)> {
+    calls
+        .iter()
+        .filter_map(|c| {
+            let index = period_index(c.quarter.as_deref()?, c.year?)?;
+            Some((c, index))
+        })
+        // Ties go to the earlier call: it was already reported at the target date
+        .min_by_key(|(_, index)| ((index - target).abs(), *index > target))
+        .map(|(c, index)| (c, index == target))
+        .ok_or_else(|| FinanceError::ResponseStructureError {
+            field: "earnings_call".to_string(),
+            context: "No labeled earnings calls available".to_string(),
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -235,6 +335,35 @@ mod tests {
         assert_eq!(result.event_id, "2");
     }
 
+    #[test]
+    fn test_find_nearest_call() {
+        let call = |id: &str, quarter: &str, year: i32| EarningsCall {
+            event_id: id.to_string(),
+            quarter: Some(quarter.to_string()),
+            year: Some(year),
+            title: format!("{} {}", quarter, year),
+            url: "".to_string(),
+        };
+        let calls = vec![
+            call("1", "Q1", 2025),
+            call("2", "Q3", 2024),
+            call("3", "Q2", 2024),
+        ];
+
+        let (found, exact) = find_nearest_call(&calls, period_index("q3", 2024).unwrap()).unwrap();
+        assert_eq!((found.event_id.as_str(), exact), ("2", true));
+
+        // Q4 2024 is missing: Q3 2024 and Q1 2025 are equally near, earlier wins
+        let (found, exact) = find_nearest_call(&calls, period_index("Q4", 2024).unwrap()).unwrap();
+        assert_eq!((found.event_id.as_str(), exact), ("2", false));
+
+        let (found, exact) = find_nearest_call(&calls, period_index("Q1", 2030).unwrap()).unwrap();
+        assert_eq!((found.event_id.as_str(), exact), ("1", false));
+
+        assert!(period_index("H1", 2024).is_none());
+        assert!(find_nearest_call(&[], 0).is_err());
+    }
+
     #[tokio::test]
     #[ignore]
     async fn test_fetch_for_symbol_latest() {
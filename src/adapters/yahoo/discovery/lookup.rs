@@ -65,6 +65,9 @@ pub struct LookupOptions {
     pub fetch_pricing_data: bool,
     /// Region for language/region settings. If None, uses client default.
     pub region: Option<Region>,
+    /// Resolve trading status for this many top results (default: 0 = disabled)
+    /// Note: This makes one additional API call per symbol
+    pub trading_status_count: u32,
 }
 
 impl Default for LookupOptions {
@@ -75,6 +78,7 @@ impl Default for LookupOptions {
             include_logo: false,
             fetch_pricing_data: true,
             region: None,
+            trading_status_count: 0,
         }
     }
 }
@@ -115,6 +119,13 @@ impl LookupOptions {
         self.region = Some(region);
         self
     }
+
+    /// Resolve trading status (active or delisted) for the top `count` results
+    /// Note: Each symbol costs one additional API call
+    pub fn trading_status_count(mut self, count: u32) -> Self {
+        self.trading_status_count = count;
+        self
+    }
 }
 
 /// Fetch lookup results for a query
@@ -192,6 +203,23 @@ pub async fn fetch(
         json = enrich_with_logos(client, json).await?;
     }
 
+    if options.trading_status_count > 0
+        && let Some(documents) = json
+            .get_mut("finance")
+            .and_then(|f| f.get_mut("result"))
+            .and_then(|r| r.as_array_mut())
+            .and_then(|arr| arr.first_mut())
+            .and_then(|first| first.get_mut("documents"))
+            .and_then(|docs| docs.as_array_mut())
+    {
+        crate::adapters::yahoo::quote::quote_type::enrich_with_trading_status(
+            client,
+            documents,
+            options.trading_status_count as usize,
+        )
+        .await;
+    }
+
     Ok(json)
 }
 
@@ -11,7 +11,17 @@ pub async fn fetch(
     screener_type: Screener,
     count: u32,
 ) -> Result<ScreenerResults> {
-    client.get_screener(screener_type, count).await
+    client.get_screener(screener_type, 0, count).await
+}
+
+/// Fetch one page of a predefined screener, starting at result `offset`.
+pub async fn fetch_page(
+    client: &YahooClient,
+    screener_type: Screener,
+    offset: u32,
+    size: u32,
+) -> Result<ScreenerResults> {
+    client.get_screener(screener_type, offset, size).await
 }
 
 /// Fetch data using a custom screener query.
@@ -23,3 +33,11 @@ pub async fn fetch_custom<F: ScreenerField>(
 ) -> Result<ScreenerResults> {
     client.custom_screener(query).await
 }
+
+/// Fetch data using a custom screener query already serialized to JSON.
+pub async fn fetch_custom_json(
+    client: &YahooClient,
+    query: &serde_json::Value,
+) -> Result<ScreenerResults> {
+    client.custom_screener_body(query).await
+}
@@ -26,6 +26,9 @@ pub struct SearchOptions {
     pub recommend_count: u32,
     /// Region for language/region settings. If None, uses client default.
     pub region: Option<Region>,
+    /// Resolve trading status for this many top quote results (default: 0 = disabled)
+    /// Note: This makes one additional API call per symbol
+    pub trading_status_count: u32,
 }
 
 impl Default for SearchOptions {
@@ -39,6 +42,7 @@ impl Default for SearchOptions {
             enable_cultural_assets: false,
             recommend_count: 5,
             region: None,
+            trading_status_count: 0,
         }
     }
 }
@@ -96,6 +100,13 @@ impl SearchOptions {
         self.region = Some(region);
         self
     }
+
+    /// Resolve trading status (active or delisted) for the top `count` quotes
+    /// Note: Each symbol costs one additional API call
+    pub fn trading_status_count(mut self, count: u32) -> Self {
+        self.trading_status_count = count;
+        self
+    }
 }
 
 /// Search for quotes, news, and research reports
@@ -169,7 +180,20 @@ pub async fn fetch(
 
     let response = client.request_with_params(api::SEARCH, &params).await?;
 
-    Ok(response.json().await?)
+    let mut json: serde_json::Value = response.json().await?;
+
+    if options.trading_status_count > 0
+        && let Some(quotes) = json.get_mut("quotes").and_then(|q| q.as_array_mut())
+    {
+        crate::adapters::yahoo::quote::quote_type::enrich_with_trading_status(
+            client,
+            quotes,
+            options.trading_status_count as usize,
+        )
+        .await;
+    }
+
+    Ok(json)
 }
 
 #[cfg(test)]
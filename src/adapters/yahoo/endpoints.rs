@@ -89,6 +89,10 @@ pub mod api {
         format!("{}/v1/finance/trending/{}", YAHOO_FINANCE_QUERY2, region)
     }
 
+    /// Visualization endpoint (POST), used for the earnings, IPO, and splits calendars
+    pub const VISUALIZATION: &str =
+        const_format::concatcp!(YAHOO_FINANCE_QUERY1, "/v1/finance/visualization");
+
     /// Batch sparkline data endpoint
     pub const SPARK: &str = const_format::concatcp!(YAHOO_FINANCE_QUERY1, "/v7/finance/spark");
 }
@@ -98,12 +102,13 @@ pub mod builders {
     use super::Screener;
     use super::base::*;
 
-    /// Screener endpoint for predefined screeners
-    pub fn screener(screener_type: Screener, count: u32) -> String {
+    /// Screener endpoint for predefined screeners, starting at result `start`
+    pub fn screener(screener_type: Screener, start: u32, count: u32) -> String {
         format!(
-            "{}/v1/finance/screener/predefined/saved?count={}&formatted=true&scrIds={}",
+            "{}/v1/finance/screener/predefined/saved?count={}&start={}&formatted=true&scrIds={}",
             YAHOO_FINANCE_QUERY1,
             count,
+            start,
             screener_type.as_scr_id()
         )
     }
@@ -1,10 +1,11 @@
 //! Financials timeseries endpoint
 //!
-//! Fetches financial statement data over time (income statement, balance sheet, cash flow).
+//! Fetches financial statement data over time (income statement, balance sheet, cash flow)
+//! and the shares-outstanding history.
 
 use crate::adapters::yahoo::client::YahooClient;
 use crate::constants::{Frequency, StatementType};
-use crate::error::Result;
+use crate::error::{FinanceError, Result};
 use crate::models::fundamentals::FinancialStatement;
 
 /// Fetch financial statement data for a symbol
@@ -37,6 +38,49 @@ pub async fn fetch(
         .await
 }
 
+/// Fetch the shares-outstanding history for a symbol
+///
+/// Calls the timeseries endpoint without a `type` filter, which returns the
+/// full `shares_out` series between `period1` and `period2` (Unix seconds).
+/// Returns `(timestamp, shares)` pairs in the order Yahoo reports them.
+pub(crate) async fn fetch_shares_history(
+    client: &YahooClient,
+    symbol: &str,
+    period1: i64,
+    period2: i64,
+) -> Result<Vec<(i64, i64)>> {
+    super::common::validate_symbol(symbol)?;
+    let url = crate::adapters::yahoo::endpoints::api::financials(symbol);
+    let params = [
+        ("symbol", symbol.to_string()),
+        ("period1", period1.to_string()),
+        ("period2", period2.to_string()),
+    ];
+    let response = client.request_with_params(&url, &params).await?;
+    let json: serde_json::Value = response.json().await?;
+    parse_shares_history(&json, symbol)
+}
+
+fn parse_shares_history(json: &serde_json::Value, symbol: &str) -> Result<Vec<(i64, i64)>> {
+    let result =
+        json.pointer("/timeseries/result/0")
+            .ok_or_else(|| FinanceError::SymbolNotFound {
+                symbol: Some(symbol.to_string()),
+                context: "No shares outstanding data".to_string(),
+            })?;
+    let (Some(timestamps), Some(shares)) = (
+        result.get("timestamp").and_then(|v| v.as_array()),
+        result.get("shares_out").and_then(|v| v.as_array()),
+    ) else {
+        return Ok(Vec::new());
+    };
+    Ok(timestamps
+        .iter()
+        .zip(shares)
+        .filter_map(|(t, s)| Some((t.as_i64()?, s.as_f64()? as i64)))
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -52,4 +96,22 @@ mod tests {
         assert_eq!(statement.symbol, "AAPL");
         assert!(statement.statement.contains_key("TotalRevenue"));
     }
+
+    #[test]
+    fn test_parse_shares_history() {
+        let json = serde_json::json!({
+            "timeseries": {"result": [{
+                "timestamp": [1_700_000_000, 1_700_086_400],
+                "shares_out": [1_000_000, 1_050_000.0]
+            }]}
+        });
+        let parsed = parse_shares_history(&json, "SPY").unwrap();
+        assert_eq!(
+            parsed,
+            [(1_700_000_000, 1_000_000), (1_700_086_400, 1_050_000)]
+        );
+
+        let empty = serde_json::json!({"timeseries": {"result": [{"meta": {}}]}});
+        assert!(parse_shares_history(&empty, "SPY").unwrap().is_empty());
+    }
 }
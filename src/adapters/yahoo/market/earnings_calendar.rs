@@ -0,0 +1,33 @@
+/// Earnings calendar endpoint
+///
+/// Fetch companies reporting earnings in a date window from Yahoo Finance.
+use crate::adapters::yahoo::client::YahooClient;
+use crate::error::Result;
+use crate::models::calendar::EarningsCalendarEntry;
+use chrono::NaiveDate;
+
+/// Fetch companies reporting earnings between `start` and `end` (inclusive).
+///
+/// Delegates to [`YahooClient::get_earnings_calendar`] for the typed result.
+pub async fn fetch(
+    client: &YahooClient,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<Vec<EarningsCalendarEntry>> {
+    client.get_earnings_calendar(start, end).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::yahoo::client::ClientConfig;
+
+    #[tokio::test]
+    #[ignore] // Requires network access
+    async fn test_fetch_earnings_calendar() {
+        let client = YahooClient::new(ClientConfig::default()).await.unwrap();
+        let today = chrono::Utc::now().date_naive();
+        let result = fetch(&client, today, today + chrono::Days::new(7)).await;
+        assert!(result.is_ok());
+    }
+}
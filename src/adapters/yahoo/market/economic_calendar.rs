@@ -0,0 +1,42 @@
+/// Economic calendar endpoint
+///
+/// Fetch scheduled macroeconomic releases in a date window from Yahoo Finance.
+use crate::adapters::yahoo::client::YahooClient;
+use crate::constants::Region;
+use crate::error::Result;
+use crate::models::calendar::EconomicEvent;
+use chrono::NaiveDate;
+
+/// Fetch economic releases between `start` and `end` (inclusive), optionally
+/// limited to one country.
+///
+/// Delegates to [`YahooClient::get_economic_calendar`] for the typed result.
+pub async fn fetch(
+    client: &YahooClient,
+    region: Option<Region>,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<Vec<EconomicEvent>> {
+    client.get_economic_calendar(region, start, end).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::yahoo::client::ClientConfig;
+
+    #[tokio::test]
+    #[ignore] // Requires network access
+    async fn test_fetch_economic_calendar() {
+        let client = YahooClient::new(ClientConfig::default()).await.unwrap();
+        let today = chrono::Utc::now().date_naive();
+        let result = fetch(
+            &client,
+            Some(Region::UnitedStates),
+            today,
+            today + chrono::Days::new(7),
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+}
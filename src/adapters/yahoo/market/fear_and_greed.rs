@@ -16,12 +16,10 @@ pub(crate) async fn fetch() -> Result<FearAndGreed> {
     // OnceLock<reqwest::Client> would bind the pool to whichever tokio runtime first
     // initialises it; if that runtime later drops (e.g. in tests, or on a server
     // restart with re-init), subsequent calls on a new runtime fail with DispatchGone.
-    let response = reqwest::Client::builder()
+    let client = reqwest::Client::builder()
         .timeout(Duration::from_secs(30))
-        .build()?
-        .get(API_URL)
-        .send()
-        .await?;
+        .build()?;
+    let response = crate::telemetry::track(API_URL, client.get(API_URL).send().await)?;
 
     let status = response.status().as_u16();
     if !response.status().is_success() {
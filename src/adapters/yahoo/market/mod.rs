@@ -1,7 +1,11 @@
 pub mod currencies;
+pub mod earnings_calendar;
+pub mod economic_calendar;
 pub mod fear_and_greed;
 pub mod hours;
 pub mod industries;
+pub mod ipo_calendar;
 pub mod market_summary;
 pub mod sectors;
+pub mod splits_calendar;
 pub mod trending;
@@ -0,0 +1,33 @@
+/// Stock-split calendar endpoint
+///
+/// Fetch stock splits taking effect in a date window from Yahoo Finance.
+use crate::adapters::yahoo::client::YahooClient;
+use crate::error::Result;
+use crate::models::calendar::SplitCalendarEntry;
+use chrono::NaiveDate;
+
+/// Fetch stock splits taking effect between `start` and `end` (inclusive).
+///
+/// Delegates to [`YahooClient::get_splits_calendar`] for the typed result.
+pub async fn fetch(
+    client: &YahooClient,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Result<Vec<SplitCalendarEntry>> {
+    client.get_splits_calendar(start, end).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::yahoo::client::ClientConfig;
+
+    #[tokio::test]
+    #[ignore] // Requires network access
+    async fn test_fetch_splits_calendar() {
+        let client = YahooClient::new(ClientConfig::default()).await.unwrap();
+        let today = chrono::Utc::now().date_naive();
+        let result = fetch(&client, today, today + chrono::Days::new(7)).await;
+        assert!(result.is_ok());
+    }
+}
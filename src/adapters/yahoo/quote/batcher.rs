@@ -0,0 +1,194 @@
+//! Request coalescing for the batch quote endpoint.
+//!
+//! When a batching window is configured
+//! ([`FinanceClientBuilder::quote_batch_window`](crate::FinanceClientBuilder::quote_batch_window)),
+//! independent callers that ask for quotes on the same session within the
+//! window are merged into a single `/v7/finance/quote` request. The first
+//! caller opens a batch and schedules the flush; later callers add their
+//! symbols to it. Every caller then receives the entries for its own symbols
+//! from the shared response.
+
+use super::quotes::fetch_quotes_batch;
+use crate::adapters::yahoo::client::YahooClient;
+use crate::error::{FinanceError, Result};
+use crate::models::quote::QuoteSummaryResponse;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::watch;
+use tracing::debug;
+
+/// Most symbols merged into one upstream call. A full batch stops accepting
+/// callers and the next caller opens a new one.
+const MAX_BATCH_SYMBOLS: usize = 200;
+
+type BatchOutcome =
+    std::result::Result<Arc<HashMap<String, QuoteSummaryResponse>>, Arc<FinanceError>>;
+
+/// A batch that is still collecting symbols.
+struct Batch {
+    symbols: Mutex<Vec<String>>,
+    done: watch::Sender<Option<BatchOutcome>>,
+}
+
+/// Per-session coalescer for batch quote requests.
+pub(crate) struct QuoteBatcher {
+    window: Duration,
+    open: Mutex<Option<Arc<Batch>>>,
+}
+
+impl QuoteBatcher {
+    pub(crate) fn new(window: Duration) -> Self {
+        Self {
+            window,
+            open: Mutex::new(None),
+        }
+    }
+
+    /// Add `symbols` to the open batch (opening one if needed) and wait for
+    /// the shared response.
+    async fn fetch(
+        &self,
+        client: &Arc<YahooClient>,
+        symbols: &[&str],
+    ) -> Result<Vec<(String, QuoteSummaryResponse)>> {
+        let mut done = self.join(client, symbols);
+        let outcome = match done.wait_for(Option::is_some).await {
+            Ok(outcome) => outcome.clone(),
+            Err(_) => {
+                return Err(FinanceError::InternalError(
+                    "quote batch was dropped before completing".to_string(),
+                ));
+            }
+        };
+        match outcome {
+            Some(Ok(quotes)) => Ok(quotes
+                .iter()
+                .filter(|(symbol, _)| symbols.iter().any(|s| s.eq_ignore_ascii_case(symbol)))
+                .map(|(symbol, quote)| (symbol.clone(), quote.clone()))
+                .collect()),
+            Some(Err(e)) => Err(share_error(&e)),
+            None => unreachable!("wait_for only returns a completed batch"),
+        }
+    }
+
+    /// Register `symbols` with the open batch and subscribe to its outcome.
+    fn join(
+        &self,
+        client: &Arc<YahooClient>,
+        symbols: &[&str],
+    ) -> watch::Receiver<Option<BatchOutcome>> {
+        let mut open = self.open.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(batch) = open.as_ref() {
+            let mut pending = batch.symbols.lock().unwrap_or_else(|e| e.into_inner());
+            let added: Vec<&str> = symbols
+                .iter()
+                .copied()
+                .filter(|s| !pending.iter().any(|p| p.eq_ignore_ascii_case(s)))
+                .collect();
+            if pending.len() + added.len() <= MAX_BATCH_SYMBOLS {
+                pending.extend(added.into_iter().map(String::from));
+                return batch.done.subscribe();
+            }
+        }
+
+        let (done, rx) = watch::channel(None);
+        let batch = Arc::new(Batch {
+            symbols: Mutex::new(symbols.iter().map(|s| s.to_string()).collect()),
+            done,
+        });
+        *open = Some(Arc::clone(&batch));
+
+        // The flush runs detached so a cancelled caller never strands the
+        // others waiting on the same batch.
+        let client = Arc::clone(client);
+        let window = self.window;
+        tokio::spawn(async move {
+            tokio::time::sleep(window).await;
+            if let Some(batcher) = client.quote_batcher() {
+                batcher.close(&batch);
+            }
+            let merged =
+                std::mem::take(&mut *batch.symbols.lock().unwrap_or_else(|e| e.into_inner()));
+            let refs: Vec<&str> = merged.iter().map(String::as_str).collect();
+            debug!("Flushing coalesced quote batch of {} symbols", refs.len());
+            let outcome = fetch_quotes_batch(&client, &refs)
+                .await
+                .map(|quotes| Arc::new(quotes.into_iter().collect()))
+                .map_err(Arc::new);
+            batch.done.send_replace(Some(outcome));
+        });
+        rx
+    }
+
+    /// Stop `batch` from accepting callers if it is still the open one.
+    fn close(&self, batch: &Arc<Batch>) {
+        let mut open = self.open.lock().unwrap_or_else(|e| e.into_inner());
+        if open.as_ref().is_some_and(|b| Arc::ptr_eq(b, batch)) {
+            *open = None;
+        }
+    }
+}
+
+/// Fetch batch quotes, coalescing with concurrent callers when the session
+/// has a batching window configured.
+pub(crate) async fn fetch_coalesced(
+    client: &Arc<YahooClient>,
+    symbols: &[&str],
+) -> Result<Vec<(String, QuoteSummaryResponse)>> {
+    match client.quote_batcher() {
+        Some(batcher) if !symbols.is_empty() && symbols.len() <= MAX_BATCH_SYMBOLS => {
+            batcher.fetch(client, symbols).await
+        }
+        _ => fetch_quotes_batch(client, symbols).await,
+    }
+}
+
+/// Rebuild a shared batch error for one caller.
+///
+/// `FinanceError` is not `Clone`; variants that callers branch on (rate
+/// limits, timeouts, auth, server errors) are reproduced exactly and the
+/// rest keep their message.
+fn share_error(e: &FinanceError) -> FinanceError {
+    match e {
+        FinanceError::AuthenticationFailed { context } => FinanceError::AuthenticationFailed {
+            context: context.clone(),
+        },
+        FinanceError::SymbolNotFound { symbol, context } => FinanceError::SymbolNotFound {
+            symbol: symbol.clone(),
+            context: context.clone(),
+        },
+        FinanceError::RateLimited { retry_after } => FinanceError::RateLimited {
+            retry_after: *retry_after,
+        },
+        FinanceError::Timeout { timeout_ms } => FinanceError::Timeout {
+            timeout_ms: *timeout_ms,
+        },
+        FinanceError::ServerError { status, context } => FinanceError::ServerError {
+            status: *status,
+            context: context.clone(),
+        },
+        FinanceError::InvalidParameter { param, reason } => FinanceError::InvalidParameter {
+            param: param.clone(),
+            reason: reason.clone(),
+        },
+        other => FinanceError::ApiError(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_share_error_preserves_retry_semantics() {
+        let shared = share_error(&FinanceError::RateLimited {
+            retry_after: Some(3),
+        });
+        assert_eq!(shared.retry_after_secs(), Some(3));
+        assert!(shared.is_retriable());
+
+        let shared = share_error(&FinanceError::UnexpectedResponse("HTTP 418".into()));
+        assert!(matches!(shared, FinanceError::ApiError(ref m) if m.contains("HTTP 418")));
+    }
+}
@@ -1,3 +1,4 @@
+pub(crate) mod batcher;
 pub mod quote_type;
 pub mod quotes;
 pub mod spark;
@@ -4,6 +4,9 @@
 use crate::adapters::yahoo::client::YahooClient;
 use crate::adapters::yahoo::endpoints::api;
 use crate::error::Result;
+use crate::models::discovery::trading_status::TradingStatus;
+use crate::models::quote::quote_type::QuoteTypeResponse;
+use std::collections::HashMap;
 use tracing::info;
 
 /// Fetch quote type data including company ID (quartrId)
@@ -23,7 +26,6 @@ use tracing::info;
 /// # Ok(())
 /// # }
 /// ```
-#[allow(dead_code)]
 pub(crate) async fn fetch(client: &YahooClient, symbol: &str) -> Result<serde_json::Value> {
     crate::adapters::yahoo::common::validate_symbol(symbol)?;
 
@@ -35,11 +37,100 @@ pub(crate) async fn fetch(client: &YahooClient, symbol: &str) -> Result<serde_js
     Ok(response.json().await?)
 }
 
+/// Resolve the trading status of each symbol, one quote type request apiece.
+///
+/// A symbol the endpoint no longer resolves (empty result or HTTP 404) is
+/// [`TradingStatus::Delisted`]. Symbols whose request fails for any other
+/// reason are left out of the map rather than guessed.
+pub(crate) async fn fetch_trading_status(
+    client: &YahooClient,
+    symbols: &[String],
+) -> HashMap<String, TradingStatus> {
+    info!("Fetching trading status for {} symbols", symbols.len());
+
+    let requests = symbols.iter().map(|symbol| async move {
+        let status = match fetch(client, symbol).await {
+            Ok(json) => trading_status_from_json(json),
+            Err(e) if e.is_not_found() => Some(TradingStatus::Delisted),
+            Err(_) => None,
+        };
+        status.map(|s| (symbol.clone(), s))
+    });
+
+    futures::future::join_all(requests)
+        .await
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+/// Set `tradingStatus` on the first `count` result objects that carry a
+/// `symbol`, for search and lookup responses.
+pub(crate) async fn enrich_with_trading_status(
+    client: &YahooClient,
+    results: &mut [serde_json::Value],
+    count: usize,
+) {
+    let symbols: Vec<String> = results
+        .iter()
+        .filter_map(|r| r.get("symbol").and_then(|s| s.as_str()))
+        .take(count)
+        .map(String::from)
+        .collect();
+    if symbols.is_empty() {
+        return;
+    }
+
+    let statuses = fetch_trading_status(client, &symbols).await;
+    for result in results.iter_mut() {
+        if let Some(status) = result
+            .get("symbol")
+            .and_then(|s| s.as_str())
+            .and_then(|s| statuses.get(s))
+            && let Some(obj) = result.as_object_mut()
+        {
+            obj.insert("tradingStatus".to_string(), serde_json::json!(status));
+        }
+    }
+}
+
+/// Trading status from a quote type response, or `None` if it doesn't parse.
+fn trading_status_from_json(json: serde_json::Value) -> Option<TradingStatus> {
+    let response: QuoteTypeResponse = serde_json::from_value(json).ok()?;
+    Some(if response.quote_type.result.is_empty() {
+        TradingStatus::Delisted
+    } else {
+        TradingStatus::Active
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::adapters::yahoo::client::ClientConfig;
 
+    #[test]
+    fn test_trading_status_from_json() {
+        let active = serde_json::json!({
+            "quoteType": {
+                "result": [{"symbol": "AAPL", "quoteType": "EQUITY", "exchange": "NMS"}],
+                "error": null
+            }
+        });
+        assert_eq!(
+            trading_status_from_json(active),
+            Some(TradingStatus::Active)
+        );
+
+        let delisted = serde_json::json!({"quoteType": {"result": [], "error": null}});
+        assert_eq!(
+            trading_status_from_json(delisted),
+            Some(TradingStatus::Delisted)
+        );
+
+        assert_eq!(trading_status_from_json(serde_json::json!({})), None);
+    }
+
     #[tokio::test]
     #[ignore] // Requires network access
     async fn test_fetch_quote_type() {
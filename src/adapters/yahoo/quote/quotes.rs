@@ -6,7 +6,8 @@
 use crate::adapters::yahoo::client::YahooClient;
 use crate::adapters::yahoo::endpoints::api;
 use crate::error::Result;
-use crate::models::quote::{FormattedValue, Price, QuoteSummaryResponse};
+use crate::models::quote::book::size;
+use crate::models::quote::{FormattedValue, Price, QuoteSummaryResponse, SummaryDetail};
 use tracing::info;
 
 /// Fetch batch quotes for multiple symbols
@@ -188,6 +189,7 @@ pub(crate) async fn fetch_quotes_batch(
             let response = QuoteSummaryResponse {
                 symbol: symbol.clone(),
                 price: Some(price),
+                summary_detail: top_of_book(item),
                 ..Default::default()
             };
             quotes.push((symbol, response));
@@ -196,6 +198,21 @@ pub(crate) async fn fetch_quotes_batch(
     Ok(quotes)
 }
 
+/// Carry bid/ask and their sizes into a summary-detail module so batch
+/// quotes expose the same top-of-book fields as single-symbol quotes.
+fn top_of_book(item: &serde_json::Value) -> Option<SummaryDetail> {
+    let fields = serde_json::json!({
+        "bid": item["bid"].as_f64(),
+        "bidSize": size(&item["bidSize"]),
+        "ask": item["ask"].as_f64(),
+        "askSize": size(&item["askSize"]),
+    });
+    if fields.as_object()?.values().all(serde_json::Value::is_null) {
+        return None;
+    }
+    serde_json::from_value(fields).ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
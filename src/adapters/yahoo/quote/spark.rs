@@ -46,6 +46,13 @@ pub async fn fetch(
         });
     }
 
+    if interval.is_resampled() {
+        return Err(crate::error::FinanceError::InvalidParameter {
+            param: "interval".to_string(),
+            reason: format!("Sparklines do not support the resampled {interval} interval"),
+        });
+    }
+
     // Validate all symbols
     for symbol in symbols {
         crate::adapters::yahoo::common::validate_symbol(symbol)?;
@@ -1,11 +1,11 @@
 /// Quote summary endpoint
 ///
-/// Fetches full quote summary data for a single symbol.
-/// Uses the /v10/finance/quoteSummary endpoint with all available modules.
+/// Fetches quote summary data for a single symbol.
+/// Uses the /v10/finance/quoteSummary endpoint with all or a subset of modules.
 use crate::adapters::yahoo::client::YahooClient;
 use crate::adapters::yahoo::endpoints::api;
 use crate::error::Result;
-use crate::models::quote::QuoteSummaryResponse;
+use crate::models::quote::{QuoteModule, QuoteSummaryResponse};
 use tracing::info;
 
 /// Fetch full quote summary for a symbol
@@ -18,10 +18,25 @@ pub(crate) async fn fetch_summary(
     client: &YahooClient,
     symbol: &str,
 ) -> Result<QuoteSummaryResponse> {
-    info!("Fetching quote summary for: {}", symbol);
+    fetch_summary_modules(client, symbol, &QuoteModule::all()).await
+}
+
+/// Fetch only the given quote summary modules for a symbol
+///
+/// Modules not in `modules` are left as `None` in the response.
+pub(crate) async fn fetch_summary_modules(
+    client: &YahooClient,
+    symbol: &str,
+    modules: &[QuoteModule],
+) -> Result<QuoteSummaryResponse> {
+    info!(
+        "Fetching quote summary for: {} ({} modules)",
+        symbol,
+        modules.len()
+    );
 
     let base_url = api::quote_summary(symbol);
-    let modules = crate::models::quote::Module::all()
+    let modules = modules
         .iter()
         .map(|m| m.as_str())
         .collect::<Vec<_>>()
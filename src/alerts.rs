@@ -0,0 +1,421 @@
+//! Alerts on screener membership.
+//!
+//! A [`ScreenerAlert`] watches a predefined screener or a custom query and
+//! reports when symbols enter or leave its top N results, e.g. "tell me when
+//! any of my holdings shows up in most-shorted-stocks". Each check compares
+//! the latest results against the previous [`ScreenerSnapshot`], which can
+//! be persisted to disk so changes are detected across runs.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use finance_query::Screener;
+//! use finance_query::alerts::{self, ScreenerCondition};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut alert = alerts::on_screener(Screener::MostShortedStocks, ScreenerCondition::Enters)
+//!     .top(25)
+//!     .watch(["AAPL", "TSLA", "GME"])
+//!     .snapshot_dir("data/screener-alerts");
+//!
+//! // Run from a cron job; the first check only records the baseline
+//! for event in alert.check().await? {
+//!     println!("{} entered {} at #{}", event.symbol, event.screener, event.rank.unwrap_or(0));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Snapshots are stored as JSON in `<dir>/<name>.json`, where the name
+//! defaults to the screener id (e.g. `most_shorted_stocks`). Give custom
+//! queries distinct names with [`ScreenerAlert::name`].
+
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::constants::screeners::Screener;
+use crate::error::{FinanceError, Result};
+use crate::finance;
+use crate::models::discovery::screeners::{
+    EquityScreenerQuery, FundScreenerQuery, ScreenerResults,
+};
+
+/// Screener an alert watches.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum ScreenerSource {
+    /// A predefined Yahoo screener
+    Predefined(Screener),
+    /// A custom equity query
+    Equity(EquityScreenerQuery),
+    /// A custom mutual fund query
+    Fund(FundScreenerQuery),
+}
+
+impl From<Screener> for ScreenerSource {
+    fn from(screener: Screener) -> Self {
+        Self::Predefined(screener)
+    }
+}
+
+impl From<EquityScreenerQuery> for ScreenerSource {
+    fn from(query: EquityScreenerQuery) -> Self {
+        Self::Equity(query)
+    }
+}
+
+impl From<FundScreenerQuery> for ScreenerSource {
+    fn from(query: FundScreenerQuery) -> Self {
+        Self::Fund(query)
+    }
+}
+
+/// Which membership changes trigger an alert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ScreenerCondition {
+    /// A symbol entered the top N
+    Enters,
+    /// A symbol dropped out of the top N
+    Leaves,
+    /// Either of the above
+    Changes,
+}
+
+/// Direction of a membership change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MembershipChange {
+    /// The symbol is now in the top N
+    Entered,
+    /// The symbol is no longer in the top N
+    Left,
+}
+
+/// A triggered screener alert.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScreenerEvent {
+    /// Alert name (the screener id unless renamed)
+    pub screener: String,
+    /// Symbol that entered or left
+    pub symbol: String,
+    /// Whether it entered or left
+    pub change: MembershipChange,
+    /// 1-based rank in the latest results; `None` when the symbol left
+    pub rank: Option<usize>,
+    /// When the change was detected (Unix)
+    pub timestamp: i64,
+}
+
+/// The top N symbols of a screener at one point in time.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScreenerSnapshot {
+    /// Alert name the snapshot belongs to
+    pub screener: String,
+    /// When the results were fetched (Unix)
+    pub timestamp: i64,
+    /// Symbols in rank order
+    pub symbols: Vec<String>,
+}
+
+/// Create an alert on `source`'s top results.
+///
+/// Defaults to the top 25 and to every symbol; narrow it with
+/// [`ScreenerAlert::watch`].
+pub fn on_screener(
+    source: impl Into<ScreenerSource>,
+    condition: ScreenerCondition,
+) -> ScreenerAlert {
+    let source = source.into();
+    let name = match &source {
+        ScreenerSource::Predefined(screener) => screener.as_scr_id().to_string(),
+        ScreenerSource::Equity(_) | ScreenerSource::Fund(_) => "custom".to_string(),
+    };
+    ScreenerAlert {
+        name,
+        source,
+        condition,
+        top: 25,
+        watch: Vec::new(),
+        snapshot_dir: None,
+        last: None,
+    }
+}
+
+/// Alert on symbols entering or leaving a screener's top N.
+///
+/// Created with [`on_screener`].
+#[derive(Debug, Clone)]
+pub struct ScreenerAlert {
+    name: String,
+    source: ScreenerSource,
+    condition: ScreenerCondition,
+    top: u32,
+    watch: Vec<String>,
+    snapshot_dir: Option<PathBuf>,
+    last: Option<ScreenerSnapshot>,
+}
+
+impl ScreenerAlert {
+    /// Name used in events and as the snapshot file name.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Number of top results that count as membership (default: 25, max 250).
+    pub fn top(mut self, n: u32) -> Self {
+        self.top = n;
+        self
+    }
+
+    /// Only alert on these symbols (e.g. your holdings).
+    pub fn watch<S, I>(mut self, symbols: I) -> Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = S>,
+    {
+        self.watch = symbols
+            .into_iter()
+            .map(|s| s.into().trim().to_uppercase())
+            .filter(|s| !s.is_empty())
+            .collect();
+        self
+    }
+
+    /// Persist the last snapshot in `dir` so checks survive restarts.
+    pub fn snapshot_dir(mut self, dir: impl AsRef<Path>) -> Self {
+        self.snapshot_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Snapshot the next check compares against, if any.
+    pub fn last_snapshot(&self) -> Option<&ScreenerSnapshot> {
+        self.last.as_ref()
+    }
+
+    /// Fetch the screener and return the changes since the last check.
+    pub async fn check(&mut self) -> Result<Vec<ScreenerEvent>> {
+        let results = match &self.source {
+            ScreenerSource::Predefined(screener) => finance::screener(*screener, self.top).await?,
+            ScreenerSource::Equity(query) => {
+                finance::custom_screener(query.clone().size(self.top)).await?
+            }
+            ScreenerSource::Fund(query) => {
+                finance::custom_screener(query.clone().size(self.top)).await?
+            }
+        };
+        self.update(&results)
+    }
+
+    /// Compare `results` against the last snapshot and record them.
+    ///
+    /// The first update with no prior snapshot records a baseline and
+    /// returns no events.
+    pub fn update(&mut self, results: &ScreenerResults) -> Result<Vec<ScreenerEvent>> {
+        let timestamp = Utc::now().timestamp();
+        let mut symbols: Vec<String> = Vec::new();
+        for quote in &results.quotes {
+            let symbol = quote.symbol.to_uppercase();
+            if !symbols.contains(&symbol) {
+                symbols.push(symbol);
+            }
+        }
+        symbols.truncate(self.top as usize);
+        let current = ScreenerSnapshot {
+            screener: self.name.clone(),
+            timestamp,
+            symbols,
+        };
+
+        if self.last.is_none() {
+            self.last = self.load()?;
+        }
+        let events = match &self.last {
+            Some(previous) => self.diff(previous, &current),
+            None => Vec::new(),
+        };
+        self.save(&current)?;
+        self.last = Some(current);
+        Ok(events)
+    }
+
+    fn diff(&self, previous: &ScreenerSnapshot, current: &ScreenerSnapshot) -> Vec<ScreenerEvent> {
+        let watched = |symbol: &String| self.watch.is_empty() || self.watch.contains(symbol);
+        let event = |symbol: &String, change, rank| ScreenerEvent {
+            screener: self.name.clone(),
+            symbol: symbol.clone(),
+            change,
+            rank,
+            timestamp: current.timestamp,
+        };
+
+        let mut events = Vec::new();
+        if self.condition != ScreenerCondition::Leaves {
+            events.extend(
+                current
+                    .symbols
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, s)| watched(s) && !previous.symbols.contains(s))
+                    .map(|(i, s)| event(s, MembershipChange::Entered, Some(i + 1))),
+            );
+        }
+        if self.condition != ScreenerCondition::Enters {
+            events.extend(
+                previous
+                    .symbols
+                    .iter()
+                    .filter(|s| watched(s) && !current.symbols.contains(s))
+                    .map(|s| event(s, MembershipChange::Left, None)),
+            );
+        }
+        events
+    }
+
+    fn snapshot_path(&self) -> Result<Option<PathBuf>> {
+        let Some(dir) = &self.snapshot_dir else {
+            return Ok(None);
+        };
+        let valid = !self.name.is_empty()
+            && self
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'));
+        if !valid {
+            return Err(FinanceError::InvalidParameter {
+                param: "name".to_string(),
+                reason: format!("'{}' cannot be used as a snapshot file name", self.name),
+            });
+        }
+        Ok(Some(dir.join(format!("{}.json", self.name))))
+    }
+
+    fn load(&self) -> Result<Option<ScreenerSnapshot>> {
+        let Some(path) = self.snapshot_path()? else {
+            return Ok(None);
+        };
+        match std::fs::read(&path) {
+            Ok(bytes) => match serde_json::from_slice(&bytes) {
+                Ok(snapshot) => Ok(Some(snapshot)),
+                Err(e) => {
+                    warn!(
+                        "Ignoring corrupt screener snapshot {}: {}",
+                        path.display(),
+                        e
+                    );
+                    Ok(None)
+                }
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn save(&self, snapshot: &ScreenerSnapshot) -> Result<()> {
+        let Some(path) = self.snapshot_path()? else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Write to a temporary file and rename so a crash never leaves a partial snapshot
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, serde_json::to_vec(snapshot)?)?;
+        std::fs::rename(&tmp, &path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results(symbols: &[&str]) -> ScreenerResults {
+        serde_json::from_value(serde_json::json!({
+            "type": "most_shorted_stocks",
+            "quotes": symbols
+                .iter()
+                .map(|s| serde_json::json!({
+                    "symbol": s,
+                    "quoteType": "EQUITY",
+                    "exchange": "NYQ",
+                    "regularMarketPrice": {"raw": 10.0},
+                    "regularMarketChange": {"raw": 0.0},
+                    "regularMarketChangePercent": {"raw": 0.0}
+                }))
+                .collect::<Vec<_>>()
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_enters_and_leaves() {
+        let mut alert = on_screener(Screener::MostShortedStocks, ScreenerCondition::Changes).top(3);
+        assert!(
+            alert
+                .update(&results(&["GME", "AMC", "BBBY"]))
+                .unwrap()
+                .is_empty()
+        );
+
+        let events = alert
+            .update(&results(&["GME", "TSLA", "AMC", "AAPL"]))
+            .unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].symbol, "TSLA");
+        assert_eq!(events[0].change, MembershipChange::Entered);
+        assert_eq!(events[0].rank, Some(2));
+        assert_eq!(events[0].screener, "most_shorted_stocks");
+        // AAPL is 4th, outside the top 3
+        assert_eq!(events[1].symbol, "BBBY");
+        assert_eq!(events[1].change, MembershipChange::Left);
+        assert_eq!(events[1].rank, None);
+    }
+
+    #[test]
+    fn test_watch_and_condition_filter() {
+        let mut alert = on_screener(Screener::MostShortedStocks, ScreenerCondition::Enters)
+            .watch(["tsla", "AAPL"]);
+        alert.update(&results(&["GME", "AAPL"])).unwrap();
+        let events = alert.update(&results(&["TSLA", "NVDA"])).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].symbol, "TSLA");
+    }
+
+    #[test]
+    fn test_snapshot_persists_across_alerts() {
+        let dir = std::env::temp_dir().join(format!(
+            "finance-query-screener-alerts-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        let alert = || {
+            on_screener(
+                EquityScreenerQuery::most_shorted(),
+                ScreenerCondition::Changes,
+            )
+            .name("my-shorts")
+            .snapshot_dir(&dir)
+        };
+
+        assert!(alert().update(&results(&["GME"])).unwrap().is_empty());
+        assert!(dir.join("my-shorts.json").exists());
+
+        let mut restarted = alert();
+        let events = restarted.update(&results(&["AMC"])).unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(restarted.last_snapshot().unwrap().symbols, vec!["AMC"]);
+
+        let mut bad = alert().name("../etc");
+        assert!(bad.update(&results(&["GME"])).is_err());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
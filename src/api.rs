@@ -0,0 +1,219 @@
+//! Single-entry command API for embedding.
+//!
+//! Applications that route requests arriving at runtime — chat bots, job
+//! queues, FFI layers — can deserialize a [`Request`], pass it to [`execute`],
+//! and serialize the [`Response`], instead of matching over the crate's
+//! methods themselves.
+//!
+//! Requests are tagged by `op`:
+//!
+//! ```json
+//! {"op": "quote", "symbol": "AAPL"}
+//! {"op": "chart", "symbol": "AAPL", "interval": "1d", "range": "1mo"}
+//! {"op": "search", "query": "apple", "quotesCount": 5}
+//! {"op": "screener", "screener": "day-gainers", "count": 10}
+//! {"op": "indicators", "symbol": "AAPL", "interval": "1d", "range": "1y"}
+//! ```
+//!
+//! Responses are tagged by `type`, with the payload under `data`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use finance_query::api::{self, Request, Response};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let request: Request = serde_json::from_str(r#"{"op": "quote", "symbol": "AAPL"}"#)?;
+//! let response = api::execute(request).await?;
+//! if let Response::Quote(quote) = &response {
+//!     println!("{}", quote.symbol);
+//! }
+//! println!("{}", serde_json::to_string(&response)?);
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::{
+    Chart, Interval, Quote, Screener, ScreenerResults, SearchOptions, SearchResults, Ticker,
+    TimeRange, finance,
+};
+
+/// An operation to run with [`execute`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "camelCase", rename_all_fields = "camelCase")]
+#[non_exhaustive]
+pub enum Request {
+    /// Full quote for one symbol ([`Ticker::quote`])
+    Quote {
+        /// Symbol, e.g. `"AAPL"`
+        symbol: String,
+    },
+    /// OHLCV history ([`Ticker::chart`])
+    Chart {
+        /// Symbol
+        symbol: String,
+        /// Bar interval, e.g. `"1d"`
+        interval: Interval,
+        /// Time range, e.g. `"1mo"`
+        range: TimeRange,
+    },
+    /// Symbol and news search ([`finance::search`])
+    Search {
+        /// Search text
+        query: String,
+        /// Maximum quotes to return (Yahoo's default when omitted)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        quotes_count: Option<u32>,
+        /// Maximum news articles to return (Yahoo's default when omitted)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        news_count: Option<u32>,
+    },
+    /// Predefined screener results ([`finance::screener`])
+    Screener {
+        /// Screener, e.g. `"day-gainers"`
+        screener: Screener,
+        /// Number of results (default 25)
+        #[serde(default = "default_screener_count")]
+        count: u32,
+    },
+    /// Technical indicators summary ([`Ticker::indicators`])
+    #[cfg(feature = "indicators")]
+    Indicators {
+        /// Symbol
+        symbol: String,
+        /// Bar interval
+        interval: Interval,
+        /// Time range
+        range: TimeRange,
+    },
+}
+
+fn default_screener_count() -> u32 {
+    25
+}
+
+/// Result of a [`Request`], one variant per operation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum Response {
+    /// Result of [`Request::Quote`]
+    Quote(Box<Quote>),
+    /// Result of [`Request::Chart`]
+    Chart(Box<Chart>),
+    /// Result of [`Request::Search`]
+    Search(Box<SearchResults>),
+    /// Result of [`Request::Screener`]
+    Screener(Box<ScreenerResults>),
+    /// Result of `Request::Indicators`
+    #[cfg(feature = "indicators")]
+    Indicators(Box<crate::indicators::IndicatorsSummary>),
+}
+
+/// Run `request` and return its result.
+///
+/// Each call creates its own [`Ticker`] or client, so nothing is cached
+/// between calls; embedders issuing many requests for the same symbol should
+/// hold a `Ticker` instead.
+pub async fn execute(request: Request) -> Result<Response> {
+    Ok(match request {
+        Request::Quote { symbol } => {
+            Response::Quote(Box::new(Ticker::new(symbol).await?.quote().await?))
+        }
+        Request::Chart {
+            symbol,
+            interval,
+            range,
+        } => Response::Chart(Box::new(
+            Ticker::new(symbol).await?.chart(interval, range).await?,
+        )),
+        Request::Search {
+            query,
+            quotes_count,
+            news_count,
+        } => {
+            let mut options = SearchOptions::new();
+            if let Some(count) = quotes_count {
+                options = options.quotes_count(count);
+            }
+            if let Some(count) = news_count {
+                options = options.news_count(count);
+            }
+            Response::Search(Box::new(finance::search(&query, &options).await?))
+        }
+        Request::Screener { screener, count } => {
+            Response::Screener(Box::new(finance::screener(screener, count).await?))
+        }
+        #[cfg(feature = "indicators")]
+        Request::Indicators {
+            symbol,
+            interval,
+            range,
+        } => Response::Indicators(Box::new(
+            Ticker::new(symbol)
+                .await?
+                .indicators(interval, range)
+                .await?,
+        )),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_request_wire_format() {
+        let request: Request = serde_json::from_value(json!({
+            "op": "chart",
+            "symbol": "AAPL",
+            "interval": "1d",
+            "range": "1mo",
+        }))
+        .unwrap();
+        assert_eq!(
+            request,
+            Request::Chart {
+                symbol: "AAPL".into(),
+                interval: Interval::OneDay,
+                range: TimeRange::OneMonth,
+            }
+        );
+
+        let request: Request = serde_json::from_value(json!({
+            "op": "screener",
+            "screener": "day-gainers",
+        }))
+        .unwrap();
+        assert_eq!(
+            request,
+            Request::Screener {
+                screener: Screener::DayGainers,
+                count: 25,
+            }
+        );
+
+        let search = Request::Search {
+            query: "apple".into(),
+            quotes_count: Some(5),
+            news_count: None,
+        };
+        assert_eq!(
+            serde_json::to_value(&search).unwrap(),
+            json!({"op": "search", "query": "apple", "quotesCount": 5})
+        );
+    }
+
+    #[test]
+    fn test_request_rejects_unknown() {
+        assert!(serde_json::from_value::<Request>(json!({"op": "trade"})).is_err());
+        assert!(
+            serde_json::from_value::<Request>(json!({"op": "screener", "screener": "nope"}))
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,293 @@
+//! Local persistence of per-symbol attention metrics.
+//!
+//! Yahoo only serves *current* news, trending lists, options activity, and
+//! short interest. An [`AttentionStore`] records a daily
+//! [`AttentionSnapshot`] of those values per symbol on disk, so a history
+//! builds up over time and can be studied alongside prices.
+//!
+//! Collection is opt-in: attach a store with
+//! [`TickerBuilder::attention`](crate::TickerBuilder::attention), call
+//! [`Ticker::record_attention`](crate::Ticker::record_attention) once a day
+//! (e.g. from a cron job), and read it back with
+//! [`Ticker::attention_history`](crate::Ticker::attention_history).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use finance_query::Ticker;
+//! use finance_query::attention::AttentionStore;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let store = AttentionStore::open("data/attention")?;
+//! let ticker = Ticker::builder("AAPL").attention(store).build().await?;
+//!
+//! ticker.record_attention().await?;
+//!
+//! for day in ticker.attention_history()? {
+//!     println!("{} news={:?} trending={}", day.date, day.news_count, day.trending);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Snapshots are stored as one JSON object per line in `<dir>/<SYMBOL>.jsonl`.
+//! Recording twice on the same day replaces that day's entry.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{FinanceError, Result};
+use crate::format::Raw;
+use crate::ticker::Ticker;
+
+/// Attention metrics for one symbol on one day.
+///
+/// Each metric is `None` when its source could not be fetched, so a partial
+/// outage does not lose the metrics that did succeed.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttentionSnapshot {
+    /// Symbol (upper-case)
+    pub symbol: String,
+    /// UTC calendar date the snapshot was taken
+    pub date: NaiveDate,
+    /// Number of news articles currently listed for the symbol
+    pub news_count: Option<usize>,
+    /// Whether the symbol appeared in the trending list
+    pub trending: bool,
+    /// 1-based position in the trending list, if trending
+    pub trending_rank: Option<usize>,
+    /// Total traded volume across calls and puts of the nearest expiration
+    pub options_volume: Option<i64>,
+    /// Number of shares sold short
+    pub shares_short: Option<i64>,
+    /// Days to cover
+    pub short_ratio: Option<f64>,
+    /// Short interest as a fraction of float
+    pub short_percent_of_float: Option<f64>,
+}
+
+impl AttentionSnapshot {
+    /// Create an empty snapshot for `symbol` on `date`.
+    pub fn new(symbol: impl Into<String>, date: NaiveDate) -> Self {
+        Self {
+            symbol: symbol.into().to_uppercase(),
+            date,
+            news_count: None,
+            trending: false,
+            trending_rank: None,
+            options_volume: None,
+            shares_short: None,
+            short_ratio: None,
+            short_percent_of_float: None,
+        }
+    }
+
+    /// Fetch today's metrics for `ticker`.
+    ///
+    /// Sources are queried concurrently; individual failures leave the
+    /// corresponding fields empty rather than failing the whole snapshot.
+    pub async fn collect(ticker: &Ticker) -> Self {
+        let (news, trending, options, quote) = tokio::join!(
+            ticker.news(),
+            crate::finance::trending(None),
+            ticker.options(None),
+            ticker.quote::<Raw>(),
+        );
+
+        let mut snapshot = Self::new(ticker.symbol(), Utc::now().date_naive());
+        snapshot.news_count = news.ok().map(|n| n.len());
+        if let Ok(trending) = trending {
+            snapshot.trending_rank = trending
+                .iter()
+                .position(|q| q.symbol.eq_ignore_ascii_case(&snapshot.symbol))
+                .map(|i| i + 1);
+            snapshot.trending = snapshot.trending_rank.is_some();
+        }
+        snapshot.options_volume = options.ok().map(|o| {
+            o.calls()
+                .iter()
+                .chain(o.puts().iter())
+                .filter_map(|c| c.volume)
+                .sum()
+        });
+        if let Ok(quote) = quote {
+            snapshot.shares_short = quote.shares_short;
+            snapshot.short_ratio = quote.short_ratio;
+            snapshot.short_percent_of_float = quote.short_percent_of_float;
+        }
+        snapshot
+    }
+}
+
+/// Directory-backed store of [`AttentionSnapshot`]s.
+///
+/// Cheap to clone; clones share the same directory.
+#[derive(Debug, Clone)]
+pub struct AttentionStore {
+    dir: PathBuf,
+}
+
+impl AttentionStore {
+    /// Open a store rooted at `dir`, creating the directory if needed.
+    pub fn open(dir: impl AsRef<Path>) -> Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    /// Directory the store writes to.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Save a snapshot, replacing any existing entry for the same symbol and date.
+    pub fn record(&self, snapshot: &AttentionSnapshot) -> Result<()> {
+        let mut history = self.history(&snapshot.symbol)?;
+        match history.binary_search_by_key(&snapshot.date, |s| s.date) {
+            Ok(i) => history[i] = snapshot.clone(),
+            Err(i) => history.insert(i, snapshot.clone()),
+        }
+
+        let mut out = String::new();
+        for entry in &history {
+            out.push_str(&serde_json::to_string(entry)?);
+            out.push('\n');
+        }
+        std::fs::write(self.path(&snapshot.symbol)?, out)?;
+        Ok(())
+    }
+
+    /// All snapshots recorded for `symbol`, oldest first.
+    ///
+    /// Returns an empty list if nothing has been recorded yet.
+    pub fn history(&self, symbol: &str) -> Result<Vec<AttentionSnapshot>> {
+        let path = self.path(symbol)?;
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut history = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<std::result::Result<Vec<AttentionSnapshot>, _>>()?;
+        history.sort_by_key(|s| s.date);
+        Ok(history)
+    }
+
+    /// Snapshots for `symbol` between `start` and `end` (inclusive).
+    pub fn history_between(
+        &self,
+        symbol: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<AttentionSnapshot>> {
+        let mut history = self.history(symbol)?;
+        history.retain(|s| s.date >= start && s.date <= end);
+        Ok(history)
+    }
+
+    /// Symbols with at least one recorded snapshot, sorted.
+    pub fn symbols(&self) -> Result<Vec<String>> {
+        let mut symbols = Vec::new();
+        for entry in std::fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "jsonl")
+                && let Some(stem) = path.file_stem().and_then(|s| s.to_str())
+            {
+                symbols.push(stem.to_string());
+            }
+        }
+        symbols.sort();
+        Ok(symbols)
+    }
+
+    fn path(&self, symbol: &str) -> Result<PathBuf> {
+        let valid = !symbol.is_empty()
+            && symbol
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '^' | '=' | '_'))
+            && !symbol.starts_with('.');
+        if !valid {
+            return Err(FinanceError::InvalidParameter {
+                param: "symbol".to_string(),
+                reason: format!("'{symbol}' cannot be used as an attention store key"),
+            });
+        }
+        Ok(self.dir.join(format!("{}.jsonl", symbol.to_uppercase())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store(name: &str) -> AttentionStore {
+        let dir = std::env::temp_dir().join(format!(
+            "finance-query-attention-{}-{name}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        AttentionStore::open(dir).unwrap()
+    }
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    #[test]
+    fn test_record_and_history() {
+        let store = store("roundtrip");
+        let mut later = AttentionSnapshot::new("aapl", day(3));
+        later.news_count = Some(12);
+        later.trending = true;
+        later.trending_rank = Some(4);
+        let earlier = AttentionSnapshot::new("AAPL", day(1));
+
+        store.record(&later).unwrap();
+        store.record(&earlier).unwrap();
+
+        let history = store.history("aapl").unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].date, day(1));
+        assert_eq!(history[1], later);
+        assert_eq!(store.symbols().unwrap(), vec!["AAPL".to_string()]);
+        assert_eq!(
+            store.history_between("AAPL", day(2), day(5)).unwrap().len(),
+            1
+        );
+
+        std::fs::remove_dir_all(store.dir()).unwrap();
+    }
+
+    #[test]
+    fn test_record_same_day_replaces() {
+        let store = store("replace");
+        let mut snapshot = AttentionSnapshot::new("MSFT", day(1));
+        snapshot.options_volume = Some(100);
+        store.record(&snapshot).unwrap();
+        snapshot.options_volume = Some(250);
+        store.record(&snapshot).unwrap();
+
+        let history = store.history("MSFT").unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].options_volume, Some(250));
+
+        std::fs::remove_dir_all(store.dir()).unwrap();
+    }
+
+    #[test]
+    fn test_missing_symbol_and_invalid_key() {
+        let store = store("invalid");
+        assert!(store.history("NVDA").unwrap().is_empty());
+        assert!(store.history("../etc/passwd").is_err());
+        assert!(store.history("").is_err());
+
+        std::fs::remove_dir_all(store.dir()).unwrap();
+    }
+}
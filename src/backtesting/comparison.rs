@@ -283,6 +283,7 @@ mod tests {
             time_in_market_pct: 0.5,
             max_idle_period: 0,
             total_dividend_income: 0.0,
+            total_financing_cost: 0.0,
             kelly_criterion: 0.0,
             sqn: 0.0,
             expectancy: 0.0,
@@ -291,6 +292,9 @@ mod tests {
             recovery_factor: 1.0,
             ulcer_index: 0.0,
             serenity_ratio: 0.0,
+            avg_mae_pct: 0.0,
+            avg_mfe_pct: 0.0,
+            avg_bars_held: 0.0,
         }
     }
 
@@ -314,6 +318,7 @@ mod tests {
             open_position: None,
             benchmark: None,
             diagnostics: vec![],
+            run_metadata: Default::default(),
         }
     }
 
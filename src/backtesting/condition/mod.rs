@@ -28,7 +28,7 @@ pub use comparison::*;
 pub use composite::*;
 pub use threshold::*;
 
-use crate::constants::Interval;
+use crate::constants::{Interval, Region};
 use crate::indicators::Indicator;
 
 use super::strategy::StrategyContext;
@@ -58,6 +58,36 @@ pub struct HtfIndicatorSpec {
     pub utc_offset_secs: i64,
 }
 
+impl HtfIndicatorSpec {
+    /// Compute `indicator` on `interval` candles, readable on each base bar
+    /// via [`StrategyContext::indicator_on`] under `name`.
+    ///
+    /// Buckets are aligned to UTC; use [`region`](Self::region) for
+    /// exchange-local weekly and monthly boundaries.
+    pub fn new(interval: Interval, name: impl Into<String>, indicator: Indicator) -> Self {
+        let base_key = name.into();
+        Self {
+            interval,
+            htf_key: htf_key(interval, &base_key),
+            base_key,
+            indicator,
+            utc_offset_secs: 0,
+        }
+    }
+
+    /// Align HTF buckets with `region`'s exchange calendar.
+    pub fn region(mut self, region: Region) -> Self {
+        self.utc_offset_secs = region.utc_offset_secs();
+        self
+    }
+}
+
+/// Key under which the engine stores the stretched HTF values of `base_key`
+/// (e.g. `"htf_1wk_sma_20"`).
+pub(crate) fn htf_key(interval: Interval, base_key: &str) -> String {
+    format!("htf_{}_{}", interval.as_str(), base_key)
+}
+
 /// A condition that can be evaluated on each candle.
 ///
 /// Conditions are the building blocks of trading strategies.
@@ -6,6 +6,7 @@ use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 
 use super::error::{BacktestError, Result};
+use super::sizing::{PositionSizer, SizingContext};
 
 // ── CommissionFn ──────────────────────────────────────────────────────────────
 
@@ -52,6 +53,79 @@ impl fmt::Debug for CommissionFn {
     }
 }
 
+// ── ScaleOutTarget ────────────────────────────────────────────────────────────
+
+/// A profit level at which part of an open position is taken off.
+///
+/// Added via [`BacktestConfigBuilder::scale_out_target`]. When the position's
+/// unrealized gain reaches `profit_pct` (measured from its weighted-average
+/// entry price), `fraction` of the **current** position quantity is closed
+/// intrabar at the target price. Each target fires at most once per position.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ScaleOutTarget {
+    /// Gain from the average entry price that triggers the exit (e.g. `0.05` = +5%).
+    pub profit_pct: f64,
+    /// Fraction of the current position quantity to close (`0.0` exclusive – `1.0`).
+    pub fraction: f64,
+}
+
+impl ScaleOutTarget {
+    /// Create a target that closes `fraction` of the position at `profit_pct` gain.
+    pub fn new(profit_pct: f64, fraction: f64) -> Self {
+        Self {
+            profit_pct,
+            fraction,
+        }
+    }
+}
+
+// ── IntrabarPriority ──────────────────────────────────────────────────────────
+
+/// Which exit fills when a single bar touches both the stop-loss and the
+/// take-profit level.
+///
+/// OHLCV bars don't record whether the high or the low came first, so the
+/// engine needs a rule. It only applies when the bar opened between the two
+/// levels: a bar that opens through either level fills that level first, at
+/// the open, whatever the priority. Set via
+/// [`BacktestConfigBuilder::intrabar_priority`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntrabarPriority {
+    /// Assume the stop-loss was hit first (pessimistic). The default.
+    #[default]
+    StopFirst,
+    /// Assume the take-profit was hit first (optimistic).
+    TargetFirst,
+    /// Assume the level closer to the bar's open was hit first, with ties
+    /// going to the stop-loss.
+    NearestToOpen,
+}
+
+impl IntrabarPriority {
+    /// Whether the take-profit at `target_price` fills before the stop-loss
+    /// at `stop_price` on a bar that opened at `open` and touched both.
+    pub(crate) fn target_first(self, open: f64, stop_price: f64, target_price: f64) -> bool {
+        // +1 for a long bracket (target above stop), -1 for a short one
+        let side = (target_price - stop_price).signum();
+        if (open - stop_price) * side <= 0.0 {
+            // Opened through the stop
+            return false;
+        }
+        if (open - target_price) * side >= 0.0 {
+            // Opened through the target
+            return true;
+        }
+        match self {
+            Self::StopFirst => false,
+            Self::TargetFirst => true,
+            Self::NearestToOpen => (target_price - open).abs() < (stop_price - open).abs(),
+        }
+    }
+}
+
 /// Configuration for backtest execution.
 ///
 /// Use `BacktestConfig::builder()` to construct with the builder pattern.
@@ -87,11 +161,39 @@ pub struct BacktestConfig {
     pub slippage_pct: f64,
 
     /// Position sizing: fraction of equity per trade (0.0 - 1.0)
+    ///
+    /// Ignored when [`position_sizer`](Self::position_sizer) is set.
     pub position_size_pct: f64,
 
+    /// Custom position sizing model, replacing
+    /// [`position_size_pct`](Self::position_size_pct) for new entries.
+    ///
+    /// See the [`sizing`](super::sizing) module for the built-in models.
+    /// Applies to single-symbol backtests; portfolio backtests size entries
+    /// by their [`RebalanceMode`](super::portfolio::RebalanceMode) and reject
+    /// a config with a sizer set.
+    ///
+    /// **Not serialized** — reconstruct after deserialization if needed.
+    #[serde(skip)]
+    pub position_sizer: Option<Arc<dyn PositionSizer>>,
+
     /// Maximum number of concurrent positions (None = unlimited)
     pub max_positions: Option<usize>,
 
+    /// Maximum number of scale-in adds per position (None = unlimited).
+    ///
+    /// [`SignalDirection::ScaleIn`](super::SignalDirection::ScaleIn) signals
+    /// beyond this many adds are recorded but not executed.
+    #[serde(default)]
+    pub max_scale_ins: Option<usize>,
+
+    /// Profit targets for partial exits, ordered by ascending `profit_pct`.
+    ///
+    /// Checked intrabar after stop-loss / take-profit each bar. Empty by
+    /// default.
+    #[serde(default)]
+    pub scale_out_targets: Vec<ScaleOutTarget>,
+
     /// Allow short selling
     pub allow_short: bool,
 
@@ -104,6 +206,15 @@ pub struct BacktestConfig {
     /// Take-profit percentage (0.0 - 1.0). Auto-exit if profit exceeds this.
     pub take_profit_pct: Option<f64>,
 
+    /// Which exit fills when one bar touches both the stop-loss and the
+    /// take-profit level.
+    ///
+    /// Stops and targets are checked against each bar's high and low, so both
+    /// can trigger on the same bar. Defaults to
+    /// [`IntrabarPriority::StopFirst`].
+    #[serde(default)]
+    pub intrabar_priority: IntrabarPriority,
+
     /// Close any open position at end of backtest
     pub close_at_end: bool,
 
@@ -133,6 +244,29 @@ pub struct BacktestConfig {
     /// In both cases the dividend amount is recorded on the `Trade` for reporting.
     pub reinvest_dividends: bool,
 
+    /// When `true` (default), short positions pay the dividend on every
+    /// ex-date they are open across, as a stock lender would require.
+    ///
+    /// Set to `false` to ignore dividends on shorts, e.g. when the price series
+    /// is already dividend-adjusted. Long positions always receive dividends.
+    #[serde(default = "default_short_pays_dividends")]
+    pub short_pays_dividends: bool,
+
+    /// When `true`, run on a dividend-reinvested price series instead of
+    /// crediting dividends separately.
+    ///
+    /// Candles are rebuilt from the dividends passed to the engine, or from
+    /// each candle's `adj_close` when there are none (see
+    /// [`Chart::total_return_series`](crate::Chart::total_return_series)).
+    /// Dividends are then part of the price, so indicators, signals, and
+    /// fills all see total-return prices, and
+    /// [`reinvest_dividends`](Self::reinvest_dividends) and
+    /// [`short_pays_dividends`](Self::short_pays_dividends) have no effect.
+    ///
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub use_total_return: bool,
+
     /// Number of bars per calendar year, used for annualising returns and ratios.
     ///
     /// Defaults to `252.0` (US equity daily bars). Set to `52.0` for weekly
@@ -165,6 +299,42 @@ pub struct BacktestConfig {
     /// Defaults to `0.0`.
     pub transaction_tax_pct: f64,
 
+    // ── Financing ────────────────────────────────────────────────────────────
+    /// Annualized stock borrow fee charged on short positions (0.0 – 1.0).
+    ///
+    /// Accrued every bar at `borrow_fee_rate / bars_per_year` of the short's
+    /// market value at the bar close, debited from cash, and deducted from the
+    /// trade's P&L (see [`Trade::financing_cost`]). With daily bars this is a
+    /// daily charge; hard-to-borrow names can run well above `0.10`.
+    ///
+    /// Defaults to `0.0`.
+    ///
+    /// [`Trade::financing_cost`]: super::Trade::financing_cost
+    #[serde(default)]
+    pub borrow_fee_rate: f64,
+
+    /// Annualized interest rate charged on margin debt (0.0 – 1.0).
+    ///
+    /// Margin debt is any negative cash balance, which only arises when a long
+    /// position is bought with more than the available cash under
+    /// [`max_leverage`](Self::max_leverage). Accrued every bar at
+    /// `margin_interest_rate / bars_per_year` of the debt. Interest is paid
+    /// from cash, so while the position is open it adds to the debt and
+    /// compounds bar over bar, as on a broker's margin account.
+    ///
+    /// Defaults to `0.0`.
+    #[serde(default)]
+    pub margin_interest_rate: f64,
+
+    /// Maximum gross exposure as a multiple of equity (`>= 1.0`).
+    ///
+    /// `1.0` (the default) means no leverage: longs are paid for in cash and
+    /// shorts are capped at equity. `2.0` models a Reg-T margin account.
+    /// Position sizing scales by this factor, and entries or scale-ins that
+    /// would push exposure past it are rejected.
+    #[serde(default = "default_max_leverage")]
+    pub max_leverage: f64,
+
     /// Custom commission function `f(size, price) -> commission`.
     ///
     /// When `Some`, **replaces** the flat [`commission`] + percentage
@@ -179,6 +349,41 @@ pub struct BacktestConfig {
     /// [`initial_capital`]: Self::initial_capital
     #[serde(skip)]
     pub commission_fn: Option<CommissionFn>,
+
+    /// Seed for stochastic analysis of this run's result.
+    ///
+    /// The engine itself is deterministic; the seed is recorded in
+    /// [`RunMetadata::seed`](super::RunMetadata::seed) and used by
+    /// [`MonteCarloConfig::run`](super::MonteCarloConfig::run) when the
+    /// Monte Carlo config has no seed of its own.
+    ///
+    /// Defaults to `None`.
+    #[serde(default)]
+    pub seed: Option<u64>,
+
+    /// Benchmark symbol to compare the strategy against (e.g. `"SPY"`).
+    ///
+    /// When set, [`Ticker::backtest`](crate::Ticker::backtest) also fetches
+    /// the benchmark's chart and fills
+    /// [`BacktestResult::benchmark`](super::BacktestResult::benchmark) with a
+    /// buy-and-hold equity curve, alpha, beta and relative drawdown. Use the
+    /// backtested symbol itself to compare against buy-and-hold of the same
+    /// instrument. [`BacktestEngine`](super::BacktestEngine) never fetches
+    /// data, so its `run*` methods ignore this field; pass benchmark candles
+    /// to [`run_with_benchmark`](super::BacktestEngine::run_with_benchmark)
+    /// instead.
+    ///
+    /// Defaults to `None`.
+    #[serde(default)]
+    pub benchmark: Option<String>,
+}
+
+fn default_short_pays_dividends() -> bool {
+    true
+}
+
+fn default_max_leverage() -> f64 {
+    1.0
 }
 
 impl Default for BacktestConfig {
@@ -189,19 +394,30 @@ impl Default for BacktestConfig {
             commission_pct: 0.001,  // 0.1% per trade
             slippage_pct: 0.001,    // 0.1% slippage
             position_size_pct: 1.0, // Use 100% of available capital
+            position_sizer: None,
             max_positions: Some(1), // Single position at a time
+            max_scale_ins: None,
+            scale_out_targets: Vec::new(),
             allow_short: false,
             min_signal_strength: 0.0,
             stop_loss_pct: None,
             take_profit_pct: None,
+            intrabar_priority: IntrabarPriority::StopFirst,
             close_at_end: true,
             risk_free_rate: 0.0,
             trailing_stop_pct: None,
             reinvest_dividends: false,
+            short_pays_dividends: true,
+            use_total_return: false,
             bars_per_year: 252.0,
             spread_pct: 0.0,
             transaction_tax_pct: 0.0,
+            borrow_fee_rate: 0.0,
+            margin_interest_rate: 0.0,
+            max_leverage: 1.0,
             commission_fn: None,
+            seed: None,
+            benchmark: None,
         }
     }
 }
@@ -290,6 +506,31 @@ impl BacktestConfig {
             ));
         }
 
+        for target in &self.scale_out_targets {
+            if !(target.profit_pct > 0.0 && target.profit_pct.is_finite()) {
+                return Err(BacktestError::invalid_param(
+                    "scale_out_targets",
+                    "profit_pct must be positive",
+                ));
+            }
+            if !(target.fraction > 0.0 && target.fraction <= 1.0) {
+                return Err(BacktestError::invalid_param(
+                    "scale_out_targets",
+                    "fraction must be between 0.0 (exclusive) and 1.0 (inclusive)",
+                ));
+            }
+        }
+        if self
+            .scale_out_targets
+            .windows(2)
+            .any(|w| w[0].profit_pct >= w[1].profit_pct)
+        {
+            return Err(BacktestError::invalid_param(
+                "scale_out_targets",
+                "must be sorted by strictly ascending profit_pct",
+            ));
+        }
+
         if !(0.0..=1.0).contains(&self.risk_free_rate) {
             return Err(BacktestError::invalid_param(
                 "risk_free_rate",
@@ -327,6 +568,38 @@ impl BacktestConfig {
             ));
         }
 
+        if !(0.0..=1.0).contains(&self.borrow_fee_rate) {
+            return Err(BacktestError::invalid_param(
+                "borrow_fee_rate",
+                "must be between 0.0 and 1.0",
+            ));
+        }
+
+        if !(0.0..=1.0).contains(&self.margin_interest_rate) {
+            return Err(BacktestError::invalid_param(
+                "margin_interest_rate",
+                "must be between 0.0 and 1.0",
+            ));
+        }
+
+        if !(self.max_leverage >= 1.0 && self.max_leverage.is_finite()) {
+            return Err(BacktestError::invalid_param(
+                "max_leverage",
+                "must be at least 1.0",
+            ));
+        }
+
+        if self
+            .benchmark
+            .as_deref()
+            .is_some_and(|b| b.trim().is_empty())
+        {
+            return Err(BacktestError::invalid_param(
+                "benchmark",
+                "must be a non-empty symbol",
+            ));
+        }
+
         Ok(())
     }
 
@@ -405,12 +678,33 @@ impl BacktestConfig {
         }
     }
 
+    /// One bar's borrow fee on a short position worth `short_value`.
+    pub fn calculate_borrow_fee(&self, short_value: f64) -> f64 {
+        short_value.abs() * self.borrow_fee_rate / self.bars_per_year
+    }
+
+    /// One bar's interest on `margin_debt` (a non-positive debt costs nothing).
+    pub fn calculate_margin_interest(&self, margin_debt: f64) -> f64 {
+        margin_debt.max(0.0) * self.margin_interest_rate / self.bars_per_year
+    }
+
+    /// Cash that can be spent on purchases: `cash` plus whatever margin
+    /// [`max_leverage`](Self::max_leverage) still allows against `equity`.
+    ///
+    /// Equals `cash` when leverage is disabled.
+    pub fn buying_power(&self, cash: f64, equity: f64) -> f64 {
+        cash + equity * (self.max_leverage - 1.0)
+    }
+
     /// Calculate position size based on available capital.
     ///
     /// `price` **must** be the fully-adjusted entry price (after slippage and
     /// spread) so that subsequent fill guards (`entry_value + costs > cash`)
     /// do not over-allocate capital.
     ///
+    /// The allocation is scaled by [`max_leverage`](Self::max_leverage), so
+    /// with `2.0` a 100% position is twice `available_capital`.
+    ///
     /// When [`commission_fn`] is set the commission component cannot be
     /// analytically solved for, so only spread and transaction-tax fractions
     /// are deducted from the denominator; the fill-rejection guard catches any
@@ -418,8 +712,30 @@ impl BacktestConfig {
     ///
     /// [`commission_fn`]: Self::commission_fn
     pub fn calculate_position_size(&self, available_capital: f64, price: f64) -> f64 {
-        let capital_to_use = available_capital * self.position_size_pct;
+        let capital_to_use = available_capital * self.position_size_pct * self.max_leverage;
+        self.quantity_for_capital(capital_to_use, price)
+    }
+
+    /// Calculate the quantity for a new entry, using
+    /// [`position_sizer`](Self::position_sizer) when set and
+    /// [`calculate_position_size`](Self::calculate_position_size) otherwise.
+    ///
+    /// The sizer's allocation is capped at `ctx.available_cash × max_leverage`.
+    pub(crate) fn size_entry(&self, ctx: &SizingContext<'_>) -> f64 {
+        let Some(sizer) = &self.position_sizer else {
+            return self.calculate_position_size(ctx.available_cash, ctx.price);
+        };
+        let allocation = sizer.allocation(ctx);
+        if !allocation.is_finite() {
+            return 0.0;
+        }
+        let capital_to_use = allocation.min(ctx.available_cash * self.max_leverage);
+        self.quantity_for_capital(capital_to_use, ctx.price)
+    }
 
+    /// Convert a capital allocation into a quantity that leaves room for
+    /// round-trip costs.
+    fn quantity_for_capital(&self, capital_to_use: f64, price: f64) -> f64 {
         let adjusted_capital = if self.commission_fn.is_some() {
             // Can't analytically invert commission_fn; use spread + tax only.
             // The fill-rejection guard will catch any over-allocation.
@@ -475,6 +791,13 @@ impl BacktestConfigBuilder {
         self
     }
 
+    /// Size new entries with a custom model instead of `position_size_pct`
+    /// (see [`BacktestConfig::position_sizer`])
+    pub fn position_sizer(mut self, sizer: impl PositionSizer + 'static) -> Self {
+        self.config.position_sizer = Some(Arc::new(sizer));
+        self
+    }
+
     /// Set maximum concurrent positions
     pub fn max_positions(mut self, max: usize) -> Self {
         self.config.max_positions = Some(max);
@@ -487,6 +810,41 @@ impl BacktestConfigBuilder {
         self
     }
 
+    /// Cap the number of scale-in adds per position
+    pub fn max_scale_ins(mut self, max: usize) -> Self {
+        self.config.max_scale_ins = Some(max);
+        self
+    }
+
+    /// Take `fraction` of the position off when it gains `profit_pct`.
+    ///
+    /// Call repeatedly to build a ladder of targets; they are kept sorted by
+    /// `profit_pct`. A fraction of `1.0` closes whatever remains.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use finance_query::backtesting::BacktestConfig;
+    ///
+    /// // Sell a third at +5%, half the remainder at +10%, the rest at +20%
+    /// let config = BacktestConfig::builder()
+    ///     .scale_out_target(0.05, 1.0 / 3.0)
+    ///     .scale_out_target(0.10, 0.5)
+    ///     .scale_out_target(0.20, 1.0)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(config.scale_out_targets.len(), 3);
+    /// ```
+    pub fn scale_out_target(mut self, profit_pct: f64, fraction: f64) -> Self {
+        self.config
+            .scale_out_targets
+            .push(ScaleOutTarget::new(profit_pct, fraction));
+        self.config
+            .scale_out_targets
+            .sort_by(|a, b| a.profit_pct.total_cmp(&b.profit_pct));
+        self
+    }
+
     /// Allow or disallow short selling
     pub fn allow_short(mut self, allow: bool) -> Self {
         self.config.allow_short = allow;
@@ -511,6 +869,13 @@ impl BacktestConfigBuilder {
         self
     }
 
+    /// Set which exit fills when one bar touches both the stop-loss and the
+    /// take-profit level (see [`IntrabarPriority`])
+    pub fn intrabar_priority(mut self, priority: IntrabarPriority) -> Self {
+        self.config.intrabar_priority = priority;
+        self
+    }
+
     /// Set whether to close open positions at end of backtest
     pub fn close_at_end(mut self, close: bool) -> Self {
         self.config.close_at_end = close;
@@ -542,6 +907,19 @@ impl BacktestConfigBuilder {
         self
     }
 
+    /// Set whether short positions pay dividends on ex-dates (default `true`)
+    pub fn short_pays_dividends(mut self, pays: bool) -> Self {
+        self.config.short_pays_dividends = pays;
+        self
+    }
+
+    /// Run on dividend-reinvested prices instead of crediting dividends
+    /// (default `false`). See [`BacktestConfig::use_total_return`].
+    pub fn use_total_return(mut self, enabled: bool) -> Self {
+        self.config.use_total_return = enabled;
+        self
+    }
+
     /// Set the number of bars per calendar year for annualisation.
     ///
     /// Defaults to `252.0` (US equity daily bars). Common values:
@@ -573,6 +951,43 @@ impl BacktestConfigBuilder {
         self
     }
 
+    /// Set the annualized borrow fee charged on short positions (0.0 – 1.0).
+    ///
+    /// Accrued each bar on the short's market value, e.g. `0.03` for a 3%
+    /// general-collateral rate.
+    pub fn borrow_fee_rate(mut self, rate: f64) -> Self {
+        self.config.borrow_fee_rate = rate;
+        self
+    }
+
+    /// Set the annualized interest rate charged on margin debt (0.0 – 1.0).
+    pub fn margin_interest_rate(mut self, rate: f64) -> Self {
+        self.config.margin_interest_rate = rate;
+        self
+    }
+
+    /// Set the maximum gross exposure as a multiple of equity (`>= 1.0`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use finance_query::backtesting::BacktestConfig;
+    ///
+    /// // 2x margin account paying 8% on debt; shorts cost 3% a year to borrow
+    /// let config = BacktestConfig::builder()
+    ///     .max_leverage(2.0)
+    ///     .margin_interest_rate(0.08)
+    ///     .allow_short(true)
+    ///     .borrow_fee_rate(0.03)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(config.max_leverage, 2.0);
+    /// ```
+    pub fn max_leverage(mut self, leverage: f64) -> Self {
+        self.config.max_leverage = leverage;
+        self
+    }
+
     /// Set a custom commission function `f(size, price) -> commission`.
     ///
     /// Replaces the flat [`commission`](BacktestConfig::commission) and
@@ -598,6 +1013,20 @@ impl BacktestConfigBuilder {
         self
     }
 
+    /// Set the RNG seed recorded with the result and used by Monte Carlo
+    /// analysis (see [`BacktestConfig::seed`]).
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self
+    }
+
+    /// Compare the strategy against a benchmark symbol (see
+    /// [`BacktestConfig::benchmark`]).
+    pub fn benchmark(mut self, symbol: impl Into<String>) -> Self {
+        self.config.benchmark = Some(symbol.into());
+        self
+    }
+
     /// Build and validate the configuration
     pub fn build(self) -> Result<BacktestConfig> {
         self.config.validate()?;
@@ -658,6 +1087,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_scale_out_targets() {
+        let config = BacktestConfig::builder()
+            .scale_out_target(0.10, 1.0)
+            .scale_out_target(0.05, 0.5)
+            .max_scale_ins(3)
+            .build()
+            .unwrap();
+        assert_eq!(config.scale_out_targets[0], ScaleOutTarget::new(0.05, 0.5));
+        assert_eq!(config.max_scale_ins, Some(3));
+
+        assert!(
+            BacktestConfig::builder()
+                .scale_out_target(0.05, 0.0)
+                .build()
+                .is_err()
+        );
+        assert!(
+            BacktestConfig::builder()
+                .scale_out_target(0.05, 0.5)
+                .scale_out_target(0.05, 0.5)
+                .build()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_financing_costs() {
+        let config = BacktestConfig::builder()
+            .borrow_fee_rate(0.0252)
+            .margin_interest_rate(0.0504)
+            .max_leverage(2.0)
+            .build()
+            .unwrap();
+
+        // 2.52% / 252 bars = 1 bp per bar
+        assert!((config.calculate_borrow_fee(10_000.0) - 1.0).abs() < 1e-9);
+        assert!((config.calculate_margin_interest(10_000.0) - 2.0).abs() < 1e-9);
+        assert_eq!(config.calculate_margin_interest(-500.0), 0.0);
+        assert!((config.buying_power(1_000.0, 1_000.0) - 2_000.0).abs() < 1e-9);
+
+        assert!(BacktestConfig::builder().max_leverage(0.5).build().is_err());
+        assert!(
+            BacktestConfig::builder()
+                .borrow_fee_rate(-0.01)
+                .build()
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_commission_calculation() {
         let config = BacktestConfig::builder()
@@ -768,6 +1247,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_intrabar_priority_target_first() {
+        // Long: stop at 95, target at 110
+        assert!(!IntrabarPriority::StopFirst.target_first(109.0, 95.0, 110.0));
+        assert!(IntrabarPriority::TargetFirst.target_first(96.0, 95.0, 110.0));
+        assert!(IntrabarPriority::NearestToOpen.target_first(104.0, 95.0, 110.0));
+        assert!(!IntrabarPriority::NearestToOpen.target_first(102.5, 95.0, 110.0));
+        // Gap through the stop: the stop fills first
+        assert!(!IntrabarPriority::NearestToOpen.target_first(90.0, 95.0, 110.0));
+        // Short: stop at 105, target at 90; gap through the target
+        assert!(IntrabarPriority::NearestToOpen.target_first(88.0, 105.0, 90.0));
+        // A bar that opens through a level fills it first under every priority
+        for priority in [
+            IntrabarPriority::StopFirst,
+            IntrabarPriority::TargetFirst,
+            IntrabarPriority::NearestToOpen,
+        ] {
+            // Long: gap up past the target, gap down past the stop
+            assert!(priority.target_first(112.0, 95.0, 110.0));
+            assert!(!priority.target_first(93.0, 95.0, 110.0));
+            // Short: gap down past the target, gap up past the stop
+            assert!(priority.target_first(88.0, 105.0, 90.0));
+            assert!(!priority.target_first(107.0, 105.0, 90.0));
+        }
+    }
+
+    #[test]
+    fn test_benchmark_validation() {
+        let config = BacktestConfig::builder().benchmark("SPY").build().unwrap();
+        assert_eq!(config.benchmark.as_deref(), Some("SPY"));
+        assert!(BacktestConfig::builder().benchmark(" ").build().is_err());
+    }
+
     #[test]
     fn test_bars_per_year_validation() {
         // Default is 252
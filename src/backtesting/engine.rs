@@ -3,15 +3,17 @@
 use std::collections::HashMap;
 
 use crate::indicators::{self, Indicator};
+use crate::models::chart::total_return::total_return_candles;
 use crate::models::chart::{Candle, Dividend};
 
 use super::config::BacktestConfig;
 use super::error::{BacktestError, Result};
 use super::position::{Position, PositionSide, Trade};
 use super::result::{
-    BacktestResult, BenchmarkMetrics, EquityPoint, PerformanceMetrics, SignalRecord,
+    BacktestResult, BenchmarkMetrics, EquityPoint, PerformanceMetrics, RunMetadata, SignalRecord,
 };
 use super::signal::{OrderType, PendingOrder, Signal, SignalDirection};
+use super::sizing::SizingContext;
 use super::strategy::{Strategy, StrategyContext};
 
 /// Backtest execution engine.
@@ -48,6 +50,7 @@ fn needs_high_low(indicator: &Indicator) -> bool {
             | Indicator::BullBearPower(_)
             | Indicator::ElderRay(_)
             | Indicator::AwesomeOscillator { .. }
+            | Indicator::Custom(_)
     )
 }
 
@@ -63,6 +66,7 @@ fn needs_volumes(indicator: &Indicator) -> bool {
             | Indicator::Vwap
             | Indicator::ChaikinOscillator
             | Indicator::AccumulationDistribution
+            | Indicator::Custom(_)
     )
 }
 
@@ -315,6 +319,18 @@ fn compute_one(
             out.push((format!("elder_bull_{period}"), er.bull_power));
             out.push((format!("elder_bear_{period}"), er.bear_power));
         }
+        // No numeric series; pattern conditions read the candles directly
+        Indicator::Patterns => {}
+        Indicator::Custom(r) => {
+            let ohlcv = indicators::Ohlcv {
+                open: opens,
+                high: highs,
+                low: lows,
+                close: closes,
+                volume: volumes,
+            };
+            out.push((name, r.compute(&ohlcv)?));
+        }
     }
     Ok(out)
 }
@@ -341,7 +357,7 @@ pub(crate) fn compute_for_candles(
     let use_vol = required.iter().any(|(_, i)| needs_volumes(i));
     let use_open = required
         .iter()
-        .any(|(_, i)| matches!(i, Indicator::BalanceOfPower(_)));
+        .any(|(_, i)| matches!(i, Indicator::BalanceOfPower(_) | Indicator::Custom(_)));
 
     // Extract price series upfront (single pass each, cache-friendly).
     let closes: Vec<f64> = candles.iter().map(|c| c.close).collect();
@@ -390,6 +406,73 @@ pub(crate) fn compute_for_candles(
     Ok(result)
 }
 
+/// Run a backtest on user-supplied candles.
+///
+/// The entry point for data that doesn't come from a [`Ticker`](crate::Ticker):
+/// a vendor feed, a database, or a CSV file read with
+/// [`Candle::from_csv`]. `candles` must be sorted by timestamp, oldest first.
+/// The config is validated before the run, and the result's `symbol` is
+/// empty; use [`BacktestEngine::run`] to label it.
+///
+/// # Example
+///
+/// ```no_run
+/// use finance_query::Candle;
+/// use finance_query::backtesting::{self, BacktestConfig, SmaCrossover};
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let candles = Candle::from_csv(std::fs::File::open("data/AAPL.csv")?)?;
+/// let result = backtesting::run(SmaCrossover::new(10, 20), &candles, BacktestConfig::default())?;
+/// println!("Return: {:.2}%", result.metrics.total_return_pct);
+/// # Ok(())
+/// # }
+/// ```
+pub fn run<S: Strategy>(
+    strategy: S,
+    candles: &[Candle],
+    config: BacktestConfig,
+) -> Result<BacktestResult> {
+    config.validate()?;
+    if candles.windows(2).any(|w| w[0].timestamp > w[1].timestamp) {
+        return Err(BacktestError::invalid_param(
+            "candles",
+            "must be sorted by timestamp (ascending)",
+        ));
+    }
+    BacktestEngine::new(config).run("", candles, strategy)
+}
+
+/// Run a backtest on candles loaded from your own dataset.
+///
+/// Equivalent to [`run`]; pairs with [`Chart::from_csv`](crate::Chart::from_csv)
+/// and `Chart::from_parquet` (`export` feature) for fully offline backtests
+/// on tick-derived bars, futures, or history older than providers serve.
+///
+/// # Example
+///
+/// ```no_run
+/// use finance_query::Chart;
+/// use finance_query::backtesting::{self, BacktestConfig, SmaCrossover};
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let chart = Chart::from_csv("data/ES.csv")?;
+/// let result = backtesting::run_backtest_on_candles(
+///     SmaCrossover::new(10, 20),
+///     &chart.candles,
+///     BacktestConfig::default(),
+/// )?;
+/// println!("{}: {:.2}%", chart.symbol, result.metrics.total_return_pct);
+/// # Ok(())
+/// # }
+/// ```
+pub fn run_backtest_on_candles<S: Strategy>(
+    strategy: S,
+    candles: &[Candle],
+    config: BacktestConfig,
+) -> Result<BacktestResult> {
+    run(strategy, candles, config)
+}
+
 impl BacktestEngine {
     /// Create a new backtest engine with the given configuration
     pub fn new(config: BacktestConfig) -> Self {
@@ -454,6 +537,15 @@ impl BacktestEngine {
             ));
         }
 
+        // Total-return mode folds dividends into the prices instead of crediting them
+        let total_return;
+        let (candles, dividends) = if self.config.use_total_return {
+            total_return = total_return_candles(candles, dividends);
+            (&total_return[..], &[][..])
+        } else {
+            (candles, dividends)
+        };
+
         // Pre-compute all required indicators (base timeframe + HTF stretched arrays)
         let mut indicators = self.compute_indicators(candles, &strategy)?;
         indicators.extend(self.compute_htf_indicators(candles, &strategy)?);
@@ -473,6 +565,8 @@ impl BacktestEngine {
         // High-water mark for the trailing stop: tracks peak price (longs) or
         // trough price (shorts) since entry. Reset to None when no position is open.
         let mut hwm: Option<f64> = None;
+        // Sequence number handed to the next position opened.
+        let mut next_position_id: usize = 0;
 
         // Dividend processing pointer: dividends must be sorted by timestamp.
         // We advance this index forward as the simulation progresses in time.
@@ -486,6 +580,9 @@ impl BacktestEngine {
         for i in 0..candles.len() {
             let candle = &candles[i];
 
+            // Charge borrow fees / margin interest for holding through this bar.
+            self.accrue_financing(&mut position, &mut cash, candle);
+
             equity = Self::update_equity_and_curve(
                 position.as_ref(),
                 candle,
@@ -495,6 +592,9 @@ impl BacktestEngine {
             );
 
             update_trailing_hwm(position.as_ref(), &mut hwm, candle);
+            if let Some(pos) = position.as_mut() {
+                pos.record_bar(candle.high, candle.low);
+            }
 
             // Credit dividend income for any dividends ex-dated on or before this bar.
             self.credit_dividends(&mut position, candle, dividends, &mut div_idx);
@@ -531,6 +631,42 @@ impl BacktestEngine {
                 }
             }
 
+            // Scale out at configured profit targets. Several targets can be
+            // crossed in one bar, so keep taking them until none are reached.
+            let mut closed_by_target = false;
+            while let Some(ref pos) = position
+                && let Some(target_signal) = check_scale_out_target(pos, candle, &self.config)
+            {
+                if let Some(pos) = position.as_mut() {
+                    pos.scale_out_targets_hit += 1;
+                }
+                let fill_price = target_signal.price;
+                let executed = self.scale_out_position_at(
+                    &mut position,
+                    &mut cash,
+                    &mut trades,
+                    &target_signal,
+                    candle,
+                    fill_price,
+                );
+                signals.push(SignalRecord {
+                    timestamp: candle.timestamp,
+                    price: fill_price,
+                    direction: target_signal.direction,
+                    strength: 1.0,
+                    reason: target_signal.reason.clone(),
+                    executed,
+                    tags: target_signal.tags.clone(),
+                });
+                if executed && position.is_none() {
+                    hwm = None;
+                    closed_by_target = true;
+                }
+            }
+            if closed_by_target {
+                continue; // Skip strategy signal this bar
+            }
+
             // ── Pending limit / stop orders ───────────────────────────────
             // Check queued orders against the current bar before evaluating
             // the strategy. This preserves the realistic ordering where a
@@ -588,16 +724,19 @@ impl BacktestEngine {
                 }
 
                 if let Some(fill_price) = order.order_type.try_fill(candle) {
-                    let is_long = matches!(order.signal.direction, SignalDirection::Long);
                     let executed = self.open_position_at_price(
                         &mut position,
                         &mut cash,
                         candle,
                         &order.signal,
-                        is_long,
                         fill_price,
+                        EntryHistory {
+                            candles: &candles[..i],
+                            trades: &trades,
+                        },
                     );
                     if executed {
+                        assign_position_id(&mut position, &mut next_position_id);
                         hwm = position.as_ref().map(|p| p.entry_price);
                         signals.push(SignalRecord {
                             timestamp: candle.timestamp,
@@ -663,6 +802,7 @@ impl BacktestEngine {
                         self.execute_signal(
                             &signal,
                             fill_candle,
+                            &candles[..=i],
                             &mut position,
                             &mut cash,
                             &mut trades,
@@ -700,6 +840,7 @@ impl BacktestEngine {
                         self.execute_signal(
                             &signal,
                             fill_candle,
+                            &candles[..=i],
                             &mut position,
                             &mut cash,
                             &mut trades,
@@ -717,6 +858,7 @@ impl BacktestEngine {
                     SignalDirection::Long | SignalDirection::Short
                 )
             {
+                assign_position_id(&mut position, &mut next_position_id);
                 hwm = position.as_ref().map(|p| p.entry_price);
             }
 
@@ -740,6 +882,7 @@ impl BacktestEngine {
                         self.execute_signal(
                             &follow,
                             fill_candle,
+                            &candles[..=i],
                             &mut position,
                             &mut cash,
                             &mut trades,
@@ -748,6 +891,7 @@ impl BacktestEngine {
                         false
                     };
                     if follow_executed && position.is_some() {
+                        assign_position_id(&mut position, &mut next_position_id);
                         hwm = position.as_ref().map(|p| p.entry_price);
                     }
                     signals.push(SignalRecord {
@@ -894,6 +1038,7 @@ impl BacktestEngine {
             open_position: position,
             benchmark: None, // Populated by run_with_benchmark when a benchmark is supplied
             diagnostics,
+            run_metadata: RunMetadata::new(&self.config, candles),
         })
     }
 
@@ -921,6 +1066,7 @@ impl BacktestEngine {
             candles,
             benchmark_candles,
             &result.equity_curve,
+            self.config.initial_capital,
             self.config.risk_free_rate,
             self.config.bars_per_year,
         ));
@@ -1047,7 +1193,9 @@ impl BacktestEngine {
         div_idx: &mut usize,
     ) {
         while *div_idx < dividends.len() && dividends[*div_idx].timestamp <= candle.timestamp {
-            if let Some(pos) = position.as_mut() {
+            if let Some(pos) = position.as_mut()
+                && (pos.is_long() || self.config.short_pays_dividends)
+            {
                 let per_share = dividends[*div_idx].amount;
                 let income = if pos.is_long() {
                     per_share * pos.quantity
@@ -1060,6 +1208,27 @@ impl BacktestEngine {
         }
     }
 
+    /// Charge one bar of financing on the open position.
+    ///
+    /// Shorts pay the borrow fee on their market value at the bar close; longs
+    /// pay margin interest on any negative cash balance. The charge is debited
+    /// from cash and accrued on the position so it lands in the trade's P&L.
+    fn accrue_financing(&self, position: &mut Option<Position>, cash: &mut f64, candle: &Candle) {
+        let Some(pos) = position.as_mut() else {
+            return;
+        };
+        let cost = if pos.is_long() {
+            self.config.calculate_margin_interest(-*cash)
+        } else {
+            self.config
+                .calculate_borrow_fee(pos.quantity * candle.close)
+        };
+        if cost > 0.0 {
+            *cash -= cost;
+            pos.accrue_financing(cost);
+        }
+    }
+
     /// Check if stop-loss, take-profit, or trailing stop should trigger intrabar.
     ///
     /// Uses `candle.low` / `candle.high` to detect breaches that occur during the
@@ -1073,15 +1242,13 @@ impl BacktestEngine {
     ///
     /// # Exit Priority
     ///
-    /// When multiple exit conditions are satisfied on the same bar, the first
-    /// one checked wins: **stop-loss → take-profit → trailing stop**.
+    /// When both stop-loss and take-profit are touched on the same bar,
+    /// [`BacktestConfig::intrabar_priority`] picks which one fills; the
+    /// default ([`IntrabarPriority::StopFirst`]) errs on the side of pessimism
+    /// because the intrabar order of events is unknowable from OHLCV data
+    /// alone.  The trailing stop is only checked when neither level was hit.
     ///
-    /// In reality, the intrabar order of events is unknowable from OHLCV data
-    /// alone — a bar could open through the take-profit level before touching
-    /// the stop-loss, or vice versa.  The fixed priority errs on the side of
-    /// pessimism (stop-loss before take-profit) for conservative simulation.
-    /// Strategies with both SL and TP set should be aware of this ordering
-    /// when both levels are close together relative to typical bar ranges.
+    /// [`IntrabarPriority::StopFirst`]: super::IntrabarPriority::StopFirst
     fn check_sl_tp(
         &self,
         position: &Position,
@@ -1098,7 +1265,7 @@ impl BacktestEngine {
             .or(self.config.trailing_stop_pct);
 
         // Stop-loss — intrabar breach via low (long) or high (short)
-        if let Some(sl_pct) = sl_pct {
+        let stop_hit = sl_pct.and_then(|sl_pct| {
             let stop_price = if position.is_long() {
                 position.entry_price * (1.0 - sl_pct)
             } else {
@@ -1109,24 +1276,11 @@ impl BacktestEngine {
             } else {
                 candle.high >= stop_price
             };
-            if triggered {
-                // if the bar already opened through the stop level, fill
-                // at the open (slippage/gap) rather than the stop price.
-                let fill_price = if position.is_long() {
-                    candle.open.min(stop_price)
-                } else {
-                    candle.open.max(stop_price)
-                };
-                let return_pct = position.unrealized_return_pct(fill_price);
-                return Some(
-                    Signal::exit(candle.timestamp, fill_price)
-                        .with_reason(format!("Stop-loss triggered ({:.1}%)", return_pct)),
-                );
-            }
-        }
+            triggered.then_some(stop_price)
+        });
 
         // Take-profit — intrabar breach via high (long) or low (short)
-        if let Some(tp_pct) = tp_pct {
+        let tp_hit = tp_pct.and_then(|tp_pct| {
             let tp_price = if position.is_long() {
                 position.entry_price * (1.0 + tp_pct)
             } else {
@@ -1137,19 +1291,47 @@ impl BacktestEngine {
             } else {
                 candle.low <= tp_price
             };
-            if triggered {
-                // Gap guard: a gap-up open past TP gives a better fill at the open.
-                let fill_price = if position.is_long() {
-                    candle.open.max(tp_price)
-                } else {
-                    candle.open.min(tp_price)
-                };
-                let return_pct = position.unrealized_return_pct(fill_price);
-                return Some(
-                    Signal::exit(candle.timestamp, fill_price)
-                        .with_reason(format!("Take-profit triggered ({:.1}%)", return_pct)),
-                );
+            triggered.then_some(tp_price)
+        });
+
+        // Both touched on one bar: the configured priority decides.
+        let tp_first = match (stop_hit, tp_hit) {
+            (Some(stop_price), Some(tp_price)) => {
+                self.config
+                    .intrabar_priority
+                    .target_first(candle.open, stop_price, tp_price)
             }
+            (None, Some(_)) => true,
+            _ => false,
+        };
+
+        if let Some(tp_price) = tp_hit.filter(|_| tp_first) {
+            // Gap guard: a gap-up open past TP gives a better fill at the open.
+            let fill_price = if position.is_long() {
+                candle.open.max(tp_price)
+            } else {
+                candle.open.min(tp_price)
+            };
+            let return_pct = position.unrealized_return_pct(fill_price);
+            return Some(
+                Signal::exit(candle.timestamp, fill_price)
+                    .with_reason(format!("Take-profit triggered ({:.1}%)", return_pct)),
+            );
+        }
+
+        if let Some(stop_price) = stop_hit {
+            // if the bar already opened through the stop level, fill
+            // at the open (slippage/gap) rather than the stop price.
+            let fill_price = if position.is_long() {
+                candle.open.min(stop_price)
+            } else {
+                candle.open.max(stop_price)
+            };
+            let return_pct = position.unrealized_return_pct(fill_price);
+            return Some(
+                Signal::exit(candle.timestamp, fill_price)
+                    .with_reason(format!("Stop-loss triggered ({:.1}%)", return_pct)),
+            );
         }
 
         // Trailing stop — checked after SL/TP so explicit levels take priority.
@@ -1196,16 +1378,21 @@ impl BacktestEngine {
         &self,
         signal: &Signal,
         candle: &Candle,
+        history: &[Candle],
         position: &mut Option<Position>,
         cash: &mut f64,
         trades: &mut Vec<Trade>,
     ) -> bool {
+        let entry = EntryHistory {
+            candles: history,
+            trades,
+        };
         match signal.direction {
             SignalDirection::Long => {
                 if position.is_some() {
                     return false; // Already have a position
                 }
-                self.open_position(position, cash, candle, signal, true)
+                self.open_position(position, cash, candle, signal, entry)
             }
             SignalDirection::Short => {
                 if position.is_some() {
@@ -1214,7 +1401,7 @@ impl BacktestEngine {
                 if !self.config.allow_short {
                     return false; // Shorts not allowed
                 }
-                self.open_position(position, cash, candle, signal, false)
+                self.open_position(position, cash, candle, signal, entry)
             }
             SignalDirection::Exit => {
                 if position.is_none() {
@@ -1252,6 +1439,14 @@ impl BacktestEngine {
             None => return false,
         };
 
+        if self
+            .config
+            .max_scale_ins
+            .is_some_and(|max| pos.scale_in_count >= max)
+        {
+            return false; // Pyramid limit reached
+        }
+
         let is_long = pos.is_long();
         let fill_price_slipped = self.config.apply_entry_slippage(candle.open, is_long);
         let fill_price = self.config.apply_entry_spread(fill_price_slipped, is_long);
@@ -1279,17 +1474,28 @@ impl BacktestEngine {
             commission
         };
 
-        if total_cost > *cash {
+        if total_cost > self.config.buying_power(*cash, equity) {
             return false; // Not enough cash
         }
 
+        let exposure = pos.quantity * candle.open + additional_value;
+        if exposure > equity * self.config.max_leverage {
+            return false; // Leverage limit reached
+        }
+
         if is_long {
             *cash -= additional_value + commission + entry_tax;
         } else {
             *cash += additional_value - commission;
         }
 
-        pos.scale_in(fill_price, additional_qty, commission, entry_tax);
+        pos.scale_in_at(
+            candle.timestamp,
+            fill_price,
+            additional_qty,
+            commission,
+            entry_tax,
+        );
         true
     }
 
@@ -1307,7 +1513,27 @@ impl BacktestEngine {
         signal: &Signal,
         candle: &Candle,
     ) -> bool {
-        let fraction = signal.scale_fraction.unwrap_or(0.0).clamp(0.0, 1.0);
+        self.scale_out_position_at(position, cash, trades, signal, candle, candle.open)
+    }
+
+    /// Scale out at an explicit `fill_price`.
+    ///
+    /// Used for intrabar scale-out targets where the fill price is the target
+    /// level (with gap guard) rather than the next bar's open. An
+    /// [`Exit`](SignalDirection::Exit) signal closes the whole position.
+    fn scale_out_position_at(
+        &self,
+        position: &mut Option<Position>,
+        cash: &mut f64,
+        trades: &mut Vec<Trade>,
+        signal: &Signal,
+        candle: &Candle,
+        fill_price: f64,
+    ) -> bool {
+        let fraction = match signal.direction {
+            SignalDirection::Exit => 1.0,
+            _ => signal.scale_fraction.unwrap_or(0.0).clamp(0.0, 1.0),
+        };
         if fraction <= 0.0 {
             return false;
         }
@@ -1315,7 +1541,7 @@ impl BacktestEngine {
         // Full close — delegate to the standard exit path so all bookkeeping
         // (cash credit, HWM reset, re-evaluation) is handled identically.
         if fraction >= 1.0 {
-            return self.close_position(position, cash, trades, candle, signal);
+            return self.close_position_at(position, cash, trades, candle, fill_price, signal);
         }
 
         let pos = match position.as_mut() {
@@ -1324,7 +1550,7 @@ impl BacktestEngine {
         };
 
         let is_long = pos.is_long();
-        let exit_price_slipped = self.config.apply_exit_slippage(candle.open, is_long);
+        let exit_price_slipped = self.config.apply_exit_slippage(fill_price, is_long);
         let exit_price = self.config.apply_exit_spread(exit_price_slipped, is_long);
         let qty_closed = pos.quantity * fraction;
         let commission = self.config.calculate_commission(qty_closed, exit_price);
@@ -1355,15 +1581,17 @@ impl BacktestEngine {
     }
 
     /// Open a new position at `candle.open` (market fill).
+    ///
+    /// The side follows `signal.direction`.
     fn open_position(
         &self,
         position: &mut Option<Position>,
         cash: &mut f64,
         candle: &Candle,
         signal: &Signal,
-        is_long: bool,
+        entry: EntryHistory<'_>,
     ) -> bool {
-        self.open_position_at_price(position, cash, candle, signal, is_long, candle.open)
+        self.open_position_at_price(position, cash, candle, signal, candle.open, entry)
     }
 
     /// Open a new position at an explicit fill price.
@@ -1376,12 +1604,18 @@ impl BacktestEngine {
         cash: &mut f64,
         candle: &Candle,
         signal: &Signal,
-        is_long: bool,
         fill_price_raw: f64,
+        entry: EntryHistory<'_>,
     ) -> bool {
+        let is_long = signal.direction == SignalDirection::Long;
         let entry_price_slipped = self.config.apply_entry_slippage(fill_price_raw, is_long);
         let entry_price = self.config.apply_entry_spread(entry_price_slipped, is_long);
-        let quantity = self.config.calculate_position_size(*cash, entry_price);
+        let quantity = self.config.size_entry(&SizingContext::new(
+            *cash,
+            entry_price,
+            entry.candles,
+            entry.trades,
+        ));
 
         if quantity <= 0.0 {
             return false; // Not enough capital
@@ -1393,7 +1627,7 @@ impl BacktestEngine {
         let entry_tax = self.config.calculate_transaction_tax(entry_value, is_long);
 
         if is_long {
-            if entry_value + commission + entry_tax > *cash {
+            if entry_value + commission + entry_tax > self.config.buying_power(*cash, *cash) {
                 return false; // Not enough capital including commission and tax
             }
         } else if commission > *cash {
@@ -1520,6 +1754,59 @@ pub(crate) fn update_trailing_hwm(
     }
 }
 
+/// Hand the next position id to a position that was just opened.
+fn assign_position_id(position: &mut Option<Position>, next_id: &mut usize) {
+    if let Some(pos) = position.as_mut() {
+        pos.position_id = *next_id;
+        *next_id += 1;
+    }
+}
+
+/// Check whether the position's next untaken scale-out target was reached
+/// intrabar.
+///
+/// Returns a [`Signal::scale_out`] carrying the fill price (target level with
+/// gap guard), or a [`Signal::exit`] when the target closes the remaining
+/// quantity. The caller advances [`Position::scale_out_targets_hit`].
+/// Also used by the portfolio engine.
+pub(crate) fn check_scale_out_target(
+    position: &Position,
+    candle: &Candle,
+    config: &BacktestConfig,
+) -> Option<Signal> {
+    let target = config
+        .scale_out_targets
+        .get(position.scale_out_targets_hit)?;
+    let (target_price, triggered) = if position.is_long() {
+        let price = position.entry_price * (1.0 + target.profit_pct);
+        (price, candle.high >= price)
+    } else {
+        let price = position.entry_price * (1.0 - target.profit_pct);
+        (price, candle.low <= price)
+    };
+    if !triggered {
+        return None;
+    }
+
+    // Gap guard: an open already past the target gives a better fill.
+    let fill_price = if position.is_long() {
+        candle.open.max(target_price)
+    } else {
+        candle.open.min(target_price)
+    };
+    let reason = format!(
+        "Scale-out target {} reached (+{:.1}%)",
+        position.scale_out_targets_hit + 1,
+        target.profit_pct * 100.0
+    );
+    let signal = if target.fraction >= 1.0 {
+        Signal::exit(candle.timestamp, fill_price)
+    } else {
+        Signal::scale_out(target.fraction, candle.timestamp, fill_price)
+    };
+    Some(signal.with_reason(reason))
+}
+
 impl BacktestEngine {
     fn sync_terminal_equity_point(
         equity_curve: &mut Vec<EquityPoint>,
@@ -1559,11 +1846,21 @@ impl BacktestEngine {
 /// `symbol_candles` are the candles for the backtested symbol (used to derive
 /// its buy-and-hold return). `benchmark_candles` are the benchmark's candles.
 /// `equity_curve` is used to derive strategy periodic returns for beta/IR.
+/// History visible to the position sizer when a position is opened.
+#[derive(Clone, Copy)]
+struct EntryHistory<'a> {
+    /// Bars up to and including the bar the entry was decided on.
+    candles: &'a [Candle],
+    /// Trades closed so far.
+    trades: &'a [Trade],
+}
+
 fn compute_benchmark_metrics(
     benchmark_symbol: &str,
     symbol_candles: &[Candle],
     benchmark_candles: &[Candle],
     equity_curve: &[EquityPoint],
+    initial_capital: f64,
     risk_free_rate: f64,
     bars_per_year: f64,
 ) -> BenchmarkMetrics {
@@ -1571,6 +1868,13 @@ fn compute_benchmark_metrics(
     let benchmark_return_pct = buy_and_hold_return(benchmark_candles);
     let buy_and_hold_return_pct = buy_and_hold_return(symbol_candles);
 
+    let bench_curve = benchmark_equity_curve(benchmark_candles, equity_curve, initial_capital);
+    let max_drawdown_pct = bench_curve
+        .iter()
+        .map(|p| p.drawdown_pct)
+        .fold(0.0, f64::max);
+    let relative_drawdown_pct = relative_drawdown(equity_curve, &bench_curve);
+
     if equity_curve.len() < 2 || benchmark_candles.len() < 2 {
         return BenchmarkMetrics {
             symbol: benchmark_symbol.to_string(),
@@ -1579,6 +1883,9 @@ fn compute_benchmark_metrics(
             alpha: 0.0,
             beta: 0.0,
             information_ratio: 0.0,
+            equity_curve: bench_curve,
+            max_drawdown_pct,
+            relative_drawdown_pct,
         };
     }
 
@@ -1657,7 +1964,88 @@ fn compute_benchmark_metrics(
         alpha,
         beta,
         information_ratio: ir,
+        equity_curve: bench_curve,
+        max_drawdown_pct,
+        relative_drawdown_pct,
+    }
+}
+
+/// Buy-and-hold equity of `benchmark_candles` sampled at each strategy
+/// equity-curve timestamp.
+///
+/// Each point uses the latest benchmark close at or before its timestamp
+/// (the first close before the benchmark starts), scaled so the curve opens
+/// at `initial_capital`. `benchmark_candles` must be sorted by timestamp.
+fn benchmark_equity_curve(
+    benchmark_candles: &[Candle],
+    equity_curve: &[EquityPoint],
+    initial_capital: f64,
+) -> Vec<EquityPoint> {
+    let Some(first) = benchmark_candles.first() else {
+        return Vec::new();
+    };
+
+    let mut idx = 0;
+    let closes: Vec<(i64, f64)> = equity_curve
+        .iter()
+        .map(|point| {
+            while idx + 1 < benchmark_candles.len()
+                && benchmark_candles[idx + 1].timestamp <= point.timestamp
+            {
+                idx += 1;
+            }
+            let close = if benchmark_candles[idx].timestamp <= point.timestamp {
+                benchmark_candles[idx].close
+            } else {
+                first.close
+            };
+            (point.timestamp, close)
+        })
+        .collect();
+
+    let base = closes.first().map_or(0.0, |&(_, c)| c);
+    let mut peak = f64::NEG_INFINITY;
+    closes
+        .into_iter()
+        .map(|(timestamp, close)| {
+            let equity = if base > 0.0 {
+                initial_capital * close / base
+            } else {
+                initial_capital
+            };
+            peak = peak.max(equity);
+            let drawdown_pct = if peak > 0.0 {
+                (peak - equity) / peak
+            } else {
+                0.0
+            };
+            EquityPoint {
+                timestamp,
+                equity,
+                drawdown_pct,
+            }
+        })
+        .collect()
+}
+
+/// Maximum drawdown (fraction) of the strategy-to-benchmark equity ratio.
+///
+/// The two curves must be aligned point for point, as produced by
+/// [`benchmark_equity_curve`].
+fn relative_drawdown(equity_curve: &[EquityPoint], benchmark_curve: &[EquityPoint]) -> f64 {
+    let mut peak = f64::NEG_INFINITY;
+    let mut max_dd = 0.0_f64;
+    for (s, b) in equity_curve.iter().zip(benchmark_curve) {
+        if b.equity <= 0.0 {
+            continue;
+        }
+        let ratio = s.equity / b.equity;
+        peak = peak.max(ratio);
+        if peak > 0.0 {
+            max_dd = max_dd.max((peak - ratio) / peak);
+        }
     }
+    max_dd
 }
 
 /// Buy-and-hold return from first to last candle close (percentage).
@@ -1722,6 +2110,7 @@ fn compute_beta(strategy_returns: &[f64], benchmark_returns: &[f64]) -> f64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::backtesting::position::FillKind;
     use crate::backtesting::strategy::SmaCrossover;
     use crate::backtesting::strategy::Strategy;
     use crate::indicators::Indicator;
@@ -1832,6 +2221,71 @@ mod tests {
         assert!(!result.equity_curve.is_empty());
     }
 
+    #[test]
+    fn test_run_metadata() {
+        let candles = make_candles(&[100.0; 30]);
+        let config = BacktestConfig::builder().seed(42).build().unwrap();
+        let result = BacktestEngine::new(config.clone())
+            .run("TEST", &candles, SmaCrossover::new(5, 10))
+            .unwrap();
+
+        let meta = &result.run_metadata;
+        assert_eq!(meta.crate_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(meta.candle_count, 30);
+        assert_eq!((meta.data_start, meta.data_end), (0, 29));
+        assert_eq!(meta.seed, Some(42));
+        assert_eq!(meta.config_hash.len(), 16);
+
+        // The hash identifies the cost model, independent of the seed
+        let reseeded = BacktestEngine::new(BacktestConfig {
+            seed: None,
+            ..config
+        })
+        .run("TEST", &candles, SmaCrossover::new(5, 10))
+        .unwrap();
+        assert_eq!(reseeded.run_metadata.config_hash, meta.config_hash);
+        let costlier = BacktestEngine::new(
+            BacktestConfig::builder()
+                .slippage_pct(0.01)
+                .build()
+                .unwrap(),
+        )
+        .run("TEST", &candles, SmaCrossover::new(5, 10))
+        .unwrap();
+        assert_ne!(costlier.run_metadata.config_hash, meta.config_hash);
+    }
+
+    #[test]
+    fn test_trade_excursions_and_bars_held() {
+        let candles = make_candles(&[100.0, 95.0, 110.0, 105.0]);
+        let config = BacktestConfig::builder()
+            .commission_pct(0.0)
+            .slippage_pct(0.0)
+            .build()
+            .unwrap();
+        let result = BacktestEngine::new(config)
+            .run("TEST", &candles, EnterLongHold)
+            .unwrap();
+
+        let trade = &result.trades[0];
+        let entry = trade.entry_price;
+        let expected_mae = (entry - 95.0 * 0.99) / entry * 100.0;
+        let expected_mfe = (110.0 * 1.01 - entry) / entry * 100.0;
+        assert!((trade.mae_pct - expected_mae).abs() < 1e-9);
+        assert!((trade.mfe_pct - expected_mfe).abs() < 1e-9);
+        assert_eq!(trade.bars_held, 3);
+        assert!((result.metrics.avg_mae_pct - trade.mae_pct).abs() < 1e-9);
+        assert_eq!(result.metrics.avg_bars_held, 3.0);
+
+        // Shorts see the same bars as the mirror image
+        let config = BacktestConfig::builder().allow_short(true).build().unwrap();
+        let short = BacktestEngine::new(config)
+            .run("TEST", &candles, EnterShortHold)
+            .unwrap();
+        let trade = &short.trades[0];
+        assert!(trade.mae_pct > trade.mfe_pct);
+    }
+
     #[test]
     fn test_stop_loss() {
         // Price drops significantly after entry
@@ -1922,6 +2376,55 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_run_on_custom_candles() {
+        let prices: Vec<f64> = (0..40).map(|i| 100.0 + (i % 10) as f64).collect();
+        let candles = make_candles(&prices);
+        let result = run(SmaCrossover::new(3, 5), &candles, BacktestConfig::default()).unwrap();
+        assert_eq!(result.symbol, "");
+        assert_eq!(result.equity_curve.len(), candles.len());
+
+        let mut unsorted = candles.clone();
+        unsorted.swap(0, 1);
+        let err = run(
+            SmaCrossover::new(3, 5),
+            &unsorted,
+            BacktestConfig::default(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, BacktestError::InvalidParameter { .. }));
+    }
+
+    #[test]
+    fn test_benchmark_equity_curve_and_relative_drawdown() {
+        // Flat strategy symbol: no crossovers, so equity stays at initial capital
+        let candles = make_candles(&[100.0; 5]);
+        // Sparse benchmark: missing bars carry the previous close forward
+        let bench = make_candles_with_timestamps(&[100.0, 120.0, 90.0], &[0, 2, 4]);
+
+        let result = BacktestEngine::new(BacktestConfig::zero_cost())
+            .run_with_benchmark(
+                "TEST",
+                &candles,
+                SmaCrossover::new(2, 3),
+                &[],
+                "BENCH",
+                &bench,
+            )
+            .unwrap();
+        assert!(result.trades.is_empty());
+
+        let bm = result.benchmark.unwrap();
+        let equity: Vec<f64> = bm.equity_curve.iter().map(|p| p.equity).collect();
+        assert_eq!(
+            equity,
+            vec![10_000.0, 10_000.0, 12_000.0, 12_000.0, 9_000.0]
+        );
+        assert!((bm.max_drawdown_pct - 0.25).abs() < 1e-9);
+        // Strategy/benchmark ratio falls from 1.0 to 10_000 / 12_000 at the peak
+        assert!((bm.relative_drawdown_pct - (1.0 - 10.0 / 12.0)).abs() < 1e-9);
+    }
+
     #[test]
     fn test_capm_alpha_with_risk_free_rate() {
         // When risk_free_rate = 0, alpha should equal the simplified formula.
@@ -2140,6 +2643,133 @@ mod tests {
         assert!(result.final_equity < 10_000.0);
     }
 
+    #[test]
+    fn test_dividends_received_and_paid() {
+        use crate::models::chart::Dividend;
+
+        let candles = make_candles(&[100.0, 100.0, 100.0]);
+        let dividends = vec![Dividend {
+            timestamp: candles[1].timestamp,
+            amount: 1.0,
+            provider_id: None,
+        }];
+        let builder = || {
+            BacktestConfig::builder()
+                .initial_capital(10_000.0)
+                .allow_short(true)
+                .commission_pct(0.0)
+                .slippage_pct(0.0)
+        };
+
+        let long = BacktestEngine::new(builder().build().unwrap())
+            .run_with_dividends("TEST", &candles, EnterLongHold, &dividends)
+            .unwrap();
+        assert!((long.dividends_received() - 100.0).abs() < 1e-9);
+        assert_eq!(long.dividends_paid(), 0.0);
+
+        let short = BacktestEngine::new(builder().build().unwrap())
+            .run_with_dividends("TEST", &candles, EnterShortHold, &dividends)
+            .unwrap();
+        assert!((short.dividends_paid() - 100.0).abs() < 1e-9);
+        assert!((short.final_equity - 9_900.0).abs() < 1e-9);
+
+        let exempt = BacktestEngine::new(builder().short_pays_dividends(false).build().unwrap())
+            .run_with_dividends("TEST", &candles, EnterShortHold, &dividends)
+            .unwrap();
+        assert_eq!(exempt.dividends_paid(), 0.0);
+        assert!((exempt.final_equity - 10_000.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_use_total_return_folds_dividends_into_prices() {
+        use crate::models::chart::Dividend;
+
+        let candles = make_candles(&[100.0, 100.0, 100.0, 100.0]);
+        let dividends = vec![Dividend {
+            timestamp: candles[2].timestamp,
+            amount: 1.0,
+            provider_id: None,
+        }];
+        let config = BacktestConfig::builder()
+            .initial_capital(10_000.0)
+            .commission_pct(0.0)
+            .slippage_pct(0.0)
+            .use_total_return(true)
+            .build()
+            .unwrap();
+
+        let result = BacktestEngine::new(config)
+            .run_with_dividends("TEST", &candles, EnterLongHold, &dividends)
+            .unwrap();
+
+        // The $1 is reinvested at the prior $100 close rather than paid out
+        assert_eq!(result.dividends_received(), 0.0);
+        assert!((result.final_equity - 10_000.0 / 0.99).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_short_borrow_fee_accrues_each_bar() {
+        let candles = make_candles(&[100.0; 11]);
+        // 25.2% / 252 bars = 0.1% of the short's value per bar
+        let config = BacktestConfig::builder()
+            .initial_capital(10_000.0)
+            .allow_short(true)
+            .commission_pct(0.0)
+            .slippage_pct(0.0)
+            .borrow_fee_rate(0.252)
+            .build()
+            .unwrap();
+
+        let result = BacktestEngine::new(config)
+            .run("TEST", &candles, EnterShortHold)
+            .unwrap();
+
+        // Short 100 @ 100 filled at bar 1's open, charged $10 on each of bars 1..=10
+        let trade = &result.trades[0];
+        assert!((trade.financing_cost - 100.0).abs() < 1e-6);
+        assert!((trade.pnl + 100.0).abs() < 1e-6);
+        assert!((result.final_equity - 9_900.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_leverage_and_margin_interest() {
+        let candles = make_candles(&[100.0; 11]);
+        let config = BacktestConfig::builder()
+            .initial_capital(10_000.0)
+            .commission_pct(0.0)
+            .slippage_pct(0.0)
+            .max_leverage(2.0)
+            .margin_interest_rate(0.252)
+            .build()
+            .unwrap();
+
+        let result = BacktestEngine::new(config)
+            .run("TEST", &candles, EnterLongHold)
+            .unwrap();
+
+        // 2x buys 200 shares with $10k of margin debt at 0.1% a bar; unpaid
+        // interest is added to the debt, so it compounds over the 10 bars
+        let interest = 10_000.0 * (1.001_f64.powi(10) - 1.0);
+        let trade = &result.trades[0];
+        assert!((trade.quantity - 200.0).abs() < 1e-6);
+        assert!((trade.financing_cost - interest).abs() < 1e-6);
+        assert!((result.final_equity - (10_000.0 - interest)).abs() < 1e-6);
+
+        // Without leverage there is no debt and so no interest
+        let config = BacktestConfig::builder()
+            .initial_capital(10_000.0)
+            .commission_pct(0.0)
+            .slippage_pct(0.0)
+            .margin_interest_rate(0.252)
+            .build()
+            .unwrap();
+        let result = BacktestEngine::new(config)
+            .run("TEST", &candles, EnterLongHold)
+            .unwrap();
+        assert!((result.trades[0].quantity - 100.0).abs() < 1e-6);
+        assert_eq!(result.trades[0].financing_cost, 0.0);
+    }
+
     #[test]
     fn test_open_position_final_equity_includes_accrued_dividends() {
         use crate::models::chart::Dividend;
@@ -2369,6 +2999,182 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_intrabar_priority_when_bar_touches_stop_and_target() {
+        use crate::backtesting::IntrabarPriority;
+
+        // Entry at 100 on bar 1; stop at 95, TP at 110. Bar 2 touches both.
+        let candles_with_open = |open: f64| {
+            vec![
+                make_candle_ohlc(0, 100.0, 101.0, 99.0, 100.0),
+                make_candle_ohlc(1, 100.0, 100.0, 100.0, 100.0), // entry fill
+                make_candle_ohlc(2, open, 111.0, 94.0, 100.0),
+                make_candle_ohlc(3, 100.0, 100.0, 100.0, 100.0),
+            ]
+        };
+        let exit_price = |priority: IntrabarPriority, open: f64| {
+            let config = BacktestConfig::builder()
+                .stop_loss_pct(0.05)
+                .take_profit_pct(0.10)
+                .intrabar_priority(priority)
+                .commission_pct(0.0)
+                .slippage_pct(0.0)
+                .build()
+                .unwrap();
+            let result = BacktestEngine::new(config)
+                .run("TEST", &candles_with_open(open), EnterLongBar0)
+                .unwrap();
+            assert_eq!(result.trades[0].exit_timestamp, 2);
+            result.trades[0].exit_price
+        };
+
+        assert!((exit_price(IntrabarPriority::StopFirst, 103.0) - 95.0).abs() < 1e-9);
+        assert!((exit_price(IntrabarPriority::TargetFirst, 103.0) - 110.0).abs() < 1e-9);
+        // Nearest to the open: 103 is closer to the TP, 97 to the stop
+        assert!((exit_price(IntrabarPriority::NearestToOpen, 103.0) - 110.0).abs() < 1e-9);
+        assert!((exit_price(IntrabarPriority::NearestToOpen, 97.0) - 95.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_intrabar_gap_through_level_fills_at_open_for_every_priority() {
+        use crate::backtesting::IntrabarPriority;
+
+        // Entry at 100 on bar 1; stop at 95, TP at 110. Bar 2 opens past one
+        // level and its range still reaches the other.
+        let exit_price = |priority: IntrabarPriority, bar: Candle| {
+            let candles = vec![
+                make_candle_ohlc(0, 100.0, 101.0, 99.0, 100.0),
+                make_candle_ohlc(1, 100.0, 100.0, 100.0, 100.0), // entry fill
+                bar,
+                make_candle_ohlc(3, 100.0, 100.0, 100.0, 100.0),
+            ];
+            let config = BacktestConfig::builder()
+                .stop_loss_pct(0.05)
+                .take_profit_pct(0.10)
+                .intrabar_priority(priority)
+                .commission_pct(0.0)
+                .slippage_pct(0.0)
+                .build()
+                .unwrap();
+            let result = BacktestEngine::new(config)
+                .run("TEST", &candles, EnterLongBar0)
+                .unwrap();
+            assert_eq!(result.trades[0].exit_timestamp, 2);
+            result.trades[0].exit_price
+        };
+
+        for priority in [
+            IntrabarPriority::StopFirst,
+            IntrabarPriority::TargetFirst,
+            IntrabarPriority::NearestToOpen,
+        ] {
+            // Gap up past the TP: the target fills at the open
+            let gap_up = make_candle_ohlc(2, 112.0, 113.0, 94.0, 100.0);
+            assert!((exit_price(priority, gap_up) - 112.0).abs() < 1e-9);
+            // Gap down past the stop: the stop fills at the open
+            let gap_down = make_candle_ohlc(2, 93.0, 111.0, 92.0, 100.0);
+            assert!((exit_price(priority, gap_down) - 93.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_position_sizer_replaces_position_size_pct() {
+        use crate::backtesting::sizing::{FixedAmount, PositionSizer, SizingContext};
+
+        let candles = vec![
+            make_candle_ohlc(0, 100.0, 100.0, 100.0, 100.0),
+            make_candle_ohlc(1, 100.0, 100.0, 100.0, 100.0), // entry fill
+            make_candle_ohlc(2, 100.0, 100.0, 100.0, 100.0),
+        ];
+        let entry_quantity = |config: BacktestConfig| {
+            BacktestEngine::new(config)
+                .run("TEST", &candles, EnterLongBar0)
+                .unwrap()
+                .trades[0]
+                .quantity
+        };
+
+        let fixed = BacktestConfig {
+            position_sizer: Some(std::sync::Arc::new(FixedAmount::new(2_500.0))),
+            ..BacktestConfig::zero_cost()
+        };
+        assert!((entry_quantity(fixed) - 25.0).abs() < 1e-9);
+
+        // The sizer sees bars through the signal bar, not the fill bar
+        #[derive(Debug)]
+        struct PerBarOfHistory;
+        impl PositionSizer for PerBarOfHistory {
+            fn allocation(&self, ctx: &SizingContext<'_>) -> f64 {
+                ctx.candles.len() as f64 * 100.0
+            }
+        }
+        let config = BacktestConfig::builder()
+            .position_sizer(PerBarOfHistory)
+            .commission_pct(0.0)
+            .slippage_pct(0.0)
+            .build()
+            .unwrap();
+        assert!((entry_quantity(config) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_indicator_on_reads_completed_higher_timeframe_bars() {
+        use crate::backtesting::condition::HtfIndicatorSpec;
+        use crate::constants::Interval;
+
+        struct DailyClose;
+        impl Strategy for DailyClose {
+            fn name(&self) -> &str {
+                "Daily Close"
+            }
+            fn required_indicators(&self) -> Vec<(String, Indicator)> {
+                vec![]
+            }
+            fn htf_requirements(&self) -> Vec<HtfIndicatorSpec> {
+                vec![HtfIndicatorSpec::new(
+                    Interval::OneDay,
+                    "sma_1",
+                    Indicator::Sma(1),
+                )]
+            }
+            fn on_candle(&self, _ctx: &StrategyContext) -> Signal {
+                Signal::hold()
+            }
+        }
+
+        // Three days of four hourly bars, closing at 10, 20, 30 each day
+        let candles: Vec<Candle> = (0..12)
+            .map(|i| {
+                let day = i / 4;
+                let ts = day * 86_400 + 14 * 3_600 + (i % 4) * 3_600;
+                make_candle_ohlc(ts, 0.0, 0.0, 0.0, (day + 1) as f64 * 10.0)
+            })
+            .collect();
+
+        let engine = BacktestEngine::new(BacktestConfig::default());
+        let indicators = engine
+            .compute_htf_indicators(&candles, &DailyClose)
+            .unwrap();
+        let daily: Vec<Option<f64>> = (0..candles.len())
+            .map(|index| {
+                StrategyContext {
+                    candles: &candles[..=index],
+                    index,
+                    position: None,
+                    equity: 0.0,
+                    indicators: &indicators,
+                }
+                .indicator_on(Interval::OneDay, "sma_1")
+            })
+            .collect();
+
+        // A day's value appears on its last hourly bar, never earlier
+        assert_eq!(&daily[..3], &[None, None, None]);
+        assert_eq!(&daily[3..7], &[Some(10.0); 4]);
+        assert_eq!(&daily[7..11], &[Some(20.0); 4]);
+        assert_eq!(daily[11], Some(30.0));
+    }
+
     // ── Position scaling integration tests ───────────────────────────────────
 
     /// Strategy: enter long on bar 0, scale in on bar 1, exit on bar 2.
@@ -2715,6 +3521,156 @@ mod tests {
         );
     }
 
+    /// Strategy: enter long on bar 0, then request a scale-in on every bar.
+    #[derive(Clone)]
+    struct EnterThenPyramid;
+
+    impl Strategy for EnterThenPyramid {
+        fn name(&self) -> &str {
+            "EnterThenPyramid"
+        }
+
+        fn required_indicators(&self) -> Vec<(String, Indicator)> {
+            vec![]
+        }
+
+        fn on_candle(&self, ctx: &StrategyContext) -> Signal {
+            if !ctx.has_position() {
+                Signal::long(ctx.timestamp(), ctx.close())
+            } else {
+                Signal::scale_in(0.05, ctx.timestamp(), ctx.close())
+            }
+        }
+    }
+
+    #[test]
+    fn test_max_scale_ins_caps_pyramid_adds() {
+        let candles = make_candles(&[100.0; 8]);
+        let config = BacktestConfig::builder()
+            .initial_capital(10_000.0)
+            .position_size_pct(0.5)
+            .commission_pct(0.0)
+            .slippage_pct(0.0)
+            .max_scale_ins(2)
+            .close_at_end(true)
+            .build()
+            .unwrap();
+
+        let result = BacktestEngine::new(config)
+            .run("TEST", &candles, EnterThenPyramid)
+            .unwrap();
+
+        let executed_adds = result
+            .signals
+            .iter()
+            .filter(|s| matches!(s.direction, SignalDirection::ScaleIn) && s.executed)
+            .count();
+        assert_eq!(executed_adds, 2);
+
+        let trade = &result.trades[0];
+        let kinds: Vec<FillKind> = trade.fills.iter().map(|f| f.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                FillKind::Entry,
+                FillKind::ScaleIn,
+                FillKind::ScaleIn,
+                FillKind::Exit
+            ]
+        );
+        // Entry and adds sum to the exited quantity
+        let bought: f64 = trade.fills[..3].iter().map(|f| f.quantity).sum();
+        assert!((bought - trade.quantity).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_scale_out_targets_take_partial_profits() {
+        // Entry fills at 100 on bar 1; bar 2 reaches +5%, bar 3 gaps past +10%.
+        let candles = vec![
+            make_candle_ohlc(0, 100.0, 100.0, 100.0, 100.0),
+            make_candle_ohlc(1, 100.0, 100.0, 100.0, 100.0),
+            make_candle_ohlc(2, 101.0, 106.0, 100.0, 104.0),
+            make_candle_ohlc(3, 112.0, 113.0, 111.0, 112.0),
+            make_candle_ohlc(4, 112.0, 112.0, 112.0, 112.0),
+        ];
+        let config = BacktestConfig::builder()
+            .initial_capital(10_000.0)
+            .commission_pct(0.0)
+            .slippage_pct(0.0)
+            .scale_out_target(0.10, 1.0)
+            .scale_out_target(0.05, 0.5)
+            .build()
+            .unwrap();
+
+        let result = BacktestEngine::new(config)
+            .run("TEST", &candles, EnterLongBar0)
+            .unwrap();
+
+        assert!(result.open_position.is_none());
+        assert_eq!(result.trades.len(), 2);
+        let (partial, last) = (&result.trades[0], &result.trades[1]);
+
+        assert!(partial.is_partial);
+        assert!((partial.exit_price - 105.0).abs() < 1e-9);
+        assert_eq!(partial.exit_timestamp, 2);
+        assert_eq!(partial.fills.len(), 1);
+
+        // Gap guard: bar 3 opened at 112, above the 110 target
+        assert!(!last.is_partial);
+        assert!((last.exit_price - 112.0).abs() < 1e-9);
+        assert!((partial.quantity - last.quantity).abs() < 1e-9);
+
+        // Parent/child grouping: both trades belong to the first position,
+        // and the parent carries entry, scale-out, and exit fills.
+        let groups = result.trades_by_position();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[&0].len(), 2);
+        let kinds: Vec<FillKind> = last.fills.iter().map(|f| f.kind).collect();
+        assert_eq!(kinds, [FillKind::Entry, FillKind::ScaleOut, FillKind::Exit]);
+    }
+
+    #[test]
+    fn test_position_ids_increment_per_position() {
+        let prices = [100.0, 101.0, 102.0, 103.0, 104.0, 105.0, 106.0, 107.0];
+        let candles = make_candles(&prices);
+        let config = BacktestConfig::builder()
+            .commission_pct(0.0)
+            .slippage_pct(0.0)
+            .build()
+            .unwrap();
+
+        let result = BacktestEngine::new(config)
+            .run("TEST", &candles, EnterScaleOutExit)
+            .unwrap();
+        assert!(result.trades.iter().all(|t| t.position_id == 0));
+
+        /// Strategy: flip between long and flat every bar.
+        #[derive(Clone)]
+        struct InAndOut;
+        impl Strategy for InAndOut {
+            fn name(&self) -> &str {
+                "InAndOut"
+            }
+            fn required_indicators(&self) -> Vec<(String, Indicator)> {
+                vec![]
+            }
+            fn on_candle(&self, ctx: &StrategyContext) -> Signal {
+                if ctx.has_position() {
+                    Signal::exit(ctx.timestamp(), ctx.close())
+                } else {
+                    Signal::long(ctx.timestamp(), ctx.close())
+                }
+            }
+        }
+
+        let result = BacktestEngine::new(BacktestConfig::zero_cost())
+            .run("TEST", &candles, InAndOut)
+            .unwrap();
+        assert!(result.trades.len() > 1);
+        let ids: Vec<usize> = result.trades.iter().map(|t| t.position_id).collect();
+        assert_eq!(ids, (0..result.trades.len()).collect::<Vec<_>>());
+    }
+
     // ── Per-trade bracket orders (Phase 11) ──────────────────────────────────
 
     // Each bracket type is tested for both Long and Short sides.
@@ -110,15 +110,19 @@ pub mod refs;
 pub mod resample;
 mod result;
 mod signal;
+pub mod sizing;
+pub mod split;
 pub mod strategy;
 pub mod walk_forward;
 
 // Re-export main types
-pub use config::{BacktestConfig, BacktestConfigBuilder};
-pub use engine::BacktestEngine;
+pub use config::{BacktestConfig, BacktestConfigBuilder, IntrabarPriority, ScaleOutTarget};
+pub use engine::{BacktestEngine, run, run_backtest_on_candles};
 pub use error::{BacktestError, Result};
-pub use position::{Position, PositionSide, Trade};
-pub use result::{BacktestResult, BenchmarkMetrics, EquityPoint, PerformanceMetrics, SignalRecord};
+pub use position::{Fill, FillKind, Position, PositionSide, Trade};
+pub use result::{
+    BacktestResult, BenchmarkMetrics, EquityPoint, PerformanceMetrics, RunMetadata, SignalRecord,
+};
 pub use signal::{
     OrderType, PendingOrder, Signal, SignalDirection, SignalMetadata, SignalStrength,
 };
@@ -129,6 +133,9 @@ pub use strategy::{Strategy, StrategyContext};
 // Re-export strategy builder
 pub use strategy::StrategyBuilder;
 
+// Re-export serializable strategy definitions
+pub use strategy::{ConditionDef, RefDef, StrategyDefinition, TargetDef, ValueDef};
+
 // Re-export pre-built strategies
 pub use strategy::{
     BollingerMeanReversion, DonchianBreakout, MacdSignal, RsiReversal, SmaCrossover,
@@ -147,6 +154,14 @@ pub use optimizer::{
 // Re-export walk-forward types
 pub use walk_forward::{WalkForwardConfig, WalkForwardReport, WindowResult};
 
+// Re-export train/test split evaluation
+pub use split::{OptimizedSplitReport, OutOfSampleReport, evaluate_out_of_sample};
+
+// Re-export position sizing models
+pub use sizing::{
+    FixedAmount, FixedFraction, KellyFraction, PositionSizer, SizingContext, VolatilityTarget,
+};
+
 // Re-export Monte Carlo types
 pub use monte_carlo::{MonteCarloConfig, MonteCarloMethod, MonteCarloResult, PercentileStats};
 
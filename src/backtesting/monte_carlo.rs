@@ -74,6 +74,9 @@ pub enum MonteCarloMethod {
 
 // ── Configuration ─────────────────────────────────────────────────────────────
 
+/// Seed used when neither the config nor the backtest result provides one.
+const DEFAULT_SEED: u64 = 12345;
+
 /// Configuration for Monte Carlo simulation.
 #[non_exhaustive]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -86,8 +89,10 @@ pub struct MonteCarloConfig {
 
     /// Optional seed for the PRNG, enabling reproducible results.
     ///
-    /// `None` (default) uses a fixed internal seed (`12345`). Provide an
-    /// explicit seed when you need deterministic output across runs.
+    /// `None` (default) falls back to the backtest's
+    /// [`RunMetadata::seed`](super::RunMetadata::seed), then to a fixed
+    /// internal seed (`12345`). Provide an explicit seed when you need
+    /// deterministic output across runs.
     pub seed: Option<u64>,
 
     /// Resampling method. Default: [`MonteCarloMethod::IidShuffle`].
@@ -140,6 +145,10 @@ impl MonteCarloConfig {
     /// Use the percentile outputs as a *relative* stress-test tool rather than
     /// a precise probability statement about future performance.
     pub fn run(&self, result: &BacktestResult) -> MonteCarloResult {
+        let seed = self
+            .seed
+            .or(result.run_metadata.seed)
+            .unwrap_or(DEFAULT_SEED);
         let initial_capital = result.initial_capital;
         let trade_returns: Vec<f64> = result.trades.iter().map(|t| t.return_pct / 100.0).collect();
 
@@ -160,6 +169,7 @@ impl MonteCarloConfig {
             return MonteCarloResult {
                 num_simulations: self.num_simulations,
                 method: self.method.clone(),
+                seed,
                 total_return: trivial(obs_return),
                 max_drawdown: trivial(obs_dd),
                 sharpe_ratio: trivial(obs_sharpe),
@@ -167,7 +177,6 @@ impl MonteCarloConfig {
             };
         }
 
-        let seed = self.seed.unwrap_or(12345);
         let mut rng = Xorshift64::new(seed);
 
         let position_size = result.config.position_size_pct;
@@ -226,6 +235,7 @@ impl MonteCarloConfig {
         MonteCarloResult {
             num_simulations: self.num_simulations,
             method: self.method.clone(),
+            seed,
             total_return: PercentileStats::from_sorted(&mut sim_returns),
             max_drawdown: PercentileStats::from_sorted(&mut sim_drawdowns),
             sharpe_ratio: PercentileStats::from_sorted(&mut sim_sharpes),
@@ -290,6 +300,10 @@ pub struct MonteCarloResult {
     /// Resampling method used to generate the simulations
     pub method: MonteCarloMethod,
 
+    /// PRNG seed the simulations were drawn with
+    #[serde(default)]
+    pub seed: u64,
+
     /// Distribution of total return (%) across simulations
     pub total_return: PercentileStats,
 
@@ -582,9 +596,15 @@ mod tests {
             return_pct: ((exit / entry) - 1.0) * 100.0,
             dividend_income: 0.0,
             unreinvested_dividends: 0.0,
+            financing_cost: 0.0,
             tags: Vec::new(),
             is_partial: false,
             scale_sequence: 0,
+            position_id: 0,
+            fills: Vec::new(),
+            mae_pct: 0.0,
+            mfe_pct: 0.0,
+            bars_held: 0,
             entry_signal: make_signal(),
             exit_signal: Signal::exit(86400, exit),
         }
@@ -627,6 +647,7 @@ mod tests {
             open_position: None,
             benchmark: None,
             diagnostics: vec![],
+            run_metadata: Default::default(),
         }
     }
 
@@ -652,6 +673,20 @@ mod tests {
         assert!((mc1.max_drawdown.p50 - mc2.max_drawdown.p50).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn test_seed_falls_back_to_run_metadata() {
+        let mut result = minimal_result(mixed_trades());
+        assert_eq!(MonteCarloConfig::default().run(&result).seed, DEFAULT_SEED);
+
+        result.run_metadata.seed = Some(7);
+        let from_result = MonteCarloConfig::default().run(&result);
+        let explicit = MonteCarloConfig::default().seed(7).run(&result);
+        assert_eq!(from_result.seed, 7);
+        assert!((from_result.total_return.p50 - explicit.total_return.p50).abs() < f64::EPSILON);
+
+        assert_eq!(MonteCarloConfig::default().seed(3).run(&result).seed, 3);
+    }
+
     #[test]
     fn test_percentile_ordering() {
         let trades = vec![
@@ -35,11 +35,12 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 
 use rayon::prelude::*;
 
-use crate::models::chart::Candle;
+use crate::models::chart::{Candle, Chart};
 
 use super::super::config::BacktestConfig;
 use super::super::engine::BacktestEngine;
 use super::super::error::{BacktestError, Result};
+use super::super::split::{OptimizedSplitReport, evaluate_out_of_sample};
 use super::super::strategy::Strategy;
 use super::{
     OptimizationReport, OptimizationResult, OptimizeMetric, ParamRange, ParamValue,
@@ -197,6 +198,39 @@ impl GridSearch {
             n_evaluations,
         })
     }
+
+    /// Optimise on `train`, then evaluate the best parameters on `test`.
+    ///
+    /// Runs the grid over the training candles, picks the best combination by
+    /// the target metric, and re-runs it through [`evaluate_out_of_sample`] so
+    /// the test segment is warmed up on the training history without trading
+    /// in it. Compare `split.in_sample` with `split.out_of_sample` to see how
+    /// much of the in-sample edge survives.
+    ///
+    /// Use [`WalkForwardConfig`] instead for repeated rolling splits.
+    ///
+    /// [`evaluate_out_of_sample`]: super::super::evaluate_out_of_sample
+    /// [`WalkForwardConfig`]: super::super::walk_forward::WalkForwardConfig
+    pub fn run_out_of_sample<S, F>(
+        &self,
+        train: &Chart,
+        test: &Chart,
+        config: &BacktestConfig,
+        factory: F,
+    ) -> Result<OptimizedSplitReport>
+    where
+        S: Strategy + Clone + Send,
+        F: Fn(&HashMap<String, ParamValue>) -> S + Send + Sync,
+    {
+        let optimization = self.run(&train.symbol, &train.candles, config, &factory)?;
+        let best_params = optimization.best.params.clone();
+        let split = evaluate_out_of_sample(factory(&best_params), train, test, config.clone())?;
+        Ok(OptimizedSplitReport {
+            optimization,
+            best_params,
+            split,
+        })
+    }
 }
 
 // ── Internal helpers ──────────────────────────────────────────────────────────
@@ -233,7 +267,7 @@ fn cartesian_product(params: &[(&str, Vec<ParamValue>)]) -> Vec<HashMap<String,
 mod tests {
     use super::*;
     use crate::backtesting::{BacktestConfig, SmaCrossover};
-    use crate::models::chart::Candle;
+    use crate::models::chart::{Candle, ChartMeta};
 
     fn make_candles(prices: &[f64]) -> Vec<Candle> {
         prices
@@ -428,6 +462,43 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_run_out_of_sample_uses_best_params() {
+        let candles = make_candles(&trending_prices(120));
+        let chart = |candles: &[Candle]| Chart {
+            symbol: "TEST".to_string(),
+            meta: ChartMeta::default(),
+            candles: candles.to_vec(),
+            interval: None,
+            range: None,
+            provider_id: None,
+            adjustment: None,
+        };
+        let (train, test) = (chart(&candles[..80]), chart(&candles[80..]));
+        let config = BacktestConfig::builder()
+            .commission_pct(0.0)
+            .slippage_pct(0.0)
+            .build()
+            .unwrap();
+
+        let report = GridSearch::new()
+            .param("fast", ParamRange::int_range(3, 9, 3))
+            .param("slow", ParamRange::int_range(10, 20, 10))
+            .optimize_for(OptimizeMetric::TotalReturn)
+            .run_out_of_sample(&train, &test, &config, |params| {
+                SmaCrossover::new(
+                    params["fast"].as_int() as usize,
+                    params["slow"].as_int() as usize,
+                )
+            })
+            .unwrap();
+
+        assert_eq!(report.best_params, report.optimization.best.params);
+        assert_eq!(report.split.split_timestamp, candles[80].timestamp);
+        let oos_start = report.split.out_of_sample.equity_curve[0].timestamp;
+        assert!(oos_start >= candles[80].timestamp);
+    }
+
     #[test]
     fn test_optimize_metric_min_drawdown() {
         let prices = trending_prices(60);
@@ -98,6 +98,13 @@ impl PortfolioConfig {
     pub fn validate(&self, num_symbols: usize) -> Result<()> {
         self.base.validate()?;
 
+        if self.base.position_sizer.is_some() {
+            return Err(BacktestError::invalid_param(
+                "position_sizer",
+                "not supported for portfolio backtests; size entries with `rebalance`",
+            ));
+        }
+
         if let Some(cap) = self.max_allocation_per_symbol
             && !(0.0..=1.0).contains(&cap)
         {
@@ -212,4 +219,16 @@ mod tests {
         let config = PortfolioConfig::default().max_allocation_per_symbol(1.5);
         assert!(config.validate(1).is_err());
     }
+
+    #[test]
+    fn test_validation_rejects_position_sizer() {
+        use crate::backtesting::sizing::FixedAmount;
+
+        let base = BacktestConfig::builder()
+            .position_sizer(FixedAmount::new(1_000.0))
+            .build()
+            .unwrap();
+        let err = PortfolioConfig::new(base).validate(1).unwrap_err();
+        assert!(err.to_string().contains("position_sizer"), "got {err}");
+    }
 }
@@ -4,12 +4,15 @@ use std::cmp::Ordering;
 use std::collections::{BTreeSet, HashMap, HashSet};
 
 use crate::backtesting::config::BacktestConfig;
-use crate::backtesting::engine::{BacktestEngine, update_trailing_hwm};
+use crate::backtesting::engine::{BacktestEngine, check_scale_out_target, update_trailing_hwm};
 use crate::backtesting::error::{BacktestError, Result};
 use crate::backtesting::position::{Position, PositionSide, Trade};
-use crate::backtesting::result::{BacktestResult, EquityPoint, PerformanceMetrics, SignalRecord};
+use crate::backtesting::result::{
+    BacktestResult, EquityPoint, PerformanceMetrics, RunMetadata, SignalRecord,
+};
 use crate::backtesting::signal::{Signal, SignalDirection};
 use crate::backtesting::strategy::{Strategy, StrategyContext};
+use crate::models::chart::total_return::total_return_candles;
 use crate::models::chart::{Candle, Dividend};
 
 use super::config::PortfolioConfig;
@@ -97,9 +100,17 @@ impl PortfolioEngine {
                 return Err(BacktestError::insufficient_data(warmup, data.candles.len()));
             }
             let strategy_name = strategy.name().to_string();
-            let indicators = helper_engine.compute_indicators(&data.candles, &strategy)?;
-            let ts_index: HashMap<i64, usize> = data
-                .candles
+            // Total-return mode folds dividends into the prices instead of crediting them
+            let (candles, dividends) = if self.config.base.use_total_return {
+                (
+                    total_return_candles(&data.candles, &data.dividends),
+                    Vec::new(),
+                )
+            } else {
+                (data.candles.clone(), data.dividends.clone())
+            };
+            let indicators = helper_engine.compute_indicators(&candles, &strategy)?;
+            let ts_index: HashMap<i64, usize> = candles
                 .iter()
                 .enumerate()
                 .map(|(i, c)| (c.timestamp, i))
@@ -118,8 +129,8 @@ impl PortfolioEngine {
             states.insert(
                 data.symbol.clone(),
                 SymbolState {
-                    candles: data.candles.clone(),
-                    dividends: data.dividends.clone(),
+                    candles,
+                    dividends,
                     ts_index,
                     indicators,
                     strategy,
@@ -129,6 +140,7 @@ impl PortfolioEngine {
                     div_idx: 0,
                     trades: vec![],
                     signals: vec![],
+                    positions_opened: 0,
                     realized_pnl: 0.0,
                     equity_curve: vec![],
                     sym_peak: sym_initial_capital,
@@ -160,6 +172,37 @@ impl PortfolioEngine {
                 .cloned()
                 .collect();
 
+            // --- Step 0: Charge financing for holding through this bar ------
+            // Shorts pay the borrow fee on their own market value; margin
+            // interest on negative cash is split across longs by market value.
+            let margin_interest = self.config.base.calculate_margin_interest(-cash);
+            let long_value: f64 = states
+                .values()
+                .filter_map(|s| {
+                    let pos = s.position.as_ref().filter(|p| p.is_long())?;
+                    close_at_or_before(s, timestamp).map(|close| pos.quantity * close)
+                })
+                .sum();
+            for state in states.values_mut() {
+                let Some(close) = close_at_or_before(state, timestamp) else {
+                    continue;
+                };
+                let Some(pos) = state.position.as_mut() else {
+                    continue;
+                };
+                let cost = if pos.is_short() {
+                    self.config.base.calculate_borrow_fee(pos.quantity * close)
+                } else if long_value > 0.0 {
+                    margin_interest * pos.quantity * close / long_value
+                } else {
+                    0.0
+                };
+                if cost > 0.0 {
+                    cash -= cost;
+                    pos.accrue_financing(cost);
+                }
+            }
+
             // --- Step 1: Update position values, dividends, trailing stops ----
             let mut auto_exits: Vec<(String, Signal)> = Vec::new();
 
@@ -171,12 +214,17 @@ impl PortfolioEngine {
                 // Update HWM for trailing stop using the intrabar extreme so the
                 // trailing stop correctly reflects the best price reached during the bar.
                 update_trailing_hwm(state.position.as_ref(), &mut state.hwm, candle);
+                if let Some(pos) = state.position.as_mut() {
+                    pos.record_bar(candle.high, candle.low);
+                }
 
                 // Credit dividends ex-dated on or before this bar
                 while state.div_idx < state.dividends.len()
                     && state.dividends[state.div_idx].timestamp <= timestamp
                 {
-                    if let Some(ref mut pos) = state.position {
+                    if let Some(ref mut pos) = state.position
+                        && (pos.is_long() || self.config.base.short_pays_dividends)
+                    {
                         let per_share = state.dividends[state.div_idx].amount;
                         let income = if pos.is_long() {
                             per_share * pos.quantity
@@ -198,6 +246,64 @@ impl PortfolioEngine {
                         check_sl_tp(pos, candle, state.hwm, &self.config.base)
                 {
                     auto_exits.push((sym.clone(), exit_signal));
+                    continue;
+                }
+
+                // Scale out at configured profit targets. Partial exits fill
+                // here; a target that closes the remainder joins the auto-exits.
+                while let Some(ref pos) = state.position
+                    && let Some(target_signal) =
+                        check_scale_out_target(pos, candle, &self.config.base)
+                {
+                    let pos = state.position.as_mut().unwrap();
+                    pos.scale_out_targets_hit += 1;
+                    if target_signal.direction == SignalDirection::Exit {
+                        auto_exits.push((sym.clone(), target_signal));
+                        break;
+                    }
+
+                    let fraction = target_signal.scale_fraction.unwrap_or(0.0);
+                    let is_long = pos.is_long();
+                    let exit_price = self.config.base.apply_exit_spread(
+                        self.config
+                            .base
+                            .apply_exit_slippage(target_signal.price, is_long),
+                        is_long,
+                    );
+                    let qty_closed = pos.quantity * fraction;
+                    let commission = self
+                        .config
+                        .base
+                        .calculate_commission(qty_closed, exit_price);
+                    let exit_tax = self
+                        .config
+                        .base
+                        .calculate_transaction_tax(exit_price * qty_closed, !is_long);
+                    let trade = pos.partial_close(
+                        fraction,
+                        timestamp,
+                        exit_price,
+                        commission,
+                        exit_tax,
+                        target_signal.clone(),
+                    );
+                    if trade.is_long() {
+                        cash += trade.exit_value() - commission + trade.unreinvested_dividends;
+                    } else {
+                        cash -= trade.exit_value() + commission + exit_tax
+                            - trade.unreinvested_dividends;
+                    }
+                    state.realized_pnl += trade.pnl;
+                    state.trades.push(trade);
+                    state.signals.push(SignalRecord {
+                        timestamp,
+                        price: target_signal.price,
+                        direction: target_signal.direction,
+                        strength: 1.0,
+                        reason: target_signal.reason,
+                        executed: true,
+                        tags: target_signal.tags,
+                    });
                 }
             }
 
@@ -274,6 +380,7 @@ impl PortfolioEngine {
 
                 // Compute portfolio equity with an immutable borrow (no conflict now)
                 let portfolio_equity = compute_portfolio_equity(cash, &states, timestamp);
+                let gross_exposure = compute_gross_exposure(&states, timestamp);
 
                 // Re-acquire the mutable borrow for strategy evaluation and signal dispatch
                 let state = states.get_mut(sym).unwrap();
@@ -372,8 +479,11 @@ impl PortfolioEngine {
                     }
                     SignalDirection::ScaleIn => {
                         let fraction = signal.scale_fraction.unwrap_or(0.0).clamp(0.0, 1.0);
+                        let max_scale_ins = self.config.base.max_scale_ins;
                         let executed = fraction > 0.0
-                            && state.position.is_some()
+                            && state.position.as_ref().is_some_and(|p| {
+                                max_scale_ins.is_none_or(|max| p.scale_in_count < max)
+                            })
                             && state
                                 .candles
                                 .get(candle_idx + 1)
@@ -402,7 +512,12 @@ impl PortfolioEngine {
                                     } else {
                                         commission
                                     };
-                                    if add_qty <= 0.0 || total_cost > cash {
+                                    if add_qty <= 0.0
+                                        || total_cost
+                                            > self.config.base.buying_power(cash, portfolio_equity)
+                                        || gross_exposure + add_value
+                                            > portfolio_equity * self.config.base.max_leverage
+                                    {
                                         return false;
                                     }
                                     if is_long {
@@ -410,7 +525,13 @@ impl PortfolioEngine {
                                     } else {
                                         cash += add_value - commission;
                                     }
-                                    pos.scale_in(fill_price, add_qty, commission, entry_tax);
+                                    pos.scale_in_at(
+                                        fill_candle.timestamp,
+                                        fill_price,
+                                        add_qty,
+                                        commission,
+                                        entry_tax,
+                                    );
                                     true
                                 });
                         state.signals.push(SignalRecord {
@@ -572,9 +693,18 @@ impl PortfolioEngine {
                 }
 
                 let is_long = signal.direction == SignalDirection::Long;
+                // Leverage scales the allocation, capped by the exposure the
+                // portfolio can still add under `max_leverage`.
+                let max_leverage = self.config.base.max_leverage;
+                let portfolio_equity = compute_portfolio_equity(cash, &states, timestamp);
+                let leverage_headroom =
+                    portfolio_equity * max_leverage - compute_gross_exposure(&states, timestamp);
                 let target_capital =
-                    self.config
-                        .allocation_target(&sym, cash, initial_capital, n_symbols);
+                    (self
+                        .config
+                        .allocation_target(&sym, cash, initial_capital, n_symbols)
+                        * max_leverage)
+                        .min(leverage_headroom);
 
                 if target_capital <= 0.0 {
                     continue;
@@ -625,7 +755,7 @@ impl PortfolioEngine {
                 let entry_cost = entry_price * quantity + entry_comm + entry_tax;
 
                 if is_long {
-                    if entry_cost > cash {
+                    if entry_cost > self.config.base.buying_power(cash, portfolio_equity) {
                         continue;
                     }
                 } else if entry_comm > cash {
@@ -645,7 +775,7 @@ impl PortfolioEngine {
                 };
 
                 let state = states.get_mut(&sym).unwrap();
-                state.position = Some(Position::new_with_tax(
+                let mut position = Position::new_with_tax(
                     side,
                     fill_ts,
                     entry_price,
@@ -653,7 +783,10 @@ impl PortfolioEngine {
                     entry_comm,
                     entry_tax,
                     signal.clone(),
-                ));
+                );
+                position.position_id = state.positions_opened;
+                state.positions_opened += 1;
+                state.position = Some(position);
                 state.hwm = Some(entry_price);
                 state.signals.push(SignalRecord {
                     timestamp: signal.timestamp,
@@ -842,6 +975,7 @@ impl PortfolioEngine {
                     open_position: state.position,
                     benchmark: None,
                     diagnostics: vec![],
+                    run_metadata: RunMetadata::new(&self.config.base, &state.candles),
                 };
 
                 (sym, result)
@@ -899,6 +1033,8 @@ struct SymbolState<S: Strategy> {
     div_idx: usize,
     trades: Vec<Trade>,
     signals: Vec<SignalRecord>,
+    /// Number of positions opened so far; the next position's id.
+    positions_opened: usize,
     /// Cumulative realized P&L (net of commissions and dividends) from closed trades.
     realized_pnl: f64,
     /// Per-symbol equity curve: sym_initial_capital + realized_pnl + open position unrealized P&L.
@@ -932,6 +1068,21 @@ fn compute_portfolio_equity<S: Strategy>(
         .sum::<f64>()
 }
 
+/// Sum of the absolute market values of all open positions.
+fn compute_gross_exposure<S: Strategy>(
+    states: &HashMap<String, SymbolState<S>>,
+    timestamp: i64,
+) -> f64 {
+    states
+        .values()
+        .filter_map(|s| {
+            s.position
+                .as_ref()
+                .and_then(|pos| close_at_or_before(s, timestamp).map(|close| pos.quantity * close))
+        })
+        .sum()
+}
+
 fn close_at_or_before<S: Strategy>(state: &SymbolState<S>, timestamp: i64) -> Option<f64> {
     // Fast path: ts_index covers all candle timestamps.
     if let Some(&idx) = state.ts_index.get(&timestamp) {
@@ -984,11 +1135,10 @@ fn compute_portfolio_time_in_market(allocation_history: &[AllocationSnapshot]) -
 ///
 /// # Exit Priority
 ///
-/// When multiple conditions breach on the same bar the evaluation order is
-/// **stop-loss → take-profit → trailing stop**.  The intrabar sequence is
-/// unknowable from OHLCV bars alone, so stop-loss is given priority for
-/// conservative simulation.  Strategies with SL and TP both active should
-/// keep those levels well separated relative to typical bar ranges.
+/// When stop-loss and take-profit both breach on the same bar,
+/// [`BacktestConfig::intrabar_priority`] picks which one fills (stop-loss by
+/// default, since the intrabar sequence is unknowable from OHLCV bars alone).
+/// The trailing stop is only checked when neither level was hit.
 fn check_sl_tp(
     pos: &Position,
     candle: &Candle,
@@ -996,7 +1146,7 @@ fn check_sl_tp(
     config: &BacktestConfig,
 ) -> Option<Signal> {
     // Stop-loss
-    if let Some(sl_pct) = config.stop_loss_pct {
+    let stop_hit = config.stop_loss_pct.and_then(|sl_pct| {
         let stop_price = if pos.is_long() {
             pos.entry_price * (1.0 - sl_pct)
         } else {
@@ -1007,22 +1157,11 @@ fn check_sl_tp(
         } else {
             candle.high >= stop_price
         };
-        if triggered {
-            let fill_price = if pos.is_long() {
-                candle.open.min(stop_price)
-            } else {
-                candle.open.max(stop_price)
-            };
-            let return_pct = pos.unrealized_return_pct(fill_price);
-            return Some(
-                Signal::exit(candle.timestamp, fill_price)
-                    .with_reason(format!("Stop-loss triggered ({:.1}%)", return_pct)),
-            );
-        }
-    }
+        triggered.then_some(stop_price)
+    });
 
     // Take-profit
-    if let Some(tp_pct) = config.take_profit_pct {
+    let tp_hit = config.take_profit_pct.and_then(|tp_pct| {
         let tp_price = if pos.is_long() {
             pos.entry_price * (1.0 + tp_pct)
         } else {
@@ -1033,18 +1172,43 @@ fn check_sl_tp(
         } else {
             candle.low <= tp_price
         };
-        if triggered {
-            let fill_price = if pos.is_long() {
-                candle.open.max(tp_price)
-            } else {
-                candle.open.min(tp_price)
-            };
-            let return_pct = pos.unrealized_return_pct(fill_price);
-            return Some(
-                Signal::exit(candle.timestamp, fill_price)
-                    .with_reason(format!("Take-profit triggered ({:.1}%)", return_pct)),
-            );
+        triggered.then_some(tp_price)
+    });
+
+    let tp_first = match (stop_hit, tp_hit) {
+        (Some(stop_price), Some(tp_price)) => {
+            config
+                .intrabar_priority
+                .target_first(candle.open, stop_price, tp_price)
         }
+        (None, Some(_)) => true,
+        _ => false,
+    };
+
+    if let Some(tp_price) = tp_hit.filter(|_| tp_first) {
+        let fill_price = if pos.is_long() {
+            candle.open.max(tp_price)
+        } else {
+            candle.open.min(tp_price)
+        };
+        let return_pct = pos.unrealized_return_pct(fill_price);
+        return Some(
+            Signal::exit(candle.timestamp, fill_price)
+                .with_reason(format!("Take-profit triggered ({:.1}%)", return_pct)),
+        );
+    }
+
+    if let Some(stop_price) = stop_hit {
+        let fill_price = if pos.is_long() {
+            candle.open.min(stop_price)
+        } else {
+            candle.open.max(stop_price)
+        };
+        let return_pct = pos.unrealized_return_pct(fill_price);
+        return Some(
+            Signal::exit(candle.timestamp, fill_price)
+                .with_reason(format!("Stop-loss triggered ({:.1}%)", return_pct)),
+        );
     }
 
     // Trailing stop — `hwm` is already updated to the intrabar extreme before this call.
@@ -23,6 +23,45 @@ impl std::fmt::Display for PositionSide {
     }
 }
 
+/// What a [`Fill`] did to its position
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FillKind {
+    /// Initial entry that opened the position
+    Entry,
+    /// Pyramid add (scale in)
+    ScaleIn,
+    /// Partial exit (scale out)
+    ScaleOut,
+    /// Final exit that closed the position
+    Exit,
+}
+
+/// A single execution within a position's lifetime
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Fill {
+    /// What the fill did to the position
+    pub kind: FillKind,
+    /// Fill timestamp
+    pub timestamp: i64,
+    /// Fill price (after slippage and spread)
+    pub price: f64,
+    /// Number of shares/units filled
+    pub quantity: f64,
+}
+
+impl Fill {
+    fn new(kind: FillKind, timestamp: i64, price: f64, quantity: f64) -> Self {
+        Self {
+            kind,
+            timestamp,
+            price,
+            quantity,
+        }
+    }
+}
+
 /// An open position
 #[non_exhaustive]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -64,6 +103,18 @@ pub struct Position {
     #[serde(default)]
     pub unreinvested_dividends: f64,
 
+    /// Short borrow fees and margin interest accrued while this position was
+    /// open.
+    ///
+    /// Already debited from cash bar by bar; deducted from trade P&L on close.
+    /// See [`BacktestConfig::borrow_fee_rate`] and
+    /// [`BacktestConfig::margin_interest_rate`].
+    ///
+    /// [`BacktestConfig::borrow_fee_rate`]: crate::backtesting::BacktestConfig::borrow_fee_rate
+    /// [`BacktestConfig::margin_interest_rate`]: crate::backtesting::BacktestConfig::margin_interest_rate
+    #[serde(default)]
+    pub financing_cost: f64,
+
     /// Number of times this position has been scaled into (pyramid adds).
     ///
     /// Starts at `0` (initial entry). Incremented by
@@ -78,6 +129,39 @@ pub struct Position {
     #[serde(default)]
     pub partial_close_count: usize,
 
+    /// Number of [`BacktestConfig::scale_out_targets`] already taken.
+    ///
+    /// Targets fire in order, each at most once per position.
+    ///
+    /// [`BacktestConfig::scale_out_targets`]: crate::backtesting::BacktestConfig::scale_out_targets
+    #[serde(default)]
+    pub scale_out_targets_hit: usize,
+
+    /// Sequence number of this position within its backtest.
+    ///
+    /// Copied to every [`Trade`] the position produces, so partial closes can
+    /// be grouped with the final close.
+    #[serde(default)]
+    pub position_id: usize,
+
+    /// Entry, scale-in, and scale-out fills so far, oldest first.
+    #[serde(default)]
+    pub fills: Vec<Fill>,
+
+    /// Highest price seen since entry: the entry fill, then each later bar's
+    /// high. Updated by [`Position::record_bar`].
+    #[serde(default)]
+    pub highest_price: f64,
+
+    /// Lowest price seen since entry: the entry fill, then each later bar's
+    /// low. Updated by [`Position::record_bar`].
+    #[serde(default)]
+    pub lowest_price: f64,
+
+    /// Bars recorded since the entry bar, including the current one.
+    #[serde(default)]
+    pub bars_held: usize,
+
     /// Per-trade stop-loss percentage override.
     ///
     /// Populated from [`Signal::bracket_stop_loss_pct`] when the position is
@@ -157,8 +241,20 @@ impl Position {
             entry_signal,
             dividend_income: 0.0,
             unreinvested_dividends: 0.0,
+            financing_cost: 0.0,
             scale_in_count: 0,
             partial_close_count: 0,
+            scale_out_targets_hit: 0,
+            position_id: 0,
+            fills: vec![Fill::new(
+                FillKind::Entry,
+                entry_timestamp,
+                entry_price,
+                quantity,
+            )],
+            highest_price: entry_price,
+            lowest_price: entry_price,
+            bars_held: 0,
             bracket_stop_loss_pct,
             bracket_take_profit_pct,
             bracket_trailing_stop_pct,
@@ -201,6 +297,7 @@ impl Position {
             }
         };
         gross_pnl - self.entry_commission - self.entry_transaction_tax + self.unreinvested_dividends
+            - self.financing_cost
     }
 
     /// Calculate unrealized return percentage
@@ -251,21 +348,80 @@ impl Position {
         self.dividend_income += income;
     }
 
+    /// Record one period of financing cost (short borrow fee or margin
+    /// interest) charged against this position.
+    ///
+    /// The caller is responsible for debiting the same amount from cash.
+    pub fn accrue_financing(&mut self, cost: f64) {
+        self.financing_cost += cost;
+    }
+
+    /// Record a bar the position was held through, tracking its price
+    /// extremes for [`Trade::mae_pct`] / [`Trade::mfe_pct`].
+    ///
+    /// The engine calls this once per bar after the entry bar, before exit
+    /// checks, so the exit bar is included.
+    pub fn record_bar(&mut self, high: f64, low: f64) {
+        self.highest_price = self.highest_price.max(high);
+        self.lowest_price = self.lowest_price.min(low);
+        self.bars_held += 1;
+    }
+
+    /// Maximum adverse and favorable excursions (in that order) as
+    /// percentages of the entry price, including a fill at `exit_price`.
+    fn excursions(&self, exit_price: f64) -> (f64, f64) {
+        if self.entry_price <= 0.0 {
+            return (0.0, 0.0);
+        }
+        let high = self.highest_price.max(exit_price);
+        let low = self.lowest_price.min(exit_price);
+        let (adverse, favorable) = match self.side {
+            PositionSide::Long => (self.entry_price - low, high - self.entry_price),
+            PositionSide::Short => (high - self.entry_price, self.entry_price - low),
+        };
+        let pct = |move_: f64| (move_.max(0.0) / self.entry_price) * 100.0;
+        (pct(adverse), pct(favorable))
+    }
+
     /// Add shares to this position (pyramid / scale-in).
     ///
+    /// Same as [`Position::scale_in_at`], but records the add in
+    /// [`Position::fills`] at the position's entry timestamp. Prefer
+    /// `scale_in_at` when the fill time is known.
+    pub fn scale_in(
+        &mut self,
+        fill_price: f64,
+        additional_qty: f64,
+        commission: f64,
+        entry_tax: f64,
+    ) {
+        self.scale_in_at(
+            self.entry_timestamp,
+            fill_price,
+            additional_qty,
+            commission,
+            entry_tax,
+        );
+    }
+
+    /// Add shares to this position (pyramid / scale-in) at `timestamp`.
+    ///
     /// Updates the weighted-average `entry_price` and `entry_quantity` to reflect
-    /// the blended cost basis and increments `scale_in_count`. The caller is
-    /// responsible for debiting the entry cost from available cash and for applying
-    /// slippage/spread to `fill_price` before calling this method.
+    /// the blended cost basis, increments `scale_in_count`, and records a
+    /// [`FillKind::ScaleIn`] fill. The caller is responsible for debiting the
+    /// entry cost from available cash and for applying slippage/spread to
+    /// `fill_price` before calling this method.
     ///
     /// # Arguments
     ///
+    /// * `timestamp`       – Timestamp of the fill.
     /// * `fill_price`      – Adjusted entry price for the new shares.
     /// * `additional_qty`  – Number of shares to add. No-op if `<= 0.0`.
     /// * `commission`      – Commission paid for this add (already applied to cash).
     /// * `entry_tax`       – Transaction tax for this add (already applied to cash).
-    pub fn scale_in(
+    pub fn scale_in_at(
         &mut self,
+        timestamp: i64,
         fill_price: f64,
         additional_qty: f64,
         commission: f64,
@@ -288,6 +444,12 @@ impl Position {
         self.entry_commission += commission;
         self.entry_transaction_tax += entry_tax;
         self.scale_in_count += 1;
+        self.fills.push(Fill::new(
+            FillKind::ScaleIn,
+            timestamp,
+            fill_price,
+            additional_qty,
+        ));
     }
 
     /// Partially close this position and return a completed [`Trade`].
@@ -332,6 +494,7 @@ impl Position {
         let unreinvested = self.unreinvested_dividends * fraction;
         let entry_comm_slice = self.entry_commission * fraction;
         let entry_tax_slice = self.entry_transaction_tax * fraction;
+        let financing_slice = self.financing_cost * fraction;
 
         // Reduce the open position; keep entry_quantity in sync with quantity so
         // close_with_tax computes the correct cost basis for the remainder.
@@ -341,6 +504,7 @@ impl Position {
         self.unreinvested_dividends -= unreinvested;
         self.entry_commission -= entry_comm_slice;
         self.entry_transaction_tax -= entry_tax_slice;
+        self.financing_cost -= financing_slice;
 
         let gross_pnl = match self.side {
             PositionSide::Long => (exit_price - self.entry_price) * qty_closed,
@@ -349,7 +513,7 @@ impl Position {
         let partial_commission = entry_comm_slice + commission;
         let partial_tax = entry_tax_slice + exit_tax;
 
-        let pnl = gross_pnl - partial_commission - partial_tax + unreinvested;
+        let pnl = gross_pnl - partial_commission - partial_tax + unreinvested - financing_slice;
         let entry_value = self.entry_price * qty_closed;
         let return_pct = if entry_value > 0.0 {
             (pnl / entry_value) * 100.0
@@ -357,8 +521,11 @@ impl Position {
             0.0
         };
 
+        let (mae_pct, mfe_pct) = self.excursions(exit_price);
         let seq = self.partial_close_count;
         self.partial_close_count += 1;
+        let fill = Fill::new(FillKind::ScaleOut, exit_ts, exit_price, qty_closed);
+        self.fills.push(fill);
 
         Trade {
             side: self.side,
@@ -374,11 +541,17 @@ impl Position {
             return_pct,
             dividend_income: div_income,
             unreinvested_dividends: unreinvested,
+            financing_cost: financing_slice,
             entry_signal: self.entry_signal.clone(),
             exit_signal: signal,
             tags: self.entry_signal.tags.clone(),
             is_partial: true,
             scale_sequence: seq,
+            position_id: self.position_id,
+            fills: vec![fill],
+            mae_pct,
+            mfe_pct,
+            bars_held: self.bars_held,
         }
     }
 
@@ -421,8 +594,9 @@ impl Position {
             PositionSide::Long => exit_value - initial_value,
             PositionSide::Short => initial_value - exit_value,
         };
-        let pnl =
-            gross_pnl - total_commission - total_transaction_tax + self.unreinvested_dividends;
+        let pnl = gross_pnl - total_commission - total_transaction_tax
+            + self.unreinvested_dividends
+            - self.financing_cost;
 
         let entry_value = self.entry_price * self.entry_quantity;
         let return_pct = if entry_value > 0.0 {
@@ -431,6 +605,15 @@ impl Position {
             0.0
         };
 
+        let (mae_pct, mfe_pct) = self.excursions(exit_price);
+        let mut fills = self.fills;
+        fills.push(Fill::new(
+            FillKind::Exit,
+            exit_timestamp,
+            exit_price,
+            self.quantity,
+        ));
+
         Trade {
             side: self.side,
             entry_timestamp: self.entry_timestamp,
@@ -445,11 +628,17 @@ impl Position {
             return_pct,
             dividend_income: self.dividend_income,
             unreinvested_dividends: self.unreinvested_dividends,
+            financing_cost: self.financing_cost,
             tags: self.entry_signal.tags.clone(),
             entry_signal: self.entry_signal,
             exit_signal,
             is_partial: false,
             scale_sequence: 0,
+            position_id: self.position_id,
+            fills,
+            mae_pct,
+            mfe_pct,
+            bars_held: self.bars_held,
         }
     }
 }
@@ -492,7 +681,8 @@ pub struct Trade {
     #[serde(default)]
     pub transaction_tax: f64,
 
-    /// Realized P&L (after commission and transaction tax, including any unreinvested dividend income)
+    /// Realized P&L (after commission, transaction tax and financing cost,
+    /// including any unreinvested dividend income)
     pub pnl: f64,
 
     /// Return as percentage
@@ -506,6 +696,13 @@ pub struct Trade {
     #[serde(default)]
     pub unreinvested_dividends: f64,
 
+    /// Short borrow fees and margin interest charged while the position was
+    /// open (the closed slice's share, for partial closes).
+    ///
+    /// Deducted from P&L along with commission.
+    #[serde(default)]
+    pub financing_cost: f64,
+
     /// Signal that triggered entry
     pub entry_signal: Signal,
 
@@ -536,6 +733,37 @@ pub struct Trade {
     /// `1`, etc. Always `0` for non-partial trades.
     #[serde(default)]
     pub scale_sequence: usize,
+
+    /// [`Position::position_id`] of the position this trade closed.
+    ///
+    /// A position's partial closes (children) and its final close (parent)
+    /// share the same id; see `BacktestResult::trades_by_position`.
+    #[serde(default)]
+    pub position_id: usize,
+
+    /// Fills belonging to this trade, oldest first.
+    ///
+    /// A partial close carries only its own [`FillKind::ScaleOut`] fill. The
+    /// final close carries the position's full history: the entry, every
+    /// scale-in and scale-out, and the exit.
+    #[serde(default)]
+    pub fills: Vec<Fill>,
+
+    /// Maximum adverse excursion: the furthest price moved against the trade
+    /// while open, as a percentage of [`entry_price`](Self::entry_price).
+    /// Always `>= 0`; costs are not included.
+    #[serde(default)]
+    pub mae_pct: f64,
+
+    /// Maximum favorable excursion: the furthest price moved in the trade's
+    /// favor while open, as a percentage of [`entry_price`](Self::entry_price).
+    /// Always `>= 0`; costs are not included.
+    #[serde(default)]
+    pub mfe_pct: f64,
+
+    /// Bars the position was held after its entry bar, including the exit bar.
+    #[serde(default)]
+    pub bars_held: usize,
 }
 
 impl Trade {
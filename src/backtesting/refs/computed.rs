@@ -11,13 +11,15 @@
 //! - **Volatility**: ATR, Bollinger Bands, Keltner Channels, Donchian Channels
 //! - **Volume**: OBV, VWAP, MFI, CMF, Chaikin Oscillator, A/D, Balance of Power
 //! - **Momentum**: Momentum, ROC, Coppock Curve, Bull/Bear Power, Elder Ray
+//! - **Custom**: indicators added with `indicators::register_indicator`
 
 // Allow missing docs on struct fields in this file - users interact via
 // fluent API functions (sma(), rsi(), etc.) rather than these internal types.
 #![allow(missing_docs)]
 
+use crate::backtesting::error::Result;
 use crate::backtesting::strategy::StrategyContext;
-use crate::indicators::Indicator;
+use crate::indicators::{CustomIndicatorRef, Indicator};
 
 use super::IndicatorRef;
 
@@ -2733,9 +2735,72 @@ impl IndicatorRef for ElderBearPowerRef {
     }
 }
 
+// ============================================================================
+// CUSTOM INDICATORS
+// ============================================================================
+
+/// Reference to an indicator from the custom indicator registry.
+#[derive(Debug, Clone)]
+pub struct CustomRef {
+    pub indicator: CustomIndicatorRef,
+    key: String,
+}
+
+impl IndicatorRef for CustomRef {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    fn required_indicators(&self) -> Vec<(String, Indicator)> {
+        vec![(self.key.clone(), Indicator::Custom(self.indicator))]
+    }
+
+    fn value(&self, ctx: &StrategyContext) -> Option<f64> {
+        ctx.indicator(self.key())
+    }
+
+    fn prev_value(&self, ctx: &StrategyContext) -> Option<f64> {
+        ctx.indicator_prev(self.key())
+    }
+}
+
+impl From<CustomIndicatorRef> for CustomRef {
+    fn from(indicator: CustomIndicatorRef) -> Self {
+        CustomRef {
+            key: format!("custom_{}", indicator.key()),
+            indicator,
+        }
+    }
+}
+
+/// Create a reference to a custom indicator registered with
+/// [`register_indicator`](crate::indicators::register_indicator).
+///
+/// Empty `params` selects the registered defaults. The indicator only has to
+/// be registered by the time the backtest runs.
+///
+/// # Errors
+///
+/// Returns [`BacktestError::IndicatorError`](crate::backtesting::BacktestError::IndicatorError)
+/// if more than [`MAX_CUSTOM_PARAMS`](crate::indicators::MAX_CUSTOM_PARAMS)
+/// parameters are given.
+///
+/// # Example
+///
+/// ```ignore
+/// use finance_query::backtesting::refs::*;
+///
+/// let entry = custom("pct_off_high", &[50.0])?.above(-5.0);
+/// ```
+#[inline]
+pub fn custom(name: &'static str, params: &[f64]) -> Result<CustomRef> {
+    Ok(CustomIndicatorRef::new(name, params)?.into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::backtesting::BacktestError;
 
     #[test]
     fn test_moving_average_keys() {
@@ -2860,6 +2925,15 @@ mod tests {
         assert_eq!(parabolic_sar(0.02, 0.2).key(), "psar_0.02_0.2");
         assert_eq!(true_range().key(), "true_range");
         assert_eq!(coppock_curve(10, 14, 11).key(), "coppock_10_14_11");
+        assert_eq!(
+            custom("my_ind", &[14.0, 2.5]).unwrap().key(),
+            "custom_my_ind_14_2.5"
+        );
+        assert_eq!(custom("my_ind", &[]).unwrap().key(), "custom_my_ind");
+        assert!(matches!(
+            custom("my_ind", &[1.0; 7]),
+            Err(BacktestError::IndicatorError(_))
+        ));
     }
 
     #[test]
@@ -61,7 +61,7 @@
 
 use std::collections::HashMap;
 
-use crate::backtesting::condition::{Condition, HtfIndicatorSpec};
+use crate::backtesting::condition::{Condition, HtfIndicatorSpec, htf_key};
 use crate::backtesting::engine::compute_for_candles;
 use crate::backtesting::resample::resample;
 use crate::backtesting::strategy::StrategyContext;
@@ -92,13 +92,12 @@ impl<C: Condition> Condition for HtfCondition<C> {
         // condition's crossover helpers (indicator_prev / crossed_above etc.) work
         // correctly, then evaluate with index=1.
         if !required.is_empty() {
-            let interval_str = self.interval.as_str();
             let mut mini_indicators: HashMap<String, Vec<Option<f64>>> =
                 HashMap::with_capacity(required.len());
             let mut all_found = true;
 
             for (base_key, _) in &required {
-                let htf_key = format!("htf_{}_{}", interval_str, base_key);
+                let htf_key = htf_key(self.interval, base_key);
                 if let Some(stretched) = ctx.indicators.get(&htf_key) {
                     let curr = stretched.get(ctx.index).copied().flatten();
                     let prev = ctx
@@ -152,13 +151,12 @@ impl<C: Condition> Condition for HtfCondition<C> {
     }
 
     fn htf_requirements(&self) -> Vec<HtfIndicatorSpec> {
-        let interval_str = self.interval.as_str();
         self.inner
             .required_indicators()
             .into_iter()
             .map(|(base_key, indicator)| HtfIndicatorSpec {
                 interval: self.interval,
-                htf_key: format!("htf_{}_{}", interval_str, base_key),
+                htf_key: htf_key(self.interval, &base_key),
                 base_key,
                 indicator,
                 utc_offset_secs: self.utc_offset_secs,
@@ -3,7 +3,7 @@
 //! This module provides references to OHLCV price data that can be used
 //! in trading conditions without requiring indicator computation.
 
-use crate::indicators::Indicator;
+use crate::indicators::{Indicator, PatternSentiment, pattern_at};
 
 use super::IndicatorRef;
 use crate::backtesting::strategy::StrategyContext;
@@ -627,6 +627,93 @@ pub fn relative_volume(period: usize) -> RelativeVolume {
     }
 }
 
+/// Reference to the number of candlestick patterns of one sentiment in the
+/// last N bars (including the current bar).
+///
+/// Patterns are detected with [`indicators::patterns`](crate::indicators::patterns),
+/// which only looks backwards, so there is no lookahead.
+///
+/// # Example
+///
+/// ```ignore
+/// use finance_query::backtesting::refs::*;
+/// use finance_query::indicators::PatternSentiment;
+///
+/// // At least two bullish patterns in the last 5 bars
+/// let confirmed = pattern_count(PatternSentiment::Bullish, 5).above(1.5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PatternCount {
+    /// Sentiment of the patterns to count
+    pub sentiment: PatternSentiment,
+    /// Number of bars to look back over
+    pub window: usize,
+    key: String,
+}
+
+impl PatternCount {
+    fn count_at(&self, candles: &[crate::Candle], idx: usize) -> Option<f64> {
+        if self.window == 0 || idx + 1 < self.window || idx >= candles.len() {
+            return None;
+        }
+        let count = (idx + 1 - self.window..=idx)
+            .filter(|&i| pattern_at(candles, i).is_some_and(|p| p.sentiment() == self.sentiment))
+            .count();
+        Some(count as f64)
+    }
+}
+
+impl IndicatorRef for PatternCount {
+    fn key(&self) -> &str {
+        &self.key
+    }
+
+    fn required_indicators(&self) -> Vec<(String, Indicator)> {
+        vec![] // We compute from candle history directly
+    }
+
+    fn value(&self, ctx: &StrategyContext) -> Option<f64> {
+        self.count_at(ctx.candles, ctx.index)
+    }
+
+    fn prev_value(&self, ctx: &StrategyContext) -> Option<f64> {
+        ctx.index
+            .checked_sub(1)
+            .and_then(|idx| self.count_at(ctx.candles, idx))
+    }
+}
+
+/// Get a reference to the number of `sentiment` candlestick patterns in the
+/// last `window` bars.
+///
+/// # Arguments
+///
+/// * `sentiment` - Which patterns to count (bullish, bearish, or neutral)
+/// * `window` - Number of bars to look back over, including the current bar
+///
+/// # Example
+///
+/// ```ignore
+/// use finance_query::backtesting::refs::*;
+/// use finance_query::indicators::PatternSentiment;
+///
+/// // No bearish patterns in the last 3 bars
+/// let clear = pattern_count(PatternSentiment::Bearish, 3).below(0.5);
+/// ```
+#[inline]
+pub fn pattern_count(sentiment: PatternSentiment, window: usize) -> PatternCount {
+    let label = match sentiment {
+        PatternSentiment::Bullish => "bullish",
+        PatternSentiment::Bearish => "bearish",
+        PatternSentiment::Neutral => "neutral",
+    };
+    PatternCount {
+        sentiment,
+        window,
+        key: format!("pattern_count_{label}_{window}"),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -677,4 +764,41 @@ mod tests {
         assert_eq!(relative_volume(20).key(), "relative_volume_20");
         assert_eq!(relative_volume(10).key(), "relative_volume_10");
     }
+
+    #[test]
+    fn test_pattern_count_key() {
+        assert_eq!(
+            pattern_count(PatternSentiment::Bullish, 5).key(),
+            "pattern_count_bullish_5"
+        );
+        assert_eq!(
+            pattern_count(PatternSentiment::Bearish, 3).key(),
+            "pattern_count_bearish_3"
+        );
+        assert!(
+            pattern_count(PatternSentiment::Neutral, 3)
+                .required_indicators()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_pattern_count_value() {
+        let doji = crate::Candle {
+            open: 10.0,
+            high: 12.0,
+            low: 8.0,
+            close: 10.1,
+            ..Default::default()
+        };
+        let candles = vec![doji.clone(), doji.clone(), doji];
+        let r = pattern_count(PatternSentiment::Neutral, 2);
+        assert_eq!(r.count_at(&candles, 0), None);
+        assert_eq!(r.count_at(&candles, 1), Some(2.0));
+        assert_eq!(r.count_at(&candles, 2), Some(2.0));
+        assert_eq!(
+            pattern_count(PatternSentiment::Bullish, 2).count_at(&candles, 2),
+            Some(0.0)
+        );
+    }
 }
@@ -167,6 +167,10 @@ pub struct PerformanceMetrics {
     /// Total dividend income received across all trades
     pub total_dividend_income: f64,
 
+    /// Total short borrow fees and margin interest paid across all trades
+    #[serde(default)]
+    pub total_financing_cost: f64,
+
     /// Kelly Criterion: optimal fraction of capital to risk per trade.
     ///
     /// Computed as `W - (1 - W) / R` where `W` is win rate and `R` is
@@ -251,6 +255,19 @@ pub struct PerformanceMetrics {
     /// penalising prolonged drawdowns more heavily than short-term volatility.
     /// Returns `f64::MAX` when Ulcer Index is zero and excess return is positive.
     pub serenity_ratio: f64,
+
+    /// Average [`Trade::mae_pct`]: how far, in percent of entry price, trades
+    /// typically moved against the position before closing.
+    pub avg_mae_pct: f64,
+
+    /// Average [`Trade::mfe_pct`]: how far, in percent of entry price, trades
+    /// typically moved in the position's favor before closing. Compare with
+    /// [`avg_win_pct`](Self::avg_win_pct) to see how much open profit exits
+    /// give back.
+    pub avg_mfe_pct: f64,
+
+    /// Average [`Trade::bars_held`].
+    pub avg_bars_held: f64,
 }
 
 impl PerformanceMetrics {
@@ -307,6 +324,7 @@ impl PerformanceMetrics {
             time_in_market_pct: 0.0,
             max_idle_period: 0,
             total_dividend_income: 0.0,
+            total_financing_cost: 0.0,
             kelly_criterion: 0.0,
             sqn: 0.0,
             expectancy: 0.0,
@@ -315,6 +333,9 @@ impl PerformanceMetrics {
             recovery_factor: 0.0,
             ulcer_index: 0.0,
             serenity_ratio: 0.0,
+            avg_mae_pct: 0.0,
+            avg_mfe_pct: 0.0,
+            avg_bars_held: 0.0,
         }
     }
 
@@ -372,6 +393,9 @@ impl PerformanceMetrics {
         };
 
         let avg_trade_duration = stats.total_duration as f64 / total_trades as f64;
+        let avg_mae_pct = stats.total_mae_pct / total_trades as f64;
+        let avg_mfe_pct = stats.total_mfe_pct / total_trades as f64;
+        let avg_bars_held = stats.total_bars_held as f64 / total_trades as f64;
 
         // Consecutive wins/losses
         let (max_consecutive_wins, max_consecutive_losses) = calculate_consecutive(trades);
@@ -502,6 +526,7 @@ impl PerformanceMetrics {
             time_in_market_pct,
             max_idle_period,
             total_dividend_income: stats.total_dividend_income,
+            total_financing_cost: stats.total_financing_cost,
             kelly_criterion,
             sqn,
             expectancy,
@@ -510,6 +535,9 @@ impl PerformanceMetrics {
             recovery_factor,
             ulcer_index,
             serenity_ratio,
+            avg_mae_pct,
+            avg_mfe_pct,
+            avg_bars_held,
         }
     }
 }
@@ -528,6 +556,10 @@ struct TradeStats {
     largest_loss: f64,
     total_commission: f64,
     total_dividend_income: f64,
+    total_financing_cost: f64,
+    total_mae_pct: f64,
+    total_mfe_pct: f64,
+    total_bars_held: usize,
     winning_returns: Vec<f64>,
     losing_returns: Vec<f64>,
     /// All trade return percentages (wins + losses + break-even).
@@ -549,6 +581,10 @@ fn analyze_trades(trades: &[Trade]) -> TradeStats {
         largest_loss: 0.0,
         total_commission: 0.0,
         total_dividend_income: 0.0,
+        total_financing_cost: 0.0,
+        total_mae_pct: 0.0,
+        total_mfe_pct: 0.0,
+        total_bars_held: 0,
         winning_returns: Vec::new(),
         losing_returns: Vec::new(),
         all_returns: Vec::new(),
@@ -575,6 +611,10 @@ fn analyze_trades(trades: &[Trade]) -> TradeStats {
         stats.total_duration += t.duration_secs();
         stats.total_commission += t.commission;
         stats.total_dividend_income += t.dividend_income;
+        stats.total_financing_cost += t.financing_cost;
+        stats.total_mae_pct += t.mae_pct;
+        stats.total_mfe_pct += t.mfe_pct;
+        stats.total_bars_held += t.bars_held;
         stats.all_returns.push(t.return_pct);
     }
 
@@ -926,7 +966,8 @@ fn datetime_from_timestamp(ts: i64) -> Option<NaiveDateTime> {
 
 /// Comparison of strategy performance against a benchmark.
 ///
-/// Populated when a benchmark symbol is supplied to `backtest_with_benchmark`.
+/// Populated when a benchmark is set via [`BacktestConfig::benchmark`] or
+/// supplied to `backtest_with_benchmark`.
 #[non_exhaustive]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BenchmarkMetrics {
@@ -963,6 +1004,91 @@ pub struct BenchmarkMetrics {
 
     /// Information ratio: excess return per unit of tracking error (annualised)
     pub information_ratio: f64,
+
+    /// Buy-and-hold equity of the benchmark, starting from the strategy's
+    /// initial capital and sampled at each strategy equity-curve timestamp
+    /// (using the latest benchmark close at or before it), so the two curves
+    /// can be plotted point for point.
+    #[serde(default)]
+    pub equity_curve: Vec<EquityPoint>,
+
+    /// Maximum drawdown of the benchmark curve as a **fraction** (0.0–1.0).
+    #[serde(default)]
+    pub max_drawdown_pct: f64,
+
+    /// Maximum drawdown of the strategy relative to the benchmark, as a
+    /// **fraction** (0.0–1.0).
+    ///
+    /// Measured on the ratio of strategy equity to benchmark equity: the
+    /// largest peak-to-trough stretch of underperformance. `0.0` means the
+    /// strategy never lost ground to the benchmark.
+    #[serde(default)]
+    pub relative_drawdown_pct: f64,
+}
+
+/// Provenance of a backtest run, for reproducing and comparing results.
+///
+/// Two runs with the same `crate_version`, `config_hash`, data range, and
+/// `candle_count` on the same strategy produce identical results.
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunMetadata {
+    /// Version of `finance-query` that produced the result
+    pub crate_version: String,
+
+    /// Stable hex fingerprint (FNV-1a, 64-bit) of the [`BacktestConfig`].
+    ///
+    /// Covers every serialized field except [`BacktestConfig::seed`], which is
+    /// reported separately. A custom `commission_fn` cannot be hashed; only
+    /// its presence is.
+    pub config_hash: String,
+
+    /// Timestamp of the first candle fed to the engine
+    pub data_start: i64,
+
+    /// Timestamp of the last candle fed to the engine
+    pub data_end: i64,
+
+    /// Number of candles fed to the engine (including warmup bars)
+    pub candle_count: usize,
+
+    /// RNG seed for stochastic analysis (see [`BacktestConfig::seed`])
+    pub seed: Option<u64>,
+}
+
+impl RunMetadata {
+    pub(crate) fn new(config: &BacktestConfig, candles: &[crate::models::chart::Candle]) -> Self {
+        Self {
+            crate_version: env!("CARGO_PKG_VERSION").to_string(),
+            config_hash: config_hash(config),
+            data_start: candles.first().map(|c| c.timestamp).unwrap_or(0),
+            data_end: candles.last().map(|c| c.timestamp).unwrap_or(0),
+            candle_count: candles.len(),
+            seed: config.seed,
+        }
+    }
+}
+
+/// FNV-1a over the JSON form of `config`, which is stable across machines and
+/// toolchains (unlike `std`'s `DefaultHasher`).
+fn config_hash(config: &BacktestConfig) -> String {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let unseeded = BacktestConfig {
+        seed: None,
+        ..config.clone()
+    };
+    let mut bytes = serde_json::to_vec(&unseeded).unwrap_or_default();
+    bytes.push(u8::from(config.commission_fn.is_some()));
+    if let Some(sizer) = &config.position_sizer {
+        bytes.extend(format!("{sizer:?}").bytes());
+    }
+
+    let hash = bytes
+        .iter()
+        .fold(OFFSET, |h, b| (h ^ u64::from(*b)).wrapping_mul(PRIME));
+    format!("{hash:016x}")
 }
 
 /// Complete backtest result
@@ -1014,6 +1140,10 @@ pub struct BacktestResult {
     /// hints when the engine detects likely misconfiguration.
     #[serde(default)]
     pub diagnostics: Vec<String>,
+
+    /// Version, config fingerprint, data range, and seed of this run
+    #[serde(default)]
+    pub run_metadata: RunMetadata,
 }
 
 impl BacktestResult {
@@ -1023,8 +1153,9 @@ impl BacktestResult {
             "Backtest: {} on {}\n\
              Period: {} bars\n\
              Initial: ${:.2} -> Final: ${:.2}\n\
-             Return: {:.2}% | Sharpe: {:.2} | Max DD: {:.2}%\n\
-             Trades: {} | Win Rate: {:.1}% | Profit Factor: {:.2}",
+             Return: {:.2}% | Sharpe: {:.2} | Sortino: {:.2} | Calmar: {:.2} | Max DD: {:.2}%\n\
+             Trades: {} | Win Rate: {:.1}% | Profit Factor: {:.2} | Max Consecutive Losses: {}\n\
+             Avg MAE: {:.2}% | Avg MFE: {:.2}% | Avg Bars Held: {:.1} | Exposure: {:.1}%",
             self.strategy_name,
             self.symbol,
             self.equity_curve.len(),
@@ -1032,13 +1163,60 @@ impl BacktestResult {
             self.final_equity,
             self.metrics.total_return_pct,
             self.metrics.sharpe_ratio,
+            self.metrics.sortino_ratio,
+            self.metrics.calmar_ratio,
             self.metrics.max_drawdown_pct * 100.0,
             self.metrics.total_trades,
             self.metrics.win_rate * 100.0,
             self.metrics.profit_factor,
+            self.metrics.max_consecutive_losses,
+            self.metrics.avg_mae_pct,
+            self.metrics.avg_mfe_pct,
+            self.metrics.avg_bars_held,
+            self.metrics.time_in_market_pct * 100.0,
         )
     }
 
+    /// Trade log as CSV, one row per [`Trade`] in execution order.
+    ///
+    /// Columns: `position_id`, `side`, `entry_timestamp`, `exit_timestamp`
+    /// (Unix seconds), `entry_price`, `exit_price`, `quantity`, `pnl`,
+    /// `return_pct`, `mae_pct`, `mfe_pct`, `bars_held`, `duration_secs`,
+    /// `is_partial`, and `tags` (joined with `;`).
+    pub fn trades_csv(&self) -> String {
+        let mut csv = String::from(
+            "position_id,side,entry_timestamp,exit_timestamp,entry_price,exit_price,quantity,\
+             pnl,return_pct,mae_pct,mfe_pct,bars_held,duration_secs,is_partial,tags\n",
+        );
+        for t in &self.trades {
+            let tags = t.tags.join(";");
+            let tags = if tags.contains([',', '"']) {
+                format!("\"{}\"", tags.replace('"', "\"\""))
+            } else {
+                tags
+            };
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{:.2},{:.4},{:.4},{:.4},{},{},{},{}\n",
+                t.position_id,
+                if t.is_long() { "long" } else { "short" },
+                t.entry_timestamp,
+                t.exit_timestamp,
+                t.entry_price,
+                t.exit_price,
+                t.quantity,
+                t.pnl,
+                t.return_pct,
+                t.mae_pct,
+                t.mfe_pct,
+                t.bars_held,
+                t.duration_secs(),
+                t.is_partial,
+                tags,
+            ));
+        }
+        csv
+    }
+
     /// Check if the backtest was profitable
     pub fn is_profitable(&self) -> bool {
         self.final_equity > self.initial_capital
@@ -1054,6 +1232,30 @@ impl BacktestResult {
         self.equity_curve.len()
     }
 
+    /// Total dividends received by long positions, including any position
+    /// still open at the end of the backtest.
+    ///
+    /// Only non-zero when dividends were supplied to the engine (e.g. via
+    /// `run_with_dividends` or `Ticker::backtest`).
+    pub fn dividends_received(&self) -> f64 {
+        self.dividend_flows().filter(|&d| d > 0.0).sum()
+    }
+
+    /// Total dividends paid by short positions, as a positive amount.
+    ///
+    /// Zero when [`BacktestConfig::short_pays_dividends`] is `false`.
+    pub fn dividends_paid(&self) -> f64 {
+        -self.dividend_flows().filter(|&d| d < 0.0).sum::<f64>()
+    }
+
+    /// Signed dividend cash flow of every trade and the open position.
+    fn dividend_flows(&self) -> impl Iterator<Item = f64> + '_ {
+        self.trades
+            .iter()
+            .map(|t| t.dividend_income)
+            .chain(self.open_position.iter().map(|p| p.dividend_income))
+    }
+
     // ─── Phase 2 — Rolling & Temporal Analysis ───────────────────────────────
 
     /// Rolling Sharpe ratio over a sliding window of equity-curve bars.
@@ -1405,6 +1607,23 @@ impl BacktestResult {
         partial_period_adjust(metrics, equity_curve.len(), bpy)
     }
 
+    /// Group trades by the position that produced them.
+    ///
+    /// Each entry maps a [`Trade::position_id`] to that position's trades in
+    /// execution order: its partial closes (children, `is_partial == true`)
+    /// followed by the final close (parent), which carries the full
+    /// [`Trade::fills`] history. A position still open at the end of the run
+    /// has only children here; its fills are on
+    /// [`open_position`](Self::open_position).
+    pub fn trades_by_position(&self) -> std::collections::BTreeMap<usize, Vec<&Trade>> {
+        let mut groups: std::collections::BTreeMap<usize, Vec<&Trade>> =
+            std::collections::BTreeMap::new();
+        for trade in &self.trades {
+            groups.entry(trade.position_id).or_default().push(trade);
+        }
+        groups
+    }
+
     /// Return a sorted, deduplicated list of all tags used across all trades.
     ///
     /// Useful for discovering which tags are present in a result before
@@ -1445,9 +1664,15 @@ mod tests {
             return_pct,
             dividend_income: 0.0,
             unreinvested_dividends: 0.0,
+            financing_cost: 0.0,
             tags: Vec::new(),
             is_partial: false,
             scale_sequence: 0,
+            position_id: 0,
+            fills: Vec::new(),
+            mae_pct: 0.0,
+            mfe_pct: 0.0,
+            bars_held: 0,
             entry_signal: Signal::long(0, 100.0),
             exit_signal: Signal::exit(100, 110.0),
         }
@@ -1856,9 +2081,15 @@ mod tests {
             return_pct,
             dividend_income: 0.0,
             unreinvested_dividends: 0.0,
+            financing_cost: 0.0,
             tags: Vec::new(),
             is_partial: false,
             scale_sequence: 0,
+            position_id: 0,
+            fills: Vec::new(),
+            mae_pct: 0.0,
+            mfe_pct: 0.0,
+            bars_held: 0,
             entry_signal: Signal::long(entry_ts, 100.0),
             exit_signal: Signal::exit(exit_ts, 100.0 + pnl / 10.0),
         }
@@ -1891,6 +2122,7 @@ mod tests {
             open_position: None::<Position>,
             benchmark: None,
             diagnostics: vec![],
+            run_metadata: RunMetadata::default(),
         }
     }
 
@@ -1907,6 +2139,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn trades_csv_has_one_row_per_trade() {
+        let mut tagged = make_trade(50.0, 5.0, true);
+        tagged.tags = vec!["breakout".to_string(), "a,b".to_string()];
+        tagged.mae_pct = 1.5;
+        let result = make_result(
+            vec![tagged, make_trade(-20.0, -2.0, false)],
+            vec![
+                equity_point(0, 10000.0, 0.0),
+                equity_point(100, 10030.0, 0.0),
+            ],
+        );
+
+        let csv = result.trades_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("position_id,side,entry_timestamp"));
+        assert!(lines[1].starts_with("0,long,0,100,100,105,10,50.00,5.0000,1.5000,"));
+        assert!(lines[1].ends_with(",\"breakout;a,b\""));
+        assert!(lines[2].contains(",short,"));
+        assert!(result.summary().contains("Avg MAE: 0.75%"));
+    }
+
     // ── rolling_sharpe ────────────────────────────────────────────────────────
 
     #[test]
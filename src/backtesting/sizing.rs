@@ -0,0 +1,328 @@
+//! Position sizing models.
+//!
+//! By default every entry commits [`BacktestConfig::position_size_pct`] of
+//! the available cash. A [`PositionSizer`] replaces that single knob with a
+//! rule that can look at the price history and the trades closed so far:
+//!
+//! - [`FixedFraction`] — a fixed fraction of available capital
+//! - [`FixedAmount`] — a fixed amount of currency per trade
+//! - [`VolatilityTarget`] — risk a fixed fraction of capital per ATR of movement
+//! - [`KellyFraction`] — a fraction of the Kelly bet implied by past trades
+//!
+//! Implement the trait for custom rules.
+//!
+//! # Example
+//!
+//! ```
+//! use finance_query::backtesting::{BacktestConfig, VolatilityTarget};
+//!
+//! // Risk 1% of capital per 2 ATR(14) of adverse movement
+//! let config = BacktestConfig::builder()
+//!     .position_sizer(VolatilityTarget::new(0.01, 14).atr_multiple(2.0))
+//!     .build()
+//!     .unwrap();
+//! ```
+//!
+//! [`BacktestConfig::position_size_pct`]: super::BacktestConfig::position_size_pct
+
+use std::fmt;
+
+use crate::models::chart::Candle;
+
+use super::position::Trade;
+
+/// What a [`PositionSizer`] can see when sizing a new entry.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub struct SizingContext<'a> {
+    /// Cash available for the entry.
+    pub available_cash: f64,
+    /// Expected entry price, after slippage and spread.
+    pub price: f64,
+    /// Bars up to and including the bar the entry was decided on. The fill
+    /// bar itself is not included, so sizing never sees its high, low, or
+    /// close.
+    pub candles: &'a [Candle],
+    /// Trades closed so far in the run, in execution order.
+    pub trades: &'a [Trade],
+}
+
+impl<'a> SizingContext<'a> {
+    /// Create a context, e.g. to test a sizer outside the engine.
+    pub fn new(
+        available_cash: f64,
+        price: f64,
+        candles: &'a [Candle],
+        trades: &'a [Trade],
+    ) -> Self {
+        Self {
+            available_cash,
+            price,
+            candles,
+            trades,
+        }
+    }
+
+    /// Simple average true range over the last `period` bars, or `None` with
+    /// fewer than `period + 1` bars of history.
+    pub fn atr(&self, period: usize) -> Option<f64> {
+        if period == 0 || self.candles.len() <= period {
+            return None;
+        }
+        let window = &self.candles[self.candles.len() - period - 1..];
+        let sum: f64 = window
+            .windows(2)
+            .map(|w| {
+                let prev_close = w[0].close;
+                (w[1].high - w[1].low)
+                    .max((w[1].high - prev_close).abs())
+                    .max((w[1].low - prev_close).abs())
+            })
+            .sum();
+        Some(sum / period as f64)
+    }
+}
+
+/// A rule for how much capital to commit to each new position.
+///
+/// Set via [`BacktestConfigBuilder::position_sizer`]. The engine caps the
+/// returned allocation at the available cash times
+/// [`max_leverage`](super::BacktestConfig::max_leverage), then deducts
+/// commission, spread, and tax before converting it to a quantity. Returning
+/// `0.0` skips the entry. Scale-ins keep using the signal's own fraction.
+///
+/// [`BacktestConfigBuilder::position_sizer`]: super::BacktestConfigBuilder::position_sizer
+pub trait PositionSizer: fmt::Debug + Send + Sync {
+    /// Capital to commit to a new position, in account currency.
+    fn allocation(&self, ctx: &SizingContext<'_>) -> f64;
+}
+
+/// Commit a fixed fraction of available capital to each entry.
+///
+/// `FixedFraction::new(0.5)` matches `position_size_pct(0.5)` without
+/// leverage; with leverage, fractions above `1.0` borrow up to
+/// `max_leverage`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedFraction {
+    /// Fraction of available capital per entry.
+    pub fraction: f64,
+}
+
+impl FixedFraction {
+    /// Commit `fraction` of available capital to each entry.
+    pub fn new(fraction: f64) -> Self {
+        Self { fraction }
+    }
+}
+
+impl PositionSizer for FixedFraction {
+    fn allocation(&self, ctx: &SizingContext<'_>) -> f64 {
+        ctx.available_cash * self.fraction
+    }
+}
+
+/// Commit a fixed amount of currency to each entry.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedAmount {
+    /// Currency amount per entry.
+    pub amount: f64,
+}
+
+impl FixedAmount {
+    /// Commit `amount` of currency to each entry.
+    pub fn new(amount: f64) -> Self {
+        Self { amount }
+    }
+}
+
+impl PositionSizer for FixedAmount {
+    fn allocation(&self, _ctx: &SizingContext<'_>) -> f64 {
+        self.amount
+    }
+}
+
+/// Size positions so an adverse move of `atr_multiple` ATRs costs
+/// `risk_pct` of capital.
+///
+/// Quantity is `available_cash × risk_pct / (ATR × atr_multiple)`, so volatile
+/// markets get smaller positions. No entries are taken until `atr_period + 1`
+/// bars of history are available.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VolatilityTarget {
+    /// Fraction of capital risked per `atr_multiple` ATRs (e.g. `0.01`).
+    pub risk_pct: f64,
+    /// ATR lookback in bars.
+    pub atr_period: usize,
+    /// ATR multiple treated as the adverse move (default `1.0`).
+    pub atr_multiple: f64,
+}
+
+impl VolatilityTarget {
+    /// Risk `risk_pct` of capital per ATR over `atr_period` bars.
+    pub fn new(risk_pct: f64, atr_period: usize) -> Self {
+        Self {
+            risk_pct,
+            atr_period,
+            atr_multiple: 1.0,
+        }
+    }
+
+    /// Measure risk against `multiple` ATRs instead of one, e.g. to match a
+    /// 2-ATR stop.
+    pub fn atr_multiple(mut self, multiple: f64) -> Self {
+        self.atr_multiple = multiple;
+        self
+    }
+}
+
+impl PositionSizer for VolatilityTarget {
+    fn allocation(&self, ctx: &SizingContext<'_>) -> f64 {
+        let stop_distance = match ctx.atr(self.atr_period) {
+            Some(atr) if atr > 0.0 => atr * self.atr_multiple,
+            _ => return 0.0,
+        };
+        ctx.available_cash * self.risk_pct / stop_distance * ctx.price
+    }
+}
+
+/// Bet a fraction of the Kelly criterion estimated from closed trades.
+///
+/// The Kelly fraction is `W − (1 − W) / R`, where `W` is the win rate and
+/// `R` the average win over the average loss (by `return_pct`). It is scaled
+/// by `multiplier` (e.g. `0.5` for half-Kelly) and clamped to `0.0–1.0` of
+/// available capital. Until `min_trades` trades have closed there is no
+/// estimate, so `fallback_fraction` is used instead.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KellyFraction {
+    /// Multiplier on the full Kelly fraction (e.g. `0.5` for half-Kelly).
+    pub multiplier: f64,
+    /// Closed trades required before the estimate is used (default `20`).
+    pub min_trades: usize,
+    /// Fraction of available capital used before `min_trades` trades have
+    /// closed (default `0.1`).
+    pub fallback_fraction: f64,
+}
+
+impl KellyFraction {
+    /// Bet `multiplier` × the Kelly fraction.
+    pub fn new(multiplier: f64) -> Self {
+        Self {
+            multiplier,
+            min_trades: 20,
+            fallback_fraction: 0.1,
+        }
+    }
+
+    /// Set how many closed trades are needed before the Kelly estimate is used.
+    pub fn min_trades(mut self, n: usize) -> Self {
+        self.min_trades = n;
+        self
+    }
+
+    /// Set the fraction of capital used until `min_trades` trades have closed.
+    pub fn fallback_fraction(mut self, fraction: f64) -> Self {
+        self.fallback_fraction = fraction;
+        self
+    }
+
+    /// Full Kelly fraction implied by `trades`, or `None` without enough trades.
+    fn kelly(&self, trades: &[Trade]) -> Option<f64> {
+        if trades.is_empty() || trades.len() < self.min_trades {
+            return None;
+        }
+        let (mut wins, mut win_sum, mut loss_sum) = (0usize, 0.0, 0.0);
+        for trade in trades {
+            if trade.return_pct > 0.0 {
+                wins += 1;
+                win_sum += trade.return_pct;
+            } else {
+                loss_sum -= trade.return_pct;
+            }
+        }
+        let losses = trades.len() - wins;
+        let win_rate = wins as f64 / trades.len() as f64;
+        if wins == 0 {
+            return Some(0.0);
+        }
+        if losses == 0 || loss_sum <= 0.0 {
+            return Some(1.0);
+        }
+        let payoff = (win_sum / wins as f64) / (loss_sum / losses as f64);
+        Some(win_rate - (1.0 - win_rate) / payoff)
+    }
+}
+
+impl PositionSizer for KellyFraction {
+    fn allocation(&self, ctx: &SizingContext<'_>) -> f64 {
+        let fraction = match self.kelly(ctx.trades) {
+            Some(kelly) => (kelly * self.multiplier).clamp(0.0, 1.0),
+            None => self.fallback_fraction,
+        };
+        ctx.available_cash * fraction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backtesting::position::{Position, PositionSide};
+    use crate::backtesting::signal::Signal;
+
+    fn trade(entry: f64, exit: f64) -> Trade {
+        Position::new(
+            PositionSide::Long,
+            0,
+            entry,
+            1.0,
+            0.0,
+            Signal::long(0, entry),
+        )
+        .close(1, exit, 0.0, Signal::exit(1, exit))
+    }
+
+    #[test]
+    fn test_atr_needs_period_plus_one_bars() {
+        let candles: Vec<Candle> = (0..3)
+            .map(|i| Candle::new(i, 100.0, 102.0, 98.0, 100.0, 0))
+            .collect();
+        let ctx = SizingContext::new(10_000.0, 100.0, &candles, &[]);
+        assert_eq!(ctx.atr(2), Some(4.0));
+        assert_eq!(ctx.atr(3), None);
+    }
+
+    #[test]
+    fn test_volatility_target_scales_inversely_with_atr() {
+        let candles: Vec<Candle> = (0..15)
+            .map(|i| Candle::new(i, 100.0, 102.0, 98.0, 100.0, 0))
+            .collect();
+        let ctx = SizingContext::new(10_000.0, 100.0, &candles, &[]);
+        // Risk $100 per ATR of 4 → 25 shares → $2,500
+        let sizer = VolatilityTarget::new(0.01, 14);
+        assert!((sizer.allocation(&ctx) - 2_500.0).abs() < 1e-9);
+        assert!((sizer.atr_multiple(2.0).allocation(&ctx) - 1_250.0).abs() < 1e-9);
+        // Not enough history
+        let short = SizingContext::new(10_000.0, 100.0, &candles[..5], &[]);
+        assert_eq!(sizer.allocation(&short), 0.0);
+    }
+
+    #[test]
+    fn test_kelly_uses_fallback_then_estimate() {
+        let sizer = KellyFraction::new(0.5).min_trades(4);
+        let ctx = SizingContext::new(10_000.0, 100.0, &[], &[]);
+        assert!((sizer.allocation(&ctx) - 1_000.0).abs() < 1e-9);
+
+        // W = 0.75, R = 10% / 10% = 1 → Kelly 0.5 → half-Kelly 0.25
+        let trades = vec![
+            trade(100.0, 110.0),
+            trade(100.0, 110.0),
+            trade(100.0, 110.0),
+            trade(100.0, 90.0),
+        ];
+        let ctx = SizingContext::new(10_000.0, 100.0, &[], &trades);
+        assert!((sizer.allocation(&ctx) - 2_500.0).abs() < 1e-9);
+    }
+}
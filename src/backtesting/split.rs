@@ -0,0 +1,283 @@
+//! Train/test evaluation with correct indicator warmup across the split.
+//!
+//! Slicing candles by hand and backtesting the test slice on its own throws
+//! away the history indicators need: a 200-bar SMA produces nothing for the
+//! first 199 test bars, and EMA-style indicators start from a different seed
+//! than they would in live trading. The usual "fix" — prepending some training
+//! bars — lets the strategy trade inside the training period, leaking
+//! in-sample P&L into the out-of-sample result.
+//!
+//! [`evaluate_out_of_sample`] runs the test segment with the full training
+//! history prepended so every indicator sees exactly the values it would have
+//! had in a continuous run, but suppresses all signals before the first test
+//! bar and reports only the test-period equity curve and trades.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use finance_query::{Ticker, Interval, TimeRange};
+//! use finance_query::backtesting::{BacktestConfig, SmaCrossover, evaluate_out_of_sample};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let chart = Ticker::new("AAPL")
+//!     .await?
+//!     .chart(Interval::OneDay, TimeRange::FiveYears)
+//!     .await?;
+//! let (train, test) = chart.split_at(0.7)?;
+//!
+//! let report = evaluate_out_of_sample(
+//!     SmaCrossover::new(10, 50),
+//!     &train,
+//!     &test,
+//!     BacktestConfig::default(),
+//! )?;
+//!
+//! println!("IS:  {:.2}%", report.in_sample.metrics.total_return_pct);
+//! println!("OOS: {:.2}%", report.out_of_sample.metrics.total_return_pct);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::indicators::Indicator;
+use crate::models::chart::{Candle, Chart};
+
+use super::condition::HtfIndicatorSpec;
+use super::config::BacktestConfig;
+use super::engine::BacktestEngine;
+use super::error::{BacktestError, Result};
+use super::optimizer::{OptimizationReport, ParamValue};
+use super::result::{BacktestResult, PerformanceMetrics};
+use super::signal::Signal;
+use super::strategy::{Strategy, StrategyContext};
+
+/// In-sample and out-of-sample results for a single train/test split.
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutOfSampleReport {
+    /// Backtest over the training segment
+    pub in_sample: BacktestResult,
+    /// Backtest over the test segment, warmed up on the training segment
+    pub out_of_sample: BacktestResult,
+    /// Timestamp of the first test bar
+    pub split_timestamp: i64,
+}
+
+impl OutOfSampleReport {
+    /// Out-of-sample minus in-sample total return, in percentage points.
+    ///
+    /// Large negative values are a typical sign of overfitting.
+    pub fn return_degradation(&self) -> f64 {
+        self.out_of_sample.metrics.total_return_pct - self.in_sample.metrics.total_return_pct
+    }
+}
+
+/// Grid-search result with the winning parameters re-run on held-out data.
+///
+/// Returned by [`GridSearch::run_out_of_sample`](super::GridSearch::run_out_of_sample).
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OptimizedSplitReport {
+    /// Full grid-search report over the training segment (in-sample only)
+    pub optimization: OptimizationReport,
+    /// Parameters that scored best on the training segment
+    pub best_params: HashMap<String, ParamValue>,
+    /// The best parameters evaluated on the training and test segments
+    pub split: OutOfSampleReport,
+}
+
+/// Backtest a strategy on a training segment and evaluate it on the following
+/// test segment without lookahead or lost warmup.
+///
+/// The test run sees the training candles as history (so indicators are fully
+/// warmed up at the first test bar) but cannot open positions before it. The
+/// returned out-of-sample result covers only the test period: its equity
+/// curve, trades, signals, and metrics start at the first test bar.
+///
+/// `train` and `test` are typically produced by [`Chart::split_at`].
+///
+/// # Errors
+///
+/// Returns [`BacktestError::InvalidParameter`] if either segment is empty or
+/// the test segment does not start after the training segment ends, and
+/// [`BacktestError::InsufficientData`] if the training segment is shorter than
+/// the strategy's warmup period.
+pub fn evaluate_out_of_sample<S>(
+    strategy: S,
+    train: &Chart,
+    test: &Chart,
+    config: BacktestConfig,
+) -> Result<OutOfSampleReport>
+where
+    S: Strategy + Clone,
+{
+    let (Some(train_last), Some(test_first)) = (train.candles.last(), test.candles.first()) else {
+        return Err(BacktestError::invalid_param(
+            "train/test",
+            "both segments must contain at least one candle",
+        ));
+    };
+    if test_first.timestamp <= train_last.timestamp {
+        return Err(BacktestError::invalid_param(
+            "test",
+            "test segment must start after the training segment ends",
+        ));
+    }
+
+    let engine = BacktestEngine::new(config);
+    let in_sample = engine.run(&train.symbol, &train.candles, strategy.clone())?;
+
+    let mut candles: Vec<Candle> = Vec::with_capacity(train.candles.len() + test.candles.len());
+    candles.extend_from_slice(&train.candles);
+    candles.extend_from_slice(&test.candles);
+
+    let guarded = HoldUntil {
+        inner: strategy,
+        first_bar: train.candles.len(),
+    };
+    let full = engine.run(&test.symbol, &candles, guarded)?;
+    let out_of_sample = trim_to(full, test_first.timestamp);
+
+    Ok(OutOfSampleReport {
+        in_sample,
+        out_of_sample,
+        split_timestamp: test_first.timestamp,
+    })
+}
+
+/// Drop everything before `start` from a result and recompute its metrics.
+fn trim_to(mut result: BacktestResult, start: i64) -> BacktestResult {
+    result.equity_curve.retain(|p| p.timestamp >= start);
+    result.signals.retain(|s| s.timestamp >= start);
+    result.trades.retain(|t| t.entry_timestamp >= start);
+    result.start_timestamp = start;
+    result.metrics = PerformanceMetrics::calculate(
+        &result.trades,
+        &result.equity_curve,
+        result.initial_capital,
+        result.signals.len(),
+        result.signals.iter().filter(|s| s.executed).count(),
+        result.config.risk_free_rate,
+        result.config.bars_per_year,
+    );
+    result
+}
+
+/// Wraps a strategy so it holds until `first_bar`, while computing indicators
+/// over the whole series.
+#[derive(Clone)]
+struct HoldUntil<S> {
+    inner: S,
+    first_bar: usize,
+}
+
+impl<S: Strategy> Strategy for HoldUntil<S> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+    fn required_indicators(&self) -> Vec<(String, Indicator)> {
+        self.inner.required_indicators()
+    }
+    fn htf_requirements(&self) -> Vec<HtfIndicatorSpec> {
+        self.inner.htf_requirements()
+    }
+    fn setup(&mut self, indicators: &HashMap<String, Vec<Option<f64>>>) {
+        self.inner.setup(indicators)
+    }
+    fn on_candle(&self, ctx: &StrategyContext) -> Signal {
+        if ctx.index < self.first_bar {
+            return Signal::hold();
+        }
+        self.inner.on_candle(ctx)
+    }
+    fn warmup_period(&self) -> usize {
+        // The engine skips bars `i < warmup - 1`; keep the inner warmup if it
+        // extends past the boundary.
+        self.inner.warmup_period().max(self.first_bar + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backtesting::SmaCrossover;
+    use crate::models::chart::ChartMeta;
+
+    fn chart(prices: &[f64], offset: usize) -> Chart {
+        Chart {
+            symbol: "TEST".to_string(),
+            meta: ChartMeta::default(),
+            candles: prices
+                .iter()
+                .enumerate()
+                .map(|(i, &p)| Candle {
+                    timestamp: (offset + i) as i64,
+                    open: p,
+                    high: p * 1.01,
+                    low: p * 0.99,
+                    close: p,
+                    volume: 1000,
+                    adj_close: Some(p),
+                    provider_id: None,
+                })
+                .collect(),
+            interval: None,
+            range: None,
+            provider_id: None,
+            adjustment: None,
+        }
+    }
+
+    fn config() -> BacktestConfig {
+        BacktestConfig::builder()
+            .commission_pct(0.0)
+            .slippage_pct(0.0)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_out_of_sample_starts_at_boundary() {
+        // Down then up: the crossover fires early in the test segment only if
+        // the slow SMA is already warmed up from the training data.
+        let prices: Vec<f64> = (0..60)
+            .map(|i| 100.0 - i as f64 * 0.5)
+            .chain((0..40).map(|i| 70.0 + i as f64))
+            .collect();
+        let train = chart(&prices[..60], 0);
+        let test = chart(&prices[60..], 60);
+
+        let report =
+            evaluate_out_of_sample(SmaCrossover::new(5, 20), &train, &test, config()).unwrap();
+
+        let oos = &report.out_of_sample;
+        assert_eq!(report.split_timestamp, 60);
+        assert_eq!(oos.start_timestamp, 60);
+        assert_eq!(oos.equity_curve.len(), 40);
+        assert!(oos.equity_curve.iter().all(|p| p.timestamp >= 60));
+        assert!(oos.trades.iter().all(|t| t.entry_timestamp >= 60));
+        assert!(oos.signals.iter().all(|s| s.timestamp >= 60));
+        // Warmed-up indicators let the strategy enter well before bar 60 + 20
+        let first_entry = oos
+            .signals
+            .iter()
+            .find(|s| s.executed)
+            .map(|s| s.timestamp)
+            .unwrap();
+        assert!(first_entry < 80);
+        assert_eq!(report.in_sample.end_timestamp, 59);
+    }
+
+    #[test]
+    fn test_rejects_overlapping_segments() {
+        let prices: Vec<f64> = (0..30).map(|i| 100.0 + i as f64).collect();
+        let train = chart(&prices, 0);
+        let test = chart(&prices, 10);
+        assert!(evaluate_out_of_sample(SmaCrossover::new(2, 5), &train, &test, config()).is_err());
+        let empty = chart(&[], 100);
+        assert!(evaluate_out_of_sample(SmaCrossover::new(2, 5), &train, &empty, config()).is_err());
+    }
+}
@@ -0,0 +1,991 @@
+//! Serializable strategy definitions.
+//!
+//! A [`StrategyDefinition`] describes a condition-based strategy as plain data
+//! — entry/exit conditions, indicator references, and named parameters — so
+//! it can be saved to JSON, shared, versioned, and loaded back at runtime
+//! without recompiling. [`StrategyDefinition::build`] turns it into the same
+//! [`CustomStrategy`](super::CustomStrategy) that [`StrategyBuilder`] produces.
+//!
+//! # Format
+//!
+//! ```json
+//! {
+//!   "name": "RSI Mean Reversion",
+//!   "parameters": { "period": 14, "oversold": 30 },
+//!   "entry": {
+//!     "type": "all",
+//!     "conditions": [
+//!       { "type": "crosses_below", "indicator": { "name": "rsi", "params": ["$period"] }, "target": "$oversold" },
+//!       { "type": "above", "indicator": { "name": "price" }, "target": { "name": "sma", "params": [200] } }
+//!     ]
+//!   },
+//!   "exit": {
+//!     "type": "any",
+//!     "conditions": [
+//!       { "type": "crosses_above", "indicator": { "name": "rsi", "params": ["$period"] }, "target": 70 },
+//!       { "type": "stop_loss", "pct": 0.05 }
+//!     ]
+//!   }
+//! }
+//! ```
+//!
+//! Indicator names match the functions in [`refs`](crate::backtesting::refs)
+//! (`sma`, `rsi`, `macd`, `bollinger`, …). Multi-output indicators select a
+//! line with `output` (e.g. `"signal_line"` for MACD, `"upper"` for Bollinger).
+//! Any number can be replaced by `"$name"` to reference an entry in
+//! `parameters`, which [`StrategyDefinition::with_param`] can override.
+//!
+//! Strategies composed in Rust with closures or custom [`Condition`] types
+//! cannot be serialized; define them as a `StrategyDefinition` instead.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::backtesting::condition::{
+    self, AboveRef, And, BelowRef, Condition, CrossesAboveRef, CrossesBelowRef, HtfIndicatorSpec,
+    Not, Or,
+};
+use crate::backtesting::error::{BacktestError, Result};
+use crate::backtesting::refs::{self, IndicatorRef, IndicatorRefExt};
+use crate::constants::Interval;
+use crate::indicators::{CustomIndicatorRef, Indicator, PatternSentiment};
+
+use super::{Strategy, StrategyBuilder, StrategyContext};
+
+const CURRENT_VERSION: u32 = 1;
+
+/// A number in a strategy definition: a literal or a `"$name"` parameter reference.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ValueDef {
+    /// Literal value
+    Literal(f64),
+    /// Reference to a named parameter, written as `"$name"`
+    Param(String),
+}
+
+impl From<f64> for ValueDef {
+    fn from(v: f64) -> Self {
+        Self::Literal(v)
+    }
+}
+
+/// Reference to an indicator or price series.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RefDef {
+    /// Reference name, matching the [`refs`](crate::backtesting::refs) function (e.g. `"sma"`)
+    pub name: String,
+    /// Positional arguments to the reference function
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<ValueDef>,
+    /// Output line for multi-output indicators (e.g. `"histogram"` for MACD)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+}
+
+impl RefDef {
+    /// Create a reference with literal parameters.
+    pub fn new(name: impl Into<String>, params: &[f64]) -> Self {
+        Self {
+            name: name.into(),
+            params: params.iter().map(|&p| ValueDef::Literal(p)).collect(),
+            output: None,
+        }
+    }
+
+    /// Select an output line of a multi-output indicator.
+    pub fn output(mut self, output: impl Into<String>) -> Self {
+        self.output = Some(output.into());
+        self
+    }
+}
+
+/// Right-hand side of a comparison: a number or another reference.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TargetDef {
+    /// Fixed threshold
+    Value(ValueDef),
+    /// Another indicator or price series
+    Ref(RefDef),
+}
+
+/// A serializable condition, mirroring the [`condition`](crate::backtesting::condition) API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ConditionDef {
+    /// `indicator > target`
+    Above {
+        /// Left-hand reference
+        indicator: RefDef,
+        /// Threshold or reference to compare against
+        target: TargetDef,
+    },
+    /// `indicator < target`
+    Below {
+        /// Left-hand reference
+        indicator: RefDef,
+        /// Threshold or reference to compare against
+        target: TargetDef,
+    },
+    /// `indicator` crosses from at-or-below to above `target`
+    CrossesAbove {
+        /// Left-hand reference
+        indicator: RefDef,
+        /// Threshold or reference to compare against
+        target: TargetDef,
+    },
+    /// `indicator` crosses from at-or-above to below `target`
+    CrossesBelow {
+        /// Left-hand reference
+        indicator: RefDef,
+        /// Threshold or reference to compare against
+        target: TargetDef,
+    },
+    /// `low < indicator < high`
+    Between {
+        /// Reference to test
+        indicator: RefDef,
+        /// Lower bound (exclusive)
+        low: ValueDef,
+        /// Upper bound (exclusive)
+        high: ValueDef,
+    },
+    /// `|indicator - value| <= tolerance`
+    Equals {
+        /// Reference to test
+        indicator: RefDef,
+        /// Target value
+        value: ValueDef,
+        /// Allowed absolute difference
+        tolerance: ValueDef,
+    },
+    /// All nested conditions must hold
+    All {
+        /// Nested conditions
+        conditions: Vec<ConditionDef>,
+    },
+    /// At least one nested condition must hold
+    Any {
+        /// Nested conditions
+        conditions: Vec<ConditionDef>,
+    },
+    /// Negation of the nested condition
+    Not {
+        /// Condition to negate
+        condition: Box<ConditionDef>,
+    },
+    /// Evaluate the nested condition on a higher timeframe
+    Htf {
+        /// Higher timeframe interval (e.g. `"1wk"`)
+        interval: Interval,
+        /// Condition evaluated on the resampled candles
+        condition: Box<ConditionDef>,
+    },
+    /// Position loss reaches `pct` (e.g. `0.05` for 5%)
+    StopLoss {
+        /// Loss fraction
+        pct: ValueDef,
+    },
+    /// Position gain reaches `pct`
+    TakeProfit {
+        /// Gain fraction
+        pct: ValueDef,
+    },
+    /// Price retraces `pct` from its best level since entry
+    TrailingStop {
+        /// Trail fraction
+        pct: ValueDef,
+    },
+    /// Profit retraces `pct` from its peak since entry
+    TrailingTakeProfit {
+        /// Trail fraction
+        pct: ValueDef,
+    },
+    /// Position has been held for at least `bars` bars
+    HeldForBars {
+        /// Minimum bars held
+        bars: ValueDef,
+    },
+    /// A position is open
+    HasPosition,
+    /// No position is open
+    NoPosition,
+    /// A long position is open
+    IsLong,
+    /// A short position is open
+    IsShort,
+    /// The open position is in profit
+    InProfit,
+    /// The open position is at a loss
+    InLoss,
+}
+
+/// A complete, serializable condition-based strategy.
+///
+/// # Example
+///
+/// ```no_run
+/// use finance_query::backtesting::StrategyDefinition;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let json = std::fs::read_to_string("strategies/rsi.json")?;
+/// let strategy = StrategyDefinition::from_json(&json)?
+///     .with_param("period", 10.0)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct StrategyDefinition {
+    /// Strategy name (used for reporting and [`load_by_name`](Self::load_by_name))
+    pub name: String,
+    /// Free-form description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Format version, for forward compatibility
+    #[serde(default = "current_version")]
+    pub version: u32,
+    /// Named parameters referenced as `"$name"` in conditions
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub parameters: BTreeMap<String, f64>,
+    /// Long entry condition
+    pub entry: ConditionDef,
+    /// Long exit condition
+    pub exit: ConditionDef,
+    /// Short entry condition (requires `short_exit`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_entry: Option<ConditionDef>,
+    /// Short exit condition (requires `short_entry`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_exit: Option<ConditionDef>,
+    /// Regime filter gating entries (see [`StrategyBuilder::regime_filter`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub regime_filter: Option<ConditionDef>,
+    /// Explicit warmup override (see [`StrategyBuilder::warmup`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warmup: Option<usize>,
+}
+
+fn current_version() -> u32 {
+    CURRENT_VERSION
+}
+
+impl StrategyDefinition {
+    /// Create a definition from entry and exit conditions.
+    pub fn new(name: impl Into<String>, entry: ConditionDef, exit: ConditionDef) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            version: CURRENT_VERSION,
+            parameters: BTreeMap::new(),
+            entry,
+            exit,
+            short_entry: None,
+            short_exit: None,
+            regime_filter: None,
+            warmup: None,
+        }
+    }
+
+    /// Set or override a named parameter.
+    pub fn with_param(mut self, name: impl Into<String>, value: f64) -> Self {
+        self.parameters.insert(name.into(), value);
+        self
+    }
+
+    /// Parse a definition from JSON.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let def: Self = serde_json::from_str(json)
+            .map_err(|e| BacktestError::invalid_param("definition", e.to_string()))?;
+        if def.version > CURRENT_VERSION {
+            return Err(BacktestError::invalid_param(
+                "version",
+                format!(
+                    "definition version {} is newer than supported version {CURRENT_VERSION}",
+                    def.version
+                ),
+            ));
+        }
+        Ok(def)
+    }
+
+    /// Serialize the definition to pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| BacktestError::strategy(format!("failed to serialize strategy: {e}")))
+    }
+
+    /// Read a definition from a JSON file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path).map_err(|e| {
+            BacktestError::strategy(format!("failed to read {}: {e}", path.display()))
+        })?;
+        Self::from_json(&json)
+    }
+
+    /// Write the definition to a JSON file.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_json()?).map_err(|e| {
+            BacktestError::strategy(format!("failed to write {}: {e}", path.display()))
+        })
+    }
+
+    /// Find a definition by name among the `*.json` files in `dir`.
+    ///
+    /// Matches the file stem or the `name` field, case-insensitively. Files
+    /// that fail to parse are skipped.
+    pub fn load_by_name(dir: impl AsRef<Path>, name: &str) -> Result<Self> {
+        let dir = dir.as_ref();
+        let entries = std::fs::read_dir(dir).map_err(|e| {
+            BacktestError::strategy(format!("failed to read {}: {e}", dir.display()))
+        })?;
+
+        let mut paths: Vec<_> = entries
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let stem_matches = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .is_some_and(|s| s.eq_ignore_ascii_case(name));
+            if let Ok(def) = Self::from_file(&path)
+                && (stem_matches || def.name.eq_ignore_ascii_case(name))
+            {
+                return Ok(def);
+            }
+        }
+        Err(BacktestError::invalid_param(
+            "name",
+            format!("no strategy named '{name}' in {}", dir.display()),
+        ))
+    }
+
+    /// Build a runnable strategy.
+    ///
+    /// Returns an error for unknown indicator names or outputs, wrong
+    /// parameter counts, undefined `"$name"` parameters, or a short entry
+    /// without a short exit (and vice versa).
+    pub fn build(&self) -> Result<Box<dyn Strategy>> {
+        let entry = self.condition(&self.entry)?;
+        let exit = self.condition(&self.exit)?;
+
+        let mut builder = StrategyBuilder::new(self.name.clone())
+            .entry(entry)
+            .exit(exit);
+
+        match (&self.short_entry, &self.short_exit) {
+            (Some(se), Some(sx)) => {
+                builder = builder.with_short(self.condition(se)?, self.condition(sx)?);
+            }
+            (None, None) => {}
+            _ => {
+                return Err(BacktestError::invalid_param(
+                    "short_entry/short_exit",
+                    "both must be set to enable short positions",
+                ));
+            }
+        }
+        if let Some(rf) = &self.regime_filter {
+            builder = builder.regime_filter(self.condition(rf)?);
+        }
+        if let Some(bars) = self.warmup {
+            builder = builder.warmup(bars);
+        }
+        Ok(Box::new(builder.build()))
+    }
+
+    fn value(&self, v: &ValueDef) -> Result<f64> {
+        match v {
+            ValueDef::Literal(x) => Ok(*x),
+            ValueDef::Param(p) => {
+                let key = p.strip_prefix('$').ok_or_else(|| {
+                    BacktestError::invalid_param(
+                        p.clone(),
+                        "parameter references must start with '$'",
+                    )
+                })?;
+                self.parameters.get(key).copied().ok_or_else(|| {
+                    BacktestError::invalid_param(p.clone(), "parameter is not defined")
+                })
+            }
+        }
+    }
+
+    fn condition(&self, def: &ConditionDef) -> Result<DynCondition> {
+        use ConditionDef as C;
+        Ok(match def {
+            C::Above { indicator, target } => {
+                let r = self.indicator(indicator)?;
+                match target {
+                    TargetDef::Value(v) => DynCondition::new(r.above(self.value(v)?)),
+                    TargetDef::Ref(o) => DynCondition::new(AboveRef::new(r, self.indicator(o)?)),
+                }
+            }
+            C::Below { indicator, target } => {
+                let r = self.indicator(indicator)?;
+                match target {
+                    TargetDef::Value(v) => DynCondition::new(r.below(self.value(v)?)),
+                    TargetDef::Ref(o) => DynCondition::new(BelowRef::new(r, self.indicator(o)?)),
+                }
+            }
+            C::CrossesAbove { indicator, target } => {
+                let r = self.indicator(indicator)?;
+                match target {
+                    TargetDef::Value(v) => DynCondition::new(r.crosses_above(self.value(v)?)),
+                    TargetDef::Ref(o) => {
+                        DynCondition::new(CrossesAboveRef::new(r, self.indicator(o)?))
+                    }
+                }
+            }
+            C::CrossesBelow { indicator, target } => {
+                let r = self.indicator(indicator)?;
+                match target {
+                    TargetDef::Value(v) => DynCondition::new(r.crosses_below(self.value(v)?)),
+                    TargetDef::Ref(o) => {
+                        DynCondition::new(CrossesBelowRef::new(r, self.indicator(o)?))
+                    }
+                }
+            }
+            C::Between {
+                indicator,
+                low,
+                high,
+            } => DynCondition::new(
+                self.indicator(indicator)?
+                    .between(self.value(low)?, self.value(high)?),
+            ),
+            C::Equals {
+                indicator,
+                value,
+                tolerance,
+            } => DynCondition::new(
+                self.indicator(indicator)?
+                    .equals(self.value(value)?, self.value(tolerance)?),
+            ),
+            C::All { conditions } => {
+                self.fold(conditions, "all", |a, b| DynCondition::new(And::new(a, b)))?
+            }
+            C::Any { conditions } => {
+                self.fold(conditions, "any", |a, b| DynCondition::new(Or::new(a, b)))?
+            }
+            C::Not { condition } => DynCondition::new(Not::new(self.condition(condition)?)),
+            C::Htf {
+                interval,
+                condition,
+            } => DynCondition::new(refs::htf(*interval, self.condition(condition)?)),
+            C::StopLoss { pct } => DynCondition::new(condition::stop_loss(self.value(pct)?)),
+            C::TakeProfit { pct } => DynCondition::new(condition::take_profit(self.value(pct)?)),
+            C::TrailingStop { pct } => {
+                DynCondition::new(condition::trailing_stop(self.value(pct)?))
+            }
+            C::TrailingTakeProfit { pct } => {
+                DynCondition::new(condition::trailing_take_profit(self.value(pct)?))
+            }
+            C::HeldForBars { bars } => {
+                DynCondition::new(condition::held_for_bars(self.period(bars, "bars")?))
+            }
+            C::HasPosition => DynCondition::new(condition::has_position()),
+            C::NoPosition => DynCondition::new(condition::no_position()),
+            C::IsLong => DynCondition::new(condition::is_long()),
+            C::IsShort => DynCondition::new(condition::is_short()),
+            C::InProfit => DynCondition::new(condition::in_profit()),
+            C::InLoss => DynCondition::new(condition::in_loss()),
+        })
+    }
+
+    fn fold(
+        &self,
+        conditions: &[ConditionDef],
+        kind: &str,
+        combine: impl Fn(DynCondition, DynCondition) -> DynCondition,
+    ) -> Result<DynCondition> {
+        let mut iter = conditions.iter();
+        let first = iter.next().ok_or_else(|| {
+            BacktestError::invalid_param(kind, "must contain at least one condition")
+        })?;
+        iter.try_fold(self.condition(first)?, |acc, c| {
+            Ok(combine(acc, self.condition(c)?))
+        })
+    }
+
+    fn period(&self, v: &ValueDef, name: &str) -> Result<usize> {
+        let x = self.value(v)?;
+        if x < 0.0 || x.fract() != 0.0 {
+            return Err(BacktestError::invalid_param(
+                name,
+                format!("must be a non-negative integer, got {x}"),
+            ));
+        }
+        Ok(x as usize)
+    }
+
+    fn indicator(&self, def: &RefDef) -> Result<DynRef> {
+        let name = def.name.as_str();
+        let args = def
+            .params
+            .iter()
+            .map(|v| self.value(v))
+            .collect::<Result<Vec<f64>>>()?;
+
+        let arity = |n: usize| -> Result<()> {
+            if args.len() == n {
+                Ok(())
+            } else {
+                Err(BacktestError::invalid_param(
+                    name,
+                    format!("expects {n} parameter(s), got {}", args.len()),
+                ))
+            }
+        };
+        let int = |i: usize| self.period(&ValueDef::Literal(args[i]), name);
+        let output = def.output.as_deref();
+        let bad_output = |valid: &[&str]| {
+            BacktestError::invalid_param(
+                name,
+                format!(
+                    "unknown output '{}' (expected one of: {})",
+                    output.unwrap_or_default(),
+                    valid.join(", ")
+                ),
+            )
+        };
+        if output.is_some()
+            && !matches!(
+                name,
+                "macd"
+                    | "bollinger"
+                    | "donchian"
+                    | "keltner"
+                    | "supertrend"
+                    | "stochastic"
+                    | "stochastic_rsi"
+                    | "aroon"
+                    | "ichimoku"
+                    | "pattern_count"
+            )
+        {
+            return Err(BacktestError::invalid_param(
+                name,
+                "does not have multiple outputs",
+            ));
+        }
+
+        macro_rules! period_ref {
+            ($f:path) => {{
+                arity(1)?;
+                DynRef::new($f(int(0)?))
+            }};
+        }
+        macro_rules! no_arg_ref {
+            ($f:path) => {{
+                arity(0)?;
+                DynRef::new($f())
+            }};
+        }
+
+        Ok(match name {
+            "price" | "close" => no_arg_ref!(refs::close),
+            "open" => no_arg_ref!(refs::open),
+            "high" => no_arg_ref!(refs::high),
+            "low" => no_arg_ref!(refs::low),
+            "volume" => no_arg_ref!(refs::volume),
+            "typical_price" => no_arg_ref!(refs::typical_price),
+            "median_price" => no_arg_ref!(refs::median_price),
+            "price_change_pct" => no_arg_ref!(refs::price_change_pct),
+            "gap_pct" => no_arg_ref!(refs::gap_pct),
+            "candle_range" => no_arg_ref!(refs::candle_range),
+            "candle_body" => no_arg_ref!(refs::candle_body),
+            "is_bullish" => no_arg_ref!(refs::is_bullish),
+            "is_bearish" => no_arg_ref!(refs::is_bearish),
+            "obv" => no_arg_ref!(refs::obv),
+            "vwap" => no_arg_ref!(refs::vwap),
+            "true_range" => no_arg_ref!(refs::true_range),
+            "chaikin_oscillator" => no_arg_ref!(refs::chaikin_oscillator),
+            "accumulation_distribution" => no_arg_ref!(refs::accumulation_distribution),
+            "relative_volume" => period_ref!(refs::relative_volume),
+            "sma" => period_ref!(refs::sma),
+            "ema" => period_ref!(refs::ema),
+            "wma" => period_ref!(refs::wma),
+            "dema" => period_ref!(refs::dema),
+            "tema" => period_ref!(refs::tema),
+            "hma" => period_ref!(refs::hma),
+            "vwma" => period_ref!(refs::vwma),
+            "mcginley" => period_ref!(refs::mcginley),
+            "rsi" => period_ref!(refs::rsi),
+            "cci" => period_ref!(refs::cci),
+            "williams_r" => period_ref!(refs::williams_r),
+            "cmo" => period_ref!(refs::cmo),
+            "momentum" => period_ref!(refs::momentum),
+            "roc" => period_ref!(refs::roc),
+            "adx" => period_ref!(refs::adx),
+            "atr" => period_ref!(refs::atr),
+            "mfi" => period_ref!(refs::mfi),
+            "cmf" => period_ref!(refs::cmf),
+            "choppiness_index" => period_ref!(refs::choppiness_index),
+            "bull_power" => period_ref!(refs::bull_power),
+            "bear_power" => period_ref!(refs::bear_power),
+            "elder_bull_power" => period_ref!(refs::elder_bull_power),
+            "elder_bear_power" => period_ref!(refs::elder_bear_power),
+            "balance_of_power" => match args.len() {
+                0 => DynRef::new(refs::balance_of_power(None)),
+                _ => {
+                    arity(1)?;
+                    DynRef::new(refs::balance_of_power(Some(int(0)?)))
+                }
+            },
+            "alma" => {
+                arity(3)?;
+                DynRef::new(refs::alma(int(0)?, args[1], args[2]))
+            }
+            "parabolic_sar" => {
+                arity(2)?;
+                DynRef::new(refs::parabolic_sar(args[0], args[1]))
+            }
+            "awesome_oscillator" => {
+                arity(2)?;
+                DynRef::new(refs::awesome_oscillator(int(0)?, int(1)?))
+            }
+            "coppock_curve" => {
+                arity(3)?;
+                DynRef::new(refs::coppock_curve(int(0)?, int(1)?, int(2)?))
+            }
+            "macd" => {
+                arity(3)?;
+                let m = refs::macd(int(0)?, int(1)?, int(2)?);
+                match output.unwrap_or("line") {
+                    "line" => DynRef::new(m.line()),
+                    "signal_line" | "signal" => DynRef::new(m.signal_line()),
+                    "histogram" => DynRef::new(m.histogram()),
+                    _ => return Err(bad_output(&["line", "signal_line", "histogram"])),
+                }
+            }
+            "bollinger" => {
+                arity(2)?;
+                let b = refs::bollinger(int(0)?, args[1]);
+                match output.unwrap_or("middle") {
+                    "upper" => DynRef::new(b.upper()),
+                    "middle" => DynRef::new(b.middle()),
+                    "lower" => DynRef::new(b.lower()),
+                    _ => return Err(bad_output(&["upper", "middle", "lower"])),
+                }
+            }
+            "donchian" => {
+                arity(1)?;
+                let d = refs::donchian(int(0)?);
+                match output.unwrap_or("middle") {
+                    "upper" => DynRef::new(d.upper()),
+                    "middle" => DynRef::new(d.middle()),
+                    "lower" => DynRef::new(d.lower()),
+                    _ => return Err(bad_output(&["upper", "middle", "lower"])),
+                }
+            }
+            "keltner" => {
+                arity(3)?;
+                let k = refs::keltner(int(0)?, args[1], int(2)?);
+                match output.unwrap_or("middle") {
+                    "upper" => DynRef::new(k.upper()),
+                    "middle" => DynRef::new(k.middle()),
+                    "lower" => DynRef::new(k.lower()),
+                    _ => return Err(bad_output(&["upper", "middle", "lower"])),
+                }
+            }
+            "supertrend" => {
+                arity(2)?;
+                let s = refs::supertrend(int(0)?, args[1]);
+                match output.unwrap_or("value") {
+                    "value" => DynRef::new(s.value()),
+                    "uptrend" => DynRef::new(s.uptrend()),
+                    _ => return Err(bad_output(&["value", "uptrend"])),
+                }
+            }
+            "stochastic" => {
+                arity(3)?;
+                let s = refs::stochastic(int(0)?, int(1)?, int(2)?);
+                match output.unwrap_or("k") {
+                    "k" => DynRef::new(s.k()),
+                    "d" => DynRef::new(s.d()),
+                    _ => return Err(bad_output(&["k", "d"])),
+                }
+            }
+            "stochastic_rsi" => {
+                arity(4)?;
+                let s = refs::stochastic_rsi(int(0)?, int(1)?, int(2)?, int(3)?);
+                match output.unwrap_or("k") {
+                    "k" => DynRef::new(s.k()),
+                    "d" => DynRef::new(s.d()),
+                    _ => return Err(bad_output(&["k", "d"])),
+                }
+            }
+            "aroon" => {
+                arity(1)?;
+                let a = refs::aroon(int(0)?);
+                match output {
+                    Some("up") => DynRef::new(a.up()),
+                    Some("down") => DynRef::new(a.down()),
+                    _ => return Err(bad_output(&["up", "down"])),
+                }
+            }
+            "ichimoku" => {
+                let i = match args.len() {
+                    0 => refs::ichimoku(),
+                    _ => {
+                        arity(4)?;
+                        refs::ichimoku_custom(int(0)?, int(1)?, int(2)?, int(3)?)
+                    }
+                };
+                let valid = [
+                    "conversion_line",
+                    "base_line",
+                    "leading_span_a",
+                    "leading_span_b",
+                    "lagging_span",
+                ];
+                match output {
+                    Some("conversion_line") => DynRef::new(i.conversion_line()),
+                    Some("base_line") => DynRef::new(i.base_line()),
+                    Some("leading_span_a") => DynRef::new(i.leading_span_a()),
+                    Some("leading_span_b") => DynRef::new(i.leading_span_b()),
+                    Some("lagging_span") => DynRef::new(i.lagging_span()),
+                    _ => return Err(bad_output(&valid)),
+                }
+            }
+            "pattern_count" => {
+                arity(1)?;
+                let sentiment = match output.unwrap_or("bullish") {
+                    "bullish" => PatternSentiment::Bullish,
+                    "bearish" => PatternSentiment::Bearish,
+                    "neutral" => PatternSentiment::Neutral,
+                    _ => return Err(bad_output(&["bullish", "bearish", "neutral"])),
+                };
+                DynRef::new(refs::pattern_count(sentiment, int(0)?))
+            }
+            _ => {
+                let custom = CustomIndicatorRef::lookup(name, &args)
+                    .map_err(|e| BacktestError::invalid_param(name, e.to_string()))?;
+                DynRef::new(refs::CustomRef::from(custom))
+            }
+        })
+    }
+}
+
+// ── Type erasure ─────────────────────────────────────────────────────────────
+//
+// Conditions and refs are generic in the builder API. Definitions are resolved
+// at runtime, so both are erased behind `Arc`s that re-implement the traits.
+
+trait ErasedRef: Send + Sync {
+    fn key(&self) -> &str;
+    fn required_indicators(&self) -> Vec<(String, Indicator)>;
+    fn value(&self, ctx: &StrategyContext) -> Option<f64>;
+    fn prev_value(&self, ctx: &StrategyContext) -> Option<f64>;
+}
+
+impl<R: IndicatorRef> ErasedRef for R {
+    fn key(&self) -> &str {
+        IndicatorRef::key(self)
+    }
+    fn required_indicators(&self) -> Vec<(String, Indicator)> {
+        IndicatorRef::required_indicators(self)
+    }
+    fn value(&self, ctx: &StrategyContext) -> Option<f64> {
+        IndicatorRef::value(self, ctx)
+    }
+    fn prev_value(&self, ctx: &StrategyContext) -> Option<f64> {
+        IndicatorRef::prev_value(self, ctx)
+    }
+}
+
+#[derive(Clone)]
+struct DynRef(Arc<dyn ErasedRef>);
+
+impl DynRef {
+    fn new<R: IndicatorRef>(r: R) -> Self {
+        Self(Arc::new(r))
+    }
+}
+
+impl IndicatorRef for DynRef {
+    fn key(&self) -> &str {
+        self.0.key()
+    }
+    fn required_indicators(&self) -> Vec<(String, Indicator)> {
+        self.0.required_indicators()
+    }
+    fn value(&self, ctx: &StrategyContext) -> Option<f64> {
+        self.0.value(ctx)
+    }
+    fn prev_value(&self, ctx: &StrategyContext) -> Option<f64> {
+        self.0.prev_value(ctx)
+    }
+}
+
+trait ErasedCondition: Send + Sync {
+    fn evaluate(&self, ctx: &StrategyContext) -> bool;
+    fn required_indicators(&self) -> Vec<(String, Indicator)>;
+    fn htf_requirements(&self) -> Vec<HtfIndicatorSpec>;
+    fn description(&self) -> String;
+}
+
+impl<C: Condition> ErasedCondition for C {
+    fn evaluate(&self, ctx: &StrategyContext) -> bool {
+        Condition::evaluate(self, ctx)
+    }
+    fn required_indicators(&self) -> Vec<(String, Indicator)> {
+        Condition::required_indicators(self)
+    }
+    fn htf_requirements(&self) -> Vec<HtfIndicatorSpec> {
+        Condition::htf_requirements(self)
+    }
+    fn description(&self) -> String {
+        Condition::description(self)
+    }
+}
+
+#[derive(Clone)]
+struct DynCondition(Arc<dyn ErasedCondition>);
+
+impl DynCondition {
+    fn new<C: Condition>(c: C) -> Self {
+        Self(Arc::new(c))
+    }
+}
+
+impl Condition for DynCondition {
+    fn evaluate(&self, ctx: &StrategyContext) -> bool {
+        self.0.evaluate(ctx)
+    }
+    fn required_indicators(&self) -> Vec<(String, Indicator)> {
+        self.0.required_indicators()
+    }
+    fn htf_requirements(&self) -> Vec<HtfIndicatorSpec> {
+        self.0.htf_requirements()
+    }
+    fn description(&self) -> String {
+        self.0.description()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RSI_JSON: &str = r#"{
+        "name": "RSI Mean Reversion",
+        "parameters": { "period": 14, "oversold": 30 },
+        "entry": {
+            "type": "all",
+            "conditions": [
+                { "type": "crosses_below", "indicator": { "name": "rsi", "params": ["$period"] }, "target": "$oversold" },
+                { "type": "above", "indicator": { "name": "price" }, "target": { "name": "sma", "params": [200] } }
+            ]
+        },
+        "exit": {
+            "type": "any",
+            "conditions": [
+                { "type": "crosses_above", "indicator": { "name": "rsi", "params": ["$period"] }, "target": 70 },
+                { "type": "stop_loss", "pct": 0.05 }
+            ]
+        }
+    }"#;
+
+    #[test]
+    fn test_parse_and_build() {
+        let def = StrategyDefinition::from_json(RSI_JSON).unwrap();
+        assert_eq!(def.version, 1);
+        let strategy = def.build().unwrap();
+        assert_eq!(strategy.name(), "RSI Mean Reversion");
+
+        let keys: Vec<String> = strategy
+            .required_indicators()
+            .into_iter()
+            .map(|(k, _)| k)
+            .collect();
+        assert!(keys.contains(&"rsi_14".to_string()));
+        assert!(keys.contains(&"sma_200".to_string()));
+    }
+
+    #[test]
+    fn test_param_override() {
+        let strategy = StrategyDefinition::from_json(RSI_JSON)
+            .unwrap()
+            .with_param("period", 7.0)
+            .build()
+            .unwrap();
+        let keys: Vec<String> = strategy
+            .required_indicators()
+            .into_iter()
+            .map(|(k, _)| k)
+            .collect();
+        assert!(keys.contains(&"rsi_7".to_string()));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let def = StrategyDefinition::new(
+            "MACD",
+            ConditionDef::CrossesAbove {
+                indicator: RefDef::new("macd", &[12.0, 26.0, 9.0]),
+                target: TargetDef::Ref(
+                    RefDef::new("macd", &[12.0, 26.0, 9.0]).output("signal_line"),
+                ),
+            },
+            ConditionDef::Htf {
+                interval: Interval::OneWeek,
+                condition: Box::new(ConditionDef::Below {
+                    indicator: RefDef::new("close", &[]),
+                    target: TargetDef::Ref(RefDef::new("ema", &[20.0])),
+                }),
+            },
+        );
+        let json = def.to_json().unwrap();
+        assert!(json.contains("\"type\": \"crosses_above\""));
+        assert!(json.contains("\"1wk\""));
+        let parsed = StrategyDefinition::from_json(&json).unwrap();
+        assert_eq!(parsed, def);
+        assert!(!parsed.build().unwrap().htf_requirements().is_empty());
+    }
+
+    #[test]
+    fn test_build_errors() {
+        let entry = ConditionDef::Above {
+            indicator: RefDef::new("sma", &[]),
+            target: TargetDef::Value(ValueDef::Literal(1.0)),
+        };
+        let def = StrategyDefinition::new("bad", entry, ConditionDef::IsLong);
+        assert!(def.build().is_err());
+
+        let unknown = ConditionDef::Above {
+            indicator: RefDef::new("nope", &[1.0]),
+            target: TargetDef::Value(ValueDef::Param("$missing".into())),
+        };
+        assert!(
+            StrategyDefinition::new("bad", unknown, ConditionDef::IsLong)
+                .build()
+                .is_err()
+        );
+
+        let mut short_only =
+            StrategyDefinition::new("bad", ConditionDef::IsLong, ConditionDef::IsLong);
+        short_only.short_entry = Some(ConditionDef::NoPosition);
+        assert!(short_only.build().is_err());
+
+        assert!(
+            StrategyDefinition::from_json(
+                r#"{"name":"x","version":99,"entry":{"type":"is_long"},"exit":{"type":"is_long"}}"#
+            )
+            .is_err()
+        );
+    }
+}
@@ -20,12 +20,14 @@
 //! ```
 
 mod builder;
+pub mod definition;
 mod ensemble;
 pub mod prebuilt;
 
 use std::collections::HashMap;
 
-use crate::backtesting::condition::HtfIndicatorSpec;
+use crate::backtesting::condition::{HtfIndicatorSpec, htf_key};
+use crate::constants::Interval;
 use crate::indicators::Indicator;
 use crate::models::chart::Candle;
 
@@ -35,6 +37,9 @@ use super::signal::Signal;
 // Re-export builder
 pub use builder::{CustomStrategy, StrategyBuilder};
 
+// Re-export serializable definitions
+pub use definition::{ConditionDef, RefDef, StrategyDefinition, TargetDef, ValueDef};
+
 // Re-export ensemble
 pub use ensemble::{EnsembleMode, EnsembleStrategy};
 
@@ -110,6 +115,20 @@ impl<'a> StrategyContext<'a> {
         }
     }
 
+    /// Get a higher-timeframe indicator value at the current bar.
+    ///
+    /// Reads an indicator declared with [`HtfIndicatorSpec::new`] in
+    /// [`Strategy::htf_requirements`]. The value comes from the latest
+    /// **completed** `interval` bar, so it never looks ahead.
+    pub fn indicator_on(&self, interval: Interval, name: &str) -> Option<f64> {
+        self.indicator(&htf_key(interval, name))
+    }
+
+    /// Get a higher-timeframe indicator value at the previous base bar
+    pub fn indicator_prev_on(&self, interval: Interval, name: &str) -> Option<f64> {
+        self.indicator_prev(&htf_key(interval, name))
+    }
+
     /// Check if we have a position
     pub fn has_position(&self) -> bool {
         self.position.is_some()
@@ -293,6 +312,51 @@ pub trait Strategy: Send + Sync {
     /// this automatically; raw [`Strategy`] implementations that use HTF
     /// conditions should override this to avoid the O(n²) dynamic fallback.
     ///
+    /// Raw implementations can also declare their own HTF indicators with
+    /// [`HtfIndicatorSpec::new`] and read them with
+    /// [`StrategyContext::indicator_on`]:
+    ///
+    /// ```
+    /// use finance_query::Interval;
+    /// use finance_query::backtesting::condition::HtfIndicatorSpec;
+    /// use finance_query::backtesting::{Signal, Strategy, StrategyContext};
+    /// use finance_query::indicators::Indicator;
+    ///
+    /// /// Trade hourly EMA(9) crosses, long only above the daily SMA(50).
+    /// struct DailyTrendFilter;
+    ///
+    /// impl Strategy for DailyTrendFilter {
+    ///     fn name(&self) -> &str {
+    ///         "Daily Trend Filter"
+    ///     }
+    ///
+    ///     fn required_indicators(&self) -> Vec<(String, Indicator)> {
+    ///         vec![("ema_9".to_string(), Indicator::Ema(9))]
+    ///     }
+    ///
+    ///     fn htf_requirements(&self) -> Vec<HtfIndicatorSpec> {
+    ///         vec![HtfIndicatorSpec::new(Interval::OneDay, "sma_50", Indicator::Sma(50))]
+    ///     }
+    ///
+    ///     fn on_candle(&self, ctx: &StrategyContext) -> Signal {
+    ///         let close = ctx.close();
+    ///         let (Some(ema), Some(daily_sma)) = (
+    ///             ctx.indicator("ema_9"),
+    ///             ctx.indicator_on(Interval::OneDay, "sma_50"),
+    ///         ) else {
+    ///             return Signal::hold();
+    ///         };
+    ///         if close > ema && close > daily_sma && !ctx.has_position() {
+    ///             Signal::long(ctx.timestamp(), close)
+    ///         } else if close < ema && ctx.is_long() {
+    ///             Signal::exit(ctx.timestamp(), close)
+    ///         } else {
+    ///             Signal::hold()
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
     /// [`StrategyBuilder`]: crate::backtesting::strategy::StrategyBuilder
     fn htf_requirements(&self) -> Vec<HtfIndicatorSpec> {
         vec![]
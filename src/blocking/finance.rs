@@ -0,0 +1,99 @@
+//! Blocking counterparts of [`crate::finance`] functions.
+//!
+//! Each function blocks on the async function of the same name; see those for
+//! details.
+
+use super::block_on;
+use crate::constants::Region;
+use crate::constants::screeners::Screener;
+use crate::constants::sectors::Sector;
+use crate::error::Result;
+use crate::finance::{LookupOptions, SearchOptions};
+use crate::models::calendar::{
+    EarningsCalendarEntry, EconomicEvent, IpoCalendarEntry, SplitCalendarEntry,
+};
+use crate::models::corporate::news::News;
+use crate::models::discovery::gaps::Gapper;
+use crate::models::discovery::lookup::LookupResults;
+use crate::models::discovery::screeners::{ScreenerField, ScreenerQuery, ScreenerResults};
+use crate::models::discovery::search::SearchResults;
+use crate::models::discovery::trending::TrendingQuote;
+use crate::models::market::global_snapshot::GlobalSnapshot;
+use crate::models::market::hours::MarketHours;
+use crate::models::market::industries::IndustryData;
+use crate::models::market::market_summary::MarketSummaryQuote;
+use crate::models::market::sectors::SectorData;
+use crate::models::sentiment::FearAndGreed;
+use crate::tickers::BatchQuotesResponse;
+use chrono::NaiveDate;
+
+/// Forwards to the async `crate::finance` function of the same name.
+macro_rules! forward {
+    ($(
+        $(#[$meta:meta])*
+        fn $name:ident($($arg:ident: $ty:ty),*) -> $ret:ty;
+    )*) => {
+        $(
+            $(#[$meta])*
+            pub fn $name($($arg: $ty),*) -> $ret {
+                block_on(crate::finance::$name($($arg),*))
+            }
+        )*
+    };
+}
+
+forward! {
+    /// Search for symbols and news. See [`crate::finance::search`].
+    fn search(query: &str, options: &SearchOptions) -> Result<SearchResults>;
+    /// Look up symbols by type. See [`crate::finance::lookup`].
+    fn lookup(query: &str, options: &LookupOptions) -> Result<LookupResults>;
+    /// Predefined screener results. See [`crate::finance::screener`].
+    fn screener(screener_type: Screener, count: u32) -> Result<ScreenerResults>;
+    /// One page of a predefined screener. See [`crate::finance::screener_page`].
+    fn screener_page(screener_type: Screener, offset: u32, size: u32) -> Result<ScreenerResults>;
+    /// Predefined screener restricted to a region. See [`crate::finance::regional_screener`].
+    fn regional_screener(screener_type: Screener, region: Region, count: u32) -> Result<ScreenerResults>;
+    /// General market news. See [`crate::finance::news`].
+    fn news() -> Result<Vec<News>>;
+    /// Market hours. See [`crate::finance::hours`].
+    fn hours(region: Option<Region>) -> Result<MarketHours>;
+    /// World index quotes. See [`crate::finance::indices`].
+    fn indices(region: Option<crate::constants::indices::Region>) -> Result<BatchQuotesResponse>;
+    /// Sector overview. See [`crate::finance::sector`].
+    fn sector(sector_type: Sector) -> Result<SectorData>;
+    /// Market summary. See [`crate::finance::market_summary`].
+    fn market_summary(region: Option<Region>) -> Result<Vec<MarketSummaryQuote>>;
+    /// Trending symbols. See [`crate::finance::trending`].
+    fn trending(region: Option<Region>) -> Result<Vec<TrendingQuote>>;
+    /// Market summary and trending for several regions. See [`crate::finance::global_snapshot`].
+    fn global_snapshot(regions: &[Region]) -> Result<GlobalSnapshot>;
+    /// Companies reporting earnings in a date window. See [`crate::finance::earnings_calendar`].
+    fn earnings_calendar(start_date: NaiveDate, end_date: NaiveDate) -> Result<Vec<EarningsCalendarEntry>>;
+    /// IPOs in a date window. See [`crate::finance::ipo_calendar`].
+    fn ipo_calendar(start_date: NaiveDate, end_date: NaiveDate) -> Result<Vec<IpoCalendarEntry>>;
+    /// Stock splits in a date window. See [`crate::finance::splits_calendar`].
+    fn splits_calendar(start_date: NaiveDate, end_date: NaiveDate) -> Result<Vec<SplitCalendarEntry>>;
+    /// Macroeconomic releases in a date window. See [`crate::finance::economic_calendar`].
+    fn economic_calendar(region: Option<Region>, start_date: NaiveDate, end_date: NaiveDate) -> Result<Vec<EconomicEvent>>;
+    /// CNN Fear & Greed index. See [`crate::finance::fear_and_greed`].
+    fn fear_and_greed() -> Result<FearAndGreed>;
+}
+
+/// Custom screener results. See [`crate::finance::custom_screener`].
+pub fn custom_screener<F: ScreenerField>(query: ScreenerQuery<F>) -> Result<ScreenerResults> {
+    block_on(crate::finance::custom_screener(query))
+}
+
+/// Industry overview. See [`crate::finance::industry`].
+pub fn industry(industry_key: impl AsRef<str>) -> Result<IndustryData> {
+    block_on(crate::finance::industry(industry_key))
+}
+
+/// Symbols gapping from the prior close. See [`crate::finance::gap_scan`].
+pub fn gap_scan<S, I>(symbols: I, min_gap_pct: f64) -> Result<Vec<Gapper>>
+where
+    S: Into<String>,
+    I: IntoIterator<Item = S>,
+{
+    block_on(crate::finance::gap_scan(symbols, min_gap_pct))
+}
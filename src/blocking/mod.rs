@@ -0,0 +1,82 @@
+//! Synchronous API (requires the `blocking` feature).
+//!
+//! For code that isn't async — GUI event loops, scripts, language bindings —
+//! the types here run the async API on a shared background runtime and block
+//! the calling thread until each result is ready. The runtime starts on first
+//! use and lives for the rest of the process.
+//!
+//! [`Ticker`] and [`finance`] cover the common calls; anything else can be
+//! run with [`block_on`].
+//!
+//! These functions must not be called from within an async runtime (they
+//! panic there, as blocking a runtime thread would stall it). Use the async
+//! API directly from async code.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use finance_query::blocking::{self, Ticker};
+//! use finance_query::{Interval, TimeRange};
+//!
+//! # fn example() -> finance_query::Result<()> {
+//! let ticker = Ticker::new("AAPL")?;
+//! let quote: finance_query::Quote = ticker.quote()?;
+//! let chart = ticker.chart(Interval::OneDay, TimeRange::OneMonth)?;
+//! println!("{} candles for {}", chart.candles.len(), quote.symbol);
+//!
+//! let gainers = blocking::finance::screener(finance_query::Screener::DayGainers, 10)?;
+//!
+//! // Any other async call
+//! let profile = blocking::block_on(ticker.as_async().asset_profile())?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod finance;
+mod ticker;
+
+pub use ticker::Ticker;
+
+use std::future::Future;
+use std::sync::OnceLock;
+
+use tokio::runtime::Runtime;
+
+fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    // Multi-threaded so tasks spawned by the async API (cache refreshes,
+    // stream readers) keep running between blocking calls
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .thread_name("finance-query-blocking")
+            .enable_all()
+            .build()
+            .expect("failed to start finance-query blocking runtime")
+    })
+}
+
+/// Run `future` to completion on the shared runtime, blocking this thread.
+///
+/// # Panics
+///
+/// Panics when called from within an async runtime.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    runtime().block_on(future)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_on_reuses_runtime() {
+        assert_eq!(block_on(async { 1 + 1 }), 2);
+        let first = runtime() as *const Runtime;
+        assert_eq!(
+            block_on(async { tokio::spawn(async { 3 }).await.unwrap() }),
+            3
+        );
+        assert_eq!(first, runtime() as *const Runtime);
+    }
+}
@@ -0,0 +1,170 @@
+//! Blocking wrapper around [`crate::Ticker`].
+
+use super::block_on;
+use crate::constants::{Frequency, Interval, StatementType, TimeRange};
+use crate::error::Result;
+use crate::format::Both;
+use crate::models::chart::{CapitalGain, Chart, Dividend, Split};
+use crate::models::corporate::news::News;
+use crate::models::corporate::recommendation::Recommendation;
+use crate::models::format::Format;
+use crate::models::fundamentals::FinancialStatement;
+use crate::models::options::{OptionChains, Options};
+use crate::models::quote::Quote;
+
+/// Forwards `&self` methods to the async ticker, blocking on each.
+macro_rules! forward {
+    ($(
+        $(#[$meta:meta])*
+        fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $ret:ty;
+    )*) => {
+        $(
+            $(#[$meta])*
+            pub fn $name(&self $(, $arg: $ty)*) -> $ret {
+                block_on(self.inner.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+/// Synchronous counterpart of [`crate::Ticker`].
+///
+/// Each method blocks on the async method of the same name; see those for
+/// details.
+///
+/// # Example
+///
+/// ```no_run
+/// use finance_query::blocking::Ticker;
+/// use finance_query::{Interval, TimeRange};
+///
+/// # fn example() -> finance_query::Result<()> {
+/// let ticker = Ticker::new("MSFT")?;
+/// for candle in ticker.chart(Interval::OneDay, TimeRange::FiveDays)?.candles {
+///     println!("{} {:.2}", candle.timestamp, candle.close);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Ticker {
+    inner: crate::Ticker,
+}
+
+impl Ticker {
+    /// Create a ticker with default configuration.
+    pub fn new(symbol: impl Into<String>) -> Result<Self> {
+        block_on(crate::Ticker::new(symbol)).map(Self::from)
+    }
+
+    /// Build a ticker from a configured [`TickerBuilder`](crate::TickerBuilder).
+    ///
+    /// ```no_run
+    /// use finance_query::blocking::Ticker;
+    ///
+    /// # fn example() -> finance_query::Result<()> {
+    /// let toyota = Ticker::from_builder(
+    ///     finance_query::Ticker::builder("7203.T").lang("ja-JP").region_code("JP"),
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_builder(builder: crate::TickerBuilder) -> Result<Self> {
+        block_on(builder.build()).map(Self::from)
+    }
+
+    /// The ticker symbol.
+    pub fn symbol(&self) -> &str {
+        self.inner.symbol()
+    }
+
+    /// The wrapped async ticker, for calls not mirrored here
+    /// (run them with [`block_on`](super::block_on)).
+    pub fn as_async(&self) -> &crate::Ticker {
+        &self.inner
+    }
+
+    /// Unwrap into the async ticker.
+    pub fn into_async(self) -> crate::Ticker {
+        self.inner
+    }
+
+    /// Full quote. See [`crate::Ticker::quote`].
+    pub fn quote<F>(&self) -> Result<Quote<F>>
+    where
+        F: Format,
+        Quote<Both>: Into<Quote<F>>,
+    {
+        block_on(self.inner.quote())
+    }
+
+    forward! {
+        /// OHLCV history. See [`crate::Ticker::chart`].
+        fn chart(&self, interval: Interval, range: TimeRange) -> Result<Chart>;
+        /// OHLCV history between Unix timestamps. See [`crate::Ticker::chart_range`].
+        fn chart_range(&self, interval: Interval, start: i64, end: i64) -> Result<Chart>;
+        /// Dividend history. See [`crate::Ticker::dividends`].
+        fn dividends(&self, range: TimeRange) -> Result<Vec<Dividend>>;
+        /// Stock split history. See [`crate::Ticker::splits`].
+        fn splits(&self, range: TimeRange) -> Result<Vec<Split>>;
+        /// Capital gain distributions. See [`crate::Ticker::capital_gains`].
+        fn capital_gains(&self, range: TimeRange) -> Result<Vec<CapitalGain>>;
+        /// Similar symbols. See [`crate::Ticker::recommendations`].
+        fn recommendations(&self, limit: u32) -> Result<Recommendation>;
+        /// Recent news. See [`crate::Ticker::news`].
+        fn news(&self) -> Result<Vec<News>>;
+        /// Options chain. See [`crate::Ticker::options`].
+        fn options(&self, date: Option<i64>) -> Result<Options>;
+        /// Chains for every expiration. See [`crate::Ticker::options_all`].
+        fn options_all(&self) -> Result<OptionChains>;
+        /// Financial statements. See [`crate::Ticker::financials`].
+        fn financials(&self, stmt_type: StatementType, frequency: Frequency) -> Result<FinancialStatement>;
+    }
+
+    /// OHLCV history for an exact window. See [`crate::Ticker::chart_between`].
+    pub fn chart_between<Tz: chrono::TimeZone>(
+        &self,
+        interval: Interval,
+        start: chrono::DateTime<Tz>,
+        end: chrono::DateTime<Tz>,
+    ) -> Result<Chart> {
+        block_on(self.inner.chart_between(interval, start, end))
+    }
+
+    forward! {
+        /// Price module. See [`crate::Ticker::price`].
+        fn price(&self) -> Result<Option<crate::models::quote::Price>>;
+        /// Summary detail module. See [`crate::Ticker::summary_detail`].
+        fn summary_detail(&self) -> Result<Option<crate::models::quote::SummaryDetail>>;
+        /// Financial data module. See [`crate::Ticker::financial_data`].
+        fn financial_data(&self) -> Result<Option<crate::models::quote::FinancialData>>;
+        /// Key statistics module. See [`crate::Ticker::key_stats`].
+        fn key_stats(&self) -> Result<Option<crate::models::quote::DefaultKeyStatistics>>;
+        /// Company profile module. See [`crate::Ticker::asset_profile`].
+        fn asset_profile(&self) -> Result<Option<crate::models::quote::AssetProfile>>;
+        /// Top-of-book snapshot. See [`crate::Ticker::book`].
+        fn book(&self) -> Result<crate::models::quote::TopOfBook>;
+    }
+
+    #[cfg(feature = "indicators")]
+    forward! {
+        /// Technical indicators summary. See [`crate::Ticker::indicators`].
+        fn indicators(&self, interval: Interval, range: TimeRange) -> Result<crate::indicators::IndicatorsSummary>;
+    }
+
+    /// Risk summary. See [`crate::Ticker::risk`].
+    #[cfg(feature = "risk")]
+    pub fn risk(
+        &self,
+        interval: Interval,
+        range: TimeRange,
+        benchmark: Option<&str>,
+    ) -> Result<crate::risk::RiskSummary> {
+        block_on(self.inner.risk(interval, range, benchmark))
+    }
+}
+
+impl From<crate::Ticker> for Ticker {
+    fn from(inner: crate::Ticker) -> Self {
+        Self { inner }
+    }
+}
@@ -0,0 +1,395 @@
+//! Persistent on-disk cache for chart and quote responses (requires `cache` feature).
+//!
+//! The in-memory cache enabled by [`TickerBuilder::cache`](crate::TickerBuilder::cache)
+//! lives only as long as the [`Ticker`](crate::Ticker). A [`DiskCache`] keeps
+//! responses across process restarts, so repeated backtests and research
+//! scripts don't re-download the same history on every run.
+//!
+//! Attach one with [`TickerBuilder::cache_dir`](crate::TickerBuilder::cache_dir)
+//! and optionally bound entry age with
+//! [`TickerBuilder::cache_ttl`](crate::TickerBuilder::cache_ttl):
+//!
+//! ```no_run
+//! use finance_query::{Interval, Ticker, TimeRange};
+//! use std::time::Duration;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let ticker = Ticker::builder("AAPL")
+//!     .cache_dir("data/cache")
+//!     .cache_ttl(Duration::from_secs(6 * 60 * 60))
+//!     .build()
+//!     .await?;
+//!
+//! // Served from disk on every run after the first (until the entry expires)
+//! let chart = ticker.chart(Interval::OneDay, TimeRange::FiveYears).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Entries are keyed by symbol, request parameters, and the UTC date they
+//! were fetched on, and stored as JSON:
+//!
+//! - charts: `<dir>/<SYMBOL>/chart_<interval>_<range>_<YYYY-MM-DD>.json`
+//! - quotes: `<dir>/<SYMBOL>/quote_<YYYY-MM-DD>.json`
+//!
+//! An entry is only served on the day it was fetched, and, when a TTL is set,
+//! only while its file is younger than the TTL. Unreadable or corrupt entries
+//! are treated as misses, and failed writes are logged rather than failing
+//! the request. Reads and writes made by a `Ticker` run on tokio's blocking
+//! pool. [`DiskCache::prune`] and [`DiskCache::clear`] are synchronous; call
+//! them from `spawn_blocking` when inside a runtime.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use chrono::{NaiveDate, Utc};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tracing::{debug, warn};
+
+use crate::constants::{Interval, TimeRange};
+use crate::error::{FinanceError, Result};
+use crate::models::chart::Chart;
+use crate::models::quote::Quote;
+
+/// Age after which a `*.json.tmp` file is treated as left over from an
+/// interrupted write rather than one still in progress.
+const TMP_GRACE: Duration = Duration::from_secs(60);
+
+/// Directory-backed cache of chart and quote responses.
+///
+/// Cheap to clone; clones share the same directory.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+    ttl: Option<Duration>,
+}
+
+impl DiskCache {
+    /// Open a cache rooted at `dir`, creating the directory if needed.
+    ///
+    /// Without a TTL, entries are served for the rest of the UTC day they
+    /// were fetched on.
+    pub fn open(dir: impl AsRef<Path>) -> Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self { dir, ttl: None })
+    }
+
+    /// Expire entries once their file is older than `ttl`.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Directory the cache writes to.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Maximum entry age, if set.
+    pub fn ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+
+    /// Delete every stale entry and every temporary file left by an
+    /// interrupted write, returning how many files were removed.
+    pub fn prune(&self) -> Result<usize> {
+        let today = Utc::now().date_naive();
+        let mut removed = 0;
+        for symbol_dir in std::fs::read_dir(&self.dir)? {
+            let symbol_dir = symbol_dir?.path();
+            if !symbol_dir.is_dir() {
+                continue;
+            }
+            for entry in std::fs::read_dir(&symbol_dir)? {
+                let path = entry?.path();
+                let stale = match path.extension().and_then(|ext| ext.to_str()) {
+                    Some("json") => !self.is_fresh(&path, today),
+                    Some("tmp") => {
+                        path.to_string_lossy().ends_with(".json.tmp")
+                            && age(&path).is_some_and(|age| age >= TMP_GRACE)
+                    }
+                    _ => false,
+                };
+                if stale {
+                    std::fs::remove_file(&path)?;
+                    removed += 1;
+                }
+            }
+        }
+        Ok(removed)
+    }
+
+    /// Delete every entry for `symbol`.
+    pub fn clear(&self, symbol: &str) -> Result<()> {
+        match std::fs::remove_dir_all(self.symbol_dir(symbol)?) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    pub(crate) async fn chart(
+        &self,
+        symbol: &str,
+        interval: Interval,
+        range: TimeRange,
+    ) -> Option<Chart> {
+        self.read(self.chart_path(symbol, interval, range).ok()?)
+            .await
+    }
+
+    pub(crate) async fn put_chart(
+        &self,
+        symbol: &str,
+        interval: Interval,
+        range: TimeRange,
+        chart: &Chart,
+    ) {
+        if let Ok(path) = self.chart_path(symbol, interval, range) {
+            self.write(path, chart).await;
+        }
+    }
+
+    pub(crate) async fn quote(&self, symbol: &str) -> Option<Quote> {
+        self.read(self.quote_path(symbol).ok()?).await
+    }
+
+    pub(crate) async fn put_quote(&self, symbol: &str, quote: &Quote) {
+        if let Ok(path) = self.quote_path(symbol) {
+            self.write(path, quote).await;
+        }
+    }
+
+    fn chart_path(&self, symbol: &str, interval: Interval, range: TimeRange) -> Result<PathBuf> {
+        Ok(self.symbol_dir(symbol)?.join(format!(
+            "chart_{}_{}_{}.json",
+            interval.as_str(),
+            range.as_str(),
+            Utc::now().date_naive()
+        )))
+    }
+
+    fn quote_path(&self, symbol: &str) -> Result<PathBuf> {
+        Ok(self
+            .symbol_dir(symbol)?
+            .join(format!("quote_{}.json", Utc::now().date_naive())))
+    }
+
+    fn symbol_dir(&self, symbol: &str) -> Result<PathBuf> {
+        let valid = !symbol.is_empty()
+            && symbol
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '^' | '=' | '_'))
+            && !symbol.starts_with('.');
+        if !valid {
+            return Err(FinanceError::InvalidParameter {
+                param: "symbol".to_string(),
+                reason: format!("'{symbol}' cannot be used as a disk cache key"),
+            });
+        }
+        Ok(self.dir.join(symbol.to_uppercase()))
+    }
+
+    /// Whether `path` was fetched on `today` and is within the TTL.
+    fn is_fresh(&self, path: &Path, today: NaiveDate) -> bool {
+        let fetched_on = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .and_then(|s| s.rsplit_once('_'))
+            .and_then(|(_, date)| date.parse::<NaiveDate>().ok());
+        if fetched_on != Some(today) {
+            return false;
+        }
+        let Some(ttl) = self.ttl else {
+            return true;
+        };
+        age(path).is_some_and(|age| age < ttl)
+    }
+
+    /// Read and decode an entry on the blocking pool.
+    async fn read<T: DeserializeOwned + Send + 'static>(&self, path: PathBuf) -> Option<T> {
+        let cache = self.clone();
+        tokio::task::spawn_blocking(move || {
+            if !cache.is_fresh(&path, Utc::now().date_naive()) {
+                return None;
+            }
+            let bytes = std::fs::read(&path).ok()?;
+            match serde_json::from_slice(&bytes) {
+                Ok(value) => Some(value),
+                Err(e) => {
+                    debug!("Ignoring corrupt cache entry {}: {}", path.display(), e);
+                    None
+                }
+            }
+        })
+        .await
+        .ok()
+        .flatten()
+    }
+
+    /// Encode an entry and write it on the blocking pool.
+    async fn write<T: Serialize>(&self, path: PathBuf, value: &T) {
+        let result = match serde_json::to_vec(value) {
+            Ok(bytes) => {
+                let target = path.clone();
+                tokio::task::spawn_blocking(move || write_atomic(&target, &bytes))
+                    .await
+                    .unwrap_or_else(|e| Err(FinanceError::InternalError(e.to_string())))
+            }
+            Err(e) => Err(e.into()),
+        };
+        if let Err(e) = result {
+            warn!("Failed to write cache entry {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// Write to a temporary file and rename so readers never see a partial entry.
+fn write_atomic(path: &Path, bytes: &[u8]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, bytes)?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Time since `path` was last modified.
+fn age(path: &Path) -> Option<Duration> {
+    std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::chart::{Candle, ChartMeta};
+
+    fn cache(name: &str) -> DiskCache {
+        let dir =
+            std::env::temp_dir().join(format!("finance-query-cache-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        DiskCache::open(dir).unwrap()
+    }
+
+    fn chart() -> Chart {
+        Chart {
+            symbol: "AAPL".to_string(),
+            meta: ChartMeta::default(),
+            candles: vec![Candle {
+                timestamp: 1_700_000_000,
+                open: 1.0,
+                high: 2.0,
+                low: 0.5,
+                close: 1.5,
+                volume: 100,
+                adj_close: None,
+                provider_id: None,
+            }],
+            interval: Some(Interval::OneDay),
+            range: Some(TimeRange::OneMonth),
+            provider_id: None,
+            adjustment: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chart_roundtrip() {
+        let cache = cache("chart");
+        assert!(
+            cache
+                .chart("AAPL", Interval::OneDay, TimeRange::OneMonth)
+                .await
+                .is_none()
+        );
+
+        cache
+            .put_chart("AAPL", Interval::OneDay, TimeRange::OneMonth, &chart())
+            .await;
+        let cached = cache
+            .chart("AAPL", Interval::OneDay, TimeRange::OneMonth)
+            .await
+            .unwrap();
+        assert_eq!(cached.candles.len(), 1);
+        assert_eq!(cached.candles[0].close, 1.5);
+        assert!(
+            cache
+                .chart("AAPL", Interval::OneWeek, TimeRange::OneMonth)
+                .await
+                .is_none()
+        );
+
+        cache.clear("AAPL").unwrap();
+        assert!(
+            cache
+                .chart("AAPL", Interval::OneDay, TimeRange::OneMonth)
+                .await
+                .is_none()
+        );
+        std::fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_ttl_and_prune() {
+        let cache = cache("ttl").with_ttl(Duration::ZERO);
+        cache
+            .put_chart("MSFT", Interval::OneDay, TimeRange::OneMonth, &chart())
+            .await;
+        assert!(
+            cache
+                .chart("MSFT", Interval::OneDay, TimeRange::OneMonth)
+                .await
+                .is_none()
+        );
+
+        let stale = cache.dir().join("MSFT").join("quote_2020-01-01.json");
+        std::fs::write(&stale, "{}").unwrap();
+        assert_eq!(cache.prune().unwrap(), 2);
+        std::fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    #[test]
+    fn test_prune_removes_leftover_temp_files() {
+        let cache = cache("tmp");
+        let dir = cache.dir().join("TSLA");
+        std::fs::create_dir_all(&dir).unwrap();
+        let leftover = dir.join("quote_2020-01-01.json.tmp");
+        std::fs::write(&leftover, "{").unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&leftover)
+            .unwrap()
+            .set_modified(SystemTime::now() - 2 * TMP_GRACE)
+            .unwrap();
+        let in_progress = dir.join("chart_1d_1mo_2020-01-01.json.tmp");
+        std::fs::write(&in_progress, "{").unwrap();
+
+        assert_eq!(cache.prune().unwrap(), 1);
+        assert!(!leftover.exists());
+        assert!(in_progress.exists());
+        std::fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_corrupt_entry_and_invalid_key() {
+        let cache = cache("corrupt");
+        let path = cache
+            .chart_path("NVDA", Interval::OneDay, TimeRange::OneMonth)
+            .unwrap();
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "not json").unwrap();
+        assert!(
+            cache
+                .chart("NVDA", Interval::OneDay, TimeRange::OneMonth)
+                .await
+                .is_none()
+        );
+        assert!(cache.clear("../etc").is_err());
+        assert!(cache.clear("").is_err());
+        std::fs::remove_dir_all(cache.dir()).unwrap();
+    }
+}
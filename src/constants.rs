@@ -2,11 +2,16 @@ use serde::{Deserialize, Serialize};
 
 /// Predefined screener selectors for Yahoo Finance
 pub mod screeners {
+    use super::Region;
+    use crate::models::discovery::screeners::{EquityField, EquityScreenerQuery, ScreenerFieldExt};
+    use serde::{Deserialize, Serialize};
+
     /// Predefined Yahoo Finance screener selector
     ///
     /// Passed to `finance::screener()` or `client.get_screener()` to select one of the
     /// 15 built-in Yahoo Finance screeners (equity or fund).
-    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    #[serde(rename_all = "kebab-case")]
     pub enum Screener {
         // Equity screeners
         /// Small caps with high EPS growth, sorted by volume
@@ -106,6 +111,58 @@ pub mod screeners {
                 Screener::TopMutualFunds,
             ]
         }
+
+        /// Build this screener's criteria as a custom query restricted to `region`.
+        ///
+        /// Yahoo's predefined screeners only cover US listings. The day gainers,
+        /// day losers, and most actives presets are rebuilt as custom queries
+        /// with a region filter; every other screener returns `None`.
+        ///
+        /// The US screeners' share-price and market-cap floors are in dollars,
+        /// while Yahoo reports these fields in the listing's local currency, so
+        /// the regional presets filter on percent change and volume only.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use finance_query::{Region, Screener};
+        ///
+        /// assert!(Screener::regional(Screener::DayGainers, Region::Japan).is_some());
+        /// assert!(Screener::regional(Screener::HighYieldBond, Region::Japan).is_none());
+        /// ```
+        pub fn regional(self, region: Region) -> Option<EquityScreenerQuery> {
+            let query =
+                EquityScreenerQuery::new().add_condition(EquityField::Region.eq_str(region));
+            match self {
+                Screener::DayGainers => Some(
+                    query
+                        .sort_by(EquityField::PercentChange, false)
+                        .add_condition(EquityField::PercentChange.gt(3.0))
+                        .add_condition(EquityField::DayVolume.gt(15_000.0)),
+                ),
+                Screener::DayLosers => Some(
+                    query
+                        .sort_by(EquityField::PercentChange, true)
+                        .add_condition(EquityField::PercentChange.lt(-2.5))
+                        .add_condition(EquityField::DayVolume.gt(15_000.0)),
+                ),
+                Screener::MostActives => Some(
+                    query
+                        .sort_by(EquityField::DayVolume, false)
+                        .add_condition(EquityField::DayVolume.gt(0.0)),
+                ),
+                _ => None,
+            }
+        }
+
+        /// Screeners with a [`regional`](Self::regional) equivalent.
+        pub fn regional_types() -> &'static [Screener] {
+            &[
+                Screener::DayGainers,
+                Screener::DayLosers,
+                Screener::MostActives,
+            ]
+        }
     }
 
     impl std::str::FromStr for Screener {
@@ -214,6 +271,21 @@ pub mod sectors {
              real-estate, utilities"
         }
 
+        /// Industries in this sector, in [`Industry::ALL`](super::industries::Industry::ALL) order.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use finance_query::{Industry, Sector};
+        /// assert!(Sector::Technology.industries().contains(&Industry::Semiconductors));
+        /// ```
+        pub fn industries(self) -> Vec<super::industries::Industry> {
+            super::industries::Industry::ALL
+                .into_iter()
+                .filter(|i| i.sector() == self)
+                .collect()
+        }
+
         /// Get all sector types as an array
         pub fn all() -> &'static [Sector] {
             &[
@@ -796,6 +868,9 @@ pub enum Interval {
     /// 1 minute
     #[serde(rename = "1m")]
     OneMinute,
+    /// 2 minutes
+    #[serde(rename = "2m")]
+    TwoMinutes,
     /// 5 minutes
     #[serde(rename = "5m")]
     FiveMinutes,
@@ -808,6 +883,12 @@ pub enum Interval {
     /// 1 hour
     #[serde(rename = "1h")]
     OneHour,
+    /// 90 minutes
+    #[serde(rename = "90m")]
+    NinetyMinutes,
+    /// 4 hours (built from 1 hour bars; Yahoo has no native 4h granularity)
+    #[serde(rename = "4h")]
+    FourHours,
     /// 1 day
     #[serde(rename = "1d")]
     OneDay,
@@ -827,16 +908,95 @@ impl Interval {
     pub fn as_str(&self) -> &'static str {
         match self {
             Interval::OneMinute => "1m",
+            Interval::TwoMinutes => "2m",
             Interval::FiveMinutes => "5m",
             Interval::FifteenMinutes => "15m",
             Interval::ThirtyMinutes => "30m",
             Interval::OneHour => "1h",
+            Interval::NinetyMinutes => "90m",
+            Interval::FourHours => "4h",
             Interval::OneDay => "1d",
             Interval::OneWeek => "1wk",
             Interval::OneMonth => "1mo",
             Interval::ThreeMonths => "3mo",
         }
     }
+
+    /// Approximate length of one bar in seconds.
+    ///
+    /// Calendar approximations: a month is 30 days, a quarter 90.
+    pub const fn approx_duration_secs(&self) -> i64 {
+        match self {
+            Interval::OneMinute => 60,
+            Interval::TwoMinutes => 120,
+            Interval::FiveMinutes => 300,
+            Interval::FifteenMinutes => 900,
+            Interval::ThirtyMinutes => 1_800,
+            Interval::OneHour => 3_600,
+            Interval::NinetyMinutes => 5_400,
+            Interval::FourHours => 14_400,
+            Interval::OneDay => 86_400,
+            Interval::OneWeek => 604_800,
+            Interval::OneMonth => 2_592_000,
+            Interval::ThreeMonths => 7_776_000,
+        }
+    }
+
+    /// Every interval, finest first.
+    pub const ALL: [Interval; 12] = [
+        Interval::OneMinute,
+        Interval::TwoMinutes,
+        Interval::FiveMinutes,
+        Interval::FifteenMinutes,
+        Interval::ThirtyMinutes,
+        Interval::OneHour,
+        Interval::NinetyMinutes,
+        Interval::FourHours,
+        Interval::OneDay,
+        Interval::OneWeek,
+        Interval::OneMonth,
+        Interval::ThreeMonths,
+    ];
+
+    /// Interval to request from Yahoo when it has no native granularity for
+    /// `self`, or `None` when Yahoo serves `self` directly.
+    ///
+    /// Bars at the base interval are resampled into `self`
+    /// (see [`Chart::resample`](crate::Chart::resample)).
+    pub const fn resample_base(&self) -> Option<Interval> {
+        match self {
+            Interval::FourHours => Some(Interval::OneHour),
+            _ => None,
+        }
+    }
+
+    /// Whether bars are fetched at a finer interval and resampled locally.
+    pub const fn is_resampled(&self) -> bool {
+        self.resample_base().is_some()
+    }
+
+    /// Whether bars are shorter than a day.
+    pub const fn is_intraday(&self) -> bool {
+        self.approx_duration_secs() < 86_400
+    }
+}
+
+impl TryFrom<std::time::Duration> for Interval {
+    type Error = ();
+
+    /// Maps an exact bar length to its interval: `Duration::from_secs(4 * 3600)`
+    /// is [`Interval::FourHours`]. Months and quarters use the 30/90-day
+    /// approximations of [`Interval::approx_duration_secs`]; anything else fails.
+    fn try_from(duration: std::time::Duration) -> Result<Self, Self::Error> {
+        if duration.subsec_nanos() != 0 {
+            return Err(());
+        }
+        let secs = i64::try_from(duration.as_secs()).map_err(|_| ())?;
+        Interval::ALL
+            .into_iter()
+            .find(|i| i.approx_duration_secs() == secs)
+            .ok_or(())
+    }
 }
 
 impl std::fmt::Display for Interval {
@@ -853,10 +1013,13 @@ impl std::str::FromStr for Interval {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.trim().to_lowercase().as_str() {
             "1m" => Ok(Interval::OneMinute),
+            "2m" => Ok(Interval::TwoMinutes),
             "5m" => Ok(Interval::FiveMinutes),
             "15m" => Ok(Interval::FifteenMinutes),
             "30m" => Ok(Interval::ThirtyMinutes),
-            "1h" => Ok(Interval::OneHour),
+            "1h" | "60m" => Ok(Interval::OneHour),
+            "90m" => Ok(Interval::NinetyMinutes),
+            "4h" => Ok(Interval::FourHours),
             "1d" => Ok(Interval::OneDay),
             "1wk" => Ok(Interval::OneWeek),
             "1mo" => Ok(Interval::OneMonth),
@@ -1341,30 +1504,67 @@ impl ValueFormat {
     pub fn transform(&self, value: serde_json::Value) -> serde_json::Value {
         match self {
             ValueFormat::Both => value, // No transformation needed
-            _ => self.transform_recursive(value),
+            _ => self.transform_recursive(value, None),
+        }
+    }
+
+    /// Transform a JSON value, re-rendering `fmt`/`longFmt` strings with
+    /// `options` first.
+    ///
+    /// With default options this is identical to [`transform`](Self::transform).
+    /// `Raw` output ignores the options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use finance_query::{FormatOptions, ValueFormat};
+    /// use serde_json::json;
+    ///
+    /// let data = json!({"marketCap": {"raw": 3.45e12, "fmt": "3.45T"}});
+    /// let opts = FormatOptions::new().decimals(1);
+    /// assert_eq!(
+    ///     ValueFormat::Pretty.transform_with(data, &opts),
+    ///     json!({"marketCap": "3.5T"})
+    /// );
+    /// ```
+    pub fn transform_with(
+        &self,
+        value: serde_json::Value,
+        options: &crate::FormatOptions,
+    ) -> serde_json::Value {
+        if options.is_default() || *self == ValueFormat::Raw {
+            return self.transform(value);
         }
+        self.transform_recursive(value, Some(options))
     }
 
-    fn transform_recursive(&self, value: serde_json::Value) -> serde_json::Value {
+    fn transform_recursive(
+        &self,
+        value: serde_json::Value,
+        options: Option<&crate::FormatOptions>,
+    ) -> serde_json::Value {
         use serde_json::Value;
 
         match value {
-            Value::Object(map) => {
+            Value::Object(mut map) => {
                 // Check if this looks like a FormattedValue (has 'raw' key)
                 if self.is_formatted_value(&map) {
+                    if let Some(options) = options {
+                        Self::reformat(&mut map, options);
+                    }
                     return self.extract_value(&map);
                 }
 
                 // Otherwise, recursively transform all values
                 let transformed: serde_json::Map<String, Value> = map
                     .into_iter()
-                    .map(|(k, v)| (k, self.transform_recursive(v)))
+                    .map(|(k, v)| (k, self.transform_recursive(v, options)))
                     .collect();
                 Value::Object(transformed)
             }
             Value::Array(arr) => Value::Array(
                 arr.into_iter()
-                    .map(|v| self.transform_recursive(v))
+                    .map(|v| self.transform_recursive(v, options))
                     .collect(),
             ),
             // Primitives pass through unchanged
@@ -1372,6 +1572,27 @@ impl ValueFormat {
         }
     }
 
+    /// Rewrite `fmt` (with configured units) and `longFmt` (always in full)
+    /// from `raw`. Strings that aren't numeric, like dates, are kept.
+    fn reformat(
+        map: &mut serde_json::Map<String, serde_json::Value>,
+        options: &crate::FormatOptions,
+    ) {
+        let Some(raw) = map.get("raw").and_then(|v| v.as_f64()) else {
+            return;
+        };
+        let full = options.units(crate::UnitStyle::Full);
+        for (key, opts) in [("fmt", options), ("longFmt", &full)] {
+            if let Some(rendered) = map
+                .get(key)
+                .and_then(|v| v.as_str())
+                .and_then(|s| opts.reformat(raw, s))
+            {
+                map.insert(key.to_string(), serde_json::Value::String(rendered));
+            }
+        }
+    }
+
     /// Check if an object looks like a FormattedValue
     fn is_formatted_value(&self, map: &serde_json::Map<String, serde_json::Value>) -> bool {
         // Must have 'raw' key (can be null)
@@ -1438,6 +1659,8 @@ impl ValueFormat {
 /// # }
 /// ```
 pub mod industries {
+    use super::sectors::Sector;
+
     /// Typed industry identifier for the industry endpoint and custom screener queries.
     ///
     /// See the module-level doc for usage.
@@ -1754,6 +1977,353 @@ pub mod industries {
     }
 
     impl Industry {
+        /// Every industry, in declaration order.
+        pub const ALL: [Industry; 148] = [
+            Industry::AgriculturalInputs,
+            Industry::Aluminum,
+            Industry::Coal,
+            Industry::Copper,
+            Industry::FarmProducts,
+            Industry::ForestProducts,
+            Industry::Gold,
+            Industry::LumberAndWoodProduction,
+            Industry::OtherIndustrialMetalsAndMining,
+            Industry::OtherPreciousMetalsAndMining,
+            Industry::Silver,
+            Industry::Steel,
+            Industry::ThermalCoal,
+            Industry::Uranium,
+            Industry::ApparelManufacturing,
+            Industry::ApparelRetail,
+            Industry::AutoAndTruckDealerships,
+            Industry::AutoManufacturers,
+            Industry::AutoParts,
+            Industry::BeveragesBrewers,
+            Industry::BeveragesNonAlcoholic,
+            Industry::BeveragesWineriesAndDistilleries,
+            Industry::Confectioners,
+            Industry::DepartmentStores,
+            Industry::DiscountStores,
+            Industry::ElectronicGamingAndMultimedia,
+            Industry::FoodDistribution,
+            Industry::FootwearAndAccessories,
+            Industry::FurnishingsFixturesAndAppliances,
+            Industry::Gambling,
+            Industry::GroceryStores,
+            Industry::HomeImprovementRetail,
+            Industry::HouseholdAndPersonalProducts,
+            Industry::InternetRetail,
+            Industry::Leisure,
+            Industry::Lodging,
+            Industry::LuxuryGoods,
+            Industry::PackagedFoods,
+            Industry::PersonalServices,
+            Industry::ResidentialConstruction,
+            Industry::ResortsAndCasinos,
+            Industry::Restaurants,
+            Industry::SpecialtyRetail,
+            Industry::TextileManufacturing,
+            Industry::Tobacco,
+            Industry::TravelServices,
+            Industry::OilAndGasDrilling,
+            Industry::OilAndGasEAndP,
+            Industry::OilAndGasEquipmentAndServices,
+            Industry::OilAndGasIntegrated,
+            Industry::OilAndGasMidstream,
+            Industry::OilAndGasRefiningAndMarketing,
+            Industry::Solar,
+            Industry::AssetManagement,
+            Industry::BanksDiversified,
+            Industry::BanksRegional,
+            Industry::CapitalMarkets,
+            Industry::CreditServices,
+            Industry::FinancialDataAndStockExchanges,
+            Industry::InsuranceBrokers,
+            Industry::InsuranceDiversified,
+            Industry::InsuranceLife,
+            Industry::InsurancePropertyAndCasualty,
+            Industry::InsuranceReinsurance,
+            Industry::InsuranceSpecialty,
+            Industry::MortgageFinance,
+            Industry::ShellCompanies,
+            Industry::Biotechnology,
+            Industry::DiagnosticsAndResearch,
+            Industry::DrugManufacturersGeneral,
+            Industry::DrugManufacturersSpecialtyAndGeneric,
+            Industry::HealthInformationServices,
+            Industry::HealthcarePlans,
+            Industry::MedicalCareFacilities,
+            Industry::MedicalDevices,
+            Industry::MedicalDistribution,
+            Industry::MedicalInstrumentsAndSupplies,
+            Industry::PharmaceuticalRetailers,
+            Industry::AerospaceAndDefense,
+            Industry::BuildingMaterials,
+            Industry::BuildingProductsAndEquipment,
+            Industry::BusinessEquipmentAndSupplies,
+            Industry::ChemicalManufacturing,
+            Industry::Chemicals,
+            Industry::Conglomerates,
+            Industry::ConsultingServices,
+            Industry::ElectricalEquipmentAndParts,
+            Industry::EngineeringAndConstruction,
+            Industry::FarmAndHeavyConstructionMachinery,
+            Industry::IndustrialDistribution,
+            Industry::InfrastructureOperations,
+            Industry::IntegratedFreightAndLogistics,
+            Industry::ManufacturingDiversified,
+            Industry::MarinePortsAndServices,
+            Industry::MarineShipping,
+            Industry::MetalFabrication,
+            Industry::PaperAndPaperProducts,
+            Industry::PollutionAndTreatmentControls,
+            Industry::Railroads,
+            Industry::RentalAndLeasingServices,
+            Industry::SecurityAndProtectionServices,
+            Industry::SpecialtyBusinessServices,
+            Industry::SpecialtyChemicals,
+            Industry::SpecialtyIndustrialMachinery,
+            Industry::StaffingAndEmploymentServices,
+            Industry::ToolsAndAccessories,
+            Industry::Trucking,
+            Industry::WasteManagement,
+            Industry::RealEstateDevelopment,
+            Industry::RealEstateDiversified,
+            Industry::RealEstateServices,
+            Industry::ReitDiversified,
+            Industry::ReitHealthcareFacilities,
+            Industry::ReitHotelAndMotel,
+            Industry::ReitIndustrial,
+            Industry::ReitMortgage,
+            Industry::ReitOffice,
+            Industry::ReitResidential,
+            Industry::ReitRetail,
+            Industry::ReitSpecialty,
+            Industry::CommunicationEquipment,
+            Industry::ComputerHardware,
+            Industry::ConsumerElectronics,
+            Industry::DataAnalytics,
+            Industry::ElectronicComponents,
+            Industry::ElectronicsAndComputerDistribution,
+            Industry::HardwareAndSoftwareDistribution,
+            Industry::InformationTechnologyServices,
+            Industry::InternetContentAndInformation,
+            Industry::ScientificAndTechnicalInstruments,
+            Industry::SemiconductorEquipmentAndMaterials,
+            Industry::Semiconductors,
+            Industry::SoftwareApplication,
+            Industry::SoftwareInfrastructure,
+            Industry::Broadcasting,
+            Industry::Entertainment,
+            Industry::Publishing,
+            Industry::TelecomServices,
+            Industry::UtilitiesDiversified,
+            Industry::UtilitiesIndependentPowerProducers,
+            Industry::UtilitiesRegulatedElectric,
+            Industry::UtilitiesRegulatedGas,
+            Industry::UtilitiesRegulatedWater,
+            Industry::UtilitiesRenewable,
+            Industry::ClosedEndFundDebt,
+            Industry::ClosedEndFundEquity,
+            Industry::ClosedEndFundForeign,
+            Industry::ExchangeTradedFund,
+        ];
+
+        /// The Yahoo Finance sector this industry belongs to.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use finance_query::{Industry, Sector};
+        /// assert_eq!(Industry::Semiconductors.sector(), Sector::Technology);
+        /// assert_eq!(Industry::ReitOffice.sector(), Sector::RealEstate);
+        /// ```
+        pub fn sector(self) -> Sector {
+            match self {
+                Industry::AgriculturalInputs
+                | Industry::Aluminum
+                | Industry::Coal
+                | Industry::Copper
+                | Industry::ForestProducts
+                | Industry::Gold
+                | Industry::LumberAndWoodProduction
+                | Industry::OtherIndustrialMetalsAndMining
+                | Industry::OtherPreciousMetalsAndMining
+                | Industry::Silver
+                | Industry::Steel
+                | Industry::BuildingMaterials
+                | Industry::ChemicalManufacturing
+                | Industry::Chemicals
+                | Industry::PaperAndPaperProducts
+                | Industry::SpecialtyChemicals => Sector::BasicMaterials,
+                Industry::ApparelManufacturing
+                | Industry::ApparelRetail
+                | Industry::AutoAndTruckDealerships
+                | Industry::AutoManufacturers
+                | Industry::AutoParts
+                | Industry::DepartmentStores
+                | Industry::FootwearAndAccessories
+                | Industry::FurnishingsFixturesAndAppliances
+                | Industry::Gambling
+                | Industry::HomeImprovementRetail
+                | Industry::InternetRetail
+                | Industry::Leisure
+                | Industry::Lodging
+                | Industry::LuxuryGoods
+                | Industry::PersonalServices
+                | Industry::ResidentialConstruction
+                | Industry::ResortsAndCasinos
+                | Industry::Restaurants
+                | Industry::SpecialtyRetail
+                | Industry::TextileManufacturing
+                | Industry::TravelServices => Sector::ConsumerCyclical,
+                Industry::BeveragesBrewers
+                | Industry::BeveragesNonAlcoholic
+                | Industry::BeveragesWineriesAndDistilleries
+                | Industry::Confectioners
+                | Industry::DiscountStores
+                | Industry::FarmProducts
+                | Industry::FoodDistribution
+                | Industry::GroceryStores
+                | Industry::HouseholdAndPersonalProducts
+                | Industry::PackagedFoods
+                | Industry::Tobacco => Sector::ConsumerDefensive,
+                Industry::Broadcasting
+                | Industry::ElectronicGamingAndMultimedia
+                | Industry::Entertainment
+                | Industry::InternetContentAndInformation
+                | Industry::Publishing
+                | Industry::TelecomServices => Sector::CommunicationServices,
+                Industry::OilAndGasDrilling
+                | Industry::OilAndGasEAndP
+                | Industry::OilAndGasEquipmentAndServices
+                | Industry::OilAndGasIntegrated
+                | Industry::OilAndGasMidstream
+                | Industry::OilAndGasRefiningAndMarketing
+                | Industry::ThermalCoal
+                | Industry::Uranium => Sector::Energy,
+                Industry::AssetManagement
+                | Industry::BanksDiversified
+                | Industry::BanksRegional
+                | Industry::CapitalMarkets
+                | Industry::CreditServices
+                | Industry::FinancialDataAndStockExchanges
+                | Industry::InsuranceBrokers
+                | Industry::InsuranceDiversified
+                | Industry::InsuranceLife
+                | Industry::InsurancePropertyAndCasualty
+                | Industry::InsuranceReinsurance
+                | Industry::InsuranceSpecialty
+                | Industry::MortgageFinance
+                | Industry::ShellCompanies
+                | Industry::ClosedEndFundDebt
+                | Industry::ClosedEndFundEquity
+                | Industry::ClosedEndFundForeign
+                | Industry::ExchangeTradedFund => Sector::FinancialServices,
+                Industry::Biotechnology
+                | Industry::DiagnosticsAndResearch
+                | Industry::DrugManufacturersGeneral
+                | Industry::DrugManufacturersSpecialtyAndGeneric
+                | Industry::HealthInformationServices
+                | Industry::HealthcarePlans
+                | Industry::MedicalCareFacilities
+                | Industry::MedicalDevices
+                | Industry::MedicalDistribution
+                | Industry::MedicalInstrumentsAndSupplies
+                | Industry::PharmaceuticalRetailers => Sector::Healthcare,
+                Industry::AerospaceAndDefense
+                | Industry::BuildingProductsAndEquipment
+                | Industry::BusinessEquipmentAndSupplies
+                | Industry::Conglomerates
+                | Industry::ConsultingServices
+                | Industry::ElectricalEquipmentAndParts
+                | Industry::EngineeringAndConstruction
+                | Industry::FarmAndHeavyConstructionMachinery
+                | Industry::IndustrialDistribution
+                | Industry::InfrastructureOperations
+                | Industry::IntegratedFreightAndLogistics
+                | Industry::ManufacturingDiversified
+                | Industry::MarinePortsAndServices
+                | Industry::MarineShipping
+                | Industry::MetalFabrication
+                | Industry::PollutionAndTreatmentControls
+                | Industry::Railroads
+                | Industry::RentalAndLeasingServices
+                | Industry::SecurityAndProtectionServices
+                | Industry::SpecialtyBusinessServices
+                | Industry::SpecialtyIndustrialMachinery
+                | Industry::StaffingAndEmploymentServices
+                | Industry::ToolsAndAccessories
+                | Industry::Trucking
+                | Industry::WasteManagement => Sector::Industrials,
+                Industry::RealEstateDevelopment
+                | Industry::RealEstateDiversified
+                | Industry::RealEstateServices
+                | Industry::ReitDiversified
+                | Industry::ReitHealthcareFacilities
+                | Industry::ReitHotelAndMotel
+                | Industry::ReitIndustrial
+                | Industry::ReitMortgage
+                | Industry::ReitOffice
+                | Industry::ReitResidential
+                | Industry::ReitRetail
+                | Industry::ReitSpecialty => Sector::RealEstate,
+                Industry::CommunicationEquipment
+                | Industry::ComputerHardware
+                | Industry::ConsumerElectronics
+                | Industry::DataAnalytics
+                | Industry::ElectronicComponents
+                | Industry::ElectronicsAndComputerDistribution
+                | Industry::HardwareAndSoftwareDistribution
+                | Industry::InformationTechnologyServices
+                | Industry::ScientificAndTechnicalInstruments
+                | Industry::SemiconductorEquipmentAndMaterials
+                | Industry::Semiconductors
+                | Industry::SoftwareApplication
+                | Industry::SoftwareInfrastructure
+                | Industry::Solar => Sector::Technology,
+                Industry::UtilitiesDiversified
+                | Industry::UtilitiesIndependentPowerProducers
+                | Industry::UtilitiesRegulatedElectric
+                | Industry::UtilitiesRegulatedGas
+                | Industry::UtilitiesRegulatedWater
+                | Industry::UtilitiesRenewable => Sector::Utilities,
+            }
+        }
+
+        /// Industries matching a free-text query, best match first.
+        ///
+        /// Matches slugs and display names ignoring case and punctuation, so
+        /// `"semiconductors"`, `"Semiconductor"`, and `"oil gas"` all work.
+        /// Ranking: exact match, then prefix, then substring, then all query
+        /// words prefixing words of the name, then near-misses within two edits
+        /// (typos such as `"semiconducters"`); shorter names win ties. Empty
+        /// when nothing is close.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use finance_query::Industry;
+        /// let hits = Industry::lookup("semiconductor");
+        /// assert_eq!(hits[0], Industry::Semiconductors);
+        /// assert!(hits.contains(&Industry::SemiconductorEquipmentAndMaterials));
+        /// assert_eq!(Industry::lookup("semiconducters")[0], Industry::Semiconductors);
+        /// ```
+        pub fn lookup(query: &str) -> Vec<Industry> {
+            let query = normalize(query);
+            if query.is_empty() {
+                return Vec::new();
+            }
+            let mut hits: Vec<(usize, Industry)> = Industry::ALL
+                .iter()
+                .filter_map(|&industry| match_rank(&query, industry).map(|rank| (rank, industry)))
+                .collect();
+            hits.sort_by_key(|&(rank, industry)| {
+                (rank, industry.as_slug().len(), industry.as_slug())
+            });
+            hits.into_iter().map(|(_, industry)| industry).collect()
+        }
+
         /// Returns the lowercase hyphenated slug used by `finance::industry()`.
         ///
         /// # Example
@@ -2093,6 +2663,75 @@ pub mod industries {
         }
     }
 
+    impl std::str::FromStr for Industry {
+        type Err = ();
+
+        /// Parses a slug or display name, ignoring case and punctuation
+        /// (`"semiconductors"`, `"Oil & Gas E&P"`, `"oil_gas_ep"`).
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let key = normalize(s);
+            Industry::ALL
+                .into_iter()
+                .find(|i| normalize(i.as_slug()) == key || normalize(i.screener_value()) == key)
+                .ok_or(())
+        }
+    }
+
+    /// Lowercase words separated by single spaces, with `&` and other
+    /// punctuation treated as separators.
+    fn normalize(s: &str) -> String {
+        s.split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(str::to_ascii_lowercase)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn match_rank(query: &str, industry: Industry) -> Option<usize> {
+        let names = [
+            normalize(industry.as_slug()),
+            normalize(industry.screener_value()),
+        ];
+        let query_words: Vec<&str> = query.split(' ').collect();
+        names
+            .iter()
+            .filter_map(|name| {
+                if name == query {
+                    Some(0)
+                } else if name.starts_with(query) {
+                    Some(1)
+                } else if name.contains(query) {
+                    Some(2)
+                } else if query_words
+                    .iter()
+                    .all(|q| name.split(' ').any(|w| w.starts_with(q)))
+                {
+                    Some(3)
+                } else {
+                    let distance = edit_distance(query, name);
+                    (distance <= 2).then_some(4 + distance)
+                }
+            })
+            .min()
+    }
+
+    fn edit_distance(a: &str, b: &str) -> usize {
+        let b: Vec<char> = b.chars().collect();
+        let mut row: Vec<usize> = (0..=b.len()).collect();
+        for (i, ca) in a.chars().enumerate() {
+            let mut prev = row[0];
+            row[0] = i + 1;
+            for (j, &cb) in b.iter().enumerate() {
+                let next = (prev + usize::from(ca != cb))
+                    .min(row[j] + 1)
+                    .min(row[j + 1] + 1);
+                prev = row[j + 1];
+                row[j + 1] = next;
+            }
+        }
+        row[b.len()]
+    }
+
     impl AsRef<str> for Industry {
         /// Returns the slug, enabling `finance::industry(Industry::Semiconductors)`.
         fn as_ref(&self) -> &str {
@@ -2211,8 +2850,33 @@ pub mod exchange_codes {
 
 #[cfg(test)]
 mod tests {
+    use super::screeners::Screener;
     use super::*;
 
+    #[test]
+    fn test_screener_regional_presets() {
+        for &screener in Screener::regional_types() {
+            let query = screener.regional(Region::Germany).unwrap();
+            let json = serde_json::to_value(&query).unwrap();
+            let region = &json["query"]["operands"][0];
+            assert_eq!(region["operator"], "eq");
+            assert_eq!(region["operands"][0], "region");
+            assert_eq!(region["operands"][1], "de");
+        }
+        assert_eq!(
+            Screener::regional(Screener::DayLosers, Region::Japan)
+                .unwrap()
+                .sort_type,
+            crate::SortType::Asc
+        );
+        assert!(
+            Screener::MostShortedStocks
+                .regional(Region::India)
+                .is_none()
+        );
+        assert!(Screener::TopMutualFunds.regional(Region::India).is_none());
+    }
+
     #[test]
     fn test_interval_as_str() {
         assert_eq!(Interval::OneMinute.as_str(), "1m");
@@ -2256,24 +2920,90 @@ mod tests {
 
     #[test]
     fn test_interval_from_str_round_trips_as_str() {
-        for interval in [
-            Interval::OneMinute,
-            Interval::FiveMinutes,
-            Interval::FifteenMinutes,
-            Interval::ThirtyMinutes,
-            Interval::OneHour,
-            Interval::OneDay,
-            Interval::OneWeek,
-            Interval::OneMonth,
-            Interval::ThreeMonths,
-        ] {
+        for interval in Interval::ALL {
             assert_eq!(interval.as_str().parse(), Ok(interval));
         }
         assert_eq!("1D".parse(), Ok(Interval::OneDay));
         assert_eq!(" 1d ".parse(), Ok(Interval::OneDay));
+        assert_eq!("60m".parse(), Ok(Interval::OneHour));
         assert_eq!("bogus".parse::<Interval>(), Err(()));
     }
 
+    #[test]
+    fn test_industry_from_str_and_sector_grouping() {
+        use industries::Industry;
+        use sectors::Sector;
+
+        for industry in Industry::ALL {
+            assert_eq!(industry.as_slug().parse(), Ok(industry));
+            assert_eq!(industry.screener_value().parse(), Ok(industry));
+        }
+        assert_eq!("Oil & Gas E&P".parse(), Ok(Industry::OilAndGasEAndP));
+        assert_eq!(
+            "SOFTWARE_APPLICATION".parse(),
+            Ok(Industry::SoftwareApplication)
+        );
+        assert_eq!("bogus".parse::<Industry>(), Err(()));
+
+        let grouped: usize = Sector::all().iter().map(|s| s.industries().len()).sum();
+        assert_eq!(grouped, Industry::ALL.len());
+        assert!(Sector::all().iter().all(|s| !s.industries().is_empty()));
+    }
+
+    #[test]
+    fn test_industry_lookup_ranking() {
+        use industries::Industry;
+
+        assert_eq!(
+            Industry::lookup("semiconductor"),
+            [
+                Industry::Semiconductors,
+                Industry::SemiconductorEquipmentAndMaterials
+            ]
+        );
+        assert_eq!(Industry::lookup("reit office")[0], Industry::ReitOffice);
+        assert_eq!(Industry::lookup("biotechnolgy"), [Industry::Biotechnology]);
+        assert!(Industry::lookup("oil gas").len() >= 6);
+        assert!(Industry::lookup("").is_empty());
+        assert!(Industry::lookup("xyzzy").is_empty());
+    }
+
+    #[test]
+    fn test_interval_try_from_duration() {
+        use std::time::Duration;
+        assert_eq!(
+            Interval::try_from(Duration::from_secs(120)),
+            Ok(Interval::TwoMinutes)
+        );
+        assert_eq!(
+            Interval::try_from(Duration::from_secs(90 * 60)),
+            Ok(Interval::NinetyMinutes)
+        );
+        assert_eq!(
+            Interval::try_from(Duration::from_secs(4 * 3600)),
+            Ok(Interval::FourHours)
+        );
+        assert_eq!(
+            Interval::try_from(Duration::from_secs(7 * 86_400)),
+            Ok(Interval::OneWeek)
+        );
+        assert_eq!(Interval::try_from(Duration::from_secs(7)), Err(()));
+        assert_eq!(Interval::try_from(Duration::from_millis(60_500)), Err(()));
+        for interval in Interval::ALL {
+            let secs = interval.approx_duration_secs() as u64;
+            assert_eq!(Interval::try_from(Duration::from_secs(secs)), Ok(interval));
+        }
+    }
+
+    #[test]
+    fn test_interval_resample_base() {
+        assert_eq!(Interval::FourHours.resample_base(), Some(Interval::OneHour));
+        assert!(Interval::FourHours.is_resampled());
+        assert!(!Interval::NinetyMinutes.is_resampled());
+        assert!(Interval::NinetyMinutes.is_intraday());
+        assert!(!Interval::OneDay.is_intraday());
+    }
+
     #[test]
     fn test_time_range_from_str_round_trips_as_str() {
         for range in [
@@ -2309,4 +3039,40 @@ mod tests {
         assert_eq!(TimeRange::TwoYears.default_interval(), Interval::OneWeek);
         assert_eq!(TimeRange::Max.default_interval(), Interval::OneMonth);
     }
+
+    #[test]
+    fn test_value_format_transform_with() {
+        use serde_json::json;
+
+        let data = json!({
+            "marketCap": {"raw": 3.45e12, "fmt": "3.45T", "longFmt": "3,450,000,000,000"},
+            "margin": {"raw": 0.2549, "fmt": "25.49%"},
+            "exDividendDate": {"raw": 1764806400, "fmt": "2025-12-04"},
+            "name": "Apple"
+        });
+        let opts = crate::FormatOptions::new()
+            .locale("de-DE")
+            .percent_style(crate::PercentStyle::BasisPoints);
+
+        assert_eq!(
+            ValueFormat::Pretty.transform_with(data.clone(), &opts),
+            json!({
+                "marketCap": "3,45T",
+                "margin": "2.549 bp",
+                "exDividendDate": "2025-12-04",
+                "name": "Apple"
+            })
+        );
+        let both = ValueFormat::Both.transform_with(data.clone(), &opts);
+        assert_eq!(both["marketCap"]["longFmt"], "3.450.000.000.000");
+        assert_eq!(both["marketCap"]["raw"], 3.45e12);
+        assert_eq!(
+            ValueFormat::Raw.transform_with(data.clone(), &opts),
+            ValueFormat::Raw.transform(data.clone())
+        );
+        assert_eq!(
+            ValueFormat::Pretty.transform_with(data.clone(), &crate::FormatOptions::new()),
+            ValueFormat::Pretty.transform(data)
+        );
+    }
 }
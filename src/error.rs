@@ -77,6 +77,15 @@ pub enum FinanceError {
         context: String,
     },
 
+    /// Response body exceeded the configured size or decompression ratio limit
+    #[error("Response too large (limit {limit} bytes): {context}")]
+    ResponseTooLarge {
+        /// Limit that was exceeded, in bytes
+        limit: u64,
+        /// Which limit was hit
+        context: String,
+    },
+
     /// Unexpected API response
     #[error("Unexpected response: {0}")]
     UnexpectedResponse(String),
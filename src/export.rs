@@ -0,0 +1,134 @@
+//! Write DataFrames to CSV and Parquet files (requires the `export` feature).
+//!
+//! [`DataFrameExport`] adds file writers to any polars `DataFrame`, including
+//! those from `to_dataframe()` / `vec_to_dataframe()`. [`Chart`](crate::Chart)
+//! also has `to_csv` / `to_parquet` shortcuts.
+//!
+//! ```no_run
+//! use finance_query::export::DataFrameExport;
+//! use finance_query::{Interval, Quote, Ticker, Tickers, TimeRange};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let chart = Ticker::new("AAPL")
+//!     .await?
+//!     .chart(Interval::OneDay, TimeRange::OneYear)
+//!     .await?;
+//! chart.to_parquet("aapl.parquet")?;
+//!
+//! let quotes: Vec<Quote> = Tickers::new(["AAPL", "MSFT"])
+//!     .await?
+//!     .quotes()
+//!     .await?
+//!     .quotes
+//!     .into_values()
+//!     .collect();
+//! Quote::vec_to_dataframe(&quotes)?.write_csv("quotes.csv")?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fs::File;
+use std::path::Path;
+
+use polars::prelude::{CsvWriter, DataFrame, ParquetWriter, PolarsResult, SerWriter};
+
+/// File writers for polars `DataFrame`s.
+pub trait DataFrameExport {
+    /// Write to `path` as CSV with a header row, replacing any existing file.
+    fn write_csv(&mut self, path: impl AsRef<Path>) -> PolarsResult<()>;
+
+    /// Write to `path` as Parquet, replacing any existing file.
+    fn write_parquet(&mut self, path: impl AsRef<Path>) -> PolarsResult<()>;
+}
+
+impl DataFrameExport for DataFrame {
+    fn write_csv(&mut self, path: impl AsRef<Path>) -> PolarsResult<()> {
+        let mut file = File::create(path)?;
+        CsvWriter::new(&mut file).include_header(true).finish(self)
+    }
+
+    fn write_parquet(&mut self, path: impl AsRef<Path>) -> PolarsResult<()> {
+        let file = File::create(path)?;
+        ParquetWriter::new(file).finish(self)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::chart::{Candle, Chart, ChartMeta};
+
+    fn chart() -> Chart {
+        Chart {
+            symbol: "TEST".to_string(),
+            meta: ChartMeta::default(),
+            candles: (0..3)
+                .map(|i| Candle {
+                    timestamp: 1_700_000_000 + i * 86_400,
+                    open: 10.0,
+                    high: 11.0,
+                    low: 9.0,
+                    close: 10.5 + i as f64,
+                    volume: 1_000,
+                    ..Default::default()
+                })
+                .collect(),
+            interval: None,
+            range: None,
+            provider_id: None,
+            adjustment: None,
+        }
+    }
+
+    #[test]
+    fn test_chart_to_csv() {
+        let path = std::env::temp_dir().join("finance_query_export_test.csv");
+        chart().to_csv(&path).unwrap();
+        let csv = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let mut lines = csv.lines();
+        let header = lines.next().unwrap();
+        assert!(header.starts_with("timestamp,open,high,low,close,volume"));
+        assert_eq!(lines.count(), 3);
+    }
+
+    #[test]
+    fn test_chart_to_parquet() {
+        let path = std::env::temp_dir().join("finance_query_export_test.parquet");
+        chart().to_parquet(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert!(bytes.starts_with(b"PAR1") && bytes.ends_with(b"PAR1"));
+    }
+
+    #[test]
+    fn test_chart_parquet_round_trip() {
+        let path = std::env::temp_dir().join("FQEXPORT.parquet");
+        let original = chart();
+        original.to_parquet(&path).unwrap();
+        let loaded = Chart::from_parquet(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(loaded.symbol, "FQEXPORT");
+        assert_eq!(loaded.candles.len(), 3);
+        assert_eq!(loaded.candles[2].timestamp, original.candles[2].timestamp);
+        assert_eq!(loaded.candles[2].close, 12.5);
+        assert_eq!(loaded.meta.regular_market_price, Some(12.5));
+    }
+
+    #[test]
+    fn test_chart_from_parquet_rejects_non_parquet_file() {
+        let path = std::env::temp_dir().join("FQNOTPARQUET.parquet");
+        std::fs::write(&path, "date,close\n2024-01-02,1.0\n").unwrap();
+        let err = Chart::from_parquet(&path).unwrap_err();
+        std::fs::remove_file(&path).ok();
+
+        assert!(matches!(
+            err,
+            crate::FinanceError::InvalidParameter { ref param, .. } if param == "parquet"
+        ));
+    }
+}
@@ -116,6 +116,8 @@ pub enum FeedSource {
     Fortune,
     /// Business Wire — official corporate press releases (earnings, dividends, M&A)
     BusinessWire,
+    /// PR Newswire — official corporate press releases across all US issuers
+    PrNewswire,
     /// CoinDesk — cryptocurrency and blockchain news
     CoinDesk,
     /// CoinTelegraph — cryptocurrency news and analysis
@@ -180,6 +182,7 @@ impl FeedSource {
             Self::BusinessWire => {
                 "https://feed.businesswire.com/rss/home/?rss=G1QFDERJXkJeGVtQXw==".to_string()
             }
+            Self::PrNewswire => "https://www.prnewswire.com/rss/news-releases-list.rss".to_string(),
             Self::CoinDesk => "https://www.coindesk.com/arc/outboundfeeds/rss/".to_string(),
             Self::CoinTelegraph => "https://cointelegraph.com/rss".to_string(),
             Self::TechCrunch => "https://techcrunch.com/feed/".to_string(),
@@ -220,6 +223,7 @@ impl FeedSource {
             Self::WsjMarkets => "Wall Street Journal".to_string(),
             Self::Fortune => "Fortune".to_string(),
             Self::BusinessWire => "Business Wire".to_string(),
+            Self::PrNewswire => "PR Newswire".to_string(),
             Self::CoinDesk => "CoinDesk".to_string(),
             Self::CoinTelegraph => "CoinTelegraph".to_string(),
             Self::TechCrunch => "TechCrunch".to_string(),
@@ -299,7 +303,9 @@ async fn fetch_with_client(
     url: &str,
     source_name: &str,
 ) -> Result<Vec<FeedEntry>> {
-    let text = client.get(url).send().await?.text().await?;
+    let text = crate::telemetry::track(url, client.get(url).send().await)?
+        .text()
+        .await?;
     parser::parse(text.as_bytes(), source_name)
 }
 
@@ -341,6 +347,7 @@ mod tests {
         assert!(FeedSource::WsjMarkets.url().contains("dj.com"));
         assert!(FeedSource::Fortune.url().contains("fortune.com"));
         assert!(FeedSource::BusinessWire.url().contains("businesswire.com"));
+        assert!(FeedSource::PrNewswire.url().contains("prnewswire.com"));
         assert!(FeedSource::CoinDesk.url().contains("coindesk.com"));
         assert!(
             FeedSource::CoinTelegraph
@@ -367,6 +374,7 @@ mod tests {
         assert_eq!(FeedSource::WsjMarkets.name(), "Wall Street Journal");
         assert_eq!(FeedSource::Fortune.name(), "Fortune");
         assert_eq!(FeedSource::BusinessWire.name(), "Business Wire");
+        assert_eq!(FeedSource::PrNewswire.name(), "PR Newswire");
         assert_eq!(FeedSource::CoinDesk.name(), "CoinDesk");
         assert_eq!(FeedSource::CoinTelegraph.name(), "CoinTelegraph");
         assert_eq!(FeedSource::TechCrunch.name(), "TechCrunch");
@@ -5,16 +5,17 @@
 
 use crate::adapters::yahoo::client::{ClientConfig, YahooClient};
 use crate::constants::Region;
+use crate::constants::industries::Industry;
 use crate::constants::screeners::Screener;
 use crate::constants::sectors::Sector;
 use crate::error::Result;
-use crate::models::corporate::transcript::{Transcript, TranscriptWithMeta};
+use crate::models::corporate::transcript::{MatchedTranscript, Transcript, TranscriptWithMeta};
 use crate::models::discovery::screeners::ScreenerResults;
 use crate::models::discovery::search::SearchResults;
 use crate::models::market::industries::IndustryData;
 use crate::models::market::sectors::SectorData;
 
-#[cfg(any(feature = "fmp", feature = "alphavantage"))]
+#[cfg(feature = "fmp")]
 use serde::{Deserialize, Serialize};
 
 // Re-export options for convenience
@@ -136,6 +137,140 @@ pub async fn screener(screener_type: Screener, count: u32) -> Result<ScreenerRes
     crate::adapters::yahoo::discovery::screeners::fetch(&client, screener_type, count).await
 }
 
+/// Maximum number of results Yahoo returns per screener request.
+const SCREENER_PAGE_SIZE: u32 = 250;
+
+/// Fetch one page of a predefined Yahoo Finance screener
+///
+/// Use with [`ScreenerResults::total`] to page through results beyond the
+/// 250 that [`screener`] can return, or use [`screener_stream`] to walk every
+/// page automatically. A page past the end has no quotes.
+///
+/// # Arguments
+///
+/// * `screener_type` - The predefined screener to use
+/// * `offset` - Zero-based index of the first result
+/// * `size` - Number of results in the page (capped at 250)
+///
+/// # Examples
+///
+/// ```no_run
+/// use finance_query::{finance, Screener};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// // Results 250–499 of the most active stocks
+/// let page = finance::screener_page(Screener::MostActives, 250, 250).await?;
+/// println!("{} of {:?}", page.quotes.len(), page.total);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn screener_page(
+    screener_type: Screener,
+    offset: u32,
+    size: u32,
+) -> Result<ScreenerResults> {
+    let client = YahooClient::new(ClientConfig::default()).await?;
+    crate::adapters::yahoo::discovery::screeners::fetch_page(
+        &client,
+        screener_type,
+        offset,
+        size.min(SCREENER_PAGE_SIZE),
+    )
+    .await
+}
+
+/// Stream every result of a predefined Yahoo Finance screener
+///
+/// Fetches pages of 250 on demand over a single session and yields their
+/// quotes one at a time, stopping after the last page (per
+/// [`ScreenerResults::total`]) or a short page. A failed request is yielded as
+/// an error and ends the stream.
+///
+/// # Examples
+///
+/// ```no_run
+/// use finance_query::{finance, Screener};
+/// use futures::TryStreamExt;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let universe: Vec<_> = finance::screener_stream(Screener::MostActives)
+///     .try_collect()
+///     .await?;
+/// println!("{} most active stocks", universe.len());
+/// # Ok(())
+/// # }
+/// ```
+pub fn screener_stream(
+    screener_type: Screener,
+) -> impl futures::Stream<Item = Result<crate::models::discovery::screeners::ScreenerQuote>> + Send
+{
+    use futures::{StreamExt, TryStreamExt};
+
+    futures::stream::try_unfold(Some((None::<YahooClient>, 0u32)), move |state| async move {
+        let Some((client, offset)) = state else {
+            return Result::Ok(None);
+        };
+        let client = match client {
+            Some(client) => client,
+            None => YahooClient::new(ClientConfig::default()).await?,
+        };
+        let page = crate::adapters::yahoo::discovery::screeners::fetch_page(
+            &client,
+            screener_type,
+            offset,
+            SCREENER_PAGE_SIZE,
+        )
+        .await?;
+        let next = offset + page.quotes.len() as u32;
+        let more = page.quotes.len() as u32 == SCREENER_PAGE_SIZE
+            && page.total.is_none_or(|total| i64::from(next) < total);
+        Ok(Some((page.quotes, more.then_some((Some(client), next)))))
+    })
+    .map_ok(|quotes| futures::stream::iter(quotes).map(Ok))
+    .try_flatten()
+}
+
+/// Fetch a predefined screener's results for listings in `region`
+///
+/// Runs [`Screener::regional`] as a custom query. Only
+/// [`Screener::regional_types`] are supported; other screeners return
+/// [`FinanceError::InvalidParameter`](crate::FinanceError::InvalidParameter).
+///
+/// # Arguments
+///
+/// * `screener_type` - Day gainers, day losers, or most actives
+/// * `region` - Country whose listings to screen
+/// * `count` - Number of results to return (max 250)
+///
+/// # Examples
+///
+/// ```no_run
+/// use finance_query::{finance, Region, Screener};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let tokyo_gainers = finance::regional_screener(Screener::DayGainers, Region::Japan, 25).await?;
+/// let london_actives = finance::regional_screener(Screener::MostActives, Region::UnitedKingdom, 25).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn regional_screener(
+    screener_type: Screener,
+    region: Region,
+    count: u32,
+) -> Result<ScreenerResults> {
+    let query = screener_type
+        .regional(region)
+        .ok_or_else(|| crate::FinanceError::InvalidParameter {
+            param: "screener".to_string(),
+            reason: format!(
+                "'{}' has no regional version; use day-gainers, day-losers, or most-actives",
+                screener_type.as_scr_id()
+            ),
+        })?
+        .size(count);
+    custom_screener(query).await
+}
+
 /// Execute a custom screener query
 ///
 /// Allows flexible filtering of stocks/funds/ETFs based on various criteria.
@@ -245,6 +380,94 @@ pub async fn earnings_transcript(
     transcript
 }
 
+/// Get the earnings transcript nearest a fiscal quarter and year
+///
+/// Unlike [`earnings_transcript`], a missing period is not an error: the call
+/// with the closest fiscal label is returned instead (the earlier one on a
+/// tie). Check [`MatchedTranscript::matched`] and [`MatchedTranscript::exact`]
+/// to see which call you got.
+///
+/// # Arguments
+///
+/// * `symbol` - Stock symbol (e.g., "AAPL", "MSFT")
+/// * `quarter` - Fiscal quarter (Q1, Q2, Q3, Q4)
+/// * `year` - Fiscal year
+///
+/// # Examples
+///
+/// ```no_run
+/// use finance_query::finance;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let result = finance::earnings_transcript_nearest("AAPL", "Q4", 2024).await?;
+/// if !result.exact {
+///     println!("Closest call: {} {}", result.matched.quarter, result.matched.year);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn earnings_transcript_nearest(
+    symbol: &str,
+    quarter: &str,
+    year: i32,
+) -> Result<MatchedTranscript> {
+    let client = YahooClient::new(ClientConfig::default()).await?;
+    let matched = crate::adapters::yahoo::corporate::transcripts::fetch_nearest_for_symbol(
+        &client, symbol, quarter, year,
+    )
+    .await;
+    #[cfg(feature = "sentiment")]
+    let matched = matched.map(|mut m| {
+        m.transcript.score_sentiment();
+        m
+    });
+    matched
+}
+
+/// Get the latest earnings transcript for a call held on or before a date
+///
+/// Looks up by calendar date, so callers don't need to know how a company
+/// labels its fiscal quarters. The fiscal period of the returned call is in
+/// [`MatchedTranscript::matched`]; `exact` is true when the call was held on
+/// `date` itself.
+///
+/// # Arguments
+///
+/// * `symbol` - Stock symbol (e.g., "AAPL", "MSFT")
+/// * `date` - Latest acceptable call date
+///
+/// # Examples
+///
+/// ```no_run
+/// use chrono::NaiveDate;
+/// use finance_query::finance;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let date = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+/// let result = finance::transcript_on_or_before("AAPL", date).await?;
+/// println!(
+///     "{} {} held on {:?}",
+///     result.matched.quarter, result.matched.year, result.call_date
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub async fn transcript_on_or_before(
+    symbol: &str,
+    date: chrono::NaiveDate,
+) -> Result<MatchedTranscript> {
+    let client = YahooClient::new(ClientConfig::default()).await?;
+    let matched =
+        crate::adapters::yahoo::corporate::transcripts::fetch_on_or_before(&client, symbol, date)
+            .await;
+    #[cfg(feature = "sentiment")]
+    let matched = matched.map(|mut m| {
+        m.transcript.score_sentiment();
+        m
+    });
+    matched
+}
+
 /// Get all earnings transcripts for a symbol
 ///
 /// Fetches transcripts for all available earnings calls.
@@ -317,6 +540,39 @@ pub async fn hours(region: Option<Region>) -> Result<crate::models::market::hour
     crate::adapters::yahoo::market::hours::fetch(&client, region.map(|r| r.region())).await
 }
 
+/// Get market hours/status for an asset class
+///
+/// Crypto, FX, and futures trade on fixed schedules that Yahoo's exchange
+/// hours don't describe, so their status is computed locally without a
+/// network request: crypto is always open, FX closes over the weekend, and
+/// futures add a daily 17:00–18:00 New York break. Any other class falls
+/// back to [`hours`] for `region`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use finance_query::finance;
+/// use finance_query::streaming::AssetClass;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let crypto = finance::hours_for(AssetClass::Crypto, None).await?;
+/// assert!(crypto.markets[0].is_open());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn hours_for(
+    asset_class: crate::streaming::AssetClass,
+    region: Option<Region>,
+) -> Result<crate::models::market::hours::MarketHours> {
+    match crate::models::market::hours::MarketTime::for_asset_class(asset_class, chrono::Utc::now())
+    {
+        Some(market) => Ok(crate::models::market::hours::MarketHours {
+            markets: vec![market],
+        }),
+        None => hours(region).await,
+    }
+}
+
 /// Get world market indices quotes
 ///
 /// Returns quotes for major world indices, optionally filtered by region.
@@ -415,6 +671,48 @@ pub async fn industry(industry_key: impl AsRef<str>) -> Result<IndustryData> {
     crate::adapters::yahoo::market::industries::fetch(&client, industry_key.as_ref()).await
 }
 
+/// List every industry key grouped by sector
+///
+/// Returns each of Yahoo's 11 sectors with its industries. Use
+/// [`Industry::as_slug`] for the key accepted by [`industry`] and
+/// [`Industry::screener_value`] for the display name. No network request.
+///
+/// # Examples
+///
+/// ```
+/// use finance_query::finance;
+///
+/// for (sector, industries) in finance::industries() {
+///     println!("{sector}:");
+///     for industry in industries {
+///         println!("  {} ({})", industry.screener_value(), industry.as_slug());
+///     }
+/// }
+/// ```
+pub fn industries() -> Vec<(Sector, Vec<Industry>)> {
+    Sector::all()
+        .iter()
+        .map(|&sector| (sector, sector.industries()))
+        .collect()
+}
+
+/// Find industries by name, best match first
+///
+/// Fuzzy matches against keys and display names; see [`Industry::lookup`]
+/// for the ranking. No network request.
+///
+/// # Examples
+///
+/// ```
+/// use finance_query::{finance, Industry};
+///
+/// let hits = finance::industry_lookup("semiconductors");
+/// assert_eq!(hits.first(), Some(&Industry::Semiconductors));
+/// ```
+pub fn industry_lookup(query: impl AsRef<str>) -> Vec<Industry> {
+    Industry::lookup(query.as_ref())
+}
+
 /// Get list of available currencies
 ///
 /// Returns currency information from Yahoo Finance.
@@ -434,6 +732,28 @@ pub async fn currencies() -> Result<Vec<crate::models::market::currencies::Curre
     crate::adapters::yahoo::market::currencies::fetch(&client).await
 }
 
+/// Get the exchange rate from one currency to another
+///
+/// Returns units of `to` per unit of `from`, from Yahoo's `{FROM}{TO}=X`
+/// quote (crossed through USD if the pair isn't quoted). Minor units such
+/// as `GBp` are accepted. Use [`CurrencyConverter`](crate::fx::CurrencyConverter)
+/// to cache rates across many conversions.
+///
+/// # Examples
+///
+/// ```no_run
+/// use finance_query::finance;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let rate = finance::fx_rate("USD", "JPY").await?;
+/// println!("1 USD = {rate:.2} JPY");
+/// # Ok(())
+/// # }
+/// ```
+pub async fn fx_rate(from: &str, to: &str) -> Result<f64> {
+    crate::fx::CurrencyConverter::new().rate(from, to).await
+}
+
 /// Get list of supported exchanges
 ///
 /// Scrapes the Yahoo Finance help page for a list of supported exchanges
@@ -484,6 +804,306 @@ pub async fn market_summary(
     crate::adapters::yahoo::market::market_summary::fetch(&client, region).await
 }
 
+/// Get daily history for the market summary instruments
+///
+/// Fetches the current [`market_summary`] instrument set (indices, futures, FX)
+/// and reconstructs daily closes for each over `range`. Series are aligned
+/// onto a shared calendar (carrying closes forward across holidays) and
+/// rebased to 100, so 1-month and 3-month trends can be compared per tile.
+///
+/// Instruments whose chart fails to load are listed in
+/// [`errors`](crate::MarketSummaryHistory::errors) rather than failing the call.
+///
+/// # Examples
+///
+/// ```no_run
+/// use finance_query::{finance, TimeRange};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let history = finance::market_summary_history(TimeRange::ThreeMonths).await?;
+/// for series in &history.series {
+///     println!("{}: {:+.2}%", series.symbol, series.change_percent.unwrap_or(0.0));
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn market_summary_history(
+    range: crate::constants::TimeRange,
+) -> Result<crate::models::market::market_summary::MarketSummaryHistory> {
+    use crate::Tickers;
+    use crate::constants::Interval;
+    use crate::models::market::market_summary::MarketSummaryHistory;
+
+    let quotes = market_summary(None).await?;
+    let tickers = Tickers::new(quotes.iter().map(|q| q.symbol.clone())).await?;
+    let charts = tickers.charts(Interval::OneDay, range).await?;
+    Ok(MarketSummaryHistory::from_charts(
+        range,
+        &quotes,
+        &charts.charts,
+        charts.errors,
+    ))
+}
+
+/// Estimate ETF fund flows from shares-outstanding changes
+///
+/// Combines Yahoo's shares-outstanding history with daily closes: each
+/// change in shares outstanding is valued at the close on that date to
+/// approximate creations (inflows) and redemptions (outflows).
+///
+/// These are estimates — the close stands in for NAV, reporting cadence
+/// varies, and splits are not separated from flows. See
+/// [`FundFlows`](crate::FundFlows) for the full caveats.
+///
+/// # Examples
+///
+/// ```no_run
+/// use finance_query::{finance, TimeRange};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let flows = finance::etf_flows_proxy("SPY", TimeRange::ThreeMonths).await?;
+/// println!("Net flow: ${:.0}", flows.net_flow());
+/// for point in flows.points.iter().rev().take(5) {
+///     println!("{}: {:?}", point.timestamp, point.flow);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn etf_flows_proxy(
+    symbol: &str,
+    range: crate::constants::TimeRange,
+) -> Result<crate::models::fundamentals::FundFlows> {
+    use crate::constants::Interval;
+    use crate::models::fundamentals::FundFlows;
+
+    /// Look back this far before the first candle for a seed observation.
+    const SEED_LOOKBACK_SECS: i64 = 14 * 86_400;
+
+    let client = YahooClient::new(ClientConfig::default()).await?;
+    let chart = client.get_chart(symbol, Interval::OneDay, range).await?;
+    let now = chrono::Utc::now().timestamp();
+    let start = chart
+        .candles
+        .first()
+        .map_or(now - range.approx_duration_secs(), |c| c.timestamp);
+    let shares = crate::adapters::yahoo::fundamentals::fetch_shares_history(
+        &client,
+        symbol,
+        start - SEED_LOOKBACK_SECS,
+        now,
+    )
+    .await?;
+    Ok(FundFlows::estimate(symbol, range, &shares, &chart.candles))
+}
+
+/// Scan symbols for opening gaps
+///
+/// Compares each symbol's pre-market price (before the bell) or regular-session
+/// open (after it) against the prior close using one batch quote request, and
+/// returns those gapping at least `min_gap_pct` percent in either direction.
+/// Each gapper is enriched with today's pre-market volume, a count of recent
+/// news articles, and, once the session has opened, its intraday relative
+/// volume on 5-minute bars. Results are sorted by absolute gap, largest first.
+///
+/// # Examples
+///
+/// ```no_run
+/// use finance_query::finance;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let gappers = finance::gap_scan(["AAPL", "NVDA", "TSLA", "AMD"], 2.0).await?;
+/// for g in &gappers {
+///     println!(
+///         "{}: {:+.2}% (pre-mkt vol {:?}, RVOL {:?}, news: {})",
+///         g.symbol, g.gap_percent, g.premarket_volume, g.rvol, g.has_news()
+///     );
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn gap_scan<S, I>(
+    symbols: I,
+    min_gap_pct: f64,
+) -> Result<Vec<crate::models::discovery::gaps::Gapper>>
+where
+    S: Into<String>,
+    I: IntoIterator<Item = S>,
+{
+    use crate::Tickers;
+    use crate::models::discovery::gaps::{GapBasis, Gapper, compute_gap, is_recent_news};
+
+    let quotes = Tickers::new(symbols).await?.quotes().await?;
+    let mut gappers: Vec<Gapper> = quotes
+        .quotes
+        .values()
+        .filter_map(|q| {
+            let (price, previous_close, gap_percent, basis) = compute_gap(
+                q.market_state.as_deref(),
+                q.pre_market_price.as_ref().and_then(|v| v.raw),
+                q.regular_market_open.as_ref().and_then(|v| v.raw),
+                q.regular_market_price.as_ref().and_then(|v| v.raw),
+                q.regular_market_previous_close.as_ref().and_then(|v| v.raw),
+            )?;
+            (gap_percent.abs() >= min_gap_pct).then(|| Gapper {
+                symbol: q.symbol.clone(),
+                name: q.short_name.clone().or_else(|| q.long_name.clone()),
+                previous_close,
+                price,
+                gap_percent,
+                basis,
+                premarket_volume: None,
+                rvol: None,
+                recent_news: 0,
+            })
+        })
+        .collect();
+    if gappers.is_empty() {
+        return Ok(gappers);
+    }
+
+    let client = YahooClient::new(ClientConfig::default()).await?;
+    let gapper_tickers = Tickers::new(gappers.iter().map(|g| g.symbol.clone())).await?;
+    let (volumes, news, charts) = tokio::join!(
+        futures::future::join_all(gappers.iter().map(|g| {
+            crate::adapters::yahoo::chart::fetch_premarket_volume(&client, &g.symbol)
+        })),
+        gapper_tickers.news(),
+        gapper_tickers.charts(crate::Interval::FiveMinutes, crate::TimeRange::OneMonth)
+    );
+    let news = news.ok();
+    let charts = charts.ok();
+    for (gapper, volume) in gappers.iter_mut().zip(volumes) {
+        gapper.premarket_volume = volume.ok().flatten();
+        // Before the open the latest session in the chart is yesterday's
+        if gapper.basis == GapBasis::Open {
+            gapper.rvol = charts
+                .as_ref()
+                .and_then(|c| c.charts.get(&gapper.symbol))
+                .and_then(crate::models::chart::RelativeVolume::from_chart)
+                .and_then(|rv| rv.rvol);
+        }
+        gapper.recent_news = news
+            .as_ref()
+            .and_then(|n| n.news.get(&gapper.symbol))
+            .map_or(0, |articles| {
+                articles.iter().filter(|a| is_recent_news(&a.time)).count() as u32
+            });
+    }
+
+    gappers.sort_by(|a, b| b.gap_percent.abs().total_cmp(&a.gap_percent.abs()));
+    Ok(gappers)
+}
+
+/// Fetch corporate actions for multiple symbols as one time-ordered stream each.
+///
+/// Merges dividends, splits, and capital gains with symbol changes and
+/// delistings, so position-keeping code can consume a single normalized feed
+/// instead of stitching several endpoints together. Each symbol's actions are
+/// sorted by timestamp and filtered to `range`.
+///
+/// Coverage by action kind:
+///
+/// - dividends, splits, capital gains: from chart events
+/// - symbol changes: from Polygon ticker events, when the `polygon` feature
+///   is enabled and an API key is configured; otherwise none are reported
+/// - delistings: reported when Yahoo no longer resolves the symbol, stamped
+///   with the time of the request because Yahoo doesn't give the date.
+///   Mergers show up the same way.
+///
+/// Symbols whose events cannot be fetched are listed in
+/// [`CorporateActions::errors`](crate::CorporateActions::errors) unless they
+/// are known to be delisted.
+///
+/// # Examples
+///
+/// ```no_run
+/// use finance_query::{CorporateActionKind, TimeRange, finance};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let feed = finance::corporate_actions(["AAPL", "NVDA"], TimeRange::FiveYears).await?;
+/// for (symbol, actions) in &feed.actions {
+///     for action in actions.iter().filter(|a| a.affects_position()) {
+///         if let CorporateActionKind::Split { ratio, .. } = &action.kind {
+///             println!("{symbol}: {ratio} split at {}", action.timestamp);
+///         }
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn corporate_actions<S, I>(
+    symbols: I,
+    range: crate::TimeRange,
+) -> Result<crate::models::corporate::actions::CorporateActions>
+where
+    S: Into<String>,
+    I: IntoIterator<Item = S>,
+{
+    use crate::Tickers;
+    use crate::models::corporate::actions::{CorporateActions, merge};
+    use crate::models::discovery::trading_status::TradingStatus;
+
+    let tickers = Tickers::new(symbols).await?;
+    let symbols: Vec<String> = tickers.symbols().into_iter().map(String::from).collect();
+    let client = YahooClient::new(ClientConfig::default()).await?;
+
+    // The first batch call loads events for every symbol; the others read the cache
+    let (dividends, statuses) = tokio::join!(
+        tickers.dividends(range),
+        crate::adapters::yahoo::quote::quote_type::fetch_trading_status(&client, &symbols)
+    );
+    let mut dividends = dividends?;
+    let mut splits = tickers.splits(range).await?;
+    let mut capital_gains = tickers.capital_gains(range).await?;
+
+    #[cfg(feature = "polygon")]
+    let mut symbol_changes: std::collections::HashMap<String, Vec<(i64, String)>> =
+        futures::future::join_all(symbols.iter().map(|symbol| async move {
+            let changes = crate::adapters::polygon::fetch_symbol_changes(symbol)
+                .await
+                .unwrap_or_default();
+            (symbol.clone(), changes)
+        }))
+        .await
+        .into_iter()
+        .collect();
+
+    let now = crate::utils::now_unix_secs();
+    let mut feed = CorporateActions::default();
+    for symbol in symbols {
+        let delisted = statuses.get(&symbol) == Some(&TradingStatus::Delisted);
+        if let Some(error) = dividends.errors.remove(&symbol)
+            && !delisted
+        {
+            feed.errors.insert(symbol, error);
+            continue;
+        }
+
+        #[cfg(feature = "polygon")]
+        let changes: Vec<(i64, String)> = symbol_changes
+            .remove(&symbol)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(timestamp, _)| *timestamp >= crate::utils::range_to_cutoff(range))
+            .collect();
+        #[cfg(not(feature = "polygon"))]
+        let changes = Vec::new();
+
+        let actions = merge(
+            &dividends.dividends.remove(&symbol).unwrap_or_default(),
+            &splits.splits.remove(&symbol).unwrap_or_default(),
+            &capital_gains
+                .capital_gains
+                .remove(&symbol)
+                .unwrap_or_default(),
+            changes,
+            delisted.then_some(now),
+        );
+        feed.actions.insert(symbol, actions);
+    }
+    Ok(feed)
+}
+
 /// Get trending tickers for a region
 ///
 /// Returns trending stocks for a specific region.
@@ -512,6 +1132,372 @@ pub async fn trending(
     crate::adapters::yahoo::market::trending::fetch(&client, region).await
 }
 
+/// Get US companies reporting earnings between two dates
+///
+/// Answers "who reports this week" across the market, with consensus EPS
+/// estimates (and reported EPS once available), sorted by report time. Both
+/// dates are inclusive.
+///
+/// # Arguments
+///
+/// * `start_date` - First report date to include
+/// * `end_date` - Last report date to include
+///
+/// # Examples
+///
+/// ```no_run
+/// use finance_query::finance;
+/// use chrono::{Days, Utc};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let today = Utc::now().date_naive();
+/// let reports = finance::earnings_calendar(today, today + Days::new(6)).await?;
+/// for entry in &reports {
+///     println!("{:?} {} est. {:?}", entry.report_date, entry.symbol, entry.estimate);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn earnings_calendar(
+    start_date: chrono::NaiveDate,
+    end_date: chrono::NaiveDate,
+) -> Result<Vec<crate::models::calendar::EarningsCalendarEntry>> {
+    check_date_window(start_date, end_date)?;
+    let client = YahooClient::new(ClientConfig::default()).await?;
+    crate::adapters::yahoo::market::earnings_calendar::fetch(&client, start_date, end_date).await
+}
+
+/// Get IPOs scheduled (or recently priced) between two dates
+///
+/// Covers filed, expected, priced, and withdrawn deals, with the marketed
+/// price range and, once priced, the offer price. Both dates are inclusive.
+///
+/// # Arguments
+///
+/// * `start_date` - First IPO date to include
+/// * `end_date` - Last IPO date to include
+///
+/// # Examples
+///
+/// ```no_run
+/// use finance_query::finance;
+/// use chrono::{Days, Utc};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let today = Utc::now().date_naive();
+/// let ipos = finance::ipo_calendar(today, today + Days::new(30)).await?;
+/// for ipo in &ipos {
+///     println!("{:?} {} {:?}-{:?}", ipo.ipo_date, ipo.symbol, ipo.price_from, ipo.price_to);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn ipo_calendar(
+    start_date: chrono::NaiveDate,
+    end_date: chrono::NaiveDate,
+) -> Result<Vec<crate::models::calendar::IpoCalendarEntry>> {
+    check_date_window(start_date, end_date)?;
+    let client = YahooClient::new(ClientConfig::default()).await?;
+    crate::adapters::yahoo::market::ipo_calendar::fetch(&client, start_date, end_date).await
+}
+
+/// Get stock splits taking effect between two dates
+///
+/// Includes reverse splits; use
+/// [`SplitCalendarEntry::ratio`](crate::models::calendar::SplitCalendarEntry::ratio)
+/// to tell them apart. Both dates are inclusive.
+///
+/// # Arguments
+///
+/// * `start_date` - First split date to include
+/// * `end_date` - Last split date to include
+///
+/// # Examples
+///
+/// ```no_run
+/// use finance_query::finance;
+/// use chrono::{Days, Utc};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let today = Utc::now().date_naive();
+/// let splits = finance::splits_calendar(today, today + Days::new(30)).await?;
+/// for split in splits.iter().filter(|s| s.ratio().is_some_and(|r| r < 1.0)) {
+///     println!("Reverse split: {} on {:?}", split.symbol, split.split_date);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn splits_calendar(
+    start_date: chrono::NaiveDate,
+    end_date: chrono::NaiveDate,
+) -> Result<Vec<crate::models::calendar::SplitCalendarEntry>> {
+    check_date_window(start_date, end_date)?;
+    let client = YahooClient::new(ClientConfig::default()).await?;
+    crate::adapters::yahoo::market::splits_calendar::fetch(&client, start_date, end_date).await
+}
+
+/// Get scheduled macroeconomic releases between two dates
+///
+/// Covers CPI, central-bank decisions, payrolls, GDP, PMIs, and the rest of
+/// the macro calendar, with consensus forecasts and (once released) actual
+/// figures. Each event carries an
+/// [`EconomicEventCategory`](crate::EconomicEventCategory) for filtering.
+/// Both dates are inclusive.
+///
+/// # Arguments
+///
+/// * `region` - Country to keep (`None` for every country)
+/// * `start_date` - First release date to include
+/// * `end_date` - Last release date to include
+///
+/// # Examples
+///
+/// ```no_run
+/// use finance_query::{EconomicEventCategory, Region, finance};
+/// use chrono::{Days, Utc};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let today = Utc::now().date_naive();
+/// let events =
+///     finance::economic_calendar(Some(Region::UnitedStates), today, today + Days::new(6)).await?;
+/// for event in events.iter().filter(|e| e.category == EconomicEventCategory::Inflation) {
+///     println!("{:?} {} consensus {:?}", event.release_date, event.event, event.consensus);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn economic_calendar(
+    region: Option<Region>,
+    start_date: chrono::NaiveDate,
+    end_date: chrono::NaiveDate,
+) -> Result<Vec<crate::models::calendar::EconomicEvent>> {
+    check_date_window(start_date, end_date)?;
+    let client = YahooClient::new(ClientConfig::default()).await?;
+    crate::adapters::yahoo::market::economic_calendar::fetch(&client, region, start_date, end_date)
+        .await
+}
+
+/// Reject a calendar window whose end precedes its start.
+fn check_date_window(start_date: chrono::NaiveDate, end_date: chrono::NaiveDate) -> Result<()> {
+    if end_date < start_date {
+        return Err(crate::error::FinanceError::InvalidParameter {
+            param: "end_date".to_string(),
+            reason: format!("{end_date} is before start_date {start_date}"),
+        });
+    }
+    Ok(())
+}
+
+/// Get market summary and trending symbols for several regions at once
+///
+/// Fetches every region's market summary and trending list concurrently over
+/// one shared client, instead of 2×N sequential calls. Duplicate regions are
+/// fetched once. Failures are reported per region in
+/// [`errors`](crate::GlobalSnapshot::errors) rather than failing the call.
+///
+/// # Arguments
+///
+/// * `regions` - Regions to include
+///
+/// # Examples
+///
+/// ```no_run
+/// use finance_query::{finance, Region};
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let world = finance::global_snapshot(&[Region::UnitedStates, Region::Japan, Region::Germany]).await?;
+/// if let Some(japan) = world.get(Region::Japan) {
+///     println!("{} indices, {} trending", japan.market_summary.len(), japan.trending.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn global_snapshot(regions: &[Region]) -> Result<crate::GlobalSnapshot> {
+    use crate::models::market::global_snapshot::{GlobalSnapshot, RegionSnapshot};
+
+    let mut unique: Vec<Region> = Vec::with_capacity(regions.len());
+    for region in regions {
+        if !unique.contains(region) {
+            unique.push(*region);
+        }
+    }
+
+    let client = YahooClient::new(ClientConfig::default()).await?;
+    let results = futures::future::join_all(unique.iter().map(|&region| {
+        let client = &client;
+        async move {
+            let (summary, trending) = tokio::join!(
+                crate::adapters::yahoo::market::market_summary::fetch(client, Some(region)),
+                crate::adapters::yahoo::market::trending::fetch(client, Some(region))
+            );
+            (region, summary, trending)
+        }
+    }))
+    .await;
+
+    let mut snapshot = GlobalSnapshot::default();
+    for (region, summary, trending) in results {
+        let code = region.region().to_string();
+        let mut failures = Vec::new();
+        let market_summary = summary.unwrap_or_else(|e| {
+            failures.push(format!("market summary: {e}"));
+            Vec::new()
+        });
+        let trending = trending.unwrap_or_else(|e| {
+            failures.push(format!("trending: {e}"));
+            Vec::new()
+        });
+
+        if !failures.is_empty() {
+            snapshot.errors.insert(code.clone(), failures.join("; "));
+        }
+        if !market_summary.is_empty() || !trending.is_empty() || failures.is_empty() {
+            snapshot.regions.insert(
+                code,
+                RegionSnapshot {
+                    market_summary,
+                    trending,
+                },
+            );
+        }
+    }
+    Ok(snapshot)
+}
+
+/// Collect an end-of-day research bundle for `symbols` and save it under `dir`
+///
+/// Fetches each symbol's quote (which carries key stats: market cap, P/E,
+/// beta, 52-week range, dividend yield, EPS) and recent daily bars, keeps the
+/// bar for the latest completed session, and writes the result to
+/// `dir/eod-YYYY-MM-DD.json.gz` (see [`EodSnapshot::file_name`](crate::EodSnapshot::file_name)),
+/// replacing an earlier bundle for the same session.
+///
+/// Run it after the close: U.S. market hours are recorded in the bundle, and
+/// [`EodSnapshot::is_final`](crate::EodSnapshot::is_final) reports whether
+/// the session had ended. The session date is the newest daily bar's
+/// exchange-local date, so a weekend run files Friday's data. Per-symbol
+/// failures land in [`errors`](crate::EodSnapshot::errors) rather than
+/// failing the call.
+///
+/// # Examples
+///
+/// ```no_run
+/// use finance_query::finance;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let snapshot = finance::eod_snapshot(["AAPL", "MSFT", "SPY"], "data/eod").await?;
+/// println!(
+///     "{}: {} symbols saved to data/eod/{} (final: {})",
+///     snapshot.session_date,
+///     snapshot.symbols.len(),
+///     snapshot.file_name(),
+///     snapshot.is_final()
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub async fn eod_snapshot<S, I>(
+    symbols: I,
+    dir: impl AsRef<std::path::Path>,
+) -> Result<crate::EodSnapshot>
+where
+    S: Into<String>,
+    I: IntoIterator<Item = S>,
+{
+    use crate::models::market::eod::{EodSnapshot, EodSymbol};
+    use crate::{Interval, Tickers, TimeRange};
+
+    let symbols: Vec<String> = symbols.into_iter().map(Into::into).collect();
+    if symbols.is_empty() {
+        return Err(crate::error::FinanceError::InvalidParameter {
+            param: "symbols".into(),
+            reason: "at least one symbol is required".into(),
+        });
+    }
+
+    let tickers = Tickers::new(symbols).await?;
+    let (hours, quotes, charts) = tokio::join!(
+        hours(None),
+        tickers.quotes(),
+        tickers.charts(Interval::OneDay, TimeRange::FiveDays)
+    );
+    let (quotes, charts) = (quotes?, charts?);
+    let market_status = hours
+        .ok()
+        .and_then(|h| h.markets.into_iter().next())
+        .map(|m| m.status);
+
+    let now = chrono::Utc::now();
+    let today = now
+        .with_timezone(&crate::models::market::hours::new_york_offset(now))
+        .date_naive();
+    let local_date = |candle: &crate::models::chart::Candle, offset: i64| {
+        chrono::DateTime::from_timestamp(candle.timestamp + offset, 0).map(|dt| dt.date_naive())
+    };
+    let session = charts
+        .charts
+        .values()
+        .filter_map(|chart| {
+            let offset = chart.meta.gmt_offset.unwrap_or(0);
+            chart
+                .candles
+                .iter()
+                .filter_map(|c| local_date(c, offset))
+                .filter(|d| *d <= today)
+                .max()
+        })
+        .max()
+        .unwrap_or(today);
+
+    let mut snapshot = EodSnapshot {
+        session_date: session.format("%Y-%m-%d").to_string(),
+        generated_at: now.timestamp(),
+        market_status,
+        symbols: Default::default(),
+        errors: Default::default(),
+    };
+    let mut quotes_by_symbol = quotes.quotes;
+    let mut charts_by_symbol = charts.charts;
+    for symbol in tickers.symbols() {
+        let quote = quotes_by_symbol.remove(symbol);
+        let candle = charts_by_symbol.remove(symbol).and_then(|chart| {
+            let offset = chart.meta.gmt_offset.unwrap_or(0);
+            chart
+                .candles
+                .into_iter()
+                .rev()
+                .find(|c| local_date(c, offset).is_some_and(|d| d <= session))
+        });
+
+        let mut failures = Vec::new();
+        if quote.is_none() {
+            failures.push(format!(
+                "quote: {}",
+                quotes.errors.get(symbol).map_or("missing", String::as_str)
+            ));
+        }
+        if candle.is_none() {
+            failures.push(format!(
+                "candle: {}",
+                charts.errors.get(symbol).map_or("no bar", String::as_str)
+            ));
+        }
+        if !failures.is_empty() {
+            snapshot
+                .errors
+                .insert(symbol.to_string(), failures.join("; "));
+        }
+        if quote.is_some() || candle.is_some() {
+            snapshot
+                .symbols
+                .insert(symbol.to_string(), EodSymbol { quote, candle });
+        }
+    }
+
+    snapshot.write(dir.as_ref())?;
+    Ok(snapshot)
+}
+
 /// Fetch the current CNN Fear & Greed Index from Alternative.me.
 ///
 /// Returns a 0–100 sentiment score and its classification. No API key required.
@@ -801,28 +1787,9 @@ pub async fn symbol_sentiment(symbol: &str) -> Result<crate::models::sentiment::
 
 // ── Alpha Vantage ───────────────────────────────────────────────────
 
-/// An upcoming earnings calendar entry.
-#[cfg(feature = "alphavantage")]
-#[non_exhaustive]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct EarningsCalendarEntry {
-    /// Ticker symbol.
-    pub symbol: String,
-    /// Company name.
-    pub name: Option<String>,
-    /// Report date.
-    pub report_date: Option<String>,
-    /// Fiscal date ending.
-    pub fiscal_date_ending: Option<String>,
-    /// Estimated EPS.
-    pub estimate: Option<f64>,
-    /// Currency.
-    pub currency: Option<String>,
-}
-
 #[cfg(feature = "alphavantage")]
 impl From<crate::adapters::alphavantage::models::EarningsCalendarEntryDTO>
-    for EarningsCalendarEntry
+    for crate::models::calendar::EarningsCalendarEntry
 {
     fn from(d: crate::adapters::alphavantage::models::EarningsCalendarEntryDTO) -> Self {
         Self {
@@ -832,51 +1799,47 @@ impl From<crate::adapters::alphavantage::models::EarningsCalendarEntryDTO>
             fiscal_date_ending: d.fiscal_date_ending,
             estimate: d.estimate,
             currency: d.currency,
+            ..Self::default()
         }
     }
 }
 
-/// An upcoming IPO calendar entry.
-#[cfg(feature = "alphavantage")]
-#[non_exhaustive]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct IpoCalendarEntry {
-    /// Ticker symbol.
-    pub symbol: Option<String>,
-    /// Company name.
-    pub name: Option<String>,
-    /// IPO date.
-    pub ipo_date: Option<String>,
-    /// Price range (e.g., `"$15-$17"`).
-    pub price_range: Option<String>,
-    /// Exchange.
-    pub exchange: Option<String>,
-}
-
 #[cfg(feature = "alphavantage")]
-impl From<crate::adapters::alphavantage::models::IpoCalendarEntryDTO> for IpoCalendarEntry {
+impl From<crate::adapters::alphavantage::models::IpoCalendarEntryDTO>
+    for crate::models::calendar::IpoCalendarEntry
+{
     fn from(d: crate::adapters::alphavantage::models::IpoCalendarEntryDTO) -> Self {
         Self {
-            symbol: d.symbol,
+            symbol: d.symbol.unwrap_or_default(),
             name: d.name,
-            ipo_date: d.ipo_date,
-            price_range: d.price_range,
             exchange: d.exchange,
+            ipo_date: d.ipo_date,
+            price_from: d.price_range_low,
+            price_to: d.price_range_high,
+            currency: d.currency,
+            ..Self::default()
         }
     }
 }
 
-/// Fetch the upcoming earnings calendar (market-wide, not symbol-filtered).
+/// Fetch the next three months of earnings reports from Alpha Vantage
+/// (market-wide, not symbol-filtered).
+///
+/// See [`earnings_calendar`] for an arbitrary date window from Yahoo.
 #[cfg(feature = "alphavantage")]
-pub async fn earnings_calendar() -> Result<Vec<EarningsCalendarEntry>> {
+pub async fn alphavantage_earnings_calendar()
+-> Result<Vec<crate::models::calendar::EarningsCalendarEntry>> {
     crate::adapters::alphavantage::fundamentals::earnings_calendar()
         .await
         .map(|v| v.into_iter().map(Into::into).collect())
 }
 
-/// Fetch the upcoming IPO calendar (market-wide, not symbol-filtered).
+/// Fetch the upcoming IPO calendar from Alpha Vantage (market-wide, not
+/// symbol-filtered).
+///
+/// See [`ipo_calendar`] for an arbitrary date window from Yahoo.
 #[cfg(feature = "alphavantage")]
-pub async fn ipo_calendar() -> Result<Vec<IpoCalendarEntry>> {
+pub async fn alphavantage_ipo_calendar() -> Result<Vec<crate::models::calendar::IpoCalendarEntry>> {
     crate::adapters::alphavantage::fundamentals::ipo_calendar()
         .await
         .map(|v| v.into_iter().map(Into::into).collect())
@@ -0,0 +1,439 @@
+//! Currency conversion using Yahoo FX quotes.
+//!
+//! A [`CurrencyConverter`] fetches exchange rates from Yahoo's `{FROM}{TO}=X`
+//! pairs and caches them, so holdings quoted in different currencies can be
+//! valued in one base currency. Minor-unit codes Yahoo uses for some
+//! exchanges (`GBp` pence on the LSE, `ZAc` cents, `ILA` agorot) are scaled
+//! into their major currency, as listed by
+//! [`currency_info`](crate::currency_info). Rates for a pair Yahoo doesn't
+//! quote are crossed through USD.
+//!
+//! For a single rate, [`finance::fx_rate`](crate::finance::fx_rate) is
+//! shorter.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use finance_query::fx::CurrencyConverter;
+//! use finance_query::{Interval, Ticker, TimeRange};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let fx = CurrencyConverter::new();
+//! let mut total = 0.0;
+//! for (symbol, shares) in [("7203.T", 500.0), ("AAPL", 20.0)] {
+//!     let chart = Ticker::new(symbol).await?.chart(Interval::OneDay, TimeRange::OneDay).await?;
+//!     let price = chart.meta.regular_market_price.unwrap_or_default();
+//!     let currency = chart.meta.currency.as_deref().unwrap_or("USD");
+//!     total += fx.convert(price * shares, currency, "USD").await?;
+//! }
+//! println!("Portfolio value: ${total:.2}");
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::constants::Interval;
+use crate::error::{FinanceError, Result};
+use crate::models::chart::Chart;
+use crate::models::market::currencies::currency_info;
+use crate::{ClientHandle, Ticker};
+
+/// How long a fetched spot rate is reused by default.
+const DEFAULT_TTL: Duration = Duration::from_secs(60);
+
+/// Days fetched before a series' first point, so it has a rate even when it
+/// starts on a weekend or holiday.
+const HISTORY_LOOKBACK_SECS: i64 = 7 * 86_400;
+
+/// Cross rates for pairs Yahoo doesn't quote go through this currency.
+const PIVOT: &str = "USD";
+
+type Pair = (String, String);
+
+/// A cached spot rate. Rates set with [`CurrencyConverter::set_rate`] never
+/// expire.
+#[derive(Debug, Clone, Copy)]
+struct SpotRate {
+    rate: f64,
+    fetched: Option<Instant>,
+}
+
+/// Daily closes for a pair, covering `start..=end`.
+#[derive(Debug, Clone)]
+struct RateHistory {
+    start: i64,
+    end: i64,
+    closes: Vec<(i64, f64)>,
+}
+
+/// Converts amounts and time series between currencies, caching the rates
+/// it fetches.
+///
+/// Spot rates are reused for 60 seconds by default (see
+/// [`ttl`](Self::ttl)); daily history is kept for the converter's lifetime.
+/// All fetches share one Yahoo session.
+pub struct CurrencyConverter {
+    ttl: Duration,
+    client: Mutex<Option<ClientHandle>>,
+    spot: Mutex<HashMap<Pair, SpotRate>>,
+    history: Mutex<HashMap<Pair, RateHistory>>,
+}
+
+impl std::fmt::Debug for CurrencyConverter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CurrencyConverter")
+            .field("ttl", &self.ttl)
+            .field("spot", &lock(&self.spot))
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for CurrencyConverter {
+    fn default() -> Self {
+        Self {
+            ttl: DEFAULT_TTL,
+            client: Mutex::new(None),
+            spot: Mutex::new(HashMap::new()),
+            history: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl CurrencyConverter {
+    /// Create a converter with an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how long fetched spot rates are reused (default: 60 seconds).
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Pin the rate for `from` → `to`; it is used instead of fetching (and
+    /// inverted for `to` → `from`) until replaced.
+    ///
+    /// Useful for tests, offline runs, or valuing at an agreed rate.
+    pub fn set_rate(&self, from: &str, to: &str, rate: f64) -> Result<()> {
+        let (from, _) = major_unit(from)?;
+        let (to, _) = major_unit(to)?;
+        if !(rate.is_finite() && rate > 0.0) {
+            return Err(FinanceError::InvalidParameter {
+                param: "rate".to_string(),
+                reason: format!("must be positive, got {rate}"),
+            });
+        }
+        lock(&self.spot).insert(
+            (from, to),
+            SpotRate {
+                rate,
+                fetched: None,
+            },
+        );
+        Ok(())
+    }
+
+    /// Units of `to` per unit of `from`, e.g. about 150 for `("USD", "JPY")`.
+    pub async fn rate(&self, from: &str, to: &str) -> Result<f64> {
+        let (from_major, from_unit) = major_unit(from)?;
+        let (to_major, to_unit) = major_unit(to)?;
+        let rate = self.major_rate(&from_major, &to_major).await?;
+        Ok(rate * from_unit / to_unit)
+    }
+
+    /// Convert `amount` of `from` into `to` at the current rate.
+    pub async fn convert(&self, amount: f64, from: &str, to: &str) -> Result<f64> {
+        Ok(amount * self.rate(from, to).await?)
+    }
+
+    /// Convert `(timestamp, amount)` points from `from` into `to`, each at the
+    /// daily close on or before its timestamp.
+    ///
+    /// Points must be sorted oldest first. Points before the first available
+    /// close use the earliest close.
+    pub async fn convert_series(
+        &self,
+        points: &[(i64, f64)],
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<(i64, f64)>> {
+        let (from_major, from_unit) = major_unit(from)?;
+        let (to_major, to_unit) = major_unit(to)?;
+        let (Some(first), Some(last)) = (points.first(), points.last()) else {
+            return Ok(Vec::new());
+        };
+        if from_major == to_major {
+            let factor = from_unit / to_unit;
+            return Ok(points.iter().map(|&(t, v)| (t, v * factor)).collect());
+        }
+        let history = self
+            .major_history(&from_major, &to_major, first.0, last.0)
+            .await?;
+        Ok(convert_points(points, &history, from_unit / to_unit))
+    }
+
+    /// Convert a chart's prices into `to`, each candle at that day's rate.
+    ///
+    /// Open, high, low, close, and `adj_close` are converted, and the price
+    /// fields in `meta` at the current rate. Volume is unchanged. Fails if
+    /// the chart has no currency.
+    pub async fn convert_chart(&self, chart: &Chart, to: &str) -> Result<Chart> {
+        let from =
+            chart
+                .meta
+                .currency
+                .as_deref()
+                .ok_or_else(|| FinanceError::InvalidParameter {
+                    param: "chart".to_string(),
+                    reason: format!("{} has no currency", chart.symbol),
+                })?;
+        let closes: Vec<(i64, f64)> = chart.candles.iter().map(|c| (c.timestamp, 1.0)).collect();
+        let rates = self.convert_series(&closes, from, to).await?;
+        let spot = self.rate(from, to).await?;
+
+        let mut out = chart.clone();
+        for (candle, (_, rate)) in out.candles.iter_mut().zip(rates) {
+            candle.open *= rate;
+            candle.high *= rate;
+            candle.low *= rate;
+            candle.close *= rate;
+            candle.adj_close = candle.adj_close.map(|v| v * rate);
+        }
+        let meta = &mut out.meta;
+        for price in [
+            &mut meta.regular_market_price,
+            &mut meta.fifty_two_week_high,
+            &mut meta.fifty_two_week_low,
+            &mut meta.regular_market_day_high,
+            &mut meta.regular_market_day_low,
+            &mut meta.chart_previous_close,
+            &mut meta.previous_close,
+        ] {
+            *price = price.map(|v| v * spot);
+        }
+        meta.currency = Some(major_unit(to)?.0);
+        Ok(out)
+    }
+
+    /// Rate between two major currency codes, from the cache, Yahoo, or a
+    /// cross through USD.
+    async fn major_rate(&self, from: &str, to: &str) -> Result<f64> {
+        if from == to {
+            return Ok(1.0);
+        }
+        if let Some(rate) = self.cached_spot(from, to) {
+            return Ok(rate);
+        }
+        let rate = match self.fetch_spot(from, to).await {
+            Ok(rate) => rate,
+            Err(e) if from == PIVOT || to == PIVOT => return Err(e),
+            Err(_) => {
+                Box::pin(self.major_rate(from, PIVOT)).await?
+                    * Box::pin(self.major_rate(PIVOT, to)).await?
+            }
+        };
+        lock(&self.spot).insert(
+            (from.to_string(), to.to_string()),
+            SpotRate {
+                rate,
+                fetched: Some(Instant::now()),
+            },
+        );
+        Ok(rate)
+    }
+
+    fn cached_spot(&self, from: &str, to: &str) -> Option<f64> {
+        let spot = lock(&self.spot);
+        let fresh = |s: &SpotRate| s.fetched.is_none_or(|t| t.elapsed() < self.ttl);
+        let key = |a: &str, b: &str| (a.to_string(), b.to_string());
+        if let Some(s) = spot.get(&key(from, to)).filter(|s| fresh(s)) {
+            return Some(s.rate);
+        }
+        spot.get(&key(to, from))
+            .filter(|s| fresh(s))
+            .map(|s| 1.0 / s.rate)
+    }
+
+    async fn fetch_spot(&self, from: &str, to: &str) -> Result<f64> {
+        let symbol = pair_symbol(from, to);
+        let chart = self
+            .ticker(&symbol)
+            .await?
+            .chart(Interval::OneDay, crate::TimeRange::OneDay)
+            .await?;
+        chart
+            .meta
+            .regular_market_price
+            .or_else(|| chart.candles.last().map(|c| c.close))
+            .filter(|rate| rate.is_finite() && *rate > 0.0)
+            .ok_or_else(|| no_rate(&symbol))
+    }
+
+    /// Daily closes for a pair of major currencies covering `start..=end`.
+    async fn major_history(
+        &self,
+        from: &str,
+        to: &str,
+        start: i64,
+        end: i64,
+    ) -> Result<Vec<(i64, f64)>> {
+        let key = (from.to_string(), to.to_string());
+        if let Some(h) = lock(&self.history).get(&key)
+            && h.start <= start
+            && h.end >= end
+        {
+            return Ok(h.closes.clone());
+        }
+
+        let fetch_start = start - HISTORY_LOOKBACK_SECS;
+        // A single-day request still needs a non-empty window
+        let fetch_end = end.max(start + 1).min(chrono::Utc::now().timestamp());
+        let closes = match self.fetch_history(from, to, fetch_start, fetch_end).await {
+            Ok(closes) => closes,
+            Err(e) if from == PIVOT || to == PIVOT => return Err(e),
+            Err(_) => {
+                let via = Box::pin(self.major_history(from, PIVOT, start, end)).await?;
+                let onward = Box::pin(self.major_history(PIVOT, to, start, end)).await?;
+                via.iter()
+                    .filter_map(|&(t, r)| Some((t, r * rate_at(&onward, t)?)))
+                    .collect()
+            }
+        };
+        lock(&self.history).insert(
+            key,
+            RateHistory {
+                start,
+                end,
+                closes: closes.clone(),
+            },
+        );
+        Ok(closes)
+    }
+
+    async fn fetch_history(
+        &self,
+        from: &str,
+        to: &str,
+        start: i64,
+        end: i64,
+    ) -> Result<Vec<(i64, f64)>> {
+        let symbol = pair_symbol(from, to);
+        let chart = self
+            .ticker(&symbol)
+            .await?
+            .chart_range(Interval::OneDay, start, end)
+            .await?;
+        let closes: Vec<(i64, f64)> = chart
+            .candles
+            .iter()
+            .filter(|c| c.close.is_finite() && c.close > 0.0)
+            .map(|c| (c.timestamp, c.close))
+            .collect();
+        if closes.is_empty() {
+            return Err(no_rate(&symbol));
+        }
+        Ok(closes)
+    }
+
+    /// A ticker on the converter's shared Yahoo session.
+    async fn ticker(&self, symbol: &str) -> Result<Ticker> {
+        let handle = lock(&self.client).clone();
+        match handle {
+            Some(handle) => Ticker::builder(symbol).client(handle).build().await,
+            None => {
+                let ticker = Ticker::new(symbol).await?;
+                lock(&self.client).get_or_insert_with(|| ticker.client_handle());
+                Ok(ticker)
+            }
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Yahoo's symbol for a currency pair, e.g. `"USDJPY=X"`.
+fn pair_symbol(from: &str, to: &str) -> String {
+    format!("{from}{to}=X")
+}
+
+fn no_rate(symbol: &str) -> FinanceError {
+    FinanceError::SymbolNotFound {
+        symbol: Some(symbol.to_string()),
+        context: "no exchange rate available".to_string(),
+    }
+}
+
+/// Map a currency code to its major currency and the size of one unit in
+/// it: `GBp` (pence) is `("GBP", 0.01)`, `usd` is `("USD", 1.0)`.
+fn major_unit(code: &str) -> Result<(String, f64)> {
+    let code = code.trim();
+    if let Some((major, divisor)) = currency_info(code).and_then(|c| c.minor_of) {
+        return Ok((major.to_string(), 1.0 / f64::from(divisor)));
+    }
+    if code.len() != 3 || !code.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return Err(FinanceError::InvalidParameter {
+            param: "currency".to_string(),
+            reason: format!("expected a 3-letter currency code, got {code:?}"),
+        });
+    }
+    Ok((code.to_ascii_uppercase(), 1.0))
+}
+
+/// Latest close on or before `timestamp`, or the earliest close if
+/// `timestamp` precedes them all.
+fn rate_at(closes: &[(i64, f64)], timestamp: i64) -> Option<f64> {
+    let after = closes.partition_point(|&(t, _)| t <= timestamp);
+    closes.get(after.saturating_sub(1)).map(|&(_, rate)| rate)
+}
+
+fn convert_points(points: &[(i64, f64)], closes: &[(i64, f64)], factor: f64) -> Vec<(i64, f64)> {
+    points
+        .iter()
+        .map(|&(t, v)| (t, v * rate_at(closes, t).unwrap_or(f64::NAN) * factor))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_major_unit() {
+        assert_eq!(major_unit("usd").unwrap(), ("USD".to_string(), 1.0));
+        assert_eq!(major_unit("GBp").unwrap(), ("GBP".to_string(), 0.01));
+        assert_eq!(major_unit("GBP").unwrap(), ("GBP".to_string(), 1.0));
+        assert_eq!(major_unit("ILA").unwrap(), ("ILS".to_string(), 0.01));
+        assert!(major_unit("DOLLARS").is_err());
+        assert!(major_unit("U$D").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_pinned_rates_invert_and_scale_minor_units() {
+        let fx = CurrencyConverter::new();
+        fx.set_rate("USD", "JPY", 150.0).unwrap();
+        fx.set_rate("GBP", "USD", 1.25).unwrap();
+
+        assert_eq!(fx.rate("USD", "JPY").await.unwrap(), 150.0);
+        assert!((fx.convert(300.0, "JPY", "USD").await.unwrap() - 2.0).abs() < 1e-12);
+        // 1,000 pence = £10 = $12.50
+        assert!((fx.convert(1_000.0, "GBp", "USD").await.unwrap() - 12.5).abs() < 1e-12);
+        assert_eq!(fx.convert(250.0, "GBp", "GBP").await.unwrap(), 2.5);
+        assert_eq!(fx.rate("eur", "EUR").await.unwrap(), 1.0);
+        assert!(fx.set_rate("USD", "EUR", 0.0).is_err());
+    }
+
+    #[test]
+    fn test_points_use_prior_close() {
+        let closes = [(100, 2.0), (200, 3.0), (300, 4.0)];
+        let points = [(50, 1.0), (100, 1.0), (250, 1.0), (400, 10.0)];
+        let converted = convert_points(&points, &closes, 0.5);
+        assert_eq!(converted, [(50, 1.0), (100, 1.0), (250, 1.5), (400, 20.0)]);
+        assert_eq!(rate_at(&[], 10), None);
+    }
+}
@@ -0,0 +1,440 @@
+//! Registry for user-defined indicators.
+//!
+//! Downstream crates can add their own indicators without forking the
+//! [`Indicator`] enum. A [`CustomIndicator`] pairs a name with a compute
+//! function over OHLCV slices; once registered it can be used anywhere a
+//! built-in indicator is accepted:
+//!
+//! - `Ticker::indicator()` via [`Indicator::Custom`]
+//! - [`IndicatorsSummary::custom`](super::IndicatorsSummary::custom)
+//! - backtesting conditions via `refs::custom(name, params)`
+//! - the `fq indicator` CLI parser (`name:p1,p2`)
+//!
+//! # Example
+//!
+//! ```
+//! use finance_query::indicators::{self, CustomIndicator, Indicator};
+//!
+//! // Close relative to its N-bar high, in percent
+//! indicators::register_indicator(
+//!     CustomIndicator::new("pct_off_high", |ohlcv, params| {
+//!         let period = params.first().copied().unwrap_or(20.0) as usize;
+//!         let highs = indicators::donchian_channels(ohlcv.high, ohlcv.low, period)?.upper;
+//!         Ok(highs
+//!             .into_iter()
+//!             .zip(ohlcv.close)
+//!             .map(|(h, c)| h.map(|h| (c / h - 1.0) * 100.0))
+//!             .collect())
+//!     })
+//!     .with_label("Percent Off High")
+//!     .with_default_params(&[20.0])
+//!     .with_warmup(|params| params.first().copied().unwrap_or(20.0) as usize),
+//! )
+//! .unwrap();
+//!
+//! let indicator = Indicator::custom("pct_off_high", &[50.0]).unwrap();
+//! assert_eq!(indicator.warmup_bars(), 50);
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, LazyLock, RwLock};
+
+use super::{Indicator, IndicatorError, Result};
+
+/// Maximum number of numeric parameters a custom indicator can take.
+///
+/// Parameters are stored inline so that [`Indicator`] stays `Copy`.
+pub const MAX_CUSTOM_PARAMS: usize = 6;
+
+/// Borrowed OHLCV series passed to a custom indicator's compute function.
+///
+/// All slices have the same length, one entry per candle, oldest first.
+#[derive(Debug, Clone, Copy)]
+pub struct Ohlcv<'a> {
+    /// Open prices
+    pub open: &'a [f64],
+    /// High prices
+    pub high: &'a [f64],
+    /// Low prices
+    pub low: &'a [f64],
+    /// Close prices
+    pub close: &'a [f64],
+    /// Volumes
+    pub volume: &'a [f64],
+}
+
+impl Ohlcv<'_> {
+    /// Number of candles in the series.
+    pub fn len(&self) -> usize {
+        self.close.len()
+    }
+
+    /// Whether the series is empty.
+    pub fn is_empty(&self) -> bool {
+        self.close.is_empty()
+    }
+}
+
+type ComputeFn = dyn Fn(&Ohlcv<'_>, &[f64]) -> Result<Vec<Option<f64>>> + Send + Sync;
+type WarmupFn = dyn Fn(&[f64]) -> usize + Send + Sync;
+
+/// A user-defined indicator that can be added to the global registry.
+///
+/// Built with [`CustomIndicator::new`] and registered with
+/// [`register_indicator`].
+#[derive(Clone)]
+pub struct CustomIndicator {
+    name: &'static str,
+    label: &'static str,
+    default_params: Vec<f64>,
+    warmup: Arc<WarmupFn>,
+    compute: Arc<ComputeFn>,
+}
+
+impl fmt::Debug for CustomIndicator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomIndicator")
+            .field("name", &self.name)
+            .field("label", &self.label)
+            .field("default_params", &self.default_params)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CustomIndicator {
+    /// Create a custom indicator from a name and a compute function.
+    ///
+    /// `name` is the identifier used for lookups (case-insensitive, e.g. in the
+    /// CLI and strategy files). The compute function receives the OHLCV series
+    /// and the numeric parameters, and must return one value per candle.
+    ///
+    /// By default the indicator takes no parameters and warms up in 1 bar.
+    pub fn new<F>(name: &'static str, compute: F) -> Self
+    where
+        F: Fn(&Ohlcv<'_>, &[f64]) -> Result<Vec<Option<f64>>> + Send + Sync + 'static,
+    {
+        Self {
+            name,
+            label: name,
+            default_params: Vec::new(),
+            warmup: Arc::new(|_| 1),
+            compute: Arc::new(compute),
+        }
+    }
+
+    /// Set the human-readable name returned by [`Indicator::name`].
+    pub fn with_label(mut self, label: &'static str) -> Self {
+        self.label = label;
+        self
+    }
+
+    /// Set the parameters used when none are supplied (e.g. in
+    /// [`IndicatorsSummary`](super::IndicatorsSummary) or `fq indicator name`).
+    pub fn with_default_params(mut self, params: &[f64]) -> Self {
+        self.default_params = params.to_vec();
+        self
+    }
+
+    /// Set how many bars the indicator needs before producing output, as a
+    /// function of its parameters.
+    pub fn with_warmup<F>(mut self, warmup: F) -> Self
+    where
+        F: Fn(&[f64]) -> usize + Send + Sync + 'static,
+    {
+        self.warmup = Arc::new(warmup);
+        self
+    }
+
+    /// Registry name
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Human-readable name
+    pub fn label(&self) -> &'static str {
+        self.label
+    }
+
+    /// Parameters used when none are supplied
+    pub fn default_params(&self) -> &[f64] {
+        &self.default_params
+    }
+
+    /// Warmup bars for the given parameters
+    pub fn warmup_bars(&self, params: &[f64]) -> usize {
+        (self.warmup)(params)
+    }
+
+    /// Run the compute function, checking that it returned one value per candle.
+    pub fn compute(&self, ohlcv: &Ohlcv<'_>, params: &[f64]) -> Result<Vec<Option<f64>>> {
+        let values = (self.compute)(ohlcv, params)?;
+        if values.len() != ohlcv.len() {
+            return Err(IndicatorError::Custom(format!(
+                "'{}' returned {} values for {} candles",
+                self.name,
+                values.len(),
+                ohlcv.len()
+            )));
+        }
+        Ok(values)
+    }
+}
+
+static REGISTRY: LazyLock<RwLock<HashMap<String, CustomIndicator>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+fn registry_key(name: &str) -> String {
+    name.trim().to_ascii_lowercase()
+}
+
+/// Add a custom indicator to the global registry.
+///
+/// # Errors
+///
+/// Returns [`IndicatorError::Custom`] if the name is empty, contains
+/// characters other than ASCII letters, digits, and `_`, has more than
+/// [`MAX_CUSTOM_PARAMS`] default parameters, or is already registered.
+pub fn register_indicator(indicator: CustomIndicator) -> Result<()> {
+    let key = registry_key(indicator.name);
+    if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(IndicatorError::Custom(format!(
+            "invalid indicator name '{}': use ASCII letters, digits, and '_'",
+            indicator.name
+        )));
+    }
+    if indicator.default_params.len() > MAX_CUSTOM_PARAMS {
+        return Err(IndicatorError::Custom(format!(
+            "'{}' has more than {MAX_CUSTOM_PARAMS} parameters",
+            indicator.name
+        )));
+    }
+    let mut registry = REGISTRY.write().unwrap_or_else(|e| e.into_inner());
+    if registry.contains_key(&key) {
+        return Err(IndicatorError::Custom(format!(
+            "indicator '{}' is already registered",
+            indicator.name
+        )));
+    }
+    registry.insert(key, indicator);
+    Ok(())
+}
+
+/// Remove a custom indicator from the registry, returning it if present.
+pub fn unregister_indicator(name: &str) -> Option<CustomIndicator> {
+    REGISTRY
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(&registry_key(name))
+}
+
+/// Look up a registered custom indicator by name (case-insensitive).
+pub fn custom_indicator(name: &str) -> Option<CustomIndicator> {
+    REGISTRY
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&registry_key(name))
+        .cloned()
+}
+
+/// All registered custom indicators, sorted by name.
+pub fn custom_indicators() -> Vec<CustomIndicator> {
+    let mut all: Vec<CustomIndicator> = REGISTRY
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .values()
+        .cloned()
+        .collect();
+    all.sort_by_key(|ind| ind.name);
+    all
+}
+
+/// A registered custom indicator together with its parameters.
+///
+/// This is the payload of [`Indicator::Custom`]; create it with
+/// [`Indicator::custom`] or [`CustomIndicatorRef::new`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CustomIndicatorRef {
+    name: &'static str,
+    params: [f64; MAX_CUSTOM_PARAMS],
+    len: usize,
+}
+
+impl CustomIndicatorRef {
+    /// Reference a custom indicator by name.
+    ///
+    /// The indicator only has to be registered by the time it is computed.
+    /// When `params` is empty, the registered default parameters are used.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IndicatorError::Custom`] if more than [`MAX_CUSTOM_PARAMS`]
+    /// parameters are given.
+    pub fn new(name: &'static str, params: &[f64]) -> Result<Self> {
+        if params.len() > MAX_CUSTOM_PARAMS {
+            return Err(IndicatorError::Custom(format!(
+                "'{name}' takes at most {MAX_CUSTOM_PARAMS} parameters, got {}",
+                params.len()
+            )));
+        }
+        let mut stored = [0.0; MAX_CUSTOM_PARAMS];
+        stored[..params.len()].copy_from_slice(params);
+        Ok(Self {
+            name,
+            params: stored,
+            len: params.len(),
+        })
+    }
+
+    /// Reference a registered custom indicator by a runtime name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IndicatorError::UnknownIndicator`] if no indicator with that
+    /// name is registered, or [`IndicatorError::Custom`] if too many
+    /// parameters are given.
+    pub fn lookup(name: &str, params: &[f64]) -> Result<Self> {
+        let indicator = custom_indicator(name)
+            .ok_or_else(|| IndicatorError::UnknownIndicator(name.to_string()))?;
+        Self::new(indicator.name, params)
+    }
+
+    /// Registry name
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Parameters as supplied (empty means registered defaults)
+    pub fn params(&self) -> &[f64] {
+        &self.params[..self.len]
+    }
+
+    /// Stable key for this indicator and its parameters, e.g. `myind_14_2`.
+    pub fn key(&self) -> String {
+        let mut key = registry_key(self.name);
+        for p in self.params() {
+            key.push('_');
+            key.push_str(&p.to_string());
+        }
+        key
+    }
+
+    fn resolve(&self) -> Option<(CustomIndicator, Vec<f64>)> {
+        let indicator = custom_indicator(self.name)?;
+        let params = if self.len == 0 {
+            indicator.default_params.clone()
+        } else {
+            self.params().to_vec()
+        };
+        Some((indicator, params))
+    }
+
+    /// Warmup bars, or 1 if the indicator is not registered.
+    pub fn warmup_bars(&self) -> usize {
+        self.resolve()
+            .map(|(ind, params)| ind.warmup_bars(&params))
+            .unwrap_or(1)
+    }
+
+    /// Human-readable name, or the registry name if not registered.
+    pub fn label(&self) -> &'static str {
+        custom_indicator(self.name)
+            .map(|ind| ind.label)
+            .unwrap_or(self.name)
+    }
+
+    /// Compute the indicator over the given series.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IndicatorError::Custom`] if the indicator is not registered,
+    /// or whatever error the compute function returns.
+    pub fn compute(&self, ohlcv: &Ohlcv<'_>) -> Result<Vec<Option<f64>>> {
+        let (indicator, params) = self.resolve().ok_or_else(|| {
+            IndicatorError::Custom(format!("indicator '{}' is not registered", self.name))
+        })?;
+        indicator.compute(ohlcv, &params)
+    }
+}
+
+impl From<CustomIndicatorRef> for Indicator {
+    fn from(r: CustomIndicatorRef) -> Self {
+        Indicator::Custom(r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range_pct(name: &'static str) -> CustomIndicator {
+        CustomIndicator::new(name, |ohlcv, _| {
+            Ok(ohlcv
+                .high
+                .iter()
+                .zip(ohlcv.low)
+                .zip(ohlcv.close)
+                .map(|((h, l), c)| Some((h - l) / c * 100.0))
+                .collect())
+        })
+        .with_default_params(&[3.0])
+        .with_warmup(|p| p.first().copied().unwrap_or(0.0) as usize)
+    }
+
+    fn series() -> (Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>) {
+        (
+            vec![101.0, 102.0, 103.0],
+            vec![99.0, 100.0, 101.0],
+            vec![100.0, 101.0, 102.0],
+            vec![1000.0; 3],
+        )
+    }
+
+    #[test]
+    fn test_register_and_compute() {
+        register_indicator(range_pct("test_range_pct")).unwrap();
+        let (h, l, c, v) = series();
+        let ohlcv = Ohlcv {
+            open: &c,
+            high: &h,
+            low: &l,
+            close: &c,
+            volume: &v,
+        };
+
+        let r = CustomIndicatorRef::lookup("TEST_RANGE_PCT", &[]).unwrap();
+        assert_eq!(r.warmup_bars(), 3);
+        assert_eq!(r.key(), "test_range_pct");
+        let values = r.compute(&ohlcv).unwrap();
+        assert_eq!(values[0], Some(2.0));
+
+        let r = CustomIndicatorRef::new("test_range_pct", &[10.0, 0.5]).unwrap();
+        assert_eq!(r.warmup_bars(), 10);
+        assert_eq!(r.key(), "test_range_pct_10_0.5");
+        assert_eq!(Indicator::from(r).warmup_bars(), 10);
+
+        assert!(register_indicator(range_pct("test_range_pct")).is_err());
+        assert!(unregister_indicator("test_range_pct").is_some());
+        assert!(r.compute(&ohlcv).is_err());
+    }
+
+    #[test]
+    fn test_rejects_bad_names_and_lengths() {
+        assert!(register_indicator(range_pct("bad name")).is_err());
+        assert!(register_indicator(range_pct("")).is_err());
+        assert!(CustomIndicatorRef::new("x", &[0.0; MAX_CUSTOM_PARAMS + 1]).is_err());
+        assert!(CustomIndicatorRef::lookup("test_not_registered", &[]).is_err());
+
+        register_indicator(CustomIndicator::new("test_short_output", |_, _| Ok(vec![]))).unwrap();
+        let (h, l, c, v) = series();
+        let ohlcv = Ohlcv {
+            open: &c,
+            high: &h,
+            low: &l,
+            close: &c,
+            volume: &v,
+        };
+        let r = CustomIndicatorRef::lookup("test_short_output", &[]).unwrap();
+        assert!(r.compute(&ohlcv).is_err());
+    }
+}
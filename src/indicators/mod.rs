@@ -19,6 +19,9 @@
 //! - [`bollinger_bands`] - Bollinger Bands
 //! - [`atr`] - Average True Range
 //!
+//! ## Incremental Calculators
+//! - [`RollingSma`], [`RollingEma`], [`RollingRsi`] - O(1) per-price updates for live data
+//!
 //! # Example
 //!
 //! ```no_run
@@ -55,6 +58,7 @@ mod choppiness_index;
 mod cmf;
 mod cmo;
 mod coppock_curve;
+mod custom;
 mod dema;
 mod donchian_channels;
 mod elder_ray;
@@ -70,7 +74,10 @@ mod obv;
 mod parabolic_sar;
 mod patterns;
 mod roc;
+mod rolling;
 mod rsi;
+mod selection;
+mod series;
 mod sma;
 mod stochastic;
 mod stochastic_rsi;
@@ -101,6 +108,10 @@ pub use choppiness_index::choppiness_index;
 pub use cmf::cmf;
 pub use cmo::cmo;
 pub use coppock_curve::coppock_curve;
+pub use custom::{
+    CustomIndicator, CustomIndicatorRef, MAX_CUSTOM_PARAMS, Ohlcv, custom_indicator,
+    custom_indicators, register_indicator, unregister_indicator,
+};
 pub use dema::dema;
 pub use donchian_channels::{DonchianChannelsResult, donchian_channels};
 pub use elder_ray::{ElderRayResult, elder_ray};
@@ -114,9 +125,15 @@ pub use mfi::mfi;
 pub use momentum::momentum;
 pub use obv::obv;
 pub use parabolic_sar::parabolic_sar;
-pub use patterns::{CandlePattern, PatternSentiment, patterns};
+#[cfg(feature = "backtesting")]
+pub(crate) use patterns::pattern_at;
+pub use patterns::{CandlePattern, PatternMatch, PatternSentiment, pattern_matches, patterns};
 pub use roc::roc;
+pub use rolling::{RollingEma, RollingRsi, RollingSma};
 pub use rsi::rsi;
+pub use selection::{IndicatorKind, IndicatorSelection, IndicatorSelectionBuilder};
+pub use series::IndicatorsSeries;
+pub(crate) use series::calculate_series;
 pub use sma::sma;
 pub use stochastic::{StochasticResult, stochastic};
 pub use stochastic_rsi::stochastic_rsi;
@@ -138,6 +155,7 @@ pub use summary::{
 pub use Indicator as IndicatorType;
 
 /// Error type for indicator calculations
+#[non_exhaustive]
 #[derive(Debug, thiserror::Error)]
 pub enum IndicatorError {
     /// Not enough data points to calculate the indicator
@@ -152,6 +170,14 @@ pub enum IndicatorError {
     /// Invalid period parameter provided
     #[error("Invalid period: {0}")]
     InvalidPeriod(String),
+
+    /// Indicator name not recognized
+    #[error("Unknown indicator: {0}")]
+    UnknownIndicator(String),
+
+    /// Custom indicator registration or computation failed
+    #[error("Custom indicator error: {0}")]
+    Custom(String),
 }
 
 /// Result type for indicator calculations
@@ -187,6 +213,8 @@ pub enum IndicatorResult {
     Keltner(KeltnerChannelsResult),
     /// Donchian Channels result
     Donchian(DonchianChannelsResult),
+    /// Candlestick pattern detected on each bar, `None` where nothing matched
+    Patterns(Vec<Option<CandlePattern>>),
 }
 
 /// Enum representing all available technical indicators.
@@ -349,6 +377,14 @@ pub enum Indicator {
     AccumulationDistribution,
     /// Balance of Power
     BalanceOfPower(Option<usize>),
+    /// Candlestick pattern recognition (engulfing, doji, hammer, ...)
+    ///
+    /// Produces [`IndicatorResult::Patterns`] rather than a numeric series.
+    Patterns,
+    /// User-defined indicator from the custom indicator registry
+    ///
+    /// See [`register_indicator`] and [`Indicator::custom`].
+    Custom(CustomIndicatorRef),
 }
 
 impl Indicator {
@@ -424,6 +460,8 @@ impl Indicator {
             Indicator::ChaikinOscillator => "Chaikin Oscillator",
             Indicator::AccumulationDistribution => "Accumulation/Distribution",
             Indicator::BalanceOfPower(_) => "Balance of Power",
+            Indicator::Patterns => "Candlestick Patterns",
+            Indicator::Custom(r) => r.label(),
         }
     }
 
@@ -496,6 +534,8 @@ impl Indicator {
                 period, atr_period, ..
             } => *period.max(atr_period),
             Self::BalanceOfPower(Some(p)) => *p,
+            // Hammer-family patterns check the trend over the four prior bars
+            Self::Patterns => 5,
             // Volume/price indicators with no meaningful lookback
             Self::Obv
             | Self::Vwap
@@ -503,8 +543,21 @@ impl Indicator {
             | Self::ChaikinOscillator
             | Self::AccumulationDistribution
             | Self::BalanceOfPower(None) => 1,
+            Self::Custom(r) => r.warmup_bars(),
         }
     }
+
+    /// Reference a registered custom indicator by name.
+    ///
+    /// Empty `params` selects the indicator's registered defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IndicatorError::UnknownIndicator`] if no indicator with that
+    /// name has been registered with [`register_indicator`].
+    pub fn custom(name: &str, params: &[f64]) -> Result<Self> {
+        CustomIndicatorRef::lookup(name, params).map(Indicator::Custom)
+    }
 }
 
 /// Helper function to extract the last non-None value from a vector.
@@ -652,6 +705,14 @@ pub(crate) fn compute_indicator(
         Indicator::BalanceOfPower(p) => {
             IndicatorResult::Series(crate::indicators::balance_of_power(&o, &h, &l, &c, p)?)
         }
+        Indicator::Patterns => IndicatorResult::Patterns(chart.patterns()),
+        Indicator::Custom(r) => IndicatorResult::Series(r.compute(&Ohlcv {
+            open: &o,
+            high: &h,
+            low: &l,
+            close: &c,
+            volume: &v,
+        })?),
     })
 }
 
@@ -666,4 +727,26 @@ mod tests {
         assert_eq!(last_value(&[None, None, None]), None);
         assert_eq!(last_value(&[]), None);
     }
+
+    #[test]
+    fn test_compute_indicator_patterns() {
+        use crate::models::chart::{Candle, Chart, ChartMeta};
+
+        // A lone doji
+        let chart = Chart {
+            symbol: "AAPL".to_string(),
+            meta: ChartMeta::default(),
+            candles: vec![Candle::new(0, 10.0, 12.0, 8.0, 10.1, 0)],
+            interval: None,
+            range: None,
+            provider_id: None,
+            adjustment: None,
+        };
+        let result = compute_indicator(Indicator::Patterns, &chart).unwrap();
+        assert_eq!(
+            result,
+            IndicatorResult::Patterns(vec![Some(CandlePattern::Doji)])
+        );
+        assert_eq!(Indicator::Patterns.name(), "Candlestick Patterns");
+    }
 }
@@ -543,10 +543,62 @@ pub fn patterns(candles: &[Candle]) -> Vec<Option<CandlePattern>> {
     candles
         .iter()
         .enumerate()
-        .map(|(i, _)| {
-            detect_three_bar(candles, i)
-                .or_else(|| detect_two_bar(candles, i))
-                .or_else(|| detect_one_bar(candles, i))
+        .map(|(i, _)| pattern_at(candles, i))
+        .collect()
+}
+
+/// Detect the pattern completing on bar `i`, looking only at bars `..=i`.
+pub(crate) fn pattern_at(candles: &[Candle], i: usize) -> Option<CandlePattern> {
+    detect_three_bar(candles, i)
+        .or_else(|| detect_two_bar(candles, i))
+        .or_else(|| detect_one_bar(candles, i))
+}
+
+/// A candlestick pattern detected on a specific bar.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatternMatch {
+    /// Index of the bar that completes the pattern.
+    pub index: usize,
+    /// Timestamp of that bar (Unix seconds).
+    pub timestamp: i64,
+    /// The detected pattern.
+    pub pattern: CandlePattern,
+    /// Directional bias of the pattern.
+    pub sentiment: PatternSentiment,
+}
+
+/// Detect candlestick patterns and return only the bars where one matched.
+///
+/// A sparse form of [`patterns`] that carries each bar's index, timestamp,
+/// and sentiment alongside the pattern.
+///
+/// # Example
+///
+/// ```no_run
+/// use finance_query::{Ticker, Interval, TimeRange};
+/// use finance_query::indicators::pattern_matches;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let ticker = Ticker::new("AAPL").await?;
+/// let chart = ticker.chart(Interval::OneDay, TimeRange::ThreeMonths).await?;
+///
+/// for m in pattern_matches(&chart.candles) {
+///     println!("{}: {:?} ({:?})", m.timestamp, m.pattern, m.sentiment);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn pattern_matches(candles: &[Candle]) -> Vec<PatternMatch> {
+    (0..candles.len())
+        .filter_map(|i| {
+            pattern_at(candles, i).map(|pattern| PatternMatch {
+                index: i,
+                timestamp: candles[i].timestamp,
+                pattern,
+                sentiment: pattern.sentiment(),
+            })
         })
         .collect()
 }
@@ -578,6 +630,19 @@ mod tests {
         assert!(patterns(&[]).is_empty());
     }
 
+    #[test]
+    fn test_pattern_matches_are_sparse() {
+        let mut candles = vec![c(10.0, 12.0, 8.0, 10.1), c(10.0, 12.0, 8.0, 10.1)];
+        candles[1].timestamp = 86_400;
+        let dense = patterns(&candles);
+        let matches = pattern_matches(&candles);
+        assert_eq!(matches.len(), dense.iter().flatten().count());
+        let doji = matches.iter().find(|m| m.index == 1).unwrap();
+        assert_eq!(Some(doji.pattern), dense[1]);
+        assert_eq!(doji.sentiment, PatternSentiment::Neutral);
+        assert_eq!(doji.timestamp, 86_400);
+    }
+
     #[test]
     fn test_output_length_matches_input() {
         let candles: Vec<Candle> = (0..15)
@@ -0,0 +1,295 @@
+//! Incremental (streaming) indicator calculators.
+//!
+//! The functions in this module's parent recompute over the full price slice
+//! on every call. The types here keep just enough state to produce the next
+//! value in O(1) as each price arrives, which suits bar-by-bar backtests and
+//! live streams. Each produces exactly the same values as its batch
+//! counterpart ([`sma`](super::sma), [`ema`](super::ema), [`rsi`](super::rsi))
+//! fed the same prices.
+
+use std::collections::VecDeque;
+
+use super::{IndicatorError, Result};
+
+fn check_period(period: usize) -> Result<()> {
+    if period == 0 {
+        return Err(IndicatorError::InvalidPeriod(
+            "Period must be greater than 0".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Incremental Simple Moving Average.
+///
+/// # Example
+///
+/// ```
+/// use finance_query::indicators::RollingSma;
+///
+/// let mut sma = RollingSma::new(3)?;
+/// assert_eq!(sma.update(10.0), None);
+/// assert_eq!(sma.update(11.0), None);
+/// assert_eq!(sma.update(12.0), Some(11.0));
+/// assert_eq!(sma.update(13.0), Some(12.0));
+/// # Ok::<(), finance_query::indicators::IndicatorError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct RollingSma {
+    period: usize,
+    window: VecDeque<f64>,
+    sum: f64,
+}
+
+impl RollingSma {
+    /// Create an SMA over `period` values. Errors if `period` is 0.
+    pub fn new(period: usize) -> Result<Self> {
+        check_period(period)?;
+        Ok(Self {
+            period,
+            window: VecDeque::with_capacity(period),
+            sum: 0.0,
+        })
+    }
+
+    /// Add the next price, returning the average once `period` prices have been seen.
+    pub fn update(&mut self, price: f64) -> Option<f64> {
+        if self.window.len() == self.period {
+            let oldest = self.window.pop_front().unwrap_or_default();
+            self.sum += price - oldest;
+        } else {
+            self.sum += price;
+        }
+        self.window.push_back(price);
+        self.value()
+    }
+
+    /// The current average, or `None` during warmup.
+    pub fn value(&self) -> Option<f64> {
+        (self.window.len() == self.period).then(|| self.sum / self.period as f64)
+    }
+
+    /// Number of periods averaged.
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Discard all state, as if newly created.
+    pub fn reset(&mut self) {
+        self.window.clear();
+        self.sum = 0.0;
+    }
+}
+
+/// Incremental Exponential Moving Average, seeded with the SMA of the first
+/// `period` prices.
+///
+/// # Example
+///
+/// ```
+/// use finance_query::indicators::RollingEma;
+///
+/// let mut ema = RollingEma::new(3)?;
+/// ema.update(10.0);
+/// ema.update(11.0);
+/// assert_eq!(ema.update(12.0), Some(11.0));
+/// assert_eq!(ema.update(13.0), Some(12.0));
+/// # Ok::<(), finance_query::indicators::IndicatorError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct RollingEma {
+    period: usize,
+    multiplier: f64,
+    seen: usize,
+    /// Running sum during warmup, then the EMA itself
+    state: f64,
+}
+
+impl RollingEma {
+    /// Create an EMA over `period` values. Errors if `period` is 0.
+    pub fn new(period: usize) -> Result<Self> {
+        check_period(period)?;
+        Ok(Self {
+            period,
+            multiplier: 2.0 / (period as f64 + 1.0),
+            seen: 0,
+            state: 0.0,
+        })
+    }
+
+    /// Add the next price, returning the EMA once `period` prices have been seen.
+    pub fn update(&mut self, price: f64) -> Option<f64> {
+        if self.seen < self.period {
+            self.seen += 1;
+            self.state += price;
+            if self.seen == self.period {
+                self.state /= self.period as f64;
+            }
+        } else {
+            self.state += (price - self.state) * self.multiplier;
+        }
+        self.value()
+    }
+
+    /// The current EMA, or `None` during warmup.
+    pub fn value(&self) -> Option<f64> {
+        (self.seen == self.period).then_some(self.state)
+    }
+
+    /// Number of periods in the smoothing window.
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Discard all state, as if newly created.
+    pub fn reset(&mut self) {
+        self.seen = 0;
+        self.state = 0.0;
+    }
+}
+
+/// Incremental Relative Strength Index.
+///
+/// Needs `period + 1` prices before the first value, matching [`rsi`](super::rsi).
+///
+/// # Example
+///
+/// ```
+/// use finance_query::indicators::RollingRsi;
+///
+/// let mut rsi = RollingRsi::new(14)?;
+/// let mut last = None;
+/// for i in 0..20 {
+///     last = rsi.update(100.0 + i as f64);
+/// }
+/// assert_eq!(last, Some(100.0)); // only gains
+/// # Ok::<(), finance_query::indicators::IndicatorError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct RollingRsi {
+    period: usize,
+    multiplier: f64,
+    prev: Option<f64>,
+    changes: usize,
+    /// Summed gains/losses during warmup, then their averages
+    avg_gain: f64,
+    avg_loss: f64,
+}
+
+impl RollingRsi {
+    /// Create an RSI over `period` price changes. Errors if `period` is 0.
+    pub fn new(period: usize) -> Result<Self> {
+        check_period(period)?;
+        Ok(Self {
+            period,
+            multiplier: 2.0 / (period as f64 + 1.0),
+            prev: None,
+            changes: 0,
+            avg_gain: 0.0,
+            avg_loss: 0.0,
+        })
+    }
+
+    /// Add the next price, returning the RSI once `period + 1` prices have been seen.
+    pub fn update(&mut self, price: f64) -> Option<f64> {
+        let prev = self.prev.replace(price)?;
+        let change = price - prev;
+        let gain = if change > 0.0 { change } else { 0.0 };
+        let loss = if change < 0.0 { change.abs() } else { 0.0 };
+
+        if self.changes < self.period {
+            self.changes += 1;
+            self.avg_gain += gain;
+            self.avg_loss += loss;
+            if self.changes == self.period {
+                self.avg_gain /= self.period as f64;
+                self.avg_loss /= self.period as f64;
+            }
+        } else {
+            self.avg_gain = (gain - self.avg_gain) * self.multiplier + self.avg_gain;
+            self.avg_loss = (loss - self.avg_loss) * self.multiplier + self.avg_loss;
+        }
+        self.value()
+    }
+
+    /// The current RSI, or `None` during warmup.
+    pub fn value(&self) -> Option<f64> {
+        if self.changes < self.period {
+            return None;
+        }
+        Some(if self.avg_loss == 0.0 {
+            100.0
+        } else {
+            100.0 - 100.0 / (1.0 + self.avg_gain / self.avg_loss)
+        })
+    }
+
+    /// Number of price changes in the smoothing window.
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Discard all state, as if newly created.
+    pub fn reset(&mut self) {
+        self.prev = None;
+        self.changes = 0;
+        self.avg_gain = 0.0;
+        self.avg_loss = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::{ema, rsi, sma};
+
+    fn prices() -> Vec<f64> {
+        (0..60)
+            .map(|i| 100.0 + (i as f64 * 0.7).sin() * 5.0 + i as f64 * 0.1)
+            .collect()
+    }
+
+    #[test]
+    fn test_rolling_matches_batch() {
+        let data = prices();
+        for period in [1, 3, 14, 20] {
+            let mut r_sma = RollingSma::new(period).unwrap();
+            let mut r_ema = RollingEma::new(period).unwrap();
+            let mut r_rsi = RollingRsi::new(period).unwrap();
+            let b_sma = sma(&data, period);
+            let b_ema = ema(&data, period);
+            let b_rsi = rsi(&data, period).unwrap();
+
+            for (i, &p) in data.iter().enumerate() {
+                assert_eq!(r_sma.update(p), b_sma[i], "sma({period}) at {i}");
+                assert_eq!(r_ema.update(p), b_ema[i], "ema({period}) at {i}");
+                assert_eq!(r_rsi.update(p), b_rsi[i], "rsi({period}) at {i}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_rolling_zero_period() {
+        assert!(RollingSma::new(0).is_err());
+        assert!(RollingEma::new(0).is_err());
+        assert!(RollingRsi::new(0).is_err());
+    }
+
+    #[test]
+    fn test_rolling_reset() {
+        let mut sma = RollingSma::new(2).unwrap();
+        sma.update(1.0);
+        assert_eq!(sma.update(3.0), Some(2.0));
+        sma.reset();
+        assert_eq!(sma.value(), None);
+        assert_eq!(sma.update(5.0), None);
+        assert_eq!(sma.update(7.0), Some(6.0));
+
+        let mut rsi = RollingRsi::new(2).unwrap();
+        for p in [1.0, 2.0, 3.0] {
+            rsi.update(p);
+        }
+        rsi.reset();
+        assert_eq!(rsi.update(1.0), None);
+    }
+}
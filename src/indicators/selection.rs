@@ -0,0 +1,623 @@
+//! Selectable indicator subsets for [`IndicatorsSummary`](super::IndicatorsSummary).
+//!
+//! Computing the full summary runs 40+ indicators. An [`IndicatorSelection`]
+//! limits the work (and the serialized output) to the indicators you need,
+//! optionally with non-default periods.
+//!
+//! # Example
+//!
+//! ```
+//! use finance_query::indicators::{IndicatorKind, IndicatorSelection};
+//!
+//! // RSI(21) instead of RSI(14), plus MACD with its standard parameters
+//! let selection = IndicatorSelection::builder()
+//!     .period(IndicatorKind::Rsi, 21)
+//!     .include(IndicatorKind::Macd)
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(selection.periods(IndicatorKind::Rsi), &[21]);
+//!
+//! // Same selection, as accepted by the server's `indicators=` query param
+//! let parsed: IndicatorSelection = "rsi:21,macd".parse().unwrap();
+//! assert_eq!(parsed, selection);
+//! ```
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use super::{IndicatorError, Result};
+
+/// A group of related [`IndicatorsSummary`](super::IndicatorsSummary) fields
+/// that can be selected together.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum IndicatorKind {
+    /// Simple Moving Average
+    Sma,
+    /// Exponential Moving Average
+    Ema,
+    /// Weighted Moving Average
+    Wma,
+    /// Double Exponential Moving Average
+    Dema,
+    /// Triple Exponential Moving Average
+    Tema,
+    /// Hull Moving Average
+    Hma,
+    /// Volume Weighted Moving Average
+    Vwma,
+    /// Arnaud Legoux Moving Average
+    Alma,
+    /// McGinley Dynamic
+    McginleyDynamic,
+    /// Relative Strength Index
+    Rsi,
+    /// Stochastic Oscillator
+    Stochastic,
+    /// Stochastic RSI
+    StochasticRsi,
+    /// Commodity Channel Index
+    Cci,
+    /// Williams %R
+    WilliamsR,
+    /// Rate of Change
+    Roc,
+    /// Momentum
+    Momentum,
+    /// Chande Momentum Oscillator
+    Cmo,
+    /// Awesome Oscillator
+    AwesomeOscillator,
+    /// Coppock Curve
+    CoppockCurve,
+    /// MACD
+    Macd,
+    /// Average Directional Index
+    Adx,
+    /// Aroon
+    Aroon,
+    /// SuperTrend
+    Supertrend,
+    /// Ichimoku Cloud
+    Ichimoku,
+    /// Parabolic SAR
+    ParabolicSar,
+    /// Bull/Bear Power
+    BullBearPower,
+    /// Elder Ray Index
+    ElderRay,
+    /// Bollinger Bands
+    BollingerBands,
+    /// Keltner Channels
+    KeltnerChannels,
+    /// Donchian Channels
+    DonchianChannels,
+    /// Average True Range
+    Atr,
+    /// True Range
+    TrueRange,
+    /// Choppiness Index
+    ChoppinessIndex,
+    /// On-Balance Volume
+    Obv,
+    /// Money Flow Index
+    Mfi,
+    /// Chaikin Money Flow
+    Cmf,
+    /// Chaikin Oscillator
+    ChaikinOscillator,
+    /// Accumulation/Distribution
+    AccumulationDistribution,
+    /// Volume Weighted Average Price
+    Vwap,
+    /// Balance of Power
+    BalanceOfPower,
+    /// Indicators from the custom indicator registry
+    Custom,
+}
+
+impl IndicatorKind {
+    /// Every kind, in summary field order.
+    pub const ALL: &'static [IndicatorKind] = &[
+        Self::Sma,
+        Self::Ema,
+        Self::Wma,
+        Self::Dema,
+        Self::Tema,
+        Self::Hma,
+        Self::Vwma,
+        Self::Alma,
+        Self::McginleyDynamic,
+        Self::Rsi,
+        Self::Stochastic,
+        Self::StochasticRsi,
+        Self::Cci,
+        Self::WilliamsR,
+        Self::Roc,
+        Self::Momentum,
+        Self::Cmo,
+        Self::AwesomeOscillator,
+        Self::CoppockCurve,
+        Self::Macd,
+        Self::Adx,
+        Self::Aroon,
+        Self::Supertrend,
+        Self::Ichimoku,
+        Self::ParabolicSar,
+        Self::BullBearPower,
+        Self::ElderRay,
+        Self::BollingerBands,
+        Self::KeltnerChannels,
+        Self::DonchianChannels,
+        Self::Atr,
+        Self::TrueRange,
+        Self::ChoppinessIndex,
+        Self::Obv,
+        Self::Mfi,
+        Self::Cmf,
+        Self::ChaikinOscillator,
+        Self::AccumulationDistribution,
+        Self::Vwap,
+        Self::BalanceOfPower,
+        Self::Custom,
+    ];
+
+    /// Snake-case identifier, as accepted by [`FromStr`].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Sma => "sma",
+            Self::Ema => "ema",
+            Self::Wma => "wma",
+            Self::Dema => "dema",
+            Self::Tema => "tema",
+            Self::Hma => "hma",
+            Self::Vwma => "vwma",
+            Self::Alma => "alma",
+            Self::McginleyDynamic => "mcginley_dynamic",
+            Self::Rsi => "rsi",
+            Self::Stochastic => "stochastic",
+            Self::StochasticRsi => "stochastic_rsi",
+            Self::Cci => "cci",
+            Self::WilliamsR => "williams_r",
+            Self::Roc => "roc",
+            Self::Momentum => "momentum",
+            Self::Cmo => "cmo",
+            Self::AwesomeOscillator => "awesome_oscillator",
+            Self::CoppockCurve => "coppock_curve",
+            Self::Macd => "macd",
+            Self::Adx => "adx",
+            Self::Aroon => "aroon",
+            Self::Supertrend => "supertrend",
+            Self::Ichimoku => "ichimoku",
+            Self::ParabolicSar => "parabolic_sar",
+            Self::BullBearPower => "bull_bear_power",
+            Self::ElderRay => "elder_ray",
+            Self::BollingerBands => "bollinger_bands",
+            Self::KeltnerChannels => "keltner_channels",
+            Self::DonchianChannels => "donchian_channels",
+            Self::Atr => "atr",
+            Self::TrueRange => "true_range",
+            Self::ChoppinessIndex => "choppiness_index",
+            Self::Obv => "obv",
+            Self::Mfi => "mfi",
+            Self::Cmf => "cmf",
+            Self::ChaikinOscillator => "chaikin_oscillator",
+            Self::AccumulationDistribution => "accumulation_distribution",
+            Self::Vwap => "vwap",
+            Self::BalanceOfPower => "balance_of_power",
+            Self::Custom => "custom",
+        }
+    }
+
+    /// Periods that have a dedicated summary field (e.g. `sma_50`).
+    ///
+    /// Empty for kinds with fixed multi-parameter settings, which cannot be
+    /// given a custom period.
+    pub fn default_periods(&self) -> &'static [usize] {
+        match self {
+            Self::Sma | Self::Ema | Self::Wma => &[10, 20, 50, 100, 200],
+            Self::Dema | Self::Tema | Self::Hma | Self::Vwma | Self::McginleyDynamic => &[20],
+            Self::Alma => &[9],
+            Self::Rsi | Self::WilliamsR | Self::Cmo | Self::Adx | Self::Atr => &[14],
+            Self::ChoppinessIndex | Self::Mfi => &[14],
+            Self::Cci | Self::Cmf => &[20],
+            Self::Roc => &[12],
+            Self::Momentum => &[10],
+            _ => &[],
+        }
+    }
+
+    /// Whether a custom period can be set for this kind.
+    pub fn supports_period(&self) -> bool {
+        !self.default_periods().is_empty()
+    }
+
+    /// Serialized (camelCase) summary field name for this kind at `period`,
+    /// or `None` if the period has no dedicated field.
+    pub fn summary_field(&self, period: Option<usize>) -> Option<&'static str> {
+        Some(match (self, period) {
+            (Self::Sma, Some(10)) => "sma10",
+            (Self::Sma, Some(20)) => "sma20",
+            (Self::Sma, Some(50)) => "sma50",
+            (Self::Sma, Some(100)) => "sma100",
+            (Self::Sma, Some(200)) => "sma200",
+            (Self::Ema, Some(10)) => "ema10",
+            (Self::Ema, Some(20)) => "ema20",
+            (Self::Ema, Some(50)) => "ema50",
+            (Self::Ema, Some(100)) => "ema100",
+            (Self::Ema, Some(200)) => "ema200",
+            (Self::Wma, Some(10)) => "wma10",
+            (Self::Wma, Some(20)) => "wma20",
+            (Self::Wma, Some(50)) => "wma50",
+            (Self::Wma, Some(100)) => "wma100",
+            (Self::Wma, Some(200)) => "wma200",
+            (Self::Dema, Some(20)) => "dema20",
+            (Self::Tema, Some(20)) => "tema20",
+            (Self::Hma, Some(20)) => "hma20",
+            (Self::Vwma, Some(20)) => "vwma20",
+            (Self::Alma, Some(9)) => "alma9",
+            (Self::McginleyDynamic, Some(20)) => "mcginleyDynamic20",
+            (Self::Rsi, Some(14)) => "rsi14",
+            (Self::Cci, Some(20)) => "cci20",
+            (Self::WilliamsR, Some(14)) => "williamsR14",
+            (Self::Roc, Some(12)) => "roc12",
+            (Self::Momentum, Some(10)) => "momentum10",
+            (Self::Cmo, Some(14)) => "cmo14",
+            (Self::Adx, Some(14)) => "adx14",
+            (Self::Atr, Some(14)) => "atr14",
+            (Self::ChoppinessIndex, Some(14)) => "choppinessIndex14",
+            (Self::Mfi, Some(14)) => "mfi14",
+            (Self::Cmf, Some(20)) => "cmf20",
+            (Self::Stochastic, None) => "stochastic",
+            (Self::StochasticRsi, None) => "stochasticRsi",
+            (Self::AwesomeOscillator, None) => "awesomeOscillator",
+            (Self::CoppockCurve, None) => "coppockCurve",
+            (Self::Macd, None) => "macd",
+            (Self::Aroon, None) => "aroon",
+            (Self::Supertrend, None) => "supertrend",
+            (Self::Ichimoku, None) => "ichimoku",
+            (Self::ParabolicSar, None) => "parabolicSar",
+            (Self::BullBearPower, None) => "bullBearPower",
+            (Self::ElderRay, None) => "elderRayIndex",
+            (Self::BollingerBands, None) => "bollingerBands",
+            (Self::KeltnerChannels, None) => "keltnerChannels",
+            (Self::DonchianChannels, None) => "donchianChannels",
+            (Self::TrueRange, None) => "trueRange",
+            (Self::Obv, None) => "obv",
+            (Self::ChaikinOscillator, None) => "chaikinOscillator",
+            (Self::AccumulationDistribution, None) => "accumulationDistribution",
+            (Self::Vwap, None) => "vwap",
+            (Self::BalanceOfPower, None) => "balanceOfPower",
+            (Self::Custom, None) => "custom",
+            _ => return None,
+        })
+    }
+}
+
+impl std::fmt::Display for IndicatorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for IndicatorKind {
+    type Err = ();
+
+    /// Case-insensitive; accepts `as_str()`'s canonical form plus common
+    /// short names (`bb`, `psar`, `mcginley`, ...).
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let s = s.trim().to_ascii_lowercase();
+        let alias = match s.as_str() {
+            "mcginley" => Some(Self::McginleyDynamic),
+            "stoch" => Some(Self::Stochastic),
+            "stoch_rsi" | "stochrsi" => Some(Self::StochasticRsi),
+            "willr" | "williams" => Some(Self::WilliamsR),
+            "ao" => Some(Self::AwesomeOscillator),
+            "coppock" => Some(Self::CoppockCurve),
+            "psar" => Some(Self::ParabolicSar),
+            "bbp" => Some(Self::BullBearPower),
+            "elder" | "elder_ray_index" => Some(Self::ElderRay),
+            "bb" | "bollinger" => Some(Self::BollingerBands),
+            "keltner" => Some(Self::KeltnerChannels),
+            "donchian" => Some(Self::DonchianChannels),
+            "tr" => Some(Self::TrueRange),
+            "chop" | "choppiness" => Some(Self::ChoppinessIndex),
+            "chaikin" => Some(Self::ChaikinOscillator),
+            "ad" => Some(Self::AccumulationDistribution),
+            "bop" => Some(Self::BalanceOfPower),
+            _ => None,
+        };
+        alias
+            .or_else(|| Self::ALL.iter().copied().find(|k| k.as_str() == s))
+            .ok_or(())
+    }
+}
+
+/// A subset of [`IndicatorsSummary`](super::IndicatorsSummary) indicators to
+/// compute, with optional custom periods.
+///
+/// Values at a period with a dedicated field (e.g. SMA 50) fill that field;
+/// other periods (e.g. RSI 21) are reported in
+/// [`IndicatorsSummary::custom`](super::IndicatorsSummary::custom) under
+/// `"{kind}_{period}"`, e.g. `"rsi_21"`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IndicatorSelection {
+    /// Selected kinds mapped to custom periods (empty = default periods)
+    entries: BTreeMap<IndicatorKind, Vec<usize>>,
+}
+
+impl IndicatorSelection {
+    /// Every indicator at its default periods, matching
+    /// `Ticker::indicators()`.
+    pub fn all() -> Self {
+        IndicatorKind::ALL.into()
+    }
+
+    /// Create a builder for a selection with custom periods.
+    pub fn builder() -> IndicatorSelectionBuilder {
+        IndicatorSelectionBuilder::default()
+    }
+
+    /// Whether `kind` is selected.
+    pub fn contains(&self, kind: IndicatorKind) -> bool {
+        self.entries.contains_key(&kind)
+    }
+
+    /// Whether `kind` is selected at `period`.
+    pub fn contains_period(&self, kind: IndicatorKind, period: usize) -> bool {
+        self.periods(kind).contains(&period)
+    }
+
+    /// Selected kinds, in summary field order.
+    pub fn kinds(&self) -> impl Iterator<Item = IndicatorKind> + '_ {
+        self.entries.keys().copied()
+    }
+
+    /// Periods computed for `kind`: the custom periods if any were set,
+    /// otherwise [`IndicatorKind::default_periods`]. Empty if not selected.
+    pub fn periods(&self, kind: IndicatorKind) -> &[usize] {
+        match self.entries.get(&kind) {
+            Some(custom) if !custom.is_empty() => custom,
+            Some(_) => kind.default_periods(),
+            None => &[],
+        }
+    }
+
+    /// Whether nothing is selected.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Serialized (camelCase) summary fields this selection populates,
+    /// including `custom` when any value lands there.
+    pub fn summary_fields(&self) -> Vec<&'static str> {
+        let mut fields = Vec::new();
+        let mut custom = false;
+        for kind in self.kinds() {
+            if kind.supports_period() {
+                for &p in self.periods(kind) {
+                    match kind.summary_field(Some(p)) {
+                        Some(f) => fields.push(f),
+                        None => custom = true,
+                    }
+                }
+            } else if kind == IndicatorKind::Custom {
+                custom = true;
+            } else if let Some(f) = kind.summary_field(None) {
+                fields.push(f);
+            }
+        }
+        if custom {
+            fields.push("custom");
+        }
+        fields
+    }
+}
+
+impl std::fmt::Display for IndicatorSelection {
+    /// Canonical form accepted by [`FromStr`], e.g. `"sma:10,sma:30,rsi,macd"`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut first = true;
+        for (kind, periods) in &self.entries {
+            let items: Vec<String> = if periods.is_empty() {
+                vec![kind.to_string()]
+            } else {
+                periods.iter().map(|p| format!("{kind}:{p}")).collect()
+            };
+            for item in items {
+                if !first {
+                    f.write_str(",")?;
+                }
+                f.write_str(&item)?;
+                first = false;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<&[IndicatorKind]> for IndicatorSelection {
+    fn from(kinds: &[IndicatorKind]) -> Self {
+        Self {
+            entries: kinds.iter().map(|&k| (k, Vec::new())).collect(),
+        }
+    }
+}
+
+impl<const N: usize> From<&[IndicatorKind; N]> for IndicatorSelection {
+    fn from(kinds: &[IndicatorKind; N]) -> Self {
+        kinds.as_slice().into()
+    }
+}
+
+impl From<Vec<IndicatorKind>> for IndicatorSelection {
+    fn from(kinds: Vec<IndicatorKind>) -> Self {
+        kinds.as_slice().into()
+    }
+}
+
+impl From<IndicatorKind> for IndicatorSelection {
+    fn from(kind: IndicatorKind) -> Self {
+        [kind].as_slice().into()
+    }
+}
+
+impl FromStr for IndicatorSelection {
+    type Err = IndicatorError;
+
+    /// Parses a comma-separated list of kinds with optional periods, e.g.
+    /// `"rsi:21,macd,sma:10,sma:30"`. Repeating a kind adds periods.
+    fn from_str(s: &str) -> Result<Self> {
+        let mut builder = Self::builder();
+        for item in s.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let (name, period) = match item.split_once(':') {
+                Some((n, p)) => (n, Some(p.trim())),
+                None => (item, None),
+            };
+            let kind: IndicatorKind = name
+                .parse()
+                .map_err(|_| IndicatorError::UnknownIndicator(name.trim().to_string()))?;
+            builder = match period {
+                Some(p) => {
+                    let p = p.parse().map_err(|_| {
+                        IndicatorError::InvalidPeriod(format!("'{p}' is not a valid period"))
+                    })?;
+                    builder.period(kind, p)
+                }
+                None => builder.include(kind),
+            };
+        }
+        builder.build()
+    }
+}
+
+/// Builder for [`IndicatorSelection`].
+#[derive(Debug, Clone, Default)]
+pub struct IndicatorSelectionBuilder {
+    entries: BTreeMap<IndicatorKind, Vec<usize>>,
+}
+
+impl IndicatorSelectionBuilder {
+    /// Select `kind` at its default periods.
+    pub fn include(mut self, kind: IndicatorKind) -> Self {
+        self.entries.entry(kind).or_default();
+        self
+    }
+
+    /// Select `kind` at `period`. May be called repeatedly to add periods;
+    /// once any period is set the defaults are no longer computed.
+    pub fn period(mut self, kind: IndicatorKind, period: usize) -> Self {
+        let periods = self.entries.entry(kind).or_default();
+        if !periods.contains(&period) {
+            periods.push(period);
+        }
+        self
+    }
+
+    /// Build the selection.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`IndicatorError::InvalidPeriod`] if a period is zero or was
+    /// set for a kind without a single period parameter (see
+    /// [`IndicatorKind::supports_period`]).
+    pub fn build(self) -> Result<IndicatorSelection> {
+        for (kind, periods) in &self.entries {
+            if periods.is_empty() {
+                continue;
+            }
+            if !kind.supports_period() {
+                return Err(IndicatorError::InvalidPeriod(format!(
+                    "{kind} does not take a custom period"
+                )));
+            }
+            if periods.contains(&0) {
+                return Err(IndicatorError::InvalidPeriod(format!(
+                    "{kind} period must be greater than 0"
+                )));
+            }
+        }
+        Ok(IndicatorSelection {
+            entries: self.entries,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_round_trip() {
+        for kind in IndicatorKind::ALL {
+            assert_eq!(kind.as_str().parse::<IndicatorKind>(), Ok(*kind));
+        }
+        assert_eq!("BB".parse(), Ok(IndicatorKind::BollingerBands));
+        assert!("nope".parse::<IndicatorKind>().is_err());
+    }
+
+    #[test]
+    fn test_periods_and_fields() {
+        let sel: IndicatorSelection = "rsi:21,sma:50,sma:30,macd".parse().unwrap();
+        assert!(sel.contains(IndicatorKind::Rsi));
+        assert!(!sel.contains(IndicatorKind::Ema));
+        assert_eq!(sel.periods(IndicatorKind::Sma), &[50, 30]);
+        assert_eq!(sel.summary_fields(), vec!["sma50", "macd", "custom"]);
+        assert_eq!(sel.to_string(), "sma:50,sma:30,rsi:21,macd");
+        assert_eq!(sel.to_string().parse::<IndicatorSelection>().unwrap(), sel);
+
+        let defaults = IndicatorSelection::from(&[IndicatorKind::Rsi, IndicatorKind::Obv]);
+        assert_eq!(defaults.periods(IndicatorKind::Rsi), &[14]);
+        assert_eq!(defaults.summary_fields(), vec!["rsi14", "obv"]);
+    }
+
+    #[test]
+    fn test_invalid_selection() {
+        assert!("macd:5".parse::<IndicatorSelection>().is_err());
+        assert!("rsi:0".parse::<IndicatorSelection>().is_err());
+        assert!("rsi:abc".parse::<IndicatorSelection>().is_err());
+        assert!(matches!(
+            "foo".parse::<IndicatorSelection>(),
+            Err(IndicatorError::UnknownIndicator(_))
+        ));
+    }
+
+    #[test]
+    fn test_calculate_selected() {
+        use crate::Candle;
+        use crate::indicators::summary::calculate_selected;
+
+        let candles: Vec<Candle> = (0..60)
+            .map(|i| {
+                let p = 100.0 + (i as f64 * 0.7).sin() * 5.0;
+                Candle {
+                    timestamp: i,
+                    open: p,
+                    high: p + 1.0,
+                    low: p - 1.0,
+                    close: p,
+                    volume: 1000,
+                    adj_close: Some(p),
+                    provider_id: None,
+                }
+            })
+            .collect();
+
+        let sel: IndicatorSelection = "rsi:21,sma:20,obv".parse().unwrap();
+        let summary = calculate_selected(&candles, &sel);
+        assert!(summary.sma_20.is_some());
+        assert!(summary.obv.is_some());
+        assert!(summary.sma_10.is_none());
+        assert!(summary.rsi_14.is_none());
+        assert!(summary.macd.is_none());
+        assert!(summary.custom.contains_key("rsi_21"));
+    }
+
+    #[test]
+    fn test_all_fields_present() {
+        // Every kind maps to at least one summary field
+        for kind in IndicatorKind::ALL {
+            assert!(!IndicatorSelection::from(*kind).summary_fields().is_empty());
+        }
+    }
+}
@@ -0,0 +1,310 @@
+//! Full-history counterpart of [`IndicatorsSummary`](super::IndicatorsSummary).
+//!
+//! [`IndicatorsSeries`] holds every summary indicator as a whole time series
+//! aligned with the input candles, for plotting or analysing histories
+//! without calling each indicator separately.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Candle;
+use crate::indicators::{
+    Ohlcv, Result, accumulation_distribution, adx, alma, aroon, atr, awesome_oscillator,
+    balance_of_power, bollinger_bands, bull_bear_power, cci, chaikin_oscillator, choppiness_index,
+    cmf, cmo, coppock_curve, custom_indicators, dema, donchian_channels, elder_ray, ema, hma,
+    ichimoku, keltner_channels, macd, mcginley_dynamic, mfi, momentum, obv, parabolic_sar, roc,
+    rsi, sma, stochastic, stochastic_rsi, supertrend, tema, true_range, vwap, vwma, williams_r,
+    wma,
+};
+
+/// Every summary indicator as a full time series.
+///
+/// Obtain via [`Ticker::indicators_series`](crate::Ticker::indicators_series).
+/// Each series has one value per candle, aligned with
+/// [`timestamps`](Self::timestamps); `None` marks warm-up bars and bars where
+/// the indicator had too little data.
+///
+/// Keys follow the [`IndicatorsSummary`](super::IndicatorsSummary) field
+/// names (`sma_20`, `rsi_14`, `atr_14`, ...). Multi-line indicators get one
+/// key per line, named `{field}_{line}`: `macd_line`, `macd_signal`,
+/// `macd_histogram`, `stochastic_k`, `bollinger_bands_upper`,
+/// `supertrend_trend` (`1.0` up, `-1.0` down), and so on. Registered custom
+/// indicators appear under their registry name at their default parameters.
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndicatorsSeries {
+    /// Candle timestamps (Unix seconds), one per bar.
+    pub timestamps: Vec<i64>,
+    /// Indicator values keyed by name, each the same length as `timestamps`.
+    pub series: BTreeMap<String, Vec<Option<f64>>>,
+}
+
+impl IndicatorsSeries {
+    /// Values for `key`, if it was computed.
+    pub fn get(&self, key: &str) -> Option<&[Option<f64>]> {
+        self.series.get(key).map(Vec::as_slice)
+    }
+
+    /// Number of bars.
+    pub fn len(&self) -> usize {
+        self.timestamps.len()
+    }
+
+    /// Whether there are no bars.
+    pub fn is_empty(&self) -> bool {
+        self.timestamps.is_empty()
+    }
+}
+
+#[cfg(feature = "dataframe")]
+impl IndicatorsSeries {
+    /// Converts the series to a polars DataFrame.
+    ///
+    /// One row per bar, with a `timestamp` column followed by one `f64`
+    /// column per key in sorted order.
+    pub fn to_dataframe(&self) -> ::polars::prelude::PolarsResult<::polars::prelude::DataFrame> {
+        use ::polars::prelude::*;
+
+        let mut df = Series::new("timestamp".into(), &self.timestamps).into_frame();
+        for (key, values) in &self.series {
+            df.with_column(Series::new(key.as_str().into(), values))?;
+        }
+        Ok(df)
+    }
+}
+
+/// Collects computed series, padding failed indicators with `None` so every
+/// key is present regardless of history length.
+struct Sink<'a> {
+    series: &'a mut BTreeMap<String, Vec<Option<f64>>>,
+    len: usize,
+}
+
+impl Sink<'_> {
+    fn one(&mut self, key: &str, values: Result<Vec<Option<f64>>>) {
+        let values = values.unwrap_or_else(|_| vec![None; self.len]);
+        self.series.insert(key.to_string(), values);
+    }
+
+    fn lines<const N: usize>(
+        &mut self,
+        prefix: &str,
+        names: [&str; N],
+        values: Result<[Vec<Option<f64>>; N]>,
+    ) {
+        let values = values.unwrap_or_else(|_| std::array::from_fn(|_| vec![None; self.len]));
+        for (name, line) in names.into_iter().zip(values) {
+            self.series.insert(format!("{prefix}_{name}"), line);
+        }
+    }
+}
+
+/// Calculate every summary indicator over `candles` as full series.
+pub(crate) fn calculate_series(candles: &[Candle]) -> IndicatorsSeries {
+    let len = candles.len();
+    let mut out = IndicatorsSeries {
+        timestamps: candles.iter().map(|c| c.timestamp).collect(),
+        series: BTreeMap::new(),
+    };
+    if candles.is_empty() {
+        return out;
+    }
+
+    let mut closes = Vec::with_capacity(len);
+    let mut highs = Vec::with_capacity(len);
+    let mut lows = Vec::with_capacity(len);
+    let mut opens = Vec::with_capacity(len);
+    let mut volumes = Vec::with_capacity(len);
+    for c in candles {
+        closes.push(c.close);
+        highs.push(c.high);
+        lows.push(c.low);
+        opens.push(c.open);
+        volumes.push(c.volume as f64);
+    }
+    let (o, h, l, c, v) = (&opens[..], &highs[..], &lows[..], &closes[..], &volumes[..]);
+
+    let mut sink = Sink {
+        series: &mut out.series,
+        len,
+    };
+
+    // === MOVING AVERAGES ===
+    for p in [10, 20, 50, 100, 200] {
+        sink.one(&format!("sma_{p}"), Ok(sma(c, p)));
+        sink.one(&format!("ema_{p}"), Ok(ema(c, p)));
+        sink.one(&format!("wma_{p}"), wma(c, p));
+    }
+    sink.one("dema_20", dema(c, 20));
+    sink.one("tema_20", tema(c, 20));
+    sink.one("hma_20", hma(c, 20));
+    sink.one("vwma_20", vwma(c, v, 20));
+    sink.one("alma_9", alma(c, 9, 0.85, 6.0));
+    sink.one("mcginley_dynamic_20", mcginley_dynamic(c, 20));
+
+    // === MOMENTUM OSCILLATORS ===
+    sink.one("rsi_14", rsi(c, 14));
+    sink.lines(
+        "stochastic",
+        ["k", "d"],
+        stochastic(h, l, c, 14, 1, 3).map(|r| [r.k, r.d]),
+    );
+    sink.lines(
+        "stochastic_rsi",
+        ["k", "d"],
+        stochastic_rsi(c, 14, 14, 3, 3).map(|r| [r.k, r.d]),
+    );
+    sink.one("cci_20", cci(h, l, c, 20));
+    sink.one("williams_r_14", williams_r(h, l, c, 14));
+    sink.one("roc_12", roc(c, 12));
+    sink.one("momentum_10", momentum(c, 10));
+    sink.one("cmo_14", cmo(c, 14));
+    sink.one("awesome_oscillator", awesome_oscillator(h, l, 5, 34));
+    sink.one("coppock_curve", coppock_curve(c, 14, 11, 10));
+
+    // === TREND INDICATORS ===
+    sink.lines(
+        "macd",
+        ["line", "signal", "histogram"],
+        macd(c, 12, 26, 9).map(|r| [r.macd_line, r.signal_line, r.histogram]),
+    );
+    sink.one("adx_14", adx(h, l, c, 14));
+    sink.lines(
+        "aroon",
+        ["up", "down"],
+        aroon(h, l, 25).map(|r| [r.aroon_up, r.aroon_down]),
+    );
+    sink.lines(
+        "supertrend",
+        ["value", "trend"],
+        supertrend(h, l, c, 10, 3.0).map(|r| {
+            let trend = r
+                .is_uptrend
+                .iter()
+                .map(|t| t.map(|up| if up { 1.0 } else { -1.0 }))
+                .collect();
+            [r.value, trend]
+        }),
+    );
+    sink.lines(
+        "ichimoku",
+        [
+            "conversion_line",
+            "base_line",
+            "leading_span_a",
+            "leading_span_b",
+            "lagging_span",
+        ],
+        ichimoku(h, l, c, 9, 26, 26, 26).map(|r| {
+            [
+                r.conversion_line,
+                r.base_line,
+                r.leading_span_a,
+                r.leading_span_b,
+                r.lagging_span,
+            ]
+        }),
+    );
+    sink.one("parabolic_sar", parabolic_sar(h, l, c, 0.02, 0.2));
+    sink.lines(
+        "bull_bear_power",
+        ["bull_power", "bear_power"],
+        bull_bear_power(h, l, c, 13).map(|r| [r.bull_power, r.bear_power]),
+    );
+    sink.lines(
+        "elder_ray_index",
+        ["bull_power", "bear_power"],
+        elder_ray(h, l, c, 13).map(|r| [r.bull_power, r.bear_power]),
+    );
+
+    // === VOLATILITY INDICATORS ===
+    const BANDS: [&str; 3] = ["upper", "middle", "lower"];
+    sink.lines(
+        "bollinger_bands",
+        BANDS,
+        bollinger_bands(c, 20, 2.0).map(|r| [r.upper, r.middle, r.lower]),
+    );
+    sink.lines(
+        "keltner_channels",
+        BANDS,
+        keltner_channels(h, l, c, 20, 10, 2.0).map(|r| [r.upper, r.middle, r.lower]),
+    );
+    sink.lines(
+        "donchian_channels",
+        BANDS,
+        donchian_channels(h, l, 20).map(|r| [r.upper, r.middle, r.lower]),
+    );
+    sink.one("atr_14", atr(h, l, c, 14));
+    sink.one("true_range", true_range(h, l, c));
+    sink.one("choppiness_index_14", choppiness_index(h, l, c, 14));
+
+    // === VOLUME INDICATORS ===
+    sink.one("obv", obv(c, v));
+    sink.one("mfi_14", mfi(h, l, c, v, 14));
+    sink.one("cmf_20", cmf(h, l, c, v, 20));
+    sink.one("chaikin_oscillator", chaikin_oscillator(h, l, c, v));
+    sink.one(
+        "accumulation_distribution",
+        accumulation_distribution(h, l, c, v),
+    );
+    sink.one("vwap", vwap(h, l, c, v));
+    sink.one("balance_of_power", balance_of_power(o, h, l, c, None));
+
+    // === CUSTOM ===
+    let ohlcv = Ohlcv {
+        open: o,
+        high: h,
+        low: l,
+        close: c,
+        volume: v,
+    };
+    for ind in custom_indicators() {
+        sink.one(ind.name(), ind.compute(&ohlcv, ind.default_params()));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candles(n: i64) -> Vec<Candle> {
+        (0..n)
+            .map(|i| {
+                let close = 100.0 + (i as f64 * 0.3).sin() * 5.0;
+                Candle::new(i * 86_400, close, close + 1.0, close - 1.0, close, 1_000)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_series_aligned_with_candles() {
+        let candles = candles(60);
+        let out = calculate_series(&candles);
+        assert_eq!(out.len(), 60);
+        assert!(out.series.values().all(|s| s.len() == 60));
+
+        // Latest values agree with the summary
+        let summary = super::super::summary::calculate_indicators(&candles);
+        let last = |key: &str| out.get(key).and_then(|s| *s.last().unwrap());
+        assert_eq!(last("sma_20"), summary.sma_20);
+        assert_eq!(last("rsi_14"), summary.rsi_14);
+        assert_eq!(
+            last("macd_line"),
+            summary.macd.as_ref().and_then(|m| m.macd)
+        );
+
+        // Too short for SMA(200): present but empty
+        assert!(out.get("sma_200").unwrap().iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn test_series_empty_candles() {
+        let out = calculate_series(&[]);
+        assert!(out.is_empty());
+        assert!(out.series.is_empty());
+    }
+}
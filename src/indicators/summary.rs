@@ -6,14 +6,16 @@
 //! This module reuses the main indicator implementations and extracts the last value,
 //! ensuring consistency and eliminating code duplication.
 
-use super::last_value;
+use std::collections::BTreeMap;
+
+use super::{IndicatorKind, IndicatorSelection, last_value};
 use crate::Candle;
 use crate::indicators::{
-    accumulation_distribution, adx, alma, aroon, atr, atr::atr_raw, awesome_oscillator,
+    Ohlcv, accumulation_distribution, adx, alma, aroon, atr, atr::atr_raw, awesome_oscillator,
     balance_of_power, bollinger_bands, bull_bear_power, cci, chaikin_oscillator, choppiness_index,
-    cmf, cmo, coppock_curve, dema, donchian_channels, elder_ray, ema::ema_raw, hma, ichimoku,
-    keltner_channels::keltner_with_atr_dense, macd, mcginley_dynamic, mfi, momentum, obv,
-    parabolic_sar, roc, rsi::rsi_raw, sma::sma_raw, stochastic,
+    cmf, cmo, coppock_curve, custom_indicators, dema, donchian_channels, elder_ray, ema::ema_raw,
+    hma, ichimoku, keltner_channels::keltner_with_atr_dense, macd, mcginley_dynamic, mfi, momentum,
+    obv, parabolic_sar, roc, rsi::rsi_raw, sma::sma_raw, stochastic,
     stochastic_rsi::stochastic_rsi_from_rsi_dense, supertrend::supertrend_with_atr_dense, tema,
     true_range, vwap, vwma, williams_r, wma::wma_raw,
 };
@@ -29,9 +31,58 @@ fn last_from_result(result: crate::indicators::Result<Vec<Option<f64>>>) -> Opti
 /// Returns the latest values for all implemented indicators.
 /// Reuses the main indicator implementations for consistency.
 pub(crate) fn calculate_indicators(candles: &[Candle]) -> IndicatorsSummary {
-    if candles.is_empty() {
+    calculate_selected(candles, &IndicatorSelection::all())
+}
+
+/// Evaluate `f` only when `selected`.
+#[inline]
+fn when<T>(selected: bool, f: impl FnOnce() -> Option<T>) -> Option<T> {
+    if selected { f() } else { None }
+}
+
+/// Latest value of a single-period indicator at a non-default period.
+fn period_value(kind: IndicatorKind, period: usize, d: &Ohlcv<'_>) -> Option<f64> {
+    let (h, l, c, v) = (d.high, d.low, d.close, d.volume);
+    match kind {
+        IndicatorKind::Sma => sma_raw(c, period).last().copied(),
+        IndicatorKind::Ema => ema_raw(c, period).last().copied(),
+        IndicatorKind::Wma => wma_raw(c, period).last().copied(),
+        IndicatorKind::Dema => last_from_result(dema(c, period)),
+        IndicatorKind::Tema => last_from_result(tema(c, period)),
+        IndicatorKind::Hma => last_from_result(hma(c, period)),
+        IndicatorKind::Vwma => last_from_result(vwma(c, v, period)),
+        IndicatorKind::Alma => last_from_result(alma(c, period, 0.85, 6.0)),
+        IndicatorKind::McginleyDynamic => last_from_result(mcginley_dynamic(c, period)),
+        IndicatorKind::Rsi => rsi_raw(c, period).ok().and_then(|r| r.last().copied()),
+        IndicatorKind::Cci => last_from_result(cci(h, l, c, period)),
+        IndicatorKind::WilliamsR => last_from_result(williams_r(h, l, c, period)),
+        IndicatorKind::Roc => last_from_result(roc(c, period)),
+        IndicatorKind::Momentum => last_from_result(momentum(c, period)),
+        IndicatorKind::Cmo => last_from_result(cmo(c, period)),
+        IndicatorKind::Adx => last_from_result(adx(h, l, c, period)),
+        IndicatorKind::Atr => last_from_result(atr(h, l, c, period)),
+        IndicatorKind::ChoppinessIndex => last_from_result(choppiness_index(h, l, c, period)),
+        IndicatorKind::Mfi => last_from_result(mfi(h, l, c, v, period)),
+        IndicatorKind::Cmf => last_from_result(cmf(h, l, c, v, period)),
+        _ => None,
+    }
+}
+
+/// Calculate the indicators in `selection` from candle data.
+///
+/// Unselected fields are left as `None`. Periods without a dedicated field
+/// are reported in [`IndicatorsSummary::custom`] as `"{kind}_{period}"`.
+pub(crate) fn calculate_selected(
+    candles: &[Candle],
+    selection: &IndicatorSelection,
+) -> IndicatorsSummary {
+    use IndicatorKind as K;
+
+    if candles.is_empty() || selection.is_empty() {
         return IndicatorsSummary::default();
     }
+    let on = |kind: IndicatorKind| selection.contains(kind);
+    let at = |kind: IndicatorKind, period: usize| selection.contains_period(kind, period);
 
     // Extract price data from candles in a single pass (avoids 5 separate iterations)
     let len = candles.len();
@@ -49,54 +100,72 @@ pub(crate) fn calculate_indicators(candles: &[Candle]) -> IndicatorsSummary {
     }
 
     // Pre-compute shared intermediates (avoids redundant passes)
-    let rsi_14_dense = rsi_raw(&closes, 14).ok();
-    let atr_10_dense = atr_raw(&highs, &lows, &closes, 10).ok();
+    let rsi_14_dense = when(at(K::Rsi, 14) || on(K::StochasticRsi), || {
+        rsi_raw(&closes, 14).ok()
+    });
+    let atr_10_dense = when(on(K::Supertrend) || on(K::KeltnerChannels), || {
+        atr_raw(&highs, &lows, &closes, 10).ok()
+    });
 
     IndicatorsSummary {
         // === MOVING AVERAGES ===
-        sma_10: sma_raw(&closes, 10).last().copied(),
-        sma_20: sma_raw(&closes, 20).last().copied(),
-        sma_50: sma_raw(&closes, 50).last().copied(),
-        sma_100: sma_raw(&closes, 100).last().copied(),
-        sma_200: sma_raw(&closes, 200).last().copied(),
-
-        ema_10: ema_raw(&closes, 10).last().copied(),
-        ema_20: ema_raw(&closes, 20).last().copied(),
-        ema_50: ema_raw(&closes, 50).last().copied(),
-        ema_100: ema_raw(&closes, 100).last().copied(),
-        ema_200: ema_raw(&closes, 200).last().copied(),
-
-        wma_10: wma_raw(&closes, 10).last().copied(),
-        wma_20: wma_raw(&closes, 20).last().copied(),
-        wma_50: wma_raw(&closes, 50).last().copied(),
-        wma_100: wma_raw(&closes, 100).last().copied(),
-        wma_200: wma_raw(&closes, 200).last().copied(),
+        sma_10: when(at(K::Sma, 10), || sma_raw(&closes, 10).last().copied()),
+        sma_20: when(at(K::Sma, 20), || sma_raw(&closes, 20).last().copied()),
+        sma_50: when(at(K::Sma, 50), || sma_raw(&closes, 50).last().copied()),
+        sma_100: when(at(K::Sma, 100), || sma_raw(&closes, 100).last().copied()),
+        sma_200: when(at(K::Sma, 200), || sma_raw(&closes, 200).last().copied()),
+
+        ema_10: when(at(K::Ema, 10), || ema_raw(&closes, 10).last().copied()),
+        ema_20: when(at(K::Ema, 20), || ema_raw(&closes, 20).last().copied()),
+        ema_50: when(at(K::Ema, 50), || ema_raw(&closes, 50).last().copied()),
+        ema_100: when(at(K::Ema, 100), || ema_raw(&closes, 100).last().copied()),
+        ema_200: when(at(K::Ema, 200), || ema_raw(&closes, 200).last().copied()),
+
+        wma_10: when(at(K::Wma, 10), || wma_raw(&closes, 10).last().copied()),
+        wma_20: when(at(K::Wma, 20), || wma_raw(&closes, 20).last().copied()),
+        wma_50: when(at(K::Wma, 50), || wma_raw(&closes, 50).last().copied()),
+        wma_100: when(at(K::Wma, 100), || wma_raw(&closes, 100).last().copied()),
+        wma_200: when(at(K::Wma, 200), || wma_raw(&closes, 200).last().copied()),
 
         // Advanced Moving Averages (Result types)
-        dema_20: dema(&closes, 20).ok().and_then(|v| last_value(&v)),
-        tema_20: tema(&closes, 20).ok().and_then(|v| last_value(&v)),
-        hma_20: hma(&closes, 20).ok().and_then(|v| last_value(&v)),
-        vwma_20: vwma(&closes, &volumes, 20)
-            .ok()
-            .and_then(|v| last_value(&v)),
-        alma_9: alma(&closes, 9, 0.85, 6.0)
-            .ok()
-            .and_then(|v| last_value(&v)),
-        mcginley_dynamic_20: mcginley_dynamic(&closes, 20)
-            .ok()
-            .and_then(|v| last_value(&v)),
+        dema_20: when(at(K::Dema, 20), || {
+            dema(&closes, 20).ok().and_then(|v| last_value(&v))
+        }),
+        tema_20: when(at(K::Tema, 20), || {
+            tema(&closes, 20).ok().and_then(|v| last_value(&v))
+        }),
+        hma_20: when(at(K::Hma, 20), || {
+            hma(&closes, 20).ok().and_then(|v| last_value(&v))
+        }),
+        vwma_20: when(at(K::Vwma, 20), || {
+            vwma(&closes, &volumes, 20)
+                .ok()
+                .and_then(|v| last_value(&v))
+        }),
+        alma_9: when(at(K::Alma, 9), || {
+            alma(&closes, 9, 0.85, 6.0)
+                .ok()
+                .and_then(|v| last_value(&v))
+        }),
+        mcginley_dynamic_20: when(at(K::McginleyDynamic, 20), || {
+            mcginley_dynamic(&closes, 20)
+                .ok()
+                .and_then(|v| last_value(&v))
+        }),
 
         // === MOMENTUM OSCILLATORS ===
-        rsi_14: rsi_14_dense.as_deref().and_then(|v| v.last().copied()),
-        stochastic: {
+        rsi_14: when(at(K::Rsi, 14), || {
+            rsi_14_dense.as_deref().and_then(|v| v.last().copied())
+        }),
+        stochastic: when(on(K::Stochastic), || {
             stochastic(&highs, &lows, &closes, 14, 1, 3)
                 .ok()
                 .map(|result| StochasticData {
                     k: last_value(&result.k),
                     d: last_value(&result.d),
                 })
-        },
-        stochastic_rsi: {
+        }),
+        stochastic_rsi: when(on(K::StochasticRsi), || {
             rsi_14_dense.as_deref().and_then(|rsi_dense| {
                 stochastic_rsi_from_rsi_dense(rsi_dense, len, 14, 14, 3, 3)
                     .ok()
@@ -105,31 +174,43 @@ pub(crate) fn calculate_indicators(candles: &[Candle]) -> IndicatorsSummary {
                         d: last_value(&result.d),
                     })
             })
-        },
-        cci_20: last_from_result(cci(&highs, &lows, &closes, 20)),
-        williams_r_14: last_from_result(williams_r(&highs, &lows, &closes, 14)),
-        roc_12: last_from_result(roc(&closes, 12)),
-        momentum_10: last_from_result(momentum(&closes, 10)),
-        cmo_14: last_from_result(cmo(&closes, 14)),
-        awesome_oscillator: last_from_result(awesome_oscillator(&highs, &lows, 5, 34)),
-        coppock_curve: last_from_result(coppock_curve(&closes, 14, 11, 10)),
+        }),
+        cci_20: when(at(K::Cci, 20), || {
+            last_from_result(cci(&highs, &lows, &closes, 20))
+        }),
+        williams_r_14: when(at(K::WilliamsR, 14), || {
+            last_from_result(williams_r(&highs, &lows, &closes, 14))
+        }),
+        roc_12: when(at(K::Roc, 12), || last_from_result(roc(&closes, 12))),
+        momentum_10: when(at(K::Momentum, 10), || {
+            last_from_result(momentum(&closes, 10))
+        }),
+        cmo_14: when(at(K::Cmo, 14), || last_from_result(cmo(&closes, 14))),
+        awesome_oscillator: when(on(K::AwesomeOscillator), || {
+            last_from_result(awesome_oscillator(&highs, &lows, 5, 34))
+        }),
+        coppock_curve: when(on(K::CoppockCurve), || {
+            last_from_result(coppock_curve(&closes, 14, 11, 10))
+        }),
 
         // === TREND INDICATORS ===
-        macd: {
+        macd: when(on(K::Macd), || {
             macd(&closes, 12, 26, 9).ok().map(|result| MacdData {
                 macd: last_value(&result.macd_line),
                 signal: last_value(&result.signal_line),
                 histogram: last_value(&result.histogram),
             })
-        },
-        adx_14: last_from_result(adx(&highs, &lows, &closes, 14)),
-        aroon: {
+        }),
+        adx_14: when(at(K::Adx, 14), || {
+            last_from_result(adx(&highs, &lows, &closes, 14))
+        }),
+        aroon: when(on(K::Aroon), || {
             aroon(&highs, &lows, 25).ok().map(|result| AroonData {
                 aroon_up: last_value(&result.aroon_up),
                 aroon_down: last_value(&result.aroon_down),
             })
-        },
-        supertrend: {
+        }),
+        supertrend: when(on(K::Supertrend), || {
             atr_10_dense.as_deref().and_then(|atr_dense| {
                 supertrend_with_atr_dense(&highs, &lows, &closes, atr_dense, 10, 3.0)
                     .ok()
@@ -144,8 +225,8 @@ pub(crate) fn calculate_indicators(candles: &[Candle]) -> IndicatorsSummary {
                         }),
                     })
             })
-        },
-        ichimoku: {
+        }),
+        ichimoku: when(on(K::Ichimoku), || {
             ichimoku(&highs, &lows, &closes, 9, 26, 26, 26)
                 .ok()
                 .map(|result| IchimokuData {
@@ -155,27 +236,29 @@ pub(crate) fn calculate_indicators(candles: &[Candle]) -> IndicatorsSummary {
                     leading_span_b: last_value(&result.leading_span_b),
                     lagging_span: last_value(&result.lagging_span),
                 })
-        },
-        parabolic_sar: last_from_result(parabolic_sar(&highs, &lows, &closes, 0.02, 0.2)),
-        bull_bear_power: {
+        }),
+        parabolic_sar: when(on(K::ParabolicSar), || {
+            last_from_result(parabolic_sar(&highs, &lows, &closes, 0.02, 0.2))
+        }),
+        bull_bear_power: when(on(K::BullBearPower), || {
             bull_bear_power(&highs, &lows, &closes, 13)
                 .ok()
                 .map(|result| BullBearPowerData {
                     bull_power: last_value(&result.bull_power),
                     bear_power: last_value(&result.bear_power),
                 })
-        },
-        elder_ray_index: {
+        }),
+        elder_ray_index: when(on(K::ElderRay), || {
             elder_ray(&highs, &lows, &closes, 13)
                 .ok()
                 .map(|result| ElderRayData {
                     bull_power: last_value(&result.bull_power),
                     bear_power: last_value(&result.bear_power),
                 })
-        },
+        }),
 
         // === VOLATILITY INDICATORS ===
-        bollinger_bands: {
+        bollinger_bands: when(on(K::BollingerBands), || {
             bollinger_bands(&closes, 20, 2.0)
                 .ok()
                 .map(|result| BollingerBandsData {
@@ -183,8 +266,8 @@ pub(crate) fn calculate_indicators(candles: &[Candle]) -> IndicatorsSummary {
                     middle: last_value(&result.middle),
                     lower: last_value(&result.lower),
                 })
-        },
-        keltner_channels: {
+        }),
+        keltner_channels: when(on(K::KeltnerChannels), || {
             atr_10_dense.as_deref().and_then(|atr_dense| {
                 keltner_with_atr_dense(&closes, 20, atr_dense, 10, 2.0)
                     .ok()
@@ -194,8 +277,8 @@ pub(crate) fn calculate_indicators(candles: &[Candle]) -> IndicatorsSummary {
                         lower: last_value(&result.lower),
                     })
             })
-        },
-        donchian_channels: {
+        }),
+        donchian_channels: when(on(K::DonchianChannels), || {
             donchian_channels(&highs, &lows, 20)
                 .ok()
                 .map(|result| DonchianChannelsData {
@@ -203,21 +286,68 @@ pub(crate) fn calculate_indicators(candles: &[Candle]) -> IndicatorsSummary {
                     middle: last_value(&result.middle),
                     lower: last_value(&result.lower),
                 })
-        },
-        atr_14: last_from_result(atr(&highs, &lows, &closes, 14)),
-        true_range: last_from_result(true_range(&highs, &lows, &closes)),
-        choppiness_index_14: last_from_result(choppiness_index(&highs, &lows, &closes, 14)),
+        }),
+        atr_14: when(at(K::Atr, 14), || {
+            last_from_result(atr(&highs, &lows, &closes, 14))
+        }),
+        true_range: when(on(K::TrueRange), || {
+            last_from_result(true_range(&highs, &lows, &closes))
+        }),
+        choppiness_index_14: when(at(K::ChoppinessIndex, 14), || {
+            last_from_result(choppiness_index(&highs, &lows, &closes, 14))
+        }),
 
         // === VOLUME INDICATORS ===
-        obv: last_from_result(obv(&closes, &volumes)),
-        mfi_14: last_from_result(mfi(&highs, &lows, &closes, &volumes, 14)),
-        cmf_20: last_from_result(cmf(&highs, &lows, &closes, &volumes, 20)),
-        chaikin_oscillator: last_from_result(chaikin_oscillator(&highs, &lows, &closes, &volumes)),
-        accumulation_distribution: last_from_result(accumulation_distribution(
-            &highs, &lows, &closes, &volumes,
-        )),
-        vwap: last_from_result(vwap(&highs, &lows, &closes, &volumes)),
-        balance_of_power: last_from_result(balance_of_power(&opens, &highs, &lows, &closes, None)),
+        obv: when(on(K::Obv), || last_from_result(obv(&closes, &volumes))),
+        mfi_14: when(at(K::Mfi, 14), || {
+            last_from_result(mfi(&highs, &lows, &closes, &volumes, 14))
+        }),
+        cmf_20: when(at(K::Cmf, 20), || {
+            last_from_result(cmf(&highs, &lows, &closes, &volumes, 20))
+        }),
+        chaikin_oscillator: when(on(K::ChaikinOscillator), || {
+            last_from_result(chaikin_oscillator(&highs, &lows, &closes, &volumes))
+        }),
+        accumulation_distribution: when(on(K::AccumulationDistribution), || {
+            last_from_result(accumulation_distribution(&highs, &lows, &closes, &volumes))
+        }),
+        vwap: when(on(K::Vwap), || {
+            last_from_result(vwap(&highs, &lows, &closes, &volumes))
+        }),
+        balance_of_power: when(on(K::BalanceOfPower), || {
+            last_from_result(balance_of_power(&opens, &highs, &lows, &closes, None))
+        }),
+
+        // === CUSTOM ===
+        custom: {
+            let ohlcv = Ohlcv {
+                open: &opens,
+                high: &highs,
+                low: &lows,
+                close: &closes,
+                volume: &volumes,
+            };
+            let mut custom = BTreeMap::new();
+            for kind in selection.kinds().filter(IndicatorKind::supports_period) {
+                for &p in selection.periods(kind) {
+                    if kind.summary_field(Some(p)).is_none()
+                        && let Some(v) = period_value(kind, p, &ohlcv)
+                    {
+                        custom.insert(format!("{kind}_{p}"), v);
+                    }
+                }
+            }
+            if on(K::Custom) {
+                for ind in custom_indicators() {
+                    if let Ok(values) = ind.compute(&ohlcv, ind.default_params())
+                        && let Some(v) = last_value(&values)
+                    {
+                        custom.insert(ind.name().to_string(), v);
+                    }
+                }
+            }
+            custom
+        },
     }
 }
 
@@ -398,6 +528,13 @@ pub struct IndicatorsSummary {
     /// Balance of Power
     #[serde(skip_serializing_if = "Option::is_none")]
     pub balance_of_power: Option<f64>,
+
+    // === CUSTOM ===
+    /// Values without a dedicated field: registered custom indicators at
+    /// their default parameters (keyed by registry name) and indicators
+    /// selected at non-default periods (keyed like `rsi_21`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom: BTreeMap<String, f64>,
 }
 
 /// Stochastic Oscillator data
@@ -58,13 +58,17 @@
 // Public modules
 /// External data source adapters (internal — use the public API modules).
 pub(crate) mod adapters;
+/// Single-entry command API (serde request/response) for embedding.
+pub mod api;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 /// Error types and result definitions.
 pub mod error;
 /// Non-symbol-specific operations (search, lookup, screeners, market data, etc.).
 pub mod finance;
+#[cfg(feature = "edgar")]
 pub mod edgar {
-    //! SEC EDGAR API client (keyless — always available, no feature flag needed).
-    //!
+    //! SEC EDGAR API client (keyless; requires the `edgar` feature, on by default).
     //!
     //! Requires a one-time [`init`] call with a contact email address.
     pub use crate::adapters::edgar::{
@@ -76,8 +80,21 @@ pub mod edgar {
 mod constants;
 mod models;
 mod providers;
+// Only the keyed/external providers throttle; a Yahoo-only build leaves it unused.
+#[cfg_attr(
+    not(any(
+        feature = "edgar",
+        feature = "fred",
+        feature = "crypto",
+        feature = "alphavantage",
+        feature = "polygon",
+        feature = "fmp"
+    )),
+    allow(dead_code)
+)]
 pub(crate) mod rate_limiter;
 mod scrapers;
+mod symbol;
 mod ticker;
 mod tickers;
 mod utils;
@@ -88,21 +105,65 @@ pub mod fred {
     //! FRED economic data API (requires `fred` feature).
     //!
     //! Access 800k+ macroeconomic time series and US Treasury yield curve data.
-    pub use crate::adapters::fred::{init, init_with_timeout, series, treasury_yields};
+    pub use crate::adapters::fred::{
+        init, init_with_timeout, series, series_range, treasury_yields,
+    };
     pub use crate::models::economic::{MacroObservation, MacroSeries, TreasuryYield};
 }
 
 #[cfg(feature = "crypto")]
 pub mod crypto {
-    //! CoinGecko cryptocurrency data (requires `crypto` feature).
+    //! Cryptocurrency data (requires `crypto` feature).
+    //!
+    //! Quotes and market caps come from CoinGecko; OHLCV candles and trades
+    //! come straight from Binance or Coinbase.
     pub use crate::adapters::coingecko::{CoinQuote, coin, coins};
+    #[cfg(feature = "streaming")]
+    pub use crate::adapters::exchanges::TradeStream;
+    pub use crate::adapters::exchanges::{candles, candles_between, trades};
+    pub use crate::models::crypto::{CryptoExchange, CryptoTrade, TradeSide};
+}
+
+pub mod alerts;
+
+pub mod attention;
+
+pub mod options {
+    //! Options analytics: Black-Scholes Greeks, multi-leg strategy payoffs,
+    //! and implied volatility surfaces.
+    pub use crate::models::options::surface::IvSurface;
+    pub use crate::models::options::{greeks, strategy, surface};
 }
 
+#[cfg(feature = "cache")]
+pub mod cache;
+
+#[cfg(feature = "signals")]
+pub mod signals;
+
+#[cfg(feature = "feeds")]
 pub mod feeds;
 
+#[cfg(feature = "export")]
+pub mod export;
+
+pub mod fx;
+
+pub mod portfolio;
+
+pub mod screeners;
+
 #[cfg(feature = "risk")]
 pub mod risk;
 
+pub mod summarize;
+
+mod telemetry;
+pub use telemetry::{EndpointError, TelemetryStats, reset_stats, stats};
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
 #[cfg(feature = "translation")]
 pub mod translation;
 
@@ -110,9 +171,13 @@ pub mod translation;
 // High-level API - Primary interface for most use cases
 // ============================================================================
 pub mod domains;
+pub use adapters::limits::ResponseLimits;
+pub use adapters::pool::ConnectionPool;
 pub use providers::config::{Providers, ProvidersBuilder};
+pub use providers::custom::{DataProvider, ProviderQuote, SymbolMatch, YahooDataProvider};
 pub use providers::{Capability, Fetch, Operation, Provider};
-pub use ticker::{ClientHandle, Ticker, TickerBuilder};
+pub use symbol::Symbol;
+pub use ticker::{ClientHandle, FinanceClient, FinanceClientBuilder, Ticker, TickerBuilder};
 
 // Domain-specific query handles — constructable via Providers factory methods.
 #[cfg(any(
@@ -146,7 +211,7 @@ pub use finance::{
     analyst_recommendations, insider_trading,
 };
 #[cfg(feature = "alphavantage")]
-pub use finance::{EarningsCalendarEntry, IpoCalendarEntry, earnings_calendar, ipo_calendar};
+pub use finance::{alphavantage_earnings_calendar, alphavantage_ipo_calendar};
 
 pub use tickers::{
     BatchCapitalGainsResponse, BatchChartsResponse, BatchDividendsResponse,
@@ -175,35 +240,46 @@ pub use constants::indices::Region as IndicesRegion;
 pub use constants::screeners::Screener;
 pub use constants::sectors::Sector;
 pub use constants::{Frequency, Interval, Region, StatementType, TimeRange, ValueFormat};
+pub use models::format_options::{FormatOptions, PercentStyle, UnitStyle};
 
 // ============================================================================
 // Response types - Top-level types returned by API methods
 // ============================================================================
 pub use models::{
-    calendar::{CalendarEvent, EventKind},
+    calendar::{
+        CalendarEvent, EarningsCalendarEntry, EconomicEvent, EconomicEventCategory, EventKind,
+        IpoCalendarEntry, SplitCalendarEntry,
+    },
     chart::Chart,
     chart::spark::Spark,
+    corporate::actions::{CorporateAction, CorporateActionKind, CorporateActions},
     corporate::news::News,
     corporate::recommendation::Recommendation,
-    corporate::transcript::{Transcript, TranscriptWithMeta},
+    corporate::transcript::{FiscalPeriod, MatchedTranscript, Transcript, TranscriptWithMeta},
+    discovery::gaps::{GapBasis, Gapper},
     discovery::lookup::LookupResults,
-    discovery::screeners::ScreenerResults,
+    discovery::screeners::{ScreenerResults, Watchlist},
     discovery::search::SearchResults,
     discovery::trending::TrendingQuote,
     filings::{
         CompanyFacts, EdgarSearchResults, EdgarSubmissions, ProviderFiling, ProviderFilings,
     },
-    fundamentals::FinancialStatement,
-    market::currencies::Currency,
+    fundamentals::{FinancialStatement, FundFlowPoint, FundFlows},
+    market::currencies::{Currency, CurrencyInfo, NumberFormat, currency_info, format_amount},
+    market::eod::{EodSnapshot, EodSymbol},
     market::exchanges::Exchange,
+    market::global_snapshot::{GlobalSnapshot, RegionSnapshot},
     market::hours::MarketHours,
     market::industries::IndustryData,
-    market::market_summary::MarketSummaryQuote,
+    market::market_summary::{MarketSummaryHistory, MarketSummaryQuote},
     market::sectors::SectorData,
     options::Options,
-    quote::Quote,
+    quote::{BondQuote, Quote, QuoteChange, QuoteDiff, QuoteModule, TopOfBook},
     sentiment::{FearAndGreed, FearGreedLabel, SymbolSentiment},
 };
+// Newswire press releases (feature-gated)
+#[cfg(feature = "feeds")]
+pub use models::corporate::press_release::{PressRelease, PressWire};
 // Offline VADER sentiment scoring (feature-gated)
 #[cfg(feature = "sentiment")]
 pub use models::sentiment::{Sentiment, SentimentLabel, analyze as analyze_sentiment};
@@ -230,13 +306,22 @@ pub use models::indices::IndexQuote;
 // Nested types - Commonly accessed fields within response types
 // ============================================================================
 pub use models::{
-    chart::{Candle, CapitalGain, ChartMeta, Dividend, DividendAnalytics, Split},
+    chart::{
+        AdjustmentAudit, AdjustmentEvent, AdjustmentRange, AnnualDividend, Candle, CapitalGain,
+        ChartEvent, ChartEventKind, ChartMeta, ChartWithEvents, Dividend, DividendAnalysis,
+        DividendAnalytics, DividendYieldPoint, PayoutFrequency, RelativeVolume, Split, SplitPoint,
+    },
+    corporate::calendar_events::{CalendarEvents, EarningsCalendar, EstimateRange, KeyDates},
+    corporate::earnings::{EarningsChartData, EpsPoint, FinancialsPoint},
+    corporate::equity_performance::{PerformancePeriod, PerformanceSummary, PeriodReturn},
+    corporate::esg_scores::{EsgScores, PeerPerformance},
     corporate::recommendation::SimilarSymbol,
     discovery::lookup::LookupQuote,
     discovery::screeners::ScreenerQuote,
     discovery::search::{
         ResearchReport, ResearchReports, SearchNews, SearchNewsList, SearchQuote, SearchQuotes,
     },
+    discovery::trading_status::TradingStatus,
     filings::filing_index::{EdgarFilingIndex, EdgarFilingIndexItem},
     filings::{
         CikEntry, EdgarFiling, EdgarFilingFile, EdgarFilingRecent, EdgarFilings, EdgarSearchHit,
@@ -244,8 +329,12 @@ pub use models::{
         FactsByTaxonomy,
     },
     market::hours::MarketTime,
-    market::market_summary::SparkData,
-    options::{Contracts, OptionChain, OptionContract, OptionsQuote},
+    market::index_trend::{IndexTrend, IndustryTrend, SectorTrend, TrendEstimate},
+    market::market_summary::{MarketSummarySeries, SparkData},
+    options::{
+        BlackScholes, Contracts, Greeks, OptionChain, OptionChains, OptionContract, OptionKind,
+        OptionsQuote, OptionsStats,
+    },
     quote::FormattedValue,
 };
 
@@ -322,11 +411,15 @@ pub use indicators::{
     IchimokuData,
     Indicator,
     IndicatorError,
+    IndicatorKind,
     IndicatorResult,
+    IndicatorSelection,
+    IndicatorsSeries,
     IndicatorsSummary,
     KeltnerChannelsData,
     MacdData,
     MacdResult,
+    PatternMatch,
     PatternSentiment,
     StochasticData,
     SuperTrendData,
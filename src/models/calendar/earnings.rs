@@ -0,0 +1,141 @@
+//! Market-wide earnings calendar: who reports in a date window.
+
+use serde::{Deserialize, Serialize};
+
+use super::visualization::Page;
+
+/// A company scheduled to report earnings.
+///
+/// Obtain via [`finance::earnings_calendar`](crate::finance::earnings_calendar).
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarningsCalendarEntry {
+    /// Ticker symbol.
+    pub symbol: String,
+    /// Company name.
+    pub name: Option<String>,
+    /// Event name (e.g., `"Q3 2025 Earnings Call"`).
+    pub event_name: Option<String>,
+    /// Report date (`YYYY-MM-DD`).
+    pub report_date: Option<String>,
+    /// Report time as Unix seconds, when known.
+    pub timestamp: Option<i64>,
+    /// When in the day the company reports: `"BMO"` (before market open),
+    /// `"AMC"` (after market close), or `"TAS"`/`"TNS"` (time not supplied).
+    pub call_time: Option<String>,
+    /// Fiscal period end (`YYYY-MM-DD`).
+    pub fiscal_date_ending: Option<String>,
+    /// Consensus EPS estimate.
+    pub estimate: Option<f64>,
+    /// Reported EPS, once the company has reported.
+    pub eps_actual: Option<f64>,
+    /// EPS surprise versus the estimate, in percent.
+    pub surprise_percent: Option<f64>,
+    /// Intraday market capitalization.
+    pub market_cap: Option<f64>,
+    /// Currency.
+    pub currency: Option<String>,
+}
+
+impl EarningsCalendarEntry {
+    /// Parse one page of the visualization endpoint.
+    ///
+    /// Returns the entries and the total number of rows matching the query.
+    pub(crate) fn from_visualization(
+        raw: &serde_json::Value,
+    ) -> Result<(Vec<Self>, usize), String> {
+        let page = Page::parse(raw, "earnings calendar")?;
+        if page.is_empty() {
+            return Ok((Vec::new(), page.total));
+        }
+
+        let symbol_col = page.column("ticker").ok_or("Missing ticker column")?;
+        let name_col = page.column("companyshortname");
+        let event_col = page.column("eventname");
+        let start_col = page.column("startdatetime");
+        let call_time_col = page.column("startdatetimetype");
+        let estimate_col = page.column("epsestimate");
+        let actual_col = page.column("epsactual");
+        let surprise_col = page.column("epssurprisepct");
+        let market_cap_col = page.column("intradaymarketcap");
+
+        let entries = page
+            .rows()
+            .filter_map(|row| {
+                let start = row.datetime(start_col);
+                Some(Self {
+                    symbol: row.text(Some(symbol_col))?,
+                    name: row.text(name_col),
+                    event_name: row.text(event_col),
+                    report_date: start.map(|dt| dt.format("%Y-%m-%d").to_string()),
+                    timestamp: start.map(|dt| dt.timestamp()),
+                    call_time: row.text(call_time_col),
+                    estimate: row.number(estimate_col),
+                    eps_actual: row.number(actual_col),
+                    surprise_percent: row.number(surprise_col),
+                    market_cap: row.number(market_cap_col),
+                    ..Self::default()
+                })
+            })
+            .collect();
+        Ok((entries, page.total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_visualization() {
+        let raw = serde_json::json!({
+            "finance": {
+                "result": [{
+                    "documents": [{
+                        "columns": [
+                            {"id": "ticker", "label": "Symbol", "type": "STRING"},
+                            {"id": "companyshortname", "label": "Company", "type": "STRING"},
+                            {"id": "eventname", "label": "Event Name", "type": "STRING"},
+                            {"id": "startdatetime", "label": "Earnings Date", "type": "STRING"},
+                            {"id": "startdatetimetype", "label": "Call Time", "type": "STRING"},
+                            {"id": "epsestimate", "label": "EPS Estimate", "type": "NUMBER"},
+                            {"id": "epsactual", "label": "Reported EPS", "type": "NUMBER"},
+                            {"id": "epssurprisepct", "label": "Surprise (%)", "type": "NUMBER"}
+                        ],
+                        "rows": [
+                            ["AAPL", "Apple Inc.", "Q1 2024 Earnings Call", "2024-02-01T21:30:00.000Z", "AMC", 2.1, 2.18, 3.81],
+                            ["MSFT", "Microsoft Corporation", null, "2024-01-30T21:30:00.000Z", "AMC", 2.78, null, null],
+                            [null, "No symbol", null, null, null, null, null, null]
+                        ],
+                        "total": 120
+                    }]
+                }],
+                "error": null
+            }
+        });
+
+        let (entries, total) = EarningsCalendarEntry::from_visualization(&raw).unwrap();
+        assert_eq!(total, 120);
+        assert_eq!(entries.len(), 2);
+
+        let apple = &entries[0];
+        assert_eq!(apple.symbol, "AAPL");
+        assert_eq!(apple.name.as_deref(), Some("Apple Inc."));
+        assert_eq!(apple.report_date.as_deref(), Some("2024-02-01"));
+        assert_eq!(apple.timestamp, Some(1_706_823_000));
+        assert_eq!(apple.call_time.as_deref(), Some("AMC"));
+        assert_eq!(apple.estimate, Some(2.1));
+        assert_eq!(apple.eps_actual, Some(2.18));
+        assert_eq!(entries[1].event_name, None);
+        assert_eq!(entries[1].eps_actual, None);
+    }
+
+    #[test]
+    fn test_from_visualization_error() {
+        let raw = serde_json::json!({
+            "finance": {"result": null, "error": {"code": "Bad Request"}}
+        });
+        assert!(EarningsCalendarEntry::from_visualization(&raw).is_err());
+    }
+}
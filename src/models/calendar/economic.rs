@@ -0,0 +1,264 @@
+//! Market-wide economic calendar: scheduled macro data releases.
+
+use serde::{Deserialize, Serialize};
+
+use super::visualization::Page;
+
+/// A scheduled macroeconomic release (CPI, an FOMC decision, payrolls, GDP, ...).
+///
+/// Obtain via [`finance::economic_calendar`](crate::finance::economic_calendar).
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EconomicEvent {
+    /// Release name as Yahoo reports it (e.g., `"CPI YY"`, `"Non-Farm Payrolls"`).
+    pub event: String,
+    /// What the release measures, inferred from its name.
+    pub category: EconomicEventCategory,
+    /// Country code of the releasing economy (e.g., `"US"`, `"DE"`).
+    pub country: Option<String>,
+    /// Release date (`YYYY-MM-DD`).
+    pub release_date: Option<String>,
+    /// Release time as Unix seconds, when known.
+    pub timestamp: Option<i64>,
+    /// Period the figure covers (e.g., `"Sep"`, `"Q3"`).
+    pub period: Option<String>,
+    /// Reported value, once released.
+    pub actual: Option<f64>,
+    /// Consensus forecast.
+    pub consensus: Option<f64>,
+    /// Value reported for the prior period.
+    pub prior: Option<f64>,
+    /// Prior-period value as first reported, before any revision.
+    pub originally_reported: Option<f64>,
+}
+
+impl EconomicEvent {
+    /// Actual minus consensus, once the figure is out.
+    pub fn surprise(&self) -> Option<f64> {
+        Some(self.actual? - self.consensus?)
+    }
+
+    /// Whether the prior-period figure was revised since it was first reported.
+    pub fn prior_revised(&self) -> bool {
+        matches!((self.prior, self.originally_reported), (Some(p), Some(o)) if p != o)
+    }
+
+    /// Parse one page of the visualization endpoint.
+    ///
+    /// Returns the entries and the total number of rows matching the query.
+    pub(crate) fn from_visualization(
+        raw: &serde_json::Value,
+    ) -> Result<(Vec<Self>, usize), String> {
+        let page = Page::parse(raw, "economic calendar")?;
+        if page.is_empty() {
+            return Ok((Vec::new(), page.total));
+        }
+
+        let event_col = page
+            .column("econ_release")
+            .ok_or("Missing econ_release column")?;
+        let country_col = page.column("country_code");
+        let start_col = page.column("startdatetime");
+        let period_col = page.column("period");
+        let actual_col = page.column("after_release_actual");
+        let consensus_col = page.column("consensus_estimate");
+        let prior_col = page.column("prior_release_actual");
+        let original_col = page.column("originally_reported_actual");
+
+        let entries = page
+            .rows()
+            .filter_map(|row| {
+                let event = row.text(Some(event_col))?;
+                let start = row.datetime(start_col);
+                Some(Self {
+                    category: EconomicEventCategory::classify(&event),
+                    event,
+                    country: row.text(country_col).map(|c| c.to_ascii_uppercase()),
+                    release_date: start.map(|dt| dt.format("%Y-%m-%d").to_string()),
+                    timestamp: start.map(|dt| dt.timestamp()),
+                    period: row.text(period_col),
+                    actual: row.number(actual_col),
+                    consensus: row.number(consensus_col),
+                    prior: row.number(prior_col),
+                    originally_reported: row.number(original_col),
+                })
+            })
+            .collect();
+        Ok((entries, page.total))
+    }
+}
+
+/// Broad grouping of an [`EconomicEvent`], for filtering the calendar down to
+/// the releases a strategy cares about.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EconomicEventCategory {
+    /// Price indices: CPI, PPI, PCE, deflators.
+    Inflation,
+    /// Central-bank rate decisions, minutes, and policy statements.
+    CentralBank,
+    /// Payrolls, unemployment, jobless claims, job openings.
+    Employment,
+    /// GDP and national accounts.
+    Growth,
+    /// PMIs, industrial production, factory and durable-goods orders.
+    Manufacturing,
+    /// Retail sales, consumer confidence and sentiment, personal spending.
+    Consumer,
+    /// Housing starts, home sales, building permits, mortgage activity.
+    Housing,
+    /// Trade balance, imports and exports, current account.
+    Trade,
+    /// Anything not matched above (speeches, auctions, niche surveys).
+    #[default]
+    Other,
+}
+
+impl EconomicEventCategory {
+    /// Infer the category from a release name.
+    ///
+    /// Abbreviations match whole words so `"ISM"` doesn't fire on unrelated
+    /// names; longer phrases match anywhere. Checked in declaration order, so
+    /// `"Consumer Price Index"` is inflation rather than consumer.
+    pub fn classify(name: &str) -> Self {
+        let lower = name.to_ascii_lowercase();
+        let words: Vec<&str> = lower
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .collect();
+        let has_word = |list: &[&str]| list.iter().any(|w| words.contains(w));
+        let has_phrase = |list: &[&str]| list.iter().any(|p| lower.contains(p));
+
+        if has_word(&["cpi", "ppi", "pce", "hicp", "rpi", "deflator", "inflation"])
+            || has_phrase(&["price index", "prices index"])
+        {
+            Self::Inflation
+        } else if has_word(&[
+            "fomc", "fed", "ecb", "boe", "boj", "rba", "boc", "snb", "rbnz",
+        ]) || has_phrase(&[
+            "interest rate",
+            "rate decision",
+            "monetary policy",
+            "federal funds",
+        ]) {
+            Self::CentralBank
+        } else if has_word(&[
+            "payrolls",
+            "unemployment",
+            "jobless",
+            "jolts",
+            "employment",
+            "jobs",
+        ]) || has_phrase(&[
+            "non-farm",
+            "nonfarm",
+            "job openings",
+            "labor market",
+            "claimant count",
+        ]) {
+            Self::Employment
+        } else if has_word(&["gdp"]) || has_phrase(&["gross domestic"]) {
+            Self::Growth
+        } else if has_word(&["pmi", "ism"])
+            || has_phrase(&[
+                "industrial production",
+                "manufacturing",
+                "factory",
+                "durable",
+            ])
+        {
+            Self::Manufacturing
+        } else if has_phrase(&[
+            "retail sales",
+            "consumer confidence",
+            "consumer sentiment",
+            "personal spending",
+            "personal income",
+            "consumer credit",
+        ]) {
+            Self::Consumer
+        } else if has_phrase(&[
+            "housing",
+            "home sales",
+            "building permits",
+            "mortgage",
+            "house price",
+        ]) {
+            Self::Housing
+        } else if has_word(&["exports", "imports"])
+            || has_phrase(&["trade balance", "current account"])
+        {
+            Self::Trade
+        } else {
+            Self::Other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_visualization() {
+        let raw = serde_json::json!({
+            "finance": {
+                "result": [{
+                    "documents": [{
+                        "columns": [
+                            {"id": "econ_release"},
+                            {"id": "country_code"},
+                            {"id": "startdatetime"},
+                            {"id": "period"},
+                            {"id": "after_release_actual"},
+                            {"id": "consensus_estimate"},
+                            {"id": "prior_release_actual"},
+                            {"id": "originally_reported_actual"}
+                        ],
+                        "rows": [
+                            ["CPI YY", "us", "2024-06-12T12:30:00.000Z", "May", 3.3, 3.4, 3.4, 3.4],
+                            ["Non-Farm Payrolls", "US", "2024-06-07T12:30:00.000Z", "May", null, 180.0, 165.0, 175.0]
+                        ],
+                        "total": 2
+                    }]
+                }],
+                "error": null
+            }
+        });
+
+        let (events, total) = EconomicEvent::from_visualization(&raw).unwrap();
+        assert_eq!(total, 2);
+        let cpi = &events[0];
+        assert_eq!(cpi.category, EconomicEventCategory::Inflation);
+        assert_eq!(cpi.country.as_deref(), Some("US"));
+        assert_eq!(cpi.release_date.as_deref(), Some("2024-06-12"));
+        assert!((cpi.surprise().unwrap() + 0.1).abs() < 1e-9);
+        assert!(!cpi.prior_revised());
+        let nfp = &events[1];
+        assert_eq!(nfp.category, EconomicEventCategory::Employment);
+        assert_eq!(nfp.surprise(), None);
+        assert!(nfp.prior_revised());
+    }
+
+    #[test]
+    fn test_classify() {
+        use EconomicEventCategory::*;
+        let cases = [
+            ("Core PCE Price Index MM", Inflation),
+            ("Fed Interest Rate Decision", CentralBank),
+            ("FOMC Minutes", CentralBank),
+            ("Initial Jobless Claims", Employment),
+            ("GDP Advance", Growth),
+            ("ISM Manufacturing PMI", Manufacturing),
+            ("Retail Sales MM", Consumer),
+            ("Housing Starts", Housing),
+            ("Trade Balance", Trade),
+            ("Tourism Arrivals", Other),
+        ];
+        for (name, expected) in cases {
+            assert_eq!(EconomicEventCategory::classify(name), expected, "{name}");
+        }
+    }
+}
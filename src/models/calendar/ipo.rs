@@ -0,0 +1,138 @@
+//! Market-wide IPO calendar: which companies list in a date window.
+
+use serde::{Deserialize, Serialize};
+
+use super::visualization::Page;
+
+/// A company scheduled to go public (or that recently priced).
+///
+/// Obtain via [`finance::ipo_calendar`](crate::finance::ipo_calendar).
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IpoCalendarEntry {
+    /// Ticker symbol.
+    pub symbol: String,
+    /// Company name.
+    pub name: Option<String>,
+    /// Listing exchange (e.g., `"NASDAQ"`).
+    pub exchange: Option<String>,
+    /// Date the registration was filed (`YYYY-MM-DD`).
+    pub filing_date: Option<String>,
+    /// Expected or actual first trading date (`YYYY-MM-DD`).
+    pub ipo_date: Option<String>,
+    /// Date the filing was last amended (`YYYY-MM-DD`).
+    pub amended_date: Option<String>,
+    /// Low end of the marketed price range.
+    pub price_from: Option<f64>,
+    /// High end of the marketed price range.
+    pub price_to: Option<f64>,
+    /// Final offer price, once priced.
+    pub offer_price: Option<f64>,
+    /// Currency of the prices.
+    pub currency: Option<String>,
+    /// Number of shares offered.
+    pub shares: Option<i64>,
+    /// Deal status (e.g., `"EXPECTED"`, `"PRICED"`, `"WITHDRAWN"`).
+    pub deal_type: Option<String>,
+}
+
+impl IpoCalendarEntry {
+    /// Parse one page of the visualization endpoint.
+    ///
+    /// Returns the entries and the total number of rows matching the query.
+    pub(crate) fn from_visualization(
+        raw: &serde_json::Value,
+    ) -> Result<(Vec<Self>, usize), String> {
+        let page = Page::parse(raw, "IPO calendar")?;
+        if page.is_empty() {
+            return Ok((Vec::new(), page.total));
+        }
+
+        let symbol_col = page.column("ticker").ok_or("Missing ticker column")?;
+        let name_col = page.column("companyshortname");
+        let exchange_col = page.column("exchange_short_name");
+        let filing_col = page.column("filingdate");
+        let start_col = page.column("startdatetime");
+        let amended_col = page.column("amendeddate");
+        let from_col = page.column("pricefrom");
+        let to_col = page.column("priceto");
+        let offer_col = page.column("offerprice");
+        let currency_col = page.column("currencyname");
+        let shares_col = page.column("shares");
+        let deal_col = page.column("dealtype");
+
+        let date = |dt: chrono::DateTime<chrono::FixedOffset>| dt.format("%Y-%m-%d").to_string();
+        let entries = page
+            .rows()
+            .filter_map(|row| {
+                Some(Self {
+                    symbol: row.text(Some(symbol_col))?,
+                    name: row.text(name_col),
+                    exchange: row.text(exchange_col),
+                    filing_date: row.datetime(filing_col).map(date),
+                    ipo_date: row.datetime(start_col).map(date),
+                    amended_date: row.datetime(amended_col).map(date),
+                    price_from: row.number(from_col),
+                    price_to: row.number(to_col),
+                    offer_price: row.number(offer_col),
+                    currency: row.text(currency_col),
+                    shares: row.number(shares_col).map(|n| n as i64),
+                    deal_type: row.text(deal_col),
+                })
+            })
+            .collect();
+        Ok((entries, page.total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_visualization() {
+        let raw = serde_json::json!({
+            "finance": {
+                "result": [{
+                    "documents": [{
+                        "columns": [
+                            {"id": "ticker"},
+                            {"id": "companyshortname"},
+                            {"id": "exchange_short_name"},
+                            {"id": "filingdate"},
+                            {"id": "startdatetime"},
+                            {"id": "amendeddate"},
+                            {"id": "pricefrom"},
+                            {"id": "priceto"},
+                            {"id": "offerprice"},
+                            {"id": "currencyname"},
+                            {"id": "shares"},
+                            {"id": "dealtype"}
+                        ],
+                        "rows": [
+                            ["NEWC", "NewCo Inc.", "NASDAQ", "2024-05-01T00:00:00.000Z",
+                             "2024-06-12T00:00:00.000Z", null, 15.0, 17.0, null, "USD", 10000000, "EXPECTED"]
+                        ],
+                        "total": 1
+                    }]
+                }],
+                "error": null
+            }
+        });
+
+        let (entries, total) = IpoCalendarEntry::from_visualization(&raw).unwrap();
+        assert_eq!(total, 1);
+        let ipo = &entries[0];
+        assert_eq!(ipo.symbol, "NEWC");
+        assert_eq!(ipo.exchange.as_deref(), Some("NASDAQ"));
+        assert_eq!(ipo.filing_date.as_deref(), Some("2024-05-01"));
+        assert_eq!(ipo.ipo_date.as_deref(), Some("2024-06-12"));
+        assert_eq!(ipo.amended_date, None);
+        assert_eq!(ipo.price_from, Some(15.0));
+        assert_eq!(ipo.price_to, Some(17.0));
+        assert_eq!(ipo.offer_price, None);
+        assert_eq!(ipo.shares, Some(10_000_000));
+        assert_eq!(ipo.deal_type.as_deref(), Some("EXPECTED"));
+    }
+}
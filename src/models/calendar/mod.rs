@@ -5,11 +5,29 @@
 //! feature) a market-wide economic-data release.
 //!
 //! Construct calendars via [`Ticker::calendar`](crate::Ticker::calendar) and
-//! [`Tickers::calendar`](crate::Tickers::calendar).
+//! [`Tickers::calendar`](crate::Tickers::calendar). The market-wide
+//! [`EarningsCalendarEntry`], [`IpoCalendarEntry`], [`SplitCalendarEntry`],
+//! and [`EconomicEvent`] lists come from
+//! [`finance::earnings_calendar`](crate::finance::earnings_calendar),
+//! [`finance::ipo_calendar`](crate::finance::ipo_calendar),
+//! [`finance::splits_calendar`](crate::finance::splits_calendar), and
+//! [`finance::economic_calendar`](crate::finance::economic_calendar).
+
+mod earnings;
+mod economic;
+mod ipo;
+mod splits;
+mod visualization;
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+pub use earnings::EarningsCalendarEntry;
+pub use economic::{EconomicEvent, EconomicEventCategory};
+pub use ipo::IpoCalendarEntry;
+pub use splits::SplitCalendarEntry;
+pub(crate) use visualization::ParsePage;
+
 use crate::models::options::Options;
 use crate::models::quote::CalendarEvents;
 
@@ -0,0 +1,118 @@
+//! Market-wide stock-split calendar: which companies split in a date window.
+
+use serde::{Deserialize, Serialize};
+
+use super::visualization::Page;
+
+/// A scheduled (or recent) stock split.
+///
+/// Obtain via [`finance::splits_calendar`](crate::finance::splits_calendar).
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SplitCalendarEntry {
+    /// Ticker symbol.
+    pub symbol: String,
+    /// Company name.
+    pub name: Option<String>,
+    /// Date the split takes effect (`YYYY-MM-DD`).
+    pub split_date: Option<String>,
+    /// Split time as Unix seconds, when known.
+    pub timestamp: Option<i64>,
+    /// Whether the stock has listed options.
+    pub optionable: Option<bool>,
+    /// Shares held before the split (the `1` in a 4-for-1 split).
+    pub old_shares: Option<f64>,
+    /// Shares held after the split (the `4` in a 4-for-1 split).
+    pub new_shares: Option<f64>,
+}
+
+impl SplitCalendarEntry {
+    /// New shares per old share: `4.0` for a 4-for-1 split, `0.1` for a
+    /// 1-for-10 reverse split.
+    pub fn ratio(&self) -> Option<f64> {
+        match (self.new_shares, self.old_shares) {
+            (Some(new), Some(old)) if old > 0.0 => Some(new / old),
+            _ => None,
+        }
+    }
+
+    /// Parse one page of the visualization endpoint.
+    ///
+    /// Returns the entries and the total number of rows matching the query.
+    pub(crate) fn from_visualization(
+        raw: &serde_json::Value,
+    ) -> Result<(Vec<Self>, usize), String> {
+        let page = Page::parse(raw, "splits calendar")?;
+        if page.is_empty() {
+            return Ok((Vec::new(), page.total));
+        }
+
+        let symbol_col = page.column("ticker").ok_or("Missing ticker column")?;
+        let name_col = page.column("companyshortname");
+        let start_col = page.column("startdatetime");
+        let optionable_col = page.column("optionable");
+        let old_col = page.column("old_share_worth");
+        let new_col = page.column("share_worth");
+
+        let entries = page
+            .rows()
+            .filter_map(|row| {
+                let start = row.datetime(start_col);
+                Some(Self {
+                    symbol: row.text(Some(symbol_col))?,
+                    name: row.text(name_col),
+                    split_date: start.map(|dt| dt.format("%Y-%m-%d").to_string()),
+                    timestamp: start.map(|dt| dt.timestamp()),
+                    optionable: row
+                        .text(optionable_col)
+                        .map(|s| s.eq_ignore_ascii_case("yes")),
+                    old_shares: row.number(old_col),
+                    new_shares: row.number(new_col),
+                })
+            })
+            .collect();
+        Ok((entries, page.total))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_visualization() {
+        let raw = serde_json::json!({
+            "finance": {
+                "result": [{
+                    "documents": [{
+                        "columns": [
+                            {"id": "ticker"},
+                            {"id": "companyshortname"},
+                            {"id": "startdatetime"},
+                            {"id": "optionable"},
+                            {"id": "old_share_worth"},
+                            {"id": "share_worth"}
+                        ],
+                        "rows": [
+                            ["NVDA", "NVIDIA Corporation", "2024-06-10T00:00:00.000Z", "Yes", 1, 10],
+                            ["TINY", "Tiny Corp", "2024-06-11T00:00:00.000Z", "No", 10, 1]
+                        ],
+                        "total": 2
+                    }]
+                }],
+                "error": null
+            }
+        });
+
+        let (entries, total) = SplitCalendarEntry::from_visualization(&raw).unwrap();
+        assert_eq!(total, 2);
+        let nvda = &entries[0];
+        assert_eq!(nvda.symbol, "NVDA");
+        assert_eq!(nvda.split_date.as_deref(), Some("2024-06-10"));
+        assert_eq!(nvda.optionable, Some(true));
+        assert_eq!(nvda.ratio(), Some(10.0));
+        assert_eq!(entries[1].optionable, Some(false));
+        assert_eq!(entries[1].ratio(), Some(0.1));
+    }
+}
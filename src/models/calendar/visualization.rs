@@ -0,0 +1,126 @@
+//! Shared parsing for Yahoo's visualization endpoint, which backs the
+//! market-wide earnings, IPO, and stock-split calendars.
+//!
+//! Each response carries one document of column descriptors plus positional
+//! rows; [`Page`] resolves columns by id so the calendar models don't depend
+//! on column order.
+
+use chrono::{DateTime, FixedOffset};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Parses one page into typed entries plus the total matching row count.
+pub(crate) type ParsePage<T> = fn(&Value) -> Result<(Vec<T>, usize), String>;
+
+#[derive(Debug, Deserialize)]
+struct RawVisualizationResponse {
+    finance: RawFinance,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawFinance {
+    result: Option<Vec<RawResult>>,
+    error: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawResult {
+    #[serde(default)]
+    documents: Vec<RawDocument>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawDocument {
+    #[serde(default)]
+    columns: Vec<RawColumn>,
+    #[serde(default)]
+    rows: Vec<Vec<Value>>,
+    total: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawColumn {
+    id: String,
+}
+
+/// One page of visualization rows.
+pub(crate) struct Page {
+    columns: Vec<String>,
+    rows: Vec<Vec<Value>>,
+    /// Total number of rows matching the query, across all pages.
+    pub(crate) total: usize,
+}
+
+impl Page {
+    /// Parse a response; an empty result yields an empty page.
+    pub(crate) fn parse(raw: &Value, what: &str) -> Result<Self, String> {
+        let response: RawVisualizationResponse = serde_json::from_value(raw.clone())
+            .map_err(|e| format!("Failed to parse {} response: {}", what, e))?;
+        if let Some(err) = response.finance.error.filter(|e| !e.is_null()) {
+            return Err(format!("Yahoo Finance error: {}", err));
+        }
+        let Some(document) = response
+            .finance
+            .result
+            .and_then(|r| r.into_iter().next())
+            .and_then(|r| r.documents.into_iter().next())
+        else {
+            return Ok(Self {
+                columns: Vec::new(),
+                rows: Vec::new(),
+                total: 0,
+            });
+        };
+        Ok(Self {
+            total: document.total.unwrap_or(document.rows.len()),
+            columns: document.columns.into_iter().map(|c| c.id).collect(),
+            rows: document.rows,
+        })
+    }
+
+    /// Whether the page has no rows (and so no columns to check).
+    pub(crate) fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Position of the column with this id.
+    pub(crate) fn column(&self, id: &str) -> Option<usize> {
+        self.columns.iter().position(|c| c == id)
+    }
+
+    /// Rows, each wrapped for typed cell access.
+    pub(crate) fn rows(&self) -> impl Iterator<Item = Row<'_>> {
+        self.rows.iter().map(|cells| Row { cells })
+    }
+}
+
+/// One positional row of a [`Page`].
+#[derive(Clone, Copy)]
+pub(crate) struct Row<'a> {
+    cells: &'a [Value],
+}
+
+impl Row<'_> {
+    fn cell(&self, col: Option<usize>) -> Option<&Value> {
+        col.and_then(|i| self.cells.get(i))
+    }
+
+    /// Non-empty string cell.
+    pub(crate) fn text(&self, col: Option<usize>) -> Option<String> {
+        self.cell(col)
+            .and_then(Value::as_str)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+    }
+
+    /// Numeric cell.
+    pub(crate) fn number(&self, col: Option<usize>) -> Option<f64> {
+        self.cell(col)?.as_f64()
+    }
+
+    /// RFC 3339 timestamp cell.
+    pub(crate) fn datetime(&self, col: Option<usize>) -> Option<DateTime<FixedOffset>> {
+        self.text(col)
+            .and_then(|s| DateTime::parse_from_rfc3339(&s).ok())
+    }
+}
@@ -0,0 +1,398 @@
+//! Dividend-adjusted charts with an audit trail.
+//!
+//! Yahoo's OHLC are split-adjusted but not dividend-adjusted; only
+//! `adj_close` reflects cash distributions. [`Chart::auto_adjust`] rescales
+//! every price by `adj_close / close` and records which factor was applied
+//! to which candles, so the result can be checked against the corporate
+//! actions behind it or reconciled with another vendor's series.
+
+use serde::{Deserialize, Serialize};
+
+use super::annotated::{ChartEvent, ChartEventKind, ChartWithEvents};
+use super::{Candle, Chart};
+
+/// Relative difference below which two candles' factors count as equal.
+/// Absorbs the rounding in Yahoo's `adj_close`.
+const FACTOR_TOLERANCE: f64 = 1e-5;
+
+/// Price factor a cash distribution of `amount` implies for the candles
+/// before it: `1 - amount / prior_close`. `None` unless `prior_close` is
+/// positive.
+pub(super) fn distribution_factor(amount: f64, prior_close: f64) -> Option<f64> {
+    (prior_close > 0.0).then(|| 1.0 - amount / prior_close)
+}
+
+/// A run of consecutive candles adjusted by the same cumulative factor.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdjustmentRange {
+    /// Index of the first candle in the range
+    pub start_index: usize,
+    /// Index of the last candle in the range (inclusive)
+    pub end_index: usize,
+    /// Timestamp of the first candle
+    pub start_timestamp: i64,
+    /// Timestamp of the last candle
+    pub end_timestamp: i64,
+    /// Factor applied to open, high, low, and close (`adj_close / close`)
+    pub factor: f64,
+}
+
+/// A corporate action that falls within an adjusted chart.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdjustmentEvent {
+    /// Event timestamp (Unix)
+    pub timestamp: i64,
+    /// Index of the candle the event falls on; candles before it are adjusted
+    pub candle_index: usize,
+    /// Event type and data
+    #[serde(flatten)]
+    pub kind: ChartEventKind,
+    /// Price factor the event implies for earlier candles:
+    /// `1 - amount / prior close` for dividends and capital gains,
+    /// `denominator / numerator` for splits. `None` when the event falls on
+    /// the first candle, leaving no prior close.
+    pub factor: Option<f64>,
+}
+
+/// How a chart's prices were adjusted.
+///
+/// Set on [`Chart::adjustment`] by [`Chart::auto_adjust`] and
+/// [`ChartWithEvents::auto_adjust`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdjustmentAudit {
+    /// Corporate actions within the chart, sorted by candle. Empty when the
+    /// chart was adjusted without events.
+    pub events: Vec<AdjustmentEvent>,
+    /// Cumulative factor per candle range, covering every candle in order
+    pub ranges: Vec<AdjustmentRange>,
+}
+
+impl AdjustmentAudit {
+    /// Factor that was applied to the candle at `index`.
+    pub fn factor_at(&self, index: usize) -> Option<f64> {
+        self.ranges
+            .iter()
+            .find(|r| r.start_index <= index && index <= r.end_index)
+            .map(|r| r.factor)
+    }
+
+    /// Factor the recorded cash distributions imply for the candle at
+    /// `index`: the product of every later dividend and capital gain factor.
+    ///
+    /// Splits are left out because the provider's prices already include
+    /// them. Compare with [`factor_at`](Self::factor_at) to spot missing or
+    /// mismatched events.
+    pub fn event_factor_at(&self, index: usize) -> f64 {
+        self.events
+            .iter()
+            .filter(|e| e.candle_index > index)
+            .filter(|e| {
+                matches!(
+                    e.kind,
+                    ChartEventKind::Dividend { .. } | ChartEventKind::CapitalGain { .. }
+                )
+            })
+            .filter_map(|e| e.factor)
+            .product()
+    }
+
+    /// The audit for candles `start..end`, re-indexed from zero.
+    pub(crate) fn slice(&self, start: usize, end: usize) -> Self {
+        let ranges = self
+            .ranges
+            .iter()
+            .filter(|r| r.end_index >= start && r.start_index < end)
+            .map(|r| {
+                let mut r = r.clone();
+                r.start_index = r.start_index.max(start) - start;
+                r.end_index = r.end_index.min(end - 1) - start;
+                r
+            })
+            .collect();
+        let events = self
+            .events
+            .iter()
+            .filter(|e| (start..end).contains(&e.candle_index))
+            .map(|e| {
+                let mut e = e.clone();
+                e.candle_index -= start;
+                e
+            })
+            .collect();
+        Self { events, ranges }
+    }
+}
+
+impl Chart {
+    /// Rescale open, high, low, and close by each candle's `adj_close / close`.
+    ///
+    /// The returned chart has `close == adj_close` on every candle and an
+    /// [`adjustment`](Chart::adjustment) audit listing the factor applied to
+    /// each run of candles. Volume is left as reported. Candles without an
+    /// `adj_close` keep a factor of 1. A chart that is already adjusted is
+    /// returned unchanged.
+    ///
+    /// Use [`ChartWithEvents::auto_adjust`] to also record the dividends and
+    /// splits behind each factor.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::{Interval, Ticker, TimeRange};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ticker = Ticker::new("AAPL").await?;
+    /// let chart = ticker.chart(Interval::OneDay, TimeRange::OneYear).await?;
+    /// let adjusted = chart.auto_adjust();
+    ///
+    /// if let Some(audit) = &adjusted.adjustment {
+    ///     for range in &audit.ranges {
+    ///         println!("bars {}..={}: x{:.6}", range.start_index, range.end_index, range.factor);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn auto_adjust(&self) -> Chart {
+        self.adjust_with(&[])
+    }
+
+    fn adjust_with(&self, events: &[ChartEvent]) -> Chart {
+        if self.adjustment.is_some() {
+            return self.clone();
+        }
+
+        let mut ranges: Vec<AdjustmentRange> = Vec::new();
+        let candles: Vec<Candle> = self
+            .candles
+            .iter()
+            .enumerate()
+            .map(|(i, candle)| {
+                let factor = match candle.adj_close {
+                    Some(adj) if adj.is_finite() && adj > 0.0 && candle.close > 0.0 => {
+                        adj / candle.close
+                    }
+                    _ => 1.0,
+                };
+                match ranges.last_mut() {
+                    Some(range)
+                        if (factor - range.factor).abs() <= FACTOR_TOLERANCE * range.factor =>
+                    {
+                        range.end_index = i;
+                        range.end_timestamp = candle.timestamp;
+                    }
+                    _ => ranges.push(AdjustmentRange {
+                        start_index: i,
+                        end_index: i,
+                        start_timestamp: candle.timestamp,
+                        end_timestamp: candle.timestamp,
+                        factor,
+                    }),
+                }
+
+                let mut adjusted = candle.clone();
+                adjusted.open *= factor;
+                adjusted.high *= factor;
+                adjusted.low *= factor;
+                adjusted.close *= factor;
+                adjusted.adj_close = Some(adjusted.close);
+                adjusted
+            })
+            .collect();
+
+        let events = events
+            .iter()
+            .map(|e| {
+                let prior_close = e
+                    .candle_index
+                    .checked_sub(1)
+                    .and_then(|i| self.candles.get(i))
+                    .map(|c| c.close);
+                let factor = match &e.kind {
+                    ChartEventKind::Dividend { amount }
+                    | ChartEventKind::CapitalGain { amount } => {
+                        prior_close.and_then(|close| distribution_factor(*amount, close))
+                    }
+                    ChartEventKind::Split {
+                        numerator,
+                        denominator,
+                        ..
+                    } => (*numerator > 0.0).then(|| denominator / numerator),
+                };
+                AdjustmentEvent {
+                    timestamp: e.timestamp,
+                    candle_index: e.candle_index,
+                    kind: e.kind.clone(),
+                    factor,
+                }
+            })
+            .collect();
+
+        Chart {
+            symbol: self.symbol.clone(),
+            meta: self.meta.clone(),
+            candles,
+            interval: self.interval,
+            range: self.range,
+            provider_id: self.provider_id,
+            adjustment: Some(AdjustmentAudit { events, ranges }),
+        }
+    }
+}
+
+impl ChartWithEvents {
+    /// Adjust the chart as [`Chart::auto_adjust`] does, recording this
+    /// chart's dividends, splits, and capital gains in the audit trail.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::{Interval, Ticker, TimeRange};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ticker = Ticker::new("AAPL").await?;
+    /// let chart = ticker
+    ///     .chart_with_events(Interval::OneDay, TimeRange::FiveYears)
+    ///     .await?
+    ///     .auto_adjust();
+    ///
+    /// let audit = chart.chart.adjustment.as_ref().unwrap();
+    /// for event in &audit.events {
+    ///     println!("{:?} at bar {}: x{:?}", event.kind, event.candle_index, event.factor);
+    /// }
+    /// // Applied vs. event-implied factor for the first bar
+    /// println!("{:?} vs {:.6}", audit.factor_at(0), audit.event_factor_at(0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn auto_adjust(&self) -> ChartWithEvents {
+        ChartWithEvents {
+            chart: self.chart.adjust_with(&self.events),
+            events: self.events.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::Interval;
+    use crate::models::chart::{ChartMeta, Dividend, Split};
+
+    const DAY: i64 = 86_400;
+
+    /// Five flat bars at 100 with a $1 dividend going ex on bar 3.
+    fn chart() -> Chart {
+        Chart {
+            symbol: "TEST".to_string(),
+            meta: ChartMeta::default(),
+            candles: (0..5)
+                .map(|i| Candle {
+                    timestamp: i * DAY,
+                    open: 100.0,
+                    high: 102.0,
+                    low: 98.0,
+                    close: 100.0,
+                    volume: 1_000,
+                    adj_close: Some(if i < 3 { 99.0 } else { 100.0 }),
+                    provider_id: None,
+                })
+                .collect(),
+            interval: Some(Interval::OneDay),
+            range: None,
+            provider_id: None,
+            adjustment: None,
+        }
+    }
+
+    #[test]
+    fn test_auto_adjust_scales_prices_and_records_ranges() {
+        let adjusted = chart().auto_adjust();
+        let first = &adjusted.candles[0];
+        assert!((first.open - 99.0).abs() < 1e-9);
+        assert!((first.high - 100.98).abs() < 1e-9);
+        assert_eq!(first.adj_close, Some(first.close));
+        assert_eq!(first.volume, 1_000);
+        assert_eq!(adjusted.candles[4].close, 100.0);
+
+        let audit = adjusted.adjustment.as_ref().unwrap();
+        assert!(audit.events.is_empty());
+        assert_eq!(audit.ranges.len(), 2);
+        assert_eq!(
+            (audit.ranges[0].start_index, audit.ranges[0].end_index),
+            (0, 2)
+        );
+        assert!((audit.ranges[0].factor - 0.99).abs() < 1e-12);
+        assert_eq!(audit.ranges[1].start_timestamp, 3 * DAY);
+        assert_eq!(audit.factor_at(4), Some(1.0));
+        assert_eq!(audit.factor_at(5), None);
+
+        // Already adjusted: unchanged
+        let again = adjusted.auto_adjust();
+        assert_eq!(again.adjustment, adjusted.adjustment);
+        assert_eq!(again.candles[0].close, adjusted.candles[0].close);
+    }
+
+    #[test]
+    fn test_auto_adjust_with_events_reconciles() {
+        let dividends = [Dividend {
+            timestamp: 3 * DAY,
+            amount: 1.0,
+            provider_id: None,
+        }];
+        let splits = [Split {
+            timestamp: DAY,
+            numerator: 2.0,
+            denominator: 1.0,
+            ratio: "2:1".to_string(),
+            provider_id: None,
+        }];
+        let adjusted = chart().annotate(&dividends, &splits, &[]).auto_adjust();
+        let audit = adjusted.chart.adjustment.as_ref().unwrap();
+
+        assert_eq!(audit.events.len(), 2);
+        assert_eq!(audit.events[0].factor, Some(0.5));
+        assert!((audit.events[1].factor.unwrap() - 0.99).abs() < 1e-12);
+        for i in 0..5 {
+            let applied = audit.factor_at(i).unwrap();
+            assert!((applied - audit.event_factor_at(i)).abs() < 1e-9, "bar {i}");
+        }
+
+        let json = serde_json::to_value(audit).unwrap();
+        assert_eq!(json["events"][1]["type"], "dividend");
+        assert_eq!(json["ranges"][0]["endIndex"], 2);
+    }
+
+    #[test]
+    fn test_split_at_rebases_audit() {
+        let dividends = [Dividend {
+            timestamp: 3 * DAY,
+            amount: 1.0,
+            provider_id: None,
+        }];
+        let adjusted = chart().annotate(&dividends, &[], &[]).auto_adjust();
+        let (train, test) = adjusted.chart.split_at(2 * DAY).unwrap();
+
+        let train_audit = train.adjustment.unwrap();
+        assert_eq!(train_audit.ranges.len(), 1);
+        assert_eq!(train_audit.ranges[0].end_index, 1);
+        assert!(train_audit.events.is_empty());
+
+        let test_audit = test.adjustment.unwrap();
+        assert_eq!(test_audit.ranges.len(), 2);
+        assert_eq!(
+            (
+                test_audit.ranges[0].start_index,
+                test_audit.ranges[0].end_index
+            ),
+            (0, 0)
+        );
+        assert_eq!(test_audit.events[0].candle_index, 1);
+    }
+}
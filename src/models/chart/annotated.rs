@@ -0,0 +1,294 @@
+//! Charts annotated with corporate events.
+//!
+//! Dividends, splits, and capital gains come from separate calls and carry
+//! only timestamps. [`ChartWithEvents`] merges them into one time-ordered list
+//! where every event points at the candle it falls on, which is what plotting
+//! code and event-aware strategies need.
+
+use serde::{Deserialize, Serialize};
+
+use super::events::{CapitalGain, Dividend, Split};
+use super::{Candle, Chart};
+use crate::constants::TimeRange;
+use crate::error::Result;
+
+/// Kind-specific data of a [`ChartEvent`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ChartEventKind {
+    /// Cash dividend
+    Dividend {
+        /// Dividend amount per share
+        amount: f64,
+    },
+    /// Stock split
+    #[serde(rename_all = "camelCase")]
+    Split {
+        /// Numerator of the split ratio
+        numerator: f64,
+        /// Denominator of the split ratio
+        denominator: f64,
+        /// Split ratio as string (e.g., "4:1")
+        ratio: String,
+    },
+    /// Capital gain distribution
+    CapitalGain {
+        /// Capital gain amount per share
+        amount: f64,
+    },
+}
+
+/// A corporate event aligned to a chart candle.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartEvent {
+    /// Event timestamp (Unix)
+    pub timestamp: i64,
+    /// Index into [`Chart::candles`] of the candle whose period contains the event
+    pub candle_index: usize,
+    /// Event type and data
+    #[serde(flatten)]
+    pub kind: ChartEventKind,
+}
+
+/// A chart together with the corporate events that fall within it.
+///
+/// Obtain via [`Ticker::chart_with_events`](crate::Ticker::chart_with_events),
+/// [`Chart::with_events`], or [`Chart::annotate`]. Serializes as the chart's
+/// fields plus an `events` array.
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChartWithEvents {
+    /// The underlying chart
+    #[serde(flatten)]
+    pub chart: Chart,
+    /// Events within the chart's span, sorted by timestamp
+    pub events: Vec<ChartEvent>,
+}
+
+impl ChartWithEvents {
+    /// Candles of the underlying chart
+    pub fn candles(&self) -> &[Candle] {
+        &self.chart.candles
+    }
+
+    /// Events that fall on the candle at `index`.
+    pub fn events_at(&self, index: usize) -> impl Iterator<Item = &ChartEvent> {
+        let start = self.events.partition_point(|e| e.candle_index < index);
+        self.events[start..]
+            .iter()
+            .take_while(move |e| e.candle_index == index)
+    }
+
+    /// Dividend events
+    pub fn dividends(&self) -> impl Iterator<Item = &ChartEvent> {
+        self.events
+            .iter()
+            .filter(|e| matches!(e.kind, ChartEventKind::Dividend { .. }))
+    }
+
+    /// Split events
+    pub fn splits(&self) -> impl Iterator<Item = &ChartEvent> {
+        self.events
+            .iter()
+            .filter(|e| matches!(e.kind, ChartEventKind::Split { .. }))
+    }
+
+    /// Capital gain events
+    pub fn capital_gains(&self) -> impl Iterator<Item = &ChartEvent> {
+        self.events
+            .iter()
+            .filter(|e| matches!(e.kind, ChartEventKind::CapitalGain { .. }))
+    }
+
+    /// Discard the events and return the chart.
+    pub fn into_chart(self) -> Chart {
+        self.chart
+    }
+}
+
+impl Chart {
+    /// Attach corporate events to this chart, aligning each to its candle.
+    ///
+    /// An event belongs to the last candle that starts at or before it.
+    /// Events before the first candle, or more than one bar past the start of
+    /// the last candle, are dropped. Inputs need not be sorted.
+    pub fn annotate(
+        self,
+        dividends: &[Dividend],
+        splits: &[Split],
+        capital_gains: &[CapitalGain],
+    ) -> ChartWithEvents {
+        let raw = dividends
+            .iter()
+            .map(|d| (d.timestamp, ChartEventKind::Dividend { amount: d.amount }))
+            .chain(splits.iter().map(|s| {
+                (
+                    s.timestamp,
+                    ChartEventKind::Split {
+                        numerator: s.numerator,
+                        denominator: s.denominator,
+                        ratio: s.ratio.clone(),
+                    },
+                )
+            }))
+            .chain(capital_gains.iter().map(|g| {
+                (
+                    g.timestamp,
+                    ChartEventKind::CapitalGain { amount: g.amount },
+                )
+            }));
+
+        let mut events: Vec<ChartEvent> = raw
+            .filter_map(|(timestamp, kind)| {
+                self.candle_index_at(timestamp)
+                    .map(|candle_index| ChartEvent {
+                        timestamp,
+                        candle_index,
+                        kind,
+                    })
+            })
+            .collect();
+        events.sort_by_key(|e| (e.candle_index, e.timestamp));
+
+        ChartWithEvents {
+            chart: self,
+            events,
+        }
+    }
+
+    /// Fetch this symbol's dividends, splits, and capital gains from `ticker`
+    /// and attach those within the chart's span.
+    ///
+    /// Uses the ticker's event cache, so calling this for several charts of
+    /// the same symbol makes a single request.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::{Interval, Ticker, TimeRange};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ticker = Ticker::new("AAPL").await?;
+    /// let chart = ticker.chart(Interval::OneWeek, TimeRange::FiveYears).await?;
+    /// let annotated = chart.with_events(&ticker).await?;
+    ///
+    /// for event in annotated.splits() {
+    ///     let candle = &annotated.candles()[event.candle_index];
+    ///     println!("{:?} on bar closing at {:.2}", event.kind, candle.close);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn with_events(self, ticker: &crate::Ticker) -> Result<ChartWithEvents> {
+        let dividends = ticker.dividends(TimeRange::Max).await?;
+        let splits = ticker.splits(TimeRange::Max).await?;
+        let capital_gains = ticker.capital_gains(TimeRange::Max).await?;
+        Ok(self.annotate(&dividends, &splits, &capital_gains))
+    }
+
+    /// Index of the candle whose period contains `timestamp`, if any.
+    fn candle_index_at(&self, timestamp: i64) -> Option<usize> {
+        let idx = self
+            .candles
+            .partition_point(|c| c.timestamp <= timestamp)
+            .checked_sub(1)?;
+        if idx + 1 == self.candles.len() {
+            let last = self.candles[idx].timestamp;
+            let bar = match (self.interval, self.candles.len()) {
+                (Some(interval), _) => interval.approx_duration_secs(),
+                (None, n) if n >= 2 => last - self.candles[n - 2].timestamp,
+                _ => 0,
+            };
+            if timestamp > last && timestamp - last >= bar {
+                return None;
+            }
+        }
+        Some(idx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::Interval;
+    use crate::models::chart::ChartMeta;
+
+    const DAY: i64 = 86_400;
+
+    fn chart(days: usize) -> Chart {
+        Chart {
+            symbol: "TEST".to_string(),
+            meta: ChartMeta::default(),
+            candles: (0..days)
+                .map(|i| Candle {
+                    timestamp: i as i64 * DAY,
+                    open: 100.0,
+                    high: 101.0,
+                    low: 99.0,
+                    close: 100.0,
+                    volume: 1000,
+                    adj_close: Some(100.0),
+                    provider_id: None,
+                })
+                .collect(),
+            interval: Some(Interval::OneDay),
+            range: None,
+            provider_id: None,
+            adjustment: None,
+        }
+    }
+
+    fn dividend(timestamp: i64) -> Dividend {
+        Dividend {
+            timestamp,
+            amount: 0.25,
+            provider_id: None,
+        }
+    }
+
+    #[test]
+    fn test_annotate_aligns_to_candles() {
+        let splits = [Split {
+            timestamp: 2 * DAY + 3_600,
+            numerator: 4.0,
+            denominator: 1.0,
+            ratio: "4:1".to_string(),
+            provider_id: None,
+        }];
+        let dividends = [
+            dividend(4 * DAY),
+            dividend(-DAY),     // before the chart
+            dividend(2 * DAY),  // same candle as the split
+            dividend(10 * DAY), // after the chart
+        ];
+        let annotated = chart(5).annotate(&dividends, &splits, &[]);
+
+        let indices: Vec<usize> = annotated.events.iter().map(|e| e.candle_index).collect();
+        assert_eq!(indices, vec![2, 2, 4]);
+        assert_eq!(annotated.events_at(2).count(), 2);
+        assert_eq!(annotated.events_at(3).count(), 0);
+        assert_eq!(annotated.dividends().count(), 2);
+        assert_eq!(annotated.splits().count(), 1);
+        assert!(matches!(
+            annotated.events[0].kind,
+            ChartEventKind::Dividend { .. }
+        ));
+    }
+
+    #[test]
+    fn test_event_serialization() {
+        let annotated = chart(3).annotate(&[dividend(DAY)], &[], &[]);
+        let json = serde_json::to_value(&annotated).unwrap();
+        assert_eq!(json["symbol"], "TEST");
+        assert_eq!(json["candles"].as_array().unwrap().len(), 3);
+        assert_eq!(json["events"][0]["type"], "dividend");
+        assert_eq!(json["events"][0]["candleIndex"], 1);
+        assert_eq!(json["events"][0]["amount"], 0.25);
+
+        let back: ChartWithEvents = serde_json::from_value(json).unwrap();
+        assert_eq!(back.events, annotated.events);
+    }
+}
@@ -3,10 +3,14 @@ use crate::Provider;
 ///
 /// Contains the OHLCV candle/bar structure.
 use serde::{Deserialize, Serialize};
+use std::io::BufRead;
+
+use crate::error::{FinanceError, Result};
 
 /// A single OHLCV candle/bar
 ///
-/// Note: This struct cannot be manually constructed - obtain via `Ticker::chart()`.
+/// Obtain via `Ticker::chart()`, or bring your own data with [`Candle::new`]
+/// or [`Candle::from_csv`].
 #[non_exhaustive]
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -31,3 +35,353 @@ pub struct Candle {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub provider_id: Option<Provider>,
 }
+
+impl Candle {
+    /// Build a candle from OHLCV values, for data that doesn't come from a provider.
+    pub fn new(timestamp: i64, open: f64, high: f64, low: f64, close: f64, volume: i64) -> Self {
+        Self {
+            timestamp,
+            open,
+            high,
+            low,
+            close,
+            volume,
+            adj_close: None,
+            provider_id: None,
+        }
+    }
+
+    /// Read candles from CSV, e.g. a vendor export or a file written by
+    /// `Chart::to_csv` (`export` feature).
+    ///
+    /// The first row must be a header. Columns are matched by name, ignoring
+    /// case, spaces, and underscores, and may appear in any order:
+    ///
+    /// - `timestamp`, `date`, `time`, or `datetime` (required): Unix seconds,
+    ///   Unix milliseconds, `YYYY-MM-DD`, `YYYY-MM-DD HH:MM:SS`, or RFC 3339.
+    ///   Times without an offset are read as UTC.
+    /// - `open`, `high`, `low`, `close` (required)
+    /// - `volume` (optional, defaults to 0)
+    /// - `adj_close` / `Adj Close` (optional)
+    ///
+    /// Rows with an empty or `null` price are skipped, as Yahoo exports
+    /// contain them for non-trading days. Candles are returned sorted by
+    /// timestamp, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FinanceError::InvalidParameter`](crate::FinanceError::InvalidParameter)
+    /// naming the offending line if a required column is missing or a value
+    /// doesn't parse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use finance_query::Candle;
+    ///
+    /// let csv = "Date,Open,High,Low,Close,Adj Close,Volume
+    /// 2024-01-02,187.15,188.44,183.89,185.64,184.94,82488700
+    /// 2024-01-03,184.22,185.88,183.43,184.25,183.55,58414500";
+    ///
+    /// let candles = Candle::from_csv(csv.as_bytes())?;
+    /// assert_eq!(candles.len(), 2);
+    /// assert_eq!(candles[0].timestamp, 1704153600);
+    /// assert_eq!(candles[1].adj_close, Some(183.55));
+    /// # Ok::<(), finance_query::FinanceError>(())
+    /// ```
+    pub fn from_csv(reader: impl std::io::Read) -> Result<Vec<Candle>> {
+        let mut lines = std::io::BufReader::new(reader).lines().enumerate();
+        let header = match lines.next() {
+            Some((_, line)) => line?,
+            None => return Ok(Vec::new()),
+        };
+        let columns = CsvColumns::from_header(&header)?;
+
+        let mut candles = Vec::new();
+        for (index, line) in lines {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Some(candle) = columns
+                .parse_row(&line)
+                .map_err(|reason| csv_error(format!("line {}: {reason}", index + 1)))?
+            {
+                candles.push(candle);
+            }
+        }
+        candles.sort_by_key(|c| c.timestamp);
+        Ok(candles)
+    }
+}
+
+#[cfg(feature = "dataframe")]
+impl Candle {
+    /// Read candles from a polars DataFrame, e.g. one loaded from Parquet.
+    ///
+    /// Columns are matched by name as in [`from_csv`](Self::from_csv). The
+    /// timestamp column may be integer Unix (milli)seconds, a `Date`, or a
+    /// `Datetime` of any unit; price and volume columns may be any numeric
+    /// type. Rows with a null price are skipped, and candles are returned
+    /// sorted by timestamp, oldest first.
+    pub fn from_dataframe(
+        df: &::polars::prelude::DataFrame,
+    ) -> ::polars::prelude::PolarsResult<Vec<Candle>> {
+        use ::polars::prelude::{DataType, PolarsError, TimeUnit};
+
+        let find = |aliases: &[&str]| {
+            df.get_columns().iter().find(|c| {
+                let name: String = c
+                    .name()
+                    .chars()
+                    .filter(|ch| !ch.is_whitespace() && *ch != '_')
+                    .collect::<String>()
+                    .to_ascii_lowercase();
+                aliases.contains(&name.as_str())
+            })
+        };
+        let require = |name: &str, aliases: &[&str]| {
+            find(aliases).ok_or_else(|| {
+                PolarsError::ColumnNotFound(format!("missing '{name}' column").into())
+            })
+        };
+        let floats = |column: &::polars::prelude::Column| -> ::polars::prelude::PolarsResult<Vec<Option<f64>>> {
+            Ok(column.cast(&DataType::Float64)?.f64()?.iter().collect())
+        };
+
+        let time = require("timestamp", &["timestamp", "date", "time", "datetime"])?;
+        let timestamps: Vec<Option<i64>> = match time.dtype() {
+            DataType::Date => time
+                .cast(&DataType::Int32)?
+                .i32()?
+                .iter()
+                .map(|d| d.map(|d| i64::from(d) * 86_400))
+                .collect(),
+            DataType::Datetime(unit, _) => {
+                let per_second = match unit {
+                    TimeUnit::Milliseconds => 1_000,
+                    TimeUnit::Microseconds => 1_000_000,
+                    TimeUnit::Nanoseconds => 1_000_000_000,
+                };
+                time.cast(&DataType::Int64)?
+                    .i64()?
+                    .iter()
+                    .map(|t| t.map(|t| t.div_euclid(per_second)))
+                    .collect()
+            }
+            _ => time
+                .cast(&DataType::Int64)?
+                .i64()?
+                .iter()
+                // Seconds stay below 1e11 until the year 5138
+                .map(|t| {
+                    t.map(|t| {
+                        if t.abs() >= 100_000_000_000 {
+                            t / 1000
+                        } else {
+                            t
+                        }
+                    })
+                })
+                .collect(),
+        };
+        let open = floats(require("open", &["open"])?)?;
+        let high = floats(require("high", &["high"])?)?;
+        let low = floats(require("low", &["low"])?)?;
+        let close = floats(require("close", &["close"])?)?;
+        let volume = find(&["volume"]).map(floats).transpose()?;
+        let adj_close = find(&["adjclose"]).map(floats).transpose()?;
+
+        let mut candles = Vec::with_capacity(df.height());
+        for i in 0..df.height() {
+            let (Some(timestamp), Some(o), Some(h), Some(l), Some(c)) =
+                (timestamps[i], open[i], high[i], low[i], close[i])
+            else {
+                continue;
+            };
+            let volume = volume.as_ref().and_then(|v| v[i]).unwrap_or(0.0) as i64;
+            let mut candle = Candle::new(timestamp, o, h, l, c, volume);
+            candle.adj_close = adj_close.as_ref().and_then(|v| v[i]);
+            candles.push(candle);
+        }
+        candles.sort_by_key(|c| c.timestamp);
+        Ok(candles)
+    }
+}
+
+/// Positions of the recognized columns in a CSV header.
+struct CsvColumns {
+    timestamp: usize,
+    open: usize,
+    high: usize,
+    low: usize,
+    close: usize,
+    volume: Option<usize>,
+    adj_close: Option<usize>,
+}
+
+impl CsvColumns {
+    fn from_header(header: &str) -> Result<Self> {
+        let names: Vec<String> = split_csv_line(header)
+            .iter()
+            .map(|name| {
+                name.trim_start_matches('\u{feff}')
+                    .chars()
+                    .filter(|c| !c.is_whitespace() && *c != '_')
+                    .collect::<String>()
+                    .to_ascii_lowercase()
+            })
+            .collect();
+        let find = |aliases: &[&str]| names.iter().position(|n| aliases.contains(&n.as_str()));
+        let require = |name: &str, aliases: &[&str]| {
+            find(aliases).ok_or_else(|| csv_error(format!("missing '{name}' column")))
+        };
+        Ok(Self {
+            timestamp: require("timestamp", &["timestamp", "date", "time", "datetime"])?,
+            open: require("open", &["open"])?,
+            high: require("high", &["high"])?,
+            low: require("low", &["low"])?,
+            close: require("close", &["close"])?,
+            volume: find(&["volume"]),
+            adj_close: find(&["adjclose"]),
+        })
+    }
+
+    /// Parse one data row, or `None` if it has no prices.
+    fn parse_row(&self, line: &str) -> std::result::Result<Option<Candle>, String> {
+        let fields = split_csv_line(line);
+        let field = |index: usize| fields.get(index).map_or("", |f| f.trim());
+        let is_missing = |value: &str| value.is_empty() || value.eq_ignore_ascii_case("null");
+        let number = |name: &str, value: &str| {
+            value
+                .parse::<f64>()
+                .map_err(|_| format!("invalid {name} '{value}'"))
+        };
+
+        let prices = [self.open, self.high, self.low, self.close].map(field);
+        if prices.iter().any(|p| is_missing(p)) {
+            return Ok(None);
+        }
+        let timestamp = parse_timestamp(field(self.timestamp))?;
+        let volume = match self.volume.map(field) {
+            Some(v) if !is_missing(v) => number("volume", v)? as i64,
+            _ => 0,
+        };
+        let adj_close = match self.adj_close.map(field) {
+            Some(v) if !is_missing(v) => Some(number("adj_close", v)?),
+            _ => None,
+        };
+
+        let mut candle = Candle::new(
+            timestamp,
+            number("open", prices[0])?,
+            number("high", prices[1])?,
+            number("low", prices[2])?,
+            number("close", prices[3])?,
+            volume,
+        );
+        candle.adj_close = adj_close;
+        Ok(Some(candle))
+    }
+}
+
+/// Split a CSV line on commas, honoring double-quoted fields.
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+/// Parse a Unix (milli)second timestamp or a date/datetime string as UTC.
+fn parse_timestamp(value: &str) -> std::result::Result<i64, String> {
+    use chrono::{DateTime, NaiveDate, NaiveDateTime};
+
+    if let Ok(n) = value.parse::<i64>() {
+        // Seconds stay below 1e11 until the year 5138
+        return Ok(if n.abs() >= 100_000_000_000 {
+            n / 1000
+        } else {
+            n
+        });
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date.and_time(chrono::NaiveTime::MIN).and_utc().timestamp());
+    }
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Ok(dt.timestamp());
+    }
+    ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M"]
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(value, fmt).ok())
+        .map(|dt| dt.and_utc().timestamp())
+        .ok_or_else(|| format!("invalid timestamp '{value}'"))
+}
+
+fn csv_error(reason: String) -> FinanceError {
+    FinanceError::InvalidParameter {
+        param: "csv".to_string(),
+        reason,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_csv_yahoo_export() {
+        let csv = "Date,Open,High,Low,Close,Adj Close,Volume\n\
+                   2024-01-03,184.22,185.88,183.43,184.25,183.55,58414500\n\
+                   2024-01-02,187.15,188.44,183.89,185.64,184.94,82488700\n\
+                   2024-01-04,null,null,null,null,null,null\n";
+        let candles = Candle::from_csv(csv.as_bytes()).unwrap();
+        assert_eq!(candles.len(), 2);
+        assert_eq!(candles[0].timestamp, 1_704_153_600);
+        assert_eq!(candles[0].volume, 82_488_700);
+        assert_eq!(candles[1].close, 184.25);
+        assert_eq!(candles[1].adj_close, Some(183.55));
+    }
+
+    #[test]
+    fn test_from_csv_column_order_and_timestamps() {
+        let csv = "close,volume,timestamp,open,low,high\n\
+                   10.5,1.0e3,1704153600000,10,9.5,11\n\
+                   11,,\"2024-01-03T14:30:00Z\",10.5,10,11.5\n";
+        let candles = Candle::from_csv(csv.as_bytes()).unwrap();
+        assert_eq!(candles[0].timestamp, 1_704_153_600);
+        assert_eq!(candles[0].volume, 1000);
+        assert_eq!(candles[0].high, 11.0);
+        assert_eq!(candles[1].timestamp, 1_704_292_200);
+        assert_eq!(candles[1].volume, 0);
+        assert_eq!(candles[1].adj_close, None);
+    }
+
+    #[test]
+    fn test_from_csv_errors() {
+        let err = Candle::from_csv("date,open,high,low\n".as_bytes()).unwrap_err();
+        assert!(err.to_string().contains("missing 'close' column"));
+
+        let err = Candle::from_csv("date,open,high,low,close\nyesterday,1,1,1,1\n".as_bytes())
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("line 2: invalid timestamp 'yesterday'")
+        );
+
+        assert!(Candle::from_csv("".as_bytes()).unwrap().is_empty());
+    }
+}
@@ -1,7 +1,7 @@
 /// Chart aggregate module
 ///
 /// Contains the fully typed Chart structure for historical data.
-use super::{Candle, ChartMeta};
+use super::{AdjustmentAudit, Candle, ChartMeta};
 use crate::Provider;
 use crate::constants::{Interval, TimeRange};
 use serde::{Deserialize, Serialize};
@@ -12,7 +12,8 @@ use serde::{Deserialize, Serialize};
 /// This is the recommended type for serialization and API responses.
 /// Used for both single symbol and batch historical data requests.
 ///
-/// Note: This struct cannot be manually constructed - use `Ticker::chart()` to obtain chart data.
+/// Obtain via `Ticker::chart()`, or load your own data with [`Chart::from_csv`]
+/// or `Chart::from_parquet` (`export` feature).
 #[non_exhaustive]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Chart {
@@ -36,16 +37,406 @@ pub struct Chart {
     /// Which data provider served this data (e.g., "yahoo", "polygon").
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub provider_id: Option<Provider>,
+
+    /// Audit trail of the price adjustment, set by [`Chart::auto_adjust`].
+    /// `None` for unadjusted charts.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub adjustment: Option<AdjustmentAudit>,
+}
+
+/// Where to split a chart into two consecutive segments.
+///
+/// Used by [`Chart::split_at`]. Integers convert to [`SplitPoint::Timestamp`],
+/// floats to [`SplitPoint::Ratio`], and [`chrono::NaiveDate`] to [`SplitPoint::Date`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum SplitPoint {
+    /// Candles strictly before this Unix timestamp (seconds) go to the first segment
+    Timestamp(i64),
+    /// Candles before midnight UTC of this date go to the first segment
+    Date(chrono::NaiveDate),
+    /// Fraction of candles (0.0–1.0, exclusive) that go to the first segment
+    Ratio(f64),
+}
+
+impl From<i64> for SplitPoint {
+    fn from(timestamp: i64) -> Self {
+        Self::Timestamp(timestamp)
+    }
+}
+
+impl From<f64> for SplitPoint {
+    fn from(ratio: f64) -> Self {
+        Self::Ratio(ratio)
+    }
+}
+
+impl From<chrono::NaiveDate> for SplitPoint {
+    fn from(date: chrono::NaiveDate) -> Self {
+        Self::Date(date)
+    }
+}
+
+impl Chart {
+    /// Load a chart from a CSV file of OHLCV rows, e.g. a vendor export or a
+    /// file written by `Chart::to_csv` (`export` feature).
+    ///
+    /// Columns are read as in [`Candle::from_csv`]. The symbol is the file
+    /// name without its extension; `interval`, `range`, and `provider_id` are
+    /// unset, and `meta` holds only the symbol and the last close. Nothing is
+    /// fetched, so the result works offline with indicators and backtests.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FinanceError::RuntimeError`](crate::FinanceError::RuntimeError)
+    /// if the file can't be read, or the errors of [`Candle::from_csv`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::Chart;
+    ///
+    /// let chart = Chart::from_csv("data/ES.csv")?;
+    /// println!("{}: {} bars", chart.symbol, chart.candles.len());
+    /// # Ok::<(), finance_query::FinanceError>(())
+    /// ```
+    pub fn from_csv(path: impl AsRef<std::path::Path>) -> crate::Result<Chart> {
+        let path = path.as_ref();
+        let candles = Candle::from_csv(std::fs::File::open(path)?)?;
+        Ok(Self::from_candles(file_symbol(path), candles))
+    }
+
+    /// Wrap candles that didn't come from a provider.
+    pub(crate) fn from_candles(symbol: impl Into<String>, candles: Vec<Candle>) -> Chart {
+        let symbol = symbol.into();
+        Chart {
+            meta: ChartMeta {
+                symbol: symbol.clone(),
+                regular_market_price: candles.last().map(|c| c.close),
+                ..Default::default()
+            },
+            symbol,
+            candles,
+            interval: None,
+            range: None,
+            provider_id: None,
+            adjustment: None,
+        }
+    }
+
+    /// Split the chart into two consecutive, non-overlapping segments.
+    ///
+    /// Both halves keep the original symbol, metadata, interval, and provider;
+    /// `range` is cleared because neither half spans the requested range.
+    /// Typical use is a train/test split for backtesting — pass the halves to
+    /// [`backtesting::evaluate_out_of_sample`](crate::backtesting::evaluate_out_of_sample)
+    /// so indicator warmup is carried across the boundary instead of being
+    /// recomputed (or leaked) by hand.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FinanceError::InvalidParameter`](crate::FinanceError::InvalidParameter)
+    /// if the ratio is not strictly between 0 and 1, or if either segment
+    /// would be empty.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::{Ticker, Interval, TimeRange};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ticker = Ticker::new("AAPL").await?;
+    /// let chart = ticker.chart(Interval::OneDay, TimeRange::FiveYears).await?;
+    ///
+    /// // First 70% of bars for training, the rest for testing
+    /// let (train, test) = chart.split_at(0.7)?;
+    ///
+    /// // Or split on a calendar date
+    /// let date = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let (before, after) = chart.split_at(date)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn split_at(&self, at: impl Into<SplitPoint>) -> crate::Result<(Chart, Chart)> {
+        let idx = match at.into() {
+            SplitPoint::Ratio(ratio) => {
+                if !(ratio > 0.0 && ratio < 1.0) {
+                    return Err(crate::FinanceError::InvalidParameter {
+                        param: "ratio".to_string(),
+                        reason: format!("must be between 0 and 1 (exclusive), got {ratio}"),
+                    });
+                }
+                (self.candles.len() as f64 * ratio).round() as usize
+            }
+            SplitPoint::Timestamp(ts) => self.candles.partition_point(|c| c.timestamp < ts),
+            SplitPoint::Date(date) => {
+                let ts = date
+                    .and_hms_opt(0, 0, 0)
+                    .unwrap_or_default()
+                    .and_utc()
+                    .timestamp();
+                self.candles.partition_point(|c| c.timestamp < ts)
+            }
+        };
+
+        if idx == 0 || idx >= self.candles.len() {
+            return Err(crate::FinanceError::InvalidParameter {
+                param: "split_at".to_string(),
+                reason: format!(
+                    "split leaves an empty segment ({idx} of {} candles before the split)",
+                    self.candles.len()
+                ),
+            });
+        }
+
+        let len = self.candles.len();
+        let segment = |start: usize, end: usize| Chart {
+            symbol: self.symbol.clone(),
+            meta: self.meta.clone(),
+            candles: self.candles[start..end].to_vec(),
+            interval: self.interval,
+            range: None,
+            provider_id: self.provider_id,
+            adjustment: self.adjustment.as_ref().map(|a| a.slice(start, end)),
+        };
+        Ok((segment(0, idx), segment(idx, len)))
+    }
+
+    /// Aggregate intraday candles into coarser bars of `interval`.
+    ///
+    /// Buckets are anchored at the first candle of each exchange-local day
+    /// (using `meta.gmt_offset`), so 4h bars for a US equity start at the
+    /// 09:30 open rather than at midnight. Each bar is stamped with the
+    /// timestamp of its first constituent, matching Yahoo's convention.
+    ///
+    /// Used to serve intervals Yahoo has no native granularity for (see
+    /// [`Interval::resample_base`]); calling it with the chart's own interval
+    /// returns an unchanged copy. Any [`adjustment`](Chart::adjustment)
+    /// audit is dropped because candle indices no longer line up.
+    ///
+    /// # Errors
+    ///
+    /// [`FinanceError::InvalidParameter`](crate::FinanceError::InvalidParameter)
+    /// if `interval` is daily or coarser, or finer than the chart's interval.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::{Ticker, Interval, TimeRange};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ticker = Ticker::new("AAPL").await?;
+    /// let hourly = ticker.chart(Interval::OneHour, TimeRange::OneMonth).await?;
+    /// let four_hour = hourly.resample(Interval::FourHours)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resample(&self, interval: Interval) -> crate::Result<Chart> {
+        let invalid = |reason: String| crate::FinanceError::InvalidParameter {
+            param: "interval".to_string(),
+            reason,
+        };
+        if !interval.is_intraday() {
+            return Err(invalid(format!(
+                "can only resample into intraday intervals, got {interval}"
+            )));
+        }
+        if let Some(source) = self.interval
+            && source.approx_duration_secs() > interval.approx_duration_secs()
+        {
+            return Err(invalid(format!(
+                "cannot resample {source} candles into finer {interval} bars"
+            )));
+        }
+
+        let secs = interval.approx_duration_secs();
+        let offset = self.meta.gmt_offset.unwrap_or(0);
+        let mut candles: Vec<Candle> = Vec::new();
+        let mut session: Option<(i64, i64)> = None;
+        let mut current: Option<(i64, i64)> = None;
+        for candle in &self.candles {
+            let local = candle.timestamp + offset;
+            let day = local.div_euclid(86_400);
+            let session_start = match session {
+                Some((d, start)) if d == day => start,
+                _ => {
+                    session = Some((day, local));
+                    local
+                }
+            };
+            let bucket = (day, (local - session_start).div_euclid(secs));
+            match candles.last_mut() {
+                Some(bar) if current == Some(bucket) => {
+                    bar.high = bar.high.max(candle.high);
+                    bar.low = bar.low.min(candle.low);
+                    bar.close = candle.close;
+                    bar.volume += candle.volume;
+                    bar.adj_close = candle.adj_close;
+                }
+                _ => {
+                    candles.push(candle.clone());
+                    current = Some(bucket);
+                }
+            }
+        }
+
+        let mut meta = self.meta.clone();
+        meta.data_granularity = Some(interval.as_str().to_string());
+        Ok(Chart {
+            symbol: self.symbol.clone(),
+            meta,
+            candles,
+            interval: Some(interval),
+            range: self.range,
+            provider_id: self.provider_id,
+            adjustment: None,
+        })
+    }
+
+    /// Point-in-time view of the chart: only bars fully closed by `timestamp`.
+    ///
+    /// A bar counts as closed once its full interval has elapsed (calendar
+    /// months for `1mo`/`3mo`), so a still-forming bar at `timestamp` is
+    /// dropped rather than leaking its later high/low/close into a study. When
+    /// the chart has no `interval`, the bar width is taken from the smallest
+    /// gap between candles.
+    ///
+    /// Metadata describing the live market at fetch time (regular market
+    /// price/time, day high/low/volume, 52-week range) is cleared, and `range`
+    /// is cleared as in [`split_at`](Self::split_at). The result is an
+    /// ordinary [`Chart`], so indicators and backtests run on it unchanged.
+    /// Returns an empty chart if no bar had closed by `timestamp`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::{Ticker, Interval, TimeRange};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ticker = Ticker::new("AAPL").await?;
+    /// let chart = ticker.chart(Interval::OneHour, TimeRange::OneMonth).await?;
+    ///
+    /// // What a study running at this instant could have seen
+    /// let cutoff = chrono::Utc::now().timestamp() - 7 * 86_400;
+    /// let seen = chart.as_of(cutoff);
+    /// println!("{} bars visible", seen.candles.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_of(&self, timestamp: i64) -> Chart {
+        let width = match self.interval {
+            Some(interval) => interval.approx_duration_secs(),
+            None => self
+                .candles
+                .windows(2)
+                .map(|w| w[1].timestamp - w[0].timestamp)
+                .filter(|gap| *gap > 0)
+                .min()
+                .unwrap_or(0),
+        };
+        let months = match self.interval {
+            Some(Interval::OneMonth) => 1,
+            Some(Interval::ThreeMonths) => 3,
+            _ => 0,
+        };
+        let close_time = |open: i64| {
+            if months > 0
+                && let Some(end) = chrono::DateTime::from_timestamp(open, 0)
+                    .and_then(|t| t.checked_add_months(chrono::Months::new(months)))
+            {
+                return end.timestamp();
+            }
+            open + width
+        };
+        let idx = self
+            .candles
+            .partition_point(|c| close_time(c.timestamp) <= timestamp);
+
+        let mut meta = self.meta.clone();
+        meta.regular_market_price = None;
+        meta.regular_market_time = None;
+        meta.regular_market_day_high = None;
+        meta.regular_market_day_low = None;
+        meta.regular_market_volume = None;
+        meta.fifty_two_week_high = None;
+        meta.fifty_two_week_low = None;
+        Chart {
+            symbol: self.symbol.clone(),
+            meta,
+            candles: self.candles[..idx].to_vec(),
+            interval: self.interval,
+            range: None,
+            provider_id: self.provider_id,
+            adjustment: self.adjustment.as_ref().map(|a| a.slice(0, idx)),
+        }
+    }
 }
 
 #[cfg(feature = "dataframe")]
 impl Chart {
     /// Converts the candles to a polars DataFrame.
     ///
-    /// Each candle becomes a row with columns for timestamp, open, high, low, close, volume.
+    /// Each candle becomes a row, in chart order, with columns `timestamp`,
+    /// `open`, `high`, `low`, `close`, `volume`, and `adj_close` (null when
+    /// the provider returned no adjusted close).
     pub fn to_dataframe(&self) -> ::polars::prelude::PolarsResult<::polars::prelude::DataFrame> {
         Candle::vec_to_dataframe(&self.candles)
     }
+
+    /// Column names and dtypes of [`to_dataframe`](Self::to_dataframe),
+    /// without fetching or building any data.
+    pub fn dataframe_schema() -> ::polars::prelude::Schema {
+        Candle::dataframe_schema()
+    }
+}
+
+#[cfg(feature = "export")]
+impl Chart {
+    /// Writes the candles to `path` as CSV, with the columns of
+    /// [`to_dataframe`](Self::to_dataframe) and a header row.
+    pub fn to_csv(&self, path: impl AsRef<std::path::Path>) -> ::polars::prelude::PolarsResult<()> {
+        use crate::export::DataFrameExport;
+        self.to_dataframe()?.write_csv(path)
+    }
+
+    /// Writes the candles to `path` as Parquet, with the columns of
+    /// [`to_dataframe`](Self::to_dataframe).
+    pub fn to_parquet(
+        &self,
+        path: impl AsRef<std::path::Path>,
+    ) -> ::polars::prelude::PolarsResult<()> {
+        use crate::export::DataFrameExport;
+        self.to_dataframe()?.write_parquet(path)
+    }
+
+    /// Load a chart from a Parquet file of OHLCV rows, e.g. one written by
+    /// [`to_parquet`](Self::to_parquet).
+    ///
+    /// Columns are read as in [`Candle::from_dataframe`]; the rest of the
+    /// chart is filled in as by [`from_csv`](Self::from_csv). A file that
+    /// can't be decoded or lacks a required column is reported as
+    /// [`FinanceError::InvalidParameter`](crate::FinanceError::InvalidParameter).
+    pub fn from_parquet(path: impl AsRef<std::path::Path>) -> crate::Result<Chart> {
+        use ::polars::prelude::{ParquetReader, SerReader};
+
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)?;
+        let candles = ParquetReader::new(file)
+            .finish()
+            .and_then(|df| Candle::from_dataframe(&df))
+            .map_err(|e| crate::FinanceError::InvalidParameter {
+                param: "parquet".to_string(),
+                reason: e.to_string(),
+            })?;
+        Ok(Self::from_candles(file_symbol(path), candles))
+    }
+}
+
+/// Symbol for a chart loaded from `path`: the file name without extension.
+fn file_symbol(path: &std::path::Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default()
 }
 
 #[cfg(feature = "indicators")]
@@ -254,4 +645,179 @@ impl Chart {
     pub fn patterns(&self) -> Vec<Option<crate::indicators::CandlePattern>> {
         crate::indicators::patterns(&self.candles)
     }
+
+    /// Detect candlestick patterns and return only the bars where one matched.
+    ///
+    /// Each [`PatternMatch`](crate::indicators::PatternMatch) carries the bar
+    /// index, its timestamp, the pattern, and the pattern's sentiment.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::{Ticker, Interval, TimeRange};
+    /// use finance_query::indicators::PatternSentiment;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ticker = Ticker::new("AAPL").await?;
+    /// let chart = ticker.chart(Interval::OneDay, TimeRange::SixMonths).await?;
+    ///
+    /// for m in chart.pattern_matches() {
+    ///     if m.sentiment == PatternSentiment::Bullish {
+    ///         println!("Bar {}: {:?}", m.index, m.pattern);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pattern_matches(&self) -> Vec<crate::indicators::PatternMatch> {
+        crate::indicators::pattern_matches(&self.candles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chart(n: i64) -> Chart {
+        Chart {
+            symbol: "TEST".to_string(),
+            meta: ChartMeta::default(),
+            candles: (0..n)
+                .map(|i| Candle {
+                    timestamp: i * 86_400,
+                    close: 100.0 + i as f64,
+                    ..Default::default()
+                })
+                .collect(),
+            interval: Some(Interval::OneDay),
+            range: Some(TimeRange::OneMonth),
+            provider_id: None,
+            adjustment: None,
+        }
+    }
+
+    #[test]
+    fn test_split_by_ratio() {
+        let (train, test) = chart(10).split_at(0.7).unwrap();
+        assert_eq!(train.candles.len(), 7);
+        assert_eq!(test.candles.len(), 3);
+        assert_eq!(test.candles[0].timestamp, 7 * 86_400);
+        assert_eq!(train.interval, Some(Interval::OneDay));
+        assert_eq!(train.range, None);
+    }
+
+    #[test]
+    fn test_split_by_timestamp_and_date() {
+        let c = chart(10);
+        let (train, test) = c.split_at(4 * 86_400).unwrap();
+        assert_eq!(train.candles.len(), 4);
+        assert_eq!(test.candles.len(), 6);
+
+        let date = chrono::NaiveDate::from_ymd_opt(1970, 1, 3).unwrap();
+        let (train, _) = c.split_at(date).unwrap();
+        assert_eq!(train.candles.len(), 2);
+    }
+
+    #[test]
+    fn test_resample_four_hours_from_hourly() {
+        // Two sessions of seven hourly bars starting at 09:30 New York (UTC-4)
+        let offset = -4 * 3_600;
+        let open = |day: i64| day * 86_400 + 9 * 3_600 + 1_800 - offset;
+        let candles = [open(19_800), open(19_801)]
+            .into_iter()
+            .flat_map(|start| {
+                (0..7).map(move |h| Candle {
+                    timestamp: start + h * 3_600,
+                    open: 100.0 + h as f64,
+                    high: 101.0 + h as f64,
+                    low: 99.0 - h as f64,
+                    close: 100.5 + h as f64,
+                    volume: 10,
+                    ..Default::default()
+                })
+            })
+            .collect();
+        let hourly = Chart {
+            symbol: "TEST".to_string(),
+            meta: ChartMeta {
+                gmt_offset: Some(offset),
+                ..Default::default()
+            },
+            candles,
+            interval: Some(Interval::OneHour),
+            range: Some(TimeRange::FiveDays),
+            provider_id: None,
+            adjustment: None,
+        };
+
+        let bars = hourly.resample(Interval::FourHours).unwrap();
+        assert_eq!(bars.interval, Some(Interval::FourHours));
+        assert_eq!(bars.meta.data_granularity.as_deref(), Some("4h"));
+        assert_eq!(bars.candles.len(), 4);
+
+        let first = &bars.candles[0];
+        assert_eq!(first.timestamp, open(19_800));
+        assert_eq!(first.open, 100.0);
+        assert_eq!(first.high, 104.0);
+        assert_eq!(first.low, 96.0);
+        assert_eq!(first.close, 103.5);
+        assert_eq!(first.volume, 40);
+
+        // Afternoon bar holds the remaining three hours; next session restarts
+        assert_eq!(bars.candles[1].timestamp, open(19_800) + 4 * 3_600);
+        assert_eq!(bars.candles[1].volume, 30);
+        assert_eq!(bars.candles[2].timestamp, open(19_801));
+    }
+
+    #[test]
+    fn test_resample_rejects_daily_and_finer() {
+        assert!(chart(5).resample(Interval::OneWeek).is_err());
+        assert!(chart(5).resample(Interval::OneHour).is_err());
+    }
+
+    #[test]
+    fn test_as_of_drops_forming_bar() {
+        let mut c = chart(10);
+        c.meta.regular_market_price = Some(109.0);
+
+        // Bar opened at day 4 is still forming until day 5 starts
+        let seen = c.as_of(4 * 86_400 + 3_600);
+        assert_eq!(seen.candles.len(), 4);
+        assert_eq!(seen.candles.last().unwrap().timestamp, 3 * 86_400);
+        assert_eq!(seen.meta.regular_market_price, None);
+        assert_eq!(seen.range, None);
+
+        assert_eq!(c.as_of(5 * 86_400).candles.len(), 5);
+        assert!(c.as_of(0).candles.is_empty());
+        assert_eq!(c.as_of(i64::MAX / 2).candles.len(), 10);
+    }
+
+    #[test]
+    fn test_as_of_monthly_uses_calendar_months() {
+        let jan = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let ts = |d: chrono::NaiveDate| d.and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp();
+        let c = Chart {
+            candles: vec![Candle {
+                timestamp: ts(jan),
+                ..Default::default()
+            }],
+            interval: Some(Interval::OneMonth),
+            ..chart(0)
+        };
+
+        // 30 days after Jan 1 the January bar has not closed yet
+        let jan_31 = chrono::NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        assert!(c.as_of(ts(jan_31)).candles.is_empty());
+        let feb_1 = chrono::NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+        assert_eq!(c.as_of(ts(feb_1)).candles.len(), 1);
+    }
+
+    #[test]
+    fn test_split_rejects_empty_segments() {
+        let c = chart(10);
+        assert!(c.split_at(0.0).is_err());
+        assert!(c.split_at(1.0).is_err());
+        assert!(c.split_at(0i64).is_err());
+        assert!(c.split_at(100 * 86_400).is_err());
+    }
 }
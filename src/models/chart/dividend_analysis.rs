@@ -0,0 +1,343 @@
+//! Dividend analysis combining dividend history with price history.
+
+use chrono::{DateTime, Datelike};
+use serde::{Deserialize, Serialize};
+
+use super::candle::Candle;
+use super::dividend_analytics::DividendAnalytics;
+use super::events::Dividend;
+
+/// Trailing twelve months, in seconds.
+const TRAILING_WINDOW_SECS: i64 = 365 * 86_400;
+
+/// How often a symbol pays dividends.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PayoutFrequency {
+    /// About 12 payments a year
+    Monthly,
+    /// About 4 payments a year
+    Quarterly,
+    /// About 2 payments a year
+    SemiAnnual,
+    /// About 1 payment a year
+    Annual,
+    /// No regular schedule
+    Irregular,
+}
+
+impl PayoutFrequency {
+    /// Expected payments per year, or `None` for [`Irregular`](Self::Irregular).
+    pub fn payments_per_year(self) -> Option<u32> {
+        match self {
+            Self::Monthly => Some(12),
+            Self::Quarterly => Some(4),
+            Self::SemiAnnual => Some(2),
+            Self::Annual => Some(1),
+            Self::Irregular => None,
+        }
+    }
+
+    /// Classify from the median number of days between payments.
+    fn from_gap_days(days: f64) -> Self {
+        match days {
+            d if (20.0..=45.0).contains(&d) => Self::Monthly,
+            d if (60.0..=120.0).contains(&d) => Self::Quarterly,
+            d if (150.0..=215.0).contains(&d) => Self::SemiAnnual,
+            d if (300.0..=430.0).contains(&d) => Self::Annual,
+            _ => Self::Irregular,
+        }
+    }
+}
+
+/// Dividends paid in one calendar year.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnualDividend {
+    /// Calendar year (UTC)
+    pub year: i32,
+    /// Total dividends paid per share
+    pub total: f64,
+    /// Number of payments
+    pub payments: usize,
+}
+
+/// Trailing twelve-month dividend yield at one bar.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DividendYieldPoint {
+    /// Bar timestamp (Unix)
+    pub timestamp: i64,
+    /// Close price
+    pub close: f64,
+    /// Dividends paid in the twelve months up to and including this bar
+    pub trailing_dividends: f64,
+    /// `trailing_dividends / close`; `None` when the close is not positive
+    pub trailing_yield: Option<f64>,
+}
+
+/// Dividend history analysed against price history.
+///
+/// Obtain via [`Ticker::dividend_analysis`](crate::Ticker::dividend_analysis),
+/// or compute from data you already have with [`compute`](Self::compute).
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DividendAnalysis {
+    /// Totals for the payments within the analysed period
+    pub summary: DividendAnalytics,
+    /// Detected payout schedule; `None` with fewer than two payments
+    pub frequency: Option<PayoutFrequency>,
+    /// Totals for each calendar year fully inside the analysed period,
+    /// oldest first
+    pub annual: Vec<AnnualDividend>,
+    /// Compound annual growth rate of the yearly totals, from the first to
+    /// the last entry in `annual`; `None` with fewer than two years or a
+    /// zero first year
+    pub growth_rate: Option<f64>,
+    /// Consecutive years, counting back from the last entry in `annual`, in
+    /// which the yearly total rose
+    pub consecutive_increases: u32,
+    /// Trailing yield at the latest bar
+    pub current_yield: Option<f64>,
+    /// Trailing yield at every bar
+    pub yield_history: Vec<DividendYieldPoint>,
+}
+
+impl DividendAnalysis {
+    /// Analyse `dividends` over the period spanned by `candles`.
+    ///
+    /// Both must be sorted oldest first. `dividends` may start before the
+    /// first candle; earlier payments only feed the trailing yield of the
+    /// first year of bars.
+    pub fn compute(dividends: &[Dividend], candles: &[Candle]) -> Self {
+        let (start, end) = match (candles.first(), candles.last()) {
+            (Some(first), Some(last)) => (first.timestamp, last.timestamp),
+            _ => (i64::MAX, i64::MIN),
+        };
+        let in_period: Vec<Dividend> = dividends
+            .iter()
+            .filter(|d| (start..=end).contains(&d.timestamp))
+            .cloned()
+            .collect();
+
+        let annual = annual_totals(&in_period, start, end);
+        let growth_rate = annual_growth_rate(&annual);
+        let consecutive_increases = annual
+            .windows(2)
+            .rev()
+            .take_while(|w| w[1].total > w[0].total)
+            .count() as u32;
+
+        let yield_history = trailing_yields(dividends, candles);
+        let current_yield = yield_history.last().and_then(|p| p.trailing_yield);
+
+        Self {
+            summary: DividendAnalytics::from_dividends(&in_period),
+            frequency: detect_frequency(&in_period),
+            annual,
+            growth_rate,
+            consecutive_increases,
+            current_yield,
+            yield_history,
+        }
+    }
+}
+
+fn year_of(timestamp: i64) -> Option<i32> {
+    DateTime::from_timestamp(timestamp, 0).map(|t| t.year())
+}
+
+/// Totals per calendar year, limited to years wholly within `start..=end`.
+fn annual_totals(dividends: &[Dividend], start: i64, end: i64) -> Vec<AnnualDividend> {
+    let (Some(first_year), Some(last_year)) = (year_of(start), year_of(end)) else {
+        return Vec::new();
+    };
+    // A year is complete if the period starts on or before Jan 1 and runs
+    // past Dec 31
+    let first_full = if year_of(start - 1) == Some(first_year) {
+        first_year + 1
+    } else {
+        first_year
+    };
+    let last_full = if year_of(end + 1) == Some(last_year) {
+        last_year - 1
+    } else {
+        last_year
+    };
+
+    (first_full..=last_full)
+        .map(|year| {
+            let paid: Vec<f64> = dividends
+                .iter()
+                .filter(|d| year_of(d.timestamp) == Some(year))
+                .map(|d| d.amount)
+                .collect();
+            AnnualDividend {
+                year,
+                total: paid.iter().sum(),
+                payments: paid.len(),
+            }
+        })
+        .collect()
+}
+
+fn annual_growth_rate(annual: &[AnnualDividend]) -> Option<f64> {
+    let (first, last) = (annual.first()?, annual.last()?);
+    let years = (last.year - first.year) as f64;
+    if years < 1.0 || first.total <= 0.0 || last.total <= 0.0 {
+        return None;
+    }
+    Some((last.total / first.total).powf(1.0 / years) - 1.0)
+}
+
+/// Classify the schedule from the median gap between the most recent
+/// payments, which ignores the odd special dividend.
+fn detect_frequency(dividends: &[Dividend]) -> Option<PayoutFrequency> {
+    let recent = &dividends[dividends.len().saturating_sub(13)..];
+    let mut gaps: Vec<f64> = recent
+        .windows(2)
+        .map(|w| (w[1].timestamp - w[0].timestamp) as f64 / 86_400.0)
+        .collect();
+    if gaps.is_empty() {
+        return None;
+    }
+    gaps.sort_by(f64::total_cmp);
+    let mid = gaps.len() / 2;
+    let median = if gaps.len().is_multiple_of(2) {
+        (gaps[mid - 1] + gaps[mid]) / 2.0
+    } else {
+        gaps[mid]
+    };
+    Some(PayoutFrequency::from_gap_days(median))
+}
+
+fn trailing_yields(dividends: &[Dividend], candles: &[Candle]) -> Vec<DividendYieldPoint> {
+    let mut points = Vec::with_capacity(candles.len());
+    let (mut head, mut tail, mut trailing) = (0, 0, 0.0);
+    for candle in candles {
+        while head < dividends.len() && dividends[head].timestamp <= candle.timestamp {
+            trailing += dividends[head].amount;
+            head += 1;
+        }
+        while tail < head && dividends[tail].timestamp <= candle.timestamp - TRAILING_WINDOW_SECS {
+            trailing -= dividends[tail].amount;
+            tail += 1;
+        }
+        // Guard against drift from repeated add/subtract
+        let trailing_dividends = if tail == head { 0.0 } else { trailing };
+        points.push(DividendYieldPoint {
+            timestamp: candle.timestamp,
+            close: candle.close,
+            trailing_dividends,
+            trailing_yield: (candle.close > 0.0).then(|| trailing_dividends / candle.close),
+        });
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86_400;
+    // 2020-01-01T00:00:00Z
+    const JAN_2020: i64 = 1_577_836_800;
+
+    fn div(timestamp: i64, amount: f64) -> Dividend {
+        Dividend {
+            timestamp,
+            amount,
+            provider_id: None,
+        }
+    }
+
+    /// Quarterly dividends stepping up each year, 2019 through mid-2023.
+    fn quarterly() -> Vec<Dividend> {
+        let mut divs = Vec::new();
+        for (year, amount) in [
+            (2019, 0.20),
+            (2020, 0.21),
+            (2021, 0.22),
+            (2022, 0.20),
+            (2023, 0.25),
+        ] {
+            let jan1 = JAN_2020 + (year - 2020) * 365 * DAY + if year > 2020 { DAY } else { 0 };
+            let quarters = if year == 2023 { 2 } else { 4 };
+            for q in 0..quarters {
+                divs.push(div(jan1 + 40 * DAY + q * 91 * DAY, amount));
+            }
+        }
+        divs
+    }
+
+    /// Weekly candles at a constant price from 2020-01-01 to 2023-06-30.
+    fn candles(close: f64) -> Vec<Candle> {
+        let end = JAN_2020 + 1276 * DAY;
+        (JAN_2020..=end)
+            .step_by(7 * DAY as usize)
+            .map(|t| Candle::new(t, close, close, close, close, 0))
+            .collect()
+    }
+
+    #[test]
+    fn test_annual_growth_and_streak() {
+        let a = DividendAnalysis::compute(&quarterly(), &candles(50.0));
+
+        // 2020-2022 are complete; 2023 is partial
+        let years: Vec<i32> = a.annual.iter().map(|y| y.year).collect();
+        assert_eq!(years, [2020, 2021, 2022]);
+        assert!((a.annual[0].total - 0.84).abs() < 1e-9);
+        assert_eq!(a.annual[0].payments, 4);
+
+        // 2022 cut the dividend, so the streak is broken
+        assert_eq!(a.consecutive_increases, 0);
+        let expected = (0.80_f64 / 0.84).powf(0.5) - 1.0;
+        assert!((a.growth_rate.unwrap() - expected).abs() < 1e-9);
+        assert_eq!(a.frequency, Some(PayoutFrequency::Quarterly));
+        assert_eq!(a.summary.payment_count, 14);
+    }
+
+    #[test]
+    fn test_trailing_yield_includes_prior_payments() {
+        let a = DividendAnalysis::compute(&quarterly(), &candles(50.0));
+
+        // First bar sees the four 2019 payments
+        let first = a.yield_history[0];
+        assert!((first.trailing_dividends - 0.80).abs() < 1e-9);
+        assert!((first.trailing_yield.unwrap() - 0.016).abs() < 1e-9);
+
+        // Last bar: two 2023 payments plus the last two of 2022
+        assert!((a.yield_history.last().unwrap().trailing_dividends - 0.90).abs() < 1e-9);
+        assert!((a.current_yield.unwrap() - 0.018).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_streak_counts_rising_years() {
+        // Ending in early 2022: 2020 and 2021 are complete, and 2021 rose
+        let candles = &candles(50.0)[..106];
+        let a = DividendAnalysis::compute(&quarterly(), candles);
+        let totals: Vec<f64> = a.annual.iter().map(|y| y.total).collect();
+        assert_eq!(totals.len(), 2);
+        assert!(totals[1] > totals[0]);
+        assert_eq!(a.consecutive_increases, 1);
+    }
+
+    #[test]
+    fn test_frequency_detection() {
+        let monthly: Vec<Dividend> = (0..12).map(|i| div(JAN_2020 + i * 30 * DAY, 0.1)).collect();
+        let a = DividendAnalysis::compute(&monthly, &candles(10.0));
+        assert_eq!(a.frequency, Some(PayoutFrequency::Monthly));
+        assert_eq!(
+            a.frequency.and_then(PayoutFrequency::payments_per_year),
+            Some(12)
+        );
+
+        let single = DividendAnalysis::compute(&monthly[..1], &candles(10.0));
+        assert_eq!(single.frequency, None);
+        assert!(DividendAnalysis::compute(&[], &[]).yield_history.is_empty());
+    }
+}
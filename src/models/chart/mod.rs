@@ -2,19 +2,30 @@
 //!
 //! Contains all data structures and types for Yahoo Finance's chart endpoint.
 
+mod adjust;
+mod annotated;
 mod candle;
 mod data;
+pub mod dividend_analysis;
 pub mod dividend_analytics;
 pub(crate) mod events;
 pub(crate) mod indicators;
 mod meta;
+mod relative_volume;
 pub(crate) mod response;
 pub(crate) mod result;
 /// Spark / sparkline submodule.
 pub mod spark;
+pub(crate) mod total_return;
 
+pub use adjust::{AdjustmentAudit, AdjustmentEvent, AdjustmentRange};
+pub use annotated::{ChartEvent, ChartEventKind, ChartWithEvents};
 pub use candle::Candle;
-pub use data::Chart;
+pub use data::{Chart, SplitPoint};
+pub use dividend_analysis::{
+    AnnualDividend, DividendAnalysis, DividendYieldPoint, PayoutFrequency,
+};
 pub use dividend_analytics::DividendAnalytics;
 pub use events::{CapitalGain, Dividend, Split};
 pub use meta::ChartMeta;
+pub use relative_volume::RelativeVolume;
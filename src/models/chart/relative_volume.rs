@@ -0,0 +1,179 @@
+//! Intraday relative volume (RVOL) computed from an intraday chart.
+
+use serde::{Deserialize, Serialize};
+
+use super::{Candle, Chart};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Today's volume so far compared with prior days at the same time of day.
+///
+/// A stock that has traded 2M shares by 10:30 looks busy in absolute terms,
+/// but not if it usually trades 3M by then. RVOL compares the cumulative
+/// volume through the latest bar with the average cumulative volume through
+/// the same time of day (exchange-local) on the prior sessions in the chart.
+///
+/// Obtain via [`Ticker::rvol`](crate::Ticker::rvol), or compute from any
+/// intraday chart with [`RelativeVolume::from_chart`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "dataframe", derive(crate::ToDataFrame))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RelativeVolume {
+    /// Stock symbol
+    pub symbol: String,
+    /// Start of the latest bar (Unix seconds); volume is counted through it
+    pub as_of: i64,
+    /// Today's cumulative volume through `as_of`
+    pub volume: i64,
+    /// Average cumulative volume through the same time of day on prior sessions
+    pub average_volume: f64,
+    /// Number of prior sessions averaged
+    pub days: u32,
+    /// `volume / average_volume`; `None` when there is no prior volume to compare
+    pub rvol: Option<f64>,
+}
+
+impl RelativeVolume {
+    /// Compute RVOL from an intraday chart covering today and prior sessions.
+    ///
+    /// Sessions are split on exchange-local calendar days using the chart's
+    /// GMT offset. The latest bar is usually still forming, so RVOL early in
+    /// a bar understates activity slightly. Returns `None` for an empty chart.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::{Interval, RelativeVolume, Ticker, TimeRange};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let chart = Ticker::new("AAPL")
+    ///     .await?
+    ///     .chart(Interval::FiveMinutes, TimeRange::OneMonth)
+    ///     .await?;
+    /// if let Some(rv) = RelativeVolume::from_chart(&chart) {
+    ///     println!("RVOL {:?} over {} days", rv.rvol, rv.days);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_chart(chart: &Chart) -> Option<Self> {
+        let offset = chart.meta.gmt_offset.unwrap_or(0);
+        Self::from_candles(&chart.symbol, &chart.candles, offset)
+    }
+
+    fn from_candles(symbol: &str, candles: &[Candle], gmt_offset: i64) -> Option<Self> {
+        let last = candles.last()?;
+        let local = |c: &Candle| c.timestamp + gmt_offset;
+        let today = local(last).div_euclid(SECONDS_PER_DAY);
+        let cutoff = local(last).rem_euclid(SECONDS_PER_DAY);
+
+        // Cumulative volume through `cutoff`, per session, oldest first
+        let mut sessions: Vec<(i64, i64)> = Vec::new();
+        for candle in candles {
+            let day = local(candle).div_euclid(SECONDS_PER_DAY);
+            let volume = if local(candle).rem_euclid(SECONDS_PER_DAY) <= cutoff {
+                candle.volume.max(0)
+            } else {
+                0
+            };
+            match sessions.last_mut() {
+                Some((d, total)) if *d == day => *total += volume,
+                _ => sessions.push((day, volume)),
+            }
+        }
+
+        let volume = sessions
+            .iter()
+            .find(|(d, _)| *d == today)
+            .map_or(0, |(_, v)| *v);
+        let prior: Vec<i64> = sessions
+            .iter()
+            .filter(|(d, _)| *d < today)
+            .map(|(_, v)| *v)
+            .collect();
+        let days = prior.len() as u32;
+        let average_volume = if days == 0 {
+            0.0
+        } else {
+            prior.iter().sum::<i64>() as f64 / f64::from(days)
+        };
+        let rvol = (average_volume > 0.0).then(|| volume as f64 / average_volume);
+
+        Some(Self {
+            symbol: symbol.to_string(),
+            as_of: last.timestamp,
+            volume,
+            average_volume,
+            days,
+            rvol,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Bars at `minute` past 09:30 UTC on `day`
+    fn bar(day: i64, minute: i64, volume: i64) -> Candle {
+        Candle {
+            timestamp: day * SECONDS_PER_DAY + 9 * 3600 + 1800 + minute * 60,
+            volume,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_rvol_matches_time_of_day() {
+        let candles = vec![
+            // Two prior sessions of four bars each
+            bar(0, 0, 100),
+            bar(0, 5, 100),
+            bar(0, 10, 100),
+            bar(0, 15, 100),
+            bar(1, 0, 200),
+            bar(1, 5, 200),
+            bar(1, 10, 200),
+            bar(1, 15, 200),
+            // Today through the second bar
+            bar(2, 0, 600),
+            bar(2, 5, 300),
+        ];
+        let rv = RelativeVolume::from_candles("AAPL", &candles, 0).unwrap();
+        assert_eq!(rv.days, 2);
+        assert_eq!(rv.volume, 900);
+        // Prior sessions through 09:35: 200 and 400
+        assert_eq!(rv.average_volume, 300.0);
+        assert_eq!(rv.rvol, Some(3.0));
+        assert_eq!(rv.as_of, candles[9].timestamp);
+    }
+
+    #[test]
+    fn test_rvol_uses_exchange_local_days() {
+        // 23:30 UTC is 08:30 the next day at UTC+9
+        let candles = vec![
+            Candle {
+                timestamp: 23 * 3600 + 1800,
+                volume: 50,
+                ..Default::default()
+            },
+            Candle {
+                timestamp: SECONDS_PER_DAY + 23 * 3600 + 1800,
+                volume: 100,
+                ..Default::default()
+            },
+        ];
+        let rv = RelativeVolume::from_candles("7203.T", &candles, 9 * 3600).unwrap();
+        assert_eq!(rv.days, 1);
+        assert_eq!(rv.rvol, Some(2.0));
+    }
+
+    #[test]
+    fn test_rvol_without_history() {
+        let rv = RelativeVolume::from_candles("NEW", &[bar(0, 0, 10)], 0).unwrap();
+        assert_eq!(rv.days, 0);
+        assert_eq!(rv.rvol, None);
+        assert!(RelativeVolume::from_candles("NONE", &[], 0).is_none());
+    }
+}
@@ -0,0 +1,229 @@
+//! Total-return price series with distributions reinvested.
+//!
+//! [`Chart::auto_adjust`] scales history down so the latest bar matches the
+//! quoted price. A total-return series does the opposite: the first bar keeps
+//! its quoted price and every later bar grows by the dividends reinvested
+//! since, so the series reads as the value of one share bought at the start.
+//! Returns between any two bars are the same either way.
+
+use super::adjust::distribution_factor;
+use super::annotated::{ChartEventKind, ChartWithEvents};
+use super::{Candle, Chart, Dividend};
+
+impl Chart {
+    /// Dividend-reinvested prices derived from each candle's `adj_close`.
+    ///
+    /// The first candle is unchanged; later candles' open, high, low, and
+    /// close are scaled by how much `adj_close / close` has grown since, and
+    /// `adj_close` is set to the new close. Candles without an `adj_close`
+    /// carry the previous factor. Volume is left as reported.
+    ///
+    /// Use [`ChartWithEvents::total_return_series`] to build the series from
+    /// the dividend events instead, e.g. for data loaded without `adj_close`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::{Interval, Ticker, TimeRange};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ticker = Ticker::new("KO").await?;
+    /// let chart = ticker.chart(Interval::OneDay, TimeRange::TenYears).await?;
+    /// let total = chart.total_return_series();
+    ///
+    /// let growth = |c: &[finance_query::Candle]| c.last().unwrap().close / c[0].close - 1.0;
+    /// println!("price return: {:.1}%", growth(&chart.candles) * 100.0);
+    /// println!("total return: {:.1}%", growth(&total.candles) * 100.0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn total_return_series(&self) -> Chart {
+        self.with_candles(from_adj_close(&self.candles))
+    }
+
+    fn with_candles(&self, candles: Vec<Candle>) -> Chart {
+        Chart {
+            symbol: self.symbol.clone(),
+            meta: self.meta.clone(),
+            candles,
+            interval: self.interval,
+            range: self.range,
+            provider_id: self.provider_id,
+            adjustment: None,
+        }
+    }
+}
+
+impl ChartWithEvents {
+    /// Dividend-reinvested prices derived from this chart's dividend and
+    /// capital gain events.
+    ///
+    /// Each distribution is reinvested at the close before its ex-date, the
+    /// convention Yahoo uses for `adj_close`, so this matches
+    /// [`Chart::total_return_series`] up to Yahoo's rounding.
+    pub fn total_return_series(&self) -> Chart {
+        let distributions: Vec<(i64, f64)> =
+            self.events
+                .iter()
+                .filter_map(|e| match e.kind {
+                    ChartEventKind::Dividend { amount }
+                    | ChartEventKind::CapitalGain { amount } => Some((e.timestamp, amount)),
+                    ChartEventKind::Split { .. } => None,
+                })
+                .collect();
+        self.chart
+            .with_candles(reinvest(&self.chart.candles, &distributions))
+    }
+}
+
+/// Total-return candles for a backtest: reinvests `dividends` when given,
+/// otherwise derives the series from `adj_close`.
+#[cfg_attr(not(feature = "backtesting"), allow(dead_code))]
+pub(crate) fn total_return_candles(candles: &[Candle], dividends: &[Dividend]) -> Vec<Candle> {
+    if dividends.is_empty() {
+        return from_adj_close(candles);
+    }
+    let distributions: Vec<(i64, f64)> =
+        dividends.iter().map(|d| (d.timestamp, d.amount)).collect();
+    reinvest(candles, &distributions)
+}
+
+fn from_adj_close(candles: &[Candle]) -> Vec<Candle> {
+    let mut base: Option<f64> = None;
+    let mut factor = 1.0;
+    candles
+        .iter()
+        .map(|candle| {
+            if let Some(adj) = candle.adj_close
+                && adj.is_finite()
+                && adj > 0.0
+                && candle.close > 0.0
+            {
+                let ratio = adj / candle.close;
+                factor = ratio / *base.get_or_insert(ratio);
+            }
+            scaled(candle, factor)
+        })
+        .collect()
+}
+
+/// Reinvest `(timestamp, amount)` distributions, sorted by timestamp. Each
+/// applies from the first candle at or after its timestamp; distributions
+/// before the first candle are ignored.
+fn reinvest(candles: &[Candle], distributions: &[(i64, f64)]) -> Vec<Candle> {
+    let mut next = 0;
+    let mut factor = 1.0;
+    let mut prior_close: Option<f64> = None;
+    candles
+        .iter()
+        .map(|candle| {
+            while let Some(&(timestamp, amount)) = distributions.get(next)
+                && timestamp <= candle.timestamp
+            {
+                // Only a positive factor can be reinvested: skip zero or
+                // negative amounts and payouts at or above the prior close.
+                if let Some(event_factor) = prior_close
+                    .filter(|_| amount > 0.0)
+                    .and_then(|close| distribution_factor(amount, close))
+                    .filter(|f| *f > 0.0)
+                {
+                    factor /= event_factor;
+                }
+                next += 1;
+            }
+            prior_close = Some(candle.close);
+            scaled(candle, factor)
+        })
+        .collect()
+}
+
+fn scaled(candle: &Candle, factor: f64) -> Candle {
+    let mut out = candle.clone();
+    out.open *= factor;
+    out.high *= factor;
+    out.low *= factor;
+    out.close *= factor;
+    out.adj_close = Some(out.close);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::chart::ChartMeta;
+
+    const DAY: i64 = 86_400;
+
+    fn candle(i: i64, close: f64, adj_close: Option<f64>) -> Candle {
+        let mut c = Candle::new(i * DAY, close, close, close, close, 1_000);
+        c.adj_close = adj_close;
+        c
+    }
+
+    /// Flat at 100 with a $1 dividend going ex on bar 2 and $2 on bar 4.
+    fn chart() -> Chart {
+        let adj = [97.02, 97.02, 98.0, 98.0, 100.0];
+        Chart {
+            symbol: "TEST".to_string(),
+            meta: ChartMeta::default(),
+            candles: (0..5)
+                .map(|i| candle(i, 100.0, Some(adj[i as usize])))
+                .collect(),
+            interval: None,
+            range: None,
+            provider_id: None,
+            adjustment: None,
+        }
+    }
+
+    fn dividends() -> Vec<Dividend> {
+        [(2, 1.0), (4, 2.0)]
+            .into_iter()
+            .map(|(i, amount)| Dividend {
+                timestamp: i * DAY,
+                amount,
+                provider_id: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_from_dividends_reinvests_at_prior_close() {
+        let candles = total_return_candles(&chart().candles, &dividends());
+        let closes: Vec<f64> = candles.iter().map(|c| c.close).collect();
+        let after_first = 100.0 / 0.99;
+        let after_second = after_first / 0.98;
+        let expected = [100.0, 100.0, after_first, after_first, after_second];
+        for (got, want) in closes.iter().zip(expected) {
+            assert!((got - want).abs() < 1e-9, "{closes:?}");
+        }
+        assert_eq!(candles[4].adj_close, Some(candles[4].close));
+        assert_eq!(candles[4].volume, 1_000);
+    }
+
+    #[test]
+    fn test_adj_close_and_dividends_agree() {
+        let from_adj = chart().total_return_series();
+        let from_divs = total_return_candles(&chart().candles, &dividends());
+        for (a, b) in from_adj.candles.iter().zip(&from_divs) {
+            assert!((a.close - b.close).abs() < 1e-9);
+        }
+        // Already total-return: unchanged
+        let again = from_adj.total_return_series();
+        for (a, b) in again.candles.iter().zip(&from_adj.candles) {
+            assert_eq!(a.close, b.close);
+        }
+    }
+
+    #[test]
+    fn test_missing_adj_close_carries_factor() {
+        let candles = vec![
+            candle(0, 100.0, Some(50.0)),
+            candle(1, 100.0, Some(55.0)),
+            candle(2, 100.0, None),
+        ];
+        let out = from_adj_close(&candles);
+        assert!((out[1].close - 110.0).abs() < 1e-9);
+        assert!((out[2].close - 110.0).abs() < 1e-9);
+    }
+}
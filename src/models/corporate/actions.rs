@@ -0,0 +1,189 @@
+//! Normalized corporate actions feed.
+//!
+//! Dividends, splits, and capital gains come from chart events, symbol
+//! changes from Polygon's ticker events, and delistings from Yahoo no longer
+//! resolving the symbol. [`CorporateAction`] puts them on one time-ordered
+//! stream per symbol, which is what position-keeping systems consume.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::models::chart::{CapitalGain, Dividend, Split};
+
+/// Kind-specific data of a [`CorporateAction`].
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum CorporateActionKind {
+    /// Cash dividend
+    Dividend {
+        /// Dividend amount per share
+        amount: f64,
+    },
+    /// Stock split
+    #[serde(rename_all = "camelCase")]
+    Split {
+        /// Numerator of the split ratio
+        numerator: f64,
+        /// Denominator of the split ratio
+        denominator: f64,
+        /// Split ratio as string (e.g., "4:1")
+        ratio: String,
+    },
+    /// Capital gain distribution
+    CapitalGain {
+        /// Capital gain amount per share
+        amount: f64,
+    },
+    /// The security started trading under a new symbol
+    ///
+    /// Requires the `polygon` feature and a configured Polygon API key.
+    SymbolChange {
+        /// Symbol the security trades under from this date
+        symbol: String,
+    },
+    /// Yahoo no longer resolves the symbol
+    ///
+    /// Covers delistings and mergers alike; Yahoo does not say which, nor
+    /// when it happened, so the timestamp is when the delisting was detected.
+    Delisted,
+}
+
+/// A single corporate action affecting a symbol.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CorporateAction {
+    /// Effective date (Unix)
+    pub timestamp: i64,
+    /// Action type and data
+    #[serde(flatten)]
+    pub kind: CorporateActionKind,
+}
+
+impl CorporateAction {
+    /// Whether this action changes the share count or symbol of a position.
+    pub fn affects_position(&self) -> bool {
+        matches!(
+            self.kind,
+            CorporateActionKind::Split { .. }
+                | CorporateActionKind::SymbolChange { .. }
+                | CorporateActionKind::Delisted
+        )
+    }
+}
+
+/// Corporate actions for a batch of symbols.
+///
+/// Obtain via [`finance::corporate_actions`](crate::finance::corporate_actions).
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CorporateActions {
+    /// Actions per symbol, sorted by timestamp
+    pub actions: HashMap<String, Vec<CorporateAction>>,
+    /// Symbols whose events could not be fetched, with the reason
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub errors: HashMap<String, String>,
+}
+
+/// Merge per-kind events into one time-ordered list.
+///
+/// Actions sharing a timestamp keep the order dividends, splits, capital
+/// gains, symbol changes, delisting.
+pub(crate) fn merge(
+    dividends: &[Dividend],
+    splits: &[Split],
+    capital_gains: &[CapitalGain],
+    symbol_changes: Vec<(i64, String)>,
+    delisted_at: Option<i64>,
+) -> Vec<CorporateAction> {
+    let dividends = dividends.iter().map(|d| CorporateAction {
+        timestamp: d.timestamp,
+        kind: CorporateActionKind::Dividend { amount: d.amount },
+    });
+    let splits = splits.iter().map(|s| CorporateAction {
+        timestamp: s.timestamp,
+        kind: CorporateActionKind::Split {
+            numerator: s.numerator,
+            denominator: s.denominator,
+            ratio: s.ratio.clone(),
+        },
+    });
+    let capital_gains = capital_gains.iter().map(|c| CorporateAction {
+        timestamp: c.timestamp,
+        kind: CorporateActionKind::CapitalGain { amount: c.amount },
+    });
+    let symbol_changes = symbol_changes
+        .into_iter()
+        .map(|(timestamp, symbol)| CorporateAction {
+            timestamp,
+            kind: CorporateActionKind::SymbolChange { symbol },
+        });
+    let delisted = delisted_at.map(|timestamp| CorporateAction {
+        timestamp,
+        kind: CorporateActionKind::Delisted,
+    });
+
+    let mut actions: Vec<CorporateAction> = dividends
+        .chain(splits)
+        .chain(capital_gains)
+        .chain(symbol_changes)
+        .chain(delisted)
+        .collect();
+    // Stable sort keeps the kind order above for same-day actions
+    actions.sort_by_key(|a| a.timestamp);
+    actions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dividend(timestamp: i64, amount: f64) -> Dividend {
+        serde_json::from_value(serde_json::json!({"timestamp": timestamp, "amount": amount}))
+            .unwrap()
+    }
+
+    fn split(timestamp: i64) -> Split {
+        serde_json::from_value(serde_json::json!({
+            "timestamp": timestamp,
+            "numerator": 4.0,
+            "denominator": 1.0,
+            "ratio": "4:1"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_merge_orders_by_timestamp() {
+        let actions = merge(
+            &[dividend(300, 0.25), dividend(100, 0.24)],
+            &[split(300)],
+            &[],
+            vec![(200, "META".to_string())],
+            Some(400),
+        );
+        let timestamps: Vec<i64> = actions.iter().map(|a| a.timestamp).collect();
+        assert_eq!(timestamps, vec![100, 200, 300, 300, 400]);
+        assert!(matches!(
+            actions[2].kind,
+            CorporateActionKind::Dividend { amount } if amount == 0.25
+        ));
+        assert!(matches!(actions[3].kind, CorporateActionKind::Split { .. }));
+        assert_eq!(actions[4].kind, CorporateActionKind::Delisted);
+        assert!(!actions[0].affects_position());
+        assert!(actions[1].affects_position());
+    }
+
+    #[test]
+    fn test_action_serialization() {
+        let actions = merge(&[], &[split(100)], &[], vec![], Some(200));
+        let json = serde_json::to_value(&actions).unwrap();
+        assert_eq!(json[0]["type"], "split");
+        assert_eq!(json[0]["ratio"], "4:1");
+        assert_eq!(
+            json[1],
+            serde_json::json!({"timestamp": 200, "type": "delisted"})
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, NaiveDate};
 use serde::{Deserialize, Serialize};
 
 use crate::models::quote::FormattedValue;
@@ -54,6 +55,62 @@ pub struct EarningsCalendar {
     /// High revenue estimate
     #[serde(default)]
     pub revenue_high: Option<FormattedValue<i64>>,
+
+    /// Whether the earnings date is an estimate rather than confirmed
+    #[serde(default)]
+    pub is_earnings_date_estimate: Option<bool>,
+}
+
+/// Upcoming earnings and dividend dates as calendar dates.
+///
+/// Obtain via [`Ticker::key_dates`](crate::Ticker::key_dates).
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyDates {
+    /// Next earnings date(s); two dates mean a reporting window
+    pub earnings_dates: Vec<NaiveDate>,
+    /// Whether the earnings date is an estimate rather than confirmed
+    pub earnings_date_is_estimate: Option<bool>,
+    /// Analyst EPS estimates for the upcoming quarter
+    pub eps_estimate: EstimateRange<f64>,
+    /// Analyst revenue estimates for the upcoming quarter
+    pub revenue_estimate: EstimateRange<i64>,
+    /// Ex-dividend date
+    pub ex_dividend_date: Option<NaiveDate>,
+    /// Dividend payment date
+    pub dividend_date: Option<NaiveDate>,
+}
+
+/// Low / average / high analyst estimate.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct EstimateRange<T> {
+    /// Lowest estimate
+    pub low: Option<T>,
+    /// Consensus (average) estimate
+    pub average: Option<T>,
+    /// Highest estimate
+    pub high: Option<T>,
+}
+
+impl KeyDates {
+    /// First day of the earnings window, if scheduled.
+    pub fn next_earnings_date(&self) -> Option<NaiveDate> {
+        self.earnings_dates.first().copied()
+    }
+}
+
+/// Calendar date of a Yahoo date value.
+///
+/// Prefers the `fmt` string (exchange-local `YYYY-MM-DD`) and falls back to
+/// the UTC date of the raw timestamp.
+fn to_date(value: &FormattedValue<i64>) -> Option<NaiveDate> {
+    value
+        .fmt
+        .as_deref()
+        .and_then(|f| NaiveDate::parse_from_str(f, "%Y-%m-%d").ok())
+        .or_else(|| Some(DateTime::from_timestamp(value.raw?, 0)?.date_naive()))
 }
 
 impl CalendarEvents {
@@ -76,6 +133,35 @@ impl CalendarEvents {
     pub fn earnings_estimate(&self) -> Option<f64> {
         self.earnings.as_ref()?.earnings_average.as_ref()?.raw
     }
+
+    /// Returns the upcoming dates and estimates as typed values
+    pub fn key_dates(&self) -> KeyDates {
+        let mut dates = KeyDates {
+            ex_dividend_date: self.ex_dividend_date.as_ref().and_then(to_date),
+            dividend_date: self.dividend_date.as_ref().and_then(to_date),
+            ..Default::default()
+        };
+        if let Some(e) = &self.earnings {
+            dates.earnings_dates = e
+                .earnings_date
+                .iter()
+                .flatten()
+                .filter_map(to_date)
+                .collect();
+            dates.earnings_date_is_estimate = e.is_earnings_date_estimate;
+            dates.eps_estimate = EstimateRange {
+                low: e.earnings_low.as_ref().and_then(|v| v.raw),
+                average: e.earnings_average.as_ref().and_then(|v| v.raw),
+                high: e.earnings_high.as_ref().and_then(|v| v.raw),
+            };
+            dates.revenue_estimate = EstimateRange {
+                low: e.revenue_low.as_ref().and_then(|v| v.raw),
+                average: e.revenue_average.as_ref().and_then(|v| v.raw),
+                high: e.revenue_high.as_ref().and_then(|v| v.raw),
+            };
+        }
+        dates
+    }
 }
 
 #[cfg(test)]
@@ -118,4 +204,40 @@ mod tests {
         assert_eq!(events.next_earnings_date(), None);
         assert_eq!(events.ex_dividend_timestamp(), None);
     }
+
+    #[test]
+    fn test_key_dates() {
+        let json = json!({
+            "earnings": {
+                "earningsDate": [
+                    {"fmt": "2026-02-25", "raw": 1772053200},
+                    {"raw": 1772398800}
+                ],
+                "earningsLow": 1.40,
+                "earningsAverage": {"fmt": "1.52", "raw": 1.52},
+                "earningsHigh": 1.61,
+                "revenueAverage": {"raw": 124000000000i64},
+                "isEarningsDateEstimate": true
+            },
+            "exDividendDate": {"fmt": "2025-12-04", "raw": 1764806400},
+            "dividendDate": {}
+        });
+
+        let events: CalendarEvents = serde_json::from_value(json).unwrap();
+        let dates = events.key_dates();
+        let day = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(
+            dates.earnings_dates,
+            vec![day(2026, 2, 25), day(2026, 3, 1)]
+        );
+        assert_eq!(dates.next_earnings_date(), Some(day(2026, 2, 25)));
+        assert_eq!(dates.earnings_date_is_estimate, Some(true));
+        assert_eq!(dates.eps_estimate.low, Some(1.40));
+        assert_eq!(dates.eps_estimate.average, Some(1.52));
+        assert_eq!(dates.eps_estimate.high, Some(1.61));
+        assert_eq!(dates.revenue_estimate.average, Some(124000000000));
+        assert_eq!(dates.revenue_estimate.high, None);
+        assert_eq!(dates.ex_dividend_date, Some(day(2025, 12, 4)));
+        assert_eq!(dates.dividend_date, None);
+    }
 }
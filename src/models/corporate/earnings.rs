@@ -137,3 +137,186 @@ pub struct QuarterlyFinancials {
     #[serde(default)]
     pub earnings: Option<crate::models::quote::FormattedValue<i64>>,
 }
+
+/// Typed view of the `earnings` module for charting.
+///
+/// Flattens [`Earnings`] into plain numbers: quarterly EPS actual vs.
+/// estimate, plus revenue/earnings series by year and by quarter.
+/// Obtain via [`Ticker::earnings_chart`](crate::Ticker::earnings_chart).
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarningsChartData {
+    /// Reported quarters, oldest first
+    pub quarterly_eps: Vec<EpsPoint>,
+    /// Consensus EPS estimate for the current quarter
+    pub current_quarter_estimate: Option<f64>,
+    /// Current quarter label (e.g. "3Q2026")
+    pub current_quarter: Option<String>,
+    /// Next earnings date (Unix timestamp)
+    pub next_earnings_date: Option<i64>,
+    /// Annual revenue and earnings, oldest first
+    pub yearly: Vec<FinancialsPoint>,
+    /// Quarterly revenue and earnings, oldest first
+    pub quarterly: Vec<FinancialsPoint>,
+}
+
+/// One quarter's EPS actual vs. consensus estimate.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "dataframe", derive(crate::ToDataFrame))]
+#[serde(rename_all = "camelCase")]
+pub struct EpsPoint {
+    /// Quarter label (e.g. "4Q2025")
+    pub period: String,
+    /// Reported EPS
+    pub actual: Option<f64>,
+    /// Consensus EPS estimate
+    pub estimate: Option<f64>,
+    /// Surprise in percent (4.2 = beat by 4.2%)
+    pub surprise_percent: Option<f64>,
+}
+
+impl EpsPoint {
+    /// Reported minus estimated EPS.
+    pub fn difference(&self) -> Option<f64> {
+        Some(self.actual? - self.estimate?)
+    }
+
+    /// `Some(true)` if the quarter beat the estimate, `Some(false)` if it
+    /// missed or matched, `None` if either value is missing.
+    pub fn beat(&self) -> Option<bool> {
+        self.difference().map(|d| d > 0.0)
+    }
+}
+
+/// Revenue and earnings for one period.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "dataframe", derive(crate::ToDataFrame))]
+#[serde(rename_all = "camelCase")]
+pub struct FinancialsPoint {
+    /// Period label: fiscal year (e.g. "2024") or quarter (e.g. "3Q2024")
+    pub period: String,
+    /// Total revenue
+    pub revenue: Option<i64>,
+    /// Net earnings
+    pub earnings: Option<i64>,
+}
+
+impl Earnings {
+    /// Flatten into an [`EarningsChartData`].
+    pub fn chart_data(&self) -> EarningsChartData {
+        let mut data = EarningsChartData::default();
+
+        if let Some(chart) = &self.earnings_chart {
+            data.quarterly_eps = chart
+                .quarterly
+                .iter()
+                .map(|q| {
+                    let actual = q.actual.as_ref().and_then(|v| v.raw);
+                    let estimate = q.estimate.as_ref().and_then(|v| v.raw);
+                    let surprise_percent = q
+                        .surprise_pct
+                        .as_deref()
+                        .and_then(|s| s.trim().parse().ok())
+                        .or_else(|| match (actual, estimate) {
+                            (Some(a), Some(e)) if e != 0.0 => Some((a - e) / e.abs() * 100.0),
+                            _ => None,
+                        });
+                    EpsPoint {
+                        period: q.date.clone().unwrap_or_default(),
+                        actual,
+                        estimate,
+                        surprise_percent,
+                    }
+                })
+                .collect();
+            data.current_quarter_estimate =
+                chart.current_quarter_estimate.as_ref().and_then(|v| v.raw);
+            data.current_quarter = match (
+                &chart.current_quarter_estimate_date,
+                chart.current_quarter_estimate_year,
+            ) {
+                (Some(q), Some(y)) => Some(format!("{q}{y}")),
+                _ => chart.current_fiscal_quarter.clone(),
+            };
+            data.next_earnings_date = chart.earnings_date.first().and_then(|v| v.raw);
+        }
+
+        if let Some(fin) = &self.financials_chart {
+            data.yearly = fin
+                .yearly
+                .iter()
+                .map(|y| FinancialsPoint {
+                    period: y.date.map(|d| d.to_string()).unwrap_or_default(),
+                    revenue: y.revenue.as_ref().and_then(|v| v.raw),
+                    earnings: y.earnings.as_ref().and_then(|v| v.raw),
+                })
+                .collect();
+            data.quarterly = fin
+                .quarterly
+                .iter()
+                .map(|q| FinancialsPoint {
+                    period: q.date.clone().unwrap_or_default(),
+                    revenue: q.revenue.as_ref().and_then(|v| v.raw),
+                    earnings: q.earnings.as_ref().and_then(|v| v.raw),
+                })
+                .collect();
+        }
+
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_chart_data() {
+        let earnings: Earnings = serde_json::from_value(json!({
+            "earningsChart": {
+                "quarterly": [
+                    {"date": "3Q2025", "actual": {"raw": 1.85, "fmt": "1.85"},
+                     "estimate": {"raw": 1.77, "fmt": "1.77"}, "surprisePct": "4.5"},
+                    {"date": "4Q2025", "actual": {"raw": 2.0, "fmt": "2.00"},
+                     "estimate": {"raw": 2.5, "fmt": "2.50"}}
+                ],
+                "currentQuarterEstimate": {"raw": 1.62, "fmt": "1.62"},
+                "currentQuarterEstimateDate": "1Q",
+                "currentQuarterEstimateYear": 2026,
+                "earningsDate": [{"raw": 1777000000, "fmt": "2026-04-24"}]
+            },
+            "financialsChart": {
+                "yearly": [
+                    {"date": 2024, "revenue": {"raw": 391035000000i64}, "earnings": {"raw": 93736000000i64}}
+                ],
+                "quarterly": [
+                    {"date": "4Q2025", "revenue": {"raw": 124300000000i64}, "earnings": {"raw": 36330000000i64}}
+                ]
+            }
+        }))
+        .unwrap();
+
+        let data = earnings.chart_data();
+        assert_eq!(data.quarterly_eps.len(), 2);
+        assert_eq!(data.quarterly_eps[0].surprise_percent, Some(4.5));
+        assert_eq!(data.quarterly_eps[0].beat(), Some(true));
+        assert_eq!(data.quarterly_eps[1].beat(), Some(false));
+        assert_eq!(data.quarterly_eps[1].surprise_percent, Some(-20.0));
+        assert_eq!(data.current_quarter.as_deref(), Some("1Q2026"));
+        assert_eq!(data.current_quarter_estimate, Some(1.62));
+        assert_eq!(data.next_earnings_date, Some(1777000000));
+        assert_eq!(data.yearly[0].period, "2024");
+        assert_eq!(data.yearly[0].revenue, Some(391035000000));
+        assert_eq!(data.quarterly[0].earnings, Some(36330000000));
+    }
+
+    #[test]
+    fn test_chart_data_empty() {
+        let earnings: Earnings = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(earnings.chart_data(), EarningsChartData::default());
+    }
+}
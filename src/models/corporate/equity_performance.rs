@@ -3,6 +3,7 @@
 //! Contains equity performance data comparing stock returns against a benchmark
 //! over various time periods.
 
+use chrono::{DateTime, NaiveDate};
 use serde::{Deserialize, Serialize};
 
 /// Equity performance data comparing stock returns to benchmark
@@ -174,6 +175,191 @@ impl EquityPerformance {
     }
 }
 
+/// Lookback period reported by the equity performance module.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PerformancePeriod {
+    /// Five trading days
+    OneWeek,
+    /// One month
+    OneMonth,
+    /// Three months
+    ThreeMonths,
+    /// Six months
+    SixMonths,
+    /// Year to date
+    Ytd,
+    /// One year (total return)
+    OneYear,
+    /// Two years (total return)
+    TwoYears,
+    /// Three years (total return)
+    ThreeYears,
+    /// Five years (total return)
+    FiveYears,
+    /// Ten years (total return)
+    TenYears,
+    /// Since inception
+    Max,
+}
+
+impl PerformancePeriod {
+    /// All periods, shortest first.
+    pub const ALL: [PerformancePeriod; 11] = [
+        Self::OneWeek,
+        Self::OneMonth,
+        Self::ThreeMonths,
+        Self::SixMonths,
+        Self::Ytd,
+        Self::OneYear,
+        Self::TwoYears,
+        Self::ThreeYears,
+        Self::FiveYears,
+        Self::TenYears,
+        Self::Max,
+    ];
+
+    /// Short label (e.g. "1w", "ytd", "5y").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::OneWeek => "1w",
+            Self::OneMonth => "1m",
+            Self::ThreeMonths => "3m",
+            Self::SixMonths => "6m",
+            Self::Ytd => "ytd",
+            Self::OneYear => "1y",
+            Self::TwoYears => "2y",
+            Self::ThreeYears => "3y",
+            Self::FiveYears => "5y",
+            Self::TenYears => "10y",
+            Self::Max => "max",
+        }
+    }
+}
+
+impl std::fmt::Display for PerformancePeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl std::str::FromStr for PerformancePeriod {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.to_ascii_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|p| p.as_str() == s || (s == "5d" && *p == Self::OneWeek))
+            .ok_or(())
+    }
+}
+
+impl PerformanceOverview {
+    /// Return for `period` as a fraction (0.25 = 25%).
+    pub fn get(&self, period: PerformancePeriod) -> Option<f64> {
+        let value = match period {
+            PerformancePeriod::OneWeek => &self.five_days_return,
+            PerformancePeriod::OneMonth => &self.one_month_return,
+            PerformancePeriod::ThreeMonths => &self.three_month_return,
+            PerformancePeriod::SixMonths => &self.six_month_return,
+            PerformancePeriod::Ytd => &self.ytd_return_pct,
+            PerformancePeriod::OneYear => &self.one_year_total_return,
+            PerformancePeriod::TwoYears => &self.two_year_total_return,
+            PerformancePeriod::ThreeYears => &self.three_year_total_return,
+            PerformancePeriod::FiveYears => &self.five_year_total_return,
+            PerformancePeriod::TenYears => &self.ten_year_total_return,
+            PerformancePeriod::Max => &self.max_return,
+        };
+        value.as_ref()?.raw
+    }
+}
+
+/// Stock vs. benchmark return for one period.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodReturn {
+    /// Lookback period
+    pub period: PerformancePeriod,
+    /// Stock return as a fraction (0.25 = 25%)
+    pub stock: Option<f64>,
+    /// Benchmark return as a fraction
+    pub benchmark: Option<f64>,
+    /// Stock minus benchmark (positive = outperforming)
+    pub delta: Option<f64>,
+}
+
+/// Typed returns of a stock and its benchmark across standard periods.
+///
+/// Obtain via [`Ticker::performance_overview`](crate::Ticker::performance_overview).
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PerformanceSummary {
+    /// Benchmark symbol (e.g. "^GSPC")
+    pub benchmark_symbol: Option<String>,
+    /// Benchmark name (e.g. "S&P 500")
+    pub benchmark_name: Option<String>,
+    /// Date the returns are measured to
+    pub as_of: Option<NaiveDate>,
+    /// One entry per [`PerformancePeriod`], shortest first; periods with
+    /// neither a stock nor a benchmark value are omitted
+    pub periods: Vec<PeriodReturn>,
+}
+
+impl PerformanceSummary {
+    /// Returns for `period`, if reported.
+    pub fn get(&self, period: PerformancePeriod) -> Option<&PeriodReturn> {
+        self.periods.iter().find(|p| p.period == period)
+    }
+}
+
+impl EquityPerformance {
+    /// Returns the stock's return for `period`
+    pub fn period_return(&self, period: PerformancePeriod) -> Option<f64> {
+        self.performance_overview.as_ref()?.get(period)
+    }
+
+    /// Returns the benchmark's return for `period`
+    pub fn benchmark_period_return(&self, period: PerformancePeriod) -> Option<f64> {
+        self.performance_overview_benchmark.as_ref()?.get(period)
+    }
+
+    /// Returns stock and benchmark returns for every reported period
+    pub fn summary(&self) -> PerformanceSummary {
+        let periods = PerformancePeriod::ALL
+            .into_iter()
+            .filter_map(|period| {
+                let stock = self.period_return(period);
+                let benchmark = self.benchmark_period_return(period);
+                if stock.is_none() && benchmark.is_none() {
+                    return None;
+                }
+                let delta = stock.zip(benchmark).map(|(s, b)| s - b);
+                Some(PeriodReturn {
+                    period,
+                    stock,
+                    benchmark,
+                    delta,
+                })
+            })
+            .collect();
+        PerformanceSummary {
+            benchmark_symbol: self.benchmark.as_ref().and_then(|b| b.symbol.clone()),
+            benchmark_name: self.benchmark_name().map(str::to_string),
+            as_of: self
+                .performance_overview
+                .as_ref()
+                .and_then(|p| p.as_of_date.as_ref()?.raw)
+                .and_then(|ts| DateTime::from_timestamp(ts, 0))
+                .map(|dt| dt.date_naive()),
+            periods,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -277,4 +463,46 @@ mod tests {
         let five_year_vs = equity_performance.five_year_vs_benchmark().unwrap();
         assert!((five_year_vs - 11.634329).abs() < 0.001);
     }
+
+    #[test]
+    fn test_performance_summary() {
+        let json = json!({
+            "benchmark": {"symbol": "^GSPC", "shortName": "S&P 500"},
+            "performanceOverview": {
+                "asOfDate": {"raw": 1764892800},
+                "fiveDaysReturn": {"raw": 0.03},
+                "ytdReturnPct": {"raw": 0.35},
+                "tenYearTotalReturn": {"raw": 2.5}
+            },
+            "performanceOverviewBenchmark": {
+                "fiveDaysReturn": {"raw": 0.01},
+                "ytdReturnPct": {"raw": 0.15}
+            }
+        });
+        let perf: EquityPerformance = serde_json::from_value(json).unwrap();
+        let summary = perf.summary();
+
+        assert_eq!(summary.benchmark_symbol.as_deref(), Some("^GSPC"));
+        assert_eq!(summary.benchmark_name.as_deref(), Some("S&P 500"));
+        assert_eq!(summary.as_of, NaiveDate::from_ymd_opt(2025, 12, 5));
+        assert_eq!(summary.periods.len(), 3);
+
+        let week = summary.get(PerformancePeriod::OneWeek).unwrap();
+        assert!((week.delta.unwrap() - 0.02).abs() < 1e-9);
+        let ytd = summary.get(PerformancePeriod::Ytd).unwrap();
+        assert!((ytd.delta.unwrap() - 0.20).abs() < 1e-9);
+        let ten = summary.get(PerformancePeriod::TenYears).unwrap();
+        assert_eq!(ten.stock, Some(2.5));
+        assert_eq!(ten.delta, None);
+        assert!(summary.get(PerformancePeriod::OneYear).is_none());
+    }
+
+    #[test]
+    fn test_performance_period_parse() {
+        assert_eq!("ytd".parse(), Ok(PerformancePeriod::Ytd));
+        assert_eq!("5D".parse(), Ok(PerformancePeriod::OneWeek));
+        assert_eq!("10y".parse(), Ok(PerformancePeriod::TenYears));
+        assert_eq!("2w".parse::<PerformancePeriod>(), Err(()));
+        assert_eq!(PerformancePeriod::ThreeYears.to_string(), "3y");
+    }
 }
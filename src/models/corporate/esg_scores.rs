@@ -0,0 +1,143 @@
+//! ESG Scores Module
+//!
+//! Contains Sustainalytics environmental, social, and governance risk scores
+//! and controversy data.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::quote::FormattedValue;
+
+/// ESG risk scores for a company
+///
+/// Scores are Sustainalytics risk ratings, so lower is better. Funds and
+/// most non-US listings have no ESG data.
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EsgScores {
+    /// Maximum age of the data in seconds
+    #[serde(default)]
+    pub max_age: Option<i64>,
+
+    /// Total ESG risk score
+    #[serde(default)]
+    pub total_esg: Option<FormattedValue<f64>>,
+
+    /// Environmental risk score
+    #[serde(default)]
+    pub environment_score: Option<FormattedValue<f64>>,
+
+    /// Social risk score
+    #[serde(default)]
+    pub social_score: Option<FormattedValue<f64>>,
+
+    /// Governance risk score
+    #[serde(default)]
+    pub governance_score: Option<FormattedValue<f64>>,
+
+    /// Percentile of the total score within the peer group
+    #[serde(default)]
+    pub percentile: Option<FormattedValue<f64>>,
+
+    /// Year the rating was last updated
+    #[serde(default)]
+    pub rating_year: Option<i32>,
+
+    /// Month the rating was last updated (1-12)
+    #[serde(default)]
+    pub rating_month: Option<u32>,
+
+    /// Highest controversy level, from 0 (none) to 5 (severe)
+    #[serde(default)]
+    pub highest_controversy: Option<f64>,
+
+    /// Controversy topics the company is involved in
+    #[serde(default)]
+    pub related_controversy: Vec<String>,
+
+    /// Performance against peers (e.g., "LAG_PERF", "AVG_PERF", "OUT_PERF")
+    #[serde(default)]
+    pub esg_performance: Option<String>,
+
+    /// Peer group the scores are compared against
+    #[serde(default)]
+    pub peer_group: Option<String>,
+
+    /// Number of companies in the peer group
+    #[serde(default)]
+    pub peer_count: Option<i64>,
+
+    /// Peer group total ESG score range
+    #[serde(default)]
+    pub peer_esg_score_performance: Option<PeerPerformance>,
+
+    /// Peer group environmental score range
+    #[serde(default)]
+    pub peer_environment_performance: Option<PeerPerformance>,
+
+    /// Peer group social score range
+    #[serde(default)]
+    pub peer_social_performance: Option<PeerPerformance>,
+
+    /// Peer group governance score range
+    #[serde(default)]
+    pub peer_governance_performance: Option<PeerPerformance>,
+
+    /// Peer group highest controversy range
+    #[serde(default)]
+    pub peer_highest_controversy_performance: Option<PeerPerformance>,
+}
+
+/// Minimum, average, and maximum of a score across a peer group
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerPerformance {
+    /// Lowest score in the peer group
+    #[serde(default)]
+    pub min: Option<f64>,
+
+    /// Average score in the peer group
+    #[serde(default)]
+    pub avg: Option<f64>,
+
+    /// Highest score in the peer group
+    #[serde(default)]
+    pub max: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_esg_scores() {
+        let json = serde_json::json!({
+            "maxAge": 86400,
+            "totalEsg": {"raw": 17.22, "fmt": "17.22"},
+            "environmentScore": {"raw": 0.53, "fmt": "0.53"},
+            "socialScore": {"raw": 7.45, "fmt": "7.45"},
+            "governanceScore": {"raw": 9.24, "fmt": "9.24"},
+            "ratingYear": 2024,
+            "ratingMonth": 9,
+            "highestControversy": 3.0,
+            "peerCount": 56,
+            "esgPerformance": "UNDER_PERF",
+            "peerGroup": "Technology Hardware",
+            "relatedControversy": ["Social Supply Chain Incidents"],
+            "peerEsgScorePerformance": {"min": 6.61, "avg": 15.94, "max": 32.81},
+            "percentile": {"raw": 12.5, "fmt": "12.5"},
+            "adult": false
+        });
+
+        let esg: EsgScores = serde_json::from_value(json).unwrap();
+        assert_eq!(esg.total_esg.and_then(|v| v.raw), Some(17.22));
+        assert_eq!(esg.highest_controversy, Some(3.0));
+        assert_eq!(esg.related_controversy, ["Social Supply Chain Incidents"]);
+        assert_eq!(
+            esg.peer_esg_score_performance.and_then(|p| p.avg),
+            Some(15.94)
+        );
+        assert!(esg.peer_social_performance.is_none());
+    }
+}
@@ -3,8 +3,13 @@
 //! Company profiles, officers, ownership, insider activity, and related data.
 
 // Sub-capability directories
+/// Normalized corporate actions feed.
+pub mod actions;
 /// News article models.
 pub mod news;
+/// Official company press releases (newswire disclosures).
+#[cfg(feature = "feeds")]
+pub mod press_release;
 /// Recommendation/similar symbol models.
 pub mod recommendation;
 /// Earnings call transcripts.
@@ -17,6 +22,7 @@ pub(crate) mod earnings;
 pub(crate) mod earnings_history;
 pub(crate) mod earnings_trend;
 pub(crate) mod equity_performance;
+pub(crate) mod esg_scores;
 pub(crate) mod fund_ownership;
 pub(crate) mod fund_performance;
 pub(crate) mod fund_profile;
@@ -37,6 +43,7 @@ pub(crate) use earnings::Earnings;
 pub(crate) use earnings_history::EarningsHistory;
 pub(crate) use earnings_trend::EarningsTrend;
 pub(crate) use equity_performance::EquityPerformance;
+pub(crate) use esg_scores::EsgScores;
 pub(crate) use fund_ownership::FundOwnership;
 pub(crate) use fund_performance::FundPerformance;
 pub(crate) use fund_profile::FundProfile;
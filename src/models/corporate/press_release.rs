@@ -0,0 +1,231 @@
+//! Official company press releases.
+//!
+//! Press releases are issuer-authored disclosures distributed through a
+//! newswire. They are kept separate from [`News`](super::news::News), which
+//! mixes in journalist coverage, so disclosure-driven workflows can consume
+//! official releases only.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::feeds::FeedEntry;
+use crate::models::discovery::search::SearchNews;
+
+/// Newswire service that distributed a press release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum PressWire {
+    /// Business Wire (Berkshire Hathaway)
+    BusinessWire,
+    /// PR Newswire (Cision)
+    PrNewswire,
+    /// GlobeNewswire (Notified)
+    GlobeNewswire,
+    /// ACCESSWIRE
+    AccessWire,
+}
+
+impl PressWire {
+    /// Classify a publisher name as a newswire, if it is one.
+    ///
+    /// Matching is case-insensitive and ignores spaces, so `"Business Wire"`,
+    /// `"BusinessWire"` and `"business wire"` all map to [`PressWire::BusinessWire`].
+    pub fn from_publisher(publisher: &str) -> Option<Self> {
+        let key: String = publisher
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_ascii_lowercase();
+        match key.as_str() {
+            "businesswire" => Some(Self::BusinessWire),
+            "prnewswire" | "prnewswire-firstcall" | "cisionprnewswire" => Some(Self::PrNewswire),
+            "globenewswire" => Some(Self::GlobeNewswire),
+            "accesswire" => Some(Self::AccessWire),
+            _ => None,
+        }
+    }
+
+    /// Human-readable newswire name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::BusinessWire => "Business Wire",
+            Self::PrNewswire => "PR Newswire",
+            Self::GlobeNewswire => "GlobeNewswire",
+            Self::AccessWire => "ACCESSWIRE",
+        }
+    }
+}
+
+/// An official press release issued by a company.
+///
+/// Obtain via [`Ticker::press_releases`](crate::Ticker::press_releases).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PressRelease {
+    /// Symbol the release was matched to
+    pub symbol: String,
+    /// Release headline
+    pub title: String,
+    /// Canonical link to the release
+    pub url: String,
+    /// Newswire that distributed the release
+    pub wire: PressWire,
+    /// Publication time (Unix epoch seconds), when known
+    pub published: Option<i64>,
+    /// Short summary or lead paragraph, when the source provides one
+    pub summary: Option<String>,
+}
+
+impl PressRelease {
+    /// Convert a Yahoo search news item into a press release.
+    ///
+    /// Returns `None` when the publisher is not a recognised newswire or the
+    /// item lacks a title/link.
+    pub(crate) fn from_search_news(symbol: &str, news: &SearchNews) -> Option<Self> {
+        let wire = PressWire::from_publisher(news.publisher.as_deref()?)?;
+        Some(Self {
+            symbol: symbol.to_string(),
+            title: news.title.clone()?,
+            url: news.link.clone()?,
+            wire,
+            published: news.provider_publish_time,
+            summary: None,
+        })
+    }
+
+    /// Convert a newswire feed entry into a press release for `symbol`.
+    ///
+    /// Wire feeds cover every issuer, so an entry is only kept when it carries
+    /// the symbol's exchange tag — e.g. `(NASDAQ: AAPL)` or `(NYSE:IBM)`.
+    pub(crate) fn from_feed_entry(
+        symbol: &str,
+        wire: PressWire,
+        entry: &FeedEntry,
+    ) -> Option<Self> {
+        let mentions = mentions_exchange_tag(&entry.title, symbol)
+            || entry
+                .summary
+                .as_deref()
+                .is_some_and(|s| mentions_exchange_tag(s, symbol));
+        if !mentions {
+            return None;
+        }
+        Some(Self {
+            symbol: symbol.to_string(),
+            title: entry.title.clone(),
+            url: entry.url.clone(),
+            wire,
+            published: entry
+                .published
+                .as_deref()
+                .and_then(|p| chrono::DateTime::parse_from_rfc3339(p).ok())
+                .map(|dt| dt.timestamp()),
+            summary: entry.summary.clone(),
+        })
+    }
+}
+
+/// Deduplicate releases by URL and sort newest-first (undated last).
+pub(crate) fn merge_releases(
+    releases: impl IntoIterator<Item = PressRelease>,
+) -> Vec<PressRelease> {
+    let mut seen = HashSet::new();
+    let mut out: Vec<PressRelease> = releases
+        .into_iter()
+        .filter(|r| seen.insert(r.url.clone()))
+        .collect();
+    out.sort_by_key(|r| std::cmp::Reverse(r.published));
+    out
+}
+
+/// Whether `text` contains an exchange ticker tag such as `(NYSE: IBM)`.
+fn mentions_exchange_tag(text: &str, symbol: &str) -> bool {
+    let symbol = symbol.to_ascii_uppercase();
+    let upper = text.to_ascii_uppercase();
+    upper.match_indices(':').any(|(idx, _)| {
+        let rest = upper[idx + 1..].trim_start();
+        rest.strip_prefix(symbol.as_str())
+            .and_then(|r| r.chars().next())
+            .is_some_and(|c| c == ')' || c == ',' || c == ';')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(title: &str, summary: Option<&str>, published: Option<&str>) -> FeedEntry {
+        FeedEntry {
+            title: title.to_string(),
+            url: format!("https://example.com/{}", title.len()),
+            published: published.map(str::to_string),
+            summary: summary.map(str::to_string),
+            source: "Business Wire".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_wire_from_publisher() {
+        assert_eq!(
+            PressWire::from_publisher("Business Wire"),
+            Some(PressWire::BusinessWire)
+        );
+        assert_eq!(
+            PressWire::from_publisher("PR Newswire"),
+            Some(PressWire::PrNewswire)
+        );
+        assert_eq!(
+            PressWire::from_publisher("GlobeNewswire"),
+            Some(PressWire::GlobeNewswire)
+        );
+        assert_eq!(PressWire::from_publisher("Reuters"), None);
+        assert_eq!(PressWire::from_publisher("Motley Fool"), None);
+    }
+
+    #[test]
+    fn test_exchange_tag_matching() {
+        assert!(mentions_exchange_tag(
+            "Apple Inc. (NASDAQ: AAPL) reports",
+            "AAPL"
+        ));
+        assert!(mentions_exchange_tag("IBM (NYSE:IBM) announces", "ibm"));
+        assert!(mentions_exchange_tag("(NYSE: F, TSX: F) update", "F"));
+        assert!(!mentions_exchange_tag("(NASDAQ: AAPLX) fund", "AAPL"));
+        assert!(!mentions_exchange_tag("Apple AAPL analysis", "AAPL"));
+    }
+
+    #[test]
+    fn test_from_feed_entry_filters_symbol() {
+        let hit = entry(
+            "Acme Corp Declares Dividend",
+            Some("Acme Corp (NYSE: ACME) today declared..."),
+            Some("2025-01-02T13:00:00+00:00"),
+        );
+        let miss = entry("Other Co News", Some("(NYSE: OTH) today"), None);
+        let r = PressRelease::from_feed_entry("ACME", PressWire::BusinessWire, &hit).unwrap();
+        assert_eq!(r.wire, PressWire::BusinessWire);
+        assert_eq!(r.published, Some(1_735_822_800));
+        assert!(PressRelease::from_feed_entry("ACME", PressWire::BusinessWire, &miss).is_none());
+    }
+
+    #[test]
+    fn test_merge_dedupes_and_sorts() {
+        let mk = |url: &str, ts: Option<i64>| PressRelease {
+            symbol: "ACME".into(),
+            title: url.into(),
+            url: url.into(),
+            wire: PressWire::PrNewswire,
+            published: ts,
+            summary: None,
+        };
+        let merged = merge_releases([
+            mk("a", Some(1)),
+            mk("b", Some(3)),
+            mk("a", Some(1)),
+            mk("c", None),
+        ]);
+        let urls: Vec<_> = merged.iter().map(|r| r.url.as_str()).collect();
+        assert_eq!(urls, ["b", "a", "c"]);
+    }
+}
@@ -34,6 +34,14 @@ impl Transcript {
         self.transcript_metadata.fiscal_year
     }
 
+    /// Calendar date the call was held, if the metadata carries one.
+    pub fn call_date(&self) -> Option<chrono::NaiveDate> {
+        if self.transcript_metadata.date <= 0 {
+            return None;
+        }
+        chrono::DateTime::from_timestamp(self.transcript_metadata.date, 0).map(|dt| dt.date_naive())
+    }
+
     /// Get speaker name by speaker ID.
     pub fn speaker_name(&self, speaker_id: i32) -> Option<&str> {
         self.transcript_content
@@ -72,6 +80,38 @@ impl Transcript {
             .unwrap_or_else(crate::models::sentiment::Sentiment::neutral)
     }
 
+    /// Summarize the call with a pluggable [`Summarizer`](crate::summarize::Summarizer).
+    ///
+    /// Paragraphs are passed as `"Speaker: text"` lines so the summarizer can
+    /// attribute statements; falls back to the raw transcript text when no
+    /// paragraphs are present.
+    pub async fn summarize<S: crate::summarize::Summarizer + ?Sized>(
+        &self,
+        summarizer: &S,
+    ) -> crate::error::Result<crate::summarize::Summary> {
+        let paragraphs = self.paragraphs_with_speakers();
+        let text = if paragraphs.is_empty() {
+            self.text().to_string()
+        } else {
+            paragraphs
+                .iter()
+                .map(|(p, speaker)| match speaker {
+                    Some(name) => format!("{name}: {}", p.text),
+                    None => p.text.clone(),
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        let title = format!("{} {} earnings call", self.quarter(), self.year());
+        crate::summarize::summarize_text(
+            summarizer,
+            crate::summarize::DocumentKind::Transcript,
+            Some(&title),
+            &text,
+        )
+        .await
+    }
+
     /// Get all paragraphs with speaker names resolved.
     pub fn paragraphs_with_speakers(&self) -> Vec<(&Paragraph, Option<&str>)> {
         self.transcript_content
@@ -263,6 +303,48 @@ pub struct TranscriptWithMeta {
     pub transcript: Transcript,
 }
 
+/// Fiscal quarter and year an earnings call is labeled with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FiscalPeriod {
+    /// Fiscal quarter (e.g., "Q4").
+    pub quarter: String,
+    /// Fiscal year.
+    pub year: i32,
+}
+
+/// Transcript resolved by nearest match rather than an exact fiscal label.
+///
+/// Companies with non-calendar fiscal years label calls differently from the
+/// calendar quarter they fall in, so a lookup can land on a neighbouring call.
+/// `matched` states which call was actually returned.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchedTranscript {
+    /// Fiscal period of the returned call.
+    pub matched: FiscalPeriod,
+    /// Whether the returned call is exactly the one requested.
+    pub exact: bool,
+    /// Calendar date the call was held.
+    pub call_date: Option<chrono::NaiveDate>,
+    /// The full transcript.
+    pub transcript: Transcript,
+}
+
+impl MatchedTranscript {
+    pub(crate) fn new(transcript: Transcript, exact: bool) -> Self {
+        Self {
+            matched: FiscalPeriod {
+                quarter: transcript.quarter().to_string(),
+                year: transcript.year(),
+            },
+            exact,
+            call_date: transcript.call_date(),
+            transcript,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -306,5 +388,9 @@ mod tests {
         assert_eq!(transcript.quarter(), "Q4");
         assert_eq!(transcript.year(), 2025);
         assert_eq!(transcript.speaker_name(0), Some("Tim Cook"));
+        assert_eq!(
+            transcript.call_date(),
+            chrono::NaiveDate::from_ymd_opt(2025, 10, 30)
+        );
     }
 }
@@ -35,6 +35,7 @@ pub struct CryptoQuote {
     pub circulating_supply: Option<f64>,
 }
 
+#[cfg(feature = "crypto")]
 /// A cryptocurrency quote from CoinGecko.
 ///
 /// Obtain via [`crypto::coins`](crate::crypto::coins) or [`crypto::coin`](crate::crypto::coin).
@@ -62,3 +63,82 @@ pub struct CoinQuote {
     /// Market cap rank (1 = highest market cap)
     pub market_cap_rank: Option<u32>,
 }
+
+#[cfg(feature = "crypto")]
+/// A crypto exchange whose public market-data API backs
+/// [`crypto::candles`](crate::crypto::candles) and [`crypto::trades`](crate::crypto::trades).
+///
+/// Pairs are written `BASE-QUOTE` for both (e.g. `"BTC-USDT"` on Binance,
+/// `"BTC-USD"` on Coinbase); each exchange only lists its own pairs.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CryptoExchange {
+    /// Binance spot (`api.binance.com`). Quotes mostly in stablecoins (`USDT`, `USDC`).
+    Binance,
+    /// Coinbase Exchange (`api.exchange.coinbase.com`). Quotes in fiat (`USD`, `EUR`) and stablecoins.
+    Coinbase,
+}
+
+#[cfg(feature = "crypto")]
+impl CryptoExchange {
+    /// Display name (e.g., `"Binance"`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Binance => "Binance",
+            Self::Coinbase => "Coinbase",
+        }
+    }
+}
+
+#[cfg(feature = "crypto")]
+impl std::fmt::Display for CryptoExchange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(feature = "crypto")]
+/// Side of the order that took liquidity in a trade.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TradeSide {
+    /// An aggressive buyer lifted the offer.
+    Buy,
+    /// An aggressive seller hit the bid.
+    Sell,
+}
+
+#[cfg(feature = "crypto")]
+/// A single executed trade on a crypto exchange.
+///
+/// Obtain via [`crypto::trades`](crate::crypto::trades) or, with the
+/// `streaming` feature, [`crypto::TradeStream`](crate::crypto::TradeStream).
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CryptoTrade {
+    /// Exchange the trade printed on.
+    pub exchange: CryptoExchange,
+    /// Pair in `BASE-QUOTE` form (e.g., `"BTC-USDT"`).
+    pub pair: String,
+    /// Exchange-assigned trade ID.
+    pub trade_id: u64,
+    /// Execution price in the quote currency.
+    pub price: f64,
+    /// Quantity in the base currency.
+    pub size: f64,
+    /// Taker side, when the exchange reports it.
+    pub side: Option<TradeSide>,
+    /// Execution time as Unix milliseconds.
+    pub timestamp_ms: i64,
+}
+
+#[cfg(feature = "crypto")]
+impl CryptoTrade {
+    /// Traded value in the quote currency (`price * size`).
+    pub fn notional(&self) -> f64 {
+        self.price * self.size
+    }
+}
@@ -0,0 +1,164 @@
+//! Opening gap scan results.
+
+use serde::{Deserialize, Serialize};
+
+/// Which price the gap was measured from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum GapBasis {
+    /// Pre-market price (market state `PRE`/`PREPRE`)
+    PreMarket,
+    /// Regular-session open (first print), once the market has opened
+    Open,
+}
+
+/// A symbol gapping away from its prior close.
+///
+/// Returned by [`finance::gap_scan`](crate::finance::gap_scan).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "dataframe", derive(crate::ToDataFrame))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Gapper {
+    /// Stock symbol
+    pub symbol: String,
+    /// Short display name
+    pub name: Option<String>,
+    /// Prior regular-session close
+    pub previous_close: f64,
+    /// Pre-market price or open the gap was measured from
+    pub price: f64,
+    /// Gap from the prior close, in percent (negative for gap-downs)
+    pub gap_percent: f64,
+    /// Whether `price` is a pre-market price or the regular-session open
+    pub basis: GapBasis,
+    /// Volume traded in today's pre-market session, when available
+    pub premarket_volume: Option<i64>,
+    /// Intraday relative volume once the regular session is open
+    /// (see [`RelativeVolume`](crate::RelativeVolume))
+    pub rvol: Option<f64>,
+    /// Number of news articles published in roughly the last day
+    pub recent_news: u32,
+}
+
+impl Gapper {
+    /// Whether the symbol has recent news (a common filter for "gap on news")
+    pub fn has_news(&self) -> bool {
+        self.recent_news > 0
+    }
+
+    /// Whether the symbol is gapping up
+    pub fn is_gap_up(&self) -> bool {
+        self.gap_percent > 0.0
+    }
+}
+
+/// Determine the gap price, the close it is measured from, and the basis.
+///
+/// During pre-market the pre-market price is compared with the last regular
+/// close (`regular_market_price`), because Yahoo's
+/// `regular_market_previous_close` still refers to the session before it
+/// until the bell. Once the regular session has opened, the open is compared
+/// with `regular_market_previous_close`. Returns
+/// `(price, previous_close, gap_percent, basis)`.
+pub(crate) fn compute_gap(
+    market_state: Option<&str>,
+    pre_market_price: Option<f64>,
+    open: Option<f64>,
+    regular_market_price: Option<f64>,
+    regular_market_previous_close: Option<f64>,
+) -> Option<(f64, f64, f64, GapBasis)> {
+    let in_pre = matches!(market_state, Some("PRE" | "PREPRE"));
+    let (price, basis) = match (in_pre, pre_market_price, open) {
+        (true, Some(p), _) => (p, GapBasis::PreMarket),
+        (false, _, Some(o)) if o > 0.0 => (o, GapBasis::Open),
+        (_, Some(p), _) => (p, GapBasis::PreMarket),
+        _ => return None,
+    };
+    let previous_close = if in_pre {
+        regular_market_price.or(regular_market_previous_close)
+    } else {
+        regular_market_previous_close
+    }
+    .filter(|c| *c > 0.0)?;
+    Some((
+        price,
+        previous_close,
+        (price - previous_close) / previous_close * 100.0,
+        basis,
+    ))
+}
+
+/// Whether a relative news time such as `"3 hours ago"` falls within the last day.
+pub(crate) fn is_recent_news(time: &str) -> bool {
+    let t = time.to_ascii_lowercase();
+    if t.contains("just now") || t.contains("yesterday") {
+        return true;
+    }
+    if ["second", "minute", "hour"].iter().any(|u| t.contains(u)) {
+        return true;
+    }
+    // "1 day ago" / "a day ago" but not "2 days ago"
+    t.starts_with("1 day") || t.starts_with("a day")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pre_market_gap() {
+        let (price, close, pct, basis) =
+            compute_gap(Some("PRE"), Some(110.0), None, Some(100.0), Some(100.0)).unwrap();
+        assert_eq!(price, 110.0);
+        assert_eq!(close, 100.0);
+        assert!((pct - 10.0).abs() < 1e-9);
+        assert_eq!(basis, GapBasis::PreMarket);
+    }
+
+    #[test]
+    fn test_pre_market_gap_uses_last_regular_close() {
+        // Before the bell the previous-close field is still two sessions back
+        let (_, close, pct, _) =
+            compute_gap(Some("PRE"), Some(110.0), None, Some(100.0), Some(80.0)).unwrap();
+        assert_eq!(close, 100.0);
+        assert!((pct - 10.0).abs() < 1e-9);
+
+        // Falls back to the previous close when the last price is missing
+        let (_, close, _, _) =
+            compute_gap(Some("PREPRE"), Some(110.0), None, None, Some(80.0)).unwrap();
+        assert_eq!(close, 80.0);
+    }
+
+    #[test]
+    fn test_open_gap_after_bell() {
+        let (_, close, pct, basis) = compute_gap(
+            Some("REGULAR"),
+            Some(120.0),
+            Some(95.0),
+            Some(97.0),
+            Some(100.0),
+        )
+        .unwrap();
+        assert_eq!(close, 100.0);
+        assert!((pct + 5.0).abs() < 1e-9);
+        assert_eq!(basis, GapBasis::Open);
+    }
+
+    #[test]
+    fn test_no_reference_price() {
+        assert!(compute_gap(Some("CLOSED"), None, None, Some(100.0), Some(100.0)).is_none());
+        assert!(compute_gap(Some("PRE"), Some(1.0), None, Some(0.0), None).is_none());
+        assert!(compute_gap(Some("REGULAR"), None, Some(1.0), Some(1.0), None).is_none());
+    }
+
+    #[test]
+    fn test_recent_news() {
+        assert!(is_recent_news("12 minutes ago"));
+        assert!(is_recent_news("3 hours ago"));
+        assert!(is_recent_news("1 day ago"));
+        assert!(!is_recent_news("2 days ago"));
+        assert!(!is_recent_news("3 weeks ago"));
+    }
+}
@@ -54,4 +54,7 @@ pub struct LookupQuote {
     /// Company logo URL (alternate, populated when include_logo=true)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub company_logo_url: Option<String>,
+    /// Trading status (populated when `trading_status_count` covers this result)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trading_status: Option<crate::models::discovery::trading_status::TradingStatus>,
 }
@@ -2,11 +2,15 @@
 //!
 //! Search, lookup, screeners, and trending tickers.
 
+/// Opening gap scan results.
+pub mod gaps;
 /// Type-filtered symbol lookup.
 pub mod lookup;
 /// Predefined and custom screeners.
 pub mod screeners;
 /// Full-text symbol and news search.
 pub mod search;
+/// Trading status of search and lookup results.
+pub mod trading_status;
 /// Trending tickers.
 pub mod trending;
@@ -9,6 +9,7 @@ mod query;
 mod quote;
 mod response;
 mod values;
+mod watchlist;
 
 pub use condition::{
     ConditionValue, LogicalOperator, Operator, QueryCondition, QueryGroup, QueryOperand,
@@ -19,3 +20,4 @@ pub use query::{EquityScreenerQuery, FundScreenerQuery, QuoteType, ScreenerQuery
 pub use quote::ScreenerQuote;
 pub use response::ScreenerResults;
 pub use values::{ScreenerFundCategory, ScreenerPeerGroup};
+pub use watchlist::Watchlist;
@@ -1,4 +1,6 @@
 use super::quote::ScreenerQuote;
+use super::watchlist::Watchlist;
+use crate::tickers::Tickers;
 use serde::{Deserialize, Serialize};
 
 /// Raw response structure from Yahoo Finance screener API (predefined screeners)
@@ -167,6 +169,55 @@ impl ScreenerResults {
     }
 }
 
+impl ScreenerResults {
+    /// Symbols of the screened quotes, in rank order
+    pub fn symbols(&self) -> Vec<&str> {
+        self.quotes.iter().map(|q| q.symbol.as_str()).collect()
+    }
+
+    /// Save the screened symbols as a named [`Watchlist`].
+    ///
+    /// The watchlist keeps the screener's rank order and records the screener
+    /// type as its [`source`](Watchlist::source).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::{finance, Screener};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let gainers = finance::screener(Screener::DayGainers, 25).await?;
+    /// let watchlist = gainers.to_watchlist("Today's gainers");
+    /// let tickers = watchlist.tickers().await?;
+    /// let quotes = tickers.quotes().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_watchlist(&self, name: impl Into<String>) -> Watchlist {
+        let mut watchlist = Watchlist::new(name, self.symbols());
+        watchlist.source = Some(self.screener_type.clone());
+        watchlist
+    }
+
+    /// Build a [`Tickers`] handle over the top `n` screened symbols.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::{finance, Screener, Interval, TimeRange};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let actives = finance::screener(Screener::MostActives, 50).await?;
+    /// let top = actives.top_n_as_tickers(10).await?;
+    /// let charts = top.charts(Interval::OneDay, TimeRange::OneMonth).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn top_n_as_tickers(&self, n: usize) -> crate::error::Result<Tickers> {
+        Tickers::new(self.symbols().into_iter().take(n)).await
+    }
+}
+
 /// Map a custom screener record to a ScreenerQuote
 ///
 /// Custom screener records use different field names than predefined screeners.
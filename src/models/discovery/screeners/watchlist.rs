@@ -0,0 +1,104 @@
+//! Named symbol lists produced from screener results.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::tickers::Tickers;
+
+/// A named, ordered list of symbols.
+///
+/// Usually created with [`ScreenerResults::to_watchlist`](super::ScreenerResults::to_watchlist)
+/// and turned into a live [`Tickers`] handle with [`Watchlist::tickers`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Watchlist {
+    /// Watchlist name
+    pub name: String,
+    /// Symbols in screener rank order, without duplicates
+    pub symbols: Vec<String>,
+    /// Screener type the list was built from (e.g., "day_gainers"), if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+impl Watchlist {
+    /// Create a watchlist from symbols, dropping duplicates while keeping order.
+    pub fn new<S, I>(name: impl Into<String>, symbols: I) -> Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = S>,
+    {
+        let mut list = Self {
+            name: name.into(),
+            symbols: Vec::new(),
+            source: None,
+        };
+        for symbol in symbols {
+            list.add(symbol);
+        }
+        list
+    }
+
+    /// Add a symbol. Returns `false` if it was already present.
+    pub fn add(&mut self, symbol: impl Into<String>) -> bool {
+        let symbol = symbol.into();
+        if self.contains(&symbol) {
+            return false;
+        }
+        self.symbols.push(symbol);
+        true
+    }
+
+    /// Remove a symbol. Returns `false` if it was not present.
+    pub fn remove(&mut self, symbol: &str) -> bool {
+        let before = self.symbols.len();
+        self.symbols.retain(|s| !s.eq_ignore_ascii_case(symbol));
+        self.symbols.len() != before
+    }
+
+    /// Whether the watchlist contains `symbol` (case-insensitive).
+    pub fn contains(&self, symbol: &str) -> bool {
+        self.symbols.iter().any(|s| s.eq_ignore_ascii_case(symbol))
+    }
+
+    /// Number of symbols
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    /// Whether the watchlist is empty
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// Build a [`Tickers`] handle over every symbol in the watchlist.
+    pub async fn tickers(&self) -> Result<Tickers> {
+        Tickers::new(self.symbols.iter().cloned()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_dedupes_in_order() {
+        let list = Watchlist::new("tech", ["MSFT", "AAPL", "msft", "NVDA"]);
+        assert_eq!(list.symbols, ["MSFT", "AAPL", "NVDA"]);
+        assert_eq!(list.len(), 3);
+        assert!(list.source.is_none());
+    }
+
+    #[test]
+    fn test_add_remove() {
+        let mut list = Watchlist::new("x", Vec::<String>::new());
+        assert!(list.is_empty());
+        assert!(list.add("AAPL"));
+        assert!(!list.add("aapl"));
+        assert!(list.contains("AAPL"));
+        assert!(list.remove("Aapl"));
+        assert!(!list.remove("AAPL"));
+        assert!(list.is_empty());
+    }
+}
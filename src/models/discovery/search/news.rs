@@ -51,7 +51,8 @@ impl SearchNewsList {
 /// A news result from search
 ///
 /// When the `dataframe` feature is enabled, scalar fields can be converted
-/// to a DataFrame. Complex fields (thumbnail, related_tickers) are automatically skipped.
+/// to a DataFrame; `related_tickers` becomes a list column. Complex fields
+/// (thumbnail) are automatically skipped.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "dataframe", derive(crate::ToDataFrame))]
 #[non_exhaustive]
@@ -79,7 +80,7 @@ pub struct SearchNews {
     /// Article thumbnail image (excluded from DataFrame)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub thumbnail: Option<NewsThumbnail>,
-    /// Related stock symbols (excluded from DataFrame)
+    /// Related stock symbols (a `List(String)` DataFrame column)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub related_tickers: Option<Vec<String>>,
     /// Sentiment score for this article's title (VADER lexicon-based).
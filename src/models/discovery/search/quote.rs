@@ -107,4 +107,7 @@ pub struct SearchQuote {
     /// Date of name change
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name_change_date: Option<String>,
+    /// Trading status (populated when `trading_status_count` covers this result)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trading_status: Option<crate::models::discovery::trading_status::TradingStatus>,
 }
@@ -0,0 +1,28 @@
+//! Trading status of search and lookup results.
+
+use serde::{Deserialize, Serialize};
+
+/// Whether a symbol returned by search or lookup still trades.
+///
+/// Resolved through Yahoo's quote type endpoint when requested via
+/// [`SearchOptions::trading_status_count`](crate::SearchOptions::trading_status_count)
+/// or [`LookupOptions::trading_status_count`](crate::LookupOptions::trading_status_count).
+/// Yahoo keeps delisted tickers in its search index but no longer resolves
+/// them, which is what this reports. Trading halts are not visible to that
+/// endpoint.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TradingStatus {
+    /// The symbol resolves to a listed security
+    Active,
+    /// Yahoo no longer resolves the symbol
+    Delisted,
+}
+
+impl TradingStatus {
+    /// Whether the symbol can still be quoted and charted.
+    pub fn is_active(self) -> bool {
+        self == TradingStatus::Active
+    }
+}
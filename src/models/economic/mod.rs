@@ -36,6 +36,7 @@ pub struct MacroObservation {
 /// A FRED macro-economic time series with all its observations.
 ///
 /// Obtain via [`fred::series`](crate::fred::series).
+#[cfg(feature = "fred")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct MacroSeries {
@@ -45,10 +46,41 @@ pub struct MacroSeries {
     pub observations: Vec<MacroObservation>,
 }
 
+#[cfg(feature = "fred")]
+impl MacroSeries {
+    /// Line the series up against price data: for each Unix timestamp (e.g. a
+    /// [`Candle::timestamp`](crate::Candle::timestamp)), the latest non-missing
+    /// value dated on or before that UTC day.
+    ///
+    /// FRED dates an observation by the start of the period it covers, not by
+    /// when it was published, so monthly and quarterly series (CPI, GDP) become
+    /// visible here weeks before they were actually known.
+    pub fn align_to(&self, timestamps: &[i64]) -> Vec<Option<f64>> {
+        let points: Vec<(chrono::NaiveDate, f64)> = self
+            .observations
+            .iter()
+            .filter_map(|o| {
+                let date = chrono::NaiveDate::parse_from_str(&o.date, "%Y-%m-%d").ok()?;
+                Some((date, o.value?))
+            })
+            .collect();
+
+        timestamps
+            .iter()
+            .map(|&ts| {
+                let day = chrono::DateTime::from_timestamp(ts, 0)?.date_naive();
+                let idx = points.partition_point(|(date, _)| *date <= day);
+                idx.checked_sub(1).map(|i| points[i].1)
+            })
+            .collect()
+    }
+}
+
 /// One day of US Treasury yield curve rates.
 ///
 /// Maturities with no published rate on a given date are `None`.
 /// Obtain via [`fred::treasury_yields`](crate::fred::treasury_yields).
+#[cfg(feature = "fred")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
 pub struct TreasuryYield {
@@ -81,3 +113,27 @@ pub struct TreasuryYield {
     /// 30-year Treasury yield (%)
     pub y30: Option<f64>,
 }
+
+#[cfg(all(test, feature = "fred"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_align_to_carries_last_value_forward() {
+        let obs = |date: &str, value| MacroObservation {
+            date: date.to_string(),
+            value,
+        };
+        let series = MacroSeries {
+            id: "DGS10".to_string(),
+            observations: vec![
+                obs("2024-01-02", Some(3.95)),
+                obs("2024-01-03", None),
+                obs("2024-01-04", Some(3.99)),
+            ],
+        };
+        // 2024-01-01, 2024-01-03 14:30 UTC, 2024-01-05
+        let aligned = series.align_to(&[1_704_067_200, 1_704_292_200, 1_704_412_800]);
+        assert_eq!(aligned, vec![None, Some(3.95), Some(3.99)]);
+    }
+}
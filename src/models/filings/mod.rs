@@ -12,6 +12,7 @@ mod submissions;
 
 pub use cik::CikEntry;
 pub use company_facts::{CompanyFacts, FactConcept, FactUnit, FactsByTaxonomy};
+#[cfg(feature = "edgar")]
 pub use filing_index::EdgarFilingIndex;
 pub use provider::{ProviderFiling, ProviderFilings};
 pub use search::{
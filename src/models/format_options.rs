@@ -0,0 +1,333 @@
+//! Display options for human-readable values.
+//!
+//! Yahoo's `fmt` strings use fixed precision, US separators and abbreviated
+//! units. [`FormatOptions`] re-renders them from the raw value instead, either
+//! through [`ValueFormat::transform_with`](crate::ValueFormat::transform_with)
+//! for JSON responses or directly via its `format_*` methods.
+
+use std::str::FromStr;
+
+use crate::models::market::currencies::{NumberFormat, format_number};
+
+/// How percentages are rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PercentStyle {
+    /// `12.34%` (default)
+    #[default]
+    Percent,
+    /// `1234 bp`
+    BasisPoints,
+}
+
+/// How large numbers are rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum UnitStyle {
+    /// `3.45T`, `12.30M`, `1.20K` (default)
+    #[default]
+    Abbreviated,
+    /// `3,450,000,000,000`
+    Full,
+}
+
+/// Precision, separators and units for human-readable values.
+///
+/// Every setting is optional. Unset settings fall back to Yahoo's style (two
+/// decimals, `1,234.56`, `%`, `K/M/B/T`), and options with nothing set leave
+/// Yahoo's own strings untouched.
+///
+/// Options can also be parsed from a compact spec, as accepted by the
+/// server's `format` parameter and the CLI's `--number-format` flag:
+/// `decimals=1,locale=de-DE,percent=bp,units=full`.
+///
+/// # Example
+///
+/// ```
+/// use finance_query::{FormatOptions, PercentStyle, UnitStyle};
+///
+/// let opts = FormatOptions::new()
+///     .decimals(1)
+///     .locale("de-DE")
+///     .units(UnitStyle::Full);
+/// assert_eq!(opts.format_large(1_234_567.0), "1.234.567,0");
+/// assert_eq!(opts.format_percent(0.1234), "12,3%");
+///
+/// let bp: FormatOptions = "percent=bp".parse().unwrap();
+/// assert_eq!(bp, FormatOptions::new().percent_style(PercentStyle::BasisPoints));
+/// assert_eq!(bp.format_percent(0.0125), "125 bp");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FormatOptions {
+    decimals: Option<u8>,
+    number_format: Option<NumberFormat>,
+    percent: Option<PercentStyle>,
+    units: Option<UnitStyle>,
+}
+
+/// Largest accepted `decimals` setting
+const MAX_DECIMALS: u8 = 10;
+
+/// Abbreviation thresholds, largest first
+const UNITS: [(f64, &str); 4] = [(1e12, "T"), (1e9, "B"), (1e6, "M"), (1e3, "K")];
+
+impl FormatOptions {
+    /// Options with nothing set.
+    pub const fn new() -> Self {
+        Self {
+            decimals: None,
+            number_format: None,
+            percent: None,
+            units: None,
+        }
+    }
+
+    /// Decimal places (capped at 10).
+    pub fn decimals(mut self, decimals: u8) -> Self {
+        self.decimals = Some(decimals.min(MAX_DECIMALS));
+        self
+    }
+
+    /// Separators from a BCP 47 locale tag (see [`NumberFormat::for_locale`]).
+    pub fn locale(mut self, tag: &str) -> Self {
+        self.number_format = Some(NumberFormat::for_locale(tag));
+        self
+    }
+
+    /// Explicit separators.
+    pub fn number_format(mut self, format: NumberFormat) -> Self {
+        self.number_format = Some(format);
+        self
+    }
+
+    /// Percent or basis points.
+    pub fn percent_style(mut self, style: PercentStyle) -> Self {
+        self.percent = Some(style);
+        self
+    }
+
+    /// Abbreviated or full large numbers.
+    pub fn units(mut self, style: UnitStyle) -> Self {
+        self.units = Some(style);
+        self
+    }
+
+    /// Whether nothing is set, i.e. Yahoo's strings are kept as-is.
+    pub fn is_default(&self) -> bool {
+        *self == Self::new()
+    }
+
+    /// Format a plain number with the configured decimals and separators.
+    pub fn format_number(&self, value: f64) -> String {
+        self.render(value, self.decimals.unwrap_or(2))
+    }
+
+    /// Format a large number, abbreviated (`1.23B`) or in full.
+    ///
+    /// Full output uses no decimals unless `decimals` is set.
+    pub fn format_large(&self, value: f64) -> String {
+        if self.units.unwrap_or_default() == UnitStyle::Full {
+            return self.render(value, self.decimals.unwrap_or(0));
+        }
+        let decimals = self.decimals.unwrap_or(2);
+        match UNITS.iter().find(|(scale, _)| value.abs() >= *scale) {
+            Some((scale, suffix)) => format!("{}{suffix}", self.render(value / scale, decimals)),
+            // Whole numbers below 1K (e.g. small volumes) stay whole
+            None if self.decimals.is_none() && value.fract() == 0.0 => self.render(value, 0),
+            None => self.render(value, decimals),
+        }
+    }
+
+    /// Format a fraction (`0.1234`) as a percentage or in basis points.
+    ///
+    /// Basis points use no decimals unless `decimals` is set.
+    pub fn format_percent(&self, fraction: f64) -> String {
+        match self.percent.unwrap_or_default() {
+            PercentStyle::Percent => {
+                format!(
+                    "{}%",
+                    self.render(fraction * 100.0, self.decimals.unwrap_or(2))
+                )
+            }
+            PercentStyle::BasisPoints => format!(
+                "{} bp",
+                self.render(fraction * 10_000.0, self.decimals.unwrap_or(0))
+            ),
+        }
+    }
+
+    /// Re-render a Yahoo `fmt` string from its raw value.
+    ///
+    /// The kind of value is inferred from the original string: a trailing `%`
+    /// is a percentage, a trailing `K/M/B/T` a large number, and anything else
+    /// numeric a plain number (keeping its precision unless `decimals` is
+    /// set). Dates and other text return `None`.
+    pub(crate) fn reformat(&self, raw: f64, original: &str) -> Option<String> {
+        let original = original.trim();
+        if let Some(number) = original.strip_suffix('%') {
+            let shown = parse_number(number)?;
+            // Some fields carry a fraction (0.25 → "25.00%"), others are
+            // already in percent (1.5 → "1.50%")
+            let fraction = if (raw * 100.0 - shown).abs() <= (raw - shown).abs() {
+                raw
+            } else {
+                raw / 100.0
+            };
+            return Some(self.format_percent(fraction));
+        }
+        if let Some(number) = original.strip_suffix(['K', 'M', 'B', 'T', 'k']) {
+            parse_number(number)?;
+            return Some(self.format_large(raw));
+        }
+        parse_number(original)?;
+        // Keep the original precision unless overridden, so integers like
+        // share counts don't gain decimals
+        let decimals = self.decimals.unwrap_or_else(|| {
+            original
+                .split_once('.')
+                .map_or(0, |(_, frac)| frac.len().min(MAX_DECIMALS as usize) as u8)
+        });
+        Some(self.render(raw, decimals))
+    }
+
+    fn render(&self, value: f64, decimals: u8) -> String {
+        let number = format_number(
+            value.abs(),
+            decimals,
+            self.number_format.unwrap_or_default(),
+        );
+        if value < 0.0 && number.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            format!("-{number}")
+        } else {
+            number
+        }
+    }
+}
+
+/// Parse a Yahoo-formatted number (`1,234.56`, `-0.5`); `None` for non-numeric text.
+fn parse_number(s: &str) -> Option<f64> {
+    let cleaned: String = s.chars().filter(|c| *c != ',').collect();
+    if cleaned.is_empty() || cleaned.contains(|c: char| !(c.is_ascii_digit() || "+-.".contains(c)))
+    {
+        return None;
+    }
+    cleaned.parse().ok()
+}
+
+impl FromStr for FormatOptions {
+    type Err = ();
+
+    /// Parse a comma-separated `key=value` spec.
+    ///
+    /// Keys: `decimals` (0–10), `locale` (BCP 47 tag), `percent`
+    /// (`pct` | `bp`), `units` (`short` | `full`). An empty spec yields
+    /// default options.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut opts = Self::new();
+        for part in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (key, value) = part.split_once('=').ok_or(())?;
+            let value = value.trim();
+            match key.trim().to_ascii_lowercase().as_str() {
+                "decimals" | "dp" => {
+                    let d: u8 = value.parse().map_err(|_| ())?;
+                    if d > MAX_DECIMALS {
+                        return Err(());
+                    }
+                    opts = opts.decimals(d);
+                }
+                "locale" => opts = opts.locale(value),
+                "percent" => {
+                    opts = opts.percent_style(match value.to_ascii_lowercase().as_str() {
+                        "pct" | "percent" | "%" => PercentStyle::Percent,
+                        "bp" | "bps" => PercentStyle::BasisPoints,
+                        _ => return Err(()),
+                    })
+                }
+                "units" => {
+                    opts = opts.units(match value.to_ascii_lowercase().as_str() {
+                        "short" | "abbrev" | "abbreviated" => UnitStyle::Abbreviated,
+                        "full" => UnitStyle::Full,
+                        _ => return Err(()),
+                    })
+                }
+                _ => return Err(()),
+            }
+        }
+        Ok(opts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_match_yahoo() {
+        let opts = FormatOptions::new();
+        assert!(opts.is_default());
+        assert_eq!(opts.format_number(1234.5), "1,234.50");
+        assert_eq!(opts.format_large(3_450_000_000_000.0), "3.45T");
+        assert_eq!(opts.format_large(-12_300_000.0), "-12.30M");
+        assert_eq!(opts.format_large(999.0), "999");
+        assert_eq!(opts.format_large(999.5), "999.50");
+        assert_eq!(opts.format_percent(0.2549), "25.49%");
+    }
+
+    #[test]
+    fn test_configured() {
+        let opts = FormatOptions::new()
+            .decimals(1)
+            .locale("fr-FR")
+            .units(UnitStyle::Full)
+            .percent_style(PercentStyle::BasisPoints);
+        assert!(!opts.is_default());
+        assert_eq!(opts.format_large(1_234_567.0), "1\u{a0}234\u{a0}567,0");
+        assert_eq!(opts.format_percent(0.0125), "125,0 bp");
+        assert_eq!(
+            FormatOptions::new().decimals(50).format_number(1.0).len(),
+            12
+        );
+    }
+
+    #[test]
+    fn test_reformat_infers_kind() {
+        let opts = FormatOptions::new().decimals(1);
+        assert_eq!(opts.reformat(0.2549, "25.49%").as_deref(), Some("25.5%"));
+        // Already in percent
+        assert_eq!(opts.reformat(1.234, "1.23%").as_deref(), Some("1.2%"));
+        assert_eq!(opts.reformat(3.45e12, "3.45T").as_deref(), Some("3.5T"));
+        assert_eq!(
+            opts.reformat(1234.567, "1,234.57").as_deref(),
+            Some("1,234.6")
+        );
+        assert_eq!(opts.reformat(1764806400.0, "2025-12-04"), None);
+        assert_eq!(opts.reformat(1.0, "N/A"), None);
+
+        let locale = FormatOptions::new().locale("de-DE");
+        assert_eq!(
+            locale
+                .reformat(15_204_100_000.0, "15,204,100,000")
+                .as_deref(),
+            Some("15.204.100.000")
+        );
+        assert_eq!(locale.reformat(182.5, "182.50").as_deref(), Some("182,50"));
+    }
+
+    #[test]
+    fn test_parse_spec() {
+        let opts: FormatOptions = "decimals=3, locale=de-DE,percent=bps,units=full"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            opts,
+            FormatOptions::new()
+                .decimals(3)
+                .locale("de-DE")
+                .percent_style(PercentStyle::BasisPoints)
+                .units(UnitStyle::Full)
+        );
+        assert_eq!("".parse(), Ok(FormatOptions::new()));
+        assert!("decimals=11".parse::<FormatOptions>().is_err());
+        assert!("units=huge".parse::<FormatOptions>().is_err());
+        assert!("colour=blue".parse::<FormatOptions>().is_err());
+        assert!("decimals".parse::<FormatOptions>().is_err());
+    }
+}
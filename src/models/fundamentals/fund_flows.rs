@@ -0,0 +1,191 @@
+//! Estimated ETF fund flows.
+//!
+//! ETF creations and redemptions show up as changes in shares outstanding.
+//! Multiplying each change by the fund's price gives an approximate dollar
+//! flow, a common proxy for investor demand.
+//!
+//! # Caveats
+//!
+//! - Yahoo publishes shares outstanding irregularly (often daily for large
+//!   ETFs, sometimes weekly or with gaps), so one point may cover several
+//!   days of creation/redemption activity.
+//! - The market close is used in place of NAV; premiums/discounts to NAV
+//!   are ignored.
+//! - Share-count changes caused by splits are not distinguished from flows.
+//! - Values are estimates and will not match issuer-reported flows exactly.
+
+use serde::{Deserialize, Serialize};
+
+use crate::constants::TimeRange;
+use crate::models::chart::Candle;
+
+/// One shares-outstanding observation with its implied flow.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "dataframe", derive(crate::ToDataFrame))]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct FundFlowPoint {
+    /// Observation time (Unix epoch seconds)
+    pub timestamp: i64,
+    /// Reported shares outstanding
+    pub shares_outstanding: i64,
+    /// Change in shares outstanding since the previous observation
+    pub shares_change: Option<i64>,
+    /// Closing price on or before the observation, used as a NAV proxy
+    pub price: Option<f64>,
+    /// Estimated net assets (`shares_outstanding * price`)
+    pub net_assets: Option<f64>,
+    /// Estimated flow (`shares_change * price`); positive means net creations
+    pub flow: Option<f64>,
+}
+
+/// Estimated fund flow series for an ETF.
+///
+/// Obtain via [`finance::etf_flows_proxy`](crate::finance::etf_flows_proxy).
+/// See the module caveats: these are approximations derived from public
+/// shares-outstanding data, not issuer-reported flows.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct FundFlows {
+    /// Fund symbol
+    pub symbol: String,
+    /// Time range covered
+    pub range: TimeRange,
+    /// Observations in chronological order
+    pub points: Vec<FundFlowPoint>,
+}
+
+impl FundFlows {
+    /// Sum of estimated flows over the range
+    pub fn net_flow(&self) -> f64 {
+        self.points.iter().filter_map(|p| p.flow).sum()
+    }
+
+    /// Sum of positive (creation) flows
+    pub fn inflows(&self) -> f64 {
+        self.points
+            .iter()
+            .filter_map(|p| p.flow)
+            .filter(|f| *f > 0.0)
+            .sum()
+    }
+
+    /// Sum of negative (redemption) flows, as a negative number
+    pub fn outflows(&self) -> f64 {
+        self.points
+            .iter()
+            .filter_map(|p| p.flow)
+            .filter(|f| *f < 0.0)
+            .sum()
+    }
+
+    /// Net flow as a percentage of estimated net assets at the start of the range
+    pub fn net_flow_percent(&self) -> Option<f64> {
+        let start = self.points.iter().find_map(|p| p.net_assets)?;
+        (start != 0.0).then(|| self.net_flow() / start * 100.0)
+    }
+
+    /// Build the flow series from shares-outstanding observations and daily candles.
+    ///
+    /// Observations before the first candle are dropped (except the last one,
+    /// which seeds the first share change). Repeated observations with the
+    /// same share count are kept so the series stays evenly sampled.
+    pub(crate) fn estimate(
+        symbol: &str,
+        range: TimeRange,
+        shares: &[(i64, i64)],
+        candles: &[Candle],
+    ) -> Self {
+        let mut shares: Vec<(i64, i64)> = shares.to_vec();
+        shares.sort_by_key(|(ts, _)| *ts);
+        shares.dedup_by_key(|(ts, _)| *ts);
+
+        let start = candles.first().map(|c| c.timestamp).unwrap_or(i64::MIN);
+        let seed = shares.iter().rposition(|(ts, _)| *ts < start);
+        let mut prev = seed.map(|i| shares[i].1);
+        let in_range = &shares[seed.map_or(0, |i| i + 1)..];
+
+        let points = in_range
+            .iter()
+            .map(|&(timestamp, shares_outstanding)| {
+                let price = close_at(candles, timestamp);
+                let shares_change = prev.map(|p| shares_outstanding - p);
+                prev = Some(shares_outstanding);
+                FundFlowPoint {
+                    timestamp,
+                    shares_outstanding,
+                    shares_change,
+                    price,
+                    net_assets: price.map(|p| shares_outstanding as f64 * p),
+                    flow: shares_change.zip(price).map(|(d, p)| d as f64 * p),
+                }
+            })
+            .collect();
+
+        Self {
+            symbol: symbol.to_string(),
+            range,
+            points,
+        }
+    }
+}
+
+#[cfg(feature = "dataframe")]
+impl FundFlows {
+    /// Converts the flow points to a polars DataFrame.
+    pub fn to_dataframe(&self) -> ::polars::prelude::PolarsResult<::polars::prelude::DataFrame> {
+        FundFlowPoint::vec_to_dataframe(&self.points)
+    }
+}
+
+/// Close of the last candle at or before `timestamp`.
+fn close_at(candles: &[Candle], timestamp: i64) -> Option<f64> {
+    let idx = candles.partition_point(|c| c.timestamp <= timestamp);
+    idx.checked_sub(1).map(|i| candles[i].close)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candle(timestamp: i64, close: f64) -> Candle {
+        Candle {
+            timestamp,
+            close,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_estimate_flows() {
+        let candles = [candle(100, 10.0), candle(200, 11.0), candle(300, 12.0)];
+        let shares = [(50, 1_000), (150, 1_100), (250, 1_050), (350, 1_050)];
+        let flows = FundFlows::estimate("SPY", TimeRange::OneMonth, &shares, &candles);
+
+        // (50, 1000) precedes the first candle and only seeds the first change
+        assert_eq!(flows.points.len(), 3);
+        let p = &flows.points[0];
+        assert_eq!(p.shares_change, Some(100));
+        assert_eq!(p.price, Some(10.0));
+        assert_eq!(p.flow, Some(1_000.0));
+        assert_eq!(flows.points[1].flow, Some(-50.0 * 11.0));
+        assert_eq!(flows.points[2].flow, Some(0.0));
+
+        assert!((flows.net_flow() - 450.0).abs() < 1e-9);
+        assert!((flows.inflows() - 1_000.0).abs() < 1e-9);
+        assert!((flows.outflows() + 550.0).abs() < 1e-9);
+        // start net assets: 1100 * 10
+        assert!((flows.net_flow_percent().unwrap() - 450.0 / 11_000.0 * 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_first_point_without_seed_has_no_flow() {
+        let candles = [candle(100, 10.0)];
+        let flows = FundFlows::estimate("X", TimeRange::FiveDays, &[(100, 500)], &candles);
+        assert_eq!(flows.points[0].shares_change, None);
+        assert_eq!(flows.points[0].flow, None);
+        assert_eq!(flows.points[0].net_assets, Some(5_000.0));
+        assert_eq!(flows.net_flow(), 0.0);
+    }
+}
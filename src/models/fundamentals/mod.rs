@@ -7,6 +7,10 @@
 mod response;
 pub use response::FinancialStatement;
 
+// Estimated ETF flows (shares-outstanding timeseries)
+mod fund_flows;
+pub use fund_flows::{FundFlowPoint, FundFlows};
+
 // quoteSummary modules (canonical home, re-exported from quote/ for backward compat)
 pub(crate) mod balance_sheet_history;
 pub(crate) mod cashflow_statement_history;
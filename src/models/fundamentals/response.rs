@@ -65,6 +65,13 @@ pub struct FinancialStatement {
     /// Example: { "TotalRevenue": { "2024-09-30": 391035000000 } }
     pub statement: HashMap<String, HashMap<String, f64>>,
 
+    /// ISO 4217 currency the monetary values are reported in (e.g. `"USD"`).
+    ///
+    /// Taken from the first data point carrying a currency code; per-share and
+    /// ratio metrics are unitless regardless. `None` when the provider omits it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+
     /// Which provider supplied this data (None = Yahoo Finance default)
     pub provider_id: Option<crate::providers::Provider>,
 }
@@ -100,6 +107,7 @@ impl FinancialStatement {
         }
 
         let mut statement: HashMap<String, HashMap<String, f64>> = HashMap::new();
+        let mut currency: Option<String> = None;
 
         for result in raw_response.timeseries.result {
             // Get the metric name from meta.type (e.g., "annualTotalRevenue")
@@ -135,6 +143,13 @@ impl FinancialStatement {
                     continue;
                 }
 
+                if currency.is_none() {
+                    currency = point
+                        .get("currencyCode")
+                        .and_then(|v| v.as_str())
+                        .map(str::to_string);
+                }
+
                 // Extract the raw value, handling Yahoo's nested structure
                 let value = extract_value(point.get("reportedValue"));
 
@@ -164,6 +179,7 @@ impl FinancialStatement {
             statement_type: statement_type.as_str().to_string(),
             frequency: frequency.as_str().to_string(),
             statement,
+            currency,
             provider_id: None,
         })
     }
@@ -308,6 +324,7 @@ mod tests {
         let revenue = statement.statement.get("TotalRevenue").unwrap();
         assert_eq!(revenue.get("2024-09-30"), Some(&391035000000.0));
         assert_eq!(revenue.get("2023-09-30"), Some(&383285000000.0));
+        assert_eq!(statement.currency.as_deref(), Some("USD"));
     }
 
     #[test]
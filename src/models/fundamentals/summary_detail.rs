@@ -92,6 +92,18 @@ pub struct SummaryDetail<F: Format = Both> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dividend_yield: Option<F::Value<f64>>,
 
+    /// Annual coupon rate in percent (for bonds)
+    #[serde(alias = "coupon", skip_serializing_if = "Option::is_none")]
+    pub coupon_rate: Option<F::Value<f64>>,
+
+    /// Maturity date (for bonds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maturity_date: Option<F::Value<i64>>,
+
+    /// Yield to maturity in percent (for bonds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub yield_to_maturity: Option<F::Value<f64>>,
+
     /// Ex-dividend date
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ex_dividend_date: Option<F::Value<i64>>,
@@ -0,0 +1,330 @@
+//! ISO 4217 currency metadata and amount formatting.
+//!
+//! Yahoo reports money-valued fields as bare numbers alongside a currency code
+//! (`"USD"`, `"JPY"`, and minor-unit codes such as `"GBp"` for London-listed
+//! pence prices). This module maps those codes to display metadata and formats
+//! amounts with locale-aware separators.
+
+use serde::Serialize;
+
+/// Display metadata for a currency code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+pub struct CurrencyInfo {
+    /// ISO 4217 code (or Yahoo minor-unit code such as `"GBp"`)
+    pub code: &'static str,
+    /// English display name
+    pub name: &'static str,
+    /// Display symbol (e.g. `"$"`, `"€"`, `"¥"`)
+    pub symbol: &'static str,
+    /// Number of minor-unit decimal places (ISO 4217 exponent)
+    pub decimals: u8,
+    /// For minor-unit codes, the major currency and the divisor to convert
+    /// into it (e.g. `GBp` → `("GBP", 100)`).
+    pub minor_of: Option<(&'static str, u32)>,
+}
+
+impl CurrencyInfo {
+    const fn major(
+        code: &'static str,
+        name: &'static str,
+        symbol: &'static str,
+        decimals: u8,
+    ) -> Self {
+        Self {
+            code,
+            name,
+            symbol,
+            decimals,
+            minor_of: None,
+        }
+    }
+
+    const fn minor(
+        code: &'static str,
+        name: &'static str,
+        symbol: &'static str,
+        major: &'static str,
+        divisor: u32,
+    ) -> Self {
+        Self {
+            code,
+            name,
+            symbol,
+            decimals: 2,
+            minor_of: Some((major, divisor)),
+        }
+    }
+
+    /// Convert an amount in this currency into its major unit
+    /// (identity for major currencies; `GBp 1250` → `GBP 12.50`).
+    pub fn to_major(&self, value: f64) -> f64 {
+        match self.minor_of {
+            Some((_, divisor)) => value / divisor as f64,
+            None => value,
+        }
+    }
+}
+
+const CURRENCIES: &[CurrencyInfo] = &[
+    CurrencyInfo::major("USD", "US Dollar", "$", 2),
+    CurrencyInfo::major("EUR", "Euro", "€", 2),
+    CurrencyInfo::major("JPY", "Japanese Yen", "¥", 0),
+    CurrencyInfo::major("GBP", "British Pound", "£", 2),
+    CurrencyInfo::major("CNY", "Chinese Yuan", "CN¥", 2),
+    CurrencyInfo::major("HKD", "Hong Kong Dollar", "HK$", 2),
+    CurrencyInfo::major("CAD", "Canadian Dollar", "CA$", 2),
+    CurrencyInfo::major("AUD", "Australian Dollar", "A$", 2),
+    CurrencyInfo::major("NZD", "New Zealand Dollar", "NZ$", 2),
+    CurrencyInfo::major("CHF", "Swiss Franc", "CHF", 2),
+    CurrencyInfo::major("SEK", "Swedish Krona", "kr", 2),
+    CurrencyInfo::major("NOK", "Norwegian Krone", "kr", 2),
+    CurrencyInfo::major("DKK", "Danish Krone", "kr.", 2),
+    CurrencyInfo::major("PLN", "Polish Zloty", "zł", 2),
+    CurrencyInfo::major("CZK", "Czech Koruna", "Kč", 2),
+    CurrencyInfo::major("HUF", "Hungarian Forint", "Ft", 2),
+    CurrencyInfo::major("TRY", "Turkish Lira", "₺", 2),
+    CurrencyInfo::major("RUB", "Russian Ruble", "₽", 2),
+    CurrencyInfo::major("INR", "Indian Rupee", "₹", 2),
+    CurrencyInfo::major("KRW", "South Korean Won", "₩", 0),
+    CurrencyInfo::major("TWD", "New Taiwan Dollar", "NT$", 2),
+    CurrencyInfo::major("SGD", "Singapore Dollar", "S$", 2),
+    CurrencyInfo::major("THB", "Thai Baht", "฿", 2),
+    CurrencyInfo::major("IDR", "Indonesian Rupiah", "Rp", 2),
+    CurrencyInfo::major("MYR", "Malaysian Ringgit", "RM", 2),
+    CurrencyInfo::major("PHP", "Philippine Peso", "₱", 2),
+    CurrencyInfo::major("VND", "Vietnamese Dong", "₫", 0),
+    CurrencyInfo::major("BRL", "Brazilian Real", "R$", 2),
+    CurrencyInfo::major("MXN", "Mexican Peso", "MX$", 2),
+    CurrencyInfo::major("ARS", "Argentine Peso", "AR$", 2),
+    CurrencyInfo::major("CLP", "Chilean Peso", "CL$", 0),
+    CurrencyInfo::major("COP", "Colombian Peso", "CO$", 2),
+    CurrencyInfo::major("ZAR", "South African Rand", "R", 2),
+    CurrencyInfo::major("ILS", "Israeli New Shekel", "₪", 2),
+    CurrencyInfo::major("SAR", "Saudi Riyal", "SAR", 2),
+    CurrencyInfo::major("AED", "UAE Dirham", "AED", 2),
+    CurrencyInfo::major("QAR", "Qatari Riyal", "QAR", 2),
+    CurrencyInfo::major("KWD", "Kuwaiti Dinar", "KD", 3),
+    CurrencyInfo::major("BHD", "Bahraini Dinar", "BD", 3),
+    CurrencyInfo::major("EGP", "Egyptian Pound", "E£", 2),
+    CurrencyInfo::major("NGN", "Nigerian Naira", "₦", 2),
+    CurrencyInfo::minor("GBp", "British Penny", "p", "GBP", 100),
+    CurrencyInfo::minor("GBX", "British Penny", "p", "GBP", 100),
+    CurrencyInfo::minor("ILA", "Israeli Agora", "ag", "ILS", 100),
+    CurrencyInfo::minor("ZAc", "South African Cent", "c", "ZAR", 100),
+];
+
+/// Look up display metadata for a currency code.
+///
+/// ISO codes match case-insensitively; Yahoo's case-sensitive minor-unit
+/// codes (`"GBp"`, `"ZAc"`) are matched exactly first so `"GBp"` resolves to
+/// pence rather than pounds.
+///
+/// ```
+/// use finance_query::currency_info;
+///
+/// assert_eq!(currency_info("JPY").unwrap().decimals, 0);
+/// assert_eq!(currency_info("GBp").unwrap().minor_of, Some(("GBP", 100)));
+/// assert!(currency_info("XYZ").is_none());
+/// ```
+pub fn currency_info(code: &str) -> Option<&'static CurrencyInfo> {
+    let code = code.trim();
+    CURRENCIES.iter().find(|c| c.code == code).or_else(|| {
+        CURRENCIES
+            .iter()
+            .find(|c| c.minor_of.is_none() && c.code.eq_ignore_ascii_case(code))
+    })
+}
+
+/// Number formatting conventions for a locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct NumberFormat {
+    /// Thousands separator (`None` = no grouping)
+    pub group: Option<char>,
+    /// Decimal separator
+    pub decimal: char,
+    /// Place the currency symbol after the number (`1.234,56 €`)
+    pub symbol_after: bool,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::EN_US
+    }
+}
+
+impl NumberFormat {
+    /// `1,234.56` with a leading symbol (US, UK, Japan, China, India, …).
+    pub const EN_US: Self = Self {
+        group: Some(','),
+        decimal: '.',
+        symbol_after: false,
+    };
+    /// `1.234,56` with a trailing symbol (Germany, Spain, Italy, Brazil, …).
+    pub const DE_DE: Self = Self {
+        group: Some('.'),
+        decimal: ',',
+        symbol_after: true,
+    };
+    /// `1 234,56` with a trailing symbol (France, Nordics, Poland, Russia, …).
+    pub const FR_FR: Self = Self {
+        group: Some('\u{a0}'),
+        decimal: ',',
+        symbol_after: true,
+    };
+    /// `1'234.56` with a leading symbol (Switzerland).
+    pub const DE_CH: Self = Self {
+        group: Some('\''),
+        decimal: '.',
+        symbol_after: false,
+    };
+
+    /// Resolve conventions from a BCP 47 locale tag (e.g. `"de-DE"`, `"fr"`,
+    /// `"ja-JP"`). Unknown tags fall back to [`NumberFormat::EN_US`].
+    pub fn for_locale(tag: &str) -> Self {
+        let tag = tag.replace('_', "-").to_ascii_lowercase();
+        let mut parts = tag.split('-');
+        let lang = parts.next().unwrap_or_default();
+        let region = parts.next().unwrap_or_default();
+        match (lang, region) {
+            ("de", "ch") | ("fr", "ch") | ("it", "ch") => Self::DE_CH,
+            ("de", _)
+            | ("es", _)
+            | ("it", _)
+            | ("pt", _)
+            | ("nl", _)
+            | ("id", _)
+            | ("tr", _)
+            | ("da", _)
+            | ("el", _) => Self::DE_DE,
+            ("fr", _)
+            | ("sv", _)
+            | ("nb", _)
+            | ("no", _)
+            | ("fi", _)
+            | ("pl", _)
+            | ("ru", _)
+            | ("cs", _)
+            | ("hu", _)
+            | ("uk", _) => Self::FR_FR,
+            _ => Self::EN_US,
+        }
+    }
+
+    /// Format a monetary amount with these conventions.
+    ///
+    /// See [`format_amount`] for the locale-tag convenience wrapper.
+    pub fn format_amount(&self, value: f64, currency: &str) -> String {
+        match currency_info(currency) {
+            Some(info) => format_with(value, info.decimals, info.symbol, *self),
+            None => {
+                let mut out = format_number(value.abs(), 2, *self);
+                out.push(' ');
+                out.push_str(currency);
+                if value < 0.0 {
+                    out.insert(0, '-');
+                }
+                out
+            }
+        }
+    }
+}
+
+/// Format a monetary amount with the currency's symbol, decimal places, and
+/// the locale's separators.
+///
+/// Unknown currency codes are rendered with the code as a trailing suffix and
+/// two decimal places.
+///
+/// ```
+/// use finance_query::format_amount;
+///
+/// assert_eq!(format_amount(1234.5, "USD", "en-US"), "$1,234.50");
+/// assert_eq!(format_amount(1234.5, "EUR", "de-DE"), "1.234,50 €");
+/// assert_eq!(format_amount(-98765.4, "JPY", "ja-JP"), "-¥98,765");
+/// ```
+pub fn format_amount(value: f64, currency: &str, locale: &str) -> String {
+    NumberFormat::for_locale(locale).format_amount(value, currency)
+}
+
+fn format_with(value: f64, decimals: u8, symbol: &str, fmt: NumberFormat) -> String {
+    let number = format_number(value.abs(), decimals, fmt);
+    let sign = if value < 0.0 && number.chars().any(|c| c.is_ascii_digit() && c != '0') {
+        "-"
+    } else {
+        ""
+    };
+    if fmt.symbol_after {
+        format!("{sign}{number} {symbol}")
+    } else {
+        format!("{sign}{symbol}{number}")
+    }
+}
+
+/// Format a non-negative number with grouping and fixed decimals.
+pub(crate) fn format_number(value: f64, decimals: u8, fmt: NumberFormat) -> String {
+    let fixed = format!("{:.*}", decimals as usize, value);
+    let (int_part, frac_part) = match fixed.split_once('.') {
+        Some((i, f)) => (i, Some(f)),
+        None => (fixed.as_str(), None),
+    };
+    let mut out = String::with_capacity(fixed.len() + int_part.len() / 3);
+    for (i, c) in int_part.chars().enumerate() {
+        if i > 0
+            && (int_part.len() - i) % 3 == 0
+            && let Some(group) = fmt.group
+        {
+            out.push(group);
+        }
+        out.push(c);
+    }
+    if let Some(frac) = frac_part {
+        out.push(fmt.decimal);
+        out.push_str(frac);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_currency_info_lookup() {
+        let usd = currency_info("usd").unwrap();
+        assert_eq!(usd.code, "USD");
+        assert_eq!(usd.symbol, "$");
+        assert_eq!(currency_info("KWD").unwrap().decimals, 3);
+        // Yahoo's pence code must not resolve to pounds
+        assert_eq!(currency_info("GBp").unwrap().code, "GBp");
+        assert_eq!(currency_info("GBP").unwrap().code, "GBP");
+        assert!(currency_info("").is_none());
+    }
+
+    #[test]
+    fn test_to_major() {
+        assert_eq!(currency_info("GBp").unwrap().to_major(1250.0), 12.5);
+        assert_eq!(currency_info("USD").unwrap().to_major(12.5), 12.5);
+    }
+
+    #[test]
+    fn test_format_amount_locales() {
+        assert_eq!(
+            format_amount(1_234_567.891, "USD", "en-US"),
+            "$1,234,567.89"
+        );
+        assert_eq!(format_amount(1234.5, "EUR", "de-DE"), "1.234,50 €");
+        assert_eq!(format_amount(1234.5, "EUR", "fr-FR"), "1\u{a0}234,50 €");
+        assert_eq!(format_amount(1234.5, "CHF", "de-CH"), "CHF1'234.50");
+        assert_eq!(format_amount(999.0, "JPY", "ja"), "¥999");
+        assert_eq!(format_amount(0.5, "KWD", "en"), "KD0.500");
+    }
+
+    #[test]
+    fn test_format_amount_negative_and_unknown() {
+        assert_eq!(format_amount(-12.0, "USD", "en-US"), "-$12.00");
+        assert_eq!(format_amount(-0.001, "USD", "en-US"), "$0.00");
+        assert_eq!(format_amount(1500.0, "XYZ", "en-US"), "1,500.00 XYZ");
+    }
+}
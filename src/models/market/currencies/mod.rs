@@ -1,5 +1,8 @@
 //! Currency models.
 
+mod iso;
 mod response;
 
+pub(crate) use iso::format_number;
+pub use iso::{CurrencyInfo, NumberFormat, currency_info, format_amount};
 pub use response::Currency;
@@ -0,0 +1,124 @@
+//! End-of-day research bundle: one day's quotes, key stats, and daily bars.
+
+use std::collections::{BTreeMap, HashMap};
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::models::chart::Candle;
+use crate::models::quote::Quote;
+
+/// One symbol's data in an [`EodSnapshot`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EodSymbol {
+    /// Closing quote, including key stats (market cap, P/E, beta, 52-week
+    /// range, dividend yield, EPS).
+    pub quote: Option<Quote>,
+    /// The session's daily bar, or the latest bar before it when the symbol
+    /// didn't trade that day.
+    pub candle: Option<Candle>,
+}
+
+/// A dated end-of-day bundle for a symbol list.
+///
+/// Obtain via [`finance::eod_snapshot`](crate::finance::eod_snapshot), which
+/// also writes it to disk as gzip-compressed JSON; read a saved bundle back
+/// with [`EodSnapshot::read`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EodSnapshot {
+    /// Trading session the bundle covers (`YYYY-MM-DD`, New York date).
+    pub session_date: String,
+    /// When the bundle was collected (Unix seconds).
+    pub generated_at: i64,
+    /// U.S. market status at collection time (e.g. `"closed"`), if hours
+    /// were available.
+    pub market_status: Option<String>,
+    /// Data keyed by symbol. Symbols appear when either their quote or their
+    /// bar loaded.
+    pub symbols: BTreeMap<String, EodSymbol>,
+    /// Failed requests keyed by symbol, with the reason.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub errors: HashMap<String, String>,
+}
+
+impl EodSnapshot {
+    /// Whether the regular session had closed when the bundle was collected,
+    /// so the daily bars are final. `false` when hours were unavailable.
+    pub fn is_final(&self) -> bool {
+        self.market_status
+            .as_deref()
+            .is_some_and(|s| !s.eq_ignore_ascii_case("open"))
+    }
+
+    /// File name the bundle is saved under: `eod-YYYY-MM-DD.json.gz`.
+    pub fn file_name(&self) -> String {
+        format!("eod-{}.json.gz", self.session_date)
+    }
+
+    /// Read a bundle written by [`finance::eod_snapshot`](crate::finance::eod_snapshot).
+    pub fn read(path: impl AsRef<Path>) -> Result<Self> {
+        let file = std::fs::File::open(path)?;
+        let mut json = Vec::new();
+        flate2::read::GzDecoder::new(BufReader::new(file)).read_to_end(&mut json)?;
+        Ok(serde_json::from_slice(&json)?)
+    }
+
+    /// Write the bundle into `dir` under [`file_name`](Self::file_name),
+    /// creating `dir` if needed and replacing an earlier bundle for the same
+    /// session. The file is written beside its final name and renamed into
+    /// place, so a reader never sees a partial bundle.
+    pub(crate) fn write(&self, dir: &Path) -> Result<std::path::PathBuf> {
+        use std::io::Write;
+
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(self.file_name());
+        let tmp = dir.join(format!(".{}.tmp", self.file_name()));
+        {
+            let file = std::fs::File::create(&tmp)?;
+            let mut gz = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+            serde_json::to_writer(&mut gz, self)?;
+            gz.finish()?.flush()?;
+        }
+        std::fs::rename(&tmp, &path)?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_read_round_trip() {
+        let dir = std::env::temp_dir().join(format!("fq-eod-{}", std::process::id()));
+        let mut symbols = BTreeMap::new();
+        symbols.insert(
+            "AAPL".to_string(),
+            EodSymbol {
+                quote: None,
+                candle: Some(Candle::new(1_700_000_000, 1.0, 2.0, 0.5, 1.5, 100)),
+            },
+        );
+        let snapshot = EodSnapshot {
+            session_date: "2023-11-14".to_string(),
+            generated_at: 1_700_010_000,
+            market_status: Some("closed".to_string()),
+            symbols,
+            errors: HashMap::from([("NOPE".to_string(), "not found".to_string())]),
+        };
+
+        let path = snapshot.write(&dir).unwrap();
+        assert!(path.ends_with("eod-2023-11-14.json.gz"));
+        let read = EodSnapshot::read(&path).unwrap();
+        assert!(read.is_final());
+        assert_eq!(read.symbols["AAPL"].candle.as_ref().unwrap().close, 1.5);
+        assert_eq!(read.errors["NOPE"], "not found");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
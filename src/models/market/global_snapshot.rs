@@ -0,0 +1,42 @@
+//! Market summary and trending symbols for several regions at once.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::constants::Region;
+use crate::models::discovery::trending::TrendingQuote;
+use crate::models::market::market_summary::MarketSummaryQuote;
+
+/// Market summary and trending symbols for one region.
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegionSnapshot {
+    /// Major indices, currencies, and commodities for the region
+    pub market_summary: Vec<MarketSummaryQuote>,
+    /// Trending symbols in the region
+    pub trending: Vec<TrendingQuote>,
+}
+
+/// Market summaries and trending symbols keyed by region code.
+///
+/// Obtain via [`finance::global_snapshot`](crate::finance::global_snapshot).
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GlobalSnapshot {
+    /// Snapshots keyed by region code (e.g., "US", "JP")
+    pub regions: HashMap<String, RegionSnapshot>,
+    /// Failed requests keyed by region code, with the reason
+    ///
+    /// A region whose market summary loaded but whose trending list failed
+    /// (or vice versa) appears in both maps, with the failed part empty.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub errors: HashMap<String, String>,
+}
+
+impl GlobalSnapshot {
+    /// Snapshot for `region`, if it was requested.
+    pub fn get(&self, region: Region) -> Option<&RegionSnapshot> {
+        self.regions.get(region.region())
+    }
+}
@@ -1,5 +1,7 @@
 //! Market hours models.
 
 mod response;
+mod schedule;
 
 pub use response::{MarketHours, MarketTime};
+pub(crate) use schedule::new_york_offset;
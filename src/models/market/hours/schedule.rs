@@ -0,0 +1,274 @@
+//! Fixed trading schedules for markets Yahoo's markettime endpoint doesn't cover.
+//!
+//! Yahoo only reports equity exchange sessions. Crypto, spot FX, and CME
+//! futures follow published schedules, so their status is computed locally:
+//!
+//! - **Crypto**: always open.
+//! - **FX**: Sunday 17:00 to Friday 17:00 New York time.
+//! - **Futures**: CME Globex, Sunday 18:00 to Friday 17:00 New York time, with
+//!   a daily maintenance break from 17:00 to 18:00.
+//!
+//! Exchange holidays are not modelled.
+
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, Timelike, Utc, Weekday};
+
+use super::MarketTime;
+use crate::streaming::AssetClass;
+
+/// How far to search for a session boundary. The longest stretch without
+/// one is the five-day FX week.
+const MAX_SEARCH_HOURS: i64 = 24 * 8;
+
+impl MarketTime {
+    /// Session status for an asset class that trades outside equity hours.
+    ///
+    /// Returns `None` for equities, indices, and options, whose hours come
+    /// from the exchange via [`finance::hours`](crate::finance::hours).
+    /// `open` and `close` bound the current session, or the next one when
+    /// the market is closed; both are `None` for crypto.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use finance_query::MarketTime;
+    /// use finance_query::streaming::AssetClass;
+    ///
+    /// let crypto = MarketTime::for_asset_class(AssetClass::Crypto, chrono::Utc::now()).unwrap();
+    /// assert!(crypto.is_open());
+    /// assert!(MarketTime::for_asset_class(AssetClass::Equity, chrono::Utc::now()).is_none());
+    /// ```
+    pub fn for_asset_class(asset_class: AssetClass, at: DateTime<Utc>) -> Option<Self> {
+        let (id, name) = match asset_class {
+            AssetClass::Crypto => ("crypto", "Crypto markets"),
+            AssetClass::Fx => ("fx", "FX markets"),
+            AssetClass::Future => ("futures", "Futures markets"),
+            _ => return None,
+        };
+
+        let open = is_open(asset_class, at);
+        let status = if open { "open" } else { "closed" };
+
+        if asset_class == AssetClass::Crypto {
+            return Some(Self {
+                id: id.to_string(),
+                name: name.to_string(),
+                status: status.to_string(),
+                message: Some(format!("{name} {status}")),
+                open: None,
+                close: None,
+                time: Some(at.to_rfc3339()),
+                timezone: Some("UTC".to_string()),
+                timezone_short: Some("UTC".to_string()),
+                gmt_offset: Some(0),
+                dst: Some(false),
+            });
+        }
+
+        let session_open = if open {
+            previous_transition(asset_class, at)
+        } else {
+            next_transition(asset_class, at)
+        };
+        let session_close = session_open.and_then(|t| next_transition(asset_class, t));
+
+        let local = |t: DateTime<Utc>| t.with_timezone(&new_york_offset(t)).to_rfc3339();
+        let offset = new_york_offset(at);
+        let dst = offset.local_minus_utc() == EDT_OFFSET;
+
+        Some(Self {
+            id: id.to_string(),
+            name: name.to_string(),
+            status: status.to_string(),
+            message: Some(format!("{name} {status}")),
+            open: session_open.map(local),
+            close: session_close.map(local),
+            time: Some(local(at)),
+            timezone: Some("America/New_York".to_string()),
+            timezone_short: Some(if dst { "EDT" } else { "EST" }.to_string()),
+            gmt_offset: Some(offset.local_minus_utc()),
+            dst: Some(dst),
+        })
+    }
+
+    /// Whether the market is currently open.
+    pub fn is_open(&self) -> bool {
+        self.status.eq_ignore_ascii_case("open")
+    }
+}
+
+/// Whether `asset_class` is trading at `at`. Equity-like classes are `false`;
+/// their schedule isn't known locally.
+fn is_open(asset_class: AssetClass, at: DateTime<Utc>) -> bool {
+    let local = at.with_timezone(&new_york_offset(at));
+    let hour = local.hour();
+    match asset_class {
+        AssetClass::Crypto => true,
+        AssetClass::Fx => match local.weekday() {
+            Weekday::Sat => false,
+            Weekday::Sun => hour >= 17,
+            Weekday::Fri => hour < 17,
+            _ => true,
+        },
+        AssetClass::Future => match local.weekday() {
+            Weekday::Sat => false,
+            Weekday::Sun => hour >= 18,
+            Weekday::Fri => hour < 17,
+            _ => hour != 17,
+        },
+        _ => false,
+    }
+}
+
+/// First whole hour after `at` where the open/closed state differs.
+///
+/// Every boundary falls on a whole New York hour, and New York's UTC offset
+/// is a whole number of hours, so stepping hourly in UTC finds it exactly.
+fn next_transition(asset_class: AssetClass, at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let state = is_open(asset_class, at);
+    let start = truncate_to_hour(at);
+    (1..=MAX_SEARCH_HOURS)
+        .map(|h| start + Duration::hours(h))
+        .find(|t| is_open(asset_class, *t) != state)
+}
+
+/// Start of the hour in which the current state began.
+fn previous_transition(asset_class: AssetClass, at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let state = is_open(asset_class, at);
+    let start = truncate_to_hour(at);
+    (1..=MAX_SEARCH_HOURS)
+        .map(|h| start - Duration::hours(h))
+        .find(|t| is_open(asset_class, *t) != state)
+        .map(|t| t + Duration::hours(1))
+}
+
+fn truncate_to_hour(at: DateTime<Utc>) -> DateTime<Utc> {
+    at.with_minute(0)
+        .and_then(|t| t.with_second(0))
+        .and_then(|t| t.with_nanosecond(0))
+        .unwrap_or(at)
+}
+
+const EST_OFFSET: i32 = -5 * 3600;
+const EDT_OFFSET: i32 = -4 * 3600;
+
+/// New York's UTC offset at `at`.
+///
+/// US daylight saving runs from 02:00 local on the second Sunday of March
+/// to 02:00 local on the first Sunday of November.
+pub(crate) fn new_york_offset(at: DateTime<Utc>) -> FixedOffset {
+    let year = at.year();
+    let dst_start = nth_sunday(year, 3, 2).and_hms_opt(7, 0, 0);
+    let dst_end = nth_sunday(year, 11, 1).and_hms_opt(6, 0, 0);
+    let in_dst = match (dst_start, dst_end) {
+        (Some(start), Some(end)) => {
+            let naive = at.naive_utc();
+            naive >= start && naive < end
+        }
+        _ => false,
+    };
+    let secs = if in_dst { EDT_OFFSET } else { EST_OFFSET };
+    FixedOffset::east_opt(secs).expect("offset is within a day")
+}
+
+/// The `n`th Sunday (1-based) of `month` in `year`.
+fn nth_sunday(year: i32, month: u32, n: u32) -> NaiveDate {
+    let first = NaiveDate::from_ymd_opt(year, month, 1).unwrap_or_default();
+    let to_sunday = (7 - first.weekday().num_days_from_sunday()) % 7;
+    first + Duration::days(i64::from(to_sunday + 7 * (n - 1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn test_new_york_offset_dst() {
+        // 2024: DST from Mar 10 to Nov 3
+        assert_eq!(
+            nth_sunday(2024, 3, 2),
+            NaiveDate::from_ymd_opt(2024, 3, 10).unwrap()
+        );
+        assert_eq!(
+            nth_sunday(2024, 11, 1),
+            NaiveDate::from_ymd_opt(2024, 11, 3).unwrap()
+        );
+        assert_eq!(
+            new_york_offset(utc(2024, 1, 15, 12, 0)).local_minus_utc(),
+            EST_OFFSET
+        );
+        assert_eq!(
+            new_york_offset(utc(2024, 7, 15, 12, 0)).local_minus_utc(),
+            EDT_OFFSET
+        );
+        assert_eq!(
+            new_york_offset(utc(2024, 3, 10, 6, 59)).local_minus_utc(),
+            EST_OFFSET
+        );
+        assert_eq!(
+            new_york_offset(utc(2024, 3, 10, 7, 0)).local_minus_utc(),
+            EDT_OFFSET
+        );
+    }
+
+    #[test]
+    fn test_crypto_always_open() {
+        // Saturday
+        let t = MarketTime::for_asset_class(AssetClass::Crypto, utc(2024, 6, 15, 12, 0)).unwrap();
+        assert!(t.is_open());
+        assert_eq!(t.open, None);
+        assert_eq!(t.close, None);
+    }
+
+    #[test]
+    fn test_fx_weekend_gap() {
+        // Saturday 2024-06-15 noon ET: closed, reopens Sunday 17:00 EDT
+        let sat = MarketTime::for_asset_class(AssetClass::Fx, utc(2024, 6, 15, 16, 0)).unwrap();
+        assert!(!sat.is_open());
+        assert_eq!(sat.open.as_deref(), Some("2024-06-16T17:00:00-04:00"));
+        assert_eq!(sat.close.as_deref(), Some("2024-06-21T17:00:00-04:00"));
+        assert_eq!(sat.dst, Some(true));
+
+        // Wednesday overnight: open, session started the previous Sunday
+        let wed = MarketTime::for_asset_class(AssetClass::Fx, utc(2024, 6, 19, 4, 30)).unwrap();
+        assert!(wed.is_open());
+        assert_eq!(wed.open.as_deref(), Some("2024-06-16T17:00:00-04:00"));
+        assert_eq!(wed.close.as_deref(), Some("2024-06-21T17:00:00-04:00"));
+
+        // Friday 17:00 EST (22:00 UTC) in winter: closed
+        assert!(!is_open(AssetClass::Fx, utc(2024, 1, 19, 22, 0)));
+        assert!(is_open(AssetClass::Fx, utc(2024, 1, 19, 21, 59)));
+    }
+
+    #[test]
+    fn test_futures_daily_break() {
+        // Tuesday 17:30 EDT: maintenance break, reopens 18:00
+        let brk =
+            MarketTime::for_asset_class(AssetClass::Future, utc(2024, 6, 18, 21, 30)).unwrap();
+        assert!(!brk.is_open());
+        assert_eq!(brk.open.as_deref(), Some("2024-06-18T18:00:00-04:00"));
+        assert_eq!(brk.close.as_deref(), Some("2024-06-19T17:00:00-04:00"));
+
+        // Tuesday 02:00 EDT: overnight session
+        let night =
+            MarketTime::for_asset_class(AssetClass::Future, utc(2024, 6, 18, 6, 0)).unwrap();
+        assert!(night.is_open());
+        assert_eq!(night.open.as_deref(), Some("2024-06-17T18:00:00-04:00"));
+
+        // Sunday 17:30 EDT: FX open, futures not yet
+        let sun = utc(2024, 6, 16, 21, 30);
+        assert!(is_open(AssetClass::Fx, sun));
+        assert!(!is_open(AssetClass::Future, sun));
+    }
+
+    #[test]
+    fn test_equity_not_scheduled_locally() {
+        let now = utc(2024, 6, 18, 15, 0);
+        assert!(MarketTime::for_asset_class(AssetClass::Equity, now).is_none());
+        assert!(MarketTime::for_asset_class(AssetClass::Index, now).is_none());
+    }
+}
@@ -89,6 +89,54 @@ pub struct TrendEstimate {
     pub growth: Option<FormattedValue<f64>>,
 }
 
+impl TrendEstimate {
+    /// Growth rate as a plain number (0.15 = 15%).
+    pub fn growth_value(&self) -> Option<f64> {
+        self.growth.as_ref()?.raw
+    }
+}
+
+/// Plain-number accessors shared by the index, industry and sector trend modules.
+macro_rules! impl_trend_accessors {
+    ($($ty:ty),*) => {
+        $(
+            impl $ty {
+                /// Forward P/E ratio.
+                pub fn pe(&self) -> Option<f64> {
+                    self.pe_ratio.as_ref()?.raw
+                }
+
+                /// PEG ratio.
+                pub fn peg(&self) -> Option<f64> {
+                    self.peg_ratio.as_ref()?.raw
+                }
+
+                /// Growth estimate for `period` (e.g. "0q", "+1y", "LTG").
+                pub fn growth(&self, period: &str) -> Option<f64> {
+                    self.estimates
+                        .as_deref()?
+                        .iter()
+                        .find(|e| e.period.as_deref() == Some(period))?
+                        .growth_value()
+                }
+
+                /// Growth estimates as `(period, growth)` pairs, in Yahoo's order.
+                ///
+                /// Periods without a growth value are skipped.
+                pub fn growth_by_period(&self) -> Vec<(String, f64)> {
+                    self.estimates
+                        .iter()
+                        .flatten()
+                        .filter_map(|e| Some((e.period.clone()?, e.growth_value()?)))
+                        .collect()
+                }
+            }
+        )*
+    };
+}
+
+impl_trend_accessors!(IndexTrend, IndustryTrend, SectorTrend);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -151,4 +199,32 @@ mod tests {
         let result: Result<IndexTrend, _> = serde_json::from_str(json);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_trend_accessors() {
+        let json = r#"{
+          "symbol": "SP5",
+          "peRatio": {"raw": 21.5, "fmt": "21.50"},
+          "pegRatio": 1.8,
+          "estimates": [
+            {"period": "0q", "growth": {"raw": 0.1516, "fmt": "0.15"}},
+            {"period": "+1q", "growth": {}},
+            {"period": "+1y", "growth": {"raw": 0.12, "fmt": "0.12"}}
+          ]
+        }"#;
+        let trend: IndexTrend = serde_json::from_str(json).unwrap();
+        assert_eq!(trend.pe(), Some(21.5));
+        assert_eq!(trend.peg(), Some(1.8));
+        assert_eq!(trend.growth("+1y"), Some(0.12));
+        assert_eq!(trend.growth("+1q"), None);
+        assert_eq!(trend.growth("LTG"), None);
+        assert_eq!(
+            trend.growth_by_period(),
+            vec![("0q".to_string(), 0.1516), ("+1y".to_string(), 0.12)]
+        );
+
+        let empty: SectorTrend = serde_json::from_str("{}").unwrap();
+        assert_eq!(empty.pe(), None);
+        assert!(empty.growth_by_period().is_empty());
+    }
 }
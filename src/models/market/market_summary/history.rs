@@ -0,0 +1,240 @@
+//! Historical market summary snapshots.
+//!
+//! Daily closes for the market summary instrument set, aligned onto a shared
+//! calendar so dashboards can render multi-week trends per tile.
+
+use std::collections::{BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use super::MarketSummaryQuote;
+use crate::constants::TimeRange;
+use crate::models::chart::Chart;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Daily close history for one market summary instrument.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct MarketSummarySeries {
+    /// Instrument symbol (e.g., "^GSPC", "EURUSD=X", "CL=F")
+    pub symbol: String,
+    /// Short display name from the market summary
+    pub short_name: Option<String>,
+    /// Quote type (INDEX, CURRENCY, FUTURE, etc.)
+    pub quote_type: Option<String>,
+    /// Closes aligned to [`MarketSummaryHistory::dates`].
+    ///
+    /// Days the instrument did not trade carry the previous close forward;
+    /// days before its first close in the range are `None`.
+    pub closes: Vec<Option<f64>>,
+    /// Closes rebased so the first available close equals 100
+    pub normalized: Vec<Option<f64>>,
+    /// Percent change from the first to the last available close in the range
+    pub change_percent: Option<f64>,
+}
+
+/// Daily history for every instrument in the market summary.
+///
+/// Obtain via [`finance::market_summary_history`](crate::finance::market_summary_history).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct MarketSummaryHistory {
+    /// Time range the history covers
+    pub range: TimeRange,
+    /// Shared calendar: midnight UTC timestamps of each exchange-local trading day
+    pub dates: Vec<i64>,
+    /// One aligned series per instrument, in market summary order
+    pub series: Vec<MarketSummarySeries>,
+    /// Instruments whose chart failed to fetch, with error messages
+    pub errors: HashMap<String, String>,
+}
+
+impl MarketSummaryHistory {
+    /// Look up the series for a symbol
+    pub fn get(&self, symbol: &str) -> Option<&MarketSummarySeries> {
+        self.series.iter().find(|s| s.symbol == symbol)
+    }
+
+    /// Align daily charts for the market summary instruments.
+    ///
+    /// Candles are bucketed by their exchange-local calendar day (using the
+    /// chart's GMT offset) so that, for example, a Tokyo close and a New York
+    /// close on the same date share a column.
+    pub(crate) fn from_charts(
+        range: TimeRange,
+        quotes: &[MarketSummaryQuote],
+        charts: &HashMap<String, Chart>,
+        errors: HashMap<String, String>,
+    ) -> Self {
+        let daily: Vec<(&MarketSummaryQuote, Vec<(i64, f64)>)> = quotes
+            .iter()
+            .filter_map(|q| charts.get(&q.symbol).map(|c| (q, local_daily_closes(c))))
+            .collect();
+
+        let dates: Vec<i64> = daily
+            .iter()
+            .flat_map(|(_, closes)| closes.iter().map(|(day, _)| *day))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        let series = daily
+            .into_iter()
+            .map(|(quote, closes)| {
+                let aligned = align(&dates, &closes);
+                let base = aligned.iter().flatten().next().copied();
+                let last = aligned.iter().rev().flatten().next().copied();
+                let normalized = aligned
+                    .iter()
+                    .map(|c| match (c, base) {
+                        (Some(c), Some(b)) if b != 0.0 => Some(c / b * 100.0),
+                        _ => None,
+                    })
+                    .collect();
+                let change_percent = match (base, last) {
+                    (Some(b), Some(l)) if b != 0.0 => Some((l - b) / b * 100.0),
+                    _ => None,
+                };
+                MarketSummarySeries {
+                    symbol: quote.symbol.clone(),
+                    short_name: quote.short_name.clone(),
+                    quote_type: quote.quote_type.clone(),
+                    closes: aligned,
+                    normalized,
+                    change_percent,
+                }
+            })
+            .collect();
+
+        Self {
+            range,
+            dates,
+            series,
+            errors,
+        }
+    }
+}
+
+/// One close per exchange-local day (the last candle of the day wins).
+fn local_daily_closes(chart: &Chart) -> Vec<(i64, f64)> {
+    let offset = chart.meta.gmt_offset.unwrap_or(0);
+    let mut out: Vec<(i64, f64)> = Vec::with_capacity(chart.candles.len());
+    for candle in &chart.candles {
+        if !candle.close.is_finite() {
+            continue;
+        }
+        let day = (candle.timestamp + offset).div_euclid(SECONDS_PER_DAY) * SECONDS_PER_DAY;
+        match out.last_mut() {
+            Some((d, close)) if *d == day => *close = candle.close,
+            _ => out.push((day, candle.close)),
+        }
+    }
+    out
+}
+
+/// Map sorted `(day, close)` pairs onto `dates`, carrying closes forward.
+fn align(dates: &[i64], closes: &[(i64, f64)]) -> Vec<Option<f64>> {
+    let mut iter = closes.iter().peekable();
+    let mut current = None;
+    dates
+        .iter()
+        .map(|day| {
+            while let Some((d, c)) = iter.peek() {
+                if d > day {
+                    break;
+                }
+                current = Some(*c);
+                iter.next();
+            }
+            current
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::chart::{Candle, ChartMeta};
+
+    fn chart(symbol: &str, gmt_offset: i64, points: &[(i64, f64)]) -> Chart {
+        Chart {
+            symbol: symbol.to_string(),
+            meta: ChartMeta {
+                gmt_offset: Some(gmt_offset),
+                ..Default::default()
+            },
+            candles: points
+                .iter()
+                .map(|&(timestamp, close)| Candle {
+                    timestamp,
+                    close,
+                    ..Default::default()
+                })
+                .collect(),
+            interval: None,
+            range: None,
+            provider_id: None,
+            adjustment: None,
+        }
+    }
+
+    fn quote(symbol: &str) -> MarketSummaryQuote {
+        serde_json::from_value(serde_json::json!({ "symbol": symbol })).unwrap()
+    }
+
+    #[test]
+    fn test_aligns_across_calendars_with_forward_fill() {
+        let day = SECONDS_PER_DAY;
+        // US index: trades day 1 and day 3 (14:30 UTC, offset -4h)
+        let us = chart(
+            "^GSPC",
+            -4 * 3600,
+            &[(day + 52_200, 100.0), (3 * day + 52_200, 110.0)],
+        );
+        // Tokyo: candle at 00:00 JST = 15:00 UTC the previous day
+        let jp = chart(
+            "^N225",
+            9 * 3600,
+            &[(day - 9 * 3600, 200.0), (2 * day - 9 * 3600, 190.0)],
+        );
+        let charts = HashMap::from([("^GSPC".to_string(), us), ("^N225".to_string(), jp)]);
+        let history = MarketSummaryHistory::from_charts(
+            TimeRange::OneMonth,
+            &[quote("^GSPC"), quote("^N225"), quote("MISSING")],
+            &charts,
+            HashMap::new(),
+        );
+
+        assert_eq!(history.dates, [day, 2 * day, 3 * day]);
+        assert_eq!(history.series.len(), 2);
+
+        let us = history.get("^GSPC").unwrap();
+        assert_eq!(us.closes, [Some(100.0), Some(100.0), Some(110.0)]);
+        assert!((us.normalized[2].unwrap() - 110.0).abs() < 1e-9);
+        assert!((us.change_percent.unwrap() - 10.0).abs() < 1e-9);
+
+        let jp = history.get("^N225").unwrap();
+        assert_eq!(jp.closes, [Some(200.0), Some(190.0), Some(190.0)]);
+        assert!((jp.change_percent.unwrap() + 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_leading_gap_is_none() {
+        let day = SECONDS_PER_DAY;
+        let a = chart("A", 0, &[(day, 1.0), (2 * day, 2.0)]);
+        let b = chart("B", 0, &[(2 * day, 5.0)]);
+        let charts = HashMap::from([("A".to_string(), a), ("B".to_string(), b)]);
+        let history = MarketSummaryHistory::from_charts(
+            TimeRange::FiveDays,
+            &[quote("A"), quote("B")],
+            &charts,
+            HashMap::new(),
+        );
+        let b = history.get("B").unwrap();
+        assert_eq!(b.closes, [None, Some(5.0)]);
+        assert_eq!(b.normalized, [None, Some(100.0)]);
+    }
+}
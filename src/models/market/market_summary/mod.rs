@@ -2,6 +2,8 @@
 //!
 //! Contains data structures for Yahoo Finance's market summary endpoint.
 
+mod history;
 mod response;
 
+pub use history::{MarketSummaryHistory, MarketSummarySeries};
 pub use response::{MarketSummaryQuote, SparkData};
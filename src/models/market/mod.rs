@@ -4,8 +4,12 @@
 
 /// Currency pair data.
 pub mod currencies;
+/// End-of-day research bundle.
+pub mod eod;
 /// Exchange information.
 pub mod exchanges;
+/// Market summary and trending for several regions.
+pub mod global_snapshot;
 /// Market trading hours.
 pub mod hours;
 /// Industry-level market data.
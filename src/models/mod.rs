@@ -7,6 +7,8 @@
 
 // ── Format type parameter ──────────────────────────────────────────────────
 pub mod format;
+/// Display options for re-rendering formatted values.
+pub mod format_options;
 
 // ── Capability directories ──────────────────────────────────────────────────
 
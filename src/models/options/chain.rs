@@ -1,4 +1,4 @@
-use super::contract::OptionContract;
+use super::contract::{Contracts, OptionContract};
 use serde::{Deserialize, Serialize};
 
 /// Options chain data for a specific expiration
@@ -21,6 +21,129 @@ pub struct OptionChain {
     pub puts: Vec<OptionContract>,
 }
 
+impl OptionChain {
+    /// Open interest and volume totals for this expiration
+    pub fn stats(&self) -> OptionsStats {
+        OptionsStats::from_contracts(&self.calls, &self.puts)
+    }
+}
+
+/// Chains for every expiration of an underlying
+///
+/// Obtain via [`Ticker::options_all`](crate::Ticker::options_all).
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionChains {
+    /// Underlying symbol
+    pub symbol: String,
+
+    /// Underlying's regular market price when the chains were fetched
+    pub underlying_price: Option<f64>,
+
+    /// One chain per expiration, earliest first
+    pub chains: Vec<OptionChain>,
+}
+
+impl OptionChains {
+    /// Expiration dates (Unix timestamps), earliest first
+    pub fn expiration_dates(&self) -> Vec<i64> {
+        self.chains.iter().map(|c| c.expiration_date).collect()
+    }
+
+    /// Chain for a specific expiration, if present
+    pub fn chain(&self, expiration_date: i64) -> Option<&OptionChain> {
+        self.chains
+            .iter()
+            .find(|c| c.expiration_date == expiration_date)
+    }
+
+    /// All call contracts across every expiration
+    pub fn calls(&self) -> Contracts {
+        Contracts(
+            self.chains
+                .iter()
+                .flat_map(|c| c.calls.iter().cloned())
+                .collect(),
+        )
+    }
+
+    /// All put contracts across every expiration
+    pub fn puts(&self) -> Contracts {
+        Contracts(
+            self.chains
+                .iter()
+                .flat_map(|c| c.puts.iter().cloned())
+                .collect(),
+        )
+    }
+
+    /// Open interest and volume totals across every expiration
+    pub fn stats(&self) -> OptionsStats {
+        OptionsStats::from_contracts(
+            self.chains.iter().flat_map(|c| &c.calls),
+            self.chains.iter().flat_map(|c| &c.puts),
+        )
+    }
+}
+
+/// Aggregate open interest and volume for a set of option contracts
+///
+/// Contracts without open interest or volume data count as zero.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionsStats {
+    /// Number of call contracts
+    pub call_count: usize,
+
+    /// Number of put contracts
+    pub put_count: usize,
+
+    /// Total call open interest
+    pub call_open_interest: i64,
+
+    /// Total put open interest
+    pub put_open_interest: i64,
+
+    /// Total call volume
+    pub call_volume: i64,
+
+    /// Total put volume
+    pub put_volume: i64,
+
+    /// Put open interest divided by call open interest (`None` when there
+    /// is no call open interest)
+    pub put_call_ratio: Option<f64>,
+
+    /// Put volume divided by call volume (`None` when there is no call
+    /// volume)
+    pub put_call_volume_ratio: Option<f64>,
+}
+
+impl OptionsStats {
+    fn from_contracts<'a>(
+        calls: impl IntoIterator<Item = &'a OptionContract>,
+        puts: impl IntoIterator<Item = &'a OptionContract>,
+    ) -> Self {
+        let mut stats = Self::default();
+        for call in calls {
+            stats.call_count += 1;
+            stats.call_open_interest += call.open_interest.unwrap_or(0);
+            stats.call_volume += call.volume.unwrap_or(0);
+        }
+        for put in puts {
+            stats.put_count += 1;
+            stats.put_open_interest += put.open_interest.unwrap_or(0);
+            stats.put_volume += put.volume.unwrap_or(0);
+        }
+        let ratio = |puts: i64, calls: i64| (calls > 0).then(|| puts as f64 / calls as f64);
+        stats.put_call_ratio = ratio(stats.put_open_interest, stats.call_open_interest);
+        stats.put_call_volume_ratio = ratio(stats.put_volume, stats.call_volume);
+        stats
+    }
+}
+
 /// Quote data included with options response
 ///
 /// Note: This struct cannot be manually constructed - obtain via `Ticker::options()`.
@@ -41,3 +164,67 @@ pub struct OptionsQuote {
     /// Regular market time (Unix timestamp)
     pub regular_market_time: Option<i64>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract(symbol: &str, open_interest: Option<i64>, volume: Option<i64>) -> OptionContract {
+        serde_json::from_value(serde_json::json!({
+            "contractSymbol": symbol,
+            "strike": 150.0,
+            "openInterest": open_interest,
+            "volume": volume
+        }))
+        .unwrap()
+    }
+
+    fn chain(
+        expiration_date: i64,
+        calls: Vec<OptionContract>,
+        puts: Vec<OptionContract>,
+    ) -> OptionChain {
+        OptionChain {
+            expiration_date,
+            has_mini_options: Some(false),
+            calls,
+            puts,
+        }
+    }
+
+    #[test]
+    fn test_stats_aggregate_across_expirations() {
+        let chains = OptionChains {
+            symbol: "AAPL".to_string(),
+            underlying_price: Some(150.0),
+            chains: vec![
+                chain(
+                    1,
+                    vec![
+                        contract("C1", Some(100), Some(10)),
+                        contract("C2", None, Some(30)),
+                    ],
+                    vec![contract("P1", Some(50), None)],
+                ),
+                chain(
+                    2,
+                    vec![contract("C3", Some(100), Some(0))],
+                    vec![contract("P2", Some(250), Some(20))],
+                ),
+            ],
+        };
+
+        let stats = chains.stats();
+        assert_eq!((stats.call_count, stats.put_count), (3, 2));
+        assert_eq!(stats.call_open_interest, 200);
+        assert_eq!(stats.put_open_interest, 300);
+        assert_eq!(stats.put_call_ratio, Some(1.5));
+        assert_eq!(stats.put_call_volume_ratio, Some(0.5));
+        assert_eq!(chains.calls().len(), 3);
+        assert_eq!(chains.expiration_dates(), [1, 2]);
+
+        let first = chains.chain(1).unwrap().stats();
+        assert_eq!(first.put_call_ratio, Some(0.5));
+        assert_eq!(chain(3, vec![], vec![]).stats().put_call_ratio, None);
+    }
+}
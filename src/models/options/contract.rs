@@ -1,6 +1,12 @@
 use serde::{Deserialize, Serialize};
 use std::ops::Deref;
 
+use super::greeks::{self, BlackScholes, Greeks, OptionKind};
+
+/// Yahoo dates expirations at midnight UTC; contracts stop trading at the
+/// 16:00 New York close (20:00 UTC during daylight time).
+pub(super) const EXPIRY_CLOSE_OFFSET_SECS: i64 = 20 * 3600;
+
 /// A collection of option contracts with DataFrame support.
 ///
 /// This wrapper allows `options.calls.to_dataframe()` syntax while still
@@ -96,3 +102,113 @@ pub struct OptionContract {
     /// Whether the option is in the money
     pub in_the_money: Option<bool>,
 }
+
+impl OptionContract {
+    /// Call or put, read from the OCC contract symbol (e.g. the `C` in
+    /// `AAPL250117C00150000`).
+    pub fn kind(&self) -> Option<OptionKind> {
+        let symbol = self.contract_symbol.as_bytes();
+        match symbol.len().checked_sub(9).map(|i| symbol[i]) {
+            Some(b'C') => Some(OptionKind::Call),
+            Some(b'P') => Some(OptionKind::Put),
+            _ => None,
+        }
+    }
+
+    /// Bid/ask midpoint when both sides are quoted, otherwise the last price.
+    pub fn market_price(&self) -> Option<f64> {
+        match (self.bid, self.ask) {
+            (Some(bid), Some(ask)) if bid > 0.0 && ask >= bid => Some((bid + ask) / 2.0),
+            _ => self.last_price.filter(|p| *p > 0.0),
+        }
+    }
+
+    /// Black-Scholes Greeks as of now, given the underlying price `spot` and
+    /// the risk-free `rate` (0.05 = 5%).
+    ///
+    /// Volatility is solved from [`market_price`](Self::market_price),
+    /// falling back to Yahoo's `implied_volatility`. Returns `None` for
+    /// expired contracts or when neither is usable. Dividends are ignored;
+    /// use [`BlackScholes`] directly to include a dividend yield.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::Ticker;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ticker = Ticker::new("AAPL").await?;
+    /// let options = ticker.options(None).await?;
+    /// let spot = options.underlying_price().unwrap_or_default();
+    ///
+    /// for call in &*options.calls() {
+    ///     if let Some(g) = call.greeks(spot, 0.045) {
+    ///         println!("{} delta={:.2} theta={:.3}", call.strike, g.delta, g.theta);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn greeks(&self, spot: f64, rate: f64) -> Option<Greeks> {
+        self.greeks_at(spot, rate, chrono::Utc::now().timestamp())
+    }
+
+    /// Like [`greeks`](Self::greeks), valued at `as_of` (Unix seconds).
+    pub fn greeks_at(&self, spot: f64, rate: f64, as_of: i64) -> Option<Greeks> {
+        let years = greeks::years_between(as_of, self.expiration? + EXPIRY_CLOSE_OFFSET_SECS);
+        let model = BlackScholes::new(self.kind()?, spot, self.strike, years, 0.0).rate(rate);
+        let volatility = self
+            .market_price()
+            .and_then(|price| model.implied_volatility(price))
+            .or(self.implied_volatility.filter(|iv| *iv > 0.0))?;
+        model.volatility(volatility).greeks()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract(symbol: &str, strike: f64, bid: f64, ask: f64) -> OptionContract {
+        serde_json::from_value(serde_json::json!({
+            "contractSymbol": symbol,
+            "strike": strike,
+            "bid": bid,
+            "ask": ask,
+            "lastPrice": 1.0,
+            "expiration": 1_737_072_000,
+            "impliedVolatility": 0.3
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_kind_and_market_price() {
+        let call = contract("AAPL250117C00150000", 150.0, 2.0, 2.2);
+        assert_eq!(call.kind(), Some(OptionKind::Call));
+        assert!((call.market_price().unwrap() - 2.1).abs() < 1e-12);
+
+        let put = contract("AAPL250117P00150000", 150.0, 0.0, 0.0);
+        assert_eq!(put.kind(), Some(OptionKind::Put));
+        assert_eq!(put.market_price(), Some(1.0));
+        assert_eq!(contract("bad", 1.0, 0.0, 0.0).kind(), None);
+    }
+
+    #[test]
+    fn test_greeks_from_market_price() {
+        // 30 days before expiry, at the money
+        let as_of = 1_737_072_000 + EXPIRY_CLOSE_OFFSET_SECS - 30 * 86_400;
+        let call = contract("AAPL250117C00150000", 150.0, 4.9, 5.1);
+        let greeks = call.greeks_at(150.0, 0.04, as_of).unwrap();
+        assert!((greeks.price - 5.0).abs() < 1e-6);
+        assert!(greeks.delta > 0.5 && greeks.delta < 0.6);
+        assert!(greeks.theta < 0.0);
+
+        // Unsolvable quote falls back to Yahoo's IV
+        let cheap = contract("AAPL250117C00100000", 100.0, 0.1, 0.1);
+        let greeks = cheap.greeks_at(150.0, 0.04, as_of).unwrap();
+        assert_eq!(greeks.implied_volatility, 0.3);
+
+        assert!(call.greeks_at(150.0, 0.04, 1_800_000_000).is_none());
+    }
+}
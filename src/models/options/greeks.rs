@@ -0,0 +1,323 @@
+//! Black-Scholes pricing and Greeks for European options.
+//!
+//! Yahoo's option chain carries prices and an implied volatility but no
+//! Greeks. [`BlackScholes`] computes the theoretical price, delta, gamma,
+//! theta, vega, and rho from the underlying price, strike, time to expiry,
+//! rate, and volatility (with an optional continuous dividend yield), and
+//! solves implied volatility from a market price.
+//!
+//! [`OptionContract::greeks`](super::OptionContract::greeks) wires this up
+//! for chain contracts.
+
+use serde::{Deserialize, Serialize};
+
+const SECONDS_PER_YEAR: f64 = 365.0 * 86_400.0;
+
+/// Call or put.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OptionKind {
+    /// Right to buy at the strike
+    Call,
+    /// Right to sell at the strike
+    Put,
+}
+
+/// Theoretical price and sensitivities of an option.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Greeks {
+    /// Theoretical option price
+    pub price: f64,
+    /// Volatility used (annualized, 0.25 = 25%)
+    pub implied_volatility: f64,
+    /// Price change per $1 move in the underlying
+    pub delta: f64,
+    /// Delta change per $1 move in the underlying
+    pub gamma: f64,
+    /// Price change per calendar day passing
+    pub theta: f64,
+    /// Price change per 1 percentage point rise in volatility
+    pub vega: f64,
+    /// Price change per 1 percentage point rise in the rate
+    pub rho: f64,
+}
+
+/// Black-Scholes-Merton model inputs for a European option.
+///
+/// # Example
+///
+/// ```
+/// use finance_query::{BlackScholes, OptionKind};
+///
+/// // 1-year at-the-money call, 5% rate, 20% volatility
+/// let greeks = BlackScholes::new(OptionKind::Call, 100.0, 100.0, 1.0, 0.20)
+///     .rate(0.05)
+///     .greeks()
+///     .unwrap();
+/// assert!((greeks.price - 10.45).abs() < 0.01);
+/// assert!((greeks.delta - 0.637).abs() < 0.001);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlackScholes {
+    kind: OptionKind,
+    spot: f64,
+    strike: f64,
+    years: f64,
+    volatility: f64,
+    rate: f64,
+    dividend_yield: f64,
+}
+
+impl BlackScholes {
+    /// Create a model for an option expiring in `years` with the given
+    /// annualized `volatility`. The rate and dividend yield default to zero.
+    pub fn new(kind: OptionKind, spot: f64, strike: f64, years: f64, volatility: f64) -> Self {
+        Self {
+            kind,
+            spot,
+            strike,
+            years,
+            volatility,
+            rate: 0.0,
+            dividend_yield: 0.0,
+        }
+    }
+
+    /// Set the continuously compounded risk-free rate (0.05 = 5%).
+    pub fn rate(mut self, rate: f64) -> Self {
+        self.rate = rate;
+        self
+    }
+
+    /// Set the continuous dividend yield of the underlying (0.01 = 1%).
+    pub fn dividend_yield(mut self, dividend_yield: f64) -> Self {
+        self.dividend_yield = dividend_yield;
+        self
+    }
+
+    /// Set the volatility, e.g. after solving it with [`implied_volatility`](Self::implied_volatility).
+    pub fn volatility(mut self, volatility: f64) -> Self {
+        self.volatility = volatility;
+        self
+    }
+
+    /// Theoretical price, or `None` if the inputs are not positive and finite.
+    pub fn price(&self) -> Option<f64> {
+        self.greeks().map(|g| g.price)
+    }
+
+    /// Price and Greeks, or `None` if the spot, strike, time, or volatility
+    /// is not positive and finite.
+    pub fn greeks(&self) -> Option<Greeks> {
+        let valid = [self.spot, self.strike, self.years, self.volatility]
+            .iter()
+            .all(|v| v.is_finite() && *v > 0.0)
+            && self.rate.is_finite()
+            && self.dividend_yield.is_finite();
+        if !valid {
+            return None;
+        }
+
+        let Self {
+            spot: s,
+            strike: k,
+            years: t,
+            volatility: sigma,
+            rate: r,
+            dividend_yield: q,
+            ..
+        } = *self;
+        let sqrt_t = t.sqrt();
+        let d1 = ((s / k).ln() + (r - q + sigma * sigma / 2.0) * t) / (sigma * sqrt_t);
+        let d2 = d1 - sigma * sqrt_t;
+        let spot_discount = (-q * t).exp();
+        let strike_discount = (-r * t).exp();
+        let pdf_d1 = norm_pdf(d1);
+
+        let gamma = spot_discount * pdf_d1 / (s * sigma * sqrt_t);
+        let vega = s * spot_discount * pdf_d1 * sqrt_t / 100.0;
+        let decay = -s * spot_discount * pdf_d1 * sigma / (2.0 * sqrt_t);
+
+        let (price, delta, theta, rho) = match self.kind {
+            OptionKind::Call => (
+                s * spot_discount * norm_cdf(d1) - k * strike_discount * norm_cdf(d2),
+                spot_discount * norm_cdf(d1),
+                decay - r * k * strike_discount * norm_cdf(d2)
+                    + q * s * spot_discount * norm_cdf(d1),
+                k * t * strike_discount * norm_cdf(d2),
+            ),
+            OptionKind::Put => (
+                k * strike_discount * norm_cdf(-d2) - s * spot_discount * norm_cdf(-d1),
+                -spot_discount * norm_cdf(-d1),
+                decay + r * k * strike_discount * norm_cdf(-d2)
+                    - q * s * spot_discount * norm_cdf(-d1),
+                -k * t * strike_discount * norm_cdf(-d2),
+            ),
+        };
+
+        Some(Greeks {
+            price,
+            implied_volatility: sigma,
+            delta,
+            gamma,
+            theta: theta / 365.0,
+            vega,
+            rho: rho / 100.0,
+        })
+    }
+
+    /// Solve the volatility at which the model price equals `market_price`.
+    ///
+    /// Ignores the configured volatility. Returns `None` when the price is
+    /// outside the no-arbitrage bounds or the inputs are invalid.
+    pub fn implied_volatility(&self, market_price: f64) -> Option<f64> {
+        if !market_price.is_finite() || self.years <= 0.0 || self.spot <= 0.0 {
+            return None;
+        }
+        let spot = self.spot * (-self.dividend_yield * self.years).exp();
+        let strike = self.strike * (-self.rate * self.years).exp();
+        let (lower, upper) = match self.kind {
+            OptionKind::Call => ((spot - strike).max(0.0), spot),
+            OptionKind::Put => ((strike - spot).max(0.0), strike),
+        };
+        if market_price <= lower || market_price >= upper {
+            return None;
+        }
+
+        // Newton steps, falling back to bisection when a step leaves the bracket
+        let (mut lo, mut hi) = (1e-6, 10.0);
+        let mut sigma = 0.3;
+        for _ in 0..100 {
+            let greeks = self.volatility(sigma).greeks()?;
+            let diff = greeks.price - market_price;
+            if diff.abs() < 1e-8 {
+                return Some(sigma);
+            }
+            if diff > 0.0 {
+                hi = sigma;
+            } else {
+                lo = sigma;
+            }
+            let vega = greeks.vega * 100.0;
+            let next = sigma - diff / vega;
+            sigma = if vega > 1e-12 && next > lo && next < hi {
+                next
+            } else {
+                (lo + hi) / 2.0
+            };
+            if hi - lo < 1e-10 {
+                break;
+            }
+        }
+        (sigma < 10.0 - 1e-6).then_some(sigma)
+    }
+}
+
+/// Years from `as_of` to `expiration` (both Unix seconds).
+pub(crate) fn years_between(as_of: i64, expiration: i64) -> f64 {
+    (expiration - as_of) as f64 / SECONDS_PER_YEAR
+}
+
+fn norm_pdf(x: f64) -> f64 {
+    (-x * x / 2.0).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+fn norm_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / std::f64::consts::SQRT_2)
+}
+
+/// Complementary error function (Numerical Recipes `erfcc`, |ε| < 1.2e-7).
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    // Horner evaluation, highest-order coefficient first
+    let poly = [
+        0.170_872_77,
+        -0.822_152_23,
+        1.488_515_87,
+        -1.135_203_98,
+        0.278_868_07,
+        -0.186_288_06,
+        0.096_784_18,
+        0.374_091_96,
+        1.000_023_68,
+        -1.265_512_23,
+    ]
+    .iter()
+    .fold(0.0, |acc, c| acc * t + c);
+    let ans = t * (poly - z * z).exp();
+    if x >= 0.0 { ans } else { 2.0 - ans }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64, tol: f64) -> bool {
+        (a - b).abs() < tol
+    }
+
+    #[test]
+    fn test_reference_values() {
+        // Hull, S=K=100, T=1, r=5%, σ=20%
+        let call = BlackScholes::new(OptionKind::Call, 100.0, 100.0, 1.0, 0.2)
+            .rate(0.05)
+            .greeks()
+            .unwrap();
+        assert!(close(call.price, 10.4506, 1e-3));
+        assert!(close(call.delta, 0.6368, 1e-4));
+        assert!(close(call.gamma, 0.018_76, 1e-5));
+        assert!(close(call.vega, 0.3752, 1e-4));
+        assert!(close(call.theta, -6.414 / 365.0, 1e-4));
+        assert!(close(call.rho, 0.5323, 1e-4));
+
+        let put = BlackScholes::new(OptionKind::Put, 100.0, 100.0, 1.0, 0.2)
+            .rate(0.05)
+            .greeks()
+            .unwrap();
+        assert!(close(put.price, 5.5735, 1e-3));
+        assert!(close(put.delta, call.delta - 1.0, 1e-9));
+        assert!(close(put.gamma, call.gamma, 1e-12));
+        // Put-call parity
+        assert!(close(
+            call.price - put.price,
+            100.0 - 100.0 * (-0.05f64).exp(),
+            1e-6
+        ));
+    }
+
+    #[test]
+    fn test_dividend_yield_lowers_call_value() {
+        let model = BlackScholes::new(OptionKind::Call, 100.0, 100.0, 0.5, 0.3).rate(0.04);
+        let plain = model.greeks().unwrap();
+        let with_yield = model.dividend_yield(0.03).greeks().unwrap();
+        assert!(with_yield.price < plain.price);
+        assert!(with_yield.delta < plain.delta);
+    }
+
+    #[test]
+    fn test_implied_volatility_roundtrip() {
+        for kind in [OptionKind::Call, OptionKind::Put] {
+            for sigma in [0.05, 0.25, 1.5] {
+                let model = BlackScholes::new(kind, 50.0, 55.0, 0.25, sigma).rate(0.03);
+                let price = model.price().unwrap();
+                let solved = model.volatility(0.0).implied_volatility(price).unwrap();
+                assert!(close(solved, sigma, 1e-5), "{kind:?} {sigma} -> {solved}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_inputs() {
+        let expired = BlackScholes::new(OptionKind::Call, 100.0, 90.0, 0.0, 0.2);
+        assert!(expired.greeks().is_none());
+        assert!(expired.volatility(f64::NAN).greeks().is_none());
+
+        let call = BlackScholes::new(OptionKind::Call, 100.0, 90.0, 1.0, 0.2);
+        // Below intrinsic value and above the spot price
+        assert!(call.implied_volatility(5.0).is_none());
+        assert!(call.implied_volatility(101.0).is_none());
+    }
+}
@@ -1,11 +1,17 @@
 //! Options models.
 //!
-//! Contains all data structures for Yahoo Finance's options endpoint.
+//! Contains all data structures for Yahoo Finance's options endpoint, plus
+//! Black-Scholes [`greeks`], multi-leg [`strategy`] payoffs, and an implied
+//! volatility [`surface`] for its contracts.
 
 mod chain;
 mod contract;
+pub mod greeks;
 pub(crate) mod response;
+pub mod strategy;
+pub mod surface;
 
-pub use chain::{OptionChain, OptionsQuote};
+pub use chain::{OptionChain, OptionChains, OptionsQuote, OptionsStats};
 pub use contract::{Contracts, OptionContract};
+pub use greeks::{BlackScholes, Greeks, OptionKind};
 pub use response::Options;
@@ -1,3 +1,4 @@
+use super::chain::OptionChain;
 use super::contract::{Contracts, OptionContract};
 use crate::Provider;
 /// Options Response module
@@ -103,6 +104,33 @@ impl Options {
             .unwrap_or_default()
     }
 
+    /// Loaded chains, one per expiration present in the response.
+    pub(crate) fn chains(&self) -> Vec<OptionChain> {
+        self.first_result()
+            .map(|r| {
+                r.options
+                    .iter()
+                    .map(|chain| OptionChain {
+                        expiration_date: chain.expiration_date,
+                        has_mini_options: chain.has_mini_options,
+                        calls: chain.calls.clone().unwrap_or_default(),
+                        puts: chain.puts.clone().unwrap_or_default(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Underlying's regular market price, from the quote Yahoo returns with
+    /// the chain. Feed it to [`OptionContract::greeks`].
+    pub fn underlying_price(&self) -> Option<f64> {
+        self.first_result()?
+            .quote
+            .as_ref()?
+            .get("regularMarketPrice")?
+            .as_f64()
+    }
+
     /// Get strike prices
     pub fn strikes(&self) -> Vec<f64> {
         self.first_result()
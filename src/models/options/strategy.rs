@@ -0,0 +1,439 @@
+//! Multi-leg option strategies and their payoff at expiry.
+//!
+//! An [`OptionStrategy`] combines long and short [`Leg`]s, built by hand or
+//! from chain [`OptionContract`]s, and reports net premium, maximum profit
+//! and loss, breakevens, and the P&L curve at expiration. Presets cover the
+//! common structures: vertical spreads, straddles, strangles, and iron
+//! condors.
+//!
+//! # Example
+//!
+//! ```
+//! use finance_query::options::strategy::{Leg, OptionStrategy, Side};
+//! use finance_query::OptionKind;
+//!
+//! // 100/110 bull call spread for a $4 net debit
+//! let spread = OptionStrategy::new("Bull call spread")
+//!     .leg(Leg::new(OptionKind::Call, Side::Long, 100.0, 6.0))
+//!     .leg(Leg::new(OptionKind::Call, Side::Short, 110.0, 2.0));
+//!
+//! assert_eq!(spread.net_premium(), -400.0);
+//! assert_eq!(spread.max_profit(), Some(600.0));
+//! assert_eq!(spread.max_loss(), Some(-400.0));
+//! assert_eq!(spread.breakevens(), vec![104.0]);
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use super::contract::OptionContract;
+use super::greeks::OptionKind;
+use crate::error::{FinanceError, Result};
+
+/// Shares controlled by one standard US equity option contract.
+pub const CONTRACT_MULTIPLIER: f64 = 100.0;
+
+/// Whether a leg is bought or sold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Side {
+    /// Bought: premium paid
+    Long,
+    /// Sold (written): premium received
+    Short,
+}
+
+impl Side {
+    fn sign(self) -> f64 {
+        match self {
+            Side::Long => 1.0,
+            Side::Short => -1.0,
+        }
+    }
+}
+
+/// One option position within a strategy.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Leg {
+    /// Call or put
+    pub kind: OptionKind,
+    /// Bought or sold
+    pub side: Side,
+    /// Strike price
+    pub strike: f64,
+    /// Premium per share paid or received
+    pub premium: f64,
+    /// Number of contracts
+    pub quantity: u32,
+    /// Contract symbol, when built from a chain contract
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract_symbol: Option<String>,
+}
+
+impl Leg {
+    /// Create a one-contract leg.
+    pub fn new(kind: OptionKind, side: Side, strike: f64, premium: f64) -> Self {
+        Self {
+            kind,
+            side,
+            strike,
+            premium,
+            quantity: 1,
+            contract_symbol: None,
+        }
+    }
+
+    /// Build a leg from a chain contract, priced at its
+    /// [`market_price`](OptionContract::market_price).
+    ///
+    /// Fails if the contract symbol doesn't say call or put, or the
+    /// contract has no usable price.
+    pub fn from_contract(contract: &OptionContract, side: Side) -> Result<Self> {
+        let invalid = |reason: &str| FinanceError::InvalidParameter {
+            param: "contract".to_string(),
+            reason: format!("{}: {reason}", contract.contract_symbol),
+        };
+        let kind = contract
+            .kind()
+            .ok_or_else(|| invalid("not an OCC option symbol"))?;
+        let premium = contract
+            .market_price()
+            .ok_or_else(|| invalid("no bid/ask or last price"))?;
+        Ok(Self {
+            contract_symbol: Some(contract.contract_symbol.clone()),
+            ..Self::new(kind, side, contract.strike, premium)
+        })
+    }
+
+    /// Set the number of contracts.
+    pub fn quantity(mut self, quantity: u32) -> Self {
+        self.quantity = quantity;
+        self
+    }
+
+    /// P&L per share at expiry with the underlying at `price`, premium included.
+    fn pnl_per_share(&self, price: f64) -> f64 {
+        let intrinsic = match self.kind {
+            OptionKind::Call => (price - self.strike).max(0.0),
+            OptionKind::Put => (self.strike - price).max(0.0),
+        };
+        self.side.sign() * (intrinsic - self.premium) * self.quantity as f64
+    }
+}
+
+/// A point on a payoff curve.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PayoffPoint {
+    /// Underlying price at expiry
+    pub price: f64,
+    /// Strategy P&L at that price
+    pub pnl: f64,
+}
+
+/// A multi-leg option position evaluated at expiration.
+///
+/// Amounts are in currency for the whole position: per-share values times
+/// the contract [`multiplier`](Self::multiplier) (100 by default) and each
+/// leg's quantity. All legs are assumed to share one expiration.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionStrategy {
+    /// Strategy name
+    pub name: String,
+    /// Legs
+    pub legs: Vec<Leg>,
+    /// Shares per contract
+    pub multiplier: f64,
+}
+
+impl OptionStrategy {
+    /// Create an empty strategy.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            legs: Vec::new(),
+            multiplier: CONTRACT_MULTIPLIER,
+        }
+    }
+
+    /// Add a leg.
+    pub fn leg(mut self, leg: Leg) -> Self {
+        self.legs.push(leg);
+        self
+    }
+
+    /// Set the shares per contract (default: 100).
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Vertical spread: buy `long`, sell `short`, same kind and expiration.
+    ///
+    /// A bull call spread buys the lower strike call; a bear put spread buys
+    /// the higher strike put.
+    pub fn vertical_spread(long: &OptionContract, short: &OptionContract) -> Result<Self> {
+        let long = Leg::from_contract(long, Side::Long)?;
+        let short = Leg::from_contract(short, Side::Short)?;
+        if long.kind != short.kind {
+            return Err(FinanceError::InvalidParameter {
+                param: "contracts".to_string(),
+                reason: "a vertical spread needs two calls or two puts".to_string(),
+            });
+        }
+        let name = match (long.kind, long.strike < short.strike) {
+            (OptionKind::Call, true) => "Bull call spread",
+            (OptionKind::Call, false) => "Bear call spread",
+            (OptionKind::Put, true) => "Bull put spread",
+            (OptionKind::Put, false) => "Bear put spread",
+        };
+        Ok(Self::new(name).leg(long).leg(short))
+    }
+
+    /// Long straddle: buy a call and a put at the same strike.
+    pub fn straddle(call: &OptionContract, put: &OptionContract) -> Result<Self> {
+        Self::pair("Long straddle", call, put)
+    }
+
+    /// Long strangle: buy an out-of-the-money call and put.
+    pub fn strangle(call: &OptionContract, put: &OptionContract) -> Result<Self> {
+        Self::pair("Long strangle", call, put)
+    }
+
+    /// Short iron condor: sell a put spread and a call spread.
+    ///
+    /// Contracts are given in strike order: the long (wing) put, short put,
+    /// short call, and long (wing) call.
+    pub fn iron_condor(
+        long_put: &OptionContract,
+        short_put: &OptionContract,
+        short_call: &OptionContract,
+        long_call: &OptionContract,
+    ) -> Result<Self> {
+        let legs = [
+            Leg::from_contract(long_put, Side::Long)?,
+            Leg::from_contract(short_put, Side::Short)?,
+            Leg::from_contract(short_call, Side::Short)?,
+            Leg::from_contract(long_call, Side::Long)?,
+        ];
+        let kinds_ok = legs[0].kind == OptionKind::Put
+            && legs[1].kind == OptionKind::Put
+            && legs[2].kind == OptionKind::Call
+            && legs[3].kind == OptionKind::Call;
+        let strikes_ok = legs.windows(2).all(|w| w[0].strike <= w[1].strike);
+        if !kinds_ok || !strikes_ok {
+            return Err(FinanceError::InvalidParameter {
+                param: "contracts".to_string(),
+                reason: "an iron condor needs put, put, call, call in ascending strike order"
+                    .to_string(),
+            });
+        }
+        Ok(legs
+            .into_iter()
+            .fold(Self::new("Iron condor"), |strategy, leg| strategy.leg(leg)))
+    }
+
+    fn pair(name: &str, call: &OptionContract, put: &OptionContract) -> Result<Self> {
+        let call = Leg::from_contract(call, Side::Long)?;
+        let put = Leg::from_contract(put, Side::Long)?;
+        if call.kind != OptionKind::Call || put.kind != OptionKind::Put {
+            return Err(FinanceError::InvalidParameter {
+                param: "contracts".to_string(),
+                reason: format!("a {} needs a call and a put", name.to_lowercase()),
+            });
+        }
+        Ok(Self::new(name).leg(call).leg(put))
+    }
+
+    /// Premium received (positive, a credit) or paid (negative, a debit) to
+    /// open the position.
+    pub fn net_premium(&self) -> f64 {
+        -self
+            .legs
+            .iter()
+            .map(|leg| leg.side.sign() * leg.premium * leg.quantity as f64)
+            .sum::<f64>()
+            * self.multiplier
+    }
+
+    /// P&L at expiry with the underlying at `price`.
+    pub fn pnl_at(&self, price: f64) -> f64 {
+        self.legs
+            .iter()
+            .map(|leg| leg.pnl_per_share(price))
+            .sum::<f64>()
+            * self.multiplier
+    }
+
+    /// P&L at `steps + 1` evenly spaced prices from `low` to `high`.
+    pub fn payoff_curve(&self, low: f64, high: f64, steps: usize) -> Vec<PayoffPoint> {
+        let steps = steps.max(1);
+        (0..=steps)
+            .map(|i| {
+                let price = low + (high - low) * i as f64 / steps as f64;
+                PayoffPoint {
+                    price,
+                    pnl: self.pnl_at(price),
+                }
+            })
+            .collect()
+    }
+
+    /// Largest P&L at expiry, or `None` if it is unlimited (net long calls).
+    pub fn max_profit(&self) -> Option<f64> {
+        if self.upside_slope() > 0.0 {
+            return None;
+        }
+        self.kink_points()
+            .into_iter()
+            .map(|price| self.pnl_at(price))
+            .reduce(f64::max)
+    }
+
+    /// Largest loss at expiry as a negative P&L, or `None` if it is
+    /// unlimited (net short calls).
+    pub fn max_loss(&self) -> Option<f64> {
+        if self.upside_slope() < 0.0 {
+            return None;
+        }
+        self.kink_points()
+            .into_iter()
+            .map(|price| self.pnl_at(price))
+            .reduce(f64::min)
+    }
+
+    /// Underlying prices at which the P&L at expiry is zero, ascending.
+    pub fn breakevens(&self) -> Vec<f64> {
+        let points = self.kink_points();
+        let mut roots: Vec<f64> = Vec::new();
+        let mut push = |root: f64| {
+            if roots.last().is_none_or(|last| (root - last).abs() > 1e-9) {
+                roots.push(root);
+            }
+        };
+
+        for pair in points.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            let (pa, pb) = (self.pnl_at(a), self.pnl_at(b));
+            if pa == 0.0 {
+                push(a);
+            }
+            if pa * pb < 0.0 {
+                push(a + (b - a) * pa / (pa - pb));
+            }
+        }
+
+        // Beyond the highest strike the P&L is linear
+        if let Some(&last) = points.last() {
+            let pnl = self.pnl_at(last);
+            let slope = self.upside_slope();
+            if pnl == 0.0 {
+                push(last);
+            } else if slope != 0.0 && pnl * slope < 0.0 {
+                push(last - pnl / slope);
+            }
+        }
+        roots
+    }
+
+    /// Zero and every strike, ascending: the payoff is linear in between.
+    fn kink_points(&self) -> Vec<f64> {
+        let mut points: Vec<f64> = std::iter::once(0.0)
+            .chain(self.legs.iter().map(|leg| leg.strike))
+            .collect();
+        points.sort_by(f64::total_cmp);
+        points.dedup();
+        points
+    }
+
+    /// P&L change per $1 rise in the underlying above the highest strike.
+    fn upside_slope(&self) -> f64 {
+        self.legs
+            .iter()
+            .filter(|leg| leg.kind == OptionKind::Call)
+            .map(|leg| leg.side.sign() * leg.quantity as f64)
+            .sum::<f64>()
+            * self.multiplier
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract(symbol: &str, strike: f64, bid: f64, ask: f64) -> OptionContract {
+        serde_json::from_value(serde_json::json!({
+            "contractSymbol": symbol,
+            "strike": strike,
+            "bid": bid,
+            "ask": ask
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_iron_condor() {
+        let condor = OptionStrategy::iron_condor(
+            &contract("SPY250117P00090000", 90.0, 0.9, 1.1),
+            &contract("SPY250117P00095000", 95.0, 1.9, 2.1),
+            &contract("SPY250117C00105000", 105.0, 1.9, 2.1),
+            &contract("SPY250117C00110000", 110.0, 0.9, 1.1),
+        )
+        .unwrap();
+
+        assert_eq!(condor.net_premium(), 200.0);
+        assert_eq!(condor.max_profit(), Some(200.0));
+        assert_eq!(condor.max_loss(), Some(-300.0));
+        assert_eq!(condor.breakevens(), vec![93.0, 107.0]);
+        assert_eq!(condor.pnl_at(100.0), 200.0);
+        assert_eq!(condor.pnl_at(0.0), -300.0);
+
+        let curve = condor.payoff_curve(80.0, 120.0, 4);
+        assert_eq!(curve.len(), 5);
+        assert_eq!(curve[2].price, 100.0);
+        assert_eq!(curve[2].pnl, 200.0);
+    }
+
+    #[test]
+    fn test_unlimited_payoffs() {
+        let straddle = OptionStrategy::straddle(
+            &contract("AAPL250117C00100000", 100.0, 4.0, 4.0),
+            &contract("AAPL250117P00100000", 100.0, 3.0, 3.0),
+        )
+        .unwrap();
+        assert_eq!(straddle.net_premium(), -700.0);
+        assert_eq!(straddle.max_profit(), None);
+        assert_eq!(straddle.max_loss(), Some(-700.0));
+        assert_eq!(straddle.breakevens(), vec![93.0, 107.0]);
+
+        let naked_call = OptionStrategy::new("Short call")
+            .leg(Leg::new(OptionKind::Call, Side::Short, 50.0, 2.0).quantity(2));
+        assert_eq!(naked_call.max_profit(), Some(400.0));
+        assert_eq!(naked_call.max_loss(), None);
+        assert_eq!(naked_call.breakevens(), vec![52.0]);
+    }
+
+    #[test]
+    fn test_preset_validation() {
+        let call = contract("AAPL250117C00100000", 100.0, 1.0, 1.2);
+        let put = contract("AAPL250117P00100000", 100.0, 1.0, 1.2);
+        assert!(OptionStrategy::vertical_spread(&call, &put).is_err());
+        assert!(OptionStrategy::straddle(&put, &call).is_err());
+        assert!(Leg::from_contract(&contract("AAPL", 100.0, 0.0, 0.0), Side::Long).is_err());
+
+        let bear = OptionStrategy::vertical_spread(
+            &contract("AAPL250117P00110000", 110.0, 6.0, 6.0),
+            &put,
+        )
+        .unwrap();
+        assert_eq!(bear.name, "Bear put spread");
+        assert_eq!(
+            bear.legs[0].contract_symbol.as_deref(),
+            Some("AAPL250117P00110000")
+        );
+        assert!((bear.net_premium() + 490.0).abs() < 1e-9);
+    }
+}
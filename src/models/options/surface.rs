@@ -0,0 +1,388 @@
+//! Implied volatility surface across strikes and expirations.
+//!
+//! An [`IvSurface`] arranges the implied volatilities Yahoo reports for each
+//! contract into one [`IvSlice`] per expiration, keeping the out-of-the-money
+//! side at each strike (puts below spot, calls at or above it). It
+//! interpolates volatility at any expiration and strike with
+//! [`iv_at`](IvSurface::iv_at), and summarizes the surface as an at-the-money
+//! [term structure](IvSurface::term_structure) and a per-expiration
+//! [skew](IvSurface::skew).
+//!
+//! # Example
+//!
+//! ```no_run
+//! use finance_query::Ticker;
+//! use finance_query::options::IvSurface;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let ticker = Ticker::new("AAPL").await?;
+//! let surface = IvSurface::from_chains(&ticker.options_all().await?)?;
+//!
+//! for point in surface.term_structure() {
+//!     println!("{}: ATM IV {:.1}%", point.expiration, point.atm_iv * 100.0);
+//! }
+//!
+//! // Volatility 60 days out at 95% of spot
+//! let expiry = chrono::Utc::now().timestamp() + 60 * 86_400;
+//! let iv = surface.iv_at(expiry, surface.spot * 0.95);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::chain::OptionChains;
+use super::contract::{EXPIRY_CLOSE_OFFSET_SECS, OptionContract};
+use super::greeks::{self, OptionKind};
+use crate::error::{FinanceError, Result};
+
+/// Implied volatility of one contract on the surface.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IvPoint {
+    /// Strike price
+    pub strike: f64,
+    /// Strike divided by the underlying price
+    pub moneyness: f64,
+    /// Implied volatility (annualized, 0.25 = 25%)
+    pub iv: f64,
+    /// Whether the volatility came from the call or the put
+    pub kind: OptionKind,
+}
+
+/// Implied volatilities for a single expiration, sorted by strike.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IvSlice {
+    /// Expiration date (Unix timestamp)
+    pub expiration: i64,
+    /// Years from the surface's `as_of` to the expiration close
+    pub years: f64,
+    /// One point per strike, lowest first
+    pub points: Vec<IvPoint>,
+}
+
+impl IvSlice {
+    /// Volatility at `strike`, interpolated linearly between neighbouring
+    /// strikes and held flat beyond the lowest and highest strike.
+    pub fn iv_at(&self, strike: f64) -> Option<f64> {
+        let first = self.points.first()?;
+        let last = self.points.last()?;
+        if strike <= first.strike {
+            return Some(first.iv);
+        }
+        if strike >= last.strike {
+            return Some(last.iv);
+        }
+        let upper = self.points.partition_point(|p| p.strike < strike);
+        let (lo, hi) = (self.points[upper - 1], self.points[upper]);
+        let weight = (strike - lo.strike) / (hi.strike - lo.strike);
+        Some(lo.iv + weight * (hi.iv - lo.iv))
+    }
+
+    /// Like [`iv_at`](Self::iv_at), but `None` outside the quoted strikes.
+    fn iv_within(&self, strike: f64) -> Option<f64> {
+        let first = self.points.first()?;
+        let last = self.points.last()?;
+        (first.strike..=last.strike)
+            .contains(&strike)
+            .then(|| self.iv_at(strike))
+            .flatten()
+    }
+}
+
+/// At-the-money volatility for one expiration.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TermPoint {
+    /// Expiration date (Unix timestamp)
+    pub expiration: i64,
+    /// Years to expiration
+    pub years: f64,
+    /// Volatility at the underlying price
+    pub atm_iv: f64,
+}
+
+/// Put/call skew for one expiration.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkewPoint {
+    /// Expiration date (Unix timestamp)
+    pub expiration: i64,
+    /// Years to expiration
+    pub years: f64,
+    /// Volatility at the underlying price
+    pub atm_iv: f64,
+    /// Volatility at the downside strike, if quoted
+    pub put_iv: Option<f64>,
+    /// Volatility at the upside strike, if quoted
+    pub call_iv: Option<f64>,
+    /// `put_iv - call_iv`; positive when downside protection is richer
+    pub skew: Option<f64>,
+}
+
+/// Implied volatilities organized by expiration and strike.
+///
+/// Build with [`from_chains`](Self::from_chains) from
+/// [`Ticker::options_all`](crate::Ticker::options_all), or with
+/// [`new`](Self::new) from any set of contracts.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IvSurface {
+    /// Underlying price
+    pub spot: f64,
+    /// Valuation time (Unix seconds) that `years` are measured from
+    pub as_of: i64,
+    /// One slice per unexpired expiration, earliest first
+    pub slices: Vec<IvSlice>,
+}
+
+impl IvSurface {
+    /// Build a surface from contracts, valued at `as_of` (Unix seconds).
+    ///
+    /// Uses each contract's reported `implied_volatility`. Where both a call
+    /// and a put are quoted at a strike, the out-of-the-money one is kept.
+    /// Contracts without a positive volatility, an expiration, or a
+    /// call/put symbol are skipped, as are expired contracts.
+    pub fn new<'a>(
+        spot: f64,
+        contracts: impl IntoIterator<Item = &'a OptionContract>,
+        as_of: i64,
+    ) -> Self {
+        let mut by_expiration: BTreeMap<i64, Vec<IvPoint>> = BTreeMap::new();
+        for contract in contracts {
+            let (Some(expiration), Some(kind), Some(iv)) = (
+                contract.expiration,
+                contract.kind(),
+                contract
+                    .implied_volatility
+                    .filter(|iv| iv.is_finite() && *iv > 0.0),
+            ) else {
+                continue;
+            };
+            let points = by_expiration.entry(expiration).or_default();
+            let point = IvPoint {
+                strike: contract.strike,
+                moneyness: contract.strike / spot,
+                iv,
+                kind,
+            };
+            match points.iter_mut().find(|p| p.strike == point.strike) {
+                Some(existing) if is_otm(kind, point.strike, spot) => *existing = point,
+                Some(_) => {}
+                None => points.push(point),
+            }
+        }
+
+        let slices = by_expiration
+            .into_iter()
+            .filter_map(|(expiration, mut points)| {
+                let years = years_to(as_of, expiration);
+                if years <= 0.0 {
+                    return None;
+                }
+                points.sort_by(|a, b| a.strike.total_cmp(&b.strike));
+                Some(IvSlice {
+                    expiration,
+                    years,
+                    points,
+                })
+            })
+            .collect();
+
+        Self {
+            spot,
+            as_of,
+            slices,
+        }
+    }
+
+    /// Build a surface from every expiration's chain, valued now.
+    ///
+    /// Fails if the chains carry no underlying price.
+    pub fn from_chains(chains: &OptionChains) -> Result<Self> {
+        let spot = chains
+            .underlying_price
+            .filter(|p| *p > 0.0)
+            .ok_or_else(|| FinanceError::InvalidParameter {
+                param: "chains".to_string(),
+                reason: format!("no underlying price for {}", chains.symbol),
+            })?;
+        let contracts = chains
+            .chains
+            .iter()
+            .flat_map(|c| c.calls.iter().chain(&c.puts));
+        Ok(Self::new(spot, contracts, chrono::Utc::now().timestamp()))
+    }
+
+    /// Slice for an exact expiration, if present.
+    pub fn slice(&self, expiration: i64) -> Option<&IvSlice> {
+        self.slices.iter().find(|s| s.expiration == expiration)
+    }
+
+    /// Volatility at `expiration` (Unix timestamp) and `strike`.
+    ///
+    /// Interpolates linearly in strike within each slice, then linearly in
+    /// total variance (`iv² × years`) between the bracketing expirations.
+    /// Strikes and expirations beyond the quoted range are held flat.
+    /// Returns `None` for an empty surface or an expiration before `as_of`.
+    pub fn iv_at(&self, expiration: i64, strike: f64) -> Option<f64> {
+        let years = years_to(self.as_of, expiration);
+        if years <= 0.0 {
+            return None;
+        }
+        let first = self.slices.first()?;
+        let last = self.slices.last()?;
+        if years <= first.years {
+            return first.iv_at(strike);
+        }
+        if years >= last.years {
+            return last.iv_at(strike);
+        }
+        let upper = self.slices.partition_point(|s| s.years < years);
+        let (near, far) = (&self.slices[upper - 1], &self.slices[upper]);
+        let near_var = near.iv_at(strike)?.powi(2) * near.years;
+        let far_var = far.iv_at(strike)?.powi(2) * far.years;
+        let weight = (years - near.years) / (far.years - near.years);
+        let variance = near_var + weight * (far_var - near_var);
+        Some((variance.max(0.0) / years).sqrt())
+    }
+
+    /// Volatility at `expiration` and `moneyness` (strike / spot).
+    pub fn iv_at_moneyness(&self, expiration: i64, moneyness: f64) -> Option<f64> {
+        self.iv_at(expiration, moneyness * self.spot)
+    }
+
+    /// At-the-money volatility for each expiration, earliest first.
+    pub fn term_structure(&self) -> Vec<TermPoint> {
+        self.slices
+            .iter()
+            .filter_map(|slice| {
+                Some(TermPoint {
+                    expiration: slice.expiration,
+                    years: slice.years,
+                    atm_iv: slice.iv_at(self.spot)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Skew for each expiration, comparing volatility `width` below and
+    /// above spot in moneyness (`0.1` compares 90% and 110% strikes).
+    ///
+    /// The wing volatilities are `None` when the slice has no quotes that far
+    /// out, rather than extrapolated.
+    pub fn skew(&self, width: f64) -> Vec<SkewPoint> {
+        let down = self.spot - self.spot * width;
+        let up = self.spot + self.spot * width;
+        self.slices
+            .iter()
+            .filter_map(|slice| {
+                let put_iv = slice.iv_within(down);
+                let call_iv = slice.iv_within(up);
+                Some(SkewPoint {
+                    expiration: slice.expiration,
+                    years: slice.years,
+                    atm_iv: slice.iv_at(self.spot)?,
+                    put_iv,
+                    call_iv,
+                    skew: put_iv.zip(call_iv).map(|(p, c)| p - c),
+                })
+            })
+            .collect()
+    }
+}
+
+fn is_otm(kind: OptionKind, strike: f64, spot: f64) -> bool {
+    match kind {
+        OptionKind::Call => strike >= spot,
+        OptionKind::Put => strike < spot,
+    }
+}
+
+fn years_to(as_of: i64, expiration: i64) -> f64 {
+    greeks::years_between(as_of, expiration + EXPIRY_CLOSE_OFFSET_SECS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPIRY: i64 = 1_737_072_000;
+    const AS_OF: i64 = EXPIRY + EXPIRY_CLOSE_OFFSET_SECS - 365 * 86_400;
+
+    fn contract(kind: char, expiration: i64, strike: f64, iv: f64) -> OptionContract {
+        serde_json::from_value(serde_json::json!({
+            "contractSymbol": format!("AAPL250117{kind}{:08}", (strike * 1000.0) as i64),
+            "strike": strike,
+            "expiration": expiration,
+            "impliedVolatility": iv
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_keeps_out_of_the_money_side() {
+        let contracts = [
+            contract('C', EXPIRY, 90.0, 0.50),
+            contract('P', EXPIRY, 90.0, 0.30),
+            contract('C', EXPIRY, 110.0, 0.20),
+            contract('P', EXPIRY, 110.0, 0.60),
+            contract('C', EXPIRY - 400 * 86_400, 100.0, 0.25),
+        ];
+        let surface = IvSurface::new(100.0, &contracts, AS_OF);
+
+        // The expired slice is dropped
+        assert_eq!(surface.slices.len(), 1);
+        let slice = &surface.slices[0];
+        assert!((slice.years - 1.0).abs() < 1e-12);
+        let picked: Vec<_> = slice.points.iter().map(|p| (p.kind, p.iv)).collect();
+        assert_eq!(picked, [(OptionKind::Put, 0.30), (OptionKind::Call, 0.20)]);
+        assert!((slice.iv_at(100.0).unwrap() - 0.25).abs() < 1e-12);
+        assert_eq!(slice.iv_at(50.0), Some(0.30));
+    }
+
+    #[test]
+    fn test_interpolates_in_total_variance() {
+        let far = EXPIRY + 2 * 365 * 86_400;
+        let contracts = [
+            contract('C', EXPIRY, 100.0, 0.20),
+            contract('C', far, 100.0, 0.30),
+        ];
+        let surface = IvSurface::new(100.0, &contracts, AS_OF);
+
+        // Halfway between 1 and 3 years: total variance = (0.04 * 1 + 0.09 * 3) / 2
+        let mid = EXPIRY + 365 * 86_400;
+        let expected = ((0.04 + 0.27) / 2.0 / 2.0_f64).sqrt();
+        assert!((surface.iv_at(mid, 100.0).unwrap() - expected).abs() < 1e-9);
+
+        // Flat beyond the quoted expirations; None before as_of
+        assert_eq!(surface.iv_at(far + 86_400 * 30, 100.0), Some(0.30));
+        assert_eq!(surface.iv_at(AS_OF - 86_400 * 2, 100.0), None);
+    }
+
+    #[test]
+    fn test_term_structure_and_skew() {
+        let contracts = [
+            contract('P', EXPIRY, 90.0, 0.35),
+            contract('C', EXPIRY, 100.0, 0.25),
+            contract('C', EXPIRY, 110.0, 0.22),
+        ];
+        let surface = IvSurface::new(100.0, &contracts, AS_OF);
+
+        let term = surface.term_structure();
+        assert_eq!(term.len(), 1);
+        assert!((term[0].atm_iv - 0.25).abs() < 1e-12);
+
+        let skew = surface.skew(0.1);
+        assert!((skew[0].skew.unwrap() - 0.13).abs() < 1e-12);
+        assert_eq!(surface.skew(0.2)[0].put_iv, None);
+    }
+}
@@ -0,0 +1,132 @@
+//! Fixed-income view of a quote.
+//!
+//! [`Quote`] is shaped around equities, so a bond's coupon, maturity, and
+//! yield sit among dozens of empty stock fields. [`Quote::bond`] pulls the
+//! fixed-income fields into a [`BondQuote`] for quotes whose `quoteType` is
+//! `BOND` or `RATE`.
+
+use serde::{Deserialize, Serialize};
+
+use super::Quote;
+
+/// Bond and interest-rate fields of a [`Quote`].
+///
+/// All rates and yields are in percent (`4.25` means 4.25%).
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BondQuote {
+    /// Symbol
+    pub symbol: String,
+    /// Display name, long name preferred
+    pub name: Option<String>,
+    /// `"BOND"` or `"RATE"`
+    pub quote_type: String,
+    /// Currency code
+    pub currency: Option<String>,
+    /// Last price; for `RATE` quotes this is the rate itself
+    pub price: Option<f64>,
+    /// Change in price since the previous close
+    pub change: Option<f64>,
+    /// Annual coupon rate
+    pub coupon_rate: Option<f64>,
+    /// Maturity date as a Unix timestamp
+    pub maturity_date: Option<i64>,
+    /// Yield to maturity, falling back to Yahoo's `yield` field
+    pub yield_to_maturity: Option<f64>,
+}
+
+impl BondQuote {
+    /// Years from `now` (Unix seconds) to maturity; negative once matured.
+    pub fn years_to_maturity(&self, now: i64) -> Option<f64> {
+        const SECONDS_PER_YEAR: f64 = 365.25 * 86_400.0;
+        self.maturity_date
+            .map(|m| (m - now) as f64 / SECONDS_PER_YEAR)
+    }
+}
+
+impl Quote {
+    /// Fixed-income view of this quote, or `None` unless the quote type is
+    /// `BOND` or `RATE`.
+    ///
+    /// Treasury futures (`ZN=F`) and yield indices (`^TNX`) are typed
+    /// `FUTURE` and `INDEX` by Yahoo and keep their usual quote fields.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::Ticker;
+    ///
+    /// # async fn example(symbol: &str) -> Result<(), Box<dyn std::error::Error>> {
+    /// let quote = Ticker::new(symbol).await?.quote().await?;
+    /// if let Some(bond) = quote.bond() {
+    ///     println!("coupon {:?}% matures {:?}", bond.coupon_rate, bond.maturity_date);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bond(&self) -> Option<BondQuote> {
+        let quote_type = self.quote_type.as_deref()?;
+        if !quote_type.eq_ignore_ascii_case("BOND") && !quote_type.eq_ignore_ascii_case("RATE") {
+            return None;
+        }
+        Some(BondQuote {
+            symbol: self.symbol.clone(),
+            name: self.long_name.clone().or_else(|| self.short_name.clone()),
+            quote_type: quote_type.to_uppercase(),
+            currency: self.currency.clone(),
+            price: self.regular_market_price.as_ref().and_then(|v| v.raw),
+            change: self.regular_market_change.as_ref().and_then(|v| v.raw),
+            coupon_rate: self.coupon_rate.as_ref().and_then(|v| v.raw),
+            maturity_date: self.maturity_date.as_ref().and_then(|v| v.raw),
+            yield_to_maturity: self
+                .yield_to_maturity
+                .as_ref()
+                .and_then(|v| v.raw)
+                .or_else(|| self.yield_value.as_ref().and_then(|v| v.raw)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn quote(value: serde_json::Value) -> Quote {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_bond_view() {
+        let q = quote(json!({
+            "symbol": "US10Y",
+            "shortName": "US 10Y",
+            "quoteType": "BOND",
+            "currency": "USD",
+            "regularMarketPrice": {"raw": 98.5, "fmt": "98.50"},
+            "couponRate": {"raw": 4.25, "fmt": "4.25%"},
+            "maturityDate": {"raw": 1_920_000_000, "fmt": "2030-11-04"},
+            "yield": {"raw": 4.44, "fmt": "4.44%"},
+        }));
+        let bond = q.bond().unwrap();
+        assert_eq!(bond.name.as_deref(), Some("US 10Y"));
+        assert_eq!(bond.price, Some(98.5));
+        assert_eq!(bond.coupon_rate, Some(4.25));
+        assert_eq!(bond.maturity_date, Some(1_920_000_000));
+        assert_eq!(bond.yield_to_maturity, Some(4.44));
+
+        let years = bond.years_to_maturity(1_920_000_000 - 31_557_600).unwrap();
+        assert!((years - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bond_view_only_for_fixed_income() {
+        let rate = quote(json!({"symbol": "RATE1", "quoteType": "rate"}));
+        assert_eq!(rate.bond().unwrap().quote_type, "RATE");
+
+        let equity = quote(json!({"symbol": "AAPL", "quoteType": "EQUITY"}));
+        assert!(equity.bond().is_none());
+        assert!(quote(json!({"symbol": "X"})).bond().is_none());
+    }
+}
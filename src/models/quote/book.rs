@@ -0,0 +1,132 @@
+//! Top-of-book snapshot: the best bid and ask with their sizes.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Best bid and ask for a symbol at one moment.
+///
+/// Yahoo publishes only the top level of the book, so this is a Level 1
+/// snapshot rather than full depth. Prices of `0` (Yahoo's placeholder when
+/// no order is resting, e.g. outside trading hours) are reported as `None`.
+///
+/// Obtain via [`Ticker::book`](crate::Ticker::book).
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopOfBook {
+    /// Ticker symbol.
+    pub symbol: String,
+    /// Best bid price.
+    pub bid: Option<f64>,
+    /// Size resting at the best bid. Yahoo reports equities in round lots
+    /// (hundreds of shares) on some venues.
+    pub bid_size: Option<i64>,
+    /// Best ask price.
+    pub ask: Option<f64>,
+    /// Size resting at the best ask.
+    pub ask_size: Option<i64>,
+    /// Last regular-session trade price.
+    pub last_price: Option<f64>,
+    /// Quote currency.
+    pub currency: Option<String>,
+    /// Market state at the time of the quote (e.g., `"REGULAR"`, `"CLOSED"`).
+    pub market_state: Option<String>,
+    /// Exchange time of the last regular-session trade, as Unix seconds.
+    pub market_time: Option<i64>,
+    /// When the snapshot was taken, as Unix seconds.
+    pub fetched_at: i64,
+}
+
+impl TopOfBook {
+    /// Ask minus bid, when both sides are quoted.
+    pub fn spread(&self) -> Option<f64> {
+        Some(self.ask? - self.bid?)
+    }
+
+    /// Midpoint between bid and ask.
+    pub fn mid(&self) -> Option<f64> {
+        Some((self.ask? + self.bid?) / 2.0)
+    }
+
+    /// Spread as basis points of the midpoint.
+    pub fn spread_bps(&self) -> Option<f64> {
+        let mid = self.mid().filter(|m| *m > 0.0)?;
+        Some(self.spread()? / mid * 10_000.0)
+    }
+
+    /// Size imbalance in `[-1, 1]`: positive when more size rests on the bid,
+    /// negative when more rests on the ask.
+    pub fn imbalance(&self) -> Option<f64> {
+        let bid = self.bid_size? as f64;
+        let ask = self.ask_size? as f64;
+        let total = bid + ask;
+        (total > 0.0).then(|| (bid - ask) / total)
+    }
+
+    /// Build from one `quoteResponse.result[]` item of the v7 quote endpoint.
+    pub(crate) fn from_quote_result(item: &Value, fetched_at: i64) -> Result<Self, String> {
+        let symbol = item["symbol"]
+            .as_str()
+            .ok_or("Missing symbol in quote result")?
+            .to_string();
+        let price = |key: &str| item[key].as_f64().filter(|p| *p > 0.0);
+        Ok(Self {
+            symbol,
+            bid: price("bid"),
+            bid_size: size(&item["bidSize"]),
+            ask: price("ask"),
+            ask_size: size(&item["askSize"]),
+            last_price: item["regularMarketPrice"].as_f64(),
+            currency: item["currency"].as_str().map(String::from),
+            market_state: item["marketState"].as_str().map(String::from),
+            market_time: item["regularMarketTime"].as_i64(),
+            fetched_at,
+        })
+    }
+}
+
+/// Read a bid/ask size, which Yahoo sends as an integer for equities but as a
+/// float for some asset classes (crypto, FX).
+pub(crate) fn size(value: &Value) -> Option<i64> {
+    value
+        .as_i64()
+        .or_else(|| value.as_f64().map(|f| f.round() as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_quote_result() {
+        let item = serde_json::json!({
+            "symbol": "AAPL",
+            "bid": 189.98,
+            "bidSize": 3,
+            "ask": 190.02,
+            "askSize": 1.0,
+            "regularMarketPrice": 190.0,
+            "regularMarketTime": 1_700_000_000,
+            "marketState": "REGULAR",
+            "currency": "USD"
+        });
+        let book = TopOfBook::from_quote_result(&item, 1_700_000_005).unwrap();
+        assert_eq!(book.bid_size, Some(3));
+        assert_eq!(book.ask_size, Some(1));
+        assert_eq!(book.market_time, Some(1_700_000_000));
+        assert!((book.spread().unwrap() - 0.04).abs() < 1e-9);
+        assert!((book.mid().unwrap() - 190.0).abs() < 1e-9);
+        assert!((book.spread_bps().unwrap() - 2.105).abs() < 1e-3);
+        assert!((book.imbalance().unwrap() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_zero_prices_are_unquoted() {
+        let item = serde_json::json!({"symbol": "AAPL", "bid": 0, "ask": 0, "bidSize": 0});
+        let book = TopOfBook::from_quote_result(&item, 0).unwrap();
+        assert_eq!(book.bid, None);
+        assert_eq!(book.spread(), None);
+        assert_eq!(book.imbalance(), None);
+        assert!(TopOfBook::from_quote_result(&serde_json::json!({}), 0).is_err());
+    }
+}
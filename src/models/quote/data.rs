@@ -660,6 +660,19 @@ pub struct Quote<F: Format = Both> {
     #[serde(rename = "yield", skip_serializing_if = "Option::is_none")]
     pub yield_value: Option<F::Value<f64>>,
 
+    // ===== BOND-SPECIFIC =====
+    /// Annual coupon rate in percent (for bonds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon_rate: Option<F::Value<f64>>,
+
+    /// Maturity date (for bonds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maturity_date: Option<F::Value<i64>>,
+
+    /// Yield to maturity in percent (for bonds)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub yield_to_maturity: Option<F::Value<f64>>,
+
     // ===== STOCK SPLITS & DATES =====
     /// Last stock split factor
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -822,6 +835,34 @@ pub struct Quote<F: Format = Both> {
     pub top_holdings: Option<TopHoldings>,
 }
 
+impl<F: Format> Quote<F> {
+    /// Currency of the trading fields — prices, market cap, volumes × price,
+    /// dividends per share, analyst targets, and 52-week range.
+    pub fn price_currency(&self) -> Option<&str> {
+        self.currency.as_deref()
+    }
+
+    /// Currency of the reported financial fields — revenue, EBITDA, cash,
+    /// debt, and cash flows (the `financialData` module).
+    ///
+    /// Differs from [`price_currency`](Self::price_currency) for foreign
+    /// issuers (e.g. an ADR quoted in USD reporting in TWD). Falls back to the
+    /// price currency when Yahoo omits it.
+    pub fn reporting_currency(&self) -> Option<&str> {
+        self.financial_currency
+            .as_deref()
+            .or(self.currency.as_deref())
+    }
+
+    /// ISO 4217 metadata for [`price_currency`](Self::price_currency).
+    pub fn currency_info(
+        &self,
+    ) -> Option<&'static crate::models::market::currencies::CurrencyInfo> {
+        self.price_currency()
+            .and_then(crate::models::market::currencies::currency_info)
+    }
+}
+
 impl Quote<Both> {
     /// Creates a Quote from a QuoteSummaryResponse
     ///
@@ -1108,6 +1149,11 @@ impl Quote<Both> {
             total_assets: summary_detail.and_then(|s| s.total_assets.clone()),
             yield_value: summary_detail.and_then(|s| s.yield_value.clone()),
 
+            // ===== BOND-SPECIFIC =====
+            coupon_rate: summary_detail.and_then(|s| s.coupon_rate.clone()),
+            maturity_date: summary_detail.and_then(|s| s.maturity_date.clone()),
+            yield_to_maturity: summary_detail.and_then(|s| s.yield_to_maturity.clone()),
+
             // ===== STOCK SPLITS & DATES =====
             last_split_factor: key_stats.and_then(|k| k.last_split_factor.clone()),
             last_split_date: key_stats.and_then(|k| k.last_split_date.clone()),
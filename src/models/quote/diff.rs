@@ -0,0 +1,219 @@
+//! Field-level change detection between two quotes.
+//!
+//! [`Quote::diff`] compares two snapshots of the same symbol and reports every
+//! field whose value changed, so alerting layers can react to e.g. a new
+//! analyst rating or a short-interest update without comparing JSON blobs.
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::Quote;
+use crate::models::format::Format;
+
+/// One changed field between two quotes.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuoteChange {
+    /// Serialized (camelCase) field name, e.g. `"shortRatio"` or
+    /// `"upgradeDowngradeHistory"`
+    pub field: String,
+    /// Value in the older quote (`None` if the field was absent)
+    pub old: Option<Value>,
+    /// Value in the newer quote (`None` if the field was removed)
+    pub new: Option<Value>,
+}
+
+impl QuoteChange {
+    /// Whether the field was absent in the older quote.
+    pub fn is_added(&self) -> bool {
+        self.old.is_none()
+    }
+
+    /// Whether the field is absent in the newer quote.
+    pub fn is_removed(&self) -> bool {
+        self.new.is_none()
+    }
+
+    /// Old value as a number (the `raw` part for formatted values).
+    pub fn old_f64(&self) -> Option<f64> {
+        self.old.as_ref().and_then(number)
+    }
+
+    /// New value as a number (the `raw` part for formatted values).
+    pub fn new_f64(&self) -> Option<f64> {
+        self.new.as_ref().and_then(number)
+    }
+
+    /// `new - old` for numeric fields present in both quotes.
+    pub fn delta(&self) -> Option<f64> {
+        Some(self.new_f64()? - self.old_f64()?)
+    }
+}
+
+fn number(value: &Value) -> Option<f64> {
+    value
+        .as_f64()
+        .or_else(|| value.get("raw").and_then(Value::as_f64))
+}
+
+/// Fields that changed between two quotes, in field-name order.
+///
+/// Obtain via [`Quote::diff`] or [`Ticker::watch_quote`](crate::Ticker::watch_quote).
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuoteDiff {
+    /// Symbol of the newer quote
+    pub symbol: String,
+    /// Changed fields
+    pub changes: Vec<QuoteChange>,
+}
+
+impl QuoteDiff {
+    /// Whether nothing changed.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Change for a serialized field name (e.g. `"recommendationTrend"`).
+    pub fn get(&self, field: &str) -> Option<&QuoteChange> {
+        self.changes.iter().find(|c| c.field == field)
+    }
+
+    /// Whether the given field changed.
+    pub fn contains(&self, field: &str) -> bool {
+        self.get(field).is_some()
+    }
+
+    /// Keep only the listed fields, e.g. to ignore price ticks when watching
+    /// for analyst or dividend changes.
+    pub fn only(mut self, fields: &[&str]) -> Self {
+        self.changes.retain(|c| fields.contains(&c.field.as_str()));
+        self
+    }
+
+    /// Drop the listed fields.
+    pub fn without(mut self, fields: &[&str]) -> Self {
+        self.changes.retain(|c| !fields.contains(&c.field.as_str()));
+        self
+    }
+}
+
+impl<F: Format> Quote<F> {
+    /// Fields that differ between `older` and `self`.
+    ///
+    /// Scalar fields are compared by value; nested modules (recommendation
+    /// trend, upgrade/downgrade history, calendar events, ...) are compared as
+    /// a whole and reported once with their full old and new values.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::{Ticker, format::Raw};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ticker = Ticker::new("AAPL").await?;
+    /// let before = ticker.quote::<Raw>().await?;
+    /// // ... later, from a fresh ticker or after the cache expires
+    /// let after = Ticker::new("AAPL").await?.quote::<Raw>().await?;
+    ///
+    /// for change in after.diff(&before).changes {
+    ///     println!("{}: {:?} -> {:?}", change.field, change.old, change.new);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn diff(&self, older: &Quote<F>) -> QuoteDiff {
+        let new = to_map(self);
+        let old = to_map(older);
+        let fields: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+
+        let changes = fields
+            .into_iter()
+            .filter_map(|field| {
+                let (o, n) = (old.get(field), new.get(field));
+                (o != n).then(|| QuoteChange {
+                    field: field.clone(),
+                    old: o.cloned(),
+                    new: n.cloned(),
+                })
+            })
+            .collect();
+
+        QuoteDiff {
+            symbol: self.symbol.clone(),
+            changes,
+        }
+    }
+}
+
+fn to_map<F: Format>(quote: &Quote<F>) -> serde_json::Map<String, Value> {
+    match serde_json::to_value(quote) {
+        Ok(Value::Object(map)) => map.into_iter().filter(|(_, v)| !v.is_null()).collect(),
+        _ => serde_json::Map::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::format::Raw;
+    use serde_json::json;
+
+    fn quote(value: Value) -> Quote<Raw> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_diff_reports_changed_added_and_removed() {
+        let older = quote(json!({
+            "symbol": "AAPL",
+            "regularMarketPrice": 190.0,
+            "shortRatio": 1.5,
+            "dividendRate": 0.96,
+        }));
+        let newer = quote(json!({
+            "symbol": "AAPL",
+            "regularMarketPrice": 190.0,
+            "shortRatio": 1.75,
+            "exDividendDate": 1_731_024_000,
+        }));
+
+        let diff = newer.diff(&older);
+        let fields: Vec<&str> = diff.changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(fields, ["dividendRate", "exDividendDate", "shortRatio"]);
+
+        let short = diff.get("shortRatio").unwrap();
+        assert_eq!(short.delta(), Some(0.25));
+        assert!(diff.get("dividendRate").unwrap().is_removed());
+        assert!(diff.get("exDividendDate").unwrap().is_added());
+        assert!(!diff.contains("regularMarketPrice"));
+    }
+
+    #[test]
+    fn test_diff_identical_is_empty_and_filters() {
+        let a = quote(json!({"symbol": "MSFT", "regularMarketPrice": 400.0}));
+        assert!(a.diff(&a).is_empty());
+
+        let b = quote(json!({"symbol": "MSFT", "regularMarketPrice": 401.0, "shortRatio": 2.0}));
+        let diff = b.diff(&a);
+        assert_eq!(diff.clone().only(&["shortRatio"]).changes.len(), 1);
+        assert!(
+            diff.without(&["regularMarketPrice", "shortRatio"])
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_number_reads_formatted_raw() {
+        let change = QuoteChange {
+            field: "marketCap".into(),
+            old: Some(json!({"raw": 100.0, "fmt": "100"})),
+            new: Some(json!({"raw": 150.0, "fmt": "150"})),
+        };
+        assert_eq!(change.delta(), Some(50.0));
+    }
+}
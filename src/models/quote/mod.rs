@@ -8,12 +8,18 @@ pub(crate) mod quote_type;
 pub(crate) mod response;
 
 // Public modules
+pub mod bond;
+pub mod book;
 pub mod data;
+pub mod diff;
 /// Formatted value wrapper for Yahoo Finance numeric fields.
 pub mod formatted_value;
 
 // Re-export only the final flattened Quote struct and FormattedValue (used in Quote's public fields)
+pub use bond::BondQuote;
+pub use book::TopOfBook;
 pub use data::Quote;
+pub use diff::{QuoteChange, QuoteDiff};
 pub use formatted_value::FormattedValue;
 
 // ── Re-exports from new canonical locations (backward compat within crate) ───
@@ -28,7 +34,7 @@ pub(crate) use crate::models::fundamentals::{
 // From corporate/
 pub(crate) use crate::models::corporate::{
     AssetProfile, CalendarEvents, CompanyOfficer, Earnings, EarningsHistory, EarningsTrend,
-    EquityPerformance, FundOwnership, FundPerformance, FundProfile, InsiderHolders,
+    EquityPerformance, EsgScores, FundOwnership, FundPerformance, FundProfile, InsiderHolders,
     InsiderTransactions, InstitutionOwnership, MajorHoldersBreakdown, NetSharePurchaseActivity,
     RecommendationTrend, SecFilings, SummaryProfile, TopHoldings, UpgradeDowngradeHistory,
 };
@@ -46,7 +52,7 @@ pub(crate) use response::QuoteSummaryResponse;
 /// These correspond to the different data categories available for a stock symbol.
 /// See: https://yahooquery.dpguthrie.com/guide/ticker/modules/
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub(crate) enum Module {
+pub enum QuoteModule {
     /// Company information, location, operations, and officers
     AssetProfile,
     /// Earnings and revenue expectations for upcoming earnings date
@@ -131,98 +137,98 @@ pub(crate) enum Module {
     TopHoldings,
 }
 
-impl Module {
+impl QuoteModule {
     /// Converts the module enum to the API parameter string
     pub fn as_str(&self) -> &'static str {
         match self {
-            Module::AssetProfile => "assetProfile",
-            Module::CalendarEvents => "calendarEvents",
-            Module::CompanyOfficers => "companyOfficers",
-            Module::EarningHistory => "earningsHistory",
-            Module::Earnings => "earnings",
-            Module::EarningsTrend => "earningsTrend",
-            Module::EquityPerformance => "equityPerformance",
-            Module::FinancialData => "financialData",
-            Module::FundBondHoldings => "fundBondHoldings",
-            Module::FundBondRatings => "fundBondRatings",
-            Module::FundEquityHoldings => "fundEquityHoldings",
-            Module::FundHoldingInfo => "fundHoldingInfo",
-            Module::FundOwnership => "fundOwnership",
-            Module::FundPerformance => "fundPerformance",
-            Module::FundProfile => "fundProfile",
-            Module::FundSectorWeightings => "fundSectorWeightings",
-            Module::FundTopHoldings => "fundTopHoldings",
-            Module::GradingHistory => "upgradeDowngradeHistory",
-            Module::IndexTrend => "indexTrend",
-            Module::IndustryTrend => "industryTrend",
-            Module::InsiderHolders => "insiderHolders",
-            Module::InsiderTransactions => "insiderTransactions",
-            Module::InstitutionOwnership => "institutionOwnership",
-            Module::KeyStats => "defaultKeyStatistics",
-            Module::MajorHolders => "majorHoldersBreakdown",
-            Module::QuoteType => "quoteType",
-            Module::RecommendationTrend => "recommendationTrend",
-            Module::SecFilings => "secFilings",
-            Module::SharePurchaseActivity => "netSharePurchaseActivity",
-            Module::SummaryDetail => "summaryDetail",
-            Module::SummaryProfile => "summaryProfile",
-            Module::BalanceSheetHistory => "balanceSheetHistory",
-            Module::BalanceSheetHistoryQuarterly => "balanceSheetHistoryQuarterly",
-            Module::CashflowStatementHistory => "cashflowStatementHistory",
-            Module::CashflowStatementHistoryQuarterly => "cashflowStatementHistoryQuarterly",
-            Module::IncomeStatementHistory => "incomeStatementHistory",
-            Module::IncomeStatementHistoryQuarterly => "incomeStatementHistoryQuarterly",
-            Module::EsgScores => "esgScores",
-            Module::Price => "price",
-            Module::SectorTrend => "sectorTrend",
-            Module::TopHoldings => "topHoldings",
+            QuoteModule::AssetProfile => "assetProfile",
+            QuoteModule::CalendarEvents => "calendarEvents",
+            QuoteModule::CompanyOfficers => "companyOfficers",
+            QuoteModule::EarningHistory => "earningsHistory",
+            QuoteModule::Earnings => "earnings",
+            QuoteModule::EarningsTrend => "earningsTrend",
+            QuoteModule::EquityPerformance => "equityPerformance",
+            QuoteModule::FinancialData => "financialData",
+            QuoteModule::FundBondHoldings => "fundBondHoldings",
+            QuoteModule::FundBondRatings => "fundBondRatings",
+            QuoteModule::FundEquityHoldings => "fundEquityHoldings",
+            QuoteModule::FundHoldingInfo => "fundHoldingInfo",
+            QuoteModule::FundOwnership => "fundOwnership",
+            QuoteModule::FundPerformance => "fundPerformance",
+            QuoteModule::FundProfile => "fundProfile",
+            QuoteModule::FundSectorWeightings => "fundSectorWeightings",
+            QuoteModule::FundTopHoldings => "fundTopHoldings",
+            QuoteModule::GradingHistory => "upgradeDowngradeHistory",
+            QuoteModule::IndexTrend => "indexTrend",
+            QuoteModule::IndustryTrend => "industryTrend",
+            QuoteModule::InsiderHolders => "insiderHolders",
+            QuoteModule::InsiderTransactions => "insiderTransactions",
+            QuoteModule::InstitutionOwnership => "institutionOwnership",
+            QuoteModule::KeyStats => "defaultKeyStatistics",
+            QuoteModule::MajorHolders => "majorHoldersBreakdown",
+            QuoteModule::QuoteType => "quoteType",
+            QuoteModule::RecommendationTrend => "recommendationTrend",
+            QuoteModule::SecFilings => "secFilings",
+            QuoteModule::SharePurchaseActivity => "netSharePurchaseActivity",
+            QuoteModule::SummaryDetail => "summaryDetail",
+            QuoteModule::SummaryProfile => "summaryProfile",
+            QuoteModule::BalanceSheetHistory => "balanceSheetHistory",
+            QuoteModule::BalanceSheetHistoryQuarterly => "balanceSheetHistoryQuarterly",
+            QuoteModule::CashflowStatementHistory => "cashflowStatementHistory",
+            QuoteModule::CashflowStatementHistoryQuarterly => "cashflowStatementHistoryQuarterly",
+            QuoteModule::IncomeStatementHistory => "incomeStatementHistory",
+            QuoteModule::IncomeStatementHistoryQuarterly => "incomeStatementHistoryQuarterly",
+            QuoteModule::EsgScores => "esgScores",
+            QuoteModule::Price => "price",
+            QuoteModule::SectorTrend => "sectorTrend",
+            QuoteModule::TopHoldings => "topHoldings",
         }
     }
 
     /// Returns all available modules
-    pub fn all() -> Vec<Module> {
+    pub fn all() -> Vec<QuoteModule> {
         vec![
-            Module::AssetProfile,
-            Module::CalendarEvents,
-            Module::CompanyOfficers,
-            Module::EarningHistory,
-            Module::Earnings,
-            Module::EarningsTrend,
-            Module::EquityPerformance,
-            Module::FinancialData,
-            Module::FundBondHoldings,
-            Module::FundBondRatings,
-            Module::FundEquityHoldings,
-            Module::FundHoldingInfo,
-            Module::FundOwnership,
-            Module::FundPerformance,
-            Module::FundProfile,
-            Module::FundSectorWeightings,
-            Module::FundTopHoldings,
-            Module::GradingHistory,
-            Module::IndexTrend,
-            Module::IndustryTrend,
-            Module::InsiderHolders,
-            Module::InsiderTransactions,
-            Module::InstitutionOwnership,
-            Module::KeyStats,
-            Module::MajorHolders,
-            Module::QuoteType,
-            Module::RecommendationTrend,
-            Module::SecFilings,
-            Module::SharePurchaseActivity,
-            Module::SummaryDetail,
-            Module::SummaryProfile,
-            Module::BalanceSheetHistory,
-            Module::BalanceSheetHistoryQuarterly,
-            Module::CashflowStatementHistory,
-            Module::CashflowStatementHistoryQuarterly,
-            Module::IncomeStatementHistory,
-            Module::IncomeStatementHistoryQuarterly,
-            Module::EsgScores,
-            Module::Price,
-            Module::SectorTrend,
-            Module::TopHoldings,
+            QuoteModule::AssetProfile,
+            QuoteModule::CalendarEvents,
+            QuoteModule::CompanyOfficers,
+            QuoteModule::EarningHistory,
+            QuoteModule::Earnings,
+            QuoteModule::EarningsTrend,
+            QuoteModule::EquityPerformance,
+            QuoteModule::FinancialData,
+            QuoteModule::FundBondHoldings,
+            QuoteModule::FundBondRatings,
+            QuoteModule::FundEquityHoldings,
+            QuoteModule::FundHoldingInfo,
+            QuoteModule::FundOwnership,
+            QuoteModule::FundPerformance,
+            QuoteModule::FundProfile,
+            QuoteModule::FundSectorWeightings,
+            QuoteModule::FundTopHoldings,
+            QuoteModule::GradingHistory,
+            QuoteModule::IndexTrend,
+            QuoteModule::IndustryTrend,
+            QuoteModule::InsiderHolders,
+            QuoteModule::InsiderTransactions,
+            QuoteModule::InstitutionOwnership,
+            QuoteModule::KeyStats,
+            QuoteModule::MajorHolders,
+            QuoteModule::QuoteType,
+            QuoteModule::RecommendationTrend,
+            QuoteModule::SecFilings,
+            QuoteModule::SharePurchaseActivity,
+            QuoteModule::SummaryDetail,
+            QuoteModule::SummaryProfile,
+            QuoteModule::BalanceSheetHistory,
+            QuoteModule::BalanceSheetHistoryQuarterly,
+            QuoteModule::CashflowStatementHistory,
+            QuoteModule::CashflowStatementHistoryQuarterly,
+            QuoteModule::IncomeStatementHistory,
+            QuoteModule::IncomeStatementHistoryQuarterly,
+            QuoteModule::EsgScores,
+            QuoteModule::Price,
+            QuoteModule::SectorTrend,
+            QuoteModule::TopHoldings,
         ]
     }
 }
@@ -233,13 +239,13 @@ mod tests {
 
     #[test]
     fn test_module_as_str() {
-        assert_eq!(Module::SummaryDetail.as_str(), "summaryDetail");
-        assert_eq!(Module::KeyStats.as_str(), "defaultKeyStatistics");
+        assert_eq!(QuoteModule::SummaryDetail.as_str(), "summaryDetail");
+        assert_eq!(QuoteModule::KeyStats.as_str(), "defaultKeyStatistics");
     }
 
     #[test]
     fn test_module_all() {
-        let all_modules = Module::all();
+        let all_modules = QuoteModule::all();
         assert!(all_modules.len() > 30);
     }
 }
@@ -44,6 +44,7 @@ pub struct QuoteSummaryResponse {
     pub industry_trend: Option<IndustryTrend>,
     pub sector_trend: Option<SectorTrend>,
     pub equity_performance: Option<EquityPerformance>,
+    pub esg_scores: Option<EsgScores>,
 }
 
 impl QuoteSummaryResponse {
@@ -143,8 +144,56 @@ impl QuoteSummaryResponse {
             industry_trend: deserialize_module!("industryTrend"),
             sector_trend: deserialize_module!("sectorTrend"),
             equity_performance: deserialize_module!("equityPerformance"),
+            esg_scores: deserialize_module!("esgScores"),
         })
     }
+
+    /// Merges modules from a later partial fetch into this response.
+    ///
+    /// Modules present in `other` replace the ones here; modules `other`
+    /// didn't return are kept, so fetching only the missing modules of a
+    /// cached response never loses data.
+    pub(crate) fn merge(&mut self, other: Self) {
+        macro_rules! merge_modules {
+            ($($field:ident),* $(,)?) => {
+                $(
+                    if other.$field.is_some() {
+                        self.$field = other.$field;
+                    }
+                )*
+            };
+        }
+        merge_modules!(
+            price,
+            summary_detail,
+            financial_data,
+            default_key_statistics,
+            asset_profile,
+            calendar_events,
+            earnings,
+            earnings_trend,
+            earnings_history,
+            recommendation_trend,
+            insider_holders,
+            insider_transactions,
+            institution_ownership,
+            fund_ownership,
+            major_holders_breakdown,
+            net_share_purchase_activity,
+            quote_type,
+            summary_profile,
+            sec_filings,
+            upgrade_downgrade_history,
+            fund_performance,
+            fund_profile,
+            top_holdings,
+            index_trend,
+            industry_trend,
+            sector_trend,
+            equity_performance,
+            esg_scores,
+        );
+    }
 }
 
 #[cfg(test)]
@@ -193,4 +242,32 @@ mod tests {
         let response = QuoteSummaryResponse::from_json(json, "INVALID");
         assert!(response.is_err());
     }
+
+    #[test]
+    fn test_merge_keeps_existing_modules() {
+        let mut cached = QuoteSummaryResponse::from_json(
+            json!({
+                "quoteSummary": {
+                    "result": [{ "price": { "regularMarketPrice": { "raw": 150.0 } } }],
+                    "error": null
+                }
+            }),
+            "AAPL",
+        )
+        .unwrap();
+        let extra = QuoteSummaryResponse::from_json(
+            json!({
+                "quoteSummary": {
+                    "result": [{ "summaryDetail": { "previousClose": { "raw": 149.0 } } }],
+                    "error": null
+                }
+            }),
+            "AAPL",
+        )
+        .unwrap();
+
+        cached.merge(extra);
+        assert!(cached.price.is_some());
+        assert!(cached.summary_detail.is_some());
+    }
 }
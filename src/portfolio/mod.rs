@@ -0,0 +1,178 @@
+//! Portfolios of holdings and portfolio-level analytics.
+//!
+//! # Quick Start
+//!
+//! ```no_run
+//! use finance_query::portfolio::{Portfolio, StressFactor, StressScenario};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let portfolio = Portfolio::new()
+//!     .with_holding("AAPL", 100.0)
+//!     .with_holding("XOM", 200.0);
+//!
+//! let selloff = StressScenario::new("Equity selloff").shock(StressFactor::Equities, -0.10);
+//! for result in portfolio.stress_test(&[selloff]).await? {
+//!     println!("{}: {:+.0}", result.scenario, result.pnl);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+mod stress;
+mod transactions;
+mod valuation;
+
+pub use stress::{HoldingImpact, Shock, StressFactor, StressResult, StressScenario};
+pub use transactions::{CostBasisMethod, Lot, Transaction, TransactionKind};
+pub use valuation::{HoldingValuation, PortfolioValuation};
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+
+/// A position in a single symbol.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Holding {
+    /// Symbol, e.g. `"AAPL"`
+    pub symbol: String,
+    /// Number of shares (or units); negative for a short position
+    pub shares: f64,
+    /// Gains realized by sales recorded with [`Portfolio::record`]
+    #[serde(default)]
+    pub realized_pnl: f64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    lots: Vec<Lot>,
+}
+
+impl Holding {
+    /// Create a holding of `shares` in `symbol`.
+    pub fn new(symbol: impl Into<String>, shares: f64) -> Self {
+        Self {
+            symbol: symbol.into(),
+            shares,
+            realized_pnl: 0.0,
+            lots: Vec::new(),
+        }
+    }
+}
+
+/// A collection of holdings and the transactions that built them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Portfolio {
+    holdings: Vec<Holding>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    transactions: Vec<Transaction>,
+    #[serde(default)]
+    cost_basis_method: CostBasisMethod,
+}
+
+impl Portfolio {
+    /// Create an empty portfolio.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `shares` of `symbol`, merging with an existing holding of the same symbol.
+    pub fn with_holding(mut self, symbol: impl Into<String>, shares: f64) -> Self {
+        self.add_holding(symbol, shares);
+        self
+    }
+
+    /// Add `shares` of `symbol`, merging with an existing holding of the same symbol.
+    ///
+    /// The shares have no known cost; use [`record`](Self::record) to track
+    /// cost basis and P&L.
+    pub fn add_holding(&mut self, symbol: impl Into<String>, shares: f64) {
+        let symbol = symbol.into();
+        match self.holdings.iter_mut().find(|h| h.symbol == symbol) {
+            Some(holding) => holding.shares += shares,
+            None => self.holdings.push(Holding::new(symbol, shares)),
+        }
+    }
+
+    /// Holdings in the order they were added.
+    pub fn holdings(&self) -> &[Holding] {
+        &self.holdings
+    }
+
+    /// Symbols of all holdings.
+    pub fn symbols(&self) -> Vec<&str> {
+        self.holdings.iter().map(|h| h.symbol.as_str()).collect()
+    }
+
+    /// Whether the portfolio has no holdings.
+    pub fn is_empty(&self) -> bool {
+        self.holdings.is_empty()
+    }
+
+    /// Read a portfolio written by [`save`](Self::save).
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+    }
+
+    /// Read the portfolio at `path`, or an empty one if the file does not
+    /// exist.
+    pub fn load_or_default(path: impl AsRef<Path>) -> Result<Self> {
+        match std::fs::read(path.as_ref()) {
+            Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Write the portfolio, including its lots and transactions, to `path`
+    /// as JSON, creating parent directories as needed.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Write to a temporary file and rename so a crash never leaves a partial file
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, serde_json::to_vec_pretty(self)?)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_portfolio_merges_holdings() {
+        let portfolio = Portfolio::new()
+            .with_holding("AAPL", 10.0)
+            .with_holding("MSFT", 5.0)
+            .with_holding("AAPL", 2.5);
+        assert_eq!(portfolio.symbols(), ["AAPL", "MSFT"]);
+        assert_eq!(portfolio.holdings()[0].shares, 12.5);
+        assert!(Portfolio::new().is_empty());
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let mut portfolio = Portfolio::with_cost_basis_method(CostBasisMethod::AverageCost)
+            .with_holding("XOM", 3.0);
+        portfolio
+            .record(Transaction::buy("AAPL", 10.0, 100.0).at(1))
+            .unwrap();
+        portfolio
+            .record(Transaction::sell("AAPL", 4.0, 110.0).at(2))
+            .unwrap();
+
+        let dir = std::env::temp_dir().join(format!("fq-portfolio-{}", std::process::id()));
+        let path = dir.join("portfolio.json");
+        portfolio.save(&path).unwrap();
+        assert_eq!(Portfolio::load(&path).unwrap(), portfolio);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(Portfolio::load_or_default(&path).unwrap().is_empty());
+        assert!(Portfolio::load(&path).is_err());
+    }
+}
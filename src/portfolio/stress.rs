@@ -0,0 +1,491 @@
+//! Scenario stress testing.
+//!
+//! Each holding's sensitivity to a scenario's factors (the S&P 500, the
+//! 10-year Treasury yield, crude oil, ...) is estimated by regressing its
+//! daily returns on the factors' daily moves over the past year. A shock's
+//! estimated P&L is then `value * sum(beta * shock)`: a first-order, linear
+//! view that ignores convexity and assumes historical relationships hold.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::Portfolio;
+use crate::error::{FinanceError, Result};
+use crate::models::chart::Chart;
+use crate::{Interval, Tickers, TimeRange};
+
+/// Fewest overlapping daily returns accepted for a regression.
+const MIN_OBSERVATIONS: usize = 20;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// A market factor a scenario can shock.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum StressFactor {
+    /// US equities, proxied by the S&P 500 (`^GSPC`)
+    Equities,
+    /// Interest rates, proxied by the 10-year Treasury yield (`^TNX`)
+    Rates,
+    /// Crude oil, proxied by WTI futures (`CL=F`)
+    Oil,
+    /// Gold, proxied by gold futures (`GC=F`)
+    Gold,
+    /// US dollar, proxied by the dollar index (`DX-Y.NYB`)
+    Dollar,
+    /// Any other symbol, shocked as a percentage price move
+    Symbol(String),
+}
+
+impl StressFactor {
+    /// Symbol whose history represents this factor.
+    pub fn symbol(&self) -> &str {
+        match self {
+            StressFactor::Equities => "^GSPC",
+            StressFactor::Rates => "^TNX",
+            StressFactor::Oil => "CL=F",
+            StressFactor::Gold => "GC=F",
+            StressFactor::Dollar => "DX-Y.NYB",
+            StressFactor::Symbol(symbol) => symbol,
+        }
+    }
+
+    /// Whether shocks are absolute yield changes rather than percentage moves.
+    pub fn is_yield(&self) -> bool {
+        matches!(self, StressFactor::Rates)
+    }
+
+    /// Daily move from `prev` to `close`, in the units of a shock.
+    fn daily_move(&self, prev: f64, close: f64) -> f64 {
+        if self.is_yield() {
+            // ^TNX is quoted in percent; 4.25 -> 4.35 is +0.001 (10bps)
+            (close - prev) / 100.0
+        } else {
+            close / prev - 1.0
+        }
+    }
+}
+
+/// A single factor shock.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Shock {
+    /// Shocked factor
+    pub factor: StressFactor,
+    /// Size of the move as a fraction: `-0.10` is a 10% fall, and for
+    /// [`StressFactor::Rates`] `0.01` is a 100bps rise in yield
+    pub size: f64,
+}
+
+/// A named set of simultaneous factor shocks.
+///
+/// # Example
+///
+/// ```
+/// use finance_query::portfolio::{StressFactor, StressScenario};
+///
+/// let stagflation = StressScenario::new("Stagflation")
+///     .shock(StressFactor::Equities, -0.10)
+///     .shock(StressFactor::Rates, 0.01) // +100bps
+///     .shock(StressFactor::Oil, 0.20);
+/// assert_eq!(stagflation.shocks.len(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct StressScenario {
+    /// Scenario name
+    pub name: String,
+    /// Shocks applied together
+    pub shocks: Vec<Shock>,
+}
+
+impl StressScenario {
+    /// Create a scenario with no shocks.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            shocks: Vec::new(),
+        }
+    }
+
+    /// Add a shock of `size` to `factor`, replacing any earlier shock to it.
+    pub fn shock(mut self, factor: StressFactor, size: f64) -> Self {
+        self.shocks.retain(|s| s.factor != factor);
+        self.shocks.push(Shock { factor, size });
+        self
+    }
+}
+
+/// Estimated impact of a scenario on one holding.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct HoldingImpact {
+    /// Symbol
+    pub symbol: String,
+    /// Shares held
+    pub shares: f64,
+    /// Market value at the latest close
+    pub value: f64,
+    /// Sensitivity to each shock, in the scenario's shock order; empty when
+    /// there was too little overlapping history to estimate
+    pub betas: Vec<f64>,
+    /// Estimated P&L; `None` when betas could not be estimated
+    pub pnl: Option<f64>,
+}
+
+impl HoldingImpact {
+    /// Estimated P&L as a fraction of the holding's value.
+    pub fn pnl_percent(&self) -> Option<f64> {
+        let pnl = self.pnl?;
+        (self.value != 0.0).then(|| pnl / self.value)
+    }
+}
+
+/// Estimated impact of a scenario on a whole portfolio.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct StressResult {
+    /// Scenario name
+    pub scenario: String,
+    /// Per-holding impact, in portfolio order
+    pub holdings: Vec<HoldingImpact>,
+    /// Total market value at the latest close
+    pub value: f64,
+    /// Total estimated P&L over holdings with an estimate
+    pub pnl: f64,
+}
+
+impl StressResult {
+    /// Total estimated P&L as a fraction of portfolio value.
+    pub fn pnl_percent(&self) -> Option<f64> {
+        (self.value != 0.0).then(|| self.pnl / self.value)
+    }
+}
+
+impl Portfolio {
+    /// Estimate the P&L of each holding and the portfolio under each scenario.
+    ///
+    /// Fetches one year of daily history for every holding and factor in a
+    /// single batch, then regresses each holding's returns on the scenario's
+    /// factor moves jointly, so correlated factors (equities and rates, say)
+    /// are not double-counted. Holdings are valued at their latest close.
+    ///
+    /// Holdings without enough overlapping history (20 days) get `pnl: None`
+    /// and are left out of the total. Fails if a factor's history can't be
+    /// fetched.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::portfolio::{Portfolio, StressFactor, StressScenario};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let portfolio = Portfolio::new()
+    ///     .with_holding("AAPL", 100.0)
+    ///     .with_holding("XOM", 200.0)
+    ///     .with_holding("TLT", 150.0);
+    ///
+    /// let scenarios = [
+    ///     StressScenario::new("Equities -10%").shock(StressFactor::Equities, -0.10),
+    ///     StressScenario::new("Rates +100bps").shock(StressFactor::Rates, 0.01),
+    ///     StressScenario::new("Oil +20%").shock(StressFactor::Oil, 0.20),
+    /// ];
+    ///
+    /// for result in portfolio.stress_test(&scenarios).await? {
+    ///     println!("{}: {:+.0} ({:+.2?})", result.scenario, result.pnl, result.pnl_percent());
+    ///     for h in &result.holdings {
+    ///         println!("  {:<6} {:+.0?}", h.symbol, h.pnl);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stress_test(&self, scenarios: &[StressScenario]) -> Result<Vec<StressResult>> {
+        if self.is_empty() {
+            return Err(FinanceError::InvalidParameter {
+                param: "portfolio".into(),
+                reason: "portfolio has no holdings".into(),
+            });
+        }
+
+        let mut seen = HashSet::new();
+        let symbols: Vec<String> = self
+            .holdings
+            .iter()
+            .map(|h| h.symbol.as_str())
+            .chain(
+                scenarios
+                    .iter()
+                    .flat_map(|s| s.shocks.iter().map(|shock| shock.factor.symbol())),
+            )
+            .filter(|s| seen.insert(*s))
+            .map(str::to_string)
+            .collect();
+
+        let charts = Tickers::new(symbols)
+            .await?
+            .charts(Interval::OneDay, TimeRange::OneYear)
+            .await?
+            .charts;
+
+        scenarios
+            .iter()
+            .map(|scenario| self.stress_scenario(scenario, &charts))
+            .collect()
+    }
+
+    fn stress_scenario(
+        &self,
+        scenario: &StressScenario,
+        charts: &HashMap<String, Chart>,
+    ) -> Result<StressResult> {
+        let factors = scenario
+            .shocks
+            .iter()
+            .map(|shock| {
+                let symbol = shock.factor.symbol();
+                charts
+                    .get(symbol)
+                    .map(daily_closes)
+                    .filter(|closes| !closes.is_empty())
+                    .ok_or_else(|| FinanceError::SymbolNotFound {
+                        symbol: Some(symbol.to_string()),
+                        context: format!("no history for stress factor in '{}'", scenario.name),
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let sizes: Vec<f64> = scenario.shocks.iter().map(|s| s.size).collect();
+
+        let holdings: Vec<HoldingImpact> = self
+            .holdings
+            .iter()
+            .map(|holding| {
+                let closes = charts.get(&holding.symbol).map(daily_closes);
+                let price = closes
+                    .as_ref()
+                    .and_then(|c| c.values().next_back().copied())
+                    .unwrap_or(0.0);
+                let value = holding.shares * price;
+                let betas = closes.and_then(|c| factor_betas(&c, &factors, &scenario.shocks));
+                let pnl = betas
+                    .as_ref()
+                    .map(|b| value * b.iter().zip(&sizes).map(|(b, s)| b * s).sum::<f64>());
+                HoldingImpact {
+                    symbol: holding.symbol.clone(),
+                    shares: holding.shares,
+                    value,
+                    betas: betas.unwrap_or_default(),
+                    pnl,
+                }
+            })
+            .collect();
+
+        Ok(StressResult {
+            scenario: scenario.name.clone(),
+            value: holdings.iter().map(|h| h.value).sum(),
+            pnl: holdings.iter().filter_map(|h| h.pnl).sum(),
+            holdings,
+        })
+    }
+}
+
+/// Closes keyed by exchange-local calendar day, so symbols from different
+/// time zones line up on the same trading date.
+fn daily_closes(chart: &Chart) -> BTreeMap<i64, f64> {
+    let offset = chart.meta.gmt_offset.unwrap_or(0);
+    chart
+        .candles
+        .iter()
+        .filter(|c| c.close > 0.0)
+        .map(|c| ((c.timestamp + offset).div_euclid(SECONDS_PER_DAY), c.close))
+        .collect()
+}
+
+/// Regress the asset's daily returns on the factors' daily moves over the
+/// days all series share. Returns one beta per factor; a scenario without
+/// shocks needs no history.
+fn factor_betas(
+    asset: &BTreeMap<i64, f64>,
+    factors: &[BTreeMap<i64, f64>],
+    shocks: &[Shock],
+) -> Option<Vec<f64>> {
+    if factors.is_empty() {
+        return Some(Vec::new());
+    }
+
+    let days: Vec<i64> = asset
+        .keys()
+        .copied()
+        .filter(|day| factors.iter().all(|f| f.contains_key(day)))
+        .collect();
+
+    let mut y = Vec::with_capacity(days.len());
+    let mut x = Vec::with_capacity(days.len());
+    for pair in days.windows(2) {
+        let (prev, day) = (pair[0], pair[1]);
+        y.push(asset[&day] / asset[&prev] - 1.0);
+        x.push(
+            factors
+                .iter()
+                .zip(shocks)
+                .map(|(f, shock)| shock.factor.daily_move(f[&prev], f[&day]))
+                .collect::<Vec<_>>(),
+        );
+    }
+    if y.len() < MIN_OBSERVATIONS.max(factors.len() + 2) {
+        return None;
+    }
+
+    least_squares(&x, &y)
+}
+
+/// Slopes of an ordinary least-squares fit of `y` on the columns of `x` plus
+/// an intercept, via the normal equations.
+fn least_squares(x: &[Vec<f64>], y: &[f64]) -> Option<Vec<f64>> {
+    let k = x.first()?.len() + 1;
+
+    // Augmented [X'X | X'y]
+    let mut m = vec![vec![0.0; k + 1]; k];
+    for (xi, &yi) in x.iter().zip(y) {
+        let r: Vec<f64> = std::iter::once(1.0).chain(xi.iter().copied()).collect();
+        for (m_row, &ra) in m.iter_mut().zip(&r) {
+            for (cell, &rb) in m_row.iter_mut().zip(&r) {
+                *cell += ra * rb;
+            }
+            m_row[k] += ra * yi;
+        }
+    }
+
+    // Gauss-Jordan elimination with partial pivoting
+    for col in 0..k {
+        let pivot = (col..k).max_by(|&a, &b| m[a][col].abs().total_cmp(&m[b][col].abs()))?;
+        if m[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        m.swap(col, pivot);
+        let lead = m[col][col];
+        for v in &mut m[col] {
+            *v /= lead;
+        }
+        let pivot_row = m[col].clone();
+        for (r, row) in m.iter_mut().enumerate() {
+            let factor = row[col];
+            if r == col || factor == 0.0 {
+                continue;
+            }
+            for (v, p) in row.iter_mut().zip(&pivot_row) {
+                *v -= factor * p;
+            }
+        }
+    }
+
+    // Drop the intercept
+    Some(m[1..].iter().map(|r| r[k]).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(closes: &[f64]) -> BTreeMap<i64, f64> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| (i as i64, c))
+            .collect()
+    }
+
+    fn walk(n: usize, seed: f64, scale: f64) -> Vec<f64> {
+        let mut price = 100.0;
+        (0..n)
+            .map(|i| {
+                price *= 1.0 + ((i as f64 * seed).sin() * scale);
+                price
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_single_factor_beta() {
+        let market = walk(60, 1.3, 0.01);
+        // Asset returns are exactly 1.5x the market's
+        let mut asset = vec![50.0];
+        for w in market.windows(2) {
+            let last = *asset.last().unwrap();
+            asset.push(last * (1.0 + 1.5 * (w[1] / w[0] - 1.0)));
+        }
+        let shocks = [Shock {
+            factor: StressFactor::Equities,
+            size: -0.1,
+        }];
+        let betas = factor_betas(&series(&asset), &[series(&market)], &shocks).unwrap();
+        assert!((betas[0] - 1.5).abs() < 1e-9, "beta {}", betas[0]);
+    }
+
+    #[test]
+    fn test_two_factor_betas_and_yield_moves() {
+        let market = walk(80, 1.3, 0.01);
+        let yields: Vec<f64> = (0..80)
+            .map(|i| 4.0 + (i as f64 * 0.7).cos() * 0.3)
+            .collect();
+        // r = 0.8 * market - 5 * dYield (in decimal)
+        let mut asset = vec![100.0];
+        for i in 1..80 {
+            let m = market[i] / market[i - 1] - 1.0;
+            let dy = (yields[i] - yields[i - 1]) / 100.0;
+            let last = *asset.last().unwrap();
+            asset.push(last * (1.0 + 0.8 * m - 5.0 * dy));
+        }
+        let shocks = [
+            Shock {
+                factor: StressFactor::Equities,
+                size: -0.1,
+            },
+            Shock {
+                factor: StressFactor::Rates,
+                size: 0.01,
+            },
+        ];
+        let betas = factor_betas(
+            &series(&asset),
+            &[series(&market), series(&yields)],
+            &shocks,
+        )
+        .unwrap();
+        assert!((betas[0] - 0.8).abs() < 1e-6, "{betas:?}");
+        assert!((betas[1] + 5.0).abs() < 1e-6, "{betas:?}");
+    }
+
+    #[test]
+    fn test_betas_need_overlapping_history() {
+        let shocks = [Shock {
+            factor: StressFactor::Oil,
+            size: 0.2,
+        }];
+        let short = series(&walk(10, 1.1, 0.01));
+        assert!(factor_betas(&short, std::slice::from_ref(&short), &shocks).is_none());
+
+        // Constant factor has no variance to regress on
+        let flat = series(&[100.0; 40]);
+        let asset = series(&walk(40, 0.9, 0.01));
+        assert!(factor_betas(&asset, &[flat], &shocks).is_none());
+    }
+
+    #[test]
+    fn test_scenario_builder_replaces_shock() {
+        let scenario = StressScenario::new("Oil spike")
+            .shock(StressFactor::Oil, 0.1)
+            .shock(StressFactor::Oil, 0.2);
+        assert_eq!(scenario.shocks.len(), 1);
+        assert_eq!(scenario.shocks[0].size, 0.2);
+        assert_eq!(StressFactor::Rates.symbol(), "^TNX");
+        assert!(StressFactor::Rates.is_yield());
+        assert!(!StressFactor::Symbol("SPY".into()).is_yield());
+    }
+}
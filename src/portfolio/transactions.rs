@@ -0,0 +1,406 @@
+//! Buy/sell transactions and cost-basis tracking.
+
+use serde::{Deserialize, Serialize};
+
+use super::{Holding, Portfolio};
+use crate::error::{FinanceError, Result};
+
+/// Share counts closer to zero than this are treated as zero.
+const EPSILON: f64 = 1e-9;
+
+/// Whether a transaction adds or removes shares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum TransactionKind {
+    /// Shares bought
+    Buy,
+    /// Shares sold
+    Sell,
+}
+
+/// How the cost of sold shares is determined.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum CostBasisMethod {
+    /// Sales consume the oldest lots first
+    #[default]
+    Fifo,
+    /// Sales are costed at the average cost of all shares held
+    AverageCost,
+}
+
+/// A purchase or sale of shares.
+///
+/// # Example
+///
+/// ```
+/// use finance_query::portfolio::Transaction;
+///
+/// let buy = Transaction::buy("AAPL", 10.0, 150.0).at(1_700_000_000).fees(1.0);
+/// assert_eq!(buy.total(), 1_501.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Transaction {
+    /// Symbol traded
+    pub symbol: String,
+    /// Buy or sell
+    pub kind: TransactionKind,
+    /// Number of shares (always positive)
+    pub shares: f64,
+    /// Price per share
+    pub price: f64,
+    /// Commissions and fees paid
+    #[serde(default)]
+    pub fees: f64,
+    /// When the trade happened (Unix timestamp)
+    pub timestamp: i64,
+}
+
+impl Transaction {
+    fn new(kind: TransactionKind, symbol: impl Into<String>, shares: f64, price: f64) -> Self {
+        Self {
+            symbol: symbol.into(),
+            kind,
+            shares,
+            price,
+            fees: 0.0,
+            timestamp: chrono::Utc::now().timestamp(),
+        }
+    }
+
+    /// A purchase of `shares` at `price`, timestamped now.
+    pub fn buy(symbol: impl Into<String>, shares: f64, price: f64) -> Self {
+        Self::new(TransactionKind::Buy, symbol, shares, price)
+    }
+
+    /// A sale of `shares` at `price`, timestamped now.
+    pub fn sell(symbol: impl Into<String>, shares: f64, price: f64) -> Self {
+        Self::new(TransactionKind::Sell, symbol, shares, price)
+    }
+
+    /// Set when the trade happened (Unix timestamp).
+    pub fn at(mut self, timestamp: i64) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Set the fees paid on the trade.
+    pub fn fees(mut self, fees: f64) -> Self {
+        self.fees = fees;
+        self
+    }
+
+    /// Cash paid for a buy (price plus fees) or received for a sale (price
+    /// less fees).
+    pub fn total(&self) -> f64 {
+        match self.kind {
+            TransactionKind::Buy => self.shares * self.price + self.fees,
+            TransactionKind::Sell => self.shares * self.price - self.fees,
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        let invalid = |param: &str, reason: String| {
+            Err(FinanceError::InvalidParameter {
+                param: param.to_string(),
+                reason,
+            })
+        };
+        if !(self.shares.is_finite() && self.shares > 0.0) {
+            return invalid("shares", format!("must be positive, got {}", self.shares));
+        }
+        if !(self.price.is_finite() && self.price >= 0.0) {
+            return invalid("price", format!("must be non-negative, got {}", self.price));
+        }
+        if !(self.fees.is_finite() && self.fees >= 0.0) {
+            return invalid("fees", format!("must be non-negative, got {}", self.fees));
+        }
+        Ok(())
+    }
+}
+
+/// Shares bought together at one cost.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Lot {
+    /// Shares remaining in the lot
+    pub shares: f64,
+    /// Cost per share, including the purchase's fees
+    pub cost: f64,
+    /// When the lot was bought (Unix timestamp)
+    pub timestamp: i64,
+}
+
+impl Portfolio {
+    /// Create an empty portfolio that costs sales with `method`.
+    pub fn with_cost_basis_method(method: CostBasisMethod) -> Self {
+        Self {
+            cost_basis_method: method,
+            ..Self::default()
+        }
+    }
+
+    /// Build a portfolio by recording `transactions` in timestamp order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use finance_query::portfolio::{CostBasisMethod, Portfolio, Transaction};
+    ///
+    /// let portfolio = Portfolio::from_transactions(
+    ///     CostBasisMethod::Fifo,
+    ///     [
+    ///         Transaction::buy("AAPL", 10.0, 100.0).at(1),
+    ///         Transaction::buy("AAPL", 10.0, 120.0).at(2),
+    ///         Transaction::sell("AAPL", 15.0, 130.0).at(3),
+    ///     ],
+    /// )?;
+    ///
+    /// let aapl = &portfolio.holdings()[0];
+    /// assert_eq!(aapl.shares, 5.0);
+    /// assert_eq!(aapl.cost_basis(), Some(600.0));
+    /// assert_eq!(aapl.realized_pnl, 350.0);
+    /// # Ok::<(), finance_query::FinanceError>(())
+    /// ```
+    pub fn from_transactions(
+        method: CostBasisMethod,
+        transactions: impl IntoIterator<Item = Transaction>,
+    ) -> Result<Self> {
+        let mut transactions: Vec<Transaction> = transactions.into_iter().collect();
+        transactions.sort_by_key(|t| t.timestamp);
+        let mut portfolio = Self::with_cost_basis_method(method);
+        for transaction in transactions {
+            portfolio.record(transaction)?;
+        }
+        Ok(portfolio)
+    }
+
+    /// Apply a trade to its holding and add it to the ledger.
+    ///
+    /// Buys open a new lot. Sales are matched against lots by the
+    /// portfolio's [`CostBasisMethod`] and their gain is added to the
+    /// holding's `realized_pnl`. Shares added with
+    /// [`add_holding`](Self::add_holding) have no known cost, so sales are
+    /// matched against recorded lots first and any untracked remainder adds
+    /// no realized P&L.
+    ///
+    /// Fails if the trade has a non-positive share count, a negative price or
+    /// fee, or sells more shares than are held.
+    pub fn record(&mut self, transaction: Transaction) -> Result<()> {
+        transaction.validate()?;
+        let held = self
+            .holdings
+            .iter()
+            .position(|h| h.symbol == transaction.symbol);
+
+        match transaction.kind {
+            TransactionKind::Buy => {
+                let index = held.unwrap_or_else(|| {
+                    self.holdings
+                        .push(Holding::new(transaction.symbol.clone(), 0.0));
+                    self.holdings.len() - 1
+                });
+                self.holdings[index].buy(&transaction);
+            }
+            TransactionKind::Sell => {
+                let holding = held.map(|i| &mut self.holdings[i]);
+                let available = holding.as_ref().map_or(0.0, |h| h.shares);
+                match holding {
+                    Some(holding) if transaction.shares <= available + EPSILON => {
+                        holding.sell(&transaction, self.cost_basis_method);
+                    }
+                    _ => {
+                        return Err(FinanceError::InvalidParameter {
+                            param: "shares".into(),
+                            reason: format!(
+                                "cannot sell {} {}; {} held",
+                                transaction.shares, transaction.symbol, available
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+        self.transactions.push(transaction);
+        Ok(())
+    }
+
+    /// Recorded transactions in the order they were applied.
+    pub fn transactions(&self) -> &[Transaction] {
+        &self.transactions
+    }
+
+    /// How sales are costed.
+    pub fn cost_basis_method(&self) -> CostBasisMethod {
+        self.cost_basis_method
+    }
+
+    /// Gains realized by all sales so far.
+    pub fn realized_pnl(&self) -> f64 {
+        self.holdings.iter().map(|h| h.realized_pnl).sum()
+    }
+
+    /// Total cost of the shares held, or `None` if any holding's cost is
+    /// unknown.
+    pub fn total_cost(&self) -> Option<f64> {
+        self.holdings.iter().map(Holding::cost_basis).sum()
+    }
+}
+
+impl Holding {
+    /// Lots still held, oldest first.
+    pub fn lots(&self) -> &[Lot] {
+        &self.lots
+    }
+
+    /// Total cost of the shares held, or `None` if some shares were added
+    /// without a cost (see [`Portfolio::add_holding`]).
+    pub fn cost_basis(&self) -> Option<f64> {
+        let lot_shares: f64 = self.lots.iter().map(|l| l.shares).sum();
+        ((lot_shares - self.shares).abs() < EPSILON)
+            .then(|| self.lots.iter().map(|l| l.shares * l.cost).sum())
+    }
+
+    /// Cost per share held, or `None` if the cost is unknown or nothing is
+    /// held.
+    pub fn average_cost(&self) -> Option<f64> {
+        self.cost_basis()
+            .filter(|_| self.shares > EPSILON)
+            .map(|cost| cost / self.shares)
+    }
+
+    fn buy(&mut self, transaction: &Transaction) {
+        self.shares += transaction.shares;
+        self.lots.push(Lot {
+            shares: transaction.shares,
+            cost: transaction.total() / transaction.shares,
+            timestamp: transaction.timestamp,
+        });
+    }
+
+    fn sell(&mut self, transaction: &Transaction, method: CostBasisMethod) {
+        let lot_shares: f64 = self.lots.iter().map(|l| l.shares).sum();
+        let matched = transaction.shares.min(lot_shares);
+        let cost = match method {
+            CostBasisMethod::Fifo => {
+                let mut remaining = matched;
+                let mut cost = 0.0;
+                for lot in &mut self.lots {
+                    let take = remaining.min(lot.shares);
+                    cost += take * lot.cost;
+                    lot.shares -= take;
+                    remaining -= take;
+                    if remaining <= EPSILON {
+                        break;
+                    }
+                }
+                cost
+            }
+            CostBasisMethod::AverageCost if lot_shares > EPSILON => {
+                let total: f64 = self.lots.iter().map(|l| l.shares * l.cost).sum();
+                let keep = 1.0 - matched / lot_shares;
+                for lot in &mut self.lots {
+                    lot.shares *= keep;
+                }
+                total * matched / lot_shares
+            }
+            CostBasisMethod::AverageCost => 0.0,
+        };
+        self.lots.retain(|l| l.shares > EPSILON);
+        self.shares -= transaction.shares;
+        if self.shares.abs() < EPSILON {
+            self.shares = 0.0;
+        }
+        self.realized_pnl += transaction.total() * matched / transaction.shares - cost;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trades() -> Vec<Transaction> {
+        vec![
+            Transaction::buy("AAPL", 10.0, 100.0).at(1),
+            Transaction::buy("AAPL", 10.0, 120.0).at(2).fees(20.0),
+            Transaction::sell("AAPL", 15.0, 130.0).at(3).fees(15.0),
+        ]
+    }
+
+    #[test]
+    fn test_fifo_consumes_oldest_lots() {
+        let portfolio = Portfolio::from_transactions(CostBasisMethod::Fifo, trades()).unwrap();
+        let aapl = &portfolio.holdings()[0];
+        // Second lot cost 122/share with fees; 5 of those remain
+        assert_eq!(aapl.shares, 5.0);
+        assert_eq!(aapl.lots().len(), 1);
+        assert!((aapl.cost_basis().unwrap() - 610.0).abs() < 1e-9);
+        // 1935 proceeds - (10 * 100 + 5 * 122)
+        assert!((aapl.realized_pnl - 325.0).abs() < 1e-9);
+        assert_eq!(portfolio.transactions().len(), 3);
+    }
+
+    #[test]
+    fn test_average_cost_keeps_average() {
+        let portfolio =
+            Portfolio::from_transactions(CostBasisMethod::AverageCost, trades()).unwrap();
+        let aapl = &portfolio.holdings()[0];
+        // Average cost (1000 + 1220) / 20 = 111
+        assert!((aapl.average_cost().unwrap() - 111.0).abs() < 1e-9);
+        assert!((aapl.realized_pnl - (1935.0 - 15.0 * 111.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_untracked_shares_have_no_cost() {
+        let mut portfolio = Portfolio::new().with_holding("MSFT", 10.0);
+        assert_eq!(portfolio.holdings()[0].cost_basis(), None);
+        portfolio
+            .record(Transaction::buy("MSFT", 5.0, 200.0))
+            .unwrap();
+        assert_eq!(portfolio.total_cost(), None);
+
+        // Sales match recorded lots first
+        portfolio
+            .record(Transaction::sell("MSFT", 8.0, 210.0))
+            .unwrap();
+        let msft = &portfolio.holdings()[0];
+        assert_eq!(msft.shares, 7.0);
+        assert!((msft.realized_pnl - 50.0).abs() < 1e-9);
+        assert!(msft.lots().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_trades_are_rejected() {
+        let mut portfolio = Portfolio::new();
+        assert!(
+            portfolio
+                .record(Transaction::sell("AAPL", 1.0, 10.0))
+                .is_err()
+        );
+        assert!(
+            portfolio
+                .record(Transaction::buy("AAPL", 0.0, 10.0))
+                .is_err()
+        );
+        assert!(
+            portfolio
+                .record(Transaction::buy("AAPL", 1.0, -1.0))
+                .is_err()
+        );
+        portfolio
+            .record(Transaction::buy("AAPL", 2.0, 10.0))
+            .unwrap();
+        assert!(
+            portfolio
+                .record(Transaction::sell("AAPL", 3.0, 10.0))
+                .is_err()
+        );
+        assert_eq!(portfolio.transactions().len(), 1);
+        assert_eq!(portfolio.total_cost(), Some(20.0));
+    }
+}
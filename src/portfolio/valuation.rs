@@ -0,0 +1,185 @@
+//! Marking holdings to market.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::Portfolio;
+use crate::Tickers;
+use crate::error::{FinanceError, Result};
+
+/// One holding marked to market.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct HoldingValuation {
+    /// Symbol
+    pub symbol: String,
+    /// Shares held
+    pub shares: f64,
+    /// Price used
+    pub price: f64,
+    /// `shares * price`
+    pub market_value: f64,
+    /// Total cost of the shares held; `None` if unknown
+    pub cost_basis: Option<f64>,
+    /// `market_value - cost_basis`; `None` if the cost is unknown
+    pub unrealized_pnl: Option<f64>,
+    /// Gains realized by sales so far
+    pub realized_pnl: f64,
+    /// Share of the portfolio's market value (0.0–1.0)
+    pub weight: f64,
+}
+
+impl HoldingValuation {
+    /// Unrealized P&L as a fraction of cost.
+    pub fn unrealized_pnl_percent(&self) -> Option<f64> {
+        let cost = self.cost_basis.filter(|c| *c != 0.0)?;
+        Some(self.unrealized_pnl? / cost.abs())
+    }
+}
+
+/// A portfolio marked to market.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PortfolioValuation {
+    /// Holdings that had a price, in portfolio order
+    pub holdings: Vec<HoldingValuation>,
+    /// Total market value
+    pub market_value: f64,
+    /// Total cost; `None` if any valued holding's cost is unknown
+    pub cost_basis: Option<f64>,
+    /// Total unrealized P&L; `None` if any valued holding's cost is unknown
+    pub unrealized_pnl: Option<f64>,
+    /// Total realized P&L of the valued holdings
+    pub realized_pnl: f64,
+    /// Symbols left out because no price was available
+    pub missing: Vec<String>,
+}
+
+impl PortfolioValuation {
+    /// Realized plus unrealized P&L; `None` if any cost is unknown.
+    pub fn total_pnl(&self) -> Option<f64> {
+        Some(self.realized_pnl + self.unrealized_pnl?)
+    }
+}
+
+impl Portfolio {
+    /// Value every holding at its live quote price.
+    ///
+    /// Uses [`Quote::live_price`](crate::Quote::live_price), so pre- and
+    /// post-market prices are used outside regular hours. Values are summed
+    /// as quoted; convert prices with [`fx::CurrencyConverter`](crate::fx::CurrencyConverter)
+    /// first if holdings trade in different currencies.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::portfolio::{Portfolio, Transaction};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut portfolio = Portfolio::new();
+    /// portfolio.record(Transaction::buy("AAPL", 10.0, 150.0))?;
+    /// portfolio.record(Transaction::buy("MSFT", 5.0, 300.0))?;
+    ///
+    /// let valuation = portfolio.valuation().await?;
+    /// println!("Value: {:.2}, unrealized: {:+.2?}", valuation.market_value, valuation.unrealized_pnl);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn valuation(&self) -> Result<PortfolioValuation> {
+        if self.is_empty() {
+            return Err(FinanceError::InvalidParameter {
+                param: "portfolio".into(),
+                reason: "portfolio has no holdings".into(),
+            });
+        }
+        let quotes = Tickers::new(self.symbols()).await?.quotes().await?.quotes;
+        let prices: HashMap<String, f64> = quotes
+            .iter()
+            .filter_map(|(symbol, quote)| Some((symbol.clone(), quote.live_price()?)))
+            .collect();
+        Ok(self.valuation_at(&prices))
+    }
+
+    /// Value every holding at the given prices, keyed by symbol.
+    ///
+    /// Holdings without a price are listed in
+    /// [`missing`](PortfolioValuation::missing).
+    pub fn valuation_at(&self, prices: &HashMap<String, f64>) -> PortfolioValuation {
+        let mut missing = Vec::new();
+        let mut holdings: Vec<HoldingValuation> = self
+            .holdings
+            .iter()
+            .filter_map(|holding| {
+                let Some(&price) = prices.get(&holding.symbol).filter(|p| p.is_finite()) else {
+                    missing.push(holding.symbol.clone());
+                    return None;
+                };
+                let market_value = holding.shares * price;
+                let cost_basis = holding.cost_basis();
+                Some(HoldingValuation {
+                    symbol: holding.symbol.clone(),
+                    shares: holding.shares,
+                    price,
+                    market_value,
+                    cost_basis,
+                    unrealized_pnl: cost_basis.map(|cost| market_value - cost),
+                    realized_pnl: holding.realized_pnl,
+                    weight: 0.0,
+                })
+            })
+            .collect();
+
+        let market_value: f64 = holdings.iter().map(|h| h.market_value).sum();
+        if market_value != 0.0 {
+            for holding in &mut holdings {
+                holding.weight = holding.market_value / market_value;
+            }
+        }
+        PortfolioValuation {
+            market_value,
+            cost_basis: holdings.iter().map(|h| h.cost_basis).sum(),
+            unrealized_pnl: holdings.iter().map(|h| h.unrealized_pnl).sum(),
+            realized_pnl: holdings.iter().map(|h| h.realized_pnl).sum(),
+            holdings,
+            missing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::portfolio::Transaction;
+
+    #[test]
+    fn test_valuation_at_prices() {
+        let mut portfolio = Portfolio::new().with_holding("XOM", 10.0);
+        portfolio
+            .record(Transaction::buy("AAPL", 10.0, 100.0))
+            .unwrap();
+        portfolio
+            .record(Transaction::sell("AAPL", 5.0, 120.0))
+            .unwrap();
+        portfolio
+            .record(Transaction::buy("MSFT", 2.0, 50.0))
+            .unwrap();
+
+        let prices = HashMap::from([("AAPL".to_string(), 110.0), ("XOM".to_string(), 45.0)]);
+        let valuation = portfolio.valuation_at(&prices);
+
+        assert_eq!(valuation.missing, ["MSFT"]);
+        assert_eq!(valuation.market_value, 1_000.0);
+        let aapl = &valuation.holdings[1];
+        assert_eq!(aapl.unrealized_pnl, Some(50.0));
+        assert_eq!(aapl.realized_pnl, 100.0);
+        assert_eq!(aapl.weight, 0.55);
+        assert_eq!(aapl.unrealized_pnl_percent(), Some(0.1));
+        // XOM was added without a cost
+        assert_eq!(valuation.holdings[0].cost_basis, None);
+        assert_eq!(valuation.unrealized_pnl, None);
+        assert_eq!(valuation.realized_pnl, 100.0);
+    }
+}
@@ -229,6 +229,12 @@ impl ProvidersBuilder {
         self
     }
 
+    /// Set the connection pool and keep-alive settings for the Yahoo client.
+    pub fn connection_pool(mut self, pool: crate::ConnectionPool) -> Self {
+        self.config.pool = pool;
+        self
+    }
+
     /// Build the [`Providers`] instance, initialising all configured providers.
     pub async fn build(self) -> Result<Providers> {
         #[cfg(feature = "translation")]
@@ -0,0 +1,481 @@
+//! User-supplied data providers.
+//!
+//! Implement [`DataProvider`] to serve quotes and charts from any source
+//! (Stooq, a broker API, local CSV files) and inject it with
+//! [`TickerBuilder::provider`](crate::TickerBuilder::provider). Everything
+//! built on [`Ticker::quote`](crate::Ticker::quote) and
+//! [`Ticker::chart`](crate::Ticker::chart) (indicators, risk, backtesting)
+//! then runs on that source instead of Yahoo.
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use super::yahoo::YahooProvider;
+use super::{Capability, Fetch, Operation, Provider, ProviderAdapter, ProviderSet, Routes};
+use crate::adapters::yahoo::client::ClientConfig;
+use crate::constants::{Interval, TimeRange};
+use crate::error::{FinanceError, Result};
+use crate::models::chart::{Candle, Chart, ChartMeta};
+use crate::models::quote::{FormattedValue, Price, QuoteModule, QuoteSummaryResponse};
+
+fn not_supported(operation: Operation) -> FinanceError {
+    FinanceError::NotSupported {
+        provider: Provider::Custom,
+        operation,
+        candidates: operation.capability().candidate_providers(),
+    }
+}
+
+/// A source of quotes, charts, and symbol search.
+///
+/// Every method has a default that returns [`FinanceError::NotSupported`], so
+/// implement only what the source offers. [`chart_range`](Self::chart_range)
+/// defaults to filtering the full [`chart`](Self::chart) history, which suits
+/// file-backed sources.
+///
+/// # Example
+///
+/// ```no_run
+/// use finance_query::{Candle, DataProvider, Interval, Ticker, TimeRange};
+///
+/// /// Daily bars from `<dir>/<SYMBOL>.csv`.
+/// struct CsvProvider {
+///     dir: std::path::PathBuf,
+/// }
+///
+/// #[async_trait::async_trait]
+/// impl DataProvider for CsvProvider {
+///     fn name(&self) -> &str {
+///         "csv"
+///     }
+///
+///     async fn chart(&self, symbol: &str, _: Interval, _: TimeRange) -> finance_query::Result<Vec<Candle>> {
+///         let file = std::fs::File::open(self.dir.join(format!("{symbol}.csv")))?;
+///         Candle::from_csv(std::io::BufReader::new(file))
+///     }
+/// }
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let ticker = Ticker::builder("AAPL")
+///     .provider(CsvProvider { dir: "data".into() })
+///     .build()
+///     .await?;
+/// let chart = ticker.chart(Interval::OneDay, TimeRange::Max).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait::async_trait]
+pub trait DataProvider: Send + Sync {
+    /// Short name for logs (e.g., `"stooq"`).
+    fn name(&self) -> &str {
+        "custom"
+    }
+
+    /// Latest quote for `symbol`.
+    async fn quote(&self, _symbol: &str) -> Result<ProviderQuote> {
+        Err(not_supported(Operation::Quote))
+    }
+
+    /// OHLCV bars for `symbol` at `interval` over `range`, oldest first.
+    async fn chart(
+        &self,
+        _symbol: &str,
+        _interval: Interval,
+        _range: TimeRange,
+    ) -> Result<Vec<Candle>> {
+        Err(not_supported(Operation::Chart))
+    }
+
+    /// OHLCV bars for `symbol` between two Unix timestamps (seconds), `end`
+    /// exclusive.
+    async fn chart_range(
+        &self,
+        symbol: &str,
+        interval: Interval,
+        start: i64,
+        end: i64,
+    ) -> Result<Vec<Candle>> {
+        let candles = self.chart(symbol, interval, TimeRange::Max).await?;
+        Ok(candles
+            .into_iter()
+            .filter(|c| c.timestamp >= start && c.timestamp < end)
+            .collect())
+    }
+
+    /// Symbols matching `query` (a name or partial ticker).
+    async fn search(&self, _query: &str) -> Result<Vec<SymbolMatch>> {
+        Err(not_supported(Operation::Search))
+    }
+}
+
+/// A quote served by a [`DataProvider`].
+///
+/// Build with [`ProviderQuote::new`] and set the optional fields the source
+/// has. [`Ticker::quote`](crate::Ticker::quote) maps it onto the matching
+/// [`Quote`](crate::Quote) fields and leaves the rest empty.
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderQuote {
+    /// Symbol the quote is for.
+    pub symbol: String,
+    /// Last price.
+    pub price: f64,
+    /// Prior session's close.
+    pub previous_close: Option<f64>,
+    /// Session open.
+    pub open: Option<f64>,
+    /// Session high.
+    pub day_high: Option<f64>,
+    /// Session low.
+    pub day_low: Option<f64>,
+    /// Session volume.
+    pub volume: Option<i64>,
+    /// Currency code (e.g., `"USD"`).
+    pub currency: Option<String>,
+    /// Display name of the security.
+    pub name: Option<String>,
+    /// Time of the last price (Unix seconds).
+    pub timestamp: Option<i64>,
+}
+
+impl ProviderQuote {
+    /// Quote with a symbol and last price; other fields start empty.
+    pub fn new(symbol: impl Into<String>, price: f64) -> Self {
+        Self {
+            symbol: symbol.into(),
+            price,
+            ..Default::default()
+        }
+    }
+
+    /// Build from a quote summary's `price` module.
+    fn from_price(symbol: &str, price: &Price) -> Option<Self> {
+        let raw = |v: &Option<FormattedValue<f64>>| v.as_ref().and_then(|v| v.raw);
+        Some(Self {
+            symbol: price.symbol.clone().unwrap_or_else(|| symbol.to_string()),
+            price: raw(&price.regular_market_price)?,
+            previous_close: raw(&price.regular_market_previous_close),
+            open: raw(&price.regular_market_open),
+            day_high: raw(&price.regular_market_day_high),
+            day_low: raw(&price.regular_market_day_low),
+            volume: price.regular_market_volume.as_ref().and_then(|v| v.raw),
+            currency: price.currency.clone(),
+            name: price.long_name.clone().or_else(|| price.short_name.clone()),
+            timestamp: price.regular_market_time,
+        })
+    }
+
+    /// Express as a quote summary holding only the `price` module.
+    fn into_summary(self) -> QuoteSummaryResponse {
+        let value = |v: Option<f64>| v.map(FormattedValue::new);
+        let price = Price {
+            regular_market_price: Some(FormattedValue::new(self.price)),
+            regular_market_change: value(self.previous_close.map(|p| self.price - p)),
+            regular_market_previous_close: value(self.previous_close),
+            regular_market_open: value(self.open),
+            regular_market_day_high: value(self.day_high),
+            regular_market_day_low: value(self.day_low),
+            regular_market_volume: self.volume.map(FormattedValue::new),
+            regular_market_time: self.timestamp,
+            currency: self.currency,
+            short_name: self.name.clone(),
+            long_name: self.name,
+            symbol: Some(self.symbol.clone()),
+            ..Default::default()
+        };
+        QuoteSummaryResponse {
+            symbol: self.symbol,
+            price: Some(price),
+            ..Default::default()
+        }
+    }
+}
+
+/// A symbol returned by [`DataProvider::search`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolMatch {
+    /// Ticker symbol.
+    pub symbol: String,
+    /// Display name.
+    pub name: Option<String>,
+    /// Exchange code or name.
+    pub exchange: Option<String>,
+    /// Instrument type (e.g., `"EQUITY"`, `"ETF"`).
+    pub quote_type: Option<String>,
+}
+
+impl SymbolMatch {
+    /// Match with just a symbol; other fields start empty.
+    pub fn new(symbol: impl Into<String>) -> Self {
+        Self {
+            symbol: symbol.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// The built-in Yahoo Finance source as a [`DataProvider`].
+///
+/// [`Ticker`](crate::Ticker) uses Yahoo without this; it exists for code
+/// written against `dyn DataProvider` that should default to Yahoo.
+pub struct YahooDataProvider {
+    inner: YahooProvider,
+}
+
+impl YahooDataProvider {
+    /// Open an authenticated Yahoo session with default settings.
+    pub async fn new() -> Result<Self> {
+        Ok(Self {
+            inner: YahooProvider::new(&ClientConfig::default()).await?,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl DataProvider for YahooDataProvider {
+    fn name(&self) -> &str {
+        "yahoo"
+    }
+
+    async fn quote(&self, symbol: &str) -> Result<ProviderQuote> {
+        let summary = self
+            .inner
+            .fetch_quote_modules(symbol, &[QuoteModule::Price])
+            .await?;
+        summary
+            .price
+            .as_ref()
+            .and_then(|p| ProviderQuote::from_price(symbol, p))
+            .ok_or_else(|| FinanceError::SymbolNotFound {
+                symbol: Some(symbol.to_string()),
+                context: "Yahoo returned no price".to_string(),
+            })
+    }
+
+    async fn chart(
+        &self,
+        symbol: &str,
+        interval: Interval,
+        range: TimeRange,
+    ) -> Result<Vec<Candle>> {
+        Ok(self
+            .inner
+            .fetch_chart(symbol, interval, range)
+            .await?
+            .candles)
+    }
+
+    async fn chart_range(
+        &self,
+        symbol: &str,
+        interval: Interval,
+        start: i64,
+        end: i64,
+    ) -> Result<Vec<Candle>> {
+        Ok(self
+            .inner
+            .fetch_chart_range(symbol, interval, start, end)
+            .await?
+            .candles)
+    }
+
+    async fn search(&self, query: &str) -> Result<Vec<SymbolMatch>> {
+        let results = self
+            .inner
+            .client_arc()
+            .search(query, &crate::SearchOptions::new())
+            .await?;
+        Ok(results
+            .quotes
+            .0
+            .into_iter()
+            .map(|q| SymbolMatch {
+                name: q.long_name.or(q.short_name),
+                exchange: q.exchange,
+                quote_type: q.quote_type,
+                symbol: q.symbol,
+            })
+            .collect())
+    }
+}
+
+/// Routes a [`DataProvider`] through the provider machinery.
+pub(crate) struct CustomProvider(Arc<dyn DataProvider>);
+
+impl CustomProvider {
+    /// Provider set serving quotes and charts from `provider` alone.
+    pub(crate) fn provider_set(provider: Arc<dyn DataProvider>) -> ProviderSet {
+        let mut routes = Routes::new(Fetch::Sequential);
+        for cap in [Capability::QUOTE, Capability::CHART] {
+            routes.map.insert(cap, vec![Provider::Custom]);
+        }
+        ProviderSet::new(vec![Arc::new(Self(provider))], None, routes)
+    }
+
+    fn chart(symbol: &str, mut candles: Vec<Candle>, interval: Interval) -> Chart {
+        candles.sort_by_key(|c| c.timestamp);
+        for candle in &mut candles {
+            candle.provider_id = Some(Provider::Custom);
+        }
+        Chart {
+            symbol: symbol.to_string(),
+            meta: ChartMeta {
+                symbol: symbol.to_string(),
+                regular_market_price: candles.last().map(|c| c.close),
+                ..Default::default()
+            },
+            candles,
+            interval: Some(interval),
+            range: None,
+            provider_id: Some(Provider::Custom),
+            adjustment: None,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ProviderAdapter for CustomProvider {
+    fn id(&self) -> Provider {
+        Provider::Custom
+    }
+
+    fn capabilities(&self) -> Capability {
+        Provider::Custom.capabilities()
+    }
+
+    async fn fetch_quote(&self, symbol: &str) -> Result<QuoteSummaryResponse> {
+        debug!("{} quote: {symbol}", self.0.name());
+        Ok(self.0.quote(symbol).await?.into_summary())
+    }
+
+    async fn fetch_chart(
+        &self,
+        symbol: &str,
+        interval: Interval,
+        range: TimeRange,
+    ) -> Result<Chart> {
+        debug!("{} chart: {symbol} {interval} {range}", self.0.name());
+        let candles = self.0.chart(symbol, interval, range).await?;
+        Ok(Self::chart(symbol, candles, interval))
+    }
+
+    async fn fetch_chart_range(
+        &self,
+        symbol: &str,
+        interval: Interval,
+        start: i64,
+        end: i64,
+    ) -> Result<Chart> {
+        debug!(
+            "{} chart: {symbol} {interval} {start}..{end}",
+            self.0.name()
+        );
+        let candles = self.0.chart_range(symbol, interval, start, end).await?;
+        Ok(Self::chart(symbol, candles, interval))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Fixed;
+
+    #[async_trait::async_trait]
+    impl DataProvider for Fixed {
+        async fn quote(&self, symbol: &str) -> Result<ProviderQuote> {
+            let mut quote = ProviderQuote::new(symbol, 101.0);
+            quote.previous_close = Some(100.0);
+            Ok(quote)
+        }
+
+        async fn chart(&self, _: &str, _: Interval, _: TimeRange) -> Result<Vec<Candle>> {
+            Ok((0..5)
+                .rev()
+                .map(|i| Candle::new(i * 100, 1.0, 2.0, 0.5, 1.0 + i as f64, 10))
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chart_is_sorted_and_tagged() {
+        let adapter = CustomProvider(Arc::new(Fixed));
+        let chart = adapter
+            .fetch_chart("X", Interval::OneDay, TimeRange::Max)
+            .await
+            .unwrap();
+        assert!(
+            chart
+                .candles
+                .windows(2)
+                .all(|w| w[0].timestamp < w[1].timestamp)
+        );
+        assert_eq!(chart.meta.regular_market_price, Some(5.0));
+        assert_eq!(chart.candles[0].provider_id, Some(Provider::Custom));
+    }
+
+    #[tokio::test]
+    async fn test_default_chart_range_filters_full_history() {
+        let candles = Fixed
+            .chart_range("X", Interval::OneDay, 100, 300)
+            .await
+            .unwrap();
+        let stamps: Vec<i64> = candles.iter().map(|c| c.timestamp).collect();
+        assert_eq!(stamps, vec![200, 100]);
+    }
+
+    #[tokio::test]
+    async fn test_unimplemented_methods_are_not_supported() {
+        let err = Fixed.search("X").await.unwrap_err();
+        assert!(matches!(
+            err,
+            FinanceError::NotSupported {
+                provider: Provider::Custom,
+                operation: Operation::Search,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_quote_round_trips_through_summary() {
+        let mut quote = ProviderQuote::new("X", 101.0);
+        quote.previous_close = Some(100.0);
+        quote.volume = Some(5_000);
+        let summary = quote.clone().into_summary();
+        let price = summary.price.as_ref().unwrap();
+        assert_eq!(price.regular_market_change.as_ref().unwrap().raw, Some(1.0));
+        assert_eq!(ProviderQuote::from_price("X", price).unwrap(), {
+            quote.name = None;
+            quote
+        });
+    }
+
+    #[tokio::test]
+    async fn test_ticker_uses_injected_provider() {
+        let ticker = crate::Ticker::builder("X")
+            .provider(Fixed)
+            .build()
+            .await
+            .unwrap();
+
+        let quote: crate::Quote<crate::format::Raw> = ticker.quote().await.unwrap();
+        assert_eq!(quote.regular_market_price, Some(101.0));
+        assert_eq!(quote.regular_market_change, Some(1.0));
+
+        let chart = ticker
+            .chart(Interval::OneDay, TimeRange::Max)
+            .await
+            .unwrap();
+        assert_eq!(chart.candles.len(), 5);
+        assert!(matches!(
+            ticker.news().await,
+            Err(FinanceError::NoProviderAvailable { .. })
+        ));
+    }
+}
@@ -81,6 +81,7 @@ impl super::ProviderAdapter for FmpProvider {
             interval: Some(interval),
             range: Some(range),
             provider_id: Some(Provider::Fmp),
+            adjustment: None,
         })
     }
 
@@ -127,6 +128,7 @@ impl super::ProviderAdapter for FmpProvider {
             interval: Some(interval),
             range: None,
             provider_id: Some(Provider::Fmp),
+            adjustment: None,
         })
     }
 
@@ -227,6 +229,7 @@ fn fmp_interval_str(interval: crate::Interval) -> &'static str {
         crate::Interval::FifteenMinutes => "15min",
         crate::Interval::ThirtyMinutes => "30min",
         crate::Interval::OneHour => "1hour",
+        crate::Interval::FourHours => "4hour",
         _ => "1hour", // fallback for daily/weekly/monthly
     }
 }
@@ -256,6 +259,7 @@ mod tests {
         assert_eq!(fmp_interval_str(Interval::FifteenMinutes), "15min");
         assert_eq!(fmp_interval_str(Interval::ThirtyMinutes), "30min");
         assert_eq!(fmp_interval_str(Interval::OneHour), "1hour");
+        assert_eq!(fmp_interval_str(Interval::FourHours), "4hour");
         // Daily/weekly/monthly aren't intraday intervals → documented fallback.
         assert_eq!(fmp_interval_str(Interval::OneDay), "1hour");
         assert_eq!(fmp_interval_str(Interval::OneWeek), "1hour");
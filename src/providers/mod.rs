@@ -6,6 +6,8 @@ pub mod config;
 pub(crate) mod alphavantage;
 #[cfg(feature = "crypto")]
 pub(crate) mod coingecko;
+pub(crate) mod custom;
+#[cfg(feature = "edgar")]
 pub(crate) mod edgar;
 #[cfg(feature = "fmp")]
 pub(crate) mod fmp;
@@ -47,8 +49,12 @@ pub enum Provider {
     /// FRED economic data (requires `fred` feature).
     #[cfg(feature = "fred")]
     Fred,
-    /// SEC EDGAR filings (always available, keyless).
+    /// SEC EDGAR filings (requires `edgar` feature, on by default; keyless).
+    #[cfg(feature = "edgar")]
     Edgar,
+    /// A user-supplied [`DataProvider`](crate::DataProvider), injected with
+    /// [`TickerBuilder::provider`](crate::TickerBuilder::provider).
+    Custom,
 }
 
 impl Provider {
@@ -68,7 +74,9 @@ impl Provider {
             "coingecko" => Some(Self::CoinGecko),
             #[cfg(feature = "fred")]
             "fred" => Some(Self::Fred),
+            #[cfg(feature = "edgar")]
             "edgar" => Some(Self::Edgar),
+            "custom" => Some(Self::Custom),
             _ => None,
         }
     }
@@ -87,7 +95,9 @@ impl Provider {
             Self::CoinGecko => "coingecko",
             #[cfg(feature = "fred")]
             Self::Fred => "fred",
+            #[cfg(feature = "edgar")]
             Self::Edgar => "edgar",
+            Self::Custom => "custom",
         }
     }
 
@@ -96,6 +106,7 @@ impl Provider {
     /// hints (see [`Capability::candidate_providers`]) without needing a live
     /// `ProviderSet`.
     pub(crate) fn all() -> Vec<Self> {
+        #[allow(unused_mut)] // Yahoo-only builds push nothing else
         let mut v = vec![Self::Yahoo];
         #[cfg(feature = "polygon")]
         v.push(Self::Polygon);
@@ -107,7 +118,10 @@ impl Provider {
         v.push(Self::CoinGecko);
         #[cfg(feature = "fred")]
         v.push(Self::Fred);
+        #[cfg(feature = "edgar")]
         v.push(Self::Edgar);
+        // `Custom` is left out: it can't be enabled by a feature or route, so
+        // it's never a useful suggestion.
         v
     }
 
@@ -165,7 +179,9 @@ impl Provider {
             Self::CoinGecko => Capability::CRYPTO,
             #[cfg(feature = "fred")]
             Self::Fred => Capability::ECONOMIC,
+            #[cfg(feature = "edgar")]
             Self::Edgar => Capability::FILINGS,
+            Self::Custom => Capability::QUOTE | Capability::CHART,
         }
     }
 }
@@ -322,6 +338,8 @@ pub enum Operation {
     CommoditiesQuote,
     /// SEC EDGAR filing data.
     Filings,
+    /// Symbol search by name or partial ticker.
+    Search,
 }
 
 impl Operation {
@@ -345,13 +363,14 @@ impl Operation {
             Self::FuturesQuote => "futures_quote",
             Self::CommoditiesQuote => "commodities_quote",
             Self::Filings => "filings",
+            Self::Search => "search",
         }
     }
 
     /// The coarser [`Capability`] bit this operation falls under.
     pub fn capability(self) -> Capability {
         match self {
-            Self::Quote | Self::QuotesBatch => Capability::QUOTE,
+            Self::Quote | Self::QuotesBatch | Self::Search => Capability::QUOTE,
             Self::Chart | Self::ChartRange | Self::Spark => Capability::CHART,
             Self::Financials => Capability::FUNDAMENTALS,
             Self::News | Self::Recommendations | Self::Events => Capability::CORPORATE,
@@ -392,6 +411,9 @@ impl Routes {
     }
 }
 
+// `capabilities`/`initialize` are only called for optional providers, which a
+// Yahoo-only build compiles out.
+#[allow(dead_code)]
 #[async_trait::async_trait]
 pub(crate) trait ProviderAdapter: Send + Sync {
     fn id(&self) -> Provider;
@@ -415,6 +437,15 @@ pub(crate) trait ProviderAdapter: Send + Sync {
     async fn fetch_quote(&self, _: &str) -> Result<QuoteSummaryResponse> {
         Err(self.not_supported(Operation::Quote))
     }
+    /// Fetch only `modules` of the quote. Providers without module selection
+    /// return their full quote.
+    async fn fetch_quote_modules(
+        &self,
+        symbol: &str,
+        _modules: &[crate::models::quote::QuoteModule],
+    ) -> Result<QuoteSummaryResponse> {
+        self.fetch_quote(symbol).await
+    }
     async fn fetch_chart(
         &self,
         _: &str,
@@ -576,11 +607,14 @@ impl ProviderSet {
                 .collect()
         } else if cap == Capability::FILINGS {
             // Default: EDGAR (keyless SEC filings) first, then Yahoo
+            #[cfg(feature = "edgar")]
             let mut v: Vec<&Arc<dyn ProviderAdapter>> = self
                 .providers
                 .iter()
                 .filter(|p| p.id() == Provider::Edgar)
                 .collect();
+            #[cfg(not(feature = "edgar"))]
+            let mut v: Vec<&Arc<dyn ProviderAdapter>> = Vec::new();
             v.extend(self.providers.iter().filter(|p| p.id() == Provider::Yahoo));
             v
         } else {
@@ -616,6 +650,9 @@ impl ProviderSet {
             Fetch::Sequential => {
                 let mut last = None;
                 for p in &candidates {
+                    if last.is_some() {
+                        crate::telemetry::record_fallback();
+                    }
                     match f(p).await {
                         Ok(v) => return Ok(v),
                         Err(FinanceError::NotSupported { .. }) => continue,
@@ -693,6 +730,7 @@ pub(crate) fn build_financial_statement(
         statement_type,
         frequency,
         statement,
+        currency: None,
         provider_id: Some(provider_id),
     }
 }
@@ -872,13 +910,23 @@ pub(crate) async fn build_providers(
                 fp.initialize().await?;
                 providers.push(Arc::new(fp));
             }
+            #[cfg(feature = "edgar")]
             Provider::Edgar => providers.push(Arc::new(edgar::EdgarProvider)),
+            Provider::Custom => {
+                return Err(FinanceError::InvalidParameter {
+                    param: "providers".to_string(),
+                    reason: "custom providers are injected with `Ticker::builder(..).provider(..)`"
+                        .to_string(),
+                });
+            }
         }
     }
     // Auto-inject EDGAR if no other FILINGS-capable provider was configured
+    #[cfg(feature = "edgar")]
     let has_filings = providers
         .iter()
         .any(|p| p.capabilities().contains(Capability::FILINGS));
+    #[cfg(feature = "edgar")]
     if !has_filings {
         providers.push(Arc::new(edgar::EdgarProvider));
     }
@@ -998,6 +1046,7 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "edgar")]
     #[test]
     fn edgar_capabilities_match_static_table() {
         assert_eq!(
@@ -52,6 +52,15 @@ impl super::ProviderAdapter for YahooProvider {
         crate::adapters::yahoo::quote::summary::fetch_summary(&self.client, symbol).await
     }
 
+    async fn fetch_quote_modules(
+        &self,
+        symbol: &str,
+        modules: &[crate::models::quote::QuoteModule],
+    ) -> Result<crate::models::quote::QuoteSummaryResponse> {
+        crate::adapters::yahoo::quote::summary::fetch_summary_modules(&self.client, symbol, modules)
+            .await
+    }
+
     // ── Chart ─────────────────────────────────────────────────────
 
     async fn fetch_chart(
@@ -136,7 +145,7 @@ impl super::ProviderAdapter for YahooProvider {
         &self,
         symbols: &[&str],
     ) -> Result<Vec<(String, crate::models::quote::QuoteSummaryResponse)>> {
-        crate::adapters::yahoo::quote::quotes::fetch_quotes_batch(&self.client, symbols).await
+        crate::adapters::yahoo::quote::batcher::fetch_coalesced(&self.client, symbols).await
     }
 
     async fn fetch_spark(
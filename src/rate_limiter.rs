@@ -43,6 +43,8 @@ impl RateLimiter {
 
     /// Acquire a token, sleeping if necessary to respect the rate limit.
     pub async fn acquire(&self) {
+        let start = Instant::now();
+        let mut waited = false;
         loop {
             let sleep_duration = {
                 let mut state = self.state.lock().await;
@@ -54,12 +56,16 @@ impl RateLimiter {
 
                 if state.available >= 1.0 {
                     state.available -= 1.0;
+                    if waited {
+                        crate::telemetry::record_rate_limit_wait(start.elapsed());
+                    }
                     return;
                 }
 
                 let deficit = 1.0 - state.available;
                 Duration::from_secs_f64(deficit / state.refill_rate)
             };
+            waited = true;
             tokio::time::sleep(sleep_duration).await;
         }
     }
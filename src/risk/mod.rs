@@ -3,7 +3,9 @@
 //! Requires the **`risk`** feature flag (which implies **`indicators`**).
 //!
 //! Provides Value at Risk, Sharpe/Sortino/Calmar ratios, beta, and max drawdown
-//! as standalone metrics — independent of the backtesting engine.
+//! as standalone metrics — independent of the backtesting engine — plus
+//! time- and money-weighted portfolio returns that account for deposits and
+//! withdrawals.
 //!
 //! # Quick Start
 //!
@@ -25,11 +27,16 @@
 
 mod beta;
 mod drawdown;
+mod performance;
 mod ratios;
 mod var;
 
 pub use self::beta::beta;
 pub use self::drawdown::max_drawdown;
+pub use self::performance::{
+    CashFlow, PeriodReturn, ReturnPeriod, Valuation, money_weighted_return, period_returns,
+    time_weighted_return,
+};
 pub use self::ratios::{calmar_ratio, sharpe_ratio, sortino_ratio};
 pub use self::var::{historical_var, parametric_var};
 
@@ -118,10 +125,14 @@ pub(crate) fn periods_per_year(interval: crate::Interval, cal: TradingCalendar)
         Interval::OneWeek => 52.0,
         Interval::OneMonth => 12.0,
         Interval::ThreeMonths => 4.0,
+        // Session-anchored bars: a trailing partial bar still counts
+        Interval::FourHours => days * (cal.session_hours() / 4.0).ceil(),
+        Interval::NinetyMinutes => days * (cal.session_hours() / 1.5).ceil(),
         Interval::OneHour => days * cal.session_hours(),
         Interval::ThirtyMinutes => days * cal.session_hours() * 2.0,
         Interval::FifteenMinutes => days * cal.session_hours() * 4.0,
         Interval::FiveMinutes => days * cal.session_hours() * 12.0,
+        Interval::TwoMinutes => days * cal.session_hours() * 30.0,
         Interval::OneMinute => days * cal.session_hours() * 60.0,
     }
 }
@@ -233,6 +244,11 @@ mod tests {
             periods_per_year(Interval::OneHour, TradingCalendar::Crypto)
                 > periods_per_year(Interval::OneHour, TradingCalendar::Exchange)
         );
+        // 6.5h sessions yield two 4h bars (one partial) per day
+        assert_eq!(
+            periods_per_year(Interval::FourHours, TradingCalendar::Exchange),
+            504.0
+        );
     }
 
     #[test]
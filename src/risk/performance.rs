@@ -0,0 +1,443 @@
+//! Time- and money-weighted portfolio returns.
+//!
+//! A simple start-to-end return is misleading once money moves in or out: a
+//! deposit looks like a gain and a withdrawal like a loss. Both measures here
+//! take the portfolio's market value over time plus the external cash flows.
+//!
+//! - **Time-weighted return (TWR)** chains the returns between valuations with
+//!   each flow removed, so it measures the investments regardless of when or
+//!   how much money was added. This is the figure to compare against a benchmark.
+//! - **Money-weighted return (MWR)** is the internal rate of return of the
+//!   flows, so it also rewards or penalises the timing of deposits and
+//!   withdrawals. This is the return the investor actually experienced.
+//!
+//! A flow is assumed to land at the end of its day: its amount is already part
+//! of the first valuation at or after its timestamp.
+
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+const SECONDS_PER_YEAR: f64 = 365.25 * 86_400.0;
+
+/// Market value of a portfolio at a point in time.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Valuation {
+    /// Unix timestamp (seconds)
+    pub timestamp: i64,
+    /// Total market value, including any flow on this timestamp
+    pub value: f64,
+}
+
+/// External money moved into or out of a portfolio.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CashFlow {
+    /// Unix timestamp (seconds)
+    pub timestamp: i64,
+    /// Positive for deposits, negative for withdrawals
+    pub amount: f64,
+}
+
+/// Cumulative time-weighted return over the span of `valuations`.
+///
+/// Each sub-period return is `(value - flows) / previous_value - 1`, where
+/// `flows` sums the cash flows after the previous valuation up to and
+/// including this one; sub-period returns are then compounded. Sub-periods
+/// starting from a non-positive value (e.g. before the first deposit) are
+/// skipped. Flows outside the span of `valuations` are ignored.
+///
+/// Returns `None` with fewer than 2 valuations or no measurable sub-period.
+///
+/// # Example
+///
+/// ```
+/// use finance_query::risk::{CashFlow, Valuation, time_weighted_return};
+///
+/// let day = 86_400;
+/// let valuations = [
+///     Valuation { timestamp: 0, value: 100.0 },
+///     Valuation { timestamp: day, value: 110.0 },       // +10%
+///     Valuation { timestamp: 2 * day, value: 1_110.0 }, // flat after a 1,000 deposit
+/// ];
+/// let flows = [CashFlow { timestamp: 2 * day, amount: 1_000.0 }];
+/// let twr = time_weighted_return(&valuations, &flows).unwrap();
+/// assert!((twr - 0.10).abs() < 1e-12);
+/// ```
+pub fn time_weighted_return(valuations: &[Valuation], flows: &[CashFlow]) -> Option<f64> {
+    let valuations = sorted(valuations);
+    let mut growth = 1.0;
+    let mut measured = false;
+    for pair in valuations.windows(2) {
+        let (prev, cur) = (pair[0], pair[1]);
+        if prev.value <= 0.0 {
+            continue;
+        }
+        let net_flow = flows_between(flows, prev.timestamp, cur.timestamp);
+        growth *= (cur.value - net_flow) / prev.value;
+        measured = true;
+    }
+    measured.then_some(growth - 1.0)
+}
+
+/// Annualised money-weighted return (internal rate of return) over the span
+/// of `valuations`.
+///
+/// The opening value is treated as an initial deposit and the closing value as
+/// a final withdrawal, with each flow in between discounted by its actual
+/// date (the XIRR convention). Flows outside the span are ignored.
+///
+/// Returns `None` with fewer than 2 valuations, a zero-length span, or when no
+/// rate balances the flows.
+///
+/// # Example
+///
+/// ```
+/// use finance_query::risk::{Valuation, money_weighted_return};
+///
+/// let year = 31_557_600; // 365.25 days
+/// let valuations = [
+///     Valuation { timestamp: 0, value: 100.0 },
+///     Valuation { timestamp: year, value: 108.0 },
+/// ];
+/// let irr = money_weighted_return(&valuations, &[]).unwrap();
+/// assert!((irr - 0.08).abs() < 1e-9);
+/// ```
+pub fn money_weighted_return(valuations: &[Valuation], flows: &[CashFlow]) -> Option<f64> {
+    let valuations = sorted(valuations);
+    let (first, last) = (*valuations.first()?, *valuations.last()?);
+    if last.timestamp <= first.timestamp {
+        return None;
+    }
+
+    // Investor's view: money paid in is negative, money received is positive
+    let mut schedule = vec![(0.0, -first.value)];
+    schedule.extend(
+        flows
+            .iter()
+            .filter(|f| f.timestamp > first.timestamp && f.timestamp <= last.timestamp)
+            .map(|f| (years_between(first.timestamp, f.timestamp), -f.amount)),
+    );
+    schedule.push((years_between(first.timestamp, last.timestamp), last.value));
+
+    irr(&schedule)
+}
+
+/// Standard reporting period for [`period_returns`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum ReturnPeriod {
+    /// Since the end of last month
+    MonthToDate,
+    /// Since the end of last quarter
+    QuarterToDate,
+    /// Since the end of last year
+    YearToDate,
+    /// Trailing twelve months
+    OneYear,
+    /// Since the first valuation
+    SinceInception,
+}
+
+impl ReturnPeriod {
+    /// All periods, shortest first.
+    pub const ALL: [ReturnPeriod; 5] = [
+        ReturnPeriod::MonthToDate,
+        ReturnPeriod::QuarterToDate,
+        ReturnPeriod::YearToDate,
+        ReturnPeriod::OneYear,
+        ReturnPeriod::SinceInception,
+    ];
+
+    /// Start of the period ending at `as_of` (Unix seconds, UTC calendar).
+    ///
+    /// Returns `None` for [`SinceInception`](Self::SinceInception), whose
+    /// start depends on the data.
+    pub fn start(self, as_of: i64) -> Option<i64> {
+        let as_of = DateTime::<Utc>::from_timestamp(as_of, 0)?;
+        let date = as_of.date_naive();
+        let start = match self {
+            ReturnPeriod::MonthToDate => NaiveDate::from_ymd_opt(date.year(), date.month(), 1)?,
+            ReturnPeriod::QuarterToDate => {
+                let month = (date.month0() / 3) * 3 + 1;
+                NaiveDate::from_ymd_opt(date.year(), month, 1)?
+            }
+            ReturnPeriod::YearToDate => NaiveDate::from_ymd_opt(date.year(), 1, 1)?,
+            ReturnPeriod::OneYear => {
+                return as_of
+                    .checked_sub_months(Months::new(12))
+                    .map(|d| d.timestamp());
+            }
+            ReturnPeriod::SinceInception => return None,
+        };
+        Some(start.and_hms_opt(0, 0, 0)?.and_utc().timestamp())
+    }
+}
+
+/// Time- and money-weighted return over one [`ReturnPeriod`].
+///
+/// Both returns are cumulative over the period, not annualised; see
+/// [`annualized_twr`](Self::annualized_twr) and
+/// [`annualized_mwr`](Self::annualized_mwr) for periods of a year or more.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PeriodReturn {
+    /// Reporting period
+    pub period: ReturnPeriod,
+    /// Timestamp of the opening valuation (the last one at or before the period start)
+    pub start: i64,
+    /// Timestamp of the closing valuation
+    pub end: i64,
+    /// Cumulative time-weighted return
+    pub twr: Option<f64>,
+    /// Cumulative money-weighted return
+    pub mwr: Option<f64>,
+}
+
+impl PeriodReturn {
+    /// Length of the period in years.
+    pub fn years(&self) -> f64 {
+        years_between(self.start, self.end)
+    }
+
+    /// TWR expressed per year, or `None` for periods shorter than a year
+    /// (annualising a partial year overstates it).
+    pub fn annualized_twr(&self) -> Option<f64> {
+        annualize(self.twr?, self.years())
+    }
+
+    /// MWR expressed per year, or `None` for periods shorter than a year.
+    pub fn annualized_mwr(&self) -> Option<f64> {
+        annualize(self.mwr?, self.years())
+    }
+}
+
+/// TWR and MWR over each standard period ending at the last valuation.
+///
+/// A period is included only when the history reaches back to its start;
+/// for example [`ReturnPeriod::OneYear`] is omitted for a portfolio opened
+/// six months ago. [`ReturnPeriod::SinceInception`] is included whenever there
+/// are at least 2 valuations.
+///
+/// # Example
+///
+/// ```
+/// use finance_query::risk::{CashFlow, Valuation, period_returns};
+///
+/// let day = 86_400;
+/// // 500 deposited on day 200
+/// let valuations: Vec<Valuation> = (0..400)
+///     .map(|i| Valuation {
+///         timestamp: i * day,
+///         value: 1_000.0 + i as f64 + if i >= 200 { 500.0 } else { 0.0 },
+///     })
+///     .collect();
+/// let flows = [CashFlow { timestamp: 200 * day, amount: 500.0 }];
+///
+/// for r in period_returns(&valuations, &flows) {
+///     println!("{:?}: TWR {:?} MWR {:?}", r.period, r.twr, r.mwr);
+/// }
+/// ```
+pub fn period_returns(valuations: &[Valuation], flows: &[CashFlow]) -> Vec<PeriodReturn> {
+    let valuations = sorted(valuations);
+    let Some(last) = valuations.last().copied() else {
+        return Vec::new();
+    };
+
+    ReturnPeriod::ALL
+        .into_iter()
+        .filter_map(|period| {
+            let from = match period.start(last.timestamp) {
+                Some(start) => valuations.iter().rposition(|v| v.timestamp <= start)?,
+                None if period == ReturnPeriod::SinceInception => 0,
+                None => return None,
+            };
+            let window = &valuations[from..];
+            if window.len() < 2 {
+                return None;
+            }
+            let start = window[0].timestamp;
+            let mwr = money_weighted_return(window, flows)
+                .map(|irr| (1.0 + irr).powf(years_between(start, last.timestamp)) - 1.0);
+            Some(PeriodReturn {
+                period,
+                start,
+                end: last.timestamp,
+                twr: time_weighted_return(window, flows),
+                mwr,
+            })
+        })
+        .collect()
+}
+
+fn sorted(valuations: &[Valuation]) -> Vec<Valuation> {
+    let mut sorted = valuations.to_vec();
+    sorted.sort_by_key(|v| v.timestamp);
+    sorted
+}
+
+/// Net flow in `(after, through]`.
+fn flows_between(flows: &[CashFlow], after: i64, through: i64) -> f64 {
+    flows
+        .iter()
+        .filter(|f| f.timestamp > after && f.timestamp <= through)
+        .map(|f| f.amount)
+        .sum()
+}
+
+fn years_between(from: i64, to: i64) -> f64 {
+    (to - from) as f64 / SECONDS_PER_YEAR
+}
+
+fn annualize(cumulative: f64, years: f64) -> Option<f64> {
+    (years >= 1.0).then(|| (1.0 + cumulative).powf(1.0 / years) - 1.0)
+}
+
+/// Rate `r` at which `sum(amount / (1 + r)^years) == 0`, by bisection.
+fn irr(schedule: &[(f64, f64)]) -> Option<f64> {
+    let npv = |rate: f64| -> f64 {
+        schedule
+            .iter()
+            .map(|(years, amount)| amount / (1.0 + rate).powf(*years))
+            .sum()
+    };
+
+    let mut lo = -0.999_999;
+    let mut hi = 1.0;
+    let npv_lo = npv(lo);
+    while npv_lo.signum() == npv(hi).signum() {
+        hi *= 2.0;
+        if hi > 1e6 {
+            return None;
+        }
+    }
+
+    for _ in 0..200 {
+        let mid = (lo + hi) / 2.0;
+        let value = npv(mid);
+        if value == 0.0 || (hi - lo) < 1e-12 {
+            return Some(mid);
+        }
+        if value.signum() == npv_lo.signum() {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Some((lo + hi) / 2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86_400;
+
+    fn v(day: i64, value: f64) -> Valuation {
+        Valuation {
+            timestamp: day * DAY,
+            value,
+        }
+    }
+
+    fn f(day: i64, amount: f64) -> CashFlow {
+        CashFlow {
+            timestamp: day * DAY,
+            amount,
+        }
+    }
+
+    #[test]
+    fn test_twr_removes_flows() {
+        // +10%, then a 500 withdrawal, then +10%
+        let valuations = [v(0, 1_000.0), v(1, 1_100.0), v(2, 600.0), v(3, 660.0)];
+        let flows = [f(2, -500.0)];
+        let twr = time_weighted_return(&valuations, &flows).unwrap();
+        assert!((twr - 0.21).abs() < 1e-12, "got {twr}");
+    }
+
+    #[test]
+    fn test_twr_skips_empty_start() {
+        // Portfolio funded on day 1, then gains 5%
+        let valuations = [v(0, 0.0), v(1, 1_000.0), v(2, 1_050.0)];
+        let flows = [f(1, 1_000.0)];
+        let twr = time_weighted_return(&valuations, &flows).unwrap();
+        assert!((twr - 0.05).abs() < 1e-12);
+
+        assert!(time_weighted_return(&[v(0, 1.0)], &[]).is_none());
+        assert!(time_weighted_return(&[v(0, 0.0), v(1, 0.0)], &[]).is_none());
+    }
+
+    #[test]
+    fn test_mwr_rewards_timing() {
+        // Fund doubles in the second half of the year; money added just
+        // before the rally earns a higher MWR than TWR would suggest
+        let half = 183;
+        let year = 365;
+        let valuations = [v(0, 100.0), v(half, 1_100.0), v(year, 2_200.0)];
+        let flows = [f(half, 1_000.0)];
+        let twr = time_weighted_return(&valuations, &flows).unwrap();
+        let mwr = money_weighted_return(&valuations, &flows).unwrap();
+        assert!((twr - 1.0).abs() < 1e-12);
+        assert!(mwr > twr, "mwr {mwr} should exceed twr {twr}");
+
+        // NPV at the solved rate is ~0
+        let years = |d: i64| (d * DAY) as f64 / SECONDS_PER_YEAR;
+        let npv = -100.0 - 1_000.0 / (1.0 + mwr).powf(years(half))
+            + 2_200.0 / (1.0 + mwr).powf(years(year));
+        assert!(npv.abs() < 1e-6, "npv {npv}");
+    }
+
+    #[test]
+    fn test_mwr_without_flows_matches_cagr() {
+        let valuations = [v(0, 100.0), v(730, 121.0)];
+        let mwr = money_weighted_return(&valuations, &[]).unwrap();
+        let years = (730 * DAY) as f64 / SECONDS_PER_YEAR;
+        let cagr = 1.21_f64.powf(1.0 / years) - 1.0;
+        assert!((mwr - cagr).abs() < 1e-9);
+        assert!(money_weighted_return(&[v(0, 100.0)], &[]).is_none());
+    }
+
+    #[test]
+    fn test_period_starts() {
+        // 2024-05-15 12:00 UTC
+        let as_of = 1_715_774_400;
+        let date = |ts: i64| {
+            DateTime::<Utc>::from_timestamp(ts, 0)
+                .unwrap()
+                .format("%Y-%m-%d")
+                .to_string()
+        };
+        let start = |p: ReturnPeriod| date(p.start(as_of).unwrap());
+        assert_eq!(start(ReturnPeriod::MonthToDate), "2024-05-01");
+        assert_eq!(start(ReturnPeriod::QuarterToDate), "2024-04-01");
+        assert_eq!(start(ReturnPeriod::YearToDate), "2024-01-01");
+        assert_eq!(start(ReturnPeriod::OneYear), "2023-05-15");
+        assert_eq!(ReturnPeriod::SinceInception.start(as_of), None);
+    }
+
+    #[test]
+    fn test_period_returns_windows() {
+        // Daily valuations from 2024-01-01 through 2024-05-15, +0.1% per day
+        let jan1 = 1_704_067_200 / DAY;
+        let valuations: Vec<Valuation> = (0..=135)
+            .map(|i| v(jan1 + i, 1_000.0 * 1.001_f64.powi(i as i32)))
+            .collect();
+        let returns = period_returns(&valuations, &[]);
+        let periods: Vec<ReturnPeriod> = returns.iter().map(|r| r.period).collect();
+        // No valuation at or before Jan 1 00:00 of a year earlier
+        assert!(!periods.contains(&ReturnPeriod::OneYear));
+        assert!(periods.contains(&ReturnPeriod::MonthToDate));
+        assert!(periods.contains(&ReturnPeriod::SinceInception));
+
+        let mtd = &returns[0];
+        assert_eq!(mtd.period, ReturnPeriod::MonthToDate);
+        // Opening value is the May 1 00:00 valuation; 14 days to May 15
+        let expected = 1.001_f64.powi(14) - 1.0;
+        assert!((mtd.twr.unwrap() - expected).abs() < 1e-12);
+        assert!((mtd.mwr.unwrap() - expected).abs() < 1e-9);
+        assert!(mtd.annualized_twr().is_none());
+
+        assert!(period_returns(&valuations[..1], &[]).is_empty());
+    }
+}
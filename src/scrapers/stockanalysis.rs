@@ -163,7 +163,7 @@ pub(crate) async fn scrape_symbol_news(symbol: &str) -> Result<Vec<News>> {
     for url in urls {
         info!("Trying URL: {}", url);
 
-        match client.get(&url).send().await {
+        match crate::telemetry::track(&url, client.get(&url).send().await) {
             Ok(response) if response.status().is_success() => {
                 let html = response.text().await?;
 
@@ -191,7 +191,7 @@ pub(crate) async fn scrape_general_news() -> Result<Vec<News>> {
 
     let client = build_client()?;
 
-    let response = client.get(url).send().await?;
+    let response = crate::telemetry::track(url, client.get(url).send().await)?;
 
     if !response.status().is_success() {
         return Err(FinanceError::ServerError {
@@ -62,7 +62,7 @@ pub(crate) async fn scrape_earnings_calls(symbol: &str) -> Result<Vec<EarningsCa
         .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36")
         .build()?;
 
-    let response = client.get(&url).send().await?;
+    let response = crate::telemetry::track(&url, client.get(&url).send().await)?;
 
     if response.status() == reqwest::StatusCode::NOT_FOUND {
         return Err(FinanceError::SymbolNotFound {
@@ -20,7 +20,7 @@ pub async fn scrape_exchanges() -> Result<Vec<Exchange>> {
         .user_agent("Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36")
         .build()?;
 
-    let response = client.get(EXCHANGES_URL).send().await?;
+    let response = crate::telemetry::track(EXCHANGES_URL, client.get(EXCHANGES_URL).send().await)?;
 
     if !response.status().is_success() {
         return Err(FinanceError::ServerError {
@@ -0,0 +1,315 @@
+//! Saved screeners that remember their last results.
+//!
+//! A [`SavedScreener`] pairs a predefined screener or custom query with the
+//! results of its last run, and serializes both to a JSON file. Each
+//! [`run`](SavedScreener::run) reports which symbols were added or removed
+//! since the previous run, e.g. "new 52-week highs today".
+//!
+//! # Example
+//!
+//! ```no_run
+//! use finance_query::screeners::SavedScreener;
+//! use finance_query::{EquityField, EquityScreenerQuery, ScreenerFieldExt};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let query = EquityScreenerQuery::new()
+//!     .size(100)
+//!     .add_condition(EquityField::Region.eq_str("us"))
+//!     .add_condition(EquityField::FiftyTwoWkPctChange.gte(50.0));
+//!
+//! // Run daily; the first run only records a baseline
+//! let path = "data/52wk-leaders.json";
+//! let mut screen = SavedScreener::load_or_new(path, "52-week leaders", query)?;
+//! let diff = screen.run().await?;
+//! for quote in &diff.added {
+//!     println!("new: {}", quote.symbol);
+//! }
+//! screen.save(path)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::Path;
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::adapters::yahoo::client::{ClientConfig, YahooClient};
+use crate::constants::screeners::Screener;
+use crate::error::Result;
+use crate::finance;
+use crate::models::discovery::screeners::{
+    ScreenerField, ScreenerQuery, ScreenerQuote, ScreenerResults,
+};
+
+/// The screener a [`SavedScreener`] runs.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum SavedQuery {
+    /// A predefined Yahoo screener
+    Predefined {
+        /// Screener to run
+        screener: Screener,
+        /// Number of results to fetch (max 250)
+        count: u32,
+    },
+    /// A custom query, stored in the request format Yahoo expects
+    Custom {
+        /// Serialized [`ScreenerQuery`]
+        query: serde_json::Value,
+    },
+}
+
+impl SavedQuery {
+    /// Run a predefined screener, keeping its top `count` results.
+    pub fn predefined(screener: Screener, count: u32) -> Self {
+        Self::Predefined { screener, count }
+    }
+}
+
+impl From<Screener> for SavedQuery {
+    /// Run a predefined screener, keeping its top 25 results.
+    fn from(screener: Screener) -> Self {
+        Self::predefined(screener, 25)
+    }
+}
+
+impl<F: ScreenerField> From<ScreenerQuery<F>> for SavedQuery {
+    fn from(query: ScreenerQuery<F>) -> Self {
+        Self::Custom {
+            query: serde_json::to_value(&query).expect("screener queries always serialize"),
+        }
+    }
+}
+
+/// Changes between two runs of a [`SavedScreener`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScreenerDiff {
+    /// Quotes that were not in the previous run, in their new rank order
+    pub added: Vec<ScreenerQuote>,
+    /// Symbols from the previous run that are no longer present, in their
+    /// previous rank order
+    pub removed: Vec<String>,
+    /// Whether this was the first run, which only records a baseline and
+    /// reports no changes
+    pub baseline: bool,
+}
+
+impl ScreenerDiff {
+    /// Whether nothing was added or removed
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+
+    /// Symbols of the added quotes, in rank order
+    pub fn added_symbols(&self) -> Vec<&str> {
+        self.added.iter().map(|q| q.symbol.as_str()).collect()
+    }
+}
+
+/// A named screener with the results of its last run.
+///
+/// Serializes to JSON with [`save`](Self::save); read it back with
+/// [`load`](Self::load) or [`load_or_new`](Self::load_or_new).
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedScreener {
+    /// Screener name
+    pub name: String,
+    /// What to run
+    pub query: SavedQuery,
+    /// Results of the last run, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_results: Option<ScreenerResults>,
+    /// When the last run happened (Unix)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_run: Option<i64>,
+}
+
+impl SavedScreener {
+    /// Create a screener that has never run.
+    pub fn new(name: impl Into<String>, query: impl Into<SavedQuery>) -> Self {
+        Self {
+            name: name.into(),
+            query: query.into(),
+            last_results: None,
+            last_run: None,
+        }
+    }
+
+    /// Read a screener written by [`save`](Self::save).
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+    }
+
+    /// Read the screener at `path`, or create a new one if the file does not
+    /// exist.
+    ///
+    /// If the saved query differs from `query`, the saved results are
+    /// dropped so the next run records a fresh baseline instead of diffing
+    /// two different screens.
+    pub fn load_or_new(
+        path: impl AsRef<Path>,
+        name: impl Into<String>,
+        query: impl Into<SavedQuery>,
+    ) -> Result<Self> {
+        let fresh = Self::new(name, query);
+        match std::fs::read(path.as_ref()) {
+            Ok(bytes) => {
+                let saved: Self = serde_json::from_slice(&bytes)?;
+                Ok(if saved.query == fresh.query {
+                    Self {
+                        name: fresh.name,
+                        ..saved
+                    }
+                } else {
+                    fresh
+                })
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(fresh),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Write the screener and its last results to `path` as JSON, creating
+    /// parent directories as needed.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Write to a temporary file and rename so a crash never leaves a partial file
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, serde_json::to_vec_pretty(self)?)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    /// Run the screener and return the changes since the last run.
+    ///
+    /// Call [`save`](Self::save) afterwards to keep the new results for the
+    /// next run.
+    pub async fn run(&mut self) -> Result<ScreenerDiff> {
+        let results = match &self.query {
+            SavedQuery::Predefined { screener, count } => {
+                finance::screener(*screener, *count).await?
+            }
+            SavedQuery::Custom { query } => {
+                let client = YahooClient::new(ClientConfig::default()).await?;
+                crate::adapters::yahoo::discovery::screeners::fetch_custom_json(&client, query)
+                    .await?
+            }
+        };
+        Ok(self.record(results))
+    }
+
+    /// Compare `results` against the last run and store them as the new last
+    /// run. Symbols are compared case-insensitively.
+    pub fn record(&mut self, results: ScreenerResults) -> ScreenerDiff {
+        let diff = match &self.last_results {
+            None => ScreenerDiff {
+                baseline: true,
+                ..Default::default()
+            },
+            Some(previous) => {
+                let contains = |results: &ScreenerResults, symbol: &str| {
+                    results
+                        .quotes
+                        .iter()
+                        .any(|q| q.symbol.eq_ignore_ascii_case(symbol))
+                };
+                let mut added: Vec<ScreenerQuote> = Vec::new();
+                for quote in &results.quotes {
+                    if !contains(previous, &quote.symbol)
+                        && !added
+                            .iter()
+                            .any(|q| q.symbol.eq_ignore_ascii_case(&quote.symbol))
+                    {
+                        added.push(quote.clone());
+                    }
+                }
+                let mut removed: Vec<String> = Vec::new();
+                for quote in &previous.quotes {
+                    if !contains(&results, &quote.symbol)
+                        && !removed
+                            .iter()
+                            .any(|s| s.eq_ignore_ascii_case(&quote.symbol))
+                    {
+                        removed.push(quote.symbol.clone());
+                    }
+                }
+                ScreenerDiff {
+                    added,
+                    removed,
+                    baseline: false,
+                }
+            }
+        };
+        self.last_results = Some(results);
+        self.last_run = Some(Utc::now().timestamp());
+        diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::discovery::screeners::{EquityField, EquityScreenerQuery, ScreenerFieldExt};
+
+    fn results(symbols: &[&str]) -> ScreenerResults {
+        serde_json::from_value(serde_json::json!({
+            "type": "custom",
+            "quotes": symbols
+                .iter()
+                .map(|s| serde_json::json!({
+                    "symbol": s,
+                    "quoteType": "EQUITY",
+                    "exchange": "NYQ",
+                    "regularMarketPrice": {"raw": 10.0},
+                    "regularMarketChange": {"raw": 0.0},
+                    "regularMarketChangePercent": {"raw": 0.0}
+                }))
+                .collect::<Vec<_>>()
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_record_diffs_against_last_run() {
+        let mut screen = SavedScreener::new("highs", Screener::DayGainers);
+        let diff = screen.record(results(&["AAPL", "MSFT"]));
+        assert!(diff.baseline);
+        assert!(diff.is_empty());
+
+        let diff = screen.record(results(&["msft", "NVDA", "TSLA"]));
+        assert!(!diff.baseline);
+        assert_eq!(diff.added_symbols(), ["NVDA", "TSLA"]);
+        assert_eq!(diff.removed, ["AAPL"]);
+        assert!(screen.record(results(&["MSFT", "NVDA", "TSLA"])).is_empty());
+    }
+
+    #[test]
+    fn test_save_and_load_or_new() {
+        let dir = std::env::temp_dir().join(format!("fq-saved-screener-{}", std::process::id()));
+        let path = dir.join("highs.json");
+        let query = || EquityScreenerQuery::new().add_condition(EquityField::Region.eq_str("us"));
+
+        let mut screen = SavedScreener::load_or_new(&path, "highs", query()).unwrap();
+        assert!(screen.last_results.is_none());
+        screen.record(results(&["AAPL"]));
+        screen.save(&path).unwrap();
+
+        let mut reloaded = SavedScreener::load_or_new(&path, "highs", query()).unwrap();
+        assert_eq!(reloaded, screen);
+        assert_eq!(reloaded.record(results(&["MSFT"])).removed, ["AAPL"]);
+
+        // A different query starts over
+        let changed = SavedScreener::load_or_new(&path, "highs", Screener::DayGainers).unwrap();
+        assert!(changed.last_results.is_none());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
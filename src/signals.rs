@@ -0,0 +1,410 @@
+//! Live signal evaluation with webhook delivery (requires `signals` feature).
+//!
+//! A [`SignalRunner`] takes a saved [`StrategyDefinition`], re-runs it
+//! against fresh chart data on a schedule, and reports the entry and exit
+//! signals generated on the most recent bar. Each new signal is POSTed as
+//! JSON to a [`SignalWebhook`], which bridges research strategies to an
+//! external execution system without a broker integration.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use finance_query::backtesting::StrategyDefinition;
+//! use finance_query::signals::{SignalRunner, SignalWebhook};
+//! use std::time::Duration;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let strategy = StrategyDefinition::from_file("strategies/rsi.json")?;
+//! let webhook = SignalWebhook::new("https://example.com/hooks/signals").with_secret("s3cret");
+//!
+//! let mut runner = SignalRunner::new(strategy, ["AAPL", "MSFT"]).webhook(webhook);
+//! runner.run(Duration::from_secs(15 * 60)).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Payload
+//!
+//! Each [`SignalEvent`] is sent as a JSON object. When a secret is set, the
+//! request carries two headers:
+//!
+//! - `X-FQ-Timestamp`: Unix seconds when the request was signed
+//! - `X-FQ-Signature`: `sha256=<hex>`, the HMAC-SHA256 of
+//!   `"<timestamp>.<body>"` keyed by the secret
+//!
+//! Receivers check it with [`verify_signature`] and should reject stale
+//! timestamps to prevent replays.
+
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tracing::{info, warn};
+
+use crate::backtesting::{
+    BacktestConfig, BacktestEngine, BacktestError, SignalDirection, StrategyDefinition,
+};
+use crate::constants::{Interval, TimeRange};
+use crate::error::{FinanceError, Result};
+use crate::models::chart::Candle;
+use crate::ticker::Ticker;
+
+/// Header carrying the Unix timestamp the payload was signed at.
+pub const TIMESTAMP_HEADER: &str = "X-FQ-Timestamp";
+
+/// Header carrying the `sha256=<hex>` payload signature.
+pub const SIGNATURE_HEADER: &str = "X-FQ-Signature";
+
+/// A signal generated on the latest bar of a symbol.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignalEvent {
+    /// Strategy name
+    pub strategy: String,
+    /// Symbol the signal applies to
+    pub symbol: String,
+    /// Signal direction
+    pub direction: SignalDirection,
+    /// Close price of the signalling bar
+    pub price: f64,
+    /// Timestamp of the signalling bar (Unix)
+    pub timestamp: i64,
+    /// Signal strength (0.0-1.0)
+    pub strength: f64,
+    /// Signal reason/description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Tags copied from the strategy signal
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+/// Webhook endpoint that receives [`SignalEvent`]s.
+#[derive(Debug, Clone)]
+pub struct SignalWebhook {
+    url: String,
+    secret: Option<String>,
+    client: reqwest::Client,
+}
+
+impl SignalWebhook {
+    /// Create a webhook posting to `url`, without signatures.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            secret: None,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Sign every payload with `secret`.
+    pub fn with_secret(mut self, secret: impl Into<String>) -> Self {
+        self.secret = Some(secret.into());
+        self
+    }
+
+    /// URL the webhook posts to.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// POST `event` to the webhook.
+    ///
+    /// Returns [`FinanceError::ExternalApiError`] when the endpoint answers
+    /// with a non-2xx status.
+    pub async fn send(&self, event: &SignalEvent) -> Result<()> {
+        let body = serde_json::to_string(event)?;
+        let mut request = self
+            .client
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json");
+        if let Some(secret) = &self.secret {
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+            request = request
+                .header(TIMESTAMP_HEADER, timestamp.to_string())
+                .header(SIGNATURE_HEADER, sign(secret, timestamp, &body));
+        }
+
+        let status = crate::telemetry::track(&self.url, request.body(body).send().await)?.status();
+        if !status.is_success() {
+            return Err(FinanceError::ExternalApiError {
+                api: "webhook".to_string(),
+                status: status.as_u16(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Check a `X-FQ-Signature` header against the received body.
+///
+/// `timestamp` is the `X-FQ-Timestamp` header value. The comparison runs in
+/// constant time.
+pub fn verify_signature(secret: &str, timestamp: u64, body: &str, signature: &str) -> bool {
+    let expected = sign(secret, timestamp, body);
+    expected.len() == signature.len()
+        && expected
+            .bytes()
+            .zip(signature.bytes())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            == 0
+}
+
+fn sign(secret: &str, timestamp: u64, body: &str) -> String {
+    let mac = hmac_sha256(secret.as_bytes(), format!("{timestamp}.{body}").as_bytes());
+    format!("sha256={}", hex(&mac))
+}
+
+/// HMAC-SHA256 (RFC 2104).
+fn hmac_sha256(key: &[u8], message: &[u8]) -> [u8; 32] {
+    const BLOCK_SIZE: usize = 64;
+
+    let mut block = [0u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut inner = Sha256::new();
+    inner.update(block.map(|b| b ^ 0x36));
+    inner.update(message);
+    let mut outer = Sha256::new();
+    outer.update(block.map(|b| b ^ 0x5c));
+    outer.update(inner.finalize());
+    outer.finalize().into()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Evaluates a strategy against fresh data and emits new signals.
+///
+/// Each evaluation backtests the strategy over the configured history and
+/// keeps the non-hold signals generated on the last bar. A bar is reported
+/// at most once per symbol, so polling more often than the chart interval
+/// does not repeat signals.
+#[derive(Debug)]
+pub struct SignalRunner {
+    definition: StrategyDefinition,
+    symbols: Vec<String>,
+    interval: Interval,
+    range: TimeRange,
+    webhook: Option<SignalWebhook>,
+    last_bar: HashMap<String, i64>,
+}
+
+impl SignalRunner {
+    /// Create a runner for `definition` over `symbols`.
+    ///
+    /// Defaults to daily bars over one year of history.
+    pub fn new<S, I>(definition: StrategyDefinition, symbols: I) -> Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = S>,
+    {
+        Self {
+            definition,
+            symbols: symbols
+                .into_iter()
+                .map(|s| s.into().trim().to_uppercase())
+                .filter(|s| !s.is_empty())
+                .collect(),
+            interval: Interval::OneDay,
+            range: TimeRange::OneYear,
+            webhook: None,
+            last_bar: HashMap::new(),
+        }
+    }
+
+    /// Set the bar interval (default: daily).
+    pub fn interval(mut self, interval: Interval) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Set how much history to evaluate over (default: one year).
+    ///
+    /// Must cover the strategy's indicator warmup.
+    pub fn range(mut self, range: TimeRange) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Deliver signals to `webhook`.
+    pub fn webhook(mut self, webhook: SignalWebhook) -> Self {
+        self.webhook = Some(webhook);
+        self
+    }
+
+    /// Symbols being evaluated.
+    pub fn symbols(&self) -> &[String] {
+        &self.symbols
+    }
+
+    /// Fetch fresh charts, evaluate every symbol, and deliver new signals.
+    ///
+    /// Symbols whose chart or evaluation fails are logged and skipped, as
+    /// are failed webhook deliveries. Returns the new signals.
+    pub async fn poll(&mut self) -> Result<Vec<SignalEvent>> {
+        let mut events = Vec::new();
+        for symbol in self.symbols.clone() {
+            let chart = match Ticker::new(symbol.as_str()).await {
+                Ok(ticker) => ticker.chart(self.interval, self.range).await,
+                Err(e) => Err(e),
+            };
+            match chart.and_then(|chart| self.evaluate(&symbol, &chart.candles)) {
+                Ok(new) => events.extend(new),
+                Err(e) => warn!("Signal evaluation failed for {}: {}", symbol, e),
+            }
+        }
+
+        if let Some(webhook) = &self.webhook {
+            for event in &events {
+                if let Err(e) = webhook.send(event).await {
+                    warn!("Failed to deliver signal for {}: {}", event.symbol, e);
+                }
+            }
+        }
+        Ok(events)
+    }
+
+    /// Evaluate the strategy on `candles` and return signals for the last bar.
+    ///
+    /// Returns nothing if that bar was already reported for `symbol`.
+    /// Useful with data that doesn't come from Yahoo.
+    pub fn evaluate(&mut self, symbol: &str, candles: &[Candle]) -> Result<Vec<SignalEvent>> {
+        let Some(last) = candles.last() else {
+            return Ok(Vec::new());
+        };
+        if self.last_bar.get(symbol) == Some(&last.timestamp) {
+            return Ok(Vec::new());
+        }
+
+        let strategy = self.definition.build().map_err(backtest_error)?;
+        let config = BacktestConfig::builder()
+            .close_at_end(false)
+            .build()
+            .map_err(backtest_error)?;
+        let result = BacktestEngine::new(config)
+            .run(symbol, candles, strategy)
+            .map_err(backtest_error)?;
+        self.last_bar.insert(symbol.to_string(), last.timestamp);
+
+        Ok(result
+            .signals
+            .into_iter()
+            .filter(|s| s.timestamp == last.timestamp && s.direction != SignalDirection::Hold)
+            .map(|s| SignalEvent {
+                strategy: self.definition.name.clone(),
+                symbol: symbol.to_string(),
+                direction: s.direction,
+                price: s.price,
+                timestamp: s.timestamp,
+                strength: s.strength,
+                reason: s.reason,
+                tags: s.tags,
+            })
+            .collect())
+    }
+
+    /// Call [`poll`](Self::poll) every `every` until the task is cancelled.
+    pub async fn run(&mut self, every: Duration) -> Result<()> {
+        loop {
+            let events = self.poll().await?;
+            info!(
+                "Evaluated {} for {} symbols: {} new signals",
+                self.definition.name,
+                self.symbols.len(),
+                events.len()
+            );
+            tokio::time::sleep(every).await;
+        }
+    }
+}
+
+fn backtest_error(e: BacktestError) -> FinanceError {
+    match e {
+        BacktestError::InvalidParameter { param, reason } => {
+            FinanceError::InvalidParameter { param, reason }
+        }
+        e => FinanceError::InternalError(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backtesting::{ConditionDef, RefDef, TargetDef};
+
+    #[test]
+    fn test_hmac_sha256_rfc4231() {
+        let mac = hmac_sha256(b"Jefe", b"what do ya want for nothing?");
+        assert_eq!(
+            hex(&mac),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let body = r#"{"symbol":"AAPL"}"#;
+        let signature = sign("secret", 1_700_000_000, body);
+        assert!(signature.starts_with("sha256="));
+        assert!(verify_signature("secret", 1_700_000_000, body, &signature));
+        assert!(!verify_signature("other", 1_700_000_000, body, &signature));
+        assert!(!verify_signature("secret", 1_700_000_001, body, &signature));
+        assert!(!verify_signature(
+            "secret",
+            1_700_000_000,
+            body,
+            "sha256=00"
+        ));
+    }
+
+    fn candles(closes: &[f64]) -> Vec<Candle> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| Candle::new(1_700_000_000 + i as i64 * 86_400, c, c, c, c, 1_000))
+            .collect()
+    }
+
+    #[test]
+    fn test_evaluate_reports_last_bar_once() {
+        let price = || RefDef::new("price", &[]);
+        let def = StrategyDefinition::new(
+            "Breakout",
+            ConditionDef::Above {
+                indicator: price(),
+                target: TargetDef::Value(10.0.into()),
+            },
+            ConditionDef::Below {
+                indicator: price(),
+                target: TargetDef::Value(5.0.into()),
+            },
+        );
+        let mut runner = SignalRunner::new(def, [" aapl "]);
+        assert_eq!(runner.symbols(), ["AAPL"]);
+
+        // Breakout happened earlier in the history: nothing new on the last bar
+        let history = candles(&[8.0, 12.0, 13.0]);
+        assert!(runner.evaluate("AAPL", &history).unwrap().is_empty());
+
+        let history = candles(&[8.0, 9.0, 12.0]);
+        let events = runner.evaluate("MSFT", &history).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].direction, SignalDirection::Long);
+        assert_eq!(events[0].timestamp, history[2].timestamp);
+        assert_eq!(events[0].strategy, "Breakout");
+
+        // Same bar polled again is not reported twice
+        assert!(runner.evaluate("MSFT", &history).unwrap().is_empty());
+    }
+}
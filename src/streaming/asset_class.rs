@@ -0,0 +1,70 @@
+//! Broad asset classes shared by price streams and market-hours lookups.
+
+use serde::{Deserialize, Serialize};
+
+/// Broad asset class of a streamed symbol.
+///
+/// Yahoo populates different [`PriceUpdate`](super::PriceUpdate) fields per class: FX pairs carry
+/// no volume, and crypto trades around the clock with its volume reported
+/// over a rolling 24 hours in [`PriceUpdate::vol_24hr`](super::PriceUpdate::vol_24hr).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AssetClass {
+    /// Stocks, ETFs, funds, and warrants (e.g. `AAPL`, `SPY`)
+    Equity,
+    /// Market indices (e.g. `^GSPC`)
+    Index,
+    /// Option contracts
+    Option,
+    /// Futures and commodities (e.g. `GC=F`)
+    Future,
+    /// Currency pairs (e.g. `EURUSD=X`)
+    Fx,
+    /// Cryptocurrencies (e.g. `BTC-USD`)
+    Crypto,
+    /// Anything else, or a symbol whose class could not be determined
+    #[default]
+    Other,
+}
+
+/// Quote currencies recognised in `BASE-QUOTE` crypto symbols.
+const CRYPTO_QUOTE_SUFFIXES: &[&str] = &[
+    "-USD", "-USDT", "-USDC", "-EUR", "-GBP", "-JPY", "-CAD", "-AUD", "-BTC", "-ETH",
+];
+
+impl AssetClass {
+    /// Infer the asset class from Yahoo's symbol conventions.
+    ///
+    /// Used when an update omits its quote type. `EURUSD=X` is FX, `GC=F` a
+    /// future, `^GSPC` an index, and `BTC-USD` crypto; anything else is
+    /// assumed to be an equity.
+    pub fn from_symbol(symbol: &str) -> Self {
+        let upper = symbol.to_ascii_uppercase();
+        if upper.ends_with("=X") {
+            AssetClass::Fx
+        } else if upper.ends_with("=F") {
+            AssetClass::Future
+        } else if upper.starts_with('^') {
+            AssetClass::Index
+        } else if CRYPTO_QUOTE_SUFFIXES.iter().any(|s| upper.ends_with(s)) {
+            AssetClass::Crypto
+        } else {
+            AssetClass::Equity
+        }
+    }
+
+    /// Whether updates for this class report traded volume.
+    ///
+    /// `false` for FX: spot currency trades over the counter, so Yahoo sends
+    /// `0` for every volume field.
+    pub fn has_volume(self) -> bool {
+        !matches!(self, AssetClass::Fx)
+    }
+
+    /// Whether this class trades in a single continuous session with no
+    /// pre-market or post-market periods (FX and crypto).
+    pub fn is_continuous(self) -> bool {
+        matches!(self, AssetClass::Fx | AssetClass::Crypto)
+    }
+}
@@ -7,12 +7,13 @@
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use futures::stream::Stream;
 
+use super::heartbeat::{Heartbeat, HeartbeatConfig, StreamEvent};
 use super::pricing::PriceUpdate;
-use super::source::{StreamCommand, StreamSource, run_stream_loop};
+use super::source::{Backoff, StreamCommand, StreamSource, run_stream_loop};
 use super::subscription::Subscription;
 use super::yahoo::YahooStreamSource;
 use crate::error::FinanceError;
@@ -52,9 +53,24 @@ impl From<StreamError> for FinanceError {
     }
 }
 
-/// Reconnection backoff duration
+/// Delay before the first reconnection attempt
 const RECONNECT_BACKOFF_SECS: u64 = 3;
 
+/// Upper bound on the delay between reconnection attempts
+const MAX_RECONNECT_BACKOFF_SECS: u64 = 60;
+
+/// Growth factor of the reconnection delay per consecutive failure
+const RECONNECT_BACKOFF_MULTIPLIER: f64 = 2.0;
+
+/// Default reconnection schedule: 3s, 6s, 12s, ... up to 60s.
+fn default_backoff() -> Backoff {
+    Backoff {
+        initial: Duration::from_secs(RECONNECT_BACKOFF_SECS),
+        max: Duration::from_secs(MAX_RECONNECT_BACKOFF_SECS),
+        multiplier: RECONNECT_BACKOFF_MULTIPLIER,
+    }
+}
+
 /// Channel capacity for price updates
 const CHANNEL_CAPACITY: usize = 1024;
 
@@ -63,6 +79,11 @@ const CHANNEL_CAPACITY: usize = 1024;
 /// This provides a Flow-like API for receiving real-time price data.
 /// Backed by a pluggable source (Yahoo by default).
 ///
+/// A dropped connection doesn't end the stream: it reconnects with
+/// exponential backoff and resubscribes the current symbol set, reporting
+/// [`StreamEvent::Disconnected`] and [`StreamEvent::Reconnected`] through
+/// [`events`](Self::events). The stream only ends on [`close`](Self::close).
+///
 /// # Example
 ///
 /// ```no_run
@@ -86,6 +107,7 @@ const CHANNEL_CAPACITY: usize = 1024;
 /// ```
 pub struct PriceStream {
     inner: Subscription<PriceUpdate, StreamCommand>,
+    heartbeat: Arc<Heartbeat>,
 }
 
 impl PriceStream {
@@ -113,7 +135,8 @@ impl PriceStream {
         Self::subscribe_with_source(
             Arc::new(YahooStreamSource),
             symbols,
-            Duration::from_secs(RECONNECT_BACKOFF_SECS),
+            default_backoff(),
+            HeartbeatConfig::default(),
         )
         .await
     }
@@ -125,13 +148,16 @@ impl PriceStream {
     pub(crate) async fn subscribe_with_source<S, I>(
         source: Arc<dyn StreamSource>,
         symbols: I,
-        retry_delay: Duration,
+        backoff: Backoff,
+        heartbeat: HeartbeatConfig,
     ) -> StreamResult<Self>
     where
         S: Into<String>,
         I: IntoIterator<Item = S>,
     {
         let initial_symbols: Vec<String> = symbols.into_iter().map(Into::into).collect();
+        let heartbeat = Arc::new(Heartbeat::new(heartbeat));
+        let session_heartbeat = Arc::clone(&heartbeat);
 
         let inner = Subscription::start(
             CHANNEL_CAPACITY,
@@ -142,13 +168,14 @@ impl PriceStream {
                     initial_symbols,
                     broadcast_tx,
                     command_rx,
-                    retry_delay,
+                    backoff,
+                    session_heartbeat,
                 )
                 .await;
             },
         );
 
-        Ok(PriceStream { inner })
+        Ok(PriceStream { inner, heartbeat })
     }
 
     /// Create a new receiver for this stream.
@@ -157,9 +184,57 @@ impl PriceStream {
     pub fn resubscribe(&self) -> Self {
         PriceStream {
             inner: self.inner.resubscribe(),
+            heartbeat: Arc::clone(&self.heartbeat),
         }
     }
 
+    /// When the last price update was received, or `None` if none has arrived yet.
+    pub fn last_message_at(&self) -> Option<Instant> {
+        self.heartbeat.last_message_at()
+    }
+
+    /// Whether no update has arrived within the staleness threshold.
+    ///
+    /// Always `false` unless [`PriceStreamBuilder::stale_after`] was set.
+    pub fn is_stale(&self) -> bool {
+        self.heartbeat.is_stale()
+    }
+
+    /// Connection health events: connects, disconnects, reconnects, and staleness.
+    ///
+    /// Each call returns an independent receiver that sees events from the
+    /// moment it is created.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use finance_query::streaming::{PriceStreamBuilder, StreamEvent};
+    /// use futures::StreamExt;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let stream = PriceStreamBuilder::new()
+    ///     .symbols(["AAPL"])
+    ///     .stale_after(Duration::from_secs(30))
+    ///     .build()
+    ///     .await?;
+    ///
+    /// let mut events = stream.events();
+    /// while let Some(event) = events.next().await {
+    ///     match event {
+    ///         StreamEvent::Stale { silent_for } => println!("delayed ({silent_for:?})"),
+    ///         StreamEvent::Resumed => println!("live"),
+    ///         StreamEvent::Disconnected { reason } => println!("disconnected: {reason}"),
+    ///         _ => {}
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn events(&self) -> impl Stream<Item = StreamEvent> + Send + Unpin + use<> {
+        self.heartbeat.events()
+    }
+
     /// Add more symbols to the subscription.
     ///
     /// # Example
@@ -221,7 +296,8 @@ impl Stream for PriceStream {
 /// Builder for creating price streams with custom configuration
 pub struct PriceStreamBuilder {
     symbols: Vec<String>,
-    retry_delay: Duration,
+    backoff: Backoff,
+    heartbeat: HeartbeatConfig,
 }
 
 impl PriceStreamBuilder {
@@ -229,7 +305,8 @@ impl PriceStreamBuilder {
     pub fn new() -> Self {
         Self {
             symbols: Vec::new(),
-            retry_delay: Duration::from_secs(RECONNECT_BACKOFF_SECS),
+            backoff: default_backoff(),
+            heartbeat: HeartbeatConfig::default(),
         }
     }
 
@@ -243,9 +320,37 @@ impl PriceStreamBuilder {
         self
     }
 
-    /// Set the delay between reconnection attempts (default: 3s)
+    /// Set the delay before the first reconnection attempt (default: 3s)
     pub fn retry(mut self, delay: Duration) -> Self {
-        self.retry_delay = delay;
+        self.backoff.initial = delay;
+        self
+    }
+
+    /// Cap the delay between reconnection attempts (default: 60s)
+    pub fn max_retry_delay(mut self, delay: Duration) -> Self {
+        self.backoff.max = delay;
+        self
+    }
+
+    /// Multiply the reconnection delay by `multiplier` after each consecutive
+    /// failed attempt (default: 2.0). Values at or below 1.0 retry at a fixed
+    /// delay. The delay resets once a connection succeeds.
+    pub fn backoff_multiplier(mut self, multiplier: f64) -> Self {
+        self.backoff.multiplier = multiplier;
+        self
+    }
+
+    /// Emit [`StreamEvent::Stale`] when no update arrives for `threshold`
+    /// (default: disabled)
+    pub fn stale_after(mut self, threshold: Duration) -> Self {
+        self.heartbeat.stale_after = Some(threshold);
+        self
+    }
+
+    /// Send a WebSocket ping every `interval` to keep idle connections open
+    /// (default: disabled)
+    pub fn keepalive(mut self, interval: Duration) -> Self {
+        self.heartbeat.keepalive = Some(interval);
         self
     }
 
@@ -254,7 +359,8 @@ impl PriceStreamBuilder {
         PriceStream::subscribe_with_source(
             Arc::new(YahooStreamSource),
             self.symbols,
-            self.retry_delay,
+            self.backoff,
+            self.heartbeat,
         )
         .await
     }
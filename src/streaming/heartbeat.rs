@@ -0,0 +1,272 @@
+//! Connection health tracking for price streams.
+//!
+//! A [`Heartbeat`] is shared between the background session and every
+//! [`PriceStream`](super::PriceStream) handle. Sources record each delivered
+//! update; a watchdog compares the time since the last one against the
+//! configured threshold and publishes [`StreamEvent`]s so consumers can show
+//! a delayed or disconnected state instead of silently frozen prices.
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::time::{Duration, Instant};
+
+use futures::stream::Stream;
+use tokio::sync::broadcast;
+use tokio_stream::StreamExt;
+use tokio_stream::wrappers::BroadcastStream;
+
+/// Channel capacity for health events
+const EVENT_CAPACITY: usize = 64;
+
+/// Shortest interval between watchdog checks
+const MIN_CHECK_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Connection health event emitted by a [`PriceStream`](super::PriceStream).
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamEvent {
+    /// A session connected (also emitted after each reconnect)
+    Connected,
+    /// The session dropped or a reconnect attempt failed; the stream will
+    /// retry after its backoff delay
+    Disconnected {
+        /// Why the session ended
+        reason: String,
+    },
+    /// A session connected again after a disconnect, with the previous
+    /// symbol set resubscribed. Follows the [`StreamEvent::Connected`] for
+    /// the same session.
+    Reconnected {
+        /// Sessions that failed since the last successful connection
+        attempts: u32,
+        /// Time since the connection was lost
+        downtime: Duration,
+    },
+    /// No update arrived within the staleness threshold
+    Stale {
+        /// Time since the last update (or since connecting)
+        silent_for: Duration,
+    },
+    /// Updates resumed after a [`StreamEvent::Stale`]
+    Resumed,
+}
+
+/// Heartbeat settings for a stream.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct HeartbeatConfig {
+    /// Emit [`StreamEvent::Stale`] after this long without an update
+    pub stale_after: Option<Duration>,
+    /// Send a WebSocket ping at this interval
+    pub keepalive: Option<Duration>,
+}
+
+/// Shared liveness state for one streaming session.
+pub(crate) struct Heartbeat {
+    config: HeartbeatConfig,
+    last_message: Mutex<Option<Instant>>,
+    /// Start of the current silence window: the last update, or the connect time
+    silent_since: Mutex<Instant>,
+    stale: AtomicBool,
+    /// When the last connected session was lost, while reconnecting
+    disconnected_at: Mutex<Option<Instant>>,
+    /// Failed sessions since the last successful connection
+    failures: AtomicU32,
+    events: broadcast::Sender<StreamEvent>,
+}
+
+impl Heartbeat {
+    pub(crate) fn new(config: HeartbeatConfig) -> Self {
+        let (events, _) = broadcast::channel(EVENT_CAPACITY);
+        Self {
+            config,
+            last_message: Mutex::new(None),
+            silent_since: Mutex::new(Instant::now()),
+            stale: AtomicBool::new(false),
+            disconnected_at: Mutex::new(None),
+            failures: AtomicU32::new(0),
+            events,
+        }
+    }
+
+    /// Keepalive ping interval, if enabled.
+    pub(crate) fn keepalive(&self) -> Option<Duration> {
+        self.config.keepalive
+    }
+
+    /// Record a delivered update.
+    pub(crate) fn touch(&self) {
+        let now = Instant::now();
+        *lock(&self.last_message) = Some(now);
+        *lock(&self.silent_since) = now;
+        if self.stale.swap(false, Ordering::AcqRel) {
+            self.emit(StreamEvent::Resumed);
+        }
+    }
+
+    /// Record a (re)connect; restarts the staleness window.
+    pub(crate) fn connected(&self) {
+        *lock(&self.silent_since) = Instant::now();
+        let attempts = self.failures.swap(0, Ordering::AcqRel);
+        self.emit(StreamEvent::Connected);
+        if let Some(since) = lock(&self.disconnected_at).take() {
+            self.emit(StreamEvent::Reconnected {
+                attempts,
+                downtime: since.elapsed(),
+            });
+        }
+    }
+
+    /// Record a dropped session or failed connection attempt.
+    pub(crate) fn disconnected(&self, reason: impl Into<String>) {
+        lock(&self.disconnected_at).get_or_insert_with(Instant::now);
+        self.failures.fetch_add(1, Ordering::AcqRel);
+        self.emit(StreamEvent::Disconnected {
+            reason: reason.into(),
+        });
+    }
+
+    /// Failed sessions since the last successful connection.
+    pub(crate) fn failures(&self) -> u32 {
+        self.failures.load(Ordering::Acquire)
+    }
+
+    /// Time of the last delivered update.
+    pub(crate) fn last_message_at(&self) -> Option<Instant> {
+        *lock(&self.last_message)
+    }
+
+    /// Whether the stream is currently past its staleness threshold.
+    pub(crate) fn is_stale(&self) -> bool {
+        self.stale.load(Ordering::Acquire)
+    }
+
+    /// Subscribe to health events.
+    pub(crate) fn events(&self) -> impl Stream<Item = StreamEvent> + Send + Unpin + use<> {
+        BroadcastStream::new(self.events.subscribe()).filter_map(|e| e.ok())
+    }
+
+    /// Flag the stream stale if the threshold has passed. Returns the
+    /// emitted silence duration, if any.
+    fn check(&self, now: Instant) -> Option<Duration> {
+        let threshold = self.config.stale_after?;
+        let silent_for = now.saturating_duration_since(*lock(&self.silent_since));
+        if silent_for >= threshold && !self.stale.swap(true, Ordering::AcqRel) {
+            self.emit(StreamEvent::Stale { silent_for });
+            return Some(silent_for);
+        }
+        None
+    }
+
+    /// Periodically check staleness until the task is aborted. Returns
+    /// immediately when no threshold is configured.
+    pub(crate) async fn watch(&self) {
+        let Some(threshold) = self.config.stale_after else {
+            return;
+        };
+        let mut ticker = tokio::time::interval((threshold / 4).max(MIN_CHECK_INTERVAL));
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            self.check(Instant::now());
+        }
+    }
+
+    fn emit(&self, event: StreamEvent) {
+        // No receivers is fine; health events are opt-in
+        let _ = self.events.send(event);
+    }
+}
+
+fn lock<T>(m: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    m.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn heartbeat(stale_after: Duration) -> Heartbeat {
+        Heartbeat::new(HeartbeatConfig {
+            stale_after: Some(stale_after),
+            keepalive: None,
+        })
+    }
+
+    #[test]
+    fn test_stale_then_resumed() {
+        let hb = heartbeat(Duration::from_secs(5));
+        let mut rx = hb.events.subscribe();
+        let start = *lock(&hb.silent_since);
+
+        assert_eq!(hb.check(start + Duration::from_secs(1)), None);
+        assert_eq!(
+            hb.check(start + Duration::from_secs(6)),
+            Some(Duration::from_secs(6))
+        );
+        assert!(hb.is_stale());
+        // Only one Stale per silence window
+        assert_eq!(hb.check(start + Duration::from_secs(10)), None);
+
+        hb.touch();
+        assert!(!hb.is_stale());
+        assert!(hb.last_message_at().is_some());
+
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            StreamEvent::Stale {
+                silent_for: Duration::from_secs(6)
+            }
+        );
+        assert_eq!(rx.try_recv().unwrap(), StreamEvent::Resumed);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_reconnected_after_failures() {
+        let hb = Heartbeat::new(HeartbeatConfig::default());
+        let mut rx = hb.events.subscribe();
+
+        hb.connected();
+        assert_eq!(rx.try_recv().unwrap(), StreamEvent::Connected);
+        assert!(rx.try_recv().is_err());
+
+        hb.disconnected("reset");
+        hb.disconnected("refused");
+        assert_eq!(hb.failures(), 2);
+        hb.connected();
+        assert_eq!(hb.failures(), 0);
+
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            StreamEvent::Disconnected { .. }
+        ));
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            StreamEvent::Disconnected { .. }
+        ));
+        assert_eq!(rx.try_recv().unwrap(), StreamEvent::Connected);
+        assert!(matches!(
+            rx.try_recv().unwrap(),
+            StreamEvent::Reconnected { attempts: 2, .. }
+        ));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_no_threshold_never_stale() {
+        let hb = Heartbeat::new(HeartbeatConfig::default());
+        let later = Instant::now() + Duration::from_secs(3600);
+        assert_eq!(hb.check(later), None);
+        assert!(!hb.is_stale());
+        assert!(hb.last_message_at().is_none());
+    }
+
+    #[test]
+    fn test_connect_restarts_window() {
+        let hb = heartbeat(Duration::from_secs(5));
+        std::thread::sleep(Duration::from_millis(20));
+        hb.connected();
+        let connected_at = *lock(&hb.silent_since);
+        assert_eq!(hb.check(connected_at + Duration::from_secs(4)), None);
+    }
+}
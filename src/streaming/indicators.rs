@@ -0,0 +1,362 @@
+//! Technical indicators over live price ticks.
+//!
+//! [`LiveIndicatorStream`] wraps a [`PriceStream`], builds OHLCV candles from
+//! the ticks for each symbol, and recomputes an [`IndicatorsSummary`] every
+//! time a bar closes. Seeding it with a fetched [`Chart`] gives long-period
+//! indicators (SMA 200, MACD) their warmup without waiting for live bars.
+
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::stream::Stream;
+
+use super::{PriceStream, PriceUpdate};
+use crate::constants::Interval;
+use crate::indicators::{IndicatorSelection, IndicatorsSummary, summary};
+use crate::models::chart::{Candle, Chart};
+
+/// Default number of closed bars kept per symbol.
+const DEFAULT_MAX_BARS: usize = 500;
+
+/// A closed bar and the indicators computed through it.
+#[non_exhaustive]
+#[derive(Debug, Clone)]
+pub struct LiveIndicatorUpdate {
+    /// Symbol the bar belongs to
+    pub symbol: String,
+    /// The bar that just closed
+    pub candle: Candle,
+    /// Indicator values as of the close of `candle`
+    pub indicators: IndicatorsSummary,
+}
+
+/// Candle history and the still-forming bar for one symbol.
+#[derive(Debug, Default)]
+struct SymbolBars {
+    closed: Vec<Candle>,
+    forming: Option<Candle>,
+    /// Cumulative day volume at the previous tick
+    last_day_volume: Option<i64>,
+}
+
+/// Live indicator values, one update per closed bar.
+///
+/// Ticks are grouped into bars of `interval`, aligned to multiples of the
+/// interval's length since the Unix epoch (UTC). A bar closes when the first
+/// tick of a later bar arrives, so a quiet symbol's last bar is held until
+/// trading resumes; [`forming`](Self::forming) shows it in the meantime.
+/// Bar volume is the change in the stream's cumulative day volume.
+///
+/// # Example
+///
+/// ```no_run
+/// use finance_query::streaming::{LiveIndicatorStream, PriceStream};
+/// use finance_query::{Interval, Ticker, TimeRange};
+/// use futures::StreamExt;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// // Warm up from history so long-period indicators have values immediately
+/// let history = Ticker::new("AAPL")
+///     .await?
+///     .chart(Interval::OneMinute, TimeRange::OneDay)
+///     .await?;
+///
+/// let prices = PriceStream::subscribe(["AAPL"]).await?;
+/// let mut live = LiveIndicatorStream::new(prices, Interval::OneMinute).seed(&history);
+///
+/// while let Some(update) = live.next().await {
+///     println!(
+///         "{} close {:.2} RSI {:?} EMA(10) {:?}",
+///         update.symbol, update.candle.close, update.indicators.rsi_14, update.indicators.ema_10
+///     );
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct LiveIndicatorStream {
+    inner: PriceStream,
+    bars: LiveBars,
+}
+
+impl LiveIndicatorStream {
+    /// Aggregate `stream` into `interval` bars and compute every indicator.
+    pub fn new(stream: PriceStream, interval: Interval) -> Self {
+        Self {
+            inner: stream,
+            bars: LiveBars::new(interval),
+        }
+    }
+
+    /// Prepend historical bars for `chart.symbol`.
+    ///
+    /// Only bars older than the earliest bar already held are used, so a
+    /// chart overlapping the live bars doesn't double count.
+    pub fn seed(mut self, chart: &Chart) -> Self {
+        self.bars.seed(chart);
+        self
+    }
+
+    /// Compute only the indicators in `selection` (default: all).
+    pub fn selection(mut self, selection: impl Into<IndicatorSelection>) -> Self {
+        self.bars.selection = selection.into();
+        self
+    }
+
+    /// Number of closed bars kept per symbol (default: 500).
+    ///
+    /// Must cover the longest indicator period in use; SMA 200 needs at
+    /// least 200.
+    pub fn max_bars(mut self, max_bars: usize) -> Self {
+        self.bars.set_max_bars(max_bars);
+        self
+    }
+
+    /// The bar currently being built for `symbol`, if any tick has arrived.
+    pub fn forming(&self, symbol: &str) -> Option<&Candle> {
+        self.bars.forming(symbol)
+    }
+
+    /// Closed bars held for `symbol`, oldest first, including seeded history.
+    pub fn candles(&self, symbol: &str) -> &[Candle] {
+        self.bars.candles(symbol)
+    }
+
+    /// Close the underlying price stream.
+    pub async fn close(&self) {
+        self.inner.close().await;
+    }
+}
+
+impl Stream for LiveIndicatorStream {
+    type Item = LiveIndicatorUpdate;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match Pin::new(&mut self.inner).poll_next(cx) {
+                Poll::Ready(Some(update)) => {
+                    if let Some(out) = self.bars.ingest(&update) {
+                        return Poll::Ready(Some(out));
+                    }
+                }
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Per-symbol bar aggregation behind [`LiveIndicatorStream`].
+#[derive(Debug)]
+struct LiveBars {
+    interval_secs: i64,
+    max_bars: usize,
+    selection: IndicatorSelection,
+    symbols: HashMap<String, SymbolBars>,
+}
+
+impl LiveBars {
+    fn new(interval: Interval) -> Self {
+        Self {
+            interval_secs: interval.approx_duration_secs().max(1),
+            max_bars: DEFAULT_MAX_BARS,
+            selection: IndicatorSelection::all(),
+            symbols: HashMap::new(),
+        }
+    }
+
+    fn seed(&mut self, chart: &Chart) {
+        let entry = self.symbols.entry(chart.symbol.clone()).or_default();
+        let cutoff = entry
+            .closed
+            .first()
+            .or(entry.forming.as_ref())
+            .map(|c| c.timestamp)
+            .unwrap_or(i64::MAX);
+        let mut history: Vec<Candle> = chart
+            .candles
+            .iter()
+            .filter(|c| c.timestamp < cutoff)
+            .cloned()
+            .collect();
+        history.append(&mut entry.closed);
+        entry.closed = history;
+        trim(&mut entry.closed, self.max_bars);
+    }
+
+    fn set_max_bars(&mut self, max_bars: usize) {
+        self.max_bars = max_bars.max(1);
+        for bars in self.symbols.values_mut() {
+            trim(&mut bars.closed, self.max_bars);
+        }
+    }
+
+    fn forming(&self, symbol: &str) -> Option<&Candle> {
+        self.symbols.get(symbol).and_then(|b| b.forming.as_ref())
+    }
+
+    fn candles(&self, symbol: &str) -> &[Candle] {
+        self.symbols
+            .get(symbol)
+            .map(|b| b.closed.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Fold one tick into its symbol's bars, returning an update if it
+    /// closed the previous bar.
+    fn ingest(&mut self, update: &PriceUpdate) -> Option<LiveIndicatorUpdate> {
+        let price = f64::from(update.price);
+        if !price.is_finite() || price <= 0.0 {
+            return None;
+        }
+        let bucket = (update.time / 1000).div_euclid(self.interval_secs) * self.interval_secs;
+        let bars = self.symbols.entry(update.id.clone()).or_default();
+
+        // Day volume is cumulative and resets at the session boundary
+        let volume = match bars.last_day_volume {
+            Some(prev) if update.day_volume >= prev => update.day_volume - prev,
+            Some(_) => update.day_volume,
+            None => 0,
+        };
+        bars.last_day_volume = Some(update.day_volume);
+
+        match bars.forming.as_mut() {
+            Some(bar) if bar.timestamp == bucket => {
+                bar.high = bar.high.max(price);
+                bar.low = bar.low.min(price);
+                bar.close = price;
+                bar.adj_close = Some(price);
+                bar.volume += volume;
+                None
+            }
+            // Late tick for a bar that already closed
+            Some(bar) if bucket < bar.timestamp => None,
+            _ => {
+                let next = Candle {
+                    timestamp: bucket,
+                    open: price,
+                    high: price,
+                    low: price,
+                    close: price,
+                    volume,
+                    adj_close: Some(price),
+                    provider_id: None,
+                };
+                let closed = bars.forming.replace(next)?;
+                bars.closed.push(closed.clone());
+                trim(&mut bars.closed, self.max_bars);
+                let indicators = summary::calculate_selected(&bars.closed, &self.selection);
+                Some(LiveIndicatorUpdate {
+                    symbol: update.id.clone(),
+                    candle: closed,
+                    indicators,
+                })
+            }
+        }
+    }
+}
+
+/// Drop the oldest bars beyond `max`.
+fn trim(candles: &mut Vec<Candle>, max: usize) {
+    if candles.len() > max {
+        candles.drain(..candles.len() - max);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::indicators::IndicatorKind;
+    use crate::models::chart::ChartMeta;
+    use crate::streaming::pricing::PricingData;
+
+    fn tick(symbol: &str, secs: i64, price: f32, day_volume: i64) -> PriceUpdate {
+        PriceUpdate::from(PricingData {
+            id: symbol.to_string(),
+            price,
+            time: secs * 1000,
+            day_volume,
+            ..Default::default()
+        })
+    }
+
+    /// One-minute bars at 100 starting at t = 0.
+    fn history(bars: i64) -> Chart {
+        Chart {
+            symbol: "AAPL".to_string(),
+            meta: ChartMeta::default(),
+            candles: (0..bars)
+                .map(|i| Candle {
+                    timestamp: i * 60,
+                    open: 100.0,
+                    high: 101.0,
+                    low: 99.0,
+                    close: 100.0 + (i % 3) as f64,
+                    volume: 1_000,
+                    ..Default::default()
+                })
+                .collect(),
+            interval: Some(Interval::OneMinute),
+            range: None,
+            provider_id: None,
+            adjustment: None,
+        }
+    }
+
+    #[test]
+    fn test_ticks_aggregate_into_bars() {
+        let mut live = LiveBars::new(Interval::OneMinute);
+
+        assert!(live.ingest(&tick("AAPL", 60, 100.0, 1_000)).is_none());
+        assert!(live.ingest(&tick("AAPL", 90, 102.0, 1_300)).is_none());
+        assert!(live.ingest(&tick("AAPL", 110, 99.0, 1_500)).is_none());
+        assert_eq!(live.forming("AAPL").unwrap().high, 102.0);
+
+        let update = live.ingest(&tick("AAPL", 125, 101.0, 1_600)).unwrap();
+        assert_eq!(update.symbol, "AAPL");
+        assert_eq!(update.candle.timestamp, 60);
+        assert_eq!(update.candle.open, 100.0);
+        assert_eq!(update.candle.high, 102.0);
+        assert_eq!(update.candle.low, 99.0);
+        assert_eq!(update.candle.close, 99.0);
+        assert_eq!(update.candle.volume, 500);
+        assert_eq!(live.forming("AAPL").unwrap().volume, 100);
+
+        // Late tick for the closed bar is ignored
+        assert!(live.ingest(&tick("AAPL", 100, 50.0, 1_650)).is_none());
+        assert_eq!(live.forming("AAPL").unwrap().low, 101.0);
+
+        // Symbols are tracked independently
+        assert!(live.ingest(&tick("MSFT", 130, 400.0, 10)).is_none());
+        assert_eq!(live.candles("MSFT").len(), 0);
+    }
+
+    #[test]
+    fn test_seeded_history_warms_up_indicators() {
+        let mut live = LiveBars::new(Interval::OneMinute);
+        live.selection = (&[IndicatorKind::Sma, IndicatorKind::Rsi]).into();
+        live.seed(&history(30));
+        assert_eq!(live.candles("AAPL").len(), 30);
+
+        let start = 31 * 60;
+        assert!(live.ingest(&tick("AAPL", start, 110.0, 0)).is_none());
+        let update = live.ingest(&tick("AAPL", start + 60, 111.0, 0)).unwrap();
+        assert_eq!(live.candles("AAPL").len(), 31);
+        assert!(update.indicators.sma_20.is_some());
+        assert!(update.indicators.rsi_14.is_some());
+        assert!(update.indicators.macd.is_none());
+
+        // Re-seeding with overlapping history doesn't duplicate bars
+        live.seed(&history(40));
+        assert_eq!(live.candles("AAPL").len(), 31);
+    }
+
+    #[test]
+    fn test_max_bars_trims_history() {
+        let mut live = LiveBars::new(Interval::OneMinute);
+        live.seed(&history(30));
+        live.set_max_bars(10);
+        assert_eq!(live.candles("AAPL").len(), 10);
+        assert_eq!(live.candles("AAPL")[0].timestamp, 20 * 60);
+    }
+}
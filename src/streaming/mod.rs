@@ -1,5 +1,9 @@
 //! Real-time price streaming with pluggable provider backends.
 //!
+//! The WebSocket price streams require the **`streaming`** feature and
+//! [`NewsStream`] the **`feeds`** feature (both on by default); [`AssetClass`]
+//! is always available.
+//!
 //! This module provides a Stream-based API for receiving real-time price updates,
 //! similar to Kotlin Flow or Rx observables.
 //!
@@ -39,13 +43,32 @@
 //! # }
 //! ```
 
+mod asset_class;
+#[cfg(feature = "streaming")]
 mod client;
+#[cfg(feature = "streaming")]
+mod heartbeat;
+#[cfg(all(feature = "streaming", feature = "indicators"))]
+mod indicators;
+#[cfg(feature = "feeds")]
 mod news;
+#[cfg(feature = "streaming")]
 mod pricing;
+#[cfg(feature = "streaming")]
 mod source;
+#[cfg(any(feature = "streaming", feature = "feeds"))]
 mod subscription;
+#[cfg(feature = "streaming")]
 mod yahoo;
 
+pub use asset_class::AssetClass;
+#[cfg(feature = "streaming")]
 pub use client::{PriceStream, PriceStreamBuilder, StreamError, StreamResult};
+#[cfg(feature = "streaming")]
+pub use heartbeat::StreamEvent;
+#[cfg(all(feature = "streaming", feature = "indicators"))]
+pub use indicators::{LiveIndicatorStream, LiveIndicatorUpdate};
+#[cfg(feature = "feeds")]
 pub use news::{NewsStream, NewsStreamBuilder};
+#[cfg(feature = "streaming")]
 pub use pricing::{MarketHoursType, OptionType, PriceUpdate, QuoteType};
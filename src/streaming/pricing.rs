@@ -2,6 +2,7 @@
 //!
 //! This module contains the protobuf message definition for streaming price data.
 
+use super::asset_class::AssetClass;
 use prost::Message;
 use serde::{Deserialize, Serialize};
 
@@ -59,6 +60,30 @@ impl From<i32> for QuoteType {
     }
 }
 
+impl QuoteType {
+    /// Broad asset class for this quote type, or `None` when the type does not
+    /// identify one (e.g. [`QuoteType::None`] on a partial update).
+    pub fn asset_class(self) -> Option<AssetClass> {
+        Some(match self {
+            QuoteType::Equity
+            | QuoteType::Etf
+            | QuoteType::MutualFund
+            | QuoteType::MoneyMarket
+            | QuoteType::Warrant
+            | QuoteType::EcnQuote => AssetClass::Equity,
+            QuoteType::Index | QuoteType::Indicator => AssetClass::Index,
+            QuoteType::Option => AssetClass::Option,
+            QuoteType::Future | QuoteType::Commodity => AssetClass::Future,
+            QuoteType::Currency => AssetClass::Fx,
+            QuoteType::Cryptocurrency => AssetClass::Crypto,
+            QuoteType::Bond => AssetClass::Other,
+            QuoteType::None | QuoteType::AltSymbol | QuoteType::Heartbeat | QuoteType::Industry => {
+                return None;
+            }
+        })
+    }
+}
+
 /// Option type enumeration
 #[derive(
     Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
@@ -340,18 +365,46 @@ pub struct PriceUpdate {
     pub last_market: String,
     pub circulating_supply: f64,
     pub market_cap: f64,
+    /// Derived from `quote_type`, or from the symbol when the update omits it
+    #[serde(default)]
+    pub asset_class: AssetClass,
+}
+
+impl PriceUpdate {
+    /// Traded volume for the update's asset class.
+    ///
+    /// `None` for FX, which has no volume. Crypto uses the rolling 24-hour
+    /// volume when present; everything else uses `day_volume`.
+    pub fn volume(&self) -> Option<i64> {
+        match self.asset_class {
+            AssetClass::Fx => None,
+            AssetClass::Crypto if self.vol_24hr > 0 => Some(self.vol_24hr),
+            _ => Some(self.day_volume),
+        }
+    }
 }
 
 impl From<PricingData> for PriceUpdate {
     fn from(data: PricingData) -> Self {
+        let quote_type = QuoteType::from(data.quote_type);
+        let asset_class = quote_type
+            .asset_class()
+            .unwrap_or_else(|| AssetClass::from_symbol(&data.id));
+        // FX and crypto have no extended sessions. Updates often omit the
+        // field, which would otherwise decode as the proto default (pre-market).
+        let market_hours = if asset_class.is_continuous() {
+            MarketHoursType::RegularMarket
+        } else {
+            MarketHoursType::from(data.market_hours)
+        };
         Self {
             id: data.id,
             price: data.price,
             time: data.time,
             currency: data.currency,
             exchange: data.exchange,
-            quote_type: QuoteType::from(data.quote_type),
-            market_hours: MarketHoursType::from(data.market_hours),
+            quote_type,
+            market_hours,
             change_percent: data.change_percent,
             day_volume: data.day_volume,
             day_high: data.day_high,
@@ -378,6 +431,7 @@ impl From<PricingData> for PriceUpdate {
             last_market: data.last_market,
             circulating_supply: data.circulating_supply,
             market_cap: data.market_cap,
+            asset_class,
         }
     }
 }
@@ -420,4 +474,101 @@ mod tests {
         assert_eq!(MarketHoursType::from(1), MarketHoursType::RegularMarket);
         assert_eq!(MarketHoursType::from(2), MarketHoursType::PostMarket);
     }
+
+    /// Round-trip a fixture through the wire format the streamer receives.
+    fn decode(data: PricingData) -> PriceUpdate {
+        let encoded = base64::Engine::encode(
+            &base64::engine::general_purpose::STANDARD,
+            data.encode_to_vec(),
+        );
+        PricingData::from_base64(&encoded).unwrap().into()
+    }
+
+    fn equity_fixture() -> PricingData {
+        PricingData {
+            id: "AAPL".to_string(),
+            price: 175.5,
+            currency: "USD".to_string(),
+            exchange: "NMS".to_string(),
+            quote_type: 8,
+            market_hours: 2,
+            day_volume: 52_345_678,
+            ..Default::default()
+        }
+    }
+
+    fn fx_fixture() -> PricingData {
+        PricingData {
+            id: "EURUSD=X".to_string(),
+            price: 1.0842,
+            currency: "USD".to_string(),
+            exchange: "CCY".to_string(),
+            quote_type: 14,
+            bid: 1.0841,
+            ask: 1.0843,
+            ..Default::default()
+        }
+    }
+
+    fn crypto_fixture() -> PricingData {
+        PricingData {
+            id: "BTC-USD".to_string(),
+            price: 67_250.0,
+            currency: "USD".to_string(),
+            exchange: "CCC".to_string(),
+            quote_type: 41,
+            day_volume: 1_200_000,
+            vol_24hr: 31_000_000_000,
+            from_currency: "BTC".to_string(),
+            last_market: "CoinMarketCap".to_string(),
+            circulating_supply: 19_700_000.0,
+            market_cap: 1.32e12,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_equity_update_keeps_session_and_volume() {
+        let update = decode(equity_fixture());
+        assert_eq!(update.asset_class, AssetClass::Equity);
+        assert_eq!(update.market_hours, MarketHoursType::PostMarket);
+        assert_eq!(update.volume(), Some(52_345_678));
+    }
+
+    #[test]
+    fn test_fx_update_has_no_volume_or_extended_session() {
+        let update = decode(fx_fixture());
+        assert_eq!(update.quote_type, QuoteType::Currency);
+        assert_eq!(update.asset_class, AssetClass::Fx);
+        assert_eq!(update.market_hours, MarketHoursType::RegularMarket);
+        assert_eq!(update.volume(), None);
+        assert!((update.ask - update.bid - 0.0002).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_crypto_update_uses_24h_volume_and_session() {
+        let update = decode(crypto_fixture());
+        assert_eq!(update.asset_class, AssetClass::Crypto);
+        assert_eq!(update.market_hours, MarketHoursType::RegularMarket);
+        assert_eq!(update.volume(), Some(31_000_000_000));
+        assert_eq!(update.from_currency, "BTC");
+    }
+
+    #[test]
+    fn test_asset_class_inferred_when_quote_type_missing() {
+        for (symbol, class) in [
+            ("EURUSD=X", AssetClass::Fx),
+            ("eth-usd", AssetClass::Crypto),
+            ("GC=F", AssetClass::Future),
+            ("^GSPC", AssetClass::Index),
+            ("BRK-B", AssetClass::Equity),
+        ] {
+            let update = decode(PricingData {
+                id: symbol.to_string(),
+                ..Default::default()
+            });
+            assert_eq!(update.quote_type, QuoteType::None);
+            assert_eq!(update.asset_class, class, "{symbol}");
+        }
+    }
 }
@@ -14,6 +14,7 @@ use tokio::sync::{RwLock, broadcast, mpsc};
 use tracing::{error, info};
 
 use super::client::StreamResult;
+use super::heartbeat::Heartbeat;
 use super::pricing::PriceUpdate;
 
 /// Commands sent to a running streaming session.
@@ -26,6 +27,23 @@ pub(crate) enum StreamCommand {
     Close,
 }
 
+/// Exponential reconnect delay: `initial * multiplier^(failures - 1)`, capped at `max`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Backoff {
+    pub initial: Duration,
+    pub max: Duration,
+    pub multiplier: f64,
+}
+
+impl Backoff {
+    /// Delay before the next attempt after `failures` consecutive failed sessions.
+    pub(crate) fn delay(&self, failures: u32) -> Duration {
+        let exponent = failures.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let secs = self.initial.as_secs_f64() * self.multiplier.max(1.0).powi(exponent);
+        Duration::from_secs_f64(secs.min(self.max.as_secs_f64()))
+    }
+}
+
 /// A real-time price source backing a [`PriceStream`](super::PriceStream).
 ///
 /// Implementations own the transport and wire protocol and push decoded
@@ -38,32 +56,45 @@ pub(crate) trait StreamSource: Send + Sync + 'static {
 
     /// Run one connected session until it ends.
     ///
-    /// Returns `Ok(())` for a graceful shutdown (a [`StreamCommand::Close`] or a
-    /// server close frame) — the loop stops. Returns `Err(..)` for a recoverable
-    /// disconnect — the loop reconnects after a backoff. The session should
+    /// Returns `Ok(())` only for a [`StreamCommand::Close`] — the loop stops.
+    /// Returns `Err(..)` for anything else that ends the session, including a
+    /// server close frame — the loop reconnects after a backoff and
+    /// resubscribes the shared `subscriptions` set. The session should
     /// honor `command_rx` for live (un)subscribe and reflect changes into the
-    /// shared `subscriptions` set.
+    /// shared `subscriptions` set, call [`Heartbeat::connected`] once connected,
+    /// [`Heartbeat::touch`] for every delivered update, and send a ping every
+    /// [`Heartbeat::keepalive`] if set.
     async fn run_session(
         &self,
         subscriptions: &Arc<RwLock<HashSet<String>>>,
         broadcast_tx: &broadcast::Sender<PriceUpdate>,
         command_rx: &mut mpsc::Receiver<StreamCommand>,
+        heartbeat: &Heartbeat,
     ) -> StreamResult<()>;
 }
 
 /// Drive a [`StreamSource`] with automatic reconnection until it shuts down.
+///
+/// The delay between attempts grows with each consecutive failure and resets
+/// once a session connects.
 pub(crate) async fn run_stream_loop(
     source: Arc<dyn StreamSource>,
     initial_symbols: Vec<String>,
     broadcast_tx: broadcast::Sender<PriceUpdate>,
     mut command_rx: mpsc::Receiver<StreamCommand>,
-    retry_delay: Duration,
+    backoff: Backoff,
+    heartbeat: Arc<Heartbeat>,
 ) -> StreamResult<()> {
     let subscriptions = Arc::new(RwLock::new(HashSet::<String>::from_iter(initial_symbols)));
 
+    let watchdog = {
+        let heartbeat = Arc::clone(&heartbeat);
+        tokio::spawn(async move { heartbeat.watch().await })
+    };
+
     loop {
         match source
-            .run_session(&subscriptions, &broadcast_tx, &mut command_rx)
+            .run_session(&subscriptions, &broadcast_tx, &mut command_rx, &heartbeat)
             .await
         {
             Ok(()) => {
@@ -71,6 +102,8 @@ pub(crate) async fn run_stream_loop(
                 break;
             }
             Err(e) => {
+                heartbeat.disconnected(e.to_string());
+                let retry_delay = backoff.delay(heartbeat.failures());
                 error!(
                     "{} stream error: {}, reconnecting in {:.1}s...",
                     source.id(),
@@ -82,13 +115,15 @@ pub(crate) async fn run_stream_loop(
         }
     }
 
+    watchdog.abort();
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::streaming::client::PriceStream;
+    use crate::streaming::client::{PriceStream, StreamError};
+    use crate::streaming::heartbeat::{HeartbeatConfig, StreamEvent};
     use crate::streaming::pricing::PricingData;
     use futures::StreamExt;
 
@@ -107,7 +142,9 @@ mod tests {
             subscriptions: &Arc<RwLock<HashSet<String>>>,
             broadcast_tx: &broadcast::Sender<PriceUpdate>,
             command_rx: &mut mpsc::Receiver<StreamCommand>,
+            heartbeat: &Heartbeat,
         ) -> StreamResult<()> {
+            heartbeat.connected();
             let subs: Vec<String> = subscriptions.read().await.iter().cloned().collect();
             for sym in subs {
                 let data = PricingData {
@@ -116,6 +153,7 @@ mod tests {
                     ..Default::default()
                 };
                 let _ = broadcast_tx.send(data.into());
+                heartbeat.touch();
             }
             while let Some(cmd) = command_rx.recv().await {
                 if matches!(cmd, StreamCommand::Close) {
@@ -126,12 +164,102 @@ mod tests {
         }
     }
 
+    fn backoff() -> Backoff {
+        Backoff {
+            initial: Duration::from_millis(10),
+            max: Duration::from_millis(40),
+            multiplier: 2.0,
+        }
+    }
+
+    #[test]
+    fn backoff_grows_and_caps() {
+        let b = backoff();
+        assert_eq!(b.delay(1), Duration::from_millis(10));
+        assert_eq!(b.delay(2), Duration::from_millis(20));
+        assert_eq!(b.delay(3), Duration::from_millis(40));
+        assert_eq!(b.delay(10), Duration::from_millis(40));
+        assert_eq!(b.delay(u32::MAX), Duration::from_millis(40));
+
+        let fixed = Backoff {
+            multiplier: 1.0,
+            ..b
+        };
+        assert_eq!(fixed.delay(5), Duration::from_millis(10));
+    }
+
+    /// Drops its first session right after connecting, then behaves like
+    /// [`MockSource`] on the reconnect.
+    struct FlakySource {
+        sessions: std::sync::atomic::AtomicU32,
+    }
+
+    #[async_trait::async_trait]
+    impl StreamSource for FlakySource {
+        fn id(&self) -> &'static str {
+            "flaky"
+        }
+
+        async fn run_session(
+            &self,
+            subscriptions: &Arc<RwLock<HashSet<String>>>,
+            broadcast_tx: &broadcast::Sender<PriceUpdate>,
+            command_rx: &mut mpsc::Receiver<StreamCommand>,
+            heartbeat: &Heartbeat,
+        ) -> StreamResult<()> {
+            let session = self
+                .sessions
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if session == 0 {
+                heartbeat.connected();
+                return Err(StreamError::WebSocketError("closed by server".into()));
+            }
+            MockSource
+                .run_session(subscriptions, broadcast_tx, command_rx, heartbeat)
+                .await
+        }
+    }
+
+    #[tokio::test]
+    async fn dropped_session_reconnects_and_resubscribes() {
+        let mut stream = PriceStream::subscribe_with_source(
+            Arc::new(FlakySource {
+                sessions: Default::default(),
+            }),
+            ["MSFT"],
+            backoff(),
+            HeartbeatConfig::default(),
+        )
+        .await
+        .unwrap();
+        let mut events = stream.events();
+
+        let reconnected = tokio::time::timeout(Duration::from_secs(2), async {
+            loop {
+                if let Some(StreamEvent::Reconnected { attempts, .. }) = events.next().await {
+                    return attempts;
+                }
+            }
+        })
+        .await
+        .expect("timed out waiting for Reconnected");
+        assert_eq!(reconnected, 1);
+
+        let update = tokio::time::timeout(Duration::from_secs(2), stream.next())
+            .await
+            .expect("timed out waiting for update")
+            .expect("stream ended without an update");
+        assert_eq!(update.id, "MSFT");
+        stream.close().await;
+    }
+
     #[tokio::test]
     async fn pluggable_source_delivers_updates() {
         let mut stream = PriceStream::subscribe_with_source(
             Arc::new(MockSource),
             ["AAPL"],
-            Duration::from_millis(50),
+            backoff(),
+            HeartbeatConfig::default(),
         )
         .await
         .unwrap();
@@ -143,6 +271,39 @@ mod tests {
 
         assert_eq!(update.id, "AAPL");
         assert_eq!(update.price, 42.0);
+        assert!(stream.last_message_at().is_some());
+        stream.close().await;
+    }
+
+    #[tokio::test]
+    async fn silent_source_emits_stale() {
+        // No symbols, so the mock connects and then never sends an update
+        let stream = PriceStream::subscribe_with_source(
+            Arc::new(MockSource),
+            Vec::<String>::new(),
+            backoff(),
+            HeartbeatConfig {
+                stale_after: Some(Duration::from_millis(100)),
+                keepalive: None,
+            },
+        )
+        .await
+        .unwrap();
+        let mut events = stream.events();
+
+        let stale = tokio::time::timeout(Duration::from_secs(2), async {
+            loop {
+                if let Some(StreamEvent::Stale { silent_for }) = events.next().await {
+                    return silent_for;
+                }
+            }
+        })
+        .await
+        .expect("timed out waiting for Stale");
+
+        assert!(stale >= Duration::from_millis(100));
+        assert!(stream.is_stale());
+        assert!(stream.last_message_at().is_none());
         stream.close().await;
     }
 }
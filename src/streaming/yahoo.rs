@@ -11,11 +11,12 @@ use std::time::Duration;
 
 use futures::SinkExt;
 use tokio::sync::{RwLock, broadcast, mpsc};
-use tokio::time::interval;
+use tokio::time::{Instant, Interval, interval, interval_at};
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 use tracing::{debug, error, info, warn};
 
 use super::client::{StreamError, StreamResult};
+use super::heartbeat::Heartbeat;
 use super::pricing::{PriceUpdate, PricingData, PricingDecodeError};
 use super::source::{StreamCommand, StreamSource};
 
@@ -39,8 +40,9 @@ impl StreamSource for YahooStreamSource {
         subscriptions: &Arc<RwLock<HashSet<String>>>,
         broadcast_tx: &broadcast::Sender<PriceUpdate>,
         command_rx: &mut mpsc::Receiver<StreamCommand>,
+        heartbeat: &Heartbeat,
     ) -> StreamResult<()> {
-        connect_and_stream(subscriptions, broadcast_tx, command_rx).await
+        connect_and_stream(subscriptions, broadcast_tx, command_rx, heartbeat).await
     }
 }
 
@@ -49,6 +51,7 @@ async fn connect_and_stream(
     subscriptions: &Arc<RwLock<HashSet<String>>>,
     broadcast_tx: &broadcast::Sender<PriceUpdate>,
     command_rx: &mut mpsc::Receiver<StreamCommand>,
+    heartbeat: &Heartbeat,
 ) -> StreamResult<()> {
     use futures::StreamExt;
 
@@ -59,6 +62,7 @@ async fn connect_and_stream(
         .map_err(|e| StreamError::ConnectionFailed(e.to_string()))?;
 
     info!("Connected to Yahoo Finance WebSocket");
+    heartbeat.connected();
 
     let (mut write, mut read) = ws_stream.split();
 
@@ -100,22 +104,33 @@ async fn connect_and_stream(
         }
     });
 
+    // Optional keepalive pings, first one a full period after connecting
+    let mut keepalive = heartbeat
+        .keepalive()
+        .map(|period| interval_at(Instant::now() + period, period));
+
     loop {
         tokio::select! {
             // Handle incoming WebSocket messages
-            Some(msg) = read.next() => {
+            msg = read.next() => {
+                let Some(msg) = msg else {
+                    return Err(StreamError::WebSocketError("connection closed".to_string()));
+                };
                 match msg {
                     Ok(Message::Text(text)) => {
-                        if let Err(e) = handle_text_message(&text, broadcast_tx) {
-                            warn!("Failed to handle message: {}", e);
+                        match handle_text_message(&text, broadcast_tx) {
+                            Ok(true) => heartbeat.touch(),
+                            Ok(false) => {}
+                            Err(e) => warn!("Failed to handle message: {}", e),
                         }
                     }
                     Ok(Message::Binary(data)) => {
                         debug!("Received binary message: {} bytes", data.len());
                     }
-                    Ok(Message::Close(_)) => {
-                        info!("Received close frame");
-                        break;
+                    Ok(Message::Close(frame)) => {
+                        // Only a Close command ends the stream; a server close is a disconnect
+                        info!("Received close frame: {:?}", frame);
+                        return Err(StreamError::WebSocketError("closed by server".to_string()));
                     }
                     Ok(Message::Ping(data)) => {
                         let _ = write.send(Message::Pong(data)).await;
@@ -136,6 +151,15 @@ async fn connect_and_stream(
                 }
             }
 
+            // Keepalive ping
+            _ = tick(&mut keepalive) => {
+                if let Err(e) = write.send(Message::Ping(Vec::new().into())).await {
+                    error!("Failed to send keepalive ping: {}", e);
+                    return Err(StreamError::WebSocketError(e.to_string()));
+                }
+                debug!("Keepalive ping sent");
+            }
+
             // Handle commands (subscribe/unsubscribe)
             Some(cmd) = command_rx.recv() => {
                 match cmd {
@@ -186,11 +210,23 @@ async fn connect_and_stream(
     Ok(())
 }
 
+/// Wait for the next tick of an optional interval; never resolves if `None`.
+async fn tick(interval: &mut Option<Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
 /// Handle incoming text message from Yahoo WebSocket.
+///
+/// Returns `true` if the message carried a price update.
 fn handle_text_message(
     text: &str,
     broadcast_tx: &broadcast::Sender<PriceUpdate>,
-) -> std::result::Result<(), PricingDecodeError> {
+) -> std::result::Result<bool, PricingDecodeError> {
     // Yahoo sends JSON with base64-encoded protobuf in "message" field
     let json: serde_json::Value =
         serde_json::from_str(text).map_err(|e| PricingDecodeError::Base64(e.to_string()))?;
@@ -203,7 +239,8 @@ fn handle_text_message(
         if broadcast_tx.receiver_count() > 0 {
             let _ = broadcast_tx.send(price_update);
         }
+        return Ok(true);
     }
 
-    Ok(())
+    Ok(false)
 }
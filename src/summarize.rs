@@ -0,0 +1,298 @@
+//! Pluggable document summarization.
+//!
+//! The crate does not ship or call any summarization provider. Instead it
+//! defines the [`Summarizer`] trait and feeds it the text of transcripts,
+//! filings, and news clusters, so applications can plug in an LLM-backed,
+//! hosted, or extractive implementation of their choosing.
+//!
+//! [`LeadSentences`] is a tiny dependency-free extractive baseline, useful in
+//! tests and as a fallback when no model is configured.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use finance_query::finance;
+//! use finance_query::summarize::LeadSentences;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let transcript = finance::earnings_transcript("AAPL", None, None).await?;
+//! let summary = transcript.summarize(&LeadSentences::new(5)).await?;
+//! println!("{}", summary.text);
+//! # Ok(())
+//! # }
+//! ```
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{FinanceError, Result};
+use crate::models::corporate::news::News;
+use crate::models::filings::ProviderFiling;
+
+/// Kind of document being summarized.
+///
+/// Passed to [`Summarizer::summarize`] so implementations can tailor prompts
+/// or extraction rules per document type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DocumentKind {
+    /// Earnings call transcript (speaker-prefixed paragraphs)
+    Transcript,
+    /// SEC filing body text
+    Filing,
+    /// A group of related news headlines, one per line
+    NewsCluster,
+    /// Any other caller-supplied text
+    Text,
+}
+
+/// Input handed to a [`Summarizer`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct SummaryRequest<'a> {
+    /// Document kind
+    pub kind: DocumentKind,
+    /// Optional document title (e.g. `"AAPL Q4 2024 earnings call"`)
+    pub title: Option<&'a str>,
+    /// Full document text
+    pub text: &'a str,
+}
+
+/// A summarization backend.
+///
+/// Implement this to plug an LLM-backed or extractive summarizer into
+/// [`Transcript::summarize`](crate::Transcript::summarize),
+/// [`summarize_filing`], and [`summarize_news`].
+#[async_trait]
+pub trait Summarizer: Send + Sync {
+    /// Identifier recorded in [`Summary::summarizer`].
+    fn id(&self) -> &'static str {
+        "custom"
+    }
+
+    /// Summarize the document text.
+    async fn summarize(&self, request: SummaryRequest<'_>) -> Result<String>;
+}
+
+/// A produced summary.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Summary {
+    /// Kind of document that was summarized
+    pub kind: DocumentKind,
+    /// Summary text returned by the summarizer
+    pub text: String,
+    /// [`Summarizer::id`] of the implementation that produced it
+    pub summarizer: String,
+    /// Length of the input text in characters
+    pub source_chars: usize,
+}
+
+/// Summarize arbitrary text with the given summarizer.
+///
+/// Returns [`FinanceError::InvalidParameter`] for empty (or whitespace-only) text.
+pub async fn summarize_text<S: Summarizer + ?Sized>(
+    summarizer: &S,
+    kind: DocumentKind,
+    title: Option<&str>,
+    text: &str,
+) -> Result<Summary> {
+    if text.trim().is_empty() {
+        return Err(FinanceError::InvalidParameter {
+            param: "text".into(),
+            reason: "cannot summarize an empty document".into(),
+        });
+    }
+    let out = summarizer
+        .summarize(SummaryRequest { kind, title, text })
+        .await?;
+    Ok(Summary {
+        kind,
+        text: out,
+        summarizer: summarizer.id().to_string(),
+        source_chars: text.chars().count(),
+    })
+}
+
+/// Summarize the body text of an SEC filing.
+///
+/// The filing's type and company name are passed as the title; `text` is the
+/// document body (the caller fetches it from [`ProviderFiling::filing_url`]
+/// with whatever HTTP client and HTML stripping it prefers).
+pub async fn summarize_filing<S: Summarizer + ?Sized>(
+    summarizer: &S,
+    filing: &ProviderFiling,
+    text: &str,
+) -> Result<Summary> {
+    let title = match (&filing.company_name, &filing.filing_type) {
+        (Some(name), Some(form)) => Some(format!("{name} {form}")),
+        (Some(name), None) => Some(name.clone()),
+        (None, Some(form)) => Some(form.clone()),
+        (None, None) => None,
+    };
+    summarize_text(summarizer, DocumentKind::Filing, title.as_deref(), text).await
+}
+
+/// Summarize a cluster of related news articles.
+///
+/// Each article contributes one `"{source}: {title}"` line; grouping articles
+/// into clusters (by symbol, topic, or time window) is left to the caller.
+pub async fn summarize_news<S: Summarizer + ?Sized>(
+    summarizer: &S,
+    articles: &[News],
+) -> Result<Summary> {
+    let text = articles
+        .iter()
+        .map(|a| format!("{}: {}", a.source, a.title))
+        .collect::<Vec<_>>()
+        .join("\n");
+    summarize_text(summarizer, DocumentKind::NewsCluster, None, &text).await
+}
+
+/// Extractive baseline: keeps the first `n` sentences (or lines, for news
+/// clusters) of the document.
+#[derive(Debug, Clone, Copy)]
+pub struct LeadSentences {
+    n: usize,
+}
+
+impl LeadSentences {
+    /// Keep the first `n` sentences.
+    pub fn new(n: usize) -> Self {
+        Self { n }
+    }
+}
+
+#[async_trait]
+impl Summarizer for LeadSentences {
+    fn id(&self) -> &'static str {
+        "lead_sentences"
+    }
+
+    async fn summarize(&self, request: SummaryRequest<'_>) -> Result<String> {
+        if request.kind == DocumentKind::NewsCluster {
+            return Ok(request
+                .text
+                .lines()
+                .take(self.n)
+                .collect::<Vec<_>>()
+                .join("\n"));
+        }
+        let mut out = String::new();
+        let mut taken = 0;
+        let mut start = 0;
+        for (idx, c) in request.text.char_indices() {
+            if taken == self.n {
+                break;
+            }
+            if matches!(c, '.' | '!' | '?') {
+                let end = idx + c.len_utf8();
+                let sentence = request.text[start..end].trim();
+                if !sentence.is_empty() {
+                    if !out.is_empty() {
+                        out.push(' ');
+                    }
+                    out.push_str(sentence);
+                    taken += 1;
+                }
+                start = end;
+            }
+        }
+        if taken < self.n {
+            let rest = request.text[start..].trim();
+            if !rest.is_empty() {
+                if !out.is_empty() {
+                    out.push(' ');
+                }
+                out.push_str(rest);
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_lead_sentences() {
+        let s = summarize_text(
+            &LeadSentences::new(2),
+            DocumentKind::Text,
+            None,
+            "Revenue rose. Margins expanded! Guidance was cut? Shares fell.",
+        )
+        .await
+        .unwrap();
+        assert_eq!(s.text, "Revenue rose. Margins expanded!");
+        assert_eq!(s.summarizer, "lead_sentences");
+        assert_eq!(s.kind, DocumentKind::Text);
+    }
+
+    #[tokio::test]
+    async fn test_lead_sentences_trailing_fragment() {
+        let out = LeadSentences::new(3)
+            .summarize(SummaryRequest {
+                kind: DocumentKind::Filing,
+                title: None,
+                text: "One. Two without stop",
+            })
+            .await
+            .unwrap();
+        assert_eq!(out, "One. Two without stop");
+    }
+
+    #[tokio::test]
+    async fn test_empty_text_rejected() {
+        let err = summarize_text(&LeadSentences::new(1), DocumentKind::Text, None, "  ")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, FinanceError::InvalidParameter { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_news_cluster_lines() {
+        let articles = vec![
+            News::new(
+                "A up".into(),
+                "l1".into(),
+                "Reuters".into(),
+                "".into(),
+                "".into(),
+            ),
+            News::new(
+                "B down".into(),
+                "l2".into(),
+                "CNBC".into(),
+                "".into(),
+                "".into(),
+            ),
+        ];
+        let s = summarize_news(&LeadSentences::new(1), &articles)
+            .await
+            .unwrap();
+        assert_eq!(s.kind, DocumentKind::NewsCluster);
+        assert_eq!(s.text, "Reuters: A up");
+    }
+
+    #[tokio::test]
+    async fn test_filing_title_passed() {
+        struct Echo;
+        #[async_trait]
+        impl Summarizer for Echo {
+            async fn summarize(&self, request: SummaryRequest<'_>) -> Result<String> {
+                Ok(request.title.unwrap_or_default().to_string())
+            }
+        }
+        let filing = ProviderFiling {
+            filing_type: Some("10-K".into()),
+            company_name: Some("Acme".into()),
+            ..Default::default()
+        };
+        let s = summarize_filing(&Echo, &filing, "body").await.unwrap();
+        assert_eq!(s.text, "Acme 10-K");
+        assert_eq!(s.summarizer, "custom");
+    }
+}
@@ -0,0 +1,283 @@
+//! Validated ticker symbol type.
+//!
+//! [`Symbol`] normalizes user input (trims whitespace, upper-cases) and
+//! rejects strings Yahoo Finance can never resolve, so typos surface as an
+//! [`InvalidParameter`](crate::FinanceError::InvalidParameter) at build time
+//! instead of a confusing `SymbolNotFound` after a network round-trip.
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::{FinanceError, Result};
+
+/// Longest symbol accepted (Yahoo's longest real symbols are well under this).
+const MAX_LEN: usize = 32;
+
+/// Yahoo Finance exchange suffixes (the part after the final `.`).
+///
+/// Only these are treated as exchange suffixes; share-class dots such as
+/// `BRK.B` are left as part of the base symbol.
+const EXCHANGE_SUFFIXES: &[&str] = &[
+    "AS", "AT", "AX", "BA", "BC", "BD", "BE", "BK", "BO", "BR", "CA", "CN", "CO", "CR", "DE", "DU",
+    "F", "HA", "HE", "HK", "HM", "IC", "IL", "IR", "IS", "JK", "JO", "KL", "KQ", "KS", "KW", "L",
+    "LS", "MC", "ME", "MI", "MU", "MX", "NE", "NS", "NZ", "OL", "PA", "PR", "QA", "RG", "SA",
+    "SAU", "SG", "SI", "SN", "SR", "SS", "ST", "SW", "SZ", "T", "TA", "TI", "TL", "TO", "TW",
+    "TWO", "V", "VI", "VN", "VS", "WA", "XA", "XC",
+];
+
+/// A normalized, validated ticker symbol.
+///
+/// Parsing trims surrounding whitespace and upper-cases the input, then checks
+/// it only contains characters Yahoo uses in symbols (`A-Z`, `0-9`, `.`, `-`,
+/// `^`, `=`, `&`, `_`). Cloning is cheap (the string is reference-counted).
+///
+/// Existing APIs that take `impl Into<String>` accept a `Symbol` as well.
+///
+/// ```
+/// use finance_query::Symbol;
+///
+/// let sym: Symbol = " 7203.t ".parse()?;
+/// assert_eq!(sym.as_str(), "7203.T");
+/// assert_eq!(sym.base(), "7203");
+/// assert_eq!(sym.exchange_suffix(), Some("T"));
+///
+/// assert!(Symbol::parse("AA PL").is_err());
+/// # Ok::<(), finance_query::FinanceError>(())
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol(Arc<str>);
+
+impl Symbol {
+    /// Parse and normalize a symbol.
+    ///
+    /// Returns [`FinanceError::InvalidParameter`] for empty input, input longer
+    /// than 32 characters, or characters Yahoo never uses in symbols
+    /// (including interior whitespace).
+    pub fn parse(input: &str) -> Result<Self> {
+        let trimmed = input.trim();
+        let invalid = |reason: String| FinanceError::InvalidParameter {
+            param: "symbol".to_string(),
+            reason,
+        };
+        if trimmed.is_empty() {
+            return Err(invalid("symbol is empty".to_string()));
+        }
+        if trimmed.len() > MAX_LEN {
+            return Err(invalid(format!(
+                "'{trimmed}' is longer than {MAX_LEN} characters"
+            )));
+        }
+        if let Some(c) = trimmed.chars().find(|c| {
+            !(c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '^' | '=' | '&' | '_'))
+        }) {
+            return Err(invalid(format!(
+                "'{trimmed}' contains invalid character {c:?}"
+            )));
+        }
+        Ok(Self(trimmed.to_ascii_uppercase().into()))
+    }
+
+    /// The normalized symbol string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Exchange suffix without the leading dot (`"T"` for `7203.T`), if any.
+    ///
+    /// Only known Yahoo exchange suffixes count; `BRK.B` has none.
+    pub fn exchange_suffix(&self) -> Option<&str> {
+        let (_, suffix) = self.0.rsplit_once('.')?;
+        EXCHANGE_SUFFIXES.contains(&suffix).then_some(suffix)
+    }
+
+    /// The symbol without its exchange suffix (`"7203"` for `7203.T`).
+    pub fn base(&self) -> &str {
+        match self.exchange_suffix() {
+            Some(suffix) => &self.0[..self.0.len() - suffix.len() - 1],
+            None => &self.0,
+        }
+    }
+
+    /// Replace (or add) the exchange suffix, e.g. `SHOP` → `SHOP.TO`.
+    ///
+    /// `suffix` may be given with or without the leading dot.
+    pub fn with_exchange_suffix(&self, suffix: &str) -> Result<Self> {
+        let suffix = suffix.trim().trim_start_matches('.');
+        Self::parse(&format!("{}.{suffix}", self.base()))
+    }
+
+    /// Whether this is an index symbol (`^GSPC`).
+    pub fn is_index(&self) -> bool {
+        self.0.starts_with('^')
+    }
+
+    /// Whether this is a currency pair (`EURUSD=X`).
+    pub fn is_currency_pair(&self) -> bool {
+        self.0.ends_with("=X")
+    }
+
+    /// Whether this is a continuous futures contract (`ES=F`).
+    pub fn is_future(&self) -> bool {
+        self.0.ends_with("=F")
+    }
+
+    pub(crate) fn into_arc(self) -> Arc<str> {
+        self.0
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Symbol({:?})", &*self.0)
+    }
+}
+
+impl FromStr for Symbol {
+    type Err = FinanceError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for Symbol {
+    type Error = FinanceError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<String> for Symbol {
+    type Error = FinanceError;
+
+    fn try_from(s: String) -> Result<Self> {
+        Self::parse(&s)
+    }
+}
+
+impl From<Symbol> for String {
+    fn from(symbol: Symbol) -> Self {
+        symbol.0.to_string()
+    }
+}
+
+impl From<&Symbol> for String {
+    fn from(symbol: &Symbol) -> Self {
+        symbol.0.to_string()
+    }
+}
+
+impl Deref for Symbol {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Symbol {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for Symbol {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for Symbol {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Symbol {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl Serialize for Symbol {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Symbol {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::parse(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_case_and_whitespace() {
+        let sym = Symbol::parse("  brk-b\t").unwrap();
+        assert_eq!(sym, "BRK-B");
+        assert_eq!(sym.to_string(), "BRK-B");
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        assert!(Symbol::parse("").is_err());
+        assert!(Symbol::parse("   ").is_err());
+        assert!(Symbol::parse("AA PL").is_err());
+        assert!(Symbol::parse("AAPL/").is_err());
+        assert!(Symbol::parse(&"A".repeat(MAX_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn exchange_suffix_and_base() {
+        let toyota = Symbol::parse("7203.T").unwrap();
+        assert_eq!(toyota.exchange_suffix(), Some("T"));
+        assert_eq!(toyota.base(), "7203");
+
+        let brk = Symbol::parse("BRK.B").unwrap();
+        assert_eq!(brk.exchange_suffix(), None);
+        assert_eq!(brk.base(), "BRK.B");
+
+        let fx = Symbol::parse("EURUSD=X").unwrap();
+        assert!(fx.is_currency_pair());
+        assert_eq!(fx.base(), "EURUSD=X");
+    }
+
+    #[test]
+    fn with_exchange_suffix_replaces_existing() {
+        let shop = Symbol::parse("SHOP").unwrap();
+        assert_eq!(shop.with_exchange_suffix(".to").unwrap(), "SHOP.TO");
+        let vod = Symbol::parse("VOD.L").unwrap();
+        assert_eq!(vod.with_exchange_suffix("DE").unwrap(), "VOD.DE");
+    }
+
+    #[test]
+    fn kind_helpers() {
+        assert!(Symbol::parse("^gspc").unwrap().is_index());
+        assert!(Symbol::parse("es=f").unwrap().is_future());
+        assert!(!Symbol::parse("AAPL").unwrap().is_index());
+    }
+
+    #[test]
+    fn serde_roundtrip_validates() {
+        let sym: Symbol = serde_json::from_str("\"msft\"").unwrap();
+        assert_eq!(serde_json::to_string(&sym).unwrap(), "\"MSFT\"");
+        assert!(serde_json::from_str::<Symbol>("\"MS FT\"").is_err());
+    }
+}
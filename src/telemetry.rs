@@ -0,0 +1,381 @@
+//! Process-wide request telemetry.
+//!
+//! Every HTTP request the crate makes is counted here (provider adapters,
+//! scrapers, Yahoo session setup, and signal webhooks), along with cache hits,
+//! provider fallbacks, and rate-limiter waits. The one exception is the
+//! one-off model download for the `translation-offline` backend. Read the
+//! counters with [`stats`](crate::stats) and clear them with
+//! [`reset_stats`](crate::reset_stats).
+//!
+//! Counters are global to the process and cheap to update (atomics plus a
+//! short-lived lock for per-endpoint maps), so they are always on — no tracing
+//! subscriber or feature flag is required.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+/// Most recent failure seen for an endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct EndpointError {
+    /// Error message (HTTP status or transport error)
+    pub message: String,
+    /// When the error occurred (Unix epoch seconds)
+    pub at: i64,
+}
+
+/// Snapshot of process-wide request counters.
+///
+/// Returned by [`stats`](crate::stats). Endpoints are keyed by host and path
+/// with trailing symbol/ID segments stripped, e.g.
+/// `"query2.finance.yahoo.com/v10/finance/quoteSummary"`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TelemetryStats {
+    /// Start of the counting window (Unix epoch seconds): first use or last reset
+    pub since: i64,
+    /// Total HTTP requests sent
+    pub requests: u64,
+    /// HTTP requests sent, per endpoint
+    pub requests_by_endpoint: BTreeMap<String, u64>,
+    /// Response body bytes read, counted as the body is consumed. Yahoo bodies
+    /// are counted compressed (the crate decodes them itself); other providers'
+    /// bodies are counted after decompression.
+    pub bytes_received: u64,
+    /// Ticker/Tickers cache lookups served from cache
+    pub cache_hits: u64,
+    /// Ticker/Tickers cache lookups that required a fetch
+    pub cache_misses: u64,
+    /// Times a request fell back to the next provider after a failure. Failed
+    /// HTTP requests are not retried against the same provider.
+    pub fallbacks: u64,
+    /// Times a request waited on a rate limiter
+    pub rate_limit_waits: u64,
+    /// Total time spent waiting on rate limiters, in milliseconds
+    pub rate_limit_wait_ms: u64,
+    /// Failed requests (transport errors and non-2xx responses)
+    pub errors: u64,
+    /// Most recent failure per endpoint
+    pub last_error_by_endpoint: BTreeMap<String, EndpointError>,
+}
+
+impl TelemetryStats {
+    /// Fraction of cache lookups served from cache, or `None` before any lookup.
+    pub fn cache_hit_rate(&self) -> Option<f64> {
+        let total = self.cache_hits + self.cache_misses;
+        (total > 0).then(|| self.cache_hits as f64 / total as f64)
+    }
+}
+
+#[derive(Default)]
+struct EndpointMaps {
+    requests: BTreeMap<String, u64>,
+    last_error: BTreeMap<String, EndpointError>,
+}
+
+struct Counters {
+    since: AtomicI64,
+    requests: AtomicU64,
+    bytes_received: AtomicU64,
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
+    fallbacks: AtomicU64,
+    rate_limit_waits: AtomicU64,
+    rate_limit_wait_ms: AtomicU64,
+    errors: AtomicU64,
+    endpoints: Mutex<EndpointMaps>,
+}
+
+static COUNTERS: LazyLock<Counters> = LazyLock::new(|| Counters {
+    since: AtomicI64::new(now()),
+    requests: AtomicU64::new(0),
+    bytes_received: AtomicU64::new(0),
+    cache_hits: AtomicU64::new(0),
+    cache_misses: AtomicU64::new(0),
+    fallbacks: AtomicU64::new(0),
+    rate_limit_waits: AtomicU64::new(0),
+    rate_limit_wait_ms: AtomicU64::new(0),
+    errors: AtomicU64::new(0),
+    endpoints: Mutex::new(EndpointMaps::default()),
+});
+
+fn now() -> i64 {
+    chrono::Utc::now().timestamp()
+}
+
+fn endpoints() -> std::sync::MutexGuard<'static, EndpointMaps> {
+    COUNTERS
+        .endpoints
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Return a snapshot of process-wide request counters.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let ticker = finance_query::Ticker::new("AAPL").await?;
+/// let _quote: finance_query::Quote = ticker.quote().await?;
+///
+/// let stats = finance_query::stats();
+/// println!("{} requests, {} bytes", stats.requests, stats.bytes_received);
+/// for (endpoint, err) in &stats.last_error_by_endpoint {
+///     println!("{endpoint}: {}", err.message);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn stats() -> TelemetryStats {
+    let c = &*COUNTERS;
+    let maps = endpoints();
+    TelemetryStats {
+        since: c.since.load(Ordering::Relaxed),
+        requests: c.requests.load(Ordering::Relaxed),
+        requests_by_endpoint: maps.requests.clone(),
+        bytes_received: c.bytes_received.load(Ordering::Relaxed),
+        cache_hits: c.cache_hits.load(Ordering::Relaxed),
+        cache_misses: c.cache_misses.load(Ordering::Relaxed),
+        fallbacks: c.fallbacks.load(Ordering::Relaxed),
+        rate_limit_waits: c.rate_limit_waits.load(Ordering::Relaxed),
+        rate_limit_wait_ms: c.rate_limit_wait_ms.load(Ordering::Relaxed),
+        errors: c.errors.load(Ordering::Relaxed),
+        last_error_by_endpoint: maps.last_error.clone(),
+    }
+}
+
+/// Reset all counters to zero and restart the counting window.
+pub fn reset_stats() {
+    let c = &*COUNTERS;
+    let mut maps = endpoints();
+    *maps = EndpointMaps::default();
+    for counter in [
+        &c.requests,
+        &c.bytes_received,
+        &c.cache_hits,
+        &c.cache_misses,
+        &c.fallbacks,
+        &c.rate_limit_waits,
+        &c.rate_limit_wait_ms,
+        &c.errors,
+    ] {
+        counter.store(0, Ordering::Relaxed);
+    }
+    c.since.store(now(), Ordering::Relaxed);
+}
+
+/// Record the outcome of a sent request and pass it through unchanged.
+///
+/// Wrap every `send().await` with this so the request, its response size, and
+/// any failure are attributed to the endpoint. The response body is wrapped so
+/// bytes are counted as they are read, whatever the transfer encoding.
+pub(crate) fn track(
+    url: &str,
+    result: reqwest::Result<reqwest::Response>,
+) -> reqwest::Result<reqwest::Response> {
+    let endpoint = endpoint_key(url);
+    COUNTERS.requests.fetch_add(1, Ordering::Relaxed);
+    *endpoints().requests.entry(endpoint.clone()).or_insert(0) += 1;
+
+    match result {
+        Ok(resp) => {
+            let status = resp.status();
+            if !status.is_success() {
+                record_error(endpoint, format!("HTTP {}", status.as_u16()));
+            }
+            Ok(counted(resp))
+        }
+        Err(e) => {
+            record_error(endpoint, e.to_string());
+            Err(e)
+        }
+    }
+}
+
+/// Rebuild a response around a body that adds every chunk read to
+/// `bytes_received`.
+fn counted(resp: reqwest::Response) -> reqwest::Response {
+    use reqwest::ResponseBuilderExt;
+
+    let url = resp.url().clone();
+    let (parts, body) = http::Response::<reqwest::Body>::from(resp).into_parts();
+    let mut http = http::Response::from_parts(parts, reqwest::Body::wrap(CountingBody(body)));
+    // The conversion to `http::Response` drops the URL; carry it back over.
+    if let Ok(tagged) = http::Response::builder().url(url).body(()) {
+        http.extensions_mut()
+            .extend(tagged.into_parts().0.extensions);
+    }
+    reqwest::Response::from(http)
+}
+
+/// Body wrapper that counts data frames as they are polled.
+struct CountingBody(reqwest::Body);
+
+impl http_body::Body for CountingBody {
+    type Data = <reqwest::Body as http_body::Body>::Data;
+    type Error = <reqwest::Body as http_body::Body>::Error;
+
+    fn poll_frame(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Result<http_body::Frame<Self::Data>, Self::Error>>> {
+        let poll = std::pin::Pin::new(&mut self.0).poll_frame(cx);
+        if let std::task::Poll::Ready(Some(Ok(frame))) = &poll
+            && let Some(data) = frame.data_ref()
+        {
+            COUNTERS
+                .bytes_received
+                .fetch_add(data.len() as u64, Ordering::Relaxed);
+        }
+        poll
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.0.is_end_stream()
+    }
+
+    fn size_hint(&self) -> http_body::SizeHint {
+        self.0.size_hint()
+    }
+}
+
+fn record_error(endpoint: String, message: String) {
+    COUNTERS.errors.fetch_add(1, Ordering::Relaxed);
+    endpoints()
+        .last_error
+        .insert(endpoint, EndpointError { message, at: now() });
+}
+
+/// Record a cache lookup on a `Ticker`/`Tickers` cache.
+pub(crate) fn record_cache_lookup(hit: bool) {
+    let counter = if hit {
+        &COUNTERS.cache_hits
+    } else {
+        &COUNTERS.cache_misses
+    };
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Record a fallback attempt against another provider after a failure.
+pub(crate) fn record_fallback() {
+    COUNTERS.fallbacks.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Record time spent waiting on a rate limiter.
+#[cfg_attr(
+    not(any(
+        feature = "edgar",
+        feature = "fred",
+        feature = "crypto",
+        feature = "alphavantage",
+        feature = "polygon",
+        feature = "fmp"
+    )),
+    allow(dead_code)
+)]
+pub(crate) fn record_rate_limit_wait(waited: Duration) {
+    COUNTERS.rate_limit_waits.fetch_add(1, Ordering::Relaxed);
+    COUNTERS
+        .rate_limit_wait_ms
+        .fetch_add(waited.as_millis() as u64, Ordering::Relaxed);
+}
+
+/// Normalise a request URL to an endpoint key: host plus the leading path
+/// segments, stopping at the first segment that looks like a symbol or ID
+/// (uppercase-only, contains a dot, or starts with a non-letter other than
+/// a `v1`-style version tag).
+fn endpoint_key(url: &str) -> String {
+    let rest = url.split_once("://").map_or(url, |(_, r)| r);
+    let rest = rest.split(['?', '#']).next().unwrap_or(rest);
+    let mut parts = rest.split('/');
+    let mut key = parts.next().unwrap_or_default().to_string();
+    for seg in parts.filter(|s| !s.is_empty()) {
+        let is_version =
+            seg.len() > 1 && seg.starts_with('v') && seg[1..].chars().all(|c| c.is_ascii_digit());
+        let is_word = seg.starts_with(|c: char| c.is_ascii_lowercase())
+            && seg
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        if !(is_version || is_word) {
+            break;
+        }
+        key.push('/');
+        key.push_str(seg);
+    }
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_key_strips_symbols() {
+        assert_eq!(
+            endpoint_key(
+                "https://query2.finance.yahoo.com/v10/finance/quoteSummary/AAPL?modules=x"
+            ),
+            "query2.finance.yahoo.com/v10/finance/quoteSummary"
+        );
+        assert_eq!(
+            endpoint_key("https://query1.finance.yahoo.com/v8/finance/chart/%5EGSPC"),
+            "query1.finance.yahoo.com/v8/finance/chart"
+        );
+        assert_eq!(
+            endpoint_key("https://data.sec.gov/submissions/CIK0000320193.json"),
+            "data.sec.gov/submissions"
+        );
+        assert_eq!(
+            endpoint_key("https://api.stlouisfed.org/fred/series/observations?series_id=GDP"),
+            "api.stlouisfed.org/fred/series/observations"
+        );
+    }
+
+    #[test]
+    fn test_cache_hit_rate() {
+        let stats = TelemetryStats {
+            cache_hits: 3,
+            cache_misses: 1,
+            ..Default::default()
+        };
+        assert_eq!(stats.cache_hit_rate(), Some(0.75));
+        assert_eq!(TelemetryStats::default().cache_hit_rate(), None);
+    }
+
+    #[test]
+    fn test_counters_accumulate() {
+        // Other tests may touch the global counters concurrently, so only
+        // assert monotonic increases rather than exact values.
+        let before = stats();
+        record_cache_lookup(true);
+        record_fallback();
+        record_rate_limit_wait(Duration::from_millis(5));
+        let after = stats();
+        assert!(after.cache_hits > before.cache_hits);
+        assert!(after.fallbacks > before.fallbacks);
+        assert!(after.rate_limit_wait_ms >= before.rate_limit_wait_ms + 5);
+    }
+
+    #[tokio::test]
+    async fn test_track_counts_body_bytes_read() {
+        use reqwest::ResponseBuilderExt;
+
+        let url = reqwest::Url::parse("https://example.com/test").unwrap();
+        let http = http::Response::builder()
+            .status(200)
+            .url(url.clone())
+            .body(reqwest::Body::from("hello world"))
+            .unwrap();
+        let resp = track(url.as_str(), Ok(reqwest::Response::from(http))).unwrap();
+        assert_eq!(resp.url(), &url);
+        let before = stats().bytes_received;
+        assert_eq!(resp.text().await.unwrap(), "hello world");
+        assert!(stats().bytes_received >= before + 11);
+    }
+}
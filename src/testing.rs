@@ -0,0 +1,672 @@
+//! Deterministic, network-free test doubles (requires `testing` feature).
+//!
+//! Build canned [`Quote`], [`Chart`] and [`Options`] values, serve them from a
+//! [`MockTicker`] that mirrors the [`Ticker`] data methods, and check results
+//! with the assertion helpers — so downstream trading logic can be unit tested
+//! without network access or hand-maintained JSON fixtures.
+//!
+//! Write the logic under test against the [`TickerData`] trait; pass a
+//! [`Ticker`] in production and a [`MockTicker`] in tests.
+//!
+//! # Example
+//!
+//! ```
+//! use finance_query::testing::{ChartBuilder, MockTicker, QuoteBuilder, TickerData};
+//! use finance_query::{Interval, TimeRange};
+//!
+//! async fn above_average<T: TickerData>(t: &T) -> finance_query::Result<bool> {
+//!     let chart = t.chart(Interval::OneDay, TimeRange::OneMonth).await?;
+//!     let avg = chart.candles.iter().map(|c| c.close).sum::<f64>() / chart.candles.len() as f64;
+//!     let quote = t.quote().await?;
+//!     Ok(quote.regular_market_price.and_then(|p| p.raw).unwrap_or(0.0) > avg)
+//! }
+//!
+//! # tokio_test::block_on(async {
+//! let mock = MockTicker::new("AAPL")
+//!     .with_quote(QuoteBuilder::new("AAPL").price(110.0).build())
+//!     .with_chart(
+//!         ChartBuilder::new("AAPL")
+//!             .interval(Interval::OneDay)
+//!             .range(TimeRange::OneMonth)
+//!             .closes(&[100.0, 101.0, 102.0])
+//!             .build(),
+//!     );
+//! assert!(above_average(&mock).await.unwrap());
+//! # });
+//! ```
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use serde_json::{Map, Value, json};
+
+use crate::constants::{Interval, TimeRange};
+use crate::error::{FinanceError, Result};
+use crate::models::chart::{Candle, Chart, ChartMeta, Dividend};
+use crate::models::corporate::news::News;
+use crate::models::options::OptionContract;
+use crate::models::options::Options;
+use crate::models::options::response::{OptionChainContainer, OptionChainData, OptionChainResult};
+use crate::models::quote::Quote;
+use crate::ticker::Ticker;
+use crate::utils::filter_by_range;
+
+/// Seconds per candle step used by [`ChartBuilder::closes`] when no interval is set.
+const DEFAULT_STEP_SECS: i64 = 86_400;
+
+/// Base timestamp for generated candles (2024-01-02 00:00:00 UTC).
+const DEFAULT_START_TS: i64 = 1_704_153_600;
+
+// ============================================================================
+// Shared data-access trait
+// ============================================================================
+
+/// Symbol data methods shared by [`Ticker`] and [`MockTicker`].
+///
+/// Write code under test against this trait to swap live data for canned data.
+#[async_trait]
+pub trait TickerData: Send + Sync {
+    /// Ticker symbol.
+    fn symbol(&self) -> &str;
+    /// Full quote.
+    async fn quote(&self) -> Result<Quote>;
+    /// Historical OHLCV chart.
+    async fn chart(&self, interval: Interval, range: TimeRange) -> Result<Chart>;
+    /// Options chain for an expiration (`None` = nearest).
+    async fn options(&self, date: Option<i64>) -> Result<Options>;
+    /// Recent news.
+    async fn news(&self) -> Result<Vec<News>>;
+    /// Dividend history.
+    async fn dividends(&self, range: TimeRange) -> Result<Vec<Dividend>>;
+}
+
+#[async_trait]
+impl TickerData for Ticker {
+    fn symbol(&self) -> &str {
+        Ticker::symbol(self)
+    }
+    async fn quote(&self) -> Result<Quote> {
+        Ticker::quote(self).await
+    }
+    async fn chart(&self, interval: Interval, range: TimeRange) -> Result<Chart> {
+        Ticker::chart(self, interval, range).await
+    }
+    async fn options(&self, date: Option<i64>) -> Result<Options> {
+        Ticker::options(self, date).await
+    }
+    async fn news(&self) -> Result<Vec<News>> {
+        Ticker::news(self).await
+    }
+    async fn dividends(&self, range: TimeRange) -> Result<Vec<Dividend>> {
+        Ticker::dividends(self, range).await
+    }
+}
+
+// ============================================================================
+// MockTicker
+// ============================================================================
+
+/// A [`Ticker`] stand-in that serves canned data.
+///
+/// Methods with no canned value return [`FinanceError::SymbolNotFound`],
+/// matching what a live ticker returns for an unknown symbol.
+#[derive(Debug, Clone)]
+pub struct MockTicker {
+    symbol: String,
+    quote: Option<Quote>,
+    charts: HashMap<(Interval, TimeRange), Chart>,
+    fallback_chart: Option<Chart>,
+    options: HashMap<Option<i64>, Options>,
+    news: Vec<News>,
+    dividends: Vec<Dividend>,
+}
+
+impl MockTicker {
+    /// Create an empty mock for `symbol`.
+    pub fn new(symbol: impl Into<String>) -> Self {
+        Self {
+            symbol: symbol.into(),
+            quote: None,
+            charts: HashMap::new(),
+            fallback_chart: None,
+            options: HashMap::new(),
+            news: Vec::new(),
+            dividends: Vec::new(),
+        }
+    }
+
+    /// Serve this quote from [`quote`](TickerData::quote).
+    pub fn with_quote(mut self, quote: Quote) -> Self {
+        self.quote = Some(quote);
+        self
+    }
+
+    /// Serve this chart.
+    ///
+    /// When the chart carries an interval and range it is served only for
+    /// that pair; otherwise it answers every `chart()` call without a more
+    /// specific match.
+    pub fn with_chart(mut self, chart: Chart) -> Self {
+        match (chart.interval, chart.range) {
+            (Some(i), Some(r)) => {
+                self.charts.insert((i, r), chart);
+            }
+            _ => self.fallback_chart = Some(chart),
+        }
+        self
+    }
+
+    /// Serve this options chain for `date` (`None` = nearest expiration).
+    pub fn with_options(mut self, date: Option<i64>, options: Options) -> Self {
+        self.options.insert(date, options);
+        self
+    }
+
+    /// Serve these news articles.
+    pub fn with_news(mut self, news: Vec<News>) -> Self {
+        self.news = news;
+        self
+    }
+
+    /// Serve these dividends (filtered by range like the live ticker).
+    pub fn with_dividends(mut self, dividends: Vec<Dividend>) -> Self {
+        self.dividends = dividends;
+        self
+    }
+
+    fn not_found(&self, what: &str) -> FinanceError {
+        FinanceError::SymbolNotFound {
+            symbol: Some(self.symbol.clone()),
+            context: format!("MockTicker has no canned {what}"),
+        }
+    }
+}
+
+#[async_trait]
+impl TickerData for MockTicker {
+    fn symbol(&self) -> &str {
+        &self.symbol
+    }
+    async fn quote(&self) -> Result<Quote> {
+        self.quote.clone().ok_or_else(|| self.not_found("quote"))
+    }
+    async fn chart(&self, interval: Interval, range: TimeRange) -> Result<Chart> {
+        self.charts
+            .get(&(interval, range))
+            .or(self.fallback_chart.as_ref())
+            .cloned()
+            .ok_or_else(|| self.not_found("chart"))
+    }
+    async fn options(&self, date: Option<i64>) -> Result<Options> {
+        self.options
+            .get(&date)
+            .cloned()
+            .ok_or_else(|| self.not_found("options"))
+    }
+    async fn news(&self) -> Result<Vec<News>> {
+        Ok(self.news.clone())
+    }
+    async fn dividends(&self, range: TimeRange) -> Result<Vec<Dividend>> {
+        Ok(filter_by_range(self.dividends.clone(), range))
+    }
+}
+
+// ============================================================================
+// Builders
+// ============================================================================
+
+/// Builder for canned [`Quote`] values.
+///
+/// Unset fields are `None`, exactly like a sparse Yahoo response.
+#[derive(Debug, Clone)]
+pub struct QuoteBuilder {
+    fields: Map<String, Value>,
+}
+
+impl QuoteBuilder {
+    /// Start a quote for `symbol`.
+    pub fn new(symbol: impl Into<String>) -> Self {
+        let mut fields = Map::new();
+        fields.insert("symbol".into(), Value::String(symbol.into()));
+        Self { fields }
+    }
+
+    /// Set `regular_market_price`.
+    pub fn price(self, value: f64) -> Self {
+        self.number("regularMarketPrice", json!(value))
+    }
+    /// Set `regular_market_previous_close`.
+    pub fn previous_close(self, value: f64) -> Self {
+        self.number("regularMarketPreviousClose", json!(value))
+    }
+    /// Set `regular_market_change_percent`.
+    pub fn change_percent(self, value: f64) -> Self {
+        self.number("regularMarketChangePercent", json!(value))
+    }
+    /// Set `regular_market_volume`.
+    pub fn volume(self, value: i64) -> Self {
+        self.number("regularMarketVolume", json!(value))
+    }
+    /// Set `market_cap`.
+    pub fn market_cap(self, value: i64) -> Self {
+        self.number("marketCap", json!(value))
+    }
+    /// Set `trailing_pe`.
+    pub fn trailing_pe(self, value: f64) -> Self {
+        self.number("trailingPE", json!(value))
+    }
+    /// Set `currency`.
+    pub fn currency(self, value: impl Into<String>) -> Self {
+        self.field("currency", Value::String(value.into()))
+    }
+    /// Set `short_name`.
+    pub fn short_name(self, value: impl Into<String>) -> Self {
+        self.field("shortName", Value::String(value.into()))
+    }
+
+    /// Set any field by its camelCase JSON key (e.g. `"fiftyTwoWeekHigh"`).
+    ///
+    /// Numeric `FormattedValue` fields accept a bare number.
+    pub fn field(mut self, key: &str, value: Value) -> Self {
+        self.fields.insert(key.to_string(), value);
+        self
+    }
+
+    fn number(self, key: &str, raw: Value) -> Self {
+        let fmt = raw.to_string();
+        self.field(key, json!({ "raw": raw, "fmt": fmt }))
+    }
+
+    /// Build the quote.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value set via [`field`](Self::field) has the wrong JSON type.
+    pub fn build(self) -> Quote {
+        serde_json::from_value(Value::Object(self.fields))
+            .expect("QuoteBuilder field has an invalid JSON type")
+    }
+}
+
+/// Builder for canned [`Chart`] values.
+#[derive(Debug, Clone)]
+pub struct ChartBuilder {
+    symbol: String,
+    interval: Option<Interval>,
+    range: Option<TimeRange>,
+    currency: Option<String>,
+    start: i64,
+    candles: Vec<Candle>,
+}
+
+impl ChartBuilder {
+    /// Start a chart for `symbol`.
+    pub fn new(symbol: impl Into<String>) -> Self {
+        Self {
+            symbol: symbol.into(),
+            interval: None,
+            range: None,
+            currency: Some("USD".to_string()),
+            start: DEFAULT_START_TS,
+            candles: Vec::new(),
+        }
+    }
+
+    /// Set the chart interval (also sets the spacing used by [`closes`](Self::closes)).
+    pub fn interval(mut self, interval: Interval) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+    /// Set the chart range.
+    pub fn range(mut self, range: TimeRange) -> Self {
+        self.range = Some(range);
+        self
+    }
+    /// Set the chart currency (default `"USD"`).
+    pub fn currency(mut self, currency: impl Into<String>) -> Self {
+        self.currency = Some(currency.into());
+        self
+    }
+    /// Set the timestamp of the first generated candle.
+    pub fn start(mut self, timestamp: i64) -> Self {
+        self.start = timestamp;
+        self
+    }
+
+    /// Append one candle per close price, evenly spaced by the interval.
+    ///
+    /// Each candle opens at the previous close, with high/low bracketing the
+    /// open and close by 0.5%, and a constant volume of 1,000,000.
+    pub fn closes(mut self, closes: &[f64]) -> Self {
+        let step = self
+            .interval
+            .map(|i| i.approx_duration_secs())
+            .unwrap_or(DEFAULT_STEP_SECS);
+        let mut prev = self.candles.last().map(|c| c.close);
+        let first_ts = self
+            .candles
+            .last()
+            .map(|c| c.timestamp + step)
+            .unwrap_or(self.start);
+        for (i, &close) in closes.iter().enumerate() {
+            let open = prev.unwrap_or(close);
+            self.candles.push(candle(
+                first_ts + i as i64 * step,
+                open,
+                open.max(close) * 1.005,
+                open.min(close) * 0.995,
+                close,
+                1_000_000,
+            ));
+            prev = Some(close);
+        }
+        self
+    }
+
+    /// Append a fully specified candle.
+    pub fn candle(
+        mut self,
+        timestamp: i64,
+        open: f64,
+        high: f64,
+        low: f64,
+        close: f64,
+        volume: i64,
+    ) -> Self {
+        self.candles
+            .push(candle(timestamp, open, high, low, close, volume));
+        self
+    }
+
+    /// Build the chart.
+    pub fn build(self) -> Chart {
+        let last = self.candles.last();
+        let meta = ChartMeta {
+            symbol: self.symbol.clone(),
+            currency: self.currency,
+            regular_market_price: last.map(|c| c.close),
+            regular_market_time: last.map(|c| c.timestamp),
+            fifty_two_week_high: self.candles.iter().map(|c| c.high).reduce(f64::max),
+            fifty_two_week_low: self.candles.iter().map(|c| c.low).reduce(f64::min),
+            ..Default::default()
+        };
+        Chart {
+            symbol: self.symbol,
+            meta,
+            candles: self.candles,
+            interval: self.interval,
+            range: self.range,
+            provider_id: None,
+            adjustment: None,
+        }
+    }
+}
+
+/// Construct a single [`Candle`] (adjusted close equals close).
+pub fn candle(timestamp: i64, open: f64, high: f64, low: f64, close: f64, volume: i64) -> Candle {
+    Candle {
+        timestamp,
+        open,
+        high,
+        low,
+        close,
+        volume,
+        adj_close: Some(close),
+        provider_id: None,
+    }
+}
+
+/// Builder for canned [`Options`] chains.
+#[derive(Debug, Clone)]
+pub struct OptionsBuilder {
+    symbol: String,
+    chains: Vec<OptionChainData>,
+}
+
+impl OptionsBuilder {
+    /// Start an options chain for `symbol`.
+    pub fn new(symbol: impl Into<String>) -> Self {
+        Self {
+            symbol: symbol.into(),
+            chains: Vec::new(),
+        }
+    }
+
+    /// Start a new expiration; following `call`/`put` calls attach to it.
+    pub fn expiration(mut self, timestamp: i64) -> Self {
+        self.chains.push(OptionChainData {
+            expiration_date: timestamp,
+            has_mini_options: Some(false),
+            calls: Some(Vec::new()),
+            puts: Some(Vec::new()),
+        });
+        self
+    }
+
+    /// Add a call at `strike` with the given last price and implied volatility.
+    pub fn call(self, strike: f64, last_price: f64, implied_volatility: f64) -> Self {
+        self.contract(true, strike, last_price, implied_volatility)
+    }
+
+    /// Add a put at `strike` with the given last price and implied volatility.
+    pub fn put(self, strike: f64, last_price: f64, implied_volatility: f64) -> Self {
+        self.contract(false, strike, last_price, implied_volatility)
+    }
+
+    fn contract(mut self, is_call: bool, strike: f64, last: f64, iv: f64) -> Self {
+        if self.chains.is_empty() {
+            self = self.expiration(DEFAULT_START_TS + 30 * DEFAULT_STEP_SECS);
+        }
+        let chain = self.chains.last_mut().expect("expiration inserted above");
+        let kind = if is_call { 'C' } else { 'P' };
+        let contract = OptionContract {
+            contract_symbol: format!("{}{}{:08}", self.symbol, kind, (strike * 1000.0) as i64),
+            strike,
+            currency: Some("USD".to_string()),
+            last_price: Some(last),
+            change: None,
+            percent_change: None,
+            volume: Some(0),
+            open_interest: Some(0),
+            bid: Some(last),
+            ask: Some(last),
+            contract_size: Some("REGULAR".to_string()),
+            expiration: Some(chain.expiration_date),
+            last_trade_date: None,
+            implied_volatility: Some(iv),
+            in_the_money: None,
+        };
+        let side = if is_call {
+            &mut chain.calls
+        } else {
+            &mut chain.puts
+        };
+        side.get_or_insert_with(Vec::new).push(contract);
+        self
+    }
+
+    /// Build the options chain.
+    pub fn build(self) -> Options {
+        let expiration_dates: Vec<i64> = self.chains.iter().map(|c| c.expiration_date).collect();
+        let mut strikes: Vec<f64> = self
+            .chains
+            .iter()
+            .flat_map(|c| {
+                c.calls
+                    .iter()
+                    .chain(c.puts.iter())
+                    .flatten()
+                    .map(|k| k.strike)
+            })
+            .collect();
+        strikes.sort_by(f64::total_cmp);
+        strikes.dedup();
+        Options {
+            option_chain: OptionChainContainer {
+                result: vec![OptionChainResult {
+                    underlying_symbol: Some(self.symbol),
+                    expiration_dates: Some(expiration_dates),
+                    strikes: Some(strikes),
+                    has_mini_options: Some(false),
+                    quote: None,
+                    options: self.chains,
+                }],
+                error: None,
+            },
+            provider_id: None,
+        }
+    }
+}
+
+/// Construct a canned [`News`] article.
+pub fn news(title: impl Into<String>, source: impl Into<String>) -> News {
+    News::new(
+        title.into(),
+        String::new(),
+        source.into(),
+        String::new(),
+        String::new(),
+    )
+}
+
+// ============================================================================
+// Assertion helpers
+// ============================================================================
+
+/// Assert two floats are within `tolerance` of each other.
+///
+/// # Panics
+///
+/// Panics with both values and the difference when they are not.
+#[track_caller]
+pub fn assert_approx_eq(actual: f64, expected: f64, tolerance: f64) {
+    let diff = (actual - expected).abs();
+    assert!(
+        diff <= tolerance,
+        "expected {expected} ± {tolerance}, got {actual} (diff {diff})"
+    );
+}
+
+/// Assert candles are strictly ascending by timestamp.
+///
+/// # Panics
+///
+/// Panics at the first out-of-order pair.
+#[track_caller]
+pub fn assert_candles_sorted(candles: &[Candle]) {
+    if let Some((i, w)) = candles
+        .windows(2)
+        .enumerate()
+        .find(|(_, w)| w[1].timestamp <= w[0].timestamp)
+    {
+        panic!(
+            "candles not ascending at index {}: {} then {}",
+            i + 1,
+            w[0].timestamp,
+            w[1].timestamp
+        );
+    }
+}
+
+/// Assert every candle has a consistent OHLC shape
+/// (`low <= open, close <= high`, non-negative volume).
+///
+/// # Panics
+///
+/// Panics on the first invalid candle.
+#[track_caller]
+pub fn assert_valid_ohlc(candles: &[Candle]) {
+    for (i, c) in candles.iter().enumerate() {
+        let ok = c.low <= c.open.min(c.close) && c.high >= c.open.max(c.close) && c.volume >= 0;
+        assert!(ok, "invalid OHLC at index {i}: {c:?}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote_builder() {
+        let q = QuoteBuilder::new("AAPL")
+            .price(190.5)
+            .volume(1_000)
+            .currency("USD")
+            .field("fiftyTwoWeekHigh", json!(200.0))
+            .build();
+        assert_eq!(q.symbol, "AAPL");
+        assert_eq!(q.regular_market_price.unwrap().raw, Some(190.5));
+        assert_eq!(q.regular_market_volume.unwrap().raw, Some(1_000));
+        assert_eq!(q.fifty_two_week_high.unwrap().raw, Some(200.0));
+        assert_eq!(q.currency.as_deref(), Some("USD"));
+        assert!(q.market_cap.is_none());
+    }
+
+    #[test]
+    fn test_chart_builder_closes() {
+        let chart = ChartBuilder::new("MSFT")
+            .interval(Interval::OneDay)
+            .closes(&[10.0, 11.0, 9.0])
+            .build();
+        assert_eq!(chart.candles.len(), 3);
+        assert_candles_sorted(&chart.candles);
+        assert_valid_ohlc(&chart.candles);
+        assert_eq!(chart.candles[1].open, 10.0);
+        assert_eq!(
+            chart.candles[1].timestamp - chart.candles[0].timestamp,
+            86_400
+        );
+        assert_eq!(chart.meta.regular_market_price, Some(9.0));
+    }
+
+    #[test]
+    fn test_options_builder() {
+        let opts = OptionsBuilder::new("SPY")
+            .expiration(1_800_000_000)
+            .call(500.0, 12.0, 0.2)
+            .call(510.0, 7.0, 0.19)
+            .put(500.0, 9.0, 0.22)
+            .build();
+        assert_eq!(opts.calls().len(), 2);
+        assert_eq!(opts.puts().len(), 1);
+        assert_eq!(opts.strikes(), vec![500.0, 510.0]);
+        assert_eq!(opts.expiration_dates(), vec![1_800_000_000]);
+    }
+
+    #[tokio::test]
+    async fn test_mock_ticker_serves_and_misses() {
+        let mock = MockTicker::new("AAPL")
+            .with_chart(
+                ChartBuilder::new("AAPL")
+                    .interval(Interval::OneDay)
+                    .range(TimeRange::OneMonth)
+                    .closes(&[1.0, 2.0])
+                    .build(),
+            )
+            .with_news(vec![news("Headline", "Reuters")]);
+        assert_eq!(TickerData::symbol(&mock), "AAPL");
+        let chart = mock.chart(Interval::OneDay, TimeRange::OneMonth).await;
+        assert_eq!(chart.unwrap().candles.len(), 2);
+        assert!(
+            mock.chart(Interval::OneHour, TimeRange::OneDay)
+                .await
+                .is_err()
+        );
+        assert!(mock.quote().await.unwrap_err().is_not_found());
+        assert_eq!(mock.news().await.unwrap().len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "candles not ascending")]
+    fn test_assert_candles_sorted_panics() {
+        assert_candles_sorted(&[
+            candle(2, 1.0, 1.0, 1.0, 1.0, 0),
+            candle(1, 1.0, 1.0, 1.0, 1.0, 0),
+        ]);
+    }
+
+    #[test]
+    fn test_assert_approx_eq() {
+        assert_approx_eq(1.0, 1.0 + 1e-10, 1e-9);
+    }
+}
@@ -0,0 +1,286 @@
+//! Standalone session shared by many `Ticker`/`Tickers` handles.
+
+use super::core::{ClientHandle, TickerCaches};
+use crate::adapters::limits::ResponseLimits;
+use crate::adapters::pool::ConnectionPool;
+use crate::adapters::yahoo::client::ClientConfig;
+use crate::constants::Region;
+use crate::error::Result;
+use crate::providers::{Fetch, Provider, ProviderSet, Routes, build_providers};
+use crate::tickers::TickersCaches;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// One HTTP session (client, cookies, crumb, connection pool) shared by any
+/// number of [`Ticker`](crate::Ticker) and [`Tickers`](crate::Tickers) handles.
+///
+/// [`Ticker::new`](crate::Ticker::new) authenticates and opens its own
+/// connections every time; an app touching hundreds of symbols should build
+/// one `FinanceClient` and create handles from it with
+/// [`Ticker::with_client`](crate::Ticker::with_client) and
+/// [`Tickers::with_client`](crate::Tickers::with_client). Handles inherit the
+/// client's language and cache TTL, and read and fill the client's response
+/// cache: two `Ticker` handles for the same symbol fetch each response once,
+/// and all `Tickers` handles share per-symbol entries. A handle that changes
+/// its language or provider gets a cache of its own. Cloning is cheap and
+/// shares the session and the cache.
+///
+/// # Example
+///
+/// ```no_run
+/// use finance_query::{FinanceClient, Ticker, Tickers};
+/// use std::time::Duration;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = FinanceClient::builder()
+///     .cache(Duration::from_secs(60))
+///     .build()
+///     .await?;
+///
+/// let aapl = Ticker::with_client(&client, "AAPL").build().await?;
+/// let megacaps = Tickers::with_client(&client, ["MSFT", "NVDA", "GOOGL"])
+///     .build()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct FinanceClient {
+    pub(crate) providers: Arc<ProviderSet>,
+    lang: String,
+    pub(crate) cache_ttl: Option<Duration>,
+    pub(crate) caches: Arc<ClientCaches>,
+}
+
+/// Response caches owned by a [`FinanceClient`] and shared by its handles.
+pub(crate) struct ClientCaches {
+    lang: String,
+    ticker: Mutex<HashMap<Arc<str>, TickerCaches>>,
+    pub(crate) tickers: TickersCaches,
+}
+
+impl ClientCaches {
+    fn new(lang: &str) -> Self {
+        Self {
+            lang: lang.to_string(),
+            ticker: Mutex::default(),
+            tickers: TickersCaches::default(),
+        }
+    }
+
+    /// Language the cached values were produced in.
+    pub(crate) fn lang(&self) -> &str {
+        &self.lang
+    }
+
+    /// Caches for `symbol`, created on first use and kept for the life of
+    /// the client.
+    pub(crate) fn ticker(&self, symbol: &Arc<str>) -> TickerCaches {
+        self.ticker
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(Arc::clone(symbol))
+            .or_default()
+            .clone()
+    }
+}
+
+impl std::fmt::Debug for FinanceClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FinanceClient")
+            .field("lang", &self.lang)
+            .field("cache_ttl", &self.cache_ttl)
+            .finish()
+    }
+}
+
+impl FinanceClient {
+    /// Authenticate a new session with default configuration.
+    pub async fn new() -> Result<Self> {
+        Self::builder().build().await
+    }
+
+    /// Create a builder for configuring the session.
+    pub fn builder() -> FinanceClientBuilder {
+        FinanceClientBuilder::default()
+    }
+
+    /// Language inherited by handles created from this client.
+    pub fn lang(&self) -> &str {
+        &self.lang
+    }
+
+    /// Handle for builders that take [`.client()`](crate::TickerBuilder::client).
+    ///
+    /// Handles built this way share the session but not the response cache;
+    /// prefer [`Ticker::with_client`](crate::Ticker::with_client).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FinanceError::NoProviderAvailable`](crate::FinanceError::NoProviderAvailable)
+    /// if the client holds no Yahoo session.
+    pub fn handle(&self) -> Result<ClientHandle> {
+        self.providers.first_yahoo().map(ClientHandle)
+    }
+}
+
+/// Builder for [`FinanceClient`].
+#[derive(Debug, Default)]
+pub struct FinanceClientBuilder {
+    config: ClientConfig,
+    cache_ttl: Option<Duration>,
+}
+
+impl FinanceClientBuilder {
+    /// Set the region (automatically sets correct lang and region).
+    pub fn region(mut self, region: Region) -> Self {
+        self.config.lang = region.lang().to_string();
+        self.config.region = region.region().to_string();
+        self
+    }
+    /// Set the language code (e.g., "en-US", "ja-JP").
+    pub fn lang(mut self, lang: impl Into<String>) -> Self {
+        self.config.lang = lang.into();
+        self
+    }
+    /// Set the region code (e.g., "US", "JP").
+    pub fn region_code(mut self, r: impl Into<String>) -> Self {
+        self.config.region = r.into();
+        self
+    }
+    /// Set the HTTP request timeout.
+    pub fn timeout(mut self, t: Duration) -> Self {
+        self.config.timeout = t;
+        self
+    }
+    /// Set the proxy URL.
+    pub fn proxy(mut self, p: impl Into<String>) -> Self {
+        self.config.proxy = Some(p.into());
+        self
+    }
+    /// Set the maximum response size and decompression ratio.
+    pub fn response_limits(mut self, limits: ResponseLimits) -> Self {
+        self.config.limits = limits;
+        self
+    }
+    /// Set the connection pool and keep-alive settings.
+    pub fn connection_pool(mut self, pool: ConnectionPool) -> Self {
+        self.config.pool = pool;
+        self
+    }
+    /// Coalesce batch quote requests made within `window` of each other.
+    ///
+    /// Concurrent [`Tickers::quotes`](crate::Tickers::quotes) calls from any
+    /// handle on this client are merged into one upstream request, and each
+    /// caller receives its own symbols. Every batch waits out the window
+    /// before it is sent, so keep it short (tens of milliseconds). Disabled
+    /// by default.
+    pub fn quote_batch_window(mut self, window: Duration) -> Self {
+        self.config.quote_batch_window = Some(window);
+        self
+    }
+    /// Reuse the authenticated session saved in `path`, and save a fresh one
+    /// there when it is missing or older than an hour.
+    ///
+    /// Skips the cookie and crumb round-trips on every start, which dominate
+    /// the latency of short-lived processes such as CLI commands. The file
+    /// holds session cookies; on Unix it is created readable by the owner
+    /// only. Delete it to force a new handshake.
+    pub fn session_file(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.config.session_file = Some(path.into());
+        self
+    }
+    /// Default response cache TTL for handles created from the client.
+    pub fn cache(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Authenticate and build the client.
+    pub async fn build(self) -> Result<FinanceClient> {
+        #[cfg(feature = "translation")]
+        crate::translation::Lang::parse(&self.config.lang)?;
+        let providers = build_providers(
+            &[Provider::Yahoo],
+            &self.config,
+            Routes::new(Fetch::Sequential),
+        )
+        .await?;
+        Ok(FinanceClient {
+            providers: Arc::new(providers),
+            caches: Arc::new(ClientCaches::new(&self.config.lang)),
+            lang: self.config.lang,
+            cache_ttl: self.cache_ttl,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::chart::Candle;
+    use crate::providers::custom::{CustomProvider, DataProvider, ProviderQuote};
+    use crate::{Interval, Ticker, Tickers, TimeRange};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct Counting(Arc<AtomicUsize>);
+
+    #[async_trait::async_trait]
+    impl DataProvider for Counting {
+        async fn quote(&self, symbol: &str) -> Result<ProviderQuote> {
+            Ok(ProviderQuote::new(symbol, 1.0))
+        }
+
+        async fn chart(&self, _: &str, _: Interval, _: TimeRange) -> Result<Vec<Candle>> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(vec![Candle::new(0, 1.0, 1.0, 1.0, 1.0, 1)])
+        }
+    }
+
+    fn client(calls: &Arc<AtomicUsize>) -> FinanceClient {
+        let provider = Arc::new(Counting(Arc::clone(calls)));
+        FinanceClient {
+            providers: Arc::new(CustomProvider::provider_set(provider)),
+            lang: "en-US".to_string(),
+            cache_ttl: Some(Duration::from_secs(60)),
+            caches: Arc::new(ClientCaches::new("en-US")),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handles_share_the_client_cache() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let client = client(&calls);
+        let (range, interval) = (TimeRange::OneMonth, Interval::OneDay);
+
+        for _ in 0..2 {
+            let aapl = Ticker::with_client(&client, "AAPL").build().await.unwrap();
+            aapl.chart(interval, range).await.unwrap();
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        for symbols in [vec!["MSFT", "NVDA"], vec!["NVDA", "MSFT"], vec!["NVDA"]] {
+            let batch = Tickers::with_client(&client, symbols)
+                .build()
+                .await
+                .unwrap();
+            batch.charts(interval, range).await.unwrap();
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        let german = Ticker::with_client(&client, "AAPL")
+            .lang("de-DE")
+            .build()
+            .await
+            .unwrap();
+        german.chart(interval, range).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn test_handle_without_yahoo_session_is_an_error() {
+        let client = client(&Arc::new(AtomicUsize::new(0)));
+        assert!(client.handle().is_err());
+    }
+}
@@ -1,31 +1,43 @@
 //! Symbol-specific data access from multiple providers.
 
+use super::client::{ClientCaches, FinanceClient};
+use crate::adapters::limits::ResponseLimits;
 use crate::adapters::yahoo::client::{ClientConfig, YahooClient};
+use crate::attention::{AttentionSnapshot, AttentionStore};
 #[cfg(feature = "backtesting")]
 use crate::backtesting;
 use crate::constants::{Frequency, Interval, Region, StatementType, TimeRange};
+#[cfg(feature = "edgar")]
 use crate::edgar;
 use crate::error::{FinanceError, Result};
-use crate::format::Both;
+use crate::format::{Both, Raw};
 #[cfg(any(feature = "backtesting", feature = "indicators"))]
 use crate::indicators;
 use crate::models::chart::events::ChartEvents;
-use crate::models::chart::{CapitalGain, Chart, Dividend, DividendAnalytics, Split};
+use crate::models::chart::{
+    CapitalGain, Chart, ChartWithEvents, Dividend, DividendAnalysis, DividendAnalytics,
+    RelativeVolume, Split,
+};
 use crate::models::corporate::news::News;
+#[cfg(feature = "feeds")]
+use crate::models::corporate::press_release::{self, PressRelease, PressWire};
 use crate::models::corporate::recommendation::Recommendation;
-use crate::models::filings::{CompanyFacts, EdgarSubmissions, ProviderFilings};
+use crate::models::filings::ProviderFilings;
+#[cfg(feature = "edgar")]
+use crate::models::filings::{CompanyFacts, EdgarSubmissions};
 use crate::models::format::Format;
 use crate::models::fundamentals::FinancialStatement;
-use crate::models::options::Options;
+use crate::models::options::{OptionChains, Options};
 use crate::models::quote::{
     AssetProfile, CalendarEvents, DefaultKeyStatistics, Earnings, EarningsHistory, EarningsTrend,
-    EquityPerformance, FinancialData, FundOwnership, FundPerformance, FundProfile, IndexTrend,
-    IndustryTrend, InsiderHolders, InsiderTransactions, InstitutionOwnership,
-    MajorHoldersBreakdown, NetSharePurchaseActivity, Price, Quote, QuoteSummaryResponse,
-    QuoteTypeData, RecommendationTrend, SecFilings, SectorTrend, SummaryDetail, SummaryProfile,
-    TopHoldings, UpgradeDowngradeHistory,
+    EquityPerformance, EsgScores, FinancialData, FundOwnership, FundPerformance, FundProfile,
+    IndexTrend, IndustryTrend, InsiderHolders, InsiderTransactions, InstitutionOwnership,
+    MajorHoldersBreakdown, NetSharePurchaseActivity, Price, Quote, QuoteDiff, QuoteModule,
+    QuoteSummaryResponse, QuoteTypeData, RecommendationTrend, SecFilings, SectorTrend,
+    SummaryDetail, SummaryProfile, TopHoldings, TopOfBook, UpgradeDowngradeHistory,
 };
 
+use crate::providers::custom::{CustomProvider, DataProvider};
 use crate::providers::types::recommendation_from_similar;
 use crate::providers::yahoo::YahooProvider;
 use crate::providers::{
@@ -33,8 +45,10 @@ use crate::providers::{
 };
 #[cfg(feature = "risk")]
 use crate::risk;
+use crate::symbol::Symbol;
 use crate::utils::{CacheEntry, EVICTION_THRESHOLD, filter_by_range};
-use std::collections::HashMap;
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::RwLock;
@@ -42,6 +56,41 @@ use tokio::sync::RwLock;
 type Cache<T> = Arc<RwLock<Option<CacheEntry<T>>>>;
 type MapCache<K, V> = Arc<RwLock<HashMap<K, CacheEntry<V>>>>;
 
+/// Maximum concurrent expiration requests in [`Ticker::options_all`].
+const OPTIONS_ALL_CONCURRENCY: usize = 5;
+
+/// Cached quote summary together with the modules it was fetched with.
+///
+/// Later requests for modules outside `modules` fetch only those and merge
+/// them in, instead of re-downloading the whole summary.
+struct QuoteSummaryCache {
+    summary: QuoteSummaryResponse,
+    modules: HashSet<QuoteModule>,
+}
+
+/// Response caches for one symbol.
+///
+/// A [`FinanceClient`] keeps one set per symbol and hands clones to every
+/// [`Ticker`] it builds for that symbol, so the handles fetch each response
+/// once between them. Some cached values are already translated, so only
+/// handles in the client's language share them.
+#[derive(Clone, Default)]
+pub(crate) struct TickerCaches {
+    quote_cache: Cache<QuoteSummaryCache>,
+    quote_fetch: Arc<tokio::sync::Mutex<()>>,
+    chart_cache: MapCache<(Interval, TimeRange), Chart>,
+    events_cache: Cache<ChartEvents>,
+    news_cache: Cache<Vec<News>>,
+    options_cache: MapCache<Option<i64>, Options>,
+    financials_cache: MapCache<(StatementType, Frequency), FinancialStatement>,
+    #[cfg(feature = "indicators")]
+    indicators_cache: MapCache<(Interval, TimeRange), indicators::IndicatorsSummary>,
+    #[cfg(feature = "edgar")]
+    edgar_submissions_cache: Cache<EdgarSubmissions>,
+    #[cfg(feature = "edgar")]
+    edgar_facts_cache: Cache<CompanyFacts>,
+}
+
 /// Opaque handle to a shared Yahoo Finance client session.
 ///
 /// Allows multiple [`Ticker`] and [`Tickers`](crate::Tickers) instances to share
@@ -75,8 +124,16 @@ pub struct TickerBuilder {
     config: ClientConfig,
     shared_client: Option<ClientHandle>,
     injected_providers: Option<Arc<ProviderSet>>,
+    shared_caches: Option<Arc<ClientCaches>>,
+    data_provider: Option<Arc<dyn DataProvider>>,
     cache_ttl: Option<Duration>,
     include_logo: bool,
+    attention_store: Option<AttentionStore>,
+    quote_modules: Option<Vec<QuoteModule>>,
+    #[cfg(feature = "cache")]
+    cache_dir: Option<std::path::PathBuf>,
+    #[cfg(feature = "cache")]
+    disk_cache_ttl: Option<Duration>,
 }
 
 impl TickerBuilder {
@@ -86,8 +143,16 @@ impl TickerBuilder {
             config: ClientConfig::default(),
             shared_client: None,
             injected_providers: None,
+            shared_caches: None,
+            data_provider: None,
             cache_ttl: None,
             include_logo: false,
+            attention_store: None,
+            quote_modules: None,
+            #[cfg(feature = "cache")]
+            cache_dir: None,
+            #[cfg(feature = "cache")]
+            disk_cache_ttl: None,
         }
     }
     /// Set the region (automatically sets correct lang and region).
@@ -116,6 +181,16 @@ impl TickerBuilder {
         self.config.proxy = Some(p.into());
         self
     }
+    /// Set the maximum response size and decompression ratio.
+    pub fn response_limits(mut self, limits: ResponseLimits) -> Self {
+        self.config.limits = limits;
+        self
+    }
+    /// Set the connection pool and keep-alive settings.
+    pub fn connection_pool(mut self, pool: crate::ConnectionPool) -> Self {
+        self.config.pool = pool;
+        self
+    }
     #[allow(dead_code)]
     pub(crate) fn config(mut self, c: ClientConfig) -> Self {
         self.config = c;
@@ -131,12 +206,23 @@ impl TickerBuilder {
     /// Avoids redundant auth handshakes when creating multiple `Ticker` instances.
     /// Obtain a handle from any existing `Ticker` via [`Ticker::client_handle`].
     ///
-    /// When set, the builder's `config`, `timeout`, `proxy`, `lang`, and `region`
-    /// settings are ignored — the shared session's configuration is used instead.
+    /// When set, the builder's `config`, `timeout`, `proxy`, `lang`, `region`,
+    /// `response_limits`, and `connection_pool` settings are ignored — the shared session's configuration is used instead.
     pub fn client(mut self, handle: ClientHandle) -> Self {
         self.shared_client = Some(handle);
         self
     }
+    /// Serve quotes and charts from `provider` instead of Yahoo.
+    ///
+    /// No Yahoo session is opened, so [`build`](Self::build) makes no network
+    /// calls. Accessors built on [`quote`](Ticker::quote), [`chart`](Ticker::chart),
+    /// and [`chart_range`](Ticker::chart_range) use the provider; the rest
+    /// return [`FinanceError::NoProviderAvailable`], and
+    /// [`Ticker::client_handle`] panics.
+    pub fn provider(mut self, provider: impl DataProvider + 'static) -> Self {
+        self.data_provider = Some(Arc::new(provider));
+        self
+    }
     /// Enable response caching with a time-to-live.
     pub fn cache(mut self, ttl: Duration) -> Self {
         self.cache_ttl = Some(ttl);
@@ -147,15 +233,80 @@ impl TickerBuilder {
         self.include_logo = true;
         self
     }
+    /// Restrict the first quote-summary fetch to these modules.
+    ///
+    /// By default the first quote accessor downloads every module. With a
+    /// module set, only those (plus the one the accessor needs) are fetched;
+    /// with [`cache`](Self::cache) enabled, later accessors fetch just the
+    /// modules still missing and merge them into the cached summary.
+    ///
+    /// Accepts an array, a slice, or any iterator of modules.
+    pub fn quote_modules<M: Borrow<QuoteModule>>(
+        mut self,
+        modules: impl IntoIterator<Item = M>,
+    ) -> Self {
+        self.quote_modules = Some(modules.into_iter().map(|m| *m.borrow()).collect());
+        self
+    }
+    /// Persist daily attention metrics to `store`.
+    ///
+    /// Enables [`Ticker::record_attention`] and [`Ticker::attention_history`].
+    pub fn attention(mut self, store: AttentionStore) -> Self {
+        self.attention_store = Some(store);
+        self
+    }
+    /// Persist chart and quote responses under `dir` (requires `cache` feature).
+    ///
+    /// Entries survive process restarts and are served for the rest of the
+    /// UTC day they were fetched on; see [`cache`](crate::cache) for the layout.
+    /// The directory is created by [`build`](Self::build).
+    #[cfg(feature = "cache")]
+    pub fn cache_dir(mut self, dir: impl Into<std::path::PathBuf>) -> Self {
+        self.cache_dir = Some(dir.into());
+        self
+    }
+    /// Expire on-disk cache entries older than `ttl` (requires `cache` feature).
+    ///
+    /// Only applies with [`cache_dir`](Self::cache_dir); the in-memory cache
+    /// is configured separately with [`cache`](Self::cache).
+    #[cfg(feature = "cache")]
+    pub fn cache_ttl(mut self, ttl: Duration) -> Self {
+        self.disk_cache_ttl = Some(ttl);
+        self
+    }
 
     /// Build the Ticker instance.
     pub async fn build(self) -> Result<Ticker> {
+        let symbol = Symbol::parse(&self.symbol)?.into_arc();
+        // Share the client's caches unless this handle swapped the provider
+        // or the language, either of which changes what would be cached.
+        let caches = match &self.shared_caches {
+            Some(shared) if self.data_provider.is_none() && shared.lang() == self.config.lang => {
+                shared.ticker(&symbol)
+            }
+            _ => TickerCaches::default(),
+        };
         #[cfg(feature = "translation")]
         let translate_lang = {
             let lang = crate::translation::Lang::parse(&self.config.lang)?;
             (!lang.is_english()).then_some(lang)
         };
-        let providers = if let Some(set) = self.injected_providers {
+        #[cfg(feature = "cache")]
+        let disk_cache = match self.cache_dir {
+            Some(dir) => {
+                let cache = tokio::task::spawn_blocking(move || crate::cache::DiskCache::open(dir))
+                    .await
+                    .map_err(|e| FinanceError::InternalError(e.to_string()))??;
+                Some(match self.disk_cache_ttl {
+                    Some(ttl) => cache.with_ttl(ttl),
+                    None => cache,
+                })
+            }
+            None => None,
+        };
+        let providers = if let Some(provider) = self.data_provider {
+            Arc::new(CustomProvider::provider_set(provider))
+        } else if let Some(set) = self.injected_providers {
             set
         } else if let Some(handle) = self.shared_client {
             let yahoo = YahooProvider::from_client(handle.0);
@@ -176,23 +327,29 @@ impl TickerBuilder {
             )
         };
         Ok(Ticker {
-            symbol: self.symbol,
+            symbol,
             providers,
             cache_ttl: self.cache_ttl,
             include_logo: self.include_logo,
+            attention_store: self.attention_store,
+            #[cfg(feature = "cache")]
+            disk_cache,
             #[cfg(feature = "translation")]
             translate_lang,
-            quote_cache: Default::default(),
-            quote_fetch: Arc::new(tokio::sync::Mutex::new(())),
-            chart_cache: Default::default(),
-            events_cache: Default::default(),
-            news_cache: Default::default(),
-            options_cache: Default::default(),
-            financials_cache: Default::default(),
+            quote_modules: self.quote_modules.unwrap_or_else(QuoteModule::all).into(),
+            quote_cache: caches.quote_cache,
+            quote_fetch: caches.quote_fetch,
+            chart_cache: caches.chart_cache,
+            events_cache: caches.events_cache,
+            news_cache: caches.news_cache,
+            options_cache: caches.options_cache,
+            financials_cache: caches.financials_cache,
             #[cfg(feature = "indicators")]
-            indicators_cache: Default::default(),
-            edgar_submissions_cache: Default::default(),
-            edgar_facts_cache: Default::default(),
+            indicators_cache: caches.indicators_cache,
+            #[cfg(feature = "edgar")]
+            edgar_submissions_cache: caches.edgar_submissions_cache,
+            #[cfg(feature = "edgar")]
+            edgar_facts_cache: caches.edgar_facts_cache,
         })
     }
 }
@@ -206,9 +363,13 @@ pub struct Ticker {
     providers: Arc<ProviderSet>,
     cache_ttl: Option<Duration>,
     include_logo: bool,
+    attention_store: Option<AttentionStore>,
+    #[cfg(feature = "cache")]
+    disk_cache: Option<crate::cache::DiskCache>,
     #[cfg(feature = "translation")]
     translate_lang: Option<crate::translation::Lang>,
-    quote_cache: Cache<QuoteSummaryResponse>,
+    quote_modules: Arc<[QuoteModule]>,
+    quote_cache: Cache<QuoteSummaryCache>,
     quote_fetch: Arc<tokio::sync::Mutex<()>>,
     chart_cache: MapCache<(Interval, TimeRange), Chart>,
     events_cache: Cache<ChartEvents>,
@@ -217,7 +378,9 @@ pub struct Ticker {
     financials_cache: MapCache<(StatementType, Frequency), FinancialStatement>,
     #[cfg(feature = "indicators")]
     indicators_cache: MapCache<(Interval, TimeRange), indicators::IndicatorsSummary>,
+    #[cfg(feature = "edgar")]
     edgar_submissions_cache: Cache<EdgarSubmissions>,
+    #[cfg(feature = "edgar")]
     edgar_facts_cache: Cache<CompanyFacts>,
 }
 
@@ -230,6 +393,21 @@ impl Ticker {
     pub fn builder(symbol: impl Into<String>) -> TickerBuilder {
         TickerBuilder::new(symbol)
     }
+    /// Creates a builder that reuses `client`'s session, language, cache TTL,
+    /// and response cache.
+    ///
+    /// No new authentication handshake or connection pool is created, and
+    /// handles for the same symbol share cached responses.
+    pub fn with_client(client: &FinanceClient, symbol: impl Into<String>) -> TickerBuilder {
+        let mut builder = TickerBuilder::new(symbol)
+            .lang(client.lang())
+            .with_provider_set(Arc::clone(&client.providers));
+        builder.shared_caches = Some(Arc::clone(&client.caches));
+        match client.cache_ttl {
+            Some(ttl) => builder.cache(ttl),
+            None => builder,
+        }
+    }
     /// Returns the ticker symbol.
     pub fn symbol(&self) -> &str {
         &self.symbol
@@ -272,7 +450,9 @@ impl Ticker {
     }
 
     fn is_cache_fresh<T>(&self, entry: Option<&CacheEntry<T>>) -> bool {
-        CacheEntry::is_fresh_with_ttl(entry, self.cache_ttl)
+        let fresh = CacheEntry::is_fresh_with_ttl(entry, self.cache_ttl);
+        crate::telemetry::record_cache_lookup(fresh);
+        fresh
     }
 
     /// Like `is_cache_fresh`, but works on the shared-cache pattern
@@ -304,7 +484,22 @@ impl Ticker {
         F: Format,
         Quote<Both>: Into<Quote<F>>,
     {
-        let cache = self.ensure_quote().await?;
+        self.quote_both(true).await.map(Into::into)
+    }
+
+    /// Build the full quote, reading and writing the on-disk cache when
+    /// `use_disk_cache` is set.
+    #[cfg_attr(not(feature = "cache"), allow(unused_variables))]
+    async fn quote_both(&self, use_disk_cache: bool) -> Result<Quote<Both>> {
+        #[cfg(feature = "cache")]
+        let disk_cache = self.disk_cache.as_ref().filter(|_| use_disk_cache);
+        #[cfg(feature = "cache")]
+        if let Some(disk) = disk_cache
+            && let Some(quote) = disk.quote(&self.symbol).await
+        {
+            return self.finish_quote(quote).await;
+        }
+        let cache = self.ensure_quote(&QuoteModule::all()).await?;
         let summary = cache.as_ref().ok_or_else(|| {
             FinanceError::ApiError("Quote summary cache was empty after fetch".to_string())
         })?;
@@ -318,15 +513,86 @@ impl Ticker {
         } else {
             (None, None)
         };
-        let quote = Quote::from_response(&summary.value, logo_url, company_logo_url);
+        let quote = Quote::from_response(&summary.value.summary, logo_url, company_logo_url);
+        drop(cache);
+        #[cfg(feature = "cache")]
+        if let Some(disk) = disk_cache {
+            disk.put_quote(&self.symbol, &quote).await;
+        }
+        self.finish_quote(quote).await
+    }
+
+    /// Apply post-processing that is never cached (translation).
+    #[cfg_attr(not(feature = "translation"), allow(unused_mut))]
+    async fn finish_quote(&self, mut quote: Quote<Both>) -> Result<Quote<Both>> {
         #[cfg(feature = "translation")]
-        let quote = {
-            drop(cache);
-            let mut quote = quote;
-            self.translate_response(&mut quote).await?;
-            quote
-        };
-        Ok(quote.into())
+        self.translate_response(&mut quote).await?;
+        Ok(quote)
+    }
+
+    /// Poll the quote every `interval` and yield the fields that changed.
+    ///
+    /// The first poll sets the baseline and yields nothing; after that, each
+    /// poll that changes at least one field yields a [`QuoteDiff`]. Polls
+    /// bypass the quote cache. Fetch errors are yielded and polling carries
+    /// on; drop the stream to stop watching.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::Ticker;
+    /// use futures::StreamExt;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ticker = Ticker::new("AAPL").await?;
+    /// let mut changes = std::pin::pin!(ticker.watch_quote(Duration::from_secs(60)));
+    ///
+    /// while let Some(diff) = changes.next().await {
+    ///     let diff = diff?.only(&["recommendationTrend", "shortRatio", "exDividendDate"]);
+    ///     for change in &diff.changes {
+    ///         println!("{}: {:?} -> {:?}", change.field, change.old, change.new);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn watch_quote(
+        &self,
+        interval: Duration,
+    ) -> impl futures::Stream<Item = Result<QuoteDiff>> + '_ {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        futures::stream::unfold(
+            (ticker, None::<Quote<Raw>>),
+            move |(mut ticker, mut last)| async move {
+                loop {
+                    ticker.tick().await;
+                    *self.quote_cache.write().await = None;
+                    let quote = match self.quote_both(false).await {
+                        Ok(quote) => Quote::<Raw>::from(quote),
+                        Err(e) => return Some((Err(e), (ticker, last))),
+                    };
+                    let diff = last.as_ref().map(|older| quote.diff(older));
+                    last = Some(quote);
+                    if let Some(diff) = diff
+                        && !diff.is_empty()
+                    {
+                        return Some((Ok(diff), (ticker, last)));
+                    }
+                }
+            },
+        )
+    }
+
+    /// Get a top-of-book snapshot: best bid and ask with their sizes.
+    ///
+    /// Always fetched fresh; the quote cache is not consulted.
+    pub async fn book(&self) -> Result<TopOfBook> {
+        self.providers
+            .first_yahoo()?
+            .get_top_of_book(&self.symbol)
+            .await
     }
 
     fn chart_from_provider_data(
@@ -349,16 +615,33 @@ impl Ticker {
                 return Ok(entry.value.clone());
             }
         }
-        let sym = self.symbol.clone();
-        let data = self
-            .providers
-            .fetch(Capability::CHART, move |p| {
-                let sym = sym.clone();
-                let p = p.clone();
-                async move { p.fetch_chart(&sym, interval, range).await }
-            })
-            .await?;
-        let chart = Self::chart_from_provider_data(data, Some(interval), Some(range));
+        #[cfg(feature = "cache")]
+        let from_disk = match &self.disk_cache {
+            Some(disk) => disk.chart(&self.symbol, interval, range).await,
+            None => None,
+        };
+        #[cfg(not(feature = "cache"))]
+        let from_disk = None;
+        let chart = match from_disk {
+            Some(chart) => chart,
+            None => {
+                let sym = self.symbol.clone();
+                let data = self
+                    .providers
+                    .fetch(Capability::CHART, move |p| {
+                        let sym = sym.clone();
+                        let p = p.clone();
+                        async move { p.fetch_chart(&sym, interval, range).await }
+                    })
+                    .await?;
+                let chart = Self::chart_from_provider_data(data, Some(interval), Some(range));
+                #[cfg(feature = "cache")]
+                if let Some(disk) = &self.disk_cache {
+                    disk.put_chart(&self.symbol, interval, range, &chart).await;
+                }
+                chart
+            }
+        };
         if self.cache_ttl.is_some() {
             let mut cache = self.chart_cache.write().await;
             self.cache_insert(&mut cache, (interval, range), chart.clone());
@@ -366,6 +649,18 @@ impl Ticker {
         Ok(chart)
     }
 
+    /// Get chart data with dividends, splits, and capital gains aligned to
+    /// their candles.
+    ///
+    /// Equivalent to `ticker.chart(interval, range).await?.with_events(&ticker)`.
+    pub async fn chart_with_events(
+        &self,
+        interval: Interval,
+        range: TimeRange,
+    ) -> Result<ChartWithEvents> {
+        self.chart(interval, range).await?.with_events(self).await
+    }
+
     /// Get chart data for a custom start/end timestamp range.
     pub async fn chart_range(&self, interval: Interval, start: i64, end: i64) -> Result<Chart> {
         if start >= end {
@@ -386,6 +681,75 @@ impl Ticker {
         Ok(Self::chart_from_provider_data(data, Some(interval), None))
     }
 
+    /// Get chart data for an exact window between two instants.
+    ///
+    /// Maps to Yahoo's `period1`/`period2` parameters, so the same window
+    /// always returns the same candles, unlike the rolling [`TimeRange`]
+    /// presets of [`chart`](Self::chart). Sub-second precision is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use chrono::{TimeZone, Utc};
+    /// use finance_query::{Interval, Ticker};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ticker = Ticker::new("AAPL").await?;
+    /// let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+    /// let chart = ticker.chart_between(Interval::OneDay, start, end).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn chart_between<Tz: chrono::TimeZone>(
+        &self,
+        interval: Interval,
+        start: chrono::DateTime<Tz>,
+        end: chrono::DateTime<Tz>,
+    ) -> Result<Chart> {
+        self.chart_range(interval, start.timestamp(), end.timestamp())
+            .await
+    }
+
+    /// Intraday relative volume: today's volume so far against the average
+    /// volume through the same time of day on prior sessions.
+    ///
+    /// `interval` sets the bar size the time of day is matched on and must be
+    /// intraday. One-minute bars cover the last five days; coarser bars cover
+    /// the last month.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::{Interval, Ticker};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let rv = Ticker::new("AAPL").await?.rvol(Interval::FiveMinutes).await?;
+    /// if let Some(rvol) = rv.rvol {
+    ///     println!("{} shares, {rvol:.1}x the {}-day average", rv.volume, rv.days);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn rvol(&self, interval: Interval) -> Result<RelativeVolume> {
+        if !interval.is_intraday() {
+            return Err(FinanceError::InvalidParameter {
+                param: "interval".into(),
+                reason: format!("RVOL needs an intraday interval, got {}", interval.as_str()),
+            });
+        }
+        let range = if interval == Interval::OneMinute {
+            TimeRange::FiveDays
+        } else {
+            TimeRange::OneMonth
+        };
+        let chart = self.chart(interval, range).await?;
+        RelativeVolume::from_chart(&chart).ok_or_else(|| FinanceError::SymbolNotFound {
+            symbol: Some(self.symbol.to_string()),
+            context: format!("no intraday {} bars", interval.as_str()),
+        })
+    }
+
     async fn ensure_events(&self) -> Result<()> {
         {
             let cache = self.events_cache.read().await;
@@ -422,6 +786,21 @@ impl Ticker {
         let divs = self.dividends(range).await?;
         Ok(DividendAnalytics::from_dividends(&divs))
     }
+    /// Analyse dividends against weekly price history for the requested
+    /// time range.
+    ///
+    /// Reports the trailing twelve-month yield at every weekly bar, yearly
+    /// totals with their growth rate and run of consecutive increases, and
+    /// the detected payout frequency. Yearly figures only cover calendar
+    /// years wholly inside `range`, so use a long range (or
+    /// [`TimeRange::Max`]) for growth streaks.
+    pub async fn dividend_analysis(&self, range: TimeRange) -> Result<DividendAnalysis> {
+        let (dividends, chart) = tokio::try_join!(
+            self.dividends(TimeRange::Max),
+            self.chart(Interval::OneWeek, range)
+        )?;
+        Ok(DividendAnalysis::compute(&dividends, &chart.candles))
+    }
     /// Get stock split history.
     pub async fn splits(&self, range: TimeRange) -> Result<Vec<Split>> {
         self.ensure_events().await?;
@@ -513,6 +892,53 @@ impl Ticker {
         Ok(news)
     }
 
+    /// Get official press releases for this symbol.
+    ///
+    /// Unlike [`news`](Self::news), which mixes journalist coverage with
+    /// company disclosures, this returns only issuer-authored releases
+    /// distributed via a newswire (Business Wire, PR Newswire, GlobeNewswire,
+    /// ACCESSWIRE). Yahoo search news is filtered by publisher and merged
+    /// with the Business Wire and PR Newswire RSS feeds, keeping feed entries
+    /// that carry the symbol's exchange tag (e.g. `(NASDAQ: AAPL)`).
+    ///
+    /// Results are deduplicated by URL and sorted newest-first. The feeds are
+    /// best-effort: an unreachable feed contributes nothing rather than failing
+    /// the call.
+    ///
+    /// Requires the **`feeds`** feature (on by default).
+    #[cfg(feature = "feeds")]
+    pub async fn press_releases(&self) -> Result<Vec<PressRelease>> {
+        let yahoo = self.providers.first_yahoo()?;
+        let options = crate::finance::SearchOptions::new()
+            .quotes_count(0)
+            .news_count(50)
+            .enable_logo_url(false);
+        let (search, business_wire, pr_newswire) = tokio::join!(
+            yahoo.search(&self.symbol, &options),
+            crate::feeds::fetch(crate::feeds::FeedSource::BusinessWire),
+            crate::feeds::fetch(crate::feeds::FeedSource::PrNewswire),
+        );
+        let search = search?;
+
+        let from_search = search
+            .news
+            .iter()
+            .filter_map(|n| PressRelease::from_search_news(&self.symbol, n));
+        let from_feeds = [
+            (PressWire::BusinessWire, business_wire),
+            (PressWire::PrNewswire, pr_newswire),
+        ]
+        .into_iter()
+        .flat_map(|(wire, entries)| {
+            entries
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(move |e| PressRelease::from_feed_entry(&self.symbol, wire, &e))
+        });
+
+        Ok(press_release::merge_releases(from_search.chain(from_feeds)))
+    }
+
     /// Average sentiment across recent news headlines for this symbol.
     ///
     /// Positive = net bullish coverage, negative = net bearish. Returns a
@@ -530,6 +956,35 @@ impl Ticker {
             .unwrap_or_else(crate::models::sentiment::Sentiment::neutral))
     }
 
+    /// Collect today's attention metrics and save them to the attention store.
+    ///
+    /// Records news count, trending rank, options volume, and short interest.
+    /// Calling again on the same UTC day overwrites that day's entry.
+    /// Requires [`TickerBuilder::attention`].
+    pub async fn record_attention(&self) -> Result<AttentionSnapshot> {
+        let store = self.attention_store()?;
+        let snapshot = AttentionSnapshot::collect(self).await;
+        store.record(&snapshot)?;
+        Ok(snapshot)
+    }
+
+    /// Attention metrics previously recorded for this symbol, oldest first.
+    ///
+    /// Requires [`TickerBuilder::attention`].
+    pub fn attention_history(&self) -> Result<Vec<AttentionSnapshot>> {
+        self.attention_store()?.history(&self.symbol)
+    }
+
+    fn attention_store(&self) -> Result<&AttentionStore> {
+        self.attention_store
+            .as_ref()
+            .ok_or_else(|| FinanceError::InvalidParameter {
+                param: "attention".to_string(),
+                reason: "no attention store configured; use Ticker::builder(..).attention(store)"
+                    .to_string(),
+            })
+    }
+
     /// Get the options chain.
     pub async fn options(&self, date: Option<i64>) -> Result<Options> {
         {
@@ -556,6 +1011,43 @@ impl Ticker {
         Ok(opts)
     }
 
+    /// Get the chains for every available expiration.
+    ///
+    /// Fetches the expiration list, then each expiration's chain with at
+    /// most five requests in flight. Each chain goes
+    /// through [`options`](Self::options), so cached expirations are not
+    /// refetched. Fails if any expiration fails to load.
+    ///
+    /// Aggregate open interest and put/call ratios are available from
+    /// [`OptionChains::stats`].
+    pub async fn options_all(&self) -> Result<OptionChains> {
+        use futures::stream::{self, StreamExt, TryStreamExt};
+
+        let first = self.options(None).await?;
+        let mut chains = first.chains();
+        let loaded: HashSet<i64> = chains.iter().map(|c| c.expiration_date).collect();
+        let remaining: Vec<i64> = first
+            .expiration_dates()
+            .into_iter()
+            .filter(|date| !loaded.contains(date))
+            .collect();
+
+        let rest: Vec<Options> = stream::iter(remaining)
+            .map(|date| self.options(Some(date)))
+            .buffer_unordered(OPTIONS_ALL_CONCURRENCY)
+            .try_collect()
+            .await?;
+        chains.extend(rest.iter().flat_map(Options::chains));
+        chains.sort_by_key(|c| c.expiration_date);
+        chains.dedup_by_key(|c| c.expiration_date);
+
+        Ok(OptionChains {
+            symbol: self.symbol.to_string(),
+            underlying_price: first.underlying_price(),
+            chains,
+        })
+    }
+
     /// Get financial statements.
     pub async fn financials(
         &self,
@@ -611,11 +1103,95 @@ impl Ticker {
         Ok(ind)
     }
 
+    #[cfg(feature = "indicators")]
+    /// Calculate all technical indicators from chart data as full time series.
+    ///
+    /// Computes the same indicators as [`indicators`](Self::indicators), but
+    /// keeps every bar instead of only the latest value. Each series is aligned
+    /// with the chart's candles; see
+    /// [`IndicatorsSeries`](indicators::IndicatorsSeries) for the key names.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::{Interval, Ticker, TimeRange};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ticker = Ticker::new("AAPL").await?;
+    /// let history = ticker
+    ///     .indicators_series(Interval::OneDay, TimeRange::OneYear)
+    ///     .await?;
+    /// let rsi = history.get("rsi_14").unwrap_or_default();
+    /// println!("{} bars, {} with RSI", history.len(), rsi.iter().flatten().count());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn indicators_series(
+        &self,
+        interval: Interval,
+        range: TimeRange,
+    ) -> Result<indicators::IndicatorsSeries> {
+        let chart = self.chart(interval, range).await?;
+        Ok(indicators::calculate_series(&chart.candles))
+    }
+
+    #[cfg(feature = "indicators")]
+    /// Calculate a subset of technical indicators from chart data.
+    ///
+    /// Only the selected indicators are computed; all other summary fields are
+    /// `None`. Accepts a slice of [`IndicatorKind`](indicators::IndicatorKind)s
+    /// or an [`IndicatorSelection`](indicators::IndicatorSelection) with
+    /// custom periods.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::{Interval, Ticker, TimeRange};
+    /// use finance_query::indicators::{IndicatorKind, IndicatorSelection};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ticker = Ticker::new("AAPL").await?;
+    /// let summary = ticker
+    ///     .indicators_selected(
+    ///         &[IndicatorKind::Rsi, IndicatorKind::Macd],
+    ///         Interval::OneDay,
+    ///         TimeRange::ThreeMonths,
+    ///     )
+    ///     .await?;
+    /// println!("RSI(14): {:?}", summary.rsi_14);
+    ///
+    /// let rsi_21 = IndicatorSelection::builder()
+    ///     .period(IndicatorKind::Rsi, 21)
+    ///     .build()?;
+    /// let summary = ticker
+    ///     .indicators_selected(rsi_21, Interval::OneDay, TimeRange::ThreeMonths)
+    ///     .await?;
+    /// println!("RSI(21): {:?}", summary.custom.get("rsi_21"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn indicators_selected(
+        &self,
+        selection: impl Into<indicators::IndicatorSelection>,
+        interval: Interval,
+        range: TimeRange,
+    ) -> Result<indicators::IndicatorsSummary> {
+        let selection = selection.into();
+        let chart = self.chart(interval, range).await?;
+        Ok(indicators::summary::calculate_selected(
+            &chart.candles,
+            &selection,
+        ))
+    }
+
     /// Get SEC EDGAR filing history for this symbol.
     ///
     /// Always uses EDGAR directly — this is an EDGAR-specific API (CIK-based submission
     /// history and XBRL company facts) that no other provider replicates. For routable
     /// provider-agnostic filing data use [`filings`](Self::filings) instead.
+    ///
+    /// Requires the **`edgar`** feature (on by default).
+    #[cfg(feature = "edgar")]
     pub async fn edgar_submissions(&self) -> Result<EdgarSubmissions> {
         {
             let cache = self.edgar_submissions_cache.read().await;
@@ -638,6 +1214,9 @@ impl Ticker {
     ///
     /// Always uses EDGAR directly — XBRL `us-gaap`/`ifrs`/`dei` fact data is unique
     /// to the SEC's EDGAR API. For routable filing data use [`filings`](Self::filings).
+    ///
+    /// Requires the **`edgar`** feature (on by default).
+    #[cfg(feature = "edgar")]
     pub async fn edgar_company_facts(&self) -> Result<CompanyFacts> {
         {
             let cache = self.edgar_facts_cache.read().await;
@@ -658,8 +1237,8 @@ impl Ticker {
 
     /// Fetch SEC filings via the configured [`Capability::FILINGS`] provider.
     ///
-    /// Routes through the provider system; EDGAR is always available as a fallback
-    /// (auto-injected when no explicit FILINGS route is set). To prefer Polygon:
+    /// Routes through the provider system; with the `edgar` feature, EDGAR is always
+    /// available as a fallback (auto-injected when no explicit FILINGS route is set). To prefer Polygon:
     /// `.route(Capability::FILINGS, [Provider::Polygon, Provider::Edgar])`.
     ///
     /// For the full EDGAR submissions response or structured XBRL data, use
@@ -687,8 +1266,26 @@ impl Ticker {
         Ok(indicators::compute_indicator(indicator, &chart)?)
     }
 
+    #[cfg(feature = "indicators")]
+    /// Detect candlestick patterns on each bar over a time range.
+    ///
+    /// The result has one entry per candle, `None` where no pattern matched.
+    /// Equivalent to `indicator(Indicator::Patterns, ..)`.
+    pub async fn patterns(
+        &self,
+        interval: Interval,
+        range: TimeRange,
+    ) -> Result<Vec<Option<indicators::CandlePattern>>> {
+        let chart = self.chart(interval, range).await?;
+        Ok(chart.patterns())
+    }
+
     #[cfg(feature = "backtesting")]
     /// Run a backtest with the given strategy and configuration.
+    ///
+    /// When the config sets a [`benchmark`](backtesting::BacktestConfig::benchmark),
+    /// the benchmark's chart is fetched over the same interval and range and
+    /// the result's `benchmark` field is populated.
     pub async fn backtest<S: backtesting::Strategy>(
         &self,
         strategy: S,
@@ -698,21 +1295,50 @@ impl Ticker {
     ) -> backtesting::Result<backtesting::BacktestResult> {
         let config = config.unwrap_or_default();
         config.validate()?;
-        let chart = self
-            .chart(interval, range)
-            .await
-            .map_err(|e| backtesting::BacktestError::ChartError(e.to_string()))?;
+        let chart_err = |e: FinanceError| backtesting::BacktestError::ChartError(e.to_string());
+
+        let Some(benchmark) = config.benchmark.clone() else {
+            let chart = self.chart(interval, range).await.map_err(chart_err)?;
+            let dividends = self.dividends(range).await.unwrap_or_default();
+            return backtesting::BacktestEngine::new(config).run_with_dividends(
+                &self.symbol,
+                &chart.candles,
+                strategy,
+                &dividends,
+            );
+        };
+
+        // Buy-and-hold of the backtested symbol reuses its own chart
+        let (chart, bench_candles) = if benchmark.eq_ignore_ascii_case(&self.symbol) {
+            let chart = self.chart(interval, range).await.map_err(chart_err)?;
+            let candles = chart.candles.clone();
+            (chart, candles)
+        } else {
+            let bench_ticker = Ticker::new(&benchmark).await.map_err(chart_err)?;
+            let (chart, bench_chart) = tokio::try_join!(
+                self.chart(interval, range),
+                bench_ticker.chart(interval, range)
+            )
+            .map_err(chart_err)?;
+            (chart, bench_chart.candles)
+        };
         let dividends = self.dividends(range).await.unwrap_or_default();
-        backtesting::BacktestEngine::new(config).run_with_dividends(
+        backtesting::BacktestEngine::new(config).run_with_benchmark(
             &self.symbol,
             &chart.candles,
             strategy,
             &dividends,
+            &benchmark,
+            &bench_candles,
         )
     }
 
     #[cfg(feature = "backtesting")]
     /// Run a backtest and compare performance against a benchmark symbol.
+    ///
+    /// Shorthand for [`backtest`](Self::backtest) with
+    /// [`BacktestConfig::benchmark`](backtesting::BacktestConfig::benchmark)
+    /// set to `benchmark`.
     pub async fn backtest_with_benchmark<S: backtesting::Strategy>(
         &self,
         strategy: S,
@@ -721,25 +1347,11 @@ impl Ticker {
         config: Option<backtesting::BacktestConfig>,
         benchmark: &str,
     ) -> backtesting::Result<backtesting::BacktestResult> {
-        let config = config.unwrap_or_default();
-        config.validate()?;
-        let bench_ticker = Ticker::new(benchmark)
-            .await
-            .map_err(|e| backtesting::BacktestError::ChartError(e.to_string()))?;
-        let (chart, bench_chart) = tokio::try_join!(
-            self.chart(interval, range),
-            bench_ticker.chart(interval, range)
-        )
-        .map_err(|e| backtesting::BacktestError::ChartError(e.to_string()))?;
-        let dividends = self.dividends(range).await.unwrap_or_default();
-        backtesting::BacktestEngine::new(config).run_with_benchmark(
-            &self.symbol,
-            &chart.candles,
-            strategy,
-            &dividends,
-            benchmark,
-            &bench_chart.candles,
-        )
+        let config = backtesting::BacktestConfig {
+            benchmark: Some(benchmark.to_string()),
+            ..config.unwrap_or_default()
+        };
+        self.backtest(strategy, interval, range, Some(config)).await
     }
 
     #[cfg(feature = "risk")]
@@ -815,65 +1427,169 @@ impl Ticker {
         Ok(events)
     }
 
+    /// Get the next earnings date(s), analyst estimate ranges, and dividend
+    /// dates as calendar dates.
+    ///
+    /// Typed view of [`Ticker::calendar_events`], which is fetched with every
+    /// quote. For a merged, time-sorted event list see [`Ticker::calendar`].
+    pub async fn key_dates(
+        &self,
+    ) -> Result<Option<crate::models::corporate::calendar_events::KeyDates>> {
+        Ok(self.calendar_events().await?.map(|c| c.key_dates()))
+    }
+
+    /// Get the stock's returns against its benchmark (usually the S&P 500)
+    /// over standard periods from one week to ten years.
+    ///
+    /// Typed view of [`Ticker::equity_performance`]; returns `None` when Yahoo
+    /// has no performance data for the symbol.
+    pub async fn performance_overview(
+        &self,
+    ) -> Result<Option<crate::models::corporate::equity_performance::PerformanceSummary>> {
+        Ok(self.equity_performance().await?.map(|p| p.summary()))
+    }
+
+    /// Get quarterly EPS actual vs. estimate and revenue/earnings series as
+    /// plain numbers, ready for charting.
+    ///
+    /// Typed view of [`Ticker::earnings`]; returns `None` when the symbol has
+    /// no earnings module (e.g. ETFs and indices).
+    pub async fn earnings_chart(
+        &self,
+    ) -> Result<Option<crate::models::corporate::earnings::EarningsChartData>> {
+        Ok(self.earnings().await?.map(|e| e.chart_data()))
+    }
+
+    /// Fetch quote-summary modules into the cache without reading them.
+    ///
+    /// Only modules not already cached are requested, so priming a ticker
+    /// with a few modules and asking for more later costs one small request
+    /// per step. Requires [`TickerBuilder::cache`]; without a TTL nothing is
+    /// kept between calls.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use finance_query::{QuoteModule, Ticker};
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let ticker = Ticker::builder("AAPL")
+    ///     .quote_modules([QuoteModule::Price])
+    ///     .cache(Duration::from_secs(60))
+    ///     .build()
+    ///     .await?;
+    ///
+    /// let price = ticker.price().await?; // fetches `price` only
+    /// ticker
+    ///     .prefetch(&[QuoteModule::FinancialData, QuoteModule::KeyStats])
+    ///     .await?; // fetches the two missing modules
+    /// let financials = ticker.financial_data().await?; // cache hit
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn prefetch(&self, modules: &[QuoteModule]) -> Result<()> {
+        self.ensure_quote(modules).await.map(drop)
+    }
+
+    fn quote_covers(
+        &self,
+        entry: Option<&CacheEntry<QuoteSummaryCache>>,
+        needed: &[QuoteModule],
+    ) -> bool {
+        self.is_shared_cache_fresh(entry)
+            && entry.is_some_and(|e| needed.iter().all(|m| e.value.modules.contains(m)))
+    }
+
     async fn ensure_quote(
         &self,
-    ) -> Result<tokio::sync::RwLockReadGuard<'_, Option<CacheEntry<QuoteSummaryResponse>>>> {
+        needed: &[QuoteModule],
+    ) -> Result<tokio::sync::RwLockReadGuard<'_, Option<CacheEntry<QuoteSummaryCache>>>> {
         {
             let cache = self.quote_cache.read().await;
-            if self.is_shared_cache_fresh(cache.as_ref()) {
+            if self.quote_covers(cache.as_ref(), needed) {
                 return Ok(cache);
             }
         }
         let _guard = self.quote_fetch.lock().await;
-        {
+        // A fresh cache only needs the modules it is missing; a stale or empty
+        // one starts over from the configured module set.
+        let missing: Arc<[QuoteModule]> = {
             let cache = self.quote_cache.read().await;
-            if self.is_shared_cache_fresh(cache.as_ref()) {
+            if self.quote_covers(cache.as_ref(), needed) {
                 return Ok(cache);
             }
-        }
+            let wanted: HashSet<QuoteModule> = match cache.as_ref() {
+                Some(e) if self.is_shared_cache_fresh(Some(e)) => needed
+                    .iter()
+                    .filter(|m| !e.value.modules.contains(m))
+                    .copied()
+                    .collect(),
+                _ => self.quote_modules.iter().chain(needed).copied().collect(),
+            };
+            // Keep the canonical order so identical sets produce identical URLs
+            QuoteModule::all()
+                .into_iter()
+                .filter(|m| wanted.contains(m))
+                .collect()
+        };
         let sym = self.symbol.clone();
+        let modules = Arc::clone(&missing);
         let summary = self
             .providers
             .fetch(Capability::QUOTE, move |p| {
                 let sym = sym.clone();
                 let p = p.clone();
-                async move { p.fetch_quote(&sym).await }
+                let modules = Arc::clone(&modules);
+                async move { p.fetch_quote_modules(&sym, &modules).await }
             })
             .await?;
         {
             let mut cache = self.quote_cache.write().await;
-            *cache = Some(CacheEntry::new(summary));
+            match cache.as_mut() {
+                Some(e) if self.is_shared_cache_fresh(Some(e)) => {
+                    e.value.summary.merge(summary);
+                    e.value.modules.extend(missing.iter().copied());
+                }
+                _ => {
+                    *cache = Some(CacheEntry::new(QuoteSummaryCache {
+                        summary,
+                        modules: missing.iter().copied().collect(),
+                    }));
+                }
+            }
         }
         Ok(self.quote_cache.read().await)
     }
 }
 
 super::macros::define_quote_accessors! {
-    price -> Price, price,
-    summary_detail -> SummaryDetail, summary_detail,
-    financial_data -> FinancialData, financial_data,
-    key_stats -> DefaultKeyStatistics, default_key_statistics,
-    asset_profile -> AssetProfile, asset_profile,
-    calendar_events -> CalendarEvents, calendar_events,
-    earnings -> Earnings, earnings,
-    earnings_trend -> EarningsTrend, earnings_trend,
-    earnings_history -> EarningsHistory, earnings_history,
-    recommendation_trend -> RecommendationTrend, recommendation_trend,
-    insider_holders -> InsiderHolders, insider_holders,
-    insider_transactions -> InsiderTransactions, insider_transactions,
-    institution_ownership -> InstitutionOwnership, institution_ownership,
-    fund_ownership -> FundOwnership, fund_ownership,
-    major_holders -> MajorHoldersBreakdown, major_holders_breakdown,
-    share_purchase_activity -> NetSharePurchaseActivity, net_share_purchase_activity,
-    quote_type -> QuoteTypeData, quote_type,
-    summary_profile -> SummaryProfile, summary_profile,
-    sec_filings -> SecFilings, sec_filings,
-    grading_history -> UpgradeDowngradeHistory, upgrade_downgrade_history,
-    fund_performance -> FundPerformance, fund_performance,
-    fund_profile -> FundProfile, fund_profile,
-    top_holdings -> TopHoldings, top_holdings,
-    index_trend -> IndexTrend, index_trend,
-    industry_trend -> IndustryTrend, industry_trend,
-    sector_trend -> SectorTrend, sector_trend,
-    equity_performance -> EquityPerformance, equity_performance,
+    price -> Price, price, Price,
+    summary_detail -> SummaryDetail, summary_detail, SummaryDetail,
+    financial_data -> FinancialData, financial_data, FinancialData,
+    key_stats -> DefaultKeyStatistics, default_key_statistics, KeyStats,
+    asset_profile -> AssetProfile, asset_profile, AssetProfile,
+    calendar_events -> CalendarEvents, calendar_events, CalendarEvents,
+    earnings -> Earnings, earnings, Earnings,
+    earnings_trend -> EarningsTrend, earnings_trend, EarningsTrend,
+    earnings_history -> EarningsHistory, earnings_history, EarningHistory,
+    recommendation_trend -> RecommendationTrend, recommendation_trend, RecommendationTrend,
+    insider_holders -> InsiderHolders, insider_holders, InsiderHolders,
+    insider_transactions -> InsiderTransactions, insider_transactions, InsiderTransactions,
+    institution_ownership -> InstitutionOwnership, institution_ownership, InstitutionOwnership,
+    fund_ownership -> FundOwnership, fund_ownership, FundOwnership,
+    major_holders -> MajorHoldersBreakdown, major_holders_breakdown, MajorHolders,
+    share_purchase_activity -> NetSharePurchaseActivity, net_share_purchase_activity, SharePurchaseActivity,
+    quote_type -> QuoteTypeData, quote_type, QuoteType,
+    summary_profile -> SummaryProfile, summary_profile, SummaryProfile,
+    sec_filings -> SecFilings, sec_filings, SecFilings,
+    grading_history -> UpgradeDowngradeHistory, upgrade_downgrade_history, GradingHistory,
+    fund_performance -> FundPerformance, fund_performance, FundPerformance,
+    fund_profile -> FundProfile, fund_profile, FundProfile,
+    top_holdings -> TopHoldings, top_holdings, TopHoldings,
+    index_trend -> IndexTrend, index_trend, IndexTrend,
+    industry_trend -> IndustryTrend, industry_trend, IndustryTrend,
+    sector_trend -> SectorTrend, sector_trend, SectorTrend,
+    equity_performance -> EquityPerformance, equity_performance, EquityPerformance,
+    esg_scores -> EsgScores, esg_scores, EsgScores,
 }
@@ -1,6 +1,9 @@
 //! Symbol-specific and market-level query handles.
 #![allow(missing_docs)]
 
+mod client;
 mod core;
 mod macros;
+pub(crate) use client::ClientCaches;
+pub use client::{FinanceClient, FinanceClientBuilder};
 pub use core::{ClientHandle, Ticker, TickerBuilder};
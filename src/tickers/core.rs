@@ -21,12 +21,14 @@ use crate::models::fundamentals::FinancialStatement;
 use crate::models::options::Options;
 use crate::models::quote::{Quote, QuoteSummaryResponse};
 
+use crate::providers::custom::{CustomProvider, DataProvider};
 use crate::providers::types::recommendation_from_similar;
 use crate::providers::yahoo::YahooProvider;
 use crate::providers::{
     Capability, Fetch, Provider, ProviderAdapter, ProviderSet, Routes, build_providers,
 };
-use crate::ticker::ClientHandle;
+use crate::symbol::Symbol;
+use crate::ticker::{ClientCaches, ClientHandle};
 use crate::utils::{CacheEntry, EVICTION_THRESHOLD, filter_by_range};
 use futures::stream::{self, StreamExt};
 use std::collections::HashMap;
@@ -53,6 +55,35 @@ type IndicatorsCache = MapCache<(Arc<str>, Interval, TimeRange), indicators::Ind
 type FetchGuard = Arc<tokio::sync::Mutex<()>>;
 type FetchGuardMap<K> = Arc<RwLock<HashMap<K, FetchGuard>>>;
 
+/// Response caches and fetch guards shared by every [`Tickers`] a
+/// [`FinanceClient`](crate::FinanceClient) builds.
+///
+/// Entries are keyed by symbol, so handles over different symbol sets can
+/// share one set. Quotes fetched with logos are kept apart from plain ones.
+#[derive(Clone, Default)]
+pub(crate) struct TickersCaches {
+    quote_cache: QuoteCache,
+    logo_quote_cache: QuoteCache,
+    chart_cache: ChartCache,
+    events_cache: EventsCache,
+    financials_cache: FinancialsCache,
+    news_cache: NewsCache,
+    recommendations_cache: RecommendationsCache,
+    options_cache: OptionsCache,
+    spark_cache: SparkCache,
+    #[cfg(feature = "indicators")]
+    indicators_cache: IndicatorsCache,
+    quotes_fetch: FetchGuard,
+    charts_fetch: FetchGuardMap<(Interval, TimeRange)>,
+    financials_fetch: FetchGuardMap<(StatementType, Frequency)>,
+    news_fetch: FetchGuard,
+    recommendations_fetch: FetchGuardMap<u32>,
+    options_fetch: FetchGuardMap<Option<i64>>,
+    spark_fetch: FetchGuardMap<(Interval, TimeRange)>,
+    #[cfg(feature = "indicators")]
+    indicators_fetch: FetchGuardMap<(Interval, TimeRange)>,
+}
+
 // Generate all batch response types
 define_batch_response! {
     /// Response containing quotes for multiple symbols.
@@ -122,6 +153,8 @@ pub struct TickersBuilder {
     config: ClientConfig,
     shared_client: Option<ClientHandle>,
     injected_providers: Option<Arc<ProviderSet>>,
+    shared_caches: Option<Arc<ClientCaches>>,
+    data_provider: Option<Arc<dyn DataProvider>>,
     max_concurrency: usize,
     cache_ttl: Option<Duration>,
     include_logo: bool,
@@ -138,6 +171,8 @@ impl TickersBuilder {
             config: ClientConfig::default(),
             shared_client: None,
             injected_providers: None,
+            shared_caches: None,
+            data_provider: None,
             max_concurrency: DEFAULT_MAX_CONCURRENCY,
             cache_ttl: None,
             include_logo: false,
@@ -175,6 +210,20 @@ impl TickersBuilder {
         self
     }
 
+    /// Set the maximum response size and decompression ratio
+    pub fn response_limits(mut self, limits: crate::ResponseLimits) -> Self {
+        self.config.limits = limits;
+        self
+    }
+
+    /// Set the connection pool and keep-alive settings
+    ///
+    /// Defaults are tuned for bulk downloads; see [`crate::ConnectionPool`].
+    pub fn connection_pool(mut self, pool: crate::ConnectionPool) -> Self {
+        self.config.pool = pool;
+        self
+    }
+
     #[allow(dead_code)]
     pub(crate) fn config(mut self, config: ClientConfig) -> Self {
         self.config = config;
@@ -244,14 +293,39 @@ impl TickersBuilder {
         self
     }
 
+    /// Serve quotes and charts from `provider` instead of Yahoo.
+    ///
+    /// See [`TickerBuilder::provider`](crate::TickerBuilder::provider). Batch
+    /// quotes fall back to one [`DataProvider::quote`] call per symbol.
+    pub fn provider(mut self, provider: impl DataProvider + 'static) -> Self {
+        self.data_provider = Some(Arc::new(provider));
+        self
+    }
+
     /// Build the Tickers instance
     pub async fn build(self) -> Result<Tickers> {
+        let symbols = self
+            .symbols
+            .iter()
+            .map(|s| Symbol::parse(s).map(Symbol::into_arc))
+            .collect::<Result<Vec<_>>>()?;
+        // Share the client's caches unless this handle swapped the provider
+        // or the language, either of which changes what would be cached.
+        let caches = match &self.shared_caches {
+            Some(shared) if self.data_provider.is_none() && shared.lang() == self.config.lang => {
+                shared.tickers.clone()
+            }
+            _ => TickersCaches::default(),
+        };
+
         #[cfg(feature = "translation")]
         let translate_lang = {
             let lang = crate::translation::Lang::parse(&self.config.lang)?;
             (!lang.is_english()).then_some(lang)
         };
-        let providers = if let Some(set) = self.injected_providers {
+        let providers = if let Some(provider) = self.data_provider {
+            Arc::new(CustomProvider::provider_set(provider))
+        } else if let Some(set) = self.injected_providers {
             set
         } else if let Some(handle) = self.shared_client {
             let yahoo = YahooProvider::from_client(handle.0);
@@ -273,34 +347,38 @@ impl TickersBuilder {
         };
 
         Ok(Tickers {
-            symbols: self.symbols,
+            symbols,
             providers,
             max_concurrency: self.max_concurrency,
             cache_ttl: self.cache_ttl,
             include_logo: self.include_logo,
             #[cfg(feature = "translation")]
             translate_lang,
-            quote_cache: Default::default(),
-            chart_cache: Default::default(),
-            events_cache: Default::default(),
-            financials_cache: Default::default(),
-            news_cache: Default::default(),
-            recommendations_cache: Default::default(),
-            options_cache: Default::default(),
-            spark_cache: Default::default(),
+            quote_cache: if self.include_logo {
+                caches.logo_quote_cache
+            } else {
+                caches.quote_cache
+            },
+            chart_cache: caches.chart_cache,
+            events_cache: caches.events_cache,
+            financials_cache: caches.financials_cache,
+            news_cache: caches.news_cache,
+            recommendations_cache: caches.recommendations_cache,
+            options_cache: caches.options_cache,
+            spark_cache: caches.spark_cache,
             #[cfg(feature = "indicators")]
-            indicators_cache: Default::default(),
-
-            // Initialize fetch guards for request deduplication
-            quotes_fetch: Arc::new(tokio::sync::Mutex::new(())),
-            charts_fetch: Default::default(),
-            financials_fetch: Default::default(),
-            news_fetch: Arc::new(tokio::sync::Mutex::new(())),
-            recommendations_fetch: Default::default(),
-            options_fetch: Default::default(),
-            spark_fetch: Default::default(),
+            indicators_cache: caches.indicators_cache,
+
+            // Fetch guards for request deduplication
+            quotes_fetch: caches.quotes_fetch,
+            charts_fetch: caches.charts_fetch,
+            financials_fetch: caches.financials_fetch,
+            news_fetch: caches.news_fetch,
+            recommendations_fetch: caches.recommendations_fetch,
+            options_fetch: caches.options_fetch,
+            spark_fetch: caches.spark_fetch,
             #[cfg(feature = "indicators")]
-            indicators_fetch: Default::default(),
+            indicators_fetch: caches.indicators_fetch,
         })
     }
 }
@@ -410,6 +488,28 @@ impl Tickers {
         TickersBuilder::new(symbols)
     }
 
+    /// Creates a builder that reuses `client`'s session, language, cache TTL,
+    /// and response cache.
+    ///
+    /// No new authentication handshake or connection pool is created, and
+    /// cached responses are shared with the client's other `Tickers`
+    /// handles, so [`clear_cache`](Self::clear_cache) and
+    /// [`remove_symbols`](Self::remove_symbols) affect them too.
+    pub fn with_client<S, I>(client: &crate::FinanceClient, symbols: I) -> TickersBuilder
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = S>,
+    {
+        let mut builder = TickersBuilder::new(symbols)
+            .lang(client.lang())
+            .with_provider_set(Arc::clone(&client.providers));
+        builder.shared_caches = Some(Arc::clone(&client.caches));
+        match client.cache_ttl {
+            Some(ttl) => builder.cache(ttl),
+            None => builder,
+        }
+    }
+
     /// Returns the symbols this tickers instance manages
     pub fn symbols(&self) -> Vec<&str> {
         self.symbols.iter().map(|s| &**s).collect()
@@ -447,7 +547,9 @@ impl Tickers {
     /// Returns `true` if a cache entry exists and has not exceeded the TTL.
     #[inline]
     fn is_cache_fresh<T>(&self, entry: Option<&CacheEntry<T>>) -> bool {
-        CacheEntry::is_fresh_with_ttl(entry, self.cache_ttl)
+        let fresh = CacheEntry::is_fresh_with_ttl(entry, self.cache_ttl);
+        crate::telemetry::record_cache_lookup(fresh);
+        fresh
     }
 
     /// Translate a response value when a non-English language is configured
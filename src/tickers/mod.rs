@@ -6,6 +6,7 @@
 mod core;
 mod macros;
 
+pub(crate) use core::TickersCaches;
 pub use core::{
     BatchCapitalGainsResponse, BatchChartsResponse, BatchDividendsResponse,
     BatchFinancialsResponse, BatchNewsResponse, BatchOptionsResponse, BatchQuotesResponse,
@@ -21,11 +21,11 @@ use crate::models::market::sectors::SectorData;
 use crate::models::options::Options;
 use crate::models::quote::{
     AssetProfile, CalendarEvents, CompanyOfficer, DefaultKeyStatistics, Earnings, EarningsHistory,
-    EarningsTrend, EquityPerformance, FinancialData, FundOwnership, FundPerformance, FundProfile,
-    IndexTrend, IndustryTrend, InsiderHolders, InsiderTransactions, InstitutionOwnership,
-    MajorHoldersBreakdown, NetSharePurchaseActivity, Price, Quote, QuoteTypeData,
-    RecommendationTrend, SecFilings, SectorTrend, SummaryDetail, SummaryProfile, TopHoldings,
-    UpgradeDowngradeHistory,
+    EarningsTrend, EquityPerformance, EsgScores, FinancialData, FundOwnership, FundPerformance,
+    FundProfile, IndexTrend, IndustryTrend, InsiderHolders, InsiderTransactions,
+    InstitutionOwnership, MajorHoldersBreakdown, NetSharePurchaseActivity, Price, Quote,
+    QuoteTypeData, RecommendationTrend, SecFilings, SectorTrend, SummaryDetail, SummaryProfile,
+    TopHoldings, UpgradeDowngradeHistory,
 };
 
 /// A response type whose human-readable text fields can be translated.
@@ -294,6 +294,7 @@ not_translatable!(
     IndustryTrend,
     SectorTrend,
     EquityPerformance,
+    EsgScores,
     Chart,
     Spark,
     Dividend,
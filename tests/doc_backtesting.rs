@@ -11,10 +11,126 @@ use finance_query::backtesting::refs::*;
 use finance_query::backtesting::{
     BacktestComparison, BacktestConfig, BayesianSearch, BollingerMeanReversion, DonchianBreakout,
     EnsembleMode, EnsembleStrategy, GridSearch, MacdSignal, MonteCarloConfig, MonteCarloMethod,
-    OptimizeMetric, ParamRange, RsiReversal, Signal, SmaCrossover, StrategyBuilder,
-    SuperTrendFollow, WalkForwardConfig,
+    OptimizeMetric, ParamRange, RsiReversal, Signal, SmaCrossover, Strategy, StrategyBuilder,
+    StrategyDefinition, SuperTrendFollow, WalkForwardConfig, evaluate_out_of_sample,
 };
 
+// ---------------------------------------------------------------------------
+// Runtime — custom data sources
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_run_on_csv_candles() {
+    // From backtesting.md "Custom Data Sources" section
+    use finance_query::Candle;
+    use finance_query::backtesting;
+
+    let mut csv = String::from("Date,Open,High,Low,Close,Adj Close,Volume\n");
+    for day in 0..60 {
+        let close = 100.0 + (day as f64 / 5.0).sin() * 10.0;
+        csv.push_str(&format!(
+            "{},{close},{},{},{close},{close},1000000\n",
+            1_704_153_600 + day * 86_400,
+            close + 1.0,
+            close - 1.0
+        ));
+    }
+    let candles = Candle::from_csv(csv.as_bytes()).unwrap();
+    assert_eq!(candles.len(), 60);
+
+    let result = backtesting::run(
+        SmaCrossover::new(5, 10),
+        &candles,
+        BacktestConfig::default(),
+    )
+    .unwrap();
+    assert!(!result.equity_curve.is_empty());
+
+    let manual = Candle::new(1_704_153_600, 1.0, 2.0, 0.5, 1.5, 100);
+    assert_eq!(manual.adj_close, None);
+}
+
+#[test]
+fn test_run_backtest_on_chart_from_csv_file() {
+    // From backtesting.md "Custom Data Sources" section
+    use finance_query::Chart;
+    use finance_query::backtesting;
+
+    let mut csv = String::from("timestamp,open,high,low,close,volume\n");
+    for bar in 0..60 {
+        let close = 4500.0 + (bar as f64 / 4.0).cos() * 25.0;
+        csv.push_str(&format!(
+            "{},{close},{},{},{close},250\n",
+            1_704_153_600 + bar * 3_600,
+            close + 2.0,
+            close - 2.0
+        ));
+    }
+    let dir = std::env::temp_dir().join(format!("fq-doc-backtest-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("ES.csv");
+    std::fs::write(&path, csv).unwrap();
+
+    let chart = Chart::from_csv(&path).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(chart.symbol, "ES");
+    assert_eq!(chart.candles.len(), 60);
+
+    let result = backtesting::run_backtest_on_candles(
+        SmaCrossover::new(5, 10),
+        &chart.candles,
+        BacktestConfig::default(),
+    )
+    .unwrap();
+    assert!(!result.equity_curve.is_empty());
+}
+
+// ---------------------------------------------------------------------------
+// Runtime — live signals
+// ---------------------------------------------------------------------------
+
+#[cfg(feature = "signals")]
+#[test]
+fn test_signal_runner_evaluate() {
+    // From backtesting.md "Live Signals" section
+    use finance_query::signals::{SignalRunner, SignalWebhook, verify_signature};
+    use finance_query::{Candle, Interval, TimeRange};
+
+    let definition = StrategyDefinition::from_json(
+        r#"{
+            "name": "Breakout",
+            "entry": { "type": "above", "indicator": { "name": "price" }, "target": 110 },
+            "exit": { "type": "below", "indicator": { "name": "price" }, "target": 90 }
+        }"#,
+    )
+    .unwrap();
+    let webhook = SignalWebhook::new("https://example.com/hooks/signals").with_secret("s3cret");
+    assert_eq!(webhook.url(), "https://example.com/hooks/signals");
+
+    let mut runner = SignalRunner::new(definition, ["AAPL"])
+        .interval(Interval::OneHour)
+        .range(TimeRange::ThreeMonths)
+        .webhook(webhook);
+
+    let candles: Vec<Candle> = [100.0, 105.0, 112.0]
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| Candle::new(1_704_153_600 + i as i64 * 3_600, c, c, c, c, 1_000))
+        .collect();
+    let events = runner.evaluate("AAPL", &candles).unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].symbol, "AAPL");
+    assert_eq!(events[0].price, 112.0);
+    assert!(runner.evaluate("AAPL", &candles).unwrap().is_empty());
+
+    assert!(!verify_signature(
+        "s3cret",
+        1_718_800_000,
+        "{}",
+        "sha256=00"
+    ));
+}
+
 // ---------------------------------------------------------------------------
 // Compile-time — BacktestConfig builder
 // ---------------------------------------------------------------------------
@@ -51,6 +167,73 @@ fn test_backtest_config_zero_cost() {
     let _ = config;
 }
 
+#[test]
+fn test_backtest_config_position_sizer() {
+    // From backtesting.md "Position Sizing" section
+    use finance_query::backtesting::{FixedAmount, FixedFraction, KellyFraction, VolatilityTarget};
+
+    let config = BacktestConfig::builder()
+        .position_sizer(VolatilityTarget::new(0.01, 14).atr_multiple(2.0))
+        .build()
+        .unwrap();
+    assert!(config.position_sizer.is_some());
+
+    let _ = FixedFraction::new(0.25);
+    let _ = FixedAmount::new(5_000.0);
+    let _ = KellyFraction::new(0.5)
+        .min_trades(20)
+        .fallback_fraction(0.1);
+}
+
+#[test]
+fn test_backtest_config_intrabar_priority() {
+    // From backtesting.md "Stop and Target Fills" section
+    use finance_query::backtesting::IntrabarPriority;
+
+    let config = BacktestConfig::builder()
+        .stop_loss_pct(0.05)
+        .take_profit_pct(0.10)
+        .intrabar_priority(IntrabarPriority::NearestToOpen)
+        .build()
+        .unwrap();
+    assert_eq!(config.intrabar_priority, IntrabarPriority::NearestToOpen);
+}
+
+#[test]
+fn test_backtest_config_financing() {
+    // From backtesting.md "Leverage and Financing Costs" section
+    let config = BacktestConfig::builder()
+        .allow_short(true)
+        .borrow_fee_rate(0.03)
+        .max_leverage(2.0)
+        .margin_interest_rate(0.08)
+        .build()
+        .unwrap();
+    assert_eq!(config.max_leverage, 2.0);
+}
+
+#[test]
+fn test_backtest_config_dividends() {
+    // From backtesting.md "Dividends" section
+    let config = BacktestConfig::builder()
+        .allow_short(true)
+        .reinvest_dividends(true)
+        .short_pays_dividends(false)
+        .build()
+        .unwrap();
+    assert!(!config.short_pays_dividends);
+}
+
+#[test]
+fn test_backtest_config_total_return() {
+    // From backtesting.md "Dividends" section
+    let config = BacktestConfig::builder()
+        .use_total_return(true)
+        .build()
+        .unwrap();
+    assert!(config.use_total_return);
+}
+
 #[test]
 fn test_backtest_config_commission_fn() {
     // From backtesting.md "Custom Commission Function" section
@@ -137,6 +320,63 @@ fn test_strategy_builder_warmup() {
     let _ = strategy;
 }
 
+#[test]
+fn test_pattern_count_ref() {
+    use finance_query::indicators::PatternSentiment;
+
+    // From backtesting.md "Reference Signals" section
+    let strategy = StrategyBuilder::new("Pattern Confirmed RSI")
+        .entry(
+            rsi(14)
+                .below(30.0)
+                .and(pattern_count(PatternSentiment::Bullish, 5).above(0.5)),
+        )
+        .exit(pattern_count(PatternSentiment::Bearish, 3).above(0.5))
+        .build();
+    let _ = strategy;
+
+    let json = r#"{
+      "name": "Bullish Patterns",
+      "entry": { "type": "above", "indicator": { "name": "pattern_count", "params": [5], "output": "bullish" }, "target": 1 },
+      "exit": { "type": "stop_loss", "pct": 0.05 }
+    }"#;
+    assert!(StrategyDefinition::from_json(json).unwrap().build().is_ok());
+}
+
+#[test]
+fn test_strategy_definition_json() {
+    // From backtesting.md "Saving Strategies as JSON" section
+    let json = r#"{
+      "name": "RSI Mean Reversion",
+      "parameters": { "period": 14, "oversold": 30 },
+      "entry": {
+        "type": "all",
+        "conditions": [
+          { "type": "crosses_below", "indicator": { "name": "rsi", "params": ["$period"] }, "target": "$oversold" },
+          { "type": "above", "indicator": { "name": "price" }, "target": { "name": "sma", "params": [200] } }
+        ]
+      },
+      "exit": {
+        "type": "any",
+        "conditions": [
+          { "type": "crosses_above", "indicator": { "name": "rsi", "params": ["$period"] }, "target": 70 },
+          { "type": "stop_loss", "pct": 0.05 }
+        ]
+      }
+    }"#;
+
+    let definition = StrategyDefinition::from_json(json).unwrap();
+    let strategy = definition
+        .clone()
+        .with_param("period", 10.0)
+        .build()
+        .unwrap();
+    assert_eq!(strategy.name(), "RSI Mean Reversion");
+
+    let round_trip = StrategyDefinition::from_json(&definition.to_json().unwrap()).unwrap();
+    assert_eq!(round_trip, definition);
+}
+
 #[test]
 fn test_strategy_builder_validated() {
     // From backtesting.md "Best Practices" section
@@ -200,6 +440,68 @@ fn test_signal_scale() {
     let _ = (add_to_position, reduce_position);
 }
 
+#[test]
+fn test_scale_config_and_fills() {
+    use finance_query::Candle;
+    use finance_query::backtesting::{BacktestEngine, FillKind, StrategyContext};
+    use finance_query::indicators::Indicator;
+
+    // From backtesting.md "Scale In / Out" section
+    let config = BacktestConfig::builder()
+        .max_scale_ins(2)
+        .scale_out_target(0.05, 1.0 / 3.0)
+        .scale_out_target(0.10, 0.5)
+        .scale_out_target(0.20, 1.0)
+        .build()
+        .unwrap();
+
+    struct BuyAndHold;
+    impl Strategy for BuyAndHold {
+        fn name(&self) -> &str {
+            "BuyAndHold"
+        }
+        fn required_indicators(&self) -> Vec<(String, Indicator)> {
+            vec![]
+        }
+        fn on_candle(&self, ctx: &StrategyContext) -> Signal {
+            if ctx.has_position() {
+                Signal::hold()
+            } else {
+                Signal::long(ctx.timestamp(), ctx.close())
+            }
+        }
+    }
+
+    let candles: Vec<Candle> = (0..30)
+        .map(|i| {
+            let p = 100.0 + i as f64;
+            serde_json::from_value(serde_json::json!({
+                "timestamp": i, "open": p, "high": p, "low": p, "close": p, "volume": 0
+            }))
+            .unwrap()
+        })
+        .collect();
+    let result = BacktestEngine::new(config)
+        .run("TEST", &candles, BuyAndHold)
+        .unwrap();
+
+    for (id, trades) in result.trades_by_position() {
+        let parent = trades.last().unwrap();
+        println!("Position {id}: {} partial exit(s)", trades.len() - 1);
+        for fill in &parent.fills {
+            let label = match fill.kind {
+                FillKind::Entry => "entry",
+                FillKind::ScaleIn => "add",
+                FillKind::ScaleOut => "trim",
+                FillKind::Exit => "exit",
+                _ => "other",
+            };
+            println!("  {label}: {:.2} @ {:.2}", fill.quantity, fill.price);
+        }
+    }
+    assert_eq!(result.trades.iter().filter(|t| t.is_partial).count(), 2);
+}
+
 #[test]
 fn test_signal_tags() {
     // From backtesting.md "Signal Tags" section
@@ -252,6 +554,67 @@ fn test_htf_condition_build() {
     let _ = strategy;
 }
 
+#[test]
+fn test_htf_indicators_in_custom_strategy() {
+    // From backtesting.md "In Custom Strategies" section
+    use finance_query::backtesting::condition::HtfIndicatorSpec;
+    use finance_query::backtesting::{BacktestEngine, StrategyContext};
+    use finance_query::indicators::Indicator;
+    use finance_query::{Candle, Interval};
+
+    struct DailyTrendFilter;
+
+    impl Strategy for DailyTrendFilter {
+        fn name(&self) -> &str {
+            "Daily Trend Filter"
+        }
+
+        fn required_indicators(&self) -> Vec<(String, Indicator)> {
+            vec![("ema_9".to_string(), Indicator::Ema(9))]
+        }
+
+        fn htf_requirements(&self) -> Vec<HtfIndicatorSpec> {
+            vec![HtfIndicatorSpec::new(
+                Interval::OneDay,
+                "sma_50",
+                Indicator::Sma(50),
+            )]
+        }
+
+        fn on_candle(&self, ctx: &StrategyContext) -> Signal {
+            let close = ctx.close();
+            let (Some(ema), Some(daily_sma)) = (
+                ctx.indicator("ema_9"),
+                ctx.indicator_on(Interval::OneDay, "sma_50"),
+            ) else {
+                return Signal::hold();
+            };
+            if close > ema && close > daily_sma && !ctx.has_position() {
+                Signal::long(ctx.timestamp(), close)
+            } else if close < ema && ctx.is_long() {
+                Signal::exit(ctx.timestamp(), close)
+            } else {
+                Signal::hold()
+            }
+        }
+    }
+
+    // 80 trading days of 7 hourly bars on a rising, oscillating series
+    let candles: Vec<Candle> = (0..80 * 7)
+        .map(|i: i64| {
+            let ts = 1_704_204_000 + (i / 7) * 86_400 + (i % 7) * 3_600;
+            let close = 100.0 + i as f64 * 0.05 + (i as f64 / 3.0).sin() * 2.0;
+            Candle::new(ts, close, close + 0.5, close - 0.5, close, 1_000)
+        })
+        .collect();
+    let result = BacktestEngine::new(BacktestConfig::default())
+        .run("TEST", &candles, DailyTrendFilter)
+        .unwrap();
+    // The daily SMA(50) only exists after 50 completed days
+    let first_entry = result.trades.first().unwrap().entry_timestamp;
+    assert!(first_entry >= candles[50 * 7 - 1].timestamp);
+}
+
 // ---------------------------------------------------------------------------
 // Compile-time — MonteCarloMethod variants
 // ---------------------------------------------------------------------------
@@ -573,6 +936,9 @@ async fn test_performance_metrics_access() {
         "Max Consec. Losses: {}",
         result.metrics.max_consecutive_losses
     );
+    println!("Avg MAE: {:.2}%", result.metrics.avg_mae_pct);
+    println!("Avg MFE: {:.2}%", result.metrics.avg_mfe_pct);
+    println!("Avg Bars Held: {:.1}", result.metrics.avg_bars_held);
 
     // Position breakdown
     println!("Long Trades: {}", result.metrics.long_trades);
@@ -590,6 +956,10 @@ async fn test_performance_metrics_access() {
         "Dividend Income: ${:.2}",
         result.metrics.total_dividend_income
     );
+    println!(
+        "Financing Cost: ${:.2}",
+        result.metrics.total_financing_cost
+    );
 
     // Advanced statistics
     println!("Kelly Criterion: {:.2}", result.metrics.kelly_criterion);
@@ -602,6 +972,39 @@ async fn test_performance_metrics_access() {
     println!("Serenity Ratio: {:.2}", result.metrics.serenity_ratio);
 }
 
+// ---------------------------------------------------------------------------
+// Runtime — trade log
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_trade_log() {
+    // From backtesting.md "Trade Log" section
+    use finance_query::Candle;
+    use finance_query::backtesting;
+
+    let candles: Vec<Candle> = (0..120)
+        .map(|day| {
+            let close = 100.0 + (day as f64 / 6.0).sin() * 10.0;
+            Candle::new(day * 86_400, close, close + 1.0, close - 1.0, close, 1_000)
+        })
+        .collect();
+    let result = backtesting::run(
+        SmaCrossover::new(5, 10),
+        &candles,
+        BacktestConfig::default(),
+    )
+    .unwrap();
+    assert!(!result.trades.is_empty());
+
+    for t in &result.trades {
+        assert!(t.mae_pct >= 0.0 && t.mfe_pct >= 0.0);
+        assert!(t.bars_held > 0);
+    }
+    let csv = result.trades_csv();
+    assert_eq!(csv.lines().count(), result.trades.len() + 1);
+    assert!(result.summary().contains("Avg MFE"));
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — advanced result analysis
 // ---------------------------------------------------------------------------
@@ -700,6 +1103,34 @@ async fn test_diagnostics() {
     }
 }
 
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_run_metadata() {
+    use finance_query::{Interval, Ticker, TimeRange};
+
+    // From backtesting.md "Run Metadata" section
+    let ticker = Ticker::new("AAPL").await.unwrap();
+    let config = BacktestConfig::builder().seed(42).build().unwrap();
+    let result = ticker
+        .backtest(
+            SmaCrossover::new(10, 20),
+            Interval::OneDay,
+            TimeRange::OneYear,
+            Some(config),
+        )
+        .await
+        .unwrap();
+
+    let meta = &result.run_metadata;
+    assert_eq!(meta.seed, Some(42));
+    assert!(meta.candle_count > 0);
+    assert!(meta.data_start <= result.start_timestamp);
+    assert!(!meta.config_hash.is_empty());
+
+    let mc = MonteCarloConfig::new().run(&result);
+    assert_eq!(mc.seed, 42);
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — ensemble strategy
 // ---------------------------------------------------------------------------
@@ -736,25 +1167,62 @@ async fn test_backtest_with_benchmark() {
 
     // From backtesting.md "Benchmark Comparison" section
     let ticker = Ticker::new("AAPL").await.unwrap();
+    let config = BacktestConfig::builder().benchmark("SPY").build().unwrap();
     let result = ticker
-        .backtest_with_benchmark(
+        .backtest(
             SmaCrossover::new(10, 50),
             Interval::OneDay,
             TimeRange::OneYear,
-            None,
-            "SPY",
+            Some(config),
         )
         .await
         .unwrap();
 
-    if let Some(bench) = &result.benchmark {
-        println!("Strategy return:   {:.2}%", result.metrics.total_return_pct);
-        println!("Benchmark return:  {:.2}%", bench.benchmark_return_pct);
-        println!("Buy & hold return: {:.2}%", bench.buy_and_hold_return_pct);
-        println!("Alpha: {:.4}", bench.alpha);
-        println!("Beta:  {:.4}", bench.beta);
-        println!("Information Ratio: {:.4}", bench.information_ratio);
-    }
+    let bench = result.benchmark.as_ref().unwrap();
+    println!("Strategy return:   {:.2}%", result.metrics.total_return_pct);
+    println!("Benchmark return:  {:.2}%", bench.benchmark_return_pct);
+    println!("Buy & hold return: {:.2}%", bench.buy_and_hold_return_pct);
+    println!("Alpha: {:.4}", bench.alpha);
+    println!("Beta:  {:.4}", bench.beta);
+    println!("Information Ratio: {:.4}", bench.information_ratio);
+    println!("Benchmark max DD:  {:.2}%", bench.max_drawdown_pct * 100.0);
+    println!(
+        "Relative DD:       {:.2}%",
+        bench.relative_drawdown_pct * 100.0
+    );
+    assert_eq!(bench.equity_curve.len(), result.equity_curve.len());
+}
+
+#[test]
+fn test_benchmark_with_own_candles() {
+    // From backtesting.md "Benchmark Comparison" section (engine form)
+    use finance_query::Candle;
+    use finance_query::backtesting::BacktestEngine;
+
+    let daily = |closes: &[f64]| -> Vec<Candle> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| Candle::new(1_704_153_600 + i as i64 * 86_400, c, c, c, c, 1_000))
+            .collect()
+    };
+    let candles = daily(&[100.0, 101.0, 102.0, 101.0, 103.0, 104.0]);
+    let spy_candles = daily(&[400.0, 402.0, 401.0, 405.0, 404.0, 408.0]);
+    let result = BacktestEngine::new(BacktestConfig::default())
+        .run_with_benchmark(
+            "AAPL",
+            &candles,
+            SmaCrossover::new(2, 3),
+            &[],
+            "SPY",
+            &spy_candles,
+        )
+        .unwrap();
+
+    let bench = result.benchmark.unwrap();
+    assert_eq!(bench.symbol, "SPY");
+    assert_eq!(bench.equity_curve.len(), result.equity_curve.len());
+    assert_eq!(bench.equity_curve[0].equity, 10_000.0);
 }
 
 // ---------------------------------------------------------------------------
@@ -947,6 +1415,106 @@ async fn test_walk_forward() {
     }
 }
 
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_train_test_split() {
+    use finance_query::{Interval, Ticker, TimeRange};
+
+    // From backtesting.md "Train/Test Split" section
+    let ticker = Ticker::new("AAPL").await.unwrap();
+    let chart = ticker
+        .chart(Interval::OneDay, TimeRange::FiveYears)
+        .await
+        .unwrap();
+
+    let (train, test) = chart.split_at(0.7).unwrap();
+
+    let report = evaluate_out_of_sample(
+        SmaCrossover::new(10, 50),
+        &train,
+        &test,
+        BacktestConfig::default(),
+    )
+    .unwrap();
+
+    println!(
+        "In-sample:     {:.2}%",
+        report.in_sample.metrics.total_return_pct
+    );
+    println!(
+        "Out-of-sample: {:.2}%",
+        report.out_of_sample.metrics.total_return_pct
+    );
+    println!("Degradation:   {:.2} pp", report.return_degradation());
+    assert_eq!(
+        report.out_of_sample.start_timestamp,
+        test.candles[0].timestamp
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_grid_search_out_of_sample() {
+    use finance_query::{Interval, Ticker, TimeRange};
+
+    // From backtesting.md "Optimize, Then Hold Out" section
+    let ticker = Ticker::new("AAPL").await.unwrap();
+    let chart = ticker
+        .chart(Interval::OneDay, TimeRange::FiveYears)
+        .await
+        .unwrap();
+    let (train, test) = chart.split_at(0.7).unwrap();
+
+    let report = GridSearch::new()
+        .param("fast", ParamRange::int_range(5, 20, 5))
+        .param("slow", ParamRange::int_range(20, 60, 10))
+        .optimize_for(OptimizeMetric::SharpeRatio)
+        .run_out_of_sample(&train, &test, &BacktestConfig::default(), |params| {
+            SmaCrossover::new(
+                params["fast"].as_int() as usize,
+                params["slow"].as_int() as usize,
+            )
+        })
+        .unwrap();
+
+    println!("Best params:   {:?}", report.best_params);
+    assert_eq!(report.best_params, report.optimization.best.params);
+    assert_eq!(
+        report.split.out_of_sample.start_timestamp,
+        test.candles[0].timestamp
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_chart_as_of() {
+    use finance_query::{Interval, Ticker, TimeRange};
+
+    // From backtesting.md "Point-in-Time Data" section
+    let ticker = Ticker::new("AAPL").await.unwrap();
+    let chart = ticker
+        .chart(Interval::OneHour, TimeRange::OneMonth)
+        .await
+        .unwrap();
+
+    let cutoff = chrono::Utc::now().timestamp() - 5 * 86_400;
+    let seen = chart.as_of(cutoff);
+
+    assert!(seen.candles.len() < chart.candles.len());
+    assert!(
+        seen.candles
+            .iter()
+            .all(|c| c.timestamp + Interval::OneHour.approx_duration_secs() <= cutoff)
+    );
+    assert!(seen.meta.regular_market_price.is_none());
+
+    let result = finance_query::backtesting::BacktestEngine::new(BacktestConfig::default())
+        .run("AAPL", &seen.candles, SmaCrossover::new(10, 20))
+        .unwrap();
+    println!("Return: {:.2}%", result.metrics.total_return_pct);
+    let _rsi = seen.rsi(14).unwrap();
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — Monte Carlo simulation
 // ---------------------------------------------------------------------------
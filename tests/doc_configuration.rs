@@ -68,10 +68,13 @@ fn test_region_lang_and_region_methods() {
 fn test_interval_variants_compile() {
     // Intraday intervals
     let _ = Interval::OneMinute;
+    let _ = Interval::TwoMinutes;
     let _ = Interval::FiveMinutes;
     let _ = Interval::FifteenMinutes;
     let _ = Interval::ThirtyMinutes;
     let _ = Interval::OneHour;
+    let _ = Interval::NinetyMinutes;
+    let _ = Interval::FourHours;
     // Daily and longer
     let _ = Interval::OneDay;
     let _ = Interval::OneWeek;
@@ -79,6 +82,34 @@ fn test_interval_variants_compile() {
     let _ = Interval::ThreeMonths;
 }
 
+#[test]
+fn test_interval_try_from_duration() {
+    let interval = Interval::try_from(Duration::from_secs(4 * 3600)).unwrap();
+    assert_eq!(interval, Interval::FourHours);
+    assert!(Interval::try_from(Duration::from_secs(7)).is_err());
+    assert_eq!(Interval::FourHours.resample_base(), Some(Interval::OneHour));
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_chart_resample_four_hours() {
+    use finance_query::Ticker;
+
+    let ticker = Ticker::new("AAPL").await.unwrap();
+    let hourly = ticker
+        .chart(Interval::OneHour, TimeRange::OneMonth)
+        .await
+        .unwrap();
+    let four_hour = hourly.resample(Interval::FourHours).unwrap();
+    assert!(four_hour.candles.len() < hourly.candles.len());
+
+    let fetched = ticker
+        .chart(Interval::FourHours, TimeRange::OneMonth)
+        .await
+        .unwrap();
+    assert_eq!(fetched.interval, Some(Interval::FourHours));
+}
+
 // ---------------------------------------------------------------------------
 // TimeRange enum — all variants documented in configuration.md
 // ---------------------------------------------------------------------------
@@ -111,6 +142,31 @@ fn test_format_types_compile() {
     let _ = finance_query::format::Both;
 }
 
+#[test]
+fn test_format_options() {
+    use finance_query::{FormatOptions, PercentStyle, UnitStyle, ValueFormat};
+
+    // From configuration.md "Display Options" section
+    let opts = FormatOptions::new()
+        .decimals(1)
+        .locale("de-DE")
+        .percent_style(PercentStyle::BasisPoints)
+        .units(UnitStyle::Full);
+
+    assert_eq!(opts.format_large(3.45e12), "3.450.000.000.000,0");
+    assert_eq!(opts.format_percent(0.0125), "125,0 bp");
+
+    let json = serde_json::json!({"marketCap": {"raw": 3.45e12, "fmt": "3.45T"}});
+    let pretty = ValueFormat::Pretty.transform_with(json, &opts);
+    assert_eq!(pretty["marketCap"], "3.450.000.000.000,0");
+
+    let parsed: FormatOptions = "decimals=1,locale=de-DE,percent=bp,units=full"
+        .parse()
+        .unwrap();
+    assert_eq!(parsed, opts);
+    assert!(FormatOptions::new().is_default());
+}
+
 // ---------------------------------------------------------------------------
 // Compile-time — proxy builder methods (can't exercise real proxies in tests)
 // ---------------------------------------------------------------------------
@@ -136,6 +192,64 @@ fn test_ticker_builder_proxy_and_timeout_compiles() {
         .timeout(Duration::from_secs(45));
 }
 
+#[test]
+fn test_response_limits_builder() {
+    use finance_query::{ResponseLimits, Ticker, Tickers};
+
+    // From configuration.md "Response Size Limits" section
+    let limits = ResponseLimits::new()
+        .max_bytes(16 * 1024 * 1024)
+        .max_decompression_ratio(50.0);
+    assert_eq!(limits.bytes_limit(), Some(16 * 1024 * 1024));
+    assert_eq!(limits.ratio_limit(), Some(50.0));
+    assert_eq!(ResponseLimits::unlimited().bytes_limit(), None);
+
+    let _builder = Ticker::builder("AAPL").response_limits(limits);
+    let _builder = Tickers::builder(["AAPL", "MSFT"]).response_limits(limits);
+}
+
+#[test]
+fn test_connection_pool_builder() {
+    use finance_query::{ConnectionPool, Ticker, Tickers};
+
+    // From configuration.md "Connection Pooling" section
+    let pool = ConnectionPool::new()
+        .max_idle_per_host(128)
+        .idle_timeout(Duration::from_secs(120))
+        .keep_alive(Duration::from_secs(15))
+        .http2_adaptive_window(true);
+    assert_eq!(pool.idle_per_host(), 128);
+    assert_eq!(pool.idle_timeout_duration(), Some(Duration::from_secs(120)));
+    assert_eq!(pool.keep_alive_interval(), Some(Duration::from_secs(15)));
+    assert_eq!(pool.no_keep_alive().keep_alive_interval(), None);
+
+    let _builder = Ticker::builder("AAPL").connection_pool(pool);
+    let _builder = Tickers::builder(["AAPL", "MSFT", "NVDA"]).connection_pool(pool);
+}
+
+// ---------------------------------------------------------------------------
+// Request telemetry
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_request_telemetry_api() {
+    // From configuration.md "Request Telemetry" section
+    let stats: finance_query::TelemetryStats = finance_query::stats();
+    let _: u64 = stats.requests;
+    let _: u64 = stats.bytes_received;
+    let _: Option<f64> = stats.cache_hit_rate();
+    let _: u64 = stats.fallbacks + stats.rate_limit_waits + stats.rate_limit_wait_ms;
+    for (endpoint, count) in &stats.requests_by_endpoint {
+        let _: (&String, &u64) = (endpoint, count);
+    }
+    for (endpoint, err) in &stats.last_error_by_endpoint {
+        let _: &String = endpoint;
+        let _: &finance_query::EndpointError = err;
+        let _: (&str, i64) = (&err.message, err.at);
+    }
+    finance_query::reset_stats();
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — builder patterns that require Yahoo auth
 // ---------------------------------------------------------------------------
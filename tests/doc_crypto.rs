@@ -172,3 +172,121 @@ async fn test_crypto_coin_handle() {
     let history = btc_chart.history("usd", TimeRange::OneMonth).await.unwrap();
     assert!(!history.candles.is_empty());
 }
+
+// ---------------------------------------------------------------------------
+// Exchange candles & trades
+// ---------------------------------------------------------------------------
+
+/// Mirrors the `verify_crypto_trade_fields` block in crypto.md.
+#[allow(dead_code)]
+fn _verify_crypto_trade_fields(t: finance_query::crypto::CryptoTrade) {
+    use finance_query::crypto::{CryptoExchange, TradeSide};
+    let _: CryptoExchange = t.exchange;
+    let _: String = t.pair;
+    let _: u64 = t.trade_id;
+    let _: f64 = t.price;
+    let _: f64 = t.size;
+    let _: Option<TradeSide> = t.side;
+    let _: i64 = t.timestamp_ms;
+}
+
+#[tokio::test]
+async fn test_exchange_candles_rejects_bad_input() {
+    use finance_query::crypto::{self, CryptoExchange};
+    use finance_query::{FinanceError, Interval, TimeRange};
+
+    // Malformed pair and unsupported interval fail before any request.
+    let err = crypto::candles(
+        CryptoExchange::Binance,
+        "BTCUSDT",
+        Interval::OneHour,
+        TimeRange::OneDay,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(err, FinanceError::InvalidParameter { .. }));
+
+    let err = crypto::candles(
+        CryptoExchange::Coinbase,
+        "BTC-USD",
+        Interval::FourHours,
+        TimeRange::OneDay,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(err, FinanceError::InvalidParameter { .. }));
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_exchange_candles() {
+    use finance_query::crypto::{self, CryptoExchange};
+    use finance_query::{Interval, TimeRange};
+
+    let chart = crypto::candles(
+        CryptoExchange::Binance,
+        "BTC-USDT",
+        Interval::OneHour,
+        TimeRange::FiveDays,
+    )
+    .await
+    .unwrap();
+    assert_eq!(chart.meta.currency.as_deref(), Some("USDT"));
+    assert!(chart.candles.len() > 100);
+    assert!(
+        chart
+            .candles
+            .windows(2)
+            .all(|w| w[0].timestamp < w[1].timestamp)
+    );
+    assert!(chart.candles.iter().all(|c| c.low <= c.high));
+
+    let end = chrono::Utc::now().timestamp();
+    let minutes = crypto::candles_between(
+        CryptoExchange::Coinbase,
+        "ETH-USD",
+        Interval::OneMinute,
+        end - 6 * 3600,
+        end,
+    )
+    .await
+    .unwrap();
+    assert!(
+        minutes.candles.len() > 300,
+        "should page past Coinbase's 300-bar limit"
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_exchange_trades() {
+    use finance_query::crypto::{self, CryptoExchange};
+
+    let trades = crypto::trades(CryptoExchange::Coinbase, "BTC-USD", 100)
+        .await
+        .unwrap();
+    assert!(!trades.is_empty() && trades.len() <= 100);
+    assert!(
+        trades
+            .windows(2)
+            .all(|w| w[0].timestamp_ms >= w[1].timestamp_ms)
+    );
+    assert!(trades.iter().all(|t| t.price > 0.0 && t.pair == "BTC-USD"));
+}
+
+#[cfg(feature = "streaming")]
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_exchange_trade_stream() {
+    use finance_query::crypto::{CryptoExchange, TradeStream};
+    use futures::StreamExt;
+
+    let mut stream = TradeStream::subscribe(CryptoExchange::Binance, &["BTC-USDT"])
+        .await
+        .unwrap();
+    let trade = tokio::time::timeout(std::time::Duration::from_secs(30), stream.next())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(trade.pair, "BTC-USDT");
+}
@@ -4,9 +4,11 @@
 //!   cargo test --test doc_dataframe --features dataframe
 //!   cargo test --test doc_dataframe --features dataframe -- --ignored   (network tests)
 //!
+//! "Built-in Export" tests additionally require the `export` feature.
+//!
 //! Skipped sections (require additional Polars features not enabled by `dataframe`):
-//!   - "CSV Export"     — requires polars/csv feature
-//!   - "Parquet Export" — requires polars/parquet feature
+//!   - "CSV Export"     — requires polars/csv feature (covered by `export`)
+//!   - "Parquet Export" — requires polars/parquet feature (covered by `export`)
 //!   - "JSON Export"    — requires polars/json feature
 //!   - "Rolling Windows" — RollingOptionsFixedWindow not in polars/lazy
 //!
@@ -47,6 +49,49 @@ async fn test_chart_to_dataframe() {
     assert!(df.height() > 0);
 }
 
+#[test]
+fn test_chart_dataframe_one_row_per_candle() {
+    use finance_query::Chart;
+    use polars::prelude::*;
+
+    let chart: Chart = serde_json::from_value(serde_json::json!({
+        "symbol": "AAPL",
+        "meta": {"symbol": "AAPL"},
+        "candles": [
+            {"timestamp": 1700000000, "open": 1.0, "high": 2.0, "low": 0.5, "close": 1.5, "volume": 10, "adjClose": 1.4},
+            {"timestamp": 1700086400, "open": 1.5, "high": 2.5, "low": 1.0, "close": 2.0, "volume": 20},
+            {"timestamp": 1700172800, "open": 2.0, "high": 3.0, "low": 1.5, "close": 2.5, "volume": 30, "adjClose": 2.4},
+        ],
+    }))
+    .unwrap();
+
+    let df = chart.to_dataframe().unwrap();
+    assert_eq!(df.height(), chart.candles.len());
+    let names = df.get_column_names_owned();
+    let names: Vec<&str> = names.iter().map(|n| n.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "timestamp",
+            "open",
+            "high",
+            "low",
+            "close",
+            "volume",
+            "adj_close"
+        ]
+    );
+    for (name, dtype) in Chart::dataframe_schema().iter() {
+        assert_eq!(df.column(name).unwrap().dtype(), dtype, "column {name}");
+    }
+
+    let timestamps = df.column("timestamp").unwrap().i64().unwrap();
+    assert_eq!(timestamps.get(2), Some(1700172800));
+    let adj_close = df.column("adj_close").unwrap().f64().unwrap();
+    assert_eq!(adj_close.get(0), Some(1.4));
+    assert_eq!(adj_close.get(1), None);
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — Quote Data (dataframe.md "Quote Data" section)
 // ---------------------------------------------------------------------------
@@ -380,6 +425,78 @@ async fn test_polars_aggregations() {
     assert!(monthly.height() > 0);
 }
 
+// ---------------------------------------------------------------------------
+// Built-in Export (dataframe.md "Built-in Export" section)
+// ---------------------------------------------------------------------------
+
+#[cfg(feature = "export")]
+#[test]
+fn test_dataframe_export_trait() {
+    use finance_query::Candle;
+    use finance_query::export::DataFrameExport;
+
+    let candles: Vec<Candle> = serde_json::from_value(serde_json::json!([
+        {"timestamp": 1700000000, "open": 1.0, "high": 2.0, "low": 0.5, "close": 1.5, "volume": 10},
+        {"timestamp": 1700086400, "open": 1.5, "high": 2.5, "low": 1.0, "close": 2.0, "volume": 20},
+    ]))
+    .unwrap();
+
+    let dir = std::env::temp_dir();
+    let csv = dir.join("doc_dataframe_export.csv");
+    let parquet = dir.join("doc_dataframe_export.parquet");
+    let mut df = Candle::vec_to_dataframe(&candles).unwrap();
+    df.write_csv(&csv).unwrap();
+    df.write_parquet(&parquet).unwrap();
+
+    let text = std::fs::read_to_string(&csv).unwrap();
+    assert_eq!(text.lines().count(), 3);
+    assert!(std::fs::metadata(&parquet).unwrap().len() > 0);
+
+    // Loading back
+    let chart = finance_query::Chart::from_parquet(&parquet).unwrap();
+    assert_eq!(chart.symbol, "doc_dataframe_export");
+    assert_eq!(chart.candles.len(), 2);
+    assert_eq!(
+        finance_query::Chart::from_csv(&csv).unwrap().candles.len(),
+        2
+    );
+    assert_eq!(Candle::from_dataframe(&df).unwrap()[1].close, 2.0);
+    std::fs::remove_file(csv).ok();
+    std::fs::remove_file(parquet).ok();
+}
+
+#[cfg(feature = "export")]
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_chart_export() {
+    use finance_query::export::DataFrameExport;
+    use finance_query::{Quote, Ticker, Tickers};
+
+    let chart = Ticker::new("AAPL")
+        .await
+        .unwrap()
+        .chart(Interval::OneDay, TimeRange::OneMonth)
+        .await
+        .unwrap();
+    let dir = std::env::temp_dir();
+    chart.to_csv(dir.join("doc_aapl_prices.csv")).unwrap();
+    chart
+        .to_parquet(dir.join("doc_aapl_prices.parquet"))
+        .unwrap();
+
+    let batch = Tickers::new(["AAPL", "MSFT"])
+        .await
+        .unwrap()
+        .quotes()
+        .await
+        .unwrap();
+    let quotes: Vec<Quote> = batch.quotes.into_values().collect();
+    Quote::vec_to_dataframe(&quotes)
+        .unwrap()
+        .write_csv(dir.join("doc_quotes.csv"))
+        .unwrap();
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — Multiple Symbols (dataframe.md "Multiple Symbols" section)
 // Doc passes DataFrame slice to concat(); adapted to lazy concat for Polars 0.53.
@@ -556,6 +673,144 @@ async fn test_single_item_to_dataframe() {
     assert!(df.width() >= 30);
 }
 
+// ---------------------------------------------------------------------------
+// Pure tests — Schema Introspection (dataframe.md "Schema Introspection")
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_dataframe_schema() {
+    use finance_query::{Candle, Chart, Quote, format::Both};
+    use polars::prelude::*;
+
+    // From dataframe.md "Schema Introspection" section
+    let schema: Schema = Quote::<Both>::dataframe_schema();
+    for (name, dtype) in schema.iter() {
+        println!("{name}: {dtype}");
+    }
+    assert_eq!(schema.get("symbol"), Some(&DataType::String));
+    assert_eq!(schema.get("regular_market_price"), Some(&DataType::Float64));
+
+    assert_eq!(Chart::dataframe_schema(), Candle::dataframe_schema());
+    assert_eq!(
+        Candle::dataframe_schema().get("volume"),
+        Some(&DataType::Int64)
+    );
+
+    // The schema matches the columns of an actual conversion
+    let schema = Candle::dataframe_schema();
+    let df = Candle::vec_to_dataframe(&[Candle::default()]).unwrap();
+    assert_eq!(df.width(), schema.len());
+    for (name, dtype) in schema.iter() {
+        assert_eq!(df.column(name).unwrap().dtype(), dtype, "column {name}");
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Pure tests — Column Types (dataframe.md "Column Types")
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_list_columns() {
+    use finance_query::SearchNews;
+    use polars::prelude::*;
+
+    // From dataframe.md "Column Types" section
+    let news: Vec<SearchNews> = serde_json::from_value(serde_json::json!([
+        {"title": "Apple earnings", "relatedTickers": ["AAPL", "MSFT"]},
+        {"title": "Market wrap"},
+    ]))
+    .unwrap();
+    let df = SearchNews::vec_to_dataframe(&news).unwrap();
+    let list_dtype = DataType::List(Box::new(DataType::String));
+    assert_eq!(
+        SearchNews::dataframe_schema().get("related_tickers"),
+        Some(&list_dtype)
+    );
+
+    let related: &ListChunked = df.column("related_tickers").unwrap().list().unwrap();
+    assert_eq!(related.dtype(), &list_dtype);
+    assert_eq!(related.get_as_series(0).unwrap().len(), 2);
+    assert!(related.get_as_series(1).is_none());
+
+    // All-null and empty inputs keep the declared dtype
+    let df = SearchNews::vec_to_dataframe(&news[1..]).unwrap();
+    assert_eq!(df.column("related_tickers").unwrap().dtype(), &list_dtype);
+    let df = SearchNews::vec_to_dataframe(&[]).unwrap();
+    assert_eq!(df.column("related_tickers").unwrap().dtype(), &list_dtype);
+    assert_eq!(df.width(), SearchNews::dataframe_schema().len());
+}
+
+#[test]
+fn test_date_columns() {
+    use chrono::{DateTime, NaiveDate, Utc};
+    use finance_query::ToDataFrame;
+    use polars::prelude::*;
+
+    #[derive(ToDataFrame)]
+    struct Filing {
+        form: String,
+        filed: NaiveDate,
+        period: Option<NaiveDate>,
+        accepted: DateTime<Utc>,
+        amended: Option<DateTime<Utc>>,
+        exhibits: Vec<String>,
+    }
+
+    let accepted = DateTime::<Utc>::from_timestamp(1_700_000_000, 0).unwrap();
+    let filings = [
+        Filing {
+            form: "10-K".into(),
+            filed: NaiveDate::from_ymd_opt(2023, 11, 3).unwrap(),
+            period: NaiveDate::from_ymd_opt(2023, 9, 30),
+            accepted,
+            amended: None,
+            exhibits: vec!["EX-21".into(), "EX-23".into()],
+        },
+        Filing {
+            form: "10-Q".into(),
+            filed: NaiveDate::from_ymd_opt(2024, 2, 2).unwrap(),
+            period: None,
+            accepted,
+            amended: Some(accepted),
+            exhibits: vec![],
+        },
+    ];
+
+    let schema = Filing::dataframe_schema();
+    assert_eq!(schema.get("filed"), Some(&DataType::Date));
+    assert_eq!(
+        schema.get("accepted"),
+        Some(&DataType::Datetime(TimeUnit::Milliseconds, None))
+    );
+
+    for df in [
+        Filing::vec_to_dataframe(&filings).unwrap(),
+        filings[0].to_dataframe().unwrap(),
+    ] {
+        let names = df.get_column_names_owned();
+        let names: Vec<&str> = names.iter().map(|n| n.as_str()).collect();
+        assert_eq!(
+            names,
+            ["form", "filed", "period", "accepted", "amended", "exhibits"]
+        );
+        for (name, dtype) in schema.iter() {
+            assert_eq!(df.column(name).unwrap().dtype(), dtype, "column {name}");
+        }
+    }
+
+    let df = Filing::vec_to_dataframe(&filings).unwrap();
+    let accepted_ms = df
+        .column("accepted")
+        .unwrap()
+        .cast(&DataType::Int64)
+        .unwrap();
+    assert_eq!(
+        accepted_ms.i64().unwrap().get(0),
+        Some(accepted.timestamp_millis())
+    );
+    assert_eq!(df.column("period").unwrap().null_count(), 1);
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — Error Handling (dataframe.md "Error Handling" section)
 // ---------------------------------------------------------------------------
@@ -622,3 +877,69 @@ async fn test_best_practices_cache_and_tail() {
     // No additional API calls - data is cached in the Ticker
     assert!(recent.height() <= 5);
 }
+
+#[test]
+fn test_derive_field_attributes() {
+    use finance_query::ToDataFrame;
+    use polars::prelude::*;
+
+    #[derive(ToDataFrame)]
+    struct Price {
+        close: f64,
+        volume: i64,
+    }
+
+    #[derive(ToDataFrame)]
+    #[allow(dead_code)]
+    struct Position {
+        #[dataframe(rename = "ticker")]
+        symbol: String,
+        #[dataframe(skip)]
+        broker_id: String,
+        shares: f64,
+        #[dataframe(flatten, rename = "last")]
+        price: Option<Price>,
+        tags: Vec<String>,
+    }
+
+    let positions = [
+        Position {
+            symbol: "AAPL".into(),
+            broker_id: "x1".into(),
+            shares: 10.0,
+            price: Some(Price {
+                close: 190.5,
+                volume: 1_000,
+            }),
+            tags: vec!["tech".into()],
+        },
+        Position {
+            symbol: "MSFT".into(),
+            broker_id: "x2".into(),
+            shares: 5.0,
+            price: None,
+            tags: vec![],
+        },
+    ];
+
+    let schema = Position::dataframe_schema();
+    for df in [
+        Position::vec_to_dataframe(&positions).unwrap(),
+        positions[0].to_dataframe().unwrap(),
+    ] {
+        let names = df.get_column_names_owned();
+        let names: Vec<&str> = names.iter().map(|n| n.as_str()).collect();
+        assert_eq!(
+            names,
+            ["ticker", "shares", "last_close", "last_volume", "tags"]
+        );
+        for (name, dtype) in schema.iter() {
+            assert_eq!(df.column(name).unwrap().dtype(), dtype, "column {name}");
+        }
+    }
+
+    let df = Position::vec_to_dataframe(&positions).unwrap();
+    let close = df.column("last_close").unwrap().f64().unwrap();
+    assert_eq!(close.get(0), Some(190.5));
+    assert_eq!(close.get(1), None);
+}
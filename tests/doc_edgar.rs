@@ -1,5 +1,7 @@
 //! Compile and runtime tests for docs/library/edgar.md
 //!
+//! Requires the `edgar` feature flag (on by default).
+//!
 //! Run compile tests:
 //!   cargo test --test doc_edgar
 //! Run network tests:
@@ -13,6 +15,8 @@
 //!   - Complete example's `hits.total.value` skips two Option layers;
 //!     corrected to use Option chaining.
 
+#![cfg(feature = "edgar")]
+
 use std::time::Duration;
 
 // ---------------------------------------------------------------------------
@@ -36,6 +36,10 @@ fn _verify_error_variants() {
         status: 500,
         context: String::new(),
     };
+    let _ = FinanceError::ResponseTooLarge {
+        limit: 1024,
+        context: String::new(),
+    };
     let _ = FinanceError::UnexpectedResponse(String::new());
     let _ = FinanceError::InternalError(String::new());
     let _ = FinanceError::ApiError(String::new());
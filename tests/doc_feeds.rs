@@ -1,9 +1,11 @@
 //! Compile and runtime tests for docs/library/feeds.md
 //!
-//! `feeds` has no feature gate (always compiled):
+//! Requires the `feeds` feature flag (on by default):
 //!   cargo test --test doc_feeds
 //!   cargo test --test doc_feeds -- --ignored   (network tests)
 
+#![cfg(feature = "feeds")]
+
 use finance_query::feeds::{FeedEntry, FeedSource};
 
 // ---------------------------------------------------------------------------
@@ -44,6 +46,7 @@ fn test_feed_source_urls_are_non_empty() {
         FeedSource::WsjMarkets,
         FeedSource::Fortune,
         FeedSource::BusinessWire,
+        FeedSource::PrNewswire,
         FeedSource::CoinDesk,
         FeedSource::CoinTelegraph,
         FeedSource::TechCrunch,
@@ -3,6 +3,7 @@
 //! Run: cargo test --test doc_filings
 //! Network tests: cargo test --test doc_filings -- --ignored
 
+#[cfg(feature = "edgar")]
 #[tokio::test]
 #[ignore = "requires network access"]
 async fn test_filings_get() {
@@ -6,7 +6,12 @@
 //! Run with: `cargo test --test doc_finance`
 //! Run network tests: `cargo test --test doc_finance -- --ignored`
 
-use finance_query::{FearAndGreed, FearGreedLabel, Sector};
+use finance_query::{
+    CorporateAction, CorporateActionKind, CorporateActions, EarningsCalendarEntry, EconomicEvent,
+    EconomicEventCategory, EodSnapshot, EodSymbol, FearAndGreed, FearGreedLabel, FundFlowPoint,
+    FundFlows, GapBasis, Gapper, GlobalSnapshot, IpoCalendarEntry, MarketSummaryHistory,
+    MarketSummarySeries, Region, RegionSnapshot, Sector, SplitCalendarEntry,
+};
 
 // ---------------------------------------------------------------------------
 // FearAndGreed — compile-time field verification
@@ -20,6 +25,176 @@ fn _verify_fear_and_greed_fields(fg: FearAndGreed) {
     let _: i64 = fg.timestamp;
 }
 
+/// Verifies MarketSummaryHistory fields documented in finance.md.
+#[allow(dead_code)]
+fn _verify_market_summary_history_fields(history: MarketSummaryHistory) {
+    let _: &Vec<i64> = &history.dates;
+    let _: &std::collections::HashMap<String, String> = &history.errors;
+    let _: Option<&MarketSummarySeries> = history.get("^GSPC");
+    for series in &history.series {
+        let _: &str = &series.symbol;
+        let _: &Option<String> = &series.short_name;
+        let _: &Vec<Option<f64>> = &series.closes;
+        let _: &Vec<Option<f64>> = &series.normalized;
+        let _: Option<f64> = series.change_percent;
+    }
+}
+
+/// Verifies FundFlows fields and methods documented in finance.md.
+#[allow(dead_code)]
+fn _verify_fund_flows_fields(flows: FundFlows) {
+    let _: f64 = flows.net_flow() + flows.inflows() + flows.outflows();
+    let _: Option<f64> = flows.net_flow_percent();
+    let _: &str = &flows.symbol;
+    for point in &flows.points {
+        let _: &FundFlowPoint = point;
+        let _: i64 = point.timestamp;
+        let _: i64 = point.shares_outstanding;
+        let _: Option<i64> = point.shares_change;
+        let _: Option<f64> = point.price;
+        let _: Option<f64> = point.net_assets;
+        let _: Option<f64> = point.flow;
+    }
+}
+
+/// Verifies Gapper fields and methods documented in finance.md.
+#[allow(dead_code)]
+fn _verify_gapper_fields(g: Gapper) {
+    let _: &str = &g.symbol;
+    let _: &Option<String> = &g.name;
+    let _: f64 = g.price + g.previous_close + g.gap_percent;
+    let _: Option<i64> = g.premarket_volume;
+    let _: u32 = g.recent_news;
+    let _: bool = g.has_news() && g.is_gap_up();
+    match g.basis {
+        GapBasis::PreMarket | GapBasis::Open => {}
+        _ => {}
+    }
+}
+
+/// Verifies GlobalSnapshot fields and methods documented in finance.md.
+#[allow(dead_code)]
+fn _verify_global_snapshot_fields(world: GlobalSnapshot) {
+    let _: &std::collections::HashMap<String, RegionSnapshot> = &world.regions;
+    let _: &std::collections::HashMap<String, String> = &world.errors;
+    if let Some(region) = world.get(Region::Japan) {
+        let _: &str = &region.market_summary[0].symbol;
+        let _: &str = &region.trending[0].symbol;
+    }
+}
+
+/// Verifies EodSnapshot fields and methods documented in finance.md.
+#[allow(dead_code)]
+fn _verify_eod_snapshot_fields(snapshot: EodSnapshot) {
+    let _: &str = &snapshot.session_date;
+    let _: i64 = snapshot.generated_at;
+    let _: Option<&str> = snapshot.market_status.as_deref();
+    let _: &std::collections::BTreeMap<String, EodSymbol> = &snapshot.symbols;
+    let _: &std::collections::HashMap<String, String> = &snapshot.errors;
+    let _: bool = snapshot.is_final();
+    let _: String = snapshot.file_name();
+    if let Some(entry) = snapshot.symbols.get("AAPL") {
+        let _: Option<&finance_query::Quote> = entry.quote.as_ref();
+        let _: Option<f64> = entry.candle.as_ref().map(|c| c.close);
+    }
+}
+
+/// Verifies EarningsCalendarEntry fields documented in finance.md.
+#[allow(dead_code)]
+fn _verify_earnings_calendar_fields(entry: EarningsCalendarEntry) {
+    let _: &str = &entry.symbol;
+    let _: Option<String> = entry.name;
+    let _: Option<String> = entry.event_name;
+    let _: Option<String> = entry.report_date;
+    let _: Option<i64> = entry.timestamp;
+    let _: Option<String> = entry.call_time;
+    let _: Option<String> = entry.fiscal_date_ending;
+    let _: Option<f64> = entry.estimate;
+    let _: Option<f64> = entry.eps_actual;
+    let _: Option<f64> = entry.surprise_percent;
+    let _: Option<f64> = entry.market_cap;
+    let _: Option<String> = entry.currency;
+}
+
+/// Verifies IpoCalendarEntry fields documented in finance.md.
+#[allow(dead_code)]
+fn _verify_ipo_calendar_fields(entry: IpoCalendarEntry) {
+    let _: &str = &entry.symbol;
+    let _: Option<String> = entry.name;
+    let _: Option<String> = entry.exchange;
+    let _: Option<String> = entry.filing_date;
+    let _: Option<String> = entry.ipo_date;
+    let _: Option<String> = entry.amended_date;
+    let _: Option<f64> = entry.price_from;
+    let _: Option<f64> = entry.price_to;
+    let _: Option<f64> = entry.offer_price;
+    let _: Option<String> = entry.currency;
+    let _: Option<i64> = entry.shares;
+    let _: Option<String> = entry.deal_type;
+}
+
+/// Verifies SplitCalendarEntry fields documented in finance.md.
+#[allow(dead_code)]
+fn _verify_splits_calendar_fields(entry: SplitCalendarEntry) {
+    let _: Option<f64> = entry.ratio();
+    let _: &str = &entry.symbol;
+    let _: Option<String> = entry.name;
+    let _: Option<String> = entry.split_date;
+    let _: Option<i64> = entry.timestamp;
+    let _: Option<bool> = entry.optionable;
+    let _: Option<f64> = entry.old_shares;
+    let _: Option<f64> = entry.new_shares;
+}
+
+/// Verifies EconomicEvent fields documented in finance.md.
+#[allow(dead_code)]
+fn _verify_economic_calendar_fields(event: EconomicEvent) {
+    let _: Option<f64> = event.surprise();
+    let _: bool = event.prior_revised();
+    let _: &str = &event.event;
+    let _: EconomicEventCategory = event.category;
+    let _: Option<String> = event.country;
+    let _: Option<String> = event.release_date;
+    let _: Option<i64> = event.timestamp;
+    let _: Option<String> = event.period;
+    let _: Option<f64> = event.actual;
+    let _: Option<f64> = event.consensus;
+    let _: Option<f64> = event.prior;
+    let _: Option<f64> = event.originally_reported;
+}
+
+/// Verifies CorporateActions fields and methods documented in finance.md.
+#[allow(dead_code)]
+fn _verify_corporate_actions_fields(feed: CorporateActions) {
+    let _: &std::collections::HashMap<String, String> = &feed.errors;
+    for actions in feed.actions.values() {
+        let _: &Vec<CorporateAction> = actions;
+        for action in actions {
+            let _: i64 = action.timestamp;
+            let _: bool = action.affects_position();
+            match &action.kind {
+                CorporateActionKind::Dividend { amount }
+                | CorporateActionKind::CapitalGain { amount } => {
+                    let _: f64 = *amount;
+                }
+                CorporateActionKind::Split {
+                    numerator,
+                    denominator,
+                    ratio,
+                } => {
+                    let _: f64 = numerator / denominator;
+                    let _: &str = ratio;
+                }
+                CorporateActionKind::SymbolChange { symbol } => {
+                    let _: &str = symbol;
+                }
+                CorporateActionKind::Delisted => {}
+                _ => {}
+            }
+        }
+    }
+}
+
 #[test]
 fn test_fear_greed_label_variants_and_as_str() {
     // All variants documented in finance.md must exist and have non-empty as_str().
@@ -83,6 +258,26 @@ async fn test_sector_technology() {
     assert!(!tech.name.is_empty());
 }
 
+#[test]
+fn test_industry_discovery() {
+    use finance_query::{Industry, finance};
+
+    // From finance.md "Discovering Industry Keys" section
+    let grouped = finance::industries();
+    assert_eq!(grouped.len(), Sector::all().len());
+    let total: usize = grouped.iter().map(|(_, industries)| industries.len()).sum();
+    assert_eq!(total, Industry::ALL.len());
+
+    let hits = finance::industry_lookup("semiconductor");
+    assert_eq!(hits[0], Industry::Semiconductors);
+    assert!(Sector::Technology.industries().contains(&hits[0]));
+    assert_eq!(Industry::Semiconductors.sector(), Sector::Technology);
+    assert_eq!(
+        "Oil & Gas E&P".parse::<Industry>(),
+        Ok(Industry::OilAndGasEAndP)
+    );
+}
+
 #[tokio::test]
 #[ignore = "requires network access"]
 async fn test_industry_semiconductors() {
@@ -105,6 +300,182 @@ async fn test_search() {
     assert!(results.result_count() > 0);
 }
 
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_market_summary_history() {
+    use finance_query::{TimeRange, finance};
+
+    let history = finance::market_summary_history(TimeRange::OneMonth)
+        .await
+        .unwrap();
+    assert!(!history.dates.is_empty());
+    for series in &history.series {
+        assert_eq!(series.closes.len(), history.dates.len());
+        assert_eq!(series.normalized.len(), history.dates.len());
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_etf_flows_proxy() {
+    use finance_query::{TimeRange, finance};
+
+    let flows = finance::etf_flows_proxy("SPY", TimeRange::ThreeMonths)
+        .await
+        .unwrap();
+    assert_eq!(flows.symbol, "SPY");
+    println!("SPY net flow: {:.0}", flows.net_flow());
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_gap_scan() {
+    use finance_query::finance;
+
+    let gappers = finance::gap_scan(["AAPL", "NVDA", "TSLA", "AMD", "META"], 0.0)
+        .await
+        .unwrap();
+    for pair in gappers.windows(2) {
+        assert!(pair[0].gap_percent.abs() >= pair[1].gap_percent.abs());
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_corporate_actions() {
+    use finance_query::{TimeRange, finance};
+
+    let feed = finance::corporate_actions(["AAPL", "NVDA"], TimeRange::FiveYears)
+        .await
+        .unwrap();
+    let nvda = &feed.actions["NVDA"];
+    assert!(
+        nvda.iter()
+            .any(|a| matches!(a.kind, CorporateActionKind::Split { .. }))
+    );
+    for actions in feed.actions.values() {
+        assert!(actions.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+    }
+}
+
+#[tokio::test]
+async fn test_calendars_reject_reversed_window() {
+    use chrono::NaiveDate;
+    use finance_query::finance;
+
+    let start = NaiveDate::from_ymd_opt(2026, 10, 23).unwrap();
+    let end = NaiveDate::from_ymd_opt(2026, 10, 19).unwrap();
+    assert!(finance::earnings_calendar(start, end).await.is_err());
+    assert!(finance::ipo_calendar(start, end).await.is_err());
+    assert!(finance::splits_calendar(start, end).await.is_err());
+    assert!(finance::economic_calendar(None, start, end).await.is_err());
+}
+
+#[tokio::test]
+async fn test_eod_snapshot_rejects_empty_symbols() {
+    use finance_query::finance;
+
+    let dir = std::env::temp_dir().join("fq-doc-eod-empty");
+    assert!(
+        finance::eod_snapshot(Vec::<String>::new(), &dir)
+            .await
+            .is_err()
+    );
+    assert!(!dir.exists());
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_eod_snapshot() {
+    use finance_query::finance;
+
+    let dir = std::env::temp_dir().join(format!("fq-doc-eod-{}", std::process::id()));
+    let snapshot = finance::eod_snapshot(["AAPL", "MSFT", "INVALIDXYZ123"], &dir)
+        .await
+        .unwrap();
+    assert!(snapshot.symbols.contains_key("AAPL"));
+    assert!(snapshot.errors.contains_key("INVALIDXYZ123"));
+
+    let saved = EodSnapshot::read(dir.join(snapshot.file_name())).unwrap();
+    assert_eq!(saved.session_date, snapshot.session_date);
+    assert_eq!(saved.symbols.len(), snapshot.symbols.len());
+    assert!(saved.symbols["AAPL"].candle.is_some());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_economic_event_category() {
+    let classify = EconomicEventCategory::classify;
+    assert_eq!(classify("CPI MM"), EconomicEventCategory::Inflation);
+    assert_eq!(
+        classify("FOMC Rate Decision"),
+        EconomicEventCategory::CentralBank
+    );
+    assert_eq!(
+        classify("Non-Farm Payrolls"),
+        EconomicEventCategory::Employment
+    );
+    assert_eq!(classify("GDP Final"), EconomicEventCategory::Growth);
+    assert_eq!(
+        classify("Baker Hughes Rig Count"),
+        EconomicEventCategory::Other
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_earnings_calendar() {
+    use chrono::{Days, Utc};
+    use finance_query::finance;
+
+    let start = Utc::now().date_naive();
+    let end = start + Days::new(13);
+    let reports = finance::earnings_calendar(start, end).await.unwrap();
+    let (first, last) = (start.to_string(), end.to_string());
+    for entry in &reports {
+        assert!(!entry.symbol.is_empty());
+        if let Some(date) = entry.report_date.as_deref() {
+            assert!(date >= first.as_str() && date <= last.as_str(), "{date}");
+        }
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_ipo_and_splits_calendar() {
+    use chrono::{Days, Utc};
+    use finance_query::finance;
+
+    let start = Utc::now().date_naive();
+    let end = start + Days::new(30);
+    let ipos = finance::ipo_calendar(start, end).await.unwrap();
+    assert!(ipos.iter().all(|ipo| !ipo.symbol.is_empty()));
+
+    let (first, last) = (start.to_string(), end.to_string());
+    for split in finance::splits_calendar(start, end).await.unwrap() {
+        if let Some(date) = split.split_date.as_deref() {
+            assert!(date >= first.as_str() && date <= last.as_str(), "{date}");
+        }
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_economic_calendar() {
+    use chrono::{Days, Utc};
+    use finance_query::finance;
+
+    let start = Utc::now().date_naive();
+    let events =
+        finance::economic_calendar(Some(Region::UnitedStates), start, start + Days::new(6))
+            .await
+            .unwrap();
+    for event in &events {
+        assert!(!event.event.is_empty());
+        assert_eq!(event.country.as_deref(), Some("US"));
+    }
+}
+
 #[tokio::test]
 #[ignore = "requires network access"]
 async fn test_market_summary() {
@@ -190,6 +561,42 @@ async fn test_lookup() {
     }
 }
 
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_search_trading_status() {
+    use finance_query::{LookupOptions, SearchOptions, TradingStatus, finance};
+
+    // From finance.md "Trading Status" section
+    let options = SearchOptions::new()
+        .quotes_count(10)
+        .trading_status_count(5);
+    let results = finance::search("twitter", &options).await.unwrap();
+
+    for quote in &results.quotes {
+        match quote.trading_status {
+            Some(TradingStatus::Delisted) => println!("{} (delisted)", quote.symbol),
+            Some(_) => println!("{}", quote.symbol),
+            None => println!("{} (not checked)", quote.symbol),
+        }
+    }
+    assert!(
+        results
+            .quotes
+            .iter()
+            .skip(5)
+            .all(|q| q.trading_status.is_none())
+    );
+
+    let options = LookupOptions::new().count(5).trading_status_count(5);
+    let results = finance::lookup("AAPL", &options).await.unwrap();
+    assert!(
+        results
+            .quotes
+            .iter()
+            .any(|q| q.trading_status == Some(TradingStatus::Active))
+    );
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — Market Summary region (from finance.md "Market Summary" section)
 // ---------------------------------------------------------------------------
@@ -229,6 +636,26 @@ async fn test_trending_region() {
     assert!(!trending.is_empty());
 }
 
+// ---------------------------------------------------------------------------
+// Network tests — Global Snapshot (from finance.md "Global Snapshot" section)
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_global_snapshot() {
+    use finance_query::finance;
+
+    let world = finance::global_snapshot(&[Region::UnitedStates, Region::Japan, Region::Japan])
+        .await
+        .unwrap();
+    assert!(world.regions.len() <= 2);
+    let us = world.get(Region::UnitedStates).unwrap();
+    assert!(!us.market_summary.is_empty());
+    for (code, error) in &world.errors {
+        eprintln!("{code}: {error}");
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — Market Hours (from finance.md "Market Hours" section)
 // ---------------------------------------------------------------------------
@@ -252,6 +679,30 @@ async fn test_market_hours() {
     }
 }
 
+#[tokio::test]
+async fn test_market_hours_for_asset_class() {
+    use finance_query::streaming::AssetClass;
+    use finance_query::{MarketTime, finance};
+
+    // From finance.md "Market Hours" section — no network for these classes
+    let crypto = finance::hours_for(AssetClass::Crypto, None).await.unwrap();
+    assert!(crypto.markets[0].is_open());
+
+    let fx = finance::hours_for(AssetClass::Fx, None).await.unwrap();
+    for market in &fx.markets {
+        println!(
+            "{} open: {} (next close {:?})",
+            market.name,
+            market.is_open(),
+            market.close
+        );
+        assert!(market.close.is_some());
+    }
+
+    let futures = MarketTime::for_asset_class(AssetClass::Future, chrono::Utc::now());
+    assert!(futures.is_some());
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — Indices (from finance.md "Indices" section)
 // ---------------------------------------------------------------------------
@@ -482,6 +933,38 @@ async fn test_earnings_transcripts() {
     }
 }
 
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_transcripts_by_date() {
+    use chrono::NaiveDate;
+    use finance_query::finance;
+
+    // From finance.md "Transcripts by Date" section
+
+    // Latest call held on or before a date
+    let date = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    let result = finance::transcript_on_or_before("AAPL", date)
+        .await
+        .unwrap();
+    println!(
+        "Matched {} {} (held {:?})",
+        result.matched.quarter, result.matched.year, result.call_date
+    );
+    assert!(result.call_date.is_none_or(|d| d <= date));
+
+    // Nearest fiscal period when the exact one isn't available
+    let result = finance::earnings_transcript_nearest("AAPL", "Q4", 2024)
+        .await
+        .unwrap();
+    if !result.exact {
+        println!(
+            "No Q4 2024 call; got {} {}",
+            result.matched.quarter, result.matched.year
+        );
+    }
+    let _text = result.transcript.text();
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — Exchanges (from finance.md "Exchanges" section)
 // ---------------------------------------------------------------------------
@@ -521,6 +1004,63 @@ async fn test_currencies() {
     assert!(!currencies.is_empty());
 }
 
+// ---------------------------------------------------------------------------
+// Currency Conversion (from finance.md "Currency Conversion" section)
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn test_currency_converter_pinned_rates() {
+    use finance_query::fx::CurrencyConverter;
+
+    // From finance.md "Currency Conversion" section
+    let fx = CurrencyConverter::new();
+    fx.set_rate("EUR", "USD", 1.10).unwrap();
+
+    let usd = fx.convert(100.0, "EUR", "USD").await.unwrap();
+    assert!((usd - 110.0).abs() < 1e-9);
+    let eur = fx.convert(110.0, "USD", "EUR").await.unwrap();
+    assert!((eur - 100.0).abs() < 1e-9);
+    assert!(fx.rate("USD", "not a code").await.is_err());
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_fx_rate_and_converter() {
+    use finance_query::fx::CurrencyConverter;
+    use finance_query::{Interval, Ticker, TimeRange, finance};
+
+    // From finance.md "Currency Conversion" section
+    let rate = finance::fx_rate("USD", "JPY").await.unwrap();
+    println!("1 USD = {:.2} JPY", rate);
+    assert!(rate > 1.0);
+
+    let fx = CurrencyConverter::new();
+    let mut total = 0.0;
+    for (symbol, shares) in [("7203.T", 500.0), ("AAPL", 20.0)] {
+        let chart = Ticker::new(symbol)
+            .await
+            .unwrap()
+            .chart(Interval::OneDay, TimeRange::OneDay)
+            .await
+            .unwrap();
+        let price = chart.meta.regular_market_price.unwrap_or_default();
+        let currency = chart.meta.currency.as_deref().unwrap_or("USD");
+        total += fx.convert(price * shares, currency, "USD").await.unwrap();
+    }
+    println!("Portfolio value: ${:.2}", total);
+    assert!(total > 0.0);
+
+    let toyota = Ticker::new("7203.T")
+        .await
+        .unwrap()
+        .chart(Interval::OneDay, TimeRange::OneMonth)
+        .await
+        .unwrap();
+    let in_usd = fx.convert_chart(&toyota, "USD").await.unwrap();
+    assert_eq!(in_usd.meta.currency.as_deref(), Some("USD"));
+    assert_eq!(in_usd.candles.len(), toyota.candles.len());
+}
+
 // ---------------------------------------------------------------------------
 // Sentiment — from finance.md "News & Transcript Sentiment" section
 // (offline VADER, no network; gated on the `sentiment` feature)
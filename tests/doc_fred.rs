@@ -73,6 +73,17 @@ fn test_fred_init_with_timeout_compiles() {
     let _ = fred::init_with_timeout("your-fred-api-key", Duration::from_secs(60));
 }
 
+#[tokio::test]
+async fn test_fred_series_range_rejects_reversed_window() {
+    use finance_query::{FinanceError, fred};
+
+    // From fred.md "Fetching FRED Series" — end before start is rejected up front
+    let start = chrono::NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    let end = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    let err = fred::series_range("DGS10", start, end).await.unwrap_err();
+    assert!(matches!(err, FinanceError::InvalidParameter { .. }));
+}
+
 // ---------------------------------------------------------------------------
 // Network tests
 // ---------------------------------------------------------------------------
@@ -171,3 +182,37 @@ async fn test_yield_curve_inversion_check() {
     }
     println!("Inverted days in last 5: {inverted_count}");
 }
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_fred_series_range_aligned_to_candles() {
+    use finance_query::{Interval, Ticker, TimeRange, fred};
+
+    // From fred.md "Joining With Price Data"
+    let Ok(key) = std::env::var("FRED_API_KEY") else {
+        println!("FRED_API_KEY not set; skipping");
+        return;
+    };
+    let _ = fred::init(key);
+
+    let chart = Ticker::new("SPY")
+        .await
+        .unwrap()
+        .chart(Interval::OneDay, TimeRange::OneYear)
+        .await
+        .unwrap();
+    let start = chrono::Utc::now().date_naive() - chrono::Duration::days(400);
+    let end = chrono::Utc::now().date_naive();
+    let dgs10 = fred::series_range("DGS10", start, end).await.unwrap();
+    assert!(
+        dgs10
+            .observations
+            .iter()
+            .all(|o| o.date.as_str() >= start.to_string().as_str())
+    );
+
+    let timestamps: Vec<i64> = chart.candles.iter().map(|c| c.timestamp).collect();
+    let aligned = dgs10.align_to(&timestamps);
+    assert_eq!(aligned.len(), chart.candles.len());
+    assert!(aligned.iter().flatten().count() > aligned.len() / 2);
+}
@@ -159,6 +159,7 @@ async fn test_backtesting() {
 
 /// Verifies PriceUpdate fields used in the getting-started.md streaming example.
 /// Mirrors: `price.id`, `price.price`, `price.change_percent`
+#[cfg(feature = "streaming")]
 #[allow(dead_code)]
 fn _verify_price_update_fields(price: finance_query::streaming::PriceUpdate) {
     // From getting-started.md streaming code block
@@ -172,6 +173,7 @@ fn _verify_price_update_fields(price: finance_query::streaming::PriceUpdate) {
 // (connect only; don't consume the stream in tests to avoid hanging)
 // ---------------------------------------------------------------------------
 
+#[cfg(feature = "streaming")]
 #[tokio::test]
 #[ignore = "requires network access"]
 async fn test_streaming_connects() {
@@ -190,6 +192,7 @@ async fn test_streaming_connects() {
 // Network tests — SEC EDGAR Filings from getting-started.md
 // ---------------------------------------------------------------------------
 
+#[cfg(feature = "edgar")]
 #[tokio::test]
 #[ignore = "requires network access"]
 async fn test_edgar_filings() {
@@ -257,3 +260,77 @@ async fn test_risk_analytics() {
     println!("Max Drawdown: {:.2}%", summary.max_drawdown * 100.0);
     println!("Beta vs SPY:  {:.2}", summary.beta.unwrap_or(0.0));
 }
+
+// ---------------------------------------------------------------------------
+// Command API from getting-started.md
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_command_api_requests() {
+    use finance_query::api::Request;
+
+    for json in [
+        r#"{"op": "quote", "symbol": "AAPL"}"#,
+        r#"{"op": "chart", "symbol": "AAPL", "interval": "1d", "range": "1mo"}"#,
+        r#"{"op": "search", "query": "apple", "quotesCount": 5, "newsCount": 0}"#,
+        r#"{"op": "screener", "screener": "day-gainers"}"#,
+    ] {
+        let request: Request = serde_json::from_str(json).unwrap();
+        println!("{request:?}");
+    }
+
+    #[cfg(feature = "indicators")]
+    serde_json::from_str::<Request>(
+        r#"{"op": "indicators", "symbol": "AAPL", "interval": "1d", "range": "1y"}"#,
+    )
+    .unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_command_api_execute() {
+    use finance_query::api::{self, Request, Response};
+
+    let request: Request = serde_json::from_str(
+        r#"{"op": "chart", "symbol": "AAPL", "interval": "1d", "range": "1mo"}"#,
+    )
+    .unwrap();
+    let response: Response = api::execute(request).await.unwrap();
+
+    let json = serde_json::to_value(&response).unwrap();
+    assert_eq!(json["type"], "chart");
+    match response {
+        Response::Chart(chart) => assert!(!chart.candles.is_empty()),
+        other => panic!("unexpected response {other:?}"),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Synchronous API from getting-started.md
+// ---------------------------------------------------------------------------
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_block_on() {
+    assert_eq!(finance_query::blocking::block_on(async { 40 + 2 }), 42);
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+#[ignore = "requires network access"]
+fn test_blocking_api() {
+    use finance_query::blocking::{self, Ticker};
+    use finance_query::{Interval, Screener, TimeRange};
+
+    let ticker = Ticker::new("AAPL").unwrap();
+    let quote: finance_query::Quote = ticker.quote().unwrap();
+    let chart = ticker.chart(Interval::OneDay, TimeRange::OneMonth).unwrap();
+    assert_eq!(quote.symbol, "AAPL");
+    assert!(!chart.candles.is_empty());
+
+    let gainers = blocking::finance::screener(Screener::DayGainers, 10).unwrap();
+    assert!(gainers.quotes.len() <= 10);
+
+    let profile = blocking::block_on(ticker.as_async().asset_profile()).unwrap();
+    println!("{profile:?}");
+}
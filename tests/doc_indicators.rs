@@ -45,6 +45,31 @@ fn test_pattern_sentiment_variants_compile() {
     let _ = PatternSentiment::Bearish;
 }
 
+#[test]
+fn test_pattern_matches() {
+    use finance_query::Candle;
+    use finance_query::indicators::{CandlePattern, PatternSentiment, pattern_matches, patterns};
+
+    // From indicators.md "Pattern Matches" section — a lone doji
+    let candle: Candle = serde_json::from_value(serde_json::json!({
+        "timestamp": 1_700_000_000,
+        "open": 10.0,
+        "high": 12.0,
+        "low": 8.0,
+        "close": 10.1,
+        "volume": 0
+    }))
+    .unwrap();
+    let candles = vec![candle];
+
+    let matches = pattern_matches(&candles);
+    assert_eq!(matches.len(), patterns(&candles).iter().flatten().count());
+    assert_eq!(matches[0].index, 0);
+    assert_eq!(matches[0].timestamp, 1_700_000_000);
+    assert_eq!(matches[0].pattern, CandlePattern::Doji);
+    assert_eq!(matches[0].sentiment, PatternSentiment::Neutral);
+}
+
 // ---------------------------------------------------------------------------
 // Compile-time — IndicatorsSummary compound data type field access
 // ---------------------------------------------------------------------------
@@ -93,6 +118,70 @@ fn _verify_indicators_summary_fields(s: finance_query::IndicatorsSummary) {
     let _: Option<f64> = s.balance_of_power;
 }
 
+// ---------------------------------------------------------------------------
+// Runtime — Custom Indicators from indicators.md
+// ---------------------------------------------------------------------------
+
+fn register_pct_off_high() {
+    use finance_query::indicators::{self, CustomIndicator};
+
+    // Tests share the process-wide registry; ignore "already registered"
+    let _ = indicators::register_indicator(
+        CustomIndicator::new("pct_off_high", |ohlcv, params| {
+            let period = params.first().copied().unwrap_or(20.0) as usize;
+            let highs = indicators::donchian_channels(ohlcv.high, ohlcv.low, period)?.upper;
+            Ok(highs
+                .into_iter()
+                .zip(ohlcv.close)
+                .map(|(h, c)| h.map(|h| (c / h - 1.0) * 100.0))
+                .collect())
+        })
+        .with_label("Percent Off High")
+        .with_default_params(&[20.0])
+        .with_warmup(|params| params.first().copied().unwrap_or(20.0) as usize),
+    );
+}
+
+#[test]
+fn test_custom_indicator_registration() {
+    use finance_query::indicators::{Indicator, custom_indicator};
+
+    register_pct_off_high();
+    let ind = custom_indicator("pct_off_high").unwrap();
+    assert_eq!(ind.default_params(), &[20.0]);
+
+    let indicator = Indicator::custom("pct_off_high", &[50.0]).unwrap();
+    assert_eq!(indicator.name(), "Percent Off High");
+    assert_eq!(indicator.warmup_bars(), 50);
+    assert!(Indicator::custom("not_registered", &[]).is_err());
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_custom_indicator_via_ticker() {
+    use finance_query::indicators::{Indicator, IndicatorResult, last_value};
+    use finance_query::{Interval, Ticker, TimeRange};
+
+    register_pct_off_high();
+    let ticker = Ticker::new("AAPL").await.unwrap();
+
+    let indicator = Indicator::custom("pct_off_high", &[50.0]).unwrap();
+    let result = ticker
+        .indicator(indicator, Interval::OneDay, TimeRange::SixMonths)
+        .await
+        .unwrap();
+    let IndicatorResult::Series(values) = result else {
+        panic!("custom indicators return a single series");
+    };
+    assert!(last_value(&values).is_some_and(|v| v <= 0.0));
+
+    let summary = ticker
+        .indicators(Interval::OneDay, TimeRange::SixMonths)
+        .await
+        .unwrap();
+    assert!(summary.custom.contains_key("pct_off_high"));
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — Getting Started / Summary API from indicators.md
 // ---------------------------------------------------------------------------
@@ -114,6 +203,99 @@ async fn test_indicators_getting_started() {
     println!("MACD: {:?}", indicators.macd);
 }
 
+#[test]
+fn test_indicator_selection_parsing() {
+    use finance_query::indicators::{IndicatorKind, IndicatorSelection};
+
+    // From indicators.md "Summary API" section
+    let selection = IndicatorSelection::builder()
+        .period(IndicatorKind::Rsi, 21)
+        .period(IndicatorKind::Sma, 50)
+        .build()
+        .unwrap();
+    let parsed: IndicatorSelection = "rsi:21,sma:50".parse().unwrap();
+    assert_eq!(parsed, selection);
+    assert_eq!(selection.summary_fields(), vec!["sma50", "custom"]);
+}
+
+#[test]
+fn test_incremental_calculators() {
+    use finance_query::indicators::{RollingEma, RollingRsi, RollingSma, ema, rsi, sma};
+
+    // From indicators.md "Incremental Calculators" section
+    let closes: Vec<f64> = (0..40).map(|i| 100.0 + (i % 7) as f64).collect();
+    let mut r_sma = RollingSma::new(20).unwrap();
+    let mut r_ema = RollingEma::new(12).unwrap();
+    let mut r_rsi = RollingRsi::new(14).unwrap();
+
+    let rolled: Vec<_> = closes
+        .iter()
+        .map(|&c| (r_sma.update(c), r_ema.update(c), r_rsi.update(c)))
+        .collect();
+
+    let last = rolled.last().unwrap();
+    assert_eq!(last.0, *sma(&closes, 20).last().unwrap());
+    assert_eq!(last.1, *ema(&closes, 12).last().unwrap());
+    assert_eq!(last.2, *rsi(&closes, 14).unwrap().last().unwrap());
+    assert_eq!(rolled[13].2, None);
+    assert_eq!(r_rsi.value(), last.2);
+    assert!(RollingSma::new(0).is_err());
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_indicators_series() {
+    use finance_query::{Interval, Ticker, TimeRange};
+
+    // From indicators.md "Summary API" section — full series
+    let ticker = Ticker::new("AAPL").await.unwrap();
+    let history = ticker
+        .indicators_series(Interval::OneDay, TimeRange::OneYear)
+        .await
+        .unwrap();
+
+    for (ts, rsi) in history
+        .timestamps
+        .iter()
+        .zip(history.get("rsi_14").unwrap_or_default())
+    {
+        println!("{ts}: {rsi:?}");
+    }
+    assert!(history.series.values().all(|s| s.len() == history.len()));
+    assert!(history.get("macd_signal").is_some());
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_indicators_selected() {
+    use finance_query::indicators::{IndicatorKind, IndicatorSelection};
+    use finance_query::{Interval, Ticker, TimeRange};
+
+    let ticker = Ticker::new("AAPL").await.unwrap();
+    let subset = ticker
+        .indicators_selected(
+            &[IndicatorKind::Rsi, IndicatorKind::Macd],
+            Interval::OneDay,
+            TimeRange::ThreeMonths,
+        )
+        .await
+        .unwrap();
+    assert!(subset.rsi_14.is_some());
+    assert!(subset.macd.is_some());
+    assert!(subset.sma_20.is_none());
+
+    let selection = IndicatorSelection::builder()
+        .period(IndicatorKind::Rsi, 21)
+        .build()
+        .unwrap();
+    let custom = ticker
+        .indicators_selected(selection, Interval::OneDay, TimeRange::ThreeMonths)
+        .await
+        .unwrap();
+    assert!(custom.rsi_14.is_none());
+    assert!(custom.custom.contains_key("rsi_21"));
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — Chart Extension Methods from indicators.md
 // ---------------------------------------------------------------------------
@@ -281,6 +463,34 @@ async fn test_candlestick_patterns_via_chart() {
     let _ = PatternSentiment::Bullish; // confirm variant access
 }
 
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_patterns_through_indicator_api() {
+    use finance_query::indicators::{Indicator, IndicatorResult};
+    use finance_query::{Interval, Ticker, TimeRange};
+
+    // From indicators.md "Through the Indicator API" section
+    let ticker = Ticker::new("AAPL").await.unwrap();
+    let result = ticker
+        .indicator(
+            Indicator::Patterns,
+            Interval::OneDay,
+            TimeRange::ThreeMonths,
+        )
+        .await
+        .unwrap();
+    let IndicatorResult::Patterns(signals) = result else {
+        panic!("expected IndicatorResult::Patterns");
+    };
+    println!("{} patterns", signals.iter().flatten().count());
+
+    let direct = ticker
+        .patterns(Interval::OneDay, TimeRange::ThreeMonths)
+        .await
+        .unwrap();
+    assert_eq!(direct.len(), signals.len());
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — PatternSentiment from indicators.md
 // ---------------------------------------------------------------------------
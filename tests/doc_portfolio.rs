@@ -0,0 +1,252 @@
+//! Compile and runtime tests for docs/library/portfolio.md
+//!
+//! `portfolio` has no feature gate (always compiled):
+//!   cargo test --test doc_portfolio
+//!   cargo test --test doc_portfolio -- --ignored   (network tests)
+
+use std::collections::HashMap;
+
+use finance_query::portfolio::{
+    CostBasisMethod, HoldingImpact, HoldingValuation, Portfolio, PortfolioValuation, StressFactor,
+    StressResult, StressScenario, Transaction, TransactionKind,
+};
+
+// ---------------------------------------------------------------------------
+// StressResult / HoldingImpact — compile-time field verification
+// ---------------------------------------------------------------------------
+
+/// Verifies all StressResult fields documented in portfolio.md.
+#[allow(dead_code)]
+fn _verify_stress_result_fields(r: StressResult) {
+    let _: String = r.scenario;
+    let _: Vec<HoldingImpact> = r.holdings;
+    let _: f64 = r.value;
+    let _: f64 = r.pnl;
+}
+
+/// Verifies all HoldingImpact fields documented in portfolio.md.
+#[allow(dead_code)]
+fn _verify_holding_impact_fields(h: HoldingImpact) {
+    let _: Option<f64> = h.pnl_percent();
+    let _: String = h.symbol;
+    let _: f64 = h.shares;
+    let _: f64 = h.value;
+    let _: Vec<f64> = h.betas;
+    let _: Option<f64> = h.pnl;
+}
+
+/// Verifies all Transaction fields documented in portfolio.md.
+#[allow(dead_code)]
+fn _verify_transaction_fields(t: Transaction) {
+    let _: String = t.symbol;
+    let _: TransactionKind = t.kind;
+    let _: f64 = t.shares;
+    let _: f64 = t.price;
+    let _: f64 = t.fees;
+    let _: i64 = t.timestamp;
+}
+
+/// Verifies all PortfolioValuation and HoldingValuation fields documented in portfolio.md.
+#[allow(dead_code)]
+fn _verify_valuation_fields(v: PortfolioValuation, h: HoldingValuation) {
+    let _: Option<f64> = v.total_pnl();
+    let _: Vec<HoldingValuation> = v.holdings;
+    let _: f64 = v.market_value;
+    let _: Option<f64> = v.cost_basis;
+    let _: Option<f64> = v.unrealized_pnl;
+    let _: f64 = v.realized_pnl;
+    let _: Vec<String> = v.missing;
+
+    let _: Option<f64> = h.unrealized_pnl_percent();
+    let _: String = h.symbol;
+    let _: f64 = h.shares;
+    let _: f64 = h.price;
+    let _: f64 = h.market_value;
+    let _: Option<f64> = h.cost_basis;
+    let _: Option<f64> = h.unrealized_pnl;
+    let _: f64 = h.realized_pnl;
+    let _: f64 = h.weight;
+}
+
+// ---------------------------------------------------------------------------
+// Building a portfolio and scenarios — pure tests
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_build_portfolio() {
+    let portfolio = Portfolio::new()
+        .with_holding("AAPL", 100.0)
+        .with_holding("XOM", 200.0)
+        .with_holding("TLT", 150.0)
+        .with_holding("AAPL", 50.0);
+
+    assert_eq!(portfolio.symbols(), ["AAPL", "XOM", "TLT"]);
+    for holding in portfolio.holdings() {
+        println!("{} x {}", holding.symbol, holding.shares);
+    }
+    assert_eq!(portfolio.holdings()[0].shares, 150.0);
+}
+
+#[test]
+fn test_transactions_and_cost_basis() {
+    // From portfolio.md "Transactions & Cost Basis" section
+    let mut portfolio = Portfolio::with_cost_basis_method(CostBasisMethod::Fifo);
+    portfolio
+        .record(Transaction::buy("AAPL", 10.0, 100.0).at(1_700_000_000))
+        .unwrap();
+    portfolio
+        .record(
+            Transaction::buy("AAPL", 10.0, 120.0)
+                .at(1_710_000_000)
+                .fees(20.0),
+        )
+        .unwrap();
+    portfolio
+        .record(Transaction::sell("AAPL", 15.0, 130.0).at(1_720_000_000))
+        .unwrap();
+
+    let aapl = &portfolio.holdings()[0];
+    assert_eq!(aapl.shares, 5.0);
+    assert_eq!(aapl.cost_basis(), Some(610.0));
+    assert_eq!(aapl.average_cost(), Some(122.0));
+    assert_eq!(aapl.realized_pnl, 340.0);
+    assert_eq!(aapl.lots().len(), 1);
+    assert!(
+        portfolio
+            .record(Transaction::sell("AAPL", 6.0, 130.0))
+            .is_err()
+    );
+
+    let replayed = Portfolio::from_transactions(
+        CostBasisMethod::Fifo,
+        portfolio.transactions().iter().rev().cloned(),
+    )
+    .unwrap();
+    assert_eq!(replayed, portfolio);
+}
+
+#[test]
+fn test_valuation_at() {
+    // From portfolio.md "Valuation" section
+    let mut portfolio = Portfolio::new();
+    portfolio
+        .record(Transaction::buy("AAPL", 10.0, 100.0))
+        .unwrap();
+    portfolio
+        .record(Transaction::buy("MSFT", 5.0, 400.0))
+        .unwrap();
+
+    let prices = HashMap::from([("AAPL".to_string(), 120.0), ("MSFT".to_string(), 360.0)]);
+    let valuation = portfolio.valuation_at(&prices);
+    assert_eq!(valuation.market_value, 3_000.0);
+    assert_eq!(valuation.cost_basis, Some(3_000.0));
+    assert_eq!(valuation.unrealized_pnl, Some(0.0));
+    assert_eq!(valuation.holdings[0].unrealized_pnl, Some(200.0));
+    assert_eq!(valuation.holdings[1].weight, 0.6);
+    assert!(valuation.missing.is_empty());
+}
+
+#[test]
+fn test_save_and_load() {
+    // From portfolio.md "Saving and Loading" section
+    let dir = std::env::temp_dir().join(format!("fq-doc-portfolio-{}", std::process::id()));
+    let path = dir.join("portfolio.json");
+
+    let mut portfolio = Portfolio::load_or_default(&path).unwrap();
+    assert!(portfolio.is_empty());
+    portfolio
+        .record(Transaction::buy("MSFT", 5.0, 410.0))
+        .unwrap();
+    portfolio.save(&path).unwrap();
+
+    assert_eq!(Portfolio::load_or_default(&path).unwrap(), portfolio);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_factor_proxies() {
+    assert_eq!(StressFactor::Equities.symbol(), "^GSPC");
+    assert_eq!(StressFactor::Rates.symbol(), "^TNX");
+    assert_eq!(StressFactor::Oil.symbol(), "CL=F");
+    assert_eq!(StressFactor::Gold.symbol(), "GC=F");
+    assert_eq!(StressFactor::Dollar.symbol(), "DX-Y.NYB");
+    assert_eq!(StressFactor::Symbol("QQQ".into()).symbol(), "QQQ");
+}
+
+#[tokio::test]
+async fn test_stress_test_empty_portfolio_errors() {
+    let scenario = StressScenario::new("Equities -10%").shock(StressFactor::Equities, -0.10);
+    assert!(Portfolio::new().stress_test(&[scenario]).await.is_err());
+}
+
+// ---------------------------------------------------------------------------
+// Network tests
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_stress_test() {
+    let portfolio = Portfolio::new()
+        .with_holding("AAPL", 100.0)
+        .with_holding("XOM", 200.0)
+        .with_holding("TLT", 150.0);
+
+    let scenarios = [
+        StressScenario::new("Equities -10%").shock(StressFactor::Equities, -0.10),
+        StressScenario::new("Rates +100bps").shock(StressFactor::Rates, 0.01),
+        StressScenario::new("Stagflation")
+            .shock(StressFactor::Equities, -0.10)
+            .shock(StressFactor::Rates, 0.01)
+            .shock(StressFactor::Oil, 0.20),
+    ];
+
+    let results = portfolio.stress_test(&scenarios).await.unwrap();
+    assert_eq!(results.len(), 3);
+    for result in &results {
+        println!(
+            "{}: {:+.0} on {:.0}",
+            result.scenario, result.pnl, result.value
+        );
+        for h in &result.holdings {
+            println!("  {:<6} {:?} (betas {:?})", h.symbol, h.pnl, h.betas);
+        }
+        assert_eq!(result.holdings.len(), 3);
+        assert!(result.value > 0.0);
+    }
+
+    // A broad equity selloff should hurt a long equity holding
+    let aapl = &results[0].holdings[0];
+    assert!(aapl.pnl.unwrap() < 0.0);
+    // Long-duration Treasuries fall when rates rise
+    let tlt = &results[1].holdings[2];
+    assert!(tlt.pnl.unwrap() < 0.0);
+    assert_eq!(results[2].holdings[0].betas.len(), 3);
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_valuation() {
+    // From portfolio.md "Valuation" section
+    let mut portfolio = Portfolio::new().with_holding("XOM", 10.0);
+    portfolio
+        .record(Transaction::buy("AAPL", 10.0, 150.0))
+        .unwrap();
+
+    let valuation = portfolio.valuation().await.unwrap();
+    println!("Value: {:.2}", valuation.market_value);
+    for h in &valuation.holdings {
+        println!(
+            "{:<6} {:>8.2} @ {:>8.2}  {:>5.1}%  {:+.2?}",
+            h.symbol,
+            h.shares,
+            h.price,
+            h.weight * 100.0,
+            h.unrealized_pnl_percent()
+        );
+    }
+    assert_eq!(valuation.holdings.len(), 2);
+    assert!(valuation.market_value > 0.0);
+    // XOM has no recorded cost
+    assert_eq!(valuation.cost_basis, None);
+    assert!(valuation.holdings[1].unrealized_pnl.is_some());
+}
@@ -39,6 +39,72 @@ fn _verify_fetch_variants() {
     let _: Fetch = Fetch::Parallel;
 }
 
+// ---------------------------------------------------------------------------
+// Custom data providers — offline
+// ---------------------------------------------------------------------------
+
+struct InMemory;
+
+#[async_trait::async_trait]
+impl finance_query::DataProvider for InMemory {
+    fn name(&self) -> &str {
+        "memory"
+    }
+
+    async fn quote(&self, symbol: &str) -> finance_query::Result<finance_query::ProviderQuote> {
+        let mut quote = finance_query::ProviderQuote::new(symbol, 12.0);
+        quote.previous_close = Some(10.0);
+        Ok(quote)
+    }
+
+    async fn chart(
+        &self,
+        _: &str,
+        _: finance_query::Interval,
+        _: finance_query::TimeRange,
+    ) -> finance_query::Result<Vec<finance_query::Candle>> {
+        Ok((1..=3)
+            .map(|d| finance_query::Candle::new(d * 86_400, 10.0, 12.0, 9.0, 11.0, 100))
+            .collect())
+    }
+}
+
+/// Verifies a `DataProvider` serves `Ticker` and `Tickers` without network access.
+#[tokio::test]
+async fn test_custom_provider_serves_ticker() {
+    use finance_query::{Interval, Quote, Ticker, Tickers, TimeRange, format::Raw};
+
+    let ticker = Ticker::builder("TEST")
+        .provider(InMemory)
+        .build()
+        .await
+        .unwrap();
+    let quote: Quote<Raw> = ticker.quote().await.unwrap();
+    assert_eq!(quote.regular_market_price, Some(12.0));
+    assert_eq!(quote.regular_market_previous_close, Some(10.0));
+
+    let chart = ticker
+        .chart(Interval::OneDay, TimeRange::Max)
+        .await
+        .unwrap();
+    assert_eq!(chart.candles.len(), 3);
+    assert_eq!(chart.provider_id, Some(Provider::Custom));
+
+    let range = ticker
+        .chart_range(Interval::OneDay, 86_400, 3 * 86_400)
+        .await
+        .unwrap();
+    assert_eq!(range.candles.len(), 2);
+
+    let tickers = Tickers::builder(["A", "B"])
+        .provider(InMemory)
+        .build()
+        .await
+        .unwrap();
+    let quotes = tickers.quotes().await.unwrap();
+    assert_eq!(quotes.quotes.len(), 2);
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — require providers + API keys
 // ---------------------------------------------------------------------------
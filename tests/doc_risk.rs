@@ -7,7 +7,9 @@
 #![cfg(feature = "risk")]
 
 use finance_query::risk::{
-    beta, calmar_ratio, historical_var, max_drawdown, parametric_var, sharpe_ratio, sortino_ratio,
+    CashFlow, ReturnPeriod, Valuation, beta, calmar_ratio, historical_var, max_drawdown,
+    money_weighted_return, parametric_var, period_returns, sharpe_ratio, sortino_ratio,
+    time_weighted_return,
 };
 
 // ---------------------------------------------------------------------------
@@ -205,6 +207,73 @@ fn test_standalone_risk_workflow() {
     assert!(dd.max_drawdown >= 0.0);
 }
 
+// ---------------------------------------------------------------------------
+// Portfolio returns from risk.md
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_portfolio_returns_workflow() {
+    let day = 86_400;
+    // 2024-01-01 through 2025-03-31; +0.05% per day, 10,000 deposited mid-2024
+    let start = 1_704_067_200;
+    let mut value = 10_000.0;
+    let mut valuations = Vec::new();
+    let mut flows = Vec::new();
+    for i in 0..=455 {
+        if i > 0 {
+            value *= 1.0005;
+        }
+        if i == 182 {
+            value += 10_000.0;
+            flows.push(CashFlow {
+                timestamp: start + i * day,
+                amount: 10_000.0,
+            });
+        }
+        valuations.push(Valuation {
+            timestamp: start + i * day,
+            value,
+        });
+    }
+
+    // The deposit doesn't count as performance
+    let twr = time_weighted_return(&valuations, &flows).unwrap();
+    let expected = 1.0005_f64.powi(455) - 1.0;
+    assert!((twr - expected).abs() < 1e-9, "twr {twr} vs {expected}");
+    let irr: Option<f64> = money_weighted_return(&valuations, &flows);
+    assert!(irr.is_some());
+
+    let returns = period_returns(&valuations, &flows);
+    let periods: Vec<ReturnPeriod> = returns.iter().map(|r| r.period).collect();
+    assert_eq!(
+        periods,
+        [
+            ReturnPeriod::MonthToDate,
+            ReturnPeriod::QuarterToDate,
+            ReturnPeriod::YearToDate,
+            ReturnPeriod::OneYear,
+            ReturnPeriod::SinceInception,
+        ]
+    );
+    for r in &returns {
+        let _: i64 = r.start;
+        let _: i64 = r.end;
+        let _: Option<f64> = r.twr;
+        let _: Option<f64> = r.mwr;
+        println!(
+            "{:?}: TWR {:?}, MWR {:?}, annualised TWR {:?}",
+            r.period,
+            r.twr,
+            r.mwr,
+            r.annualized_twr()
+        );
+    }
+    // Without flows in the window, TWR and MWR agree
+    let mtd = &returns[0];
+    assert!((mtd.twr.unwrap() - mtd.mwr.unwrap()).abs() < 1e-9);
+    assert!(returns[4].annualized_twr().is_some());
+}
+
 // ---------------------------------------------------------------------------
 // Network tests
 // ---------------------------------------------------------------------------
@@ -9,7 +9,7 @@
 
 use finance_query::{
     EquityField, EquityScreenerQuery, FundField, FundScreenerQuery, LogicalOperator, Screener,
-    ScreenerFieldExt, Sector, SortType,
+    ScreenerFieldExt, Sector, SortType, Watchlist,
 };
 
 // ---------------------------------------------------------------------------
@@ -36,6 +36,20 @@ fn test_screener_variants_exist() {
     let _: Screener = Screener::UndervaluedLargeCaps;
 }
 
+#[test]
+fn test_regional_screener_presets() {
+    use finance_query::Region;
+
+    // From screeners.md "Other Countries" section
+    let query = Screener::regional(Screener::MostActives, Region::India)
+        .unwrap()
+        .size(50);
+    assert_eq!(query.size, 50);
+    assert_eq!(query.sort_field, EquityField::DayVolume);
+    assert!(Screener::regional(Screener::HighYieldBond, Region::Japan).is_none());
+    assert_eq!(Screener::regional_types().len(), 3);
+}
+
 // ---------------------------------------------------------------------------
 // Sector enum variants
 // ---------------------------------------------------------------------------
@@ -381,8 +395,91 @@ fn test_fund_screener_query_builder() {
     assert_eq!(query.include_fields.len(), 6);
 }
 
+// ---------------------------------------------------------------------------
+// Watchlist bridge
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_watchlist_methods() {
+    // From screeners.md "From Screen to Watchlist" section
+    let mut watchlist = Watchlist::new("Today's gainers", ["NVDA", "AMD"]);
+    assert!(watchlist.add("TSLA"));
+    assert!(!watchlist.add("nvda"));
+    assert!(watchlist.contains("AMD"));
+    assert!(watchlist.remove("AMD"));
+    assert_eq!(watchlist.len(), 2);
+    let _: &Option<String> = &watchlist.source;
+}
+
+#[test]
+fn test_screener_alert_builder() {
+    use finance_query::alerts::{self, ScreenerCondition};
+
+    // From screeners.md "Screener Alerts" section
+    let alert = alerts::on_screener(Screener::MostShortedStocks, ScreenerCondition::Enters)
+        .top(25)
+        .watch(["AAPL", "TSLA", "GME"])
+        .snapshot_dir("data/screener-alerts");
+    assert!(alert.last_snapshot().is_none());
+
+    let _shorts = alerts::on_screener(
+        EquityScreenerQuery::most_shorted(),
+        ScreenerCondition::Changes,
+    )
+    .name("my-shorts");
+}
+
+#[test]
+fn test_saved_screener_construction() {
+    use finance_query::screeners::{SavedQuery, SavedScreener};
+
+    // From screeners.md "Saved Screeners" section
+    let query = EquityScreenerQuery::new()
+        .size(100)
+        .add_condition(EquityField::Region.eq_str("us"))
+        .add_condition(EquityField::FiftyTwoWkPctChange.gte(50.0));
+    let screen = SavedScreener::new("52-week leaders", query);
+    assert!(screen.last_results.is_none());
+
+    let gainers = SavedScreener::new("gainers", SavedQuery::predefined(Screener::DayGainers, 50));
+    assert_eq!(
+        gainers.query,
+        SavedQuery::predefined(Screener::DayGainers, 50)
+    );
+}
+
 // ---------------------------------------------------------------------------
 // Network tests (screeners.md examples that hit Yahoo Finance)
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_screener_alert_check() {
+    use finance_query::alerts::{self, ScreenerCondition};
+
+    let mut alert =
+        alerts::on_screener(Screener::MostShortedStocks, ScreenerCondition::Changes).top(10);
+    // First check records the baseline
+    assert!(alert.check().await.unwrap().is_empty());
+    assert!(alert.last_snapshot().unwrap().symbols.len() <= 10);
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_saved_screener_run() {
+    use finance_query::screeners::{SavedQuery, SavedScreener};
+
+    // From screeners.md "Saved Screeners" section
+    let mut screen =
+        SavedScreener::new("gainers", SavedQuery::predefined(Screener::DayGainers, 25));
+    let diff = screen.run().await.unwrap();
+    assert!(diff.baseline && diff.is_empty());
+    let diff = screen.run().await.unwrap();
+    println!(
+        "added {:?}, removed {:?}",
+        diff.added_symbols(),
+        diff.removed
+    );
+}
 // ---------------------------------------------------------------------------
 
 #[tokio::test]
@@ -438,6 +535,25 @@ async fn test_predefined_screener_most_actives() {
     println!("Most actives: {}", actives.quotes.len());
 }
 
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_screener_pagination() {
+    use finance_query::finance;
+    use futures::TryStreamExt;
+
+    // From screeners.md "Beyond 250 Results" section
+    let page = finance::screener_page(Screener::MostActives, 250, 250)
+        .await
+        .unwrap();
+    println!("{} of {:?}", page.quotes.len(), page.total);
+
+    let universe: Vec<_> = finance::screener_stream(Screener::MostActives)
+        .try_collect()
+        .await
+        .unwrap();
+    assert!(universe.len() >= page.quotes.len());
+}
+
 #[tokio::test]
 #[ignore = "requires network access"]
 async fn test_custom_screener_preset_most_shorted() {
@@ -484,3 +600,41 @@ async fn test_fund_screener() {
     let results = finance::custom_screener(query).await.unwrap();
     println!("Fund results: {}", results.quotes.len());
 }
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_screener_to_watchlist_and_tickers() {
+    use finance_query::finance;
+
+    // From screeners.md "From Screen to Watchlist" section
+    let gainers = finance::screener(Screener::DayGainers, 25).await.unwrap();
+    let watchlist = gainers.to_watchlist("Today's gainers");
+    assert_eq!(
+        watchlist.source.as_deref(),
+        Some(gainers.screener_type.as_str())
+    );
+    assert!(watchlist.len() <= gainers.quotes.len());
+
+    let top = gainers.top_n_as_tickers(10).await.unwrap();
+    assert!(top.len() <= 10);
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_regional_screener_day_gainers() {
+    use finance_query::{Region, finance};
+
+    // From screeners.md "Other Countries" section
+    let jp = finance::regional_screener(Screener::DayGainers, Region::Japan, 25)
+        .await
+        .unwrap();
+    for quote in &jp.quotes {
+        println!(
+            "{}: {:?}",
+            quote.symbol, quote.regular_market_change_percent.raw
+        );
+    }
+
+    let err = finance::regional_screener(Screener::TopMutualFunds, Region::Japan, 25).await;
+    assert!(err.is_err());
+}
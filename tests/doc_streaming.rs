@@ -2,9 +2,14 @@
 //!
 //! Network tests are marked `#[ignore = "requires network access"]`.
 //!
+//! Requires the `streaming` feature flag (on by default); the `NewsStream`
+//! tests also need `feeds`.
+//!
 //! Run with: `cargo test --test doc_streaming`
 //! Run network tests: `cargo test --test doc_streaming -- --ignored`
 
+#![cfg(feature = "streaming")]
+
 // ---------------------------------------------------------------------------
 // Compile-time — PriceUpdate field access documented in streaming.md
 // ---------------------------------------------------------------------------
@@ -25,9 +30,26 @@ fn _verify_price_update_fields(p: finance_query::streaming::PriceUpdate) {
     let _: String = p.exchange;
     let _: finance_query::streaming::QuoteType = p.quote_type;
     let _: finance_query::streaming::MarketHoursType = p.market_hours;
+    let _: Option<i64> = p.volume();
+    let _: finance_query::streaming::AssetClass = p.asset_class;
     let _: i64 = p.time;
 }
 
+// ---------------------------------------------------------------------------
+// Compile-time — AssetClass documented in streaming.md "FX and Crypto"
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_asset_class_from_symbol() {
+    use finance_query::streaming::AssetClass;
+
+    assert_eq!(AssetClass::from_symbol("EURUSD=X"), AssetClass::Fx);
+    assert_eq!(AssetClass::from_symbol("BTC-USD"), AssetClass::Crypto);
+    assert_eq!(AssetClass::from_symbol("AAPL"), AssetClass::Equity);
+    assert!(!AssetClass::Fx.has_volume());
+    assert!(AssetClass::Crypto.is_continuous());
+}
+
 // ---------------------------------------------------------------------------
 // Compile-time — MarketHoursType variants documented in streaming.md
 // ---------------------------------------------------------------------------
@@ -83,6 +105,8 @@ async fn test_builder_pattern() {
     let stream = PriceStreamBuilder::new()
         .symbols(["AAPL", "MSFT", "NVDA"])
         .retry(Duration::from_secs(5))
+        .max_retry_delay(Duration::from_secs(120))
+        .backoff_multiplier(2.0)
         .build()
         .await
         .unwrap();
@@ -90,6 +114,35 @@ async fn test_builder_pattern() {
     let _ = stream;
 }
 
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_connection_health() {
+    use finance_query::streaming::{PriceStreamBuilder, StreamEvent};
+    use futures::StreamExt;
+    use std::time::Duration;
+
+    // From streaming.md "Connection Health" section
+    let stream = PriceStreamBuilder::new()
+        .symbols(["AAPL", "NVDA"])
+        .stale_after(Duration::from_secs(30))
+        .keepalive(Duration::from_secs(20))
+        .build()
+        .await
+        .unwrap();
+
+    let mut events = stream.events();
+    let first = tokio::time::timeout(Duration::from_secs(15), events.next())
+        .await
+        .unwrap();
+    assert_eq!(first, Some(StreamEvent::Connected));
+
+    if let Some(at) = stream.last_message_at() {
+        println!("last update {:?} ago", at.elapsed());
+    }
+    println!("stale: {}", stream.is_stale());
+    stream.close().await;
+}
+
 #[tokio::test]
 #[ignore = "requires network access"]
 async fn test_dynamic_subscriptions() {
@@ -174,10 +227,39 @@ async fn test_filtering_by_market_hours() {
     stream.close().await;
 }
 
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_fx_and_crypto_subscribe() {
+    use finance_query::streaming::{AssetClass, MarketHoursType, PriceStream};
+    use futures::StreamExt;
+    use tokio::time::{Duration, timeout};
+
+    // From streaming.md "FX and Crypto" section. Crypto trades 24/7, so an
+    // update should arrive even when equity markets are closed.
+    let mut stream = PriceStream::subscribe(["EURUSD=X", "BTC-USD"])
+        .await
+        .unwrap();
+
+    while let Ok(Some(price)) = timeout(Duration::from_secs(10), stream.next()).await {
+        assert_eq!(price.market_hours, MarketHoursType::RegularMarket);
+        match price.asset_class {
+            AssetClass::Fx => assert_eq!(price.volume(), None),
+            AssetClass::Crypto => {
+                assert!(price.volume().is_some());
+                break;
+            }
+            other => panic!("unexpected asset class {other:?} for {}", price.id),
+        }
+    }
+
+    stream.close().await;
+}
+
 // ---------------------------------------------------------------------------
 // NewsStream — mirrors streaming.md "News Streaming" section
 // ---------------------------------------------------------------------------
 
+#[cfg(feature = "feeds")]
 #[tokio::test]
 async fn test_news_stream_dynamic_sources_and_multiple_consumers() {
     use finance_query::feeds::FeedSource;
@@ -196,6 +278,7 @@ async fn test_news_stream_dynamic_sources_and_multiple_consumers() {
     stream.close().await;
 }
 
+#[cfg(feature = "feeds")]
 #[tokio::test]
 async fn test_news_stream_builder_custom_poll_interval() {
     use finance_query::feeds::FeedSource;
@@ -215,6 +298,7 @@ async fn test_news_stream_builder_custom_poll_interval() {
     stream.close().await;
 }
 
+#[cfg(feature = "feeds")]
 #[tokio::test]
 #[ignore = "requires network access"]
 async fn test_news_stream_quick_start() {
@@ -268,3 +352,36 @@ async fn test_multiple_consumers_with_spawn() {
 
     stream1.close().await;
 }
+
+// ---------------------------------------------------------------------------
+// LiveIndicatorStream — mirrors streaming.md "Live Indicators" section
+// ---------------------------------------------------------------------------
+
+#[cfg(feature = "indicators")]
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_live_indicator_stream() {
+    use finance_query::indicators::IndicatorKind;
+    use finance_query::streaming::{LiveIndicatorStream, PriceStream};
+    use finance_query::{Interval, Ticker, TimeRange};
+
+    // From streaming.md "Live Indicators" section
+    let history = Ticker::new("BTC-USD")
+        .await
+        .unwrap()
+        .chart(Interval::OneMinute, TimeRange::OneDay)
+        .await
+        .unwrap();
+
+    let prices = PriceStream::subscribe(["BTC-USD"]).await.unwrap();
+    let live = LiveIndicatorStream::new(prices, Interval::OneMinute)
+        .seed(&history)
+        .selection(&[IndicatorKind::Rsi, IndicatorKind::Ema])
+        .max_bars(300);
+
+    assert!(!live.candles("BTC-USD").is_empty());
+    assert!(live.candles("BTC-USD").len() <= 300);
+    assert!(live.forming("BTC-USD").is_none());
+
+    live.close().await;
+}
@@ -25,6 +25,27 @@ fn _verify_dividend_analytics_fields(a: DividendAnalytics) {
     let _: Option<Dividend> = a.first_payment;
 }
 
+/// Verifies the DividendAnalysis fields documented in ticker.md.
+#[allow(dead_code)]
+fn _verify_dividend_analysis_fields(a: finance_query::DividendAnalysis) {
+    let _: DividendAnalytics = a.summary;
+    let _: Option<finance_query::PayoutFrequency> = a.frequency;
+    let _: Option<f64> = a.growth_rate;
+    let _: u32 = a.consecutive_increases;
+    let _: Option<f64> = a.current_yield;
+    for year in a.annual {
+        let _: i32 = year.year;
+        let _: f64 = year.total;
+        let _: usize = year.payments;
+    }
+    for point in a.yield_history {
+        let _: i64 = point.timestamp;
+        let _: f64 = point.close;
+        let _: f64 = point.trailing_dividends;
+        let _: Option<f64> = point.trailing_yield;
+    }
+}
+
 /// Verifies Dividend struct fields (used in DividendAnalytics).
 #[allow(dead_code)]
 fn _verify_dividend_fields(d: Dividend) {
@@ -55,6 +76,21 @@ fn _verify_builder_manual_methods() {
         .proxy("http://proxy.example.com:8080");
 }
 
+// ---------------------------------------------------------------------------
+// Selective Module Fetching from ticker.md — builder input types
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_quote_modules_accepts_slices() {
+    use finance_query::{QuoteModule, Ticker};
+
+    // From ticker.md "Selective Module Fetching" section
+    let wanted = [QuoteModule::FinancialData, QuoteModule::Price];
+    let _from_array = Ticker::builder("AAPL").quote_modules(wanted);
+    let _from_slice = Ticker::builder("AAPL").quote_modules(&wanted[..]);
+    let _from_vec = Ticker::builder("AAPL").quote_modules(wanted.to_vec());
+}
+
 // ---------------------------------------------------------------------------
 // Network tests
 // ---------------------------------------------------------------------------
@@ -96,6 +132,41 @@ async fn test_dividend_analytics() {
     }
 }
 
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_dividend_analysis() {
+    use finance_query::{Ticker, TimeRange};
+
+    // From ticker.md "Dividend Analysis" section
+    let ticker = Ticker::new("AAPL").await.unwrap();
+    let analysis = ticker.dividend_analysis(TimeRange::TenYears).await.unwrap();
+
+    println!("Frequency:      {:?}", analysis.frequency);
+    println!("Current yield:  {:?}", analysis.current_yield);
+    println!("Growth rate:    {:?}", analysis.growth_rate);
+    println!(
+        "Increases:      {} consecutive years",
+        analysis.consecutive_increases
+    );
+
+    for year in &analysis.annual {
+        println!(
+            "  {}: ${:.2} over {} payments",
+            year.year, year.total, year.payments
+        );
+    }
+
+    for point in analysis.yield_history.iter().rev().step_by(13).take(4) {
+        println!("  {}: {:?}", point.timestamp, point.trailing_yield);
+    }
+
+    assert_eq!(
+        analysis.frequency,
+        Some(finance_query::PayoutFrequency::Quarterly)
+    );
+    assert!(analysis.current_yield.unwrap() > 0.0);
+}
+
 #[tokio::test]
 #[ignore = "requires network access"]
 async fn test_chart_candles() {
@@ -123,6 +194,31 @@ async fn test_chart_candles() {
     }
 }
 
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_relative_volume() {
+    use finance_query::{Interval, RelativeVolume, Ticker, TimeRange};
+
+    let ticker = Ticker::new("AAPL").await.unwrap();
+    let rv = ticker.rvol(Interval::FiveMinutes).await.unwrap();
+    let _: i64 = rv.volume;
+    let _: f64 = rv.average_volume;
+    let _: u32 = rv.days;
+    let _: Option<f64> = rv.rvol;
+    println!(
+        "{} RVOL {:?} ({} vs avg {:.0} over {} days)",
+        rv.symbol, rv.rvol, rv.volume, rv.average_volume, rv.days
+    );
+
+    assert!(ticker.rvol(Interval::OneDay).await.is_err());
+
+    let chart = ticker
+        .chart(Interval::FiveMinutes, TimeRange::OneMonth)
+        .await
+        .unwrap();
+    assert!(RelativeVolume::from_chart(&chart).is_some());
+}
+
 #[tokio::test]
 #[ignore = "requires network access"]
 async fn test_dividends() {
@@ -202,6 +298,55 @@ async fn test_ticker_risk_no_benchmark() {
     assert!(risk.max_drawdown >= 0.0);
 }
 
+// ---------------------------------------------------------------------------
+// Symbols from ticker.md
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_symbol_helpers() {
+    use finance_query::Symbol;
+
+    let sym: Symbol = "7203.t".parse().unwrap();
+    assert_eq!(sym.as_str(), "7203.T");
+    assert_eq!(sym.exchange_suffix(), Some("T"));
+    assert_eq!(sym.base(), "7203");
+
+    let shop_tsx = Symbol::parse("SHOP")
+        .unwrap()
+        .with_exchange_suffix("TO")
+        .unwrap();
+    assert_eq!(shop_tsx, "SHOP.TO");
+    assert!(Symbol::parse("^GSPC").unwrap().is_index());
+    assert!(Symbol::parse("EURUSD=X").unwrap().is_currency_pair());
+
+    let brk = Symbol::parse("BRK.B").unwrap();
+    assert_eq!(brk.base(), "BRK.B");
+}
+
+#[tokio::test]
+async fn test_invalid_symbol_fails_before_network() {
+    use finance_query::{FinanceError, Ticker};
+
+    let err = Ticker::new("AA PL").await.err().unwrap();
+    assert!(matches!(err, FinanceError::InvalidParameter { .. }));
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_symbol_normalized_on_build() {
+    use finance_query::{Symbol, Ticker};
+
+    let ticker = Ticker::new(" aapl ").await.unwrap();
+    assert_eq!(ticker.symbol(), "AAPL");
+
+    let shop_tsx = Symbol::parse("SHOP")
+        .unwrap()
+        .with_exchange_suffix("TO")
+        .unwrap();
+    let ticker = Ticker::new(shop_tsx).await.unwrap();
+    assert_eq!(ticker.symbol(), "SHOP.TO");
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — Builder Pattern from ticker.md
 // ---------------------------------------------------------------------------
@@ -261,6 +406,39 @@ async fn test_ticker_builder_manual_config() {
     assert_eq!(quote.symbol, "AAPL");
 }
 
+// ---------------------------------------------------------------------------
+// Network tests — Selective Module Fetching from ticker.md
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_selective_quote_modules() {
+    use finance_query::{QuoteModule, Ticker};
+    use std::time::Duration;
+
+    let ticker = Ticker::builder("AAPL")
+        .quote_modules([QuoteModule::Price, QuoteModule::SummaryDetail])
+        .cache(Duration::from_secs(60))
+        .build()
+        .await
+        .unwrap();
+
+    assert!(ticker.price().await.unwrap().is_some());
+    assert!(ticker.summary_detail().await.unwrap().is_some());
+    assert!(ticker.asset_profile().await.unwrap().is_some());
+
+    ticker
+        .prefetch(&[QuoteModule::FinancialData, QuoteModule::KeyStats])
+        .await
+        .unwrap();
+    assert!(ticker.financial_data().await.unwrap().is_some());
+
+    // Earlier modules survive the merge
+    let quote = ticker.quote::<finance_query::format::Raw>().await.unwrap();
+    assert_eq!(quote.symbol, "AAPL");
+    assert!(quote.regular_market_price.is_some());
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — Aggregated Quote from ticker.md
 // ---------------------------------------------------------------------------
@@ -351,6 +529,52 @@ async fn test_ticker_quote_modules() {
 // Network tests — Company Analysis example from ticker.md
 // ---------------------------------------------------------------------------
 
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_ticker_index_trend() {
+    use finance_query::{IndexTrend, Ticker};
+
+    // From ticker.md "Quote Modules" section
+    let ticker = Ticker::new("AAPL").await.unwrap();
+
+    let index: Option<IndexTrend> = ticker.index_trend().await.unwrap();
+    if let Some(index) = index {
+        println!(
+            "{} P/E {:?}, PEG {:?}",
+            index.symbol.as_deref().unwrap_or("index"),
+            index.pe(),
+            index.peg()
+        );
+        for (period, growth) in index.growth_by_period() {
+            println!("  {period}: {:.1}%", growth * 100.0);
+        }
+        println!("Next year: {:?}", index.growth("+1y"));
+    }
+    let _ = ticker.industry_trend().await.unwrap();
+    let _ = ticker.sector_trend().await.unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_ticker_esg_scores() {
+    use finance_query::Ticker;
+
+    // From ticker.md "Quote Modules" section
+    let ticker = Ticker::new("AAPL").await.unwrap();
+    if let Some(esg) = ticker.esg_scores().await.unwrap() {
+        let total = esg.total_esg.as_ref().and_then(|v| v.raw);
+        let peers = esg.peer_esg_score_performance.as_ref().and_then(|p| p.avg);
+        println!(
+            "ESG risk {total:?} vs peer average {peers:?} ({})",
+            esg.peer_group.as_deref().unwrap_or("-")
+        );
+        println!(
+            "Controversy level {:?}: {:?}",
+            esg.highest_controversy, esg.related_controversy
+        );
+    }
+}
+
 #[tokio::test]
 #[ignore = "requires network access"]
 async fn test_ticker_company_analysis() {
@@ -437,6 +661,40 @@ async fn test_ticker_chart_meta_fields() {
     assert!(!chart.candles.is_empty());
 }
 
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_ticker_chart_between() {
+    use chrono::{TimeZone, Utc};
+    use finance_query::{Interval, Ticker};
+
+    // From ticker.md "Chart (OHLCV) Data" section — exact window
+    let ticker = Ticker::new("AAPL").await.unwrap();
+    let start = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+    let chart = ticker
+        .chart_between(Interval::OneDay, start, end)
+        .await
+        .unwrap();
+
+    // ~253 trading days in 2020, all inside the window
+    assert!(chart.candles.len() > 240);
+    assert!(
+        chart
+            .candles
+            .iter()
+            .all(|c| c.timestamp >= start.timestamp() && c.timestamp < end.timestamp())
+    );
+    assert!(chart.range.is_none());
+
+    // Reversed bounds are rejected before any request
+    assert!(
+        ticker
+            .chart_between(Interval::OneDay, end, start)
+            .await
+            .is_err()
+    );
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — Capital Gains from ticker.md
 // ---------------------------------------------------------------------------
@@ -458,6 +716,104 @@ async fn test_ticker_capital_gains() {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Network tests — Chart with Events from ticker.md
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_ticker_chart_with_events() {
+    use finance_query::{ChartEventKind, Interval, Ticker, TimeRange};
+
+    // From ticker.md "Chart with Events" section
+    let ticker = Ticker::new("AAPL").await.unwrap();
+    let chart = ticker
+        .chart_with_events(Interval::OneWeek, TimeRange::FiveYears)
+        .await
+        .unwrap();
+
+    for event in &chart.events {
+        let candle = &chart.candles()[event.candle_index];
+        match &event.kind {
+            ChartEventKind::Dividend { amount } => {
+                println!("dividend ${amount:.4} on bar closing {:.2}", candle.close)
+            }
+            ChartEventKind::Split { ratio, .. } => {
+                println!("split {ratio} on bar closing {:.2}", candle.close)
+            }
+            _ => {}
+        }
+    }
+
+    assert!(chart.dividends().count() > 0);
+    assert!(
+        chart
+            .events
+            .iter()
+            .all(|e| e.candle_index < chart.candles().len())
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_ticker_chart_auto_adjust() {
+    use finance_query::{Interval, Ticker, TimeRange};
+
+    // From ticker.md "Adjusted Prices" section
+    let ticker = Ticker::new("AAPL").await.unwrap();
+    let chart = ticker
+        .chart_with_events(Interval::OneDay, TimeRange::FiveYears)
+        .await
+        .unwrap()
+        .auto_adjust();
+    let audit = chart.chart.adjustment.as_ref().unwrap();
+
+    for range in &audit.ranges {
+        println!(
+            "bars {}..={}: x{:.6}",
+            range.start_index, range.end_index, range.factor
+        );
+    }
+    for event in &audit.events {
+        println!(
+            "{:?} at bar {}: x{:?}",
+            event.kind, event.candle_index, event.factor
+        );
+    }
+    println!(
+        "{:?} vs {:.6}",
+        audit.factor_at(0),
+        audit.event_factor_at(0)
+    );
+
+    assert_eq!(
+        audit.ranges.last().map(|r| r.end_index + 1),
+        Some(chart.candles().len())
+    );
+    assert!(chart.candles().iter().all(|c| c.adj_close == Some(c.close)));
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_ticker_chart_total_return() {
+    use finance_query::{Candle, Interval, Ticker, TimeRange};
+
+    // From ticker.md "Total Return" section
+    let ticker = Ticker::new("KO").await.unwrap();
+    let chart = ticker
+        .chart(Interval::OneDay, TimeRange::TenYears)
+        .await
+        .unwrap();
+    let total = chart.total_return_series();
+
+    let growth = |c: &[Candle]| c.last().unwrap().close / c[0].close - 1.0;
+    println!("Price return: {:.1}%", growth(&chart.candles) * 100.0);
+    println!("Total return: {:.1}%", growth(&total.candles) * 100.0);
+
+    assert_eq!(total.candles[0].close, chart.candles[0].close);
+    assert!(growth(&total.candles) > growth(&chart.candles));
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — Technical Indicators from ticker.md (indicators feature)
 // ---------------------------------------------------------------------------
@@ -654,6 +1010,119 @@ async fn test_ticker_financials() {
     let _ = cashflow;
 }
 
+// ---------------------------------------------------------------------------
+// Runtime — Greeks from ticker.md
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_black_scholes_greeks() {
+    use finance_query::{BlackScholes, OptionKind};
+
+    // From ticker.md "Greeks" section
+    let model = BlackScholes::new(OptionKind::Put, 190.0, 180.0, 30.0 / 365.0, 0.25)
+        .rate(0.045)
+        .dividend_yield(0.005);
+    let greeks = model.greeks().unwrap();
+    assert!(greeks.delta < 0.0 && greeks.delta > -0.5);
+    assert!(greeks.gamma > 0.0);
+    assert!(greeks.theta < 0.0);
+
+    let iv = model.implied_volatility(greeks.price).unwrap();
+    assert!((iv - 0.25).abs() < 1e-6);
+    assert!(model.implied_volatility(200.0).is_none());
+}
+
+/// Verifies the Greeks fields and contract helpers documented in ticker.md.
+#[allow(dead_code)]
+fn _verify_greeks_fields(contract: &finance_query::OptionContract) {
+    if let Some(g) = contract.greeks(190.0, 0.045) {
+        let _: f64 = g.price;
+        let _: f64 = g.implied_volatility;
+        let _: f64 = g.delta;
+        let _: f64 = g.gamma;
+        let _: f64 = g.theta;
+        let _: f64 = g.vega;
+        let _: f64 = g.rho;
+    }
+    let _: Option<finance_query::OptionKind> = contract.kind();
+    let _: Option<f64> = contract.market_price();
+    let _ = contract.greeks_at(190.0, 0.045, 1_700_000_000);
+}
+
+#[test]
+fn test_option_strategy_payoff() {
+    use finance_query::OptionKind;
+    use finance_query::options::strategy::{Leg, OptionStrategy, Side};
+
+    // From ticker.md "Strategies" section
+    let ratio = OptionStrategy::new("1x2 call ratio")
+        .leg(Leg::new(OptionKind::Call, Side::Long, 100.0, 5.0))
+        .leg(Leg::new(OptionKind::Call, Side::Short, 110.0, 2.0).quantity(2));
+
+    assert_eq!(ratio.net_premium(), -100.0);
+    assert_eq!(ratio.max_profit(), Some(900.0));
+    assert_eq!(ratio.max_loss(), None);
+    assert_eq!(ratio.breakevens(), vec![101.0, 119.0]);
+
+    let curve = ratio.payoff_curve(80.0, 130.0, 10);
+    assert_eq!(curve.len(), 11);
+    assert_eq!(curve[0].pnl, -100.0);
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_iv_surface() {
+    use finance_query::Ticker;
+    use finance_query::options::IvSurface;
+
+    // From ticker.md "Volatility Surface" section
+    let ticker = Ticker::new("AAPL").await.unwrap();
+    let surface = IvSurface::from_chains(&ticker.options_all().await.unwrap()).unwrap();
+
+    for point in surface.term_structure() {
+        println!(
+            "{} ({:.2}y): {:.1}%",
+            point.expiration,
+            point.years,
+            point.atm_iv * 100.0
+        );
+    }
+
+    for point in surface.skew(0.10) {
+        println!("{}: skew {:?}", point.expiration, point.skew);
+    }
+
+    let expiry = chrono::Utc::now().timestamp() + 45 * 86_400;
+    println!("45d 95% IV: {:?}", surface.iv_at_moneyness(expiry, 0.95));
+    println!("45d $200 IV: {:?}", surface.iv_at(expiry, 200.0));
+
+    assert!(!surface.slices.is_empty());
+    assert!(surface.iv_at_moneyness(expiry, 1.0).unwrap() > 0.0);
+}
+
+/// Verifies the strategy presets documented in ticker.md.
+#[allow(dead_code)]
+fn _verify_option_strategy_fields(
+    calls: &[finance_query::OptionContract],
+) -> finance_query::Result<()> {
+    use finance_query::options::strategy::OptionStrategy;
+
+    let spread = OptionStrategy::vertical_spread(&calls[0], &calls[1])?;
+    let _: &str = &spread.name;
+    let _: f64 = spread.multiplier;
+    for leg in &spread.legs {
+        let _: finance_query::OptionKind = leg.kind;
+        let _: f64 = leg.strike;
+        let _: f64 = leg.premium;
+        let _: u32 = leg.quantity;
+        let _: Option<&str> = leg.contract_symbol.as_deref();
+    }
+    let _ = OptionStrategy::straddle(&calls[0], &calls[1])?;
+    let _ = OptionStrategy::strangle(&calls[0], &calls[1])?;
+    let _ = OptionStrategy::iron_condor(&calls[0], &calls[1], &calls[2], &calls[3])?;
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — Options Data from ticker.md
 // ---------------------------------------------------------------------------
@@ -697,6 +1166,51 @@ async fn test_ticker_options() {
     if exp_dates.len() > 1 {
         let _options_dated = ticker.options(Some(exp_dates[1])).await.unwrap();
     }
+
+    // From ticker.md "Greeks" section
+    let spot = options.underlying_price().unwrap_or_default();
+    assert!(spot > 0.0);
+    let with_greeks = options
+        .calls()
+        .iter()
+        .filter_map(|c| c.greeks(spot, 0.045))
+        .count();
+    println!("\n{with_greeks} calls with Greeks");
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_ticker_options_all() {
+    use finance_query::Ticker;
+
+    // From ticker.md "All Expirations" section
+    let ticker = Ticker::new("AAPL").await.unwrap();
+    let all = ticker.options_all().await.unwrap();
+
+    for chain in &all.chains {
+        let stats = chain.stats();
+        println!(
+            "{}: {} calls, {} puts, put/call OI {:.2}",
+            chain.expiration_date,
+            stats.call_count,
+            stats.put_count,
+            stats.put_call_ratio.unwrap_or(0.0),
+        );
+    }
+
+    let stats = all.stats();
+    println!(
+        "Call OI: {}  Put OI: {}",
+        stats.call_open_interest, stats.put_open_interest
+    );
+    println!("Put/call ratio (OI): {:?}", stats.put_call_ratio);
+    println!("Put/call ratio (volume): {:?}", stats.put_call_volume_ratio);
+
+    assert!(all.chains.len() > 1);
+    assert!(
+        all.expiration_dates().windows(2).all(|w| w[0] < w[1]),
+        "chains should be sorted by expiration"
+    );
 }
 
 // ---------------------------------------------------------------------------
@@ -745,6 +1259,88 @@ async fn test_ticker_calendar() {
     }
 }
 
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_ticker_performance_overview() {
+    use finance_query::{PerformancePeriod, Ticker};
+
+    // From ticker.md "Performance vs Benchmark" section
+    let ticker = Ticker::new("AAPL").await.unwrap();
+
+    if let Some(perf) = ticker.performance_overview().await.unwrap() {
+        println!("vs {:?} as of {:?}", perf.benchmark_name, perf.as_of);
+        for row in &perf.periods {
+            println!(
+                "{:>4}: {:?} vs {:?} (delta {:?})",
+                row.period, row.stock, row.benchmark, row.delta
+            );
+        }
+        if let Some(ytd) = perf.get(PerformancePeriod::Ytd) {
+            println!("YTD outperformance: {:?}", ytd.delta);
+        }
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_ticker_key_dates() {
+    use finance_query::Ticker;
+
+    // From ticker.md "Key Dates" section
+    let ticker = Ticker::new("AAPL").await.unwrap();
+
+    if let Some(dates) = ticker.key_dates().await.unwrap() {
+        if let Some(next) = dates.next_earnings_date() {
+            println!(
+                "Next earnings: {next} (estimate: {:?})",
+                dates.earnings_date_is_estimate
+            );
+        }
+        let eps = dates.eps_estimate;
+        println!(
+            "EPS est: {:?} ({:?} – {:?})",
+            eps.average, eps.low, eps.high
+        );
+        println!(
+            "Ex-dividend: {:?}, paid: {:?}",
+            dates.ex_dividend_date, dates.dividend_date
+        );
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_ticker_earnings_chart() {
+    use finance_query::Ticker;
+
+    // From ticker.md "Earnings Chart" section
+    let ticker = Ticker::new("AAPL").await.unwrap();
+
+    let chart = ticker
+        .earnings_chart()
+        .await
+        .unwrap()
+        .expect("AAPL should have earnings data");
+    assert!(!chart.quarterly_eps.is_empty());
+    for q in &chart.quarterly_eps {
+        let mark = match q.beat() {
+            Some(true) => "beat",
+            Some(false) => "miss",
+            None => "n/a",
+        };
+        println!(
+            "{} actual {:?} vs est {:?} ({mark})",
+            q.period, q.actual, q.estimate
+        );
+    }
+    for y in &chart.yearly {
+        println!(
+            "{} revenue {:?} earnings {:?}",
+            y.period, y.revenue, y.earnings
+        );
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — News from ticker.md
 // ---------------------------------------------------------------------------
@@ -769,6 +1365,83 @@ async fn test_ticker_news() {
     assert!(!news.is_empty());
 }
 
+#[cfg(feature = "feeds")]
+#[allow(dead_code)]
+fn _verify_press_release_fields(r: finance_query::PressRelease) {
+    let _: String = r.symbol;
+    let _: String = r.title;
+    let _: String = r.url;
+    let _: finance_query::PressWire = r.wire;
+    let _: Option<i64> = r.published;
+    let _: Option<String> = r.summary;
+}
+
+#[cfg(feature = "feeds")]
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_ticker_press_releases() {
+    use finance_query::Ticker;
+
+    // From ticker.md "Press Releases" section
+    let ticker = Ticker::new("AAPL").await.unwrap();
+    let releases = ticker.press_releases().await.unwrap();
+
+    for release in &releases {
+        println!("[{}] {}", release.wire.name(), release.title);
+        println!("  URL: {}", release.url);
+        assert_eq!(release.symbol, "AAPL");
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Attention History from ticker.md
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_attention_store_roundtrip() {
+    use chrono::NaiveDate;
+    use finance_query::attention::{AttentionSnapshot, AttentionStore};
+
+    let dir = std::env::temp_dir().join(format!("fq-doc-attention-{}", std::process::id()));
+    let store = AttentionStore::open(&dir).unwrap();
+
+    let mut snapshot = AttentionSnapshot::new("AAPL", NaiveDate::from_ymd_opt(2026, 1, 5).unwrap());
+    snapshot.news_count = Some(8);
+    store.record(&snapshot).unwrap();
+
+    let history = store.history("AAPL").unwrap();
+    assert_eq!(history, vec![snapshot]);
+    assert_eq!(store.symbols().unwrap(), vec!["AAPL".to_string()]);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_ticker_attention_history() {
+    use finance_query::Ticker;
+    use finance_query::attention::AttentionStore;
+
+    let dir = std::env::temp_dir().join(format!("fq-doc-attention-net-{}", std::process::id()));
+    let store = AttentionStore::open(&dir).unwrap();
+    let ticker = Ticker::builder("AAPL")
+        .attention(store)
+        .build()
+        .await
+        .unwrap();
+
+    let today = ticker.record_attention().await.unwrap();
+    println!(
+        "news={:?} trending_rank={:?}",
+        today.news_count, today.trending_rank
+    );
+
+    let history = ticker.attention_history().unwrap();
+    assert_eq!(history.last(), Some(&today));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
 // ---------------------------------------------------------------------------
 // Network tests — Earnings Transcripts from ticker.md
 // ---------------------------------------------------------------------------
@@ -896,3 +1569,252 @@ async fn test_ticker_news_sentiment() {
         SentimentLabel::Bullish | SentimentLabel::Neutral | SentimentLabel::Bearish
     ));
 }
+
+// ---------------------------------------------------------------------------
+// Quote Changes — from ticker.md "Quote Changes" section
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_quote_diff() {
+    use finance_query::{Quote, format::Raw};
+
+    let before: Quote<Raw> = serde_json::from_value(serde_json::json!({
+        "symbol": "AAPL",
+        "regularMarketPrice": 190.0,
+        "shortRatio": 1.5,
+    }))
+    .unwrap();
+    let after: Quote<Raw> = serde_json::from_value(serde_json::json!({
+        "symbol": "AAPL",
+        "regularMarketPrice": 191.0,
+        "shortRatio": 1.5,
+    }))
+    .unwrap();
+
+    let diff = after.diff(&before);
+    assert_eq!(diff.symbol, "AAPL");
+    assert_eq!(diff.changes.len(), 1);
+    assert_eq!(diff.get("regularMarketPrice").unwrap().delta(), Some(1.0));
+    assert!(
+        diff.only(&["recommendationTrend", "exDividendDate"])
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_watch_quote() {
+    use finance_query::Ticker;
+    use futures::StreamExt;
+    use std::time::Duration;
+
+    let ticker = Ticker::new("BTC-USD").await.unwrap();
+    let changes = ticker.watch_quote(Duration::from_secs(5));
+    let mut changes = std::pin::pin!(changes);
+
+    let first = tokio::time::timeout(Duration::from_secs(60), changes.next()).await;
+    if let Ok(Some(diff)) = first {
+        let diff = diff.unwrap();
+        assert_eq!(diff.symbol, "BTC-USD");
+        assert!(!diff.is_empty());
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Bond Quotes — from ticker.md "Bond Quotes" section
+// ---------------------------------------------------------------------------
+
+#[test]
+fn test_bond_quote_view() {
+    use finance_query::Quote;
+
+    let quote: Quote = serde_json::from_value(serde_json::json!({
+        "symbol": "BOND1",
+        "quoteType": "BOND",
+        "regularMarketPrice": {"raw": 99.1, "fmt": "99.10"},
+        "couponRate": {"raw": 4.0, "fmt": "4.00%"},
+        "maturityDate": {"raw": 2_000_000_000, "fmt": "2033-05-18"},
+        "yieldToMaturity": {"raw": 4.12, "fmt": "4.12%"},
+    }))
+    .unwrap();
+
+    let bond = quote.bond().unwrap();
+    assert_eq!(bond.coupon_rate, Some(4.0));
+    assert_eq!(bond.yield_to_maturity, Some(4.12));
+    assert!(bond.years_to_maturity(1_700_000_000).unwrap() > 9.0);
+
+    let equity: Quote =
+        serde_json::from_value(serde_json::json!({"symbol": "AAPL", "quoteType": "EQUITY"}))
+            .unwrap();
+    assert!(equity.bond().is_none());
+}
+
+// ---------------------------------------------------------------------------
+// Top of Book — from ticker.md "Top of Book" section
+// ---------------------------------------------------------------------------
+
+/// Verifies the TopOfBook fields documented in ticker.md.
+#[allow(dead_code)]
+fn _verify_top_of_book_fields(b: finance_query::TopOfBook) {
+    let _: String = b.symbol;
+    let _: Option<f64> = b.bid;
+    let _: Option<i64> = b.bid_size;
+    let _: Option<f64> = b.ask;
+    let _: Option<i64> = b.ask_size;
+    let _: Option<f64> = b.last_price;
+    let _: Option<String> = b.currency;
+    let _: Option<String> = b.market_state;
+    let _: Option<i64> = b.market_time;
+    let _: i64 = b.fetched_at;
+}
+
+#[test]
+fn test_top_of_book_metrics() {
+    use finance_query::TopOfBook;
+
+    let book: TopOfBook = serde_json::from_value(serde_json::json!({
+        "symbol": "AAPL",
+        "bid": 99.0,
+        "bidSize": 3,
+        "ask": 101.0,
+        "askSize": 1,
+        "fetchedAt": 1_700_000_000,
+    }))
+    .unwrap();
+    assert_eq!(book.spread(), Some(2.0));
+    assert_eq!(book.mid(), Some(100.0));
+    assert_eq!(book.spread_bps(), Some(200.0));
+    assert_eq!(book.imbalance(), Some(0.5));
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_ticker_book() {
+    use finance_query::Ticker;
+
+    let ticker = Ticker::new("AAPL").await.unwrap();
+    let book = ticker.book().await.unwrap();
+    assert_eq!(book.symbol, "AAPL");
+    assert!(book.fetched_at > 0);
+    if let (Some(bid), Some(ask)) = (book.bid, book.ask) {
+        assert!(ask >= bid);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Shared Session — from ticker.md "Shared Session" section
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_finance_client_shared_session() {
+    use finance_query::{FinanceClient, Ticker, Tickers};
+    use std::time::Duration;
+
+    let client = FinanceClient::builder()
+        .timeout(Duration::from_secs(20))
+        .cache(Duration::from_secs(60))
+        .build()
+        .await
+        .unwrap();
+    assert_eq!(client.lang(), "en-US");
+
+    let aapl = Ticker::with_client(&client, "AAPL").build().await.unwrap();
+    let batch = Tickers::with_client(&client, ["NVDA", "GOOGL"])
+        .build()
+        .await
+        .unwrap();
+    assert_eq!(aapl.symbol(), "AAPL");
+    assert_eq!(batch.symbols(), ["NVDA", "GOOGL"]);
+
+    let quote = aapl.quote::<finance_query::format::Raw>().await.unwrap();
+    assert_eq!(quote.symbol, "AAPL");
+    let _msft = Ticker::builder("MSFT")
+        .client(client.handle().unwrap())
+        .build()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_finance_client_session_file() {
+    use finance_query::{FinanceClient, Ticker};
+
+    let path = std::env::temp_dir().join(format!("fq-doc-session-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    FinanceClient::builder()
+        .session_file(&path)
+        .build()
+        .await
+        .unwrap();
+    assert!(path.exists());
+
+    // Second build reuses the saved session without a handshake
+    let client = FinanceClient::builder()
+        .session_file(&path)
+        .build()
+        .await
+        .unwrap();
+    let aapl = Ticker::with_client(&client, "AAPL").build().await.unwrap();
+    assert!(aapl.price().await.unwrap().is_some());
+    let _ = std::fs::remove_file(&path);
+}
+
+// ---------------------------------------------------------------------------
+// On-Disk Cache — from ticker.md "On-Disk Cache (Optional)" section
+// ---------------------------------------------------------------------------
+
+#[cfg(feature = "cache")]
+#[test]
+fn test_disk_cache_open_and_prune() {
+    use finance_query::cache::DiskCache;
+    use std::time::Duration;
+
+    let dir = std::env::temp_dir().join(format!("fq-doc-cache-{}", std::process::id()));
+    let cache = DiskCache::open(&dir)
+        .unwrap()
+        .with_ttl(Duration::from_secs(60));
+    assert_eq!(cache.ttl(), Some(Duration::from_secs(60)));
+    assert_eq!(cache.prune().unwrap(), 0);
+    cache.clear("AAPL").unwrap();
+    assert!(cache.clear("../AAPL").is_err());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(feature = "cache")]
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_ticker_disk_cache() {
+    use finance_query::{Interval, Ticker, TimeRange};
+    use std::time::Duration;
+
+    let dir = std::env::temp_dir().join(format!("fq-doc-cache-net-{}", std::process::id()));
+    let ticker = Ticker::builder("AAPL")
+        .cache_dir(&dir)
+        .cache_ttl(Duration::from_secs(6 * 60 * 60))
+        .build()
+        .await
+        .unwrap();
+    let chart = ticker
+        .chart(Interval::OneDay, TimeRange::OneMonth)
+        .await
+        .unwrap();
+    let quote = ticker.quote::<finance_query::format::Raw>().await.unwrap();
+
+    // A fresh Ticker with the same directory is served from disk
+    let again = Ticker::builder("AAPL")
+        .cache_dir(&dir)
+        .build()
+        .await
+        .unwrap();
+    let cached = again
+        .chart(Interval::OneDay, TimeRange::OneMonth)
+        .await
+        .unwrap();
+    assert_eq!(cached.candles.len(), chart.candles.len());
+    let cached_quote = again.quote::<finance_query::format::Raw>().await.unwrap();
+    assert_eq!(cached_quote.symbol, quote.symbol);
+    assert!(dir.join("AAPL").is_dir());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
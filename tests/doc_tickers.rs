@@ -114,6 +114,38 @@ async fn test_batch_quotes_utility_methods() {
     assert!(response.success_count() > 0);
 }
 
+#[tokio::test]
+#[ignore = "requires network access"]
+async fn test_quote_batch_window() {
+    use finance_query::{FinanceClient, Tickers};
+    use std::time::Duration;
+
+    // From tickers.md "Coalescing Concurrent Requests" section
+    let client = FinanceClient::builder()
+        .quote_batch_window(Duration::from_millis(50))
+        .build()
+        .await
+        .unwrap();
+
+    let tech = Tickers::with_client(&client, ["AAPL", "MSFT", "NVDA"])
+        .build()
+        .await
+        .unwrap();
+    let mixed = Tickers::with_client(&client, ["NVDA", "JPM"])
+        .build()
+        .await
+        .unwrap();
+
+    let (tech_quotes, mixed_quotes) = tokio::join!(tech.quotes(), mixed.quotes());
+    let (tech_quotes, mixed_quotes) = (tech_quotes.unwrap(), mixed_quotes.unwrap());
+
+    // Each caller only sees its own symbols from the merged response
+    assert!(tech_quotes.quotes.contains_key("AAPL"));
+    assert!(!tech_quotes.quotes.contains_key("JPM"));
+    assert!(mixed_quotes.quotes.contains_key("JPM"));
+    assert!(!mixed_quotes.quotes.contains_key("AAPL"));
+}
+
 #[tokio::test]
 #[ignore = "requires network access"]
 async fn test_batch_charts() {